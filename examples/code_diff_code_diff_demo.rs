@@ -1,38 +1,125 @@
-use crossterm::event::KeyCode;
-use ratatui::{widgets::Block, Frame};
-use ratkit::widgets::code_diff::CodeDiff;
+use crossterm::event::{KeyCode, MouseEvent};
+use ratatui::{layout::Rect, widgets::Block, Frame};
+use ratkit::widgets::code_diff::{CodeDiff, CodeDiffEvent, Direction};
+use ratkit::widgets::copy_menu::{CopyMenu, CopyMenuEvent};
 use ratkit::{
     run_with_diagnostics, CoordinatorAction, CoordinatorApp, CoordinatorEvent, RunnerConfig,
 };
 
 struct CodeDiffDemo {
     diff: CodeDiff,
+    copy_menu: CopyMenu,
+    last_copy: Option<String>,
+    last_file_request: Option<Direction>,
+    diff_area: Rect,
 }
 
 impl CodeDiffDemo {
     fn new() -> Self {
-        let diff = CodeDiff::from_unified_diff("@@ -1,3 +1,3 @@\n-old line\n+new line\n unchanged")
-            .with_file_path("src/lib.rs");
-        Self { diff }
+        // The last hunk has long unchanged runs, which fold behind markers
+        let mut patch = String::from(
+            "@@ -1,2 +1,2 @@\n-old line\n+new line\n unchanged\n@@ -4,1 +4,2 @@\n-stale line\n+fresh line\n+extra line\n@@ -5,30 +6,30 @@\n",
+        );
+        let mut base = String::from("old line\nunchanged\nmiddle\nnot what the hunk expects\n");
+        for n in 5..35 {
+            base.push_str(&format!("line {n}\n"));
+            if n == 20 {
+                patch.push_str("-line 20\n+line twenty\n");
+            } else {
+                patch.push_str(&format!(" line {n}\n"));
+            }
+        }
+        let diff = CodeDiff::from_unified_diff(&patch)
+            .expect("the demo diff is well formed")
+            .with_file_path("src/lib.rs")
+            .with_base_content(&base);
+        Self {
+            diff,
+            copy_menu: CopyMenu::new(),
+            last_copy: None,
+            last_file_request: None,
+            diff_area: Rect::default(),
+        }
+    }
+
+    fn record_copy(&mut self, event: Option<CopyMenuEvent>) {
+        if let Some(CopyMenuEvent::Copied(payload)) = event {
+            self.last_copy = Some(payload.label);
+        }
     }
 }
 
 impl CoordinatorApp for CodeDiffDemo {
     fn on_event(&mut self, event: CoordinatorEvent) -> ratkit::LayoutResult<CoordinatorAction> {
         match event {
+            CoordinatorEvent::Keyboard(keyboard) if self.copy_menu.is_visible() => {
+                let event = self.copy_menu.handle_key(&keyboard.key_code);
+                self.record_copy(event);
+                Ok(CoordinatorAction::Redraw)
+            }
             CoordinatorEvent::Keyboard(keyboard) if keyboard.key_code == KeyCode::Char('q') => {
                 Ok(CoordinatorAction::Quit)
             }
+            // c copies the kind of reference chosen last time, C picks another
+            CoordinatorEvent::Keyboard(keyboard) if keyboard.key_code == KeyCode::Char('c') => {
+                let event = self.copy_menu.open(&self.diff);
+                self.record_copy(event);
+                Ok(CoordinatorAction::Redraw)
+            }
+            CoordinatorEvent::Keyboard(keyboard) if keyboard.key_code == KeyCode::Char('C') => {
+                self.copy_menu.show(&self.diff);
+                Ok(CoordinatorAction::Redraw)
+            }
+            CoordinatorEvent::Keyboard(keyboard) => {
+                // j/k move, space skips a hunk, p flips to the patched file,
+                // s switches between unified and side-by-side, o/O expand a
+                // fold marker, z folds everything again and ]h/[h jump
+                // between hunks. ]f/[f ask for another file, which a host
+                // with a file list would switch to
+                if let Some(CodeDiffEvent::FileNavigationRequested(direction)) =
+                    self.diff.handle_key(keyboard.key_code)
+                {
+                    self.last_file_request = Some(direction);
+                }
+                Ok(CoordinatorAction::Redraw)
+            }
+            CoordinatorEvent::Mouse(mouse) if !self.copy_menu.is_visible() => {
+                // Clicking a fold marker expands it
+                let event = MouseEvent {
+                    kind: mouse.kind,
+                    column: mouse.column,
+                    row: mouse.row,
+                    modifiers: mouse.modifiers,
+                };
+                if self.diff.handle_mouse(event, self.diff_area) {
+                    Ok(CoordinatorAction::Redraw)
+                } else {
+                    Ok(CoordinatorAction::Continue)
+                }
+            }
             _ => Ok(CoordinatorAction::Redraw),
         }
     }
 
     fn on_draw(&mut self, frame: &mut Frame) {
         let area = frame.area();
-        let block = Block::default().title(" Code Diff ");
+        let hunk = match self.diff.current_hunk() {
+            Some(index) => format!("hunk {}/{}", index + 1, self.diff.hunks().len()),
+            None => "preview".to_string(),
+        };
+        let title = match (&self.last_copy, self.last_file_request) {
+            (Some(label), _) => format!(" Code Diff - {hunk} - copied {label} "),
+            (None, Some(direction)) => {
+                format!(" Code Diff - {hunk} - asked for the {direction:?} file ")
+            }
+            (None, None) => format!(" Code Diff - {hunk} - c copy, C choose what to copy "),
+        };
+        let block = Block::default().title(title);
         let inner = block.inner(area);
         frame.render_widget(block, area);
+        self.diff_area = inner;
         frame.render_widget(self.diff.clone(), inner);
+        self.copy_menu.render(frame, area);
     }
 }
 
@@ -0,0 +1,101 @@
+use std::io;
+use std::process::Command;
+
+use crossterm::event::{MouseButton, MouseEventKind};
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::{Color, Style};
+use ratatui::text::Line;
+use ratatui::widgets::Paragraph;
+use ratatui::Frame;
+use ratkit::primitives::dialog::{CommandDialog, CommandDialogEvent, CommandDialogWidget};
+use ratkit::{
+    run_with_diagnostics, CoordinatorAction, CoordinatorApp, CoordinatorEvent, RunnerConfig,
+};
+
+struct CommandDialogDemo {
+    dialog: CommandDialog,
+    status: String,
+}
+
+impl CommandDialogDemo {
+    fn new() -> io::Result<Self> {
+        let mut command = Command::new("cargo");
+        command.args(["check", "--color=always"]);
+        let dialog = CommandDialog::spawn(command)?.title("cargo check");
+
+        Ok(Self {
+            dialog,
+            status: "Running cargo check…".to_string(),
+        })
+    }
+
+    fn apply(&mut self, event: Option<CommandDialogEvent>) -> CoordinatorAction {
+        match event {
+            Some(CommandDialogEvent::Completed { exit_code }) => {
+                self.status = format!("cargo check finished with {exit_code:?}");
+            }
+            Some(CommandDialogEvent::Cancelled) => {
+                self.status = "cargo check cancelled".to_string();
+            }
+            Some(CommandDialogEvent::CopyOutput(text)) => {
+                self.status = format!("Copied {} lines of output", text.lines().count());
+            }
+            Some(CommandDialogEvent::Close) => return CoordinatorAction::Quit,
+            None => {}
+        }
+        CoordinatorAction::Redraw
+    }
+}
+
+impl CoordinatorApp for CommandDialogDemo {
+    fn on_event(&mut self, event: CoordinatorEvent) -> ratkit::LayoutResult<CoordinatorAction> {
+        match event {
+            CoordinatorEvent::Tick(_) => {
+                let event = self.dialog.poll();
+                Ok(self.apply(event))
+            }
+            CoordinatorEvent::Keyboard(keyboard) => {
+                let event = self.dialog.handle_key_event(keyboard.key_code);
+                Ok(self.apply(event))
+            }
+            CoordinatorEvent::Mouse(mouse) => match mouse.kind {
+                MouseEventKind::Down(MouseButton::Left) => {
+                    let event = self.dialog.handle_click(mouse.column, mouse.row);
+                    Ok(self.apply(event))
+                }
+                MouseEventKind::ScrollUp => {
+                    self.dialog.scroll_up(3);
+                    Ok(CoordinatorAction::Redraw)
+                }
+                MouseEventKind::ScrollDown => {
+                    self.dialog.scroll_down(3);
+                    Ok(CoordinatorAction::Redraw)
+                }
+                _ => Ok(CoordinatorAction::Continue),
+            },
+            _ => Ok(CoordinatorAction::Redraw),
+        }
+    }
+
+    fn on_draw(&mut self, frame: &mut Frame) {
+        let area = frame.area();
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(area);
+
+        frame.render_widget(CommandDialogWidget::new(&mut self.dialog), chunks[0]);
+
+        let help = Paragraph::new(Line::from(format!(
+            "{}  |  ↑/↓ scroll  |  Tab switch button  |  Enter activate  |  Esc cancel/close",
+            self.status
+        )))
+        .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(help, chunks[1]);
+    }
+}
+
+fn main() -> io::Result<()> {
+    let app = CommandDialogDemo::new()?;
+    run_with_diagnostics(app, RunnerConfig::default())
+}
@@ -3,19 +3,24 @@ use std::path::PathBuf;
 
 use crossterm::event::KeyCode;
 use ratatui::{
+    layout::Constraint,
     text::Line,
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Paragraph, Wrap},
     Frame,
 };
+use ratkit::widgets::copy_menu::{CopyMenu, CopyMenuEvent};
 use ratkit::widgets::file_system_tree::{FileSystemTree, FileSystemTreeState};
 use ratkit::{
-    run_with_diagnostics, CoordinatorAction, CoordinatorApp, CoordinatorEvent, RunnerConfig,
+    run_with_diagnostics, CoordinatorAction, CoordinatorApp, CoordinatorEvent, RunnerConfig, Slot,
+    SlotLayout, SlotSpec,
 };
 
 struct FileSystemTreeDemo {
     tree: FileSystemTree<'static>,
     state: FileSystemTreeState,
     last_selection: String,
+    copy_menu: CopyMenu,
+    slots: SlotLayout,
 }
 
 impl FileSystemTreeDemo {
@@ -30,6 +35,12 @@ impl FileSystemTreeDemo {
             tree,
             state,
             last_selection: "No selection".to_string(),
+            copy_menu: CopyMenu::new(),
+            slots: SlotLayout::new(SlotSpec {
+                footer: Constraint::Length(3),
+                sidebar: Some(Constraint::Percentage(30)),
+                ..SlotSpec::default()
+            }),
         })
     }
 }
@@ -37,9 +48,20 @@ impl FileSystemTreeDemo {
 impl CoordinatorApp for FileSystemTreeDemo {
     fn on_event(&mut self, event: CoordinatorEvent) -> ratkit::LayoutResult<CoordinatorAction> {
         match event {
+            CoordinatorEvent::Keyboard(keyboard) if self.copy_menu.is_visible() => {
+                if let Some(CopyMenuEvent::Copied(payload)) =
+                    self.copy_menu.handle_key(&keyboard.key_code)
+                {
+                    self.last_selection = format!("Copied {}: {}", payload.label, payload.text);
+                }
+                Ok(CoordinatorAction::Redraw)
+            }
             CoordinatorEvent::Keyboard(keyboard) => {
                 match keyboard.key_code {
                     KeyCode::Char('q') => return Ok(CoordinatorAction::Quit),
+                    KeyCode::Char('s') if !self.tree.is_filter_mode(&self.state) => {
+                        self.slots.toggle(Slot::Sidebar);
+                    }
                     KeyCode::Down
                     | KeyCode::Up
                     | KeyCode::Char('j')
@@ -53,6 +75,23 @@ impl CoordinatorApp for FileSystemTreeDemo {
                             .tree
                             .handle_navigation_key(keyboard.key_code, &mut self.state);
                     }
+                    // c copies the kind of path chosen last time, C picks another
+                    KeyCode::Char('c') if !self.tree.is_filter_mode(&self.state) => {
+                        if let Some(CopyMenuEvent::Copied(payload)) =
+                            self.copy_menu.open(&(&self.tree, &self.state))
+                        {
+                            self.last_selection =
+                                format!("Copied {}: {}", payload.label, payload.text);
+                        }
+                        return Ok(CoordinatorAction::Redraw);
+                    }
+                    KeyCode::Char('C') if !self.tree.is_filter_mode(&self.state) => {
+                        self.copy_menu.show(&(&self.tree, &self.state));
+                        return Ok(CoordinatorAction::Redraw);
+                    }
+                    KeyCode::Char('i') if !self.tree.is_filter_mode(&self.state) => {
+                        let _ = self.tree.toggle_gitignore(&mut self.state);
+                    }
                     KeyCode::Char('/') => {
                         if !self.tree.is_filter_mode(&self.state) {
                             self.tree.enter_filter_mode(&mut self.state);
@@ -78,28 +117,30 @@ impl CoordinatorApp for FileSystemTreeDemo {
     }
 
     fn on_draw(&mut self, frame: &mut Frame) {
-        let area = frame.area();
-        let layout = ratatui::layout::Layout::default()
-            .direction(ratatui::layout::Direction::Vertical)
-            .constraints([
-                ratatui::layout::Constraint::Min(0),
-                ratatui::layout::Constraint::Length(3),
-            ])
-            .split(area);
+        let areas = self.slots.split(frame.area());
 
         let tree = self.tree.clone().block(
             Block::default()
                 .borders(Borders::ALL)
                 .title(" File System "),
         );
-        frame.render_stateful_widget(tree, layout[0], &mut self.state);
+        frame.render_stateful_widget(tree, areas.content, &mut self.state);
+
+        let details = Paragraph::new(self.last_selection.as_str())
+            .wrap(Wrap { trim: false })
+            .block(Block::default().borders(Borders::ALL).title(" Selected "));
+        frame.render_widget(details, areas.sidebar);
 
         let footer = Paragraph::new(vec![
-            Line::from("j/k or Up/Down move, Enter toggle, h/l collapse/expand, / filter"),
-            Line::from(format!("Selected: {}", self.last_selection)),
+            Line::from(
+                "j/k or Up/Down move, Enter toggle, h/l collapse/expand, / filter, i gitignore",
+            ),
+            Line::from("c copy path, C choose what to copy, s toggle sidebar, q quit"),
         ])
-        .block(Block::default().borders(Borders::ALL).title(" Status "));
-        frame.render_widget(footer, layout[1]);
+        .block(Block::default().borders(Borders::ALL).title(" Keys "));
+        frame.render_widget(footer, areas.footer);
+
+        self.copy_menu.render(frame, frame.area());
     }
 }
 
@@ -1,7 +1,14 @@
 //! Interactive markdown preview demo with TOC hover and dev bar.
 //!
+//! Press `c` to copy the selection, a link to the current section or the
+//! code block under the cursor, `C` to choose which one.
+//!
+//! Press `e` to edit the section under the cursor in `$EDITOR`. Set
+//! `RATKIT_MD_DEMO_FILE` to edit a file; the demo reloads it when the editor
+//! exits.
+//!
 //! Run with:
-//! `cargo run --example markdown_preview_markdown_preview_demo --features markdown-preview`
+//! `cargo run --example markdown_preview_markdown_preview_demo --features markdown-preview,copy-menu`
 
 use std::env;
 use std::io;
@@ -21,13 +28,17 @@ use ratatui::{
 use ratkit::prelude::{
     run, CoordinatorAction, CoordinatorApp, CoordinatorEvent, LayoutResult, RunnerConfig,
 };
+use ratkit::widgets::copy_menu::{CopyMenu, CopyMenuEvent};
+use ratkit::widgets::markdown_preview::services::editor::{edit_text_in_editor, open_in_editor};
 use ratkit::widgets::markdown_preview::{
     CacheState, CollapseState, DisplaySettings, DoubleClickState, ExpandableState, GitStatsState,
-    MarkdownEvent, MarkdownWidget, ScrollState, SelectionState, SourceState, VimState,
+    MarkdownEvent, MarkdownWidget, MarkdownWidgetMode, ScrollState, SelectionState, SourceState,
+    VimState,
 };
 
 struct MarkdownPreviewDemo {
     widget: MarkdownWidget<'static>,
+    copy_menu: CopyMenu,
     markdown_area: Rect,
     mouse_x: u16,
     mouse_y: u16,
@@ -47,12 +58,21 @@ struct MarkdownPreviewDemo {
 impl MarkdownPreviewDemo {
     fn new(
         markdown_content: String,
+        markdown_path: Option<PathBuf>,
         frontmatter_collapsed: bool,
         startup_probe: bool,
         startup_started_at: Instant,
     ) -> Self {
         let mut source = SourceState::default();
-        source.set_source_string(markdown_content.clone());
+        match &markdown_path {
+            Some(path) => {
+                if let Err(err) = source.set_source_file(path) {
+                    eprintln!("Could not watch {}: {err}", path.display());
+                    source.set_source_string(markdown_content.clone());
+                }
+            }
+            None => source.set_source_string(markdown_content.clone()),
+        }
 
         let mut scroll = ScrollState::default();
         scroll.update_total_lines(markdown_content.lines().count().max(1));
@@ -77,10 +97,12 @@ impl MarkdownPreviewDemo {
         .with_frontmatter_collapsed(frontmatter_collapsed)
         .show_toc(true)
         .show_scrollbar(true)
-        .show_statusline(true);
+        .show_statusline(true)
+        .show_document_stats(true);
 
         Self {
             widget,
+            copy_menu: CopyMenu::new(),
             markdown_area: Rect::default(),
             mouse_x: 0,
             mouse_y: 0,
@@ -114,6 +136,38 @@ impl MarkdownPreviewDemo {
         false
     }
 
+    fn edit_section(&mut self, event: MarkdownEvent) {
+        let MarkdownEvent::EditRequested {
+            path,
+            line,
+            lines,
+            text,
+        } = event
+        else {
+            return;
+        };
+
+        if let Some(path) = path {
+            match open_in_editor(&path, line) {
+                Ok(_) => match self.widget.reload_source_if_changed() {
+                    Ok(true) => self.show_toast(format!("Reloaded {}", path.display())),
+                    Ok(false) => {}
+                    Err(err) => self.show_toast(format!("Reload failed: {err}")),
+                },
+                Err(err) => self.show_toast(format!("Editor failed: {err}")),
+            }
+        } else if let Some(text) = text {
+            match edit_text_in_editor(&text) {
+                Ok(Some(edited)) => {
+                    self.widget.apply_section_edit(lines, &edited);
+                    self.show_toast("Section updated");
+                }
+                Ok(None) => self.show_toast("Edit cancelled"),
+                Err(err) => self.show_toast(format!("Editor failed: {err}")),
+            }
+        }
+    }
+
     fn update_fps(&mut self) {
         self.frames_this_second = self.frames_this_second.saturating_add(1);
         let elapsed = self.fps_window_start.elapsed();
@@ -138,6 +192,30 @@ impl CoordinatorApp for MarkdownPreviewDemo {
                     return Ok(CoordinatorAction::Continue);
                 }
 
+                if self.copy_menu.is_visible() {
+                    if let Some(CopyMenuEvent::Copied(payload)) =
+                        self.copy_menu.handle_key(&key.key_code)
+                    {
+                        self.show_toast(format!("Copied {}", payload.label.to_lowercase()));
+                    }
+                    return Ok(CoordinatorAction::Redraw);
+                }
+
+                if self.widget.get_mode() == MarkdownWidgetMode::Normal {
+                    if key.key_code == KeyCode::Char('c') {
+                        if let Some(CopyMenuEvent::Copied(payload)) =
+                            self.copy_menu.open(&self.widget)
+                        {
+                            self.show_toast(format!("Copied {}", payload.label.to_lowercase()));
+                        }
+                        return Ok(CoordinatorAction::Redraw);
+                    }
+                    if key.key_code == KeyCode::Char('C') {
+                        self.copy_menu.show(&self.widget);
+                        return Ok(CoordinatorAction::Redraw);
+                    }
+                }
+
                 if key.key_code == KeyCode::Char('q')
                     || (key.key_code == KeyCode::Char('c')
                         && key
@@ -172,6 +250,10 @@ impl CoordinatorApp for MarkdownPreviewDemo {
                 if let Some(copied_chars) = copied_chars {
                     self.show_toast(format!("Copied {} chars to clipboard", copied_chars));
                 }
+                if matches!(markdown_event, MarkdownEvent::EditRequested { .. }) {
+                    self.edit_section(markdown_event);
+                    return Ok(CoordinatorAction::Redraw);
+                }
                 if matches!(markdown_event, MarkdownEvent::None) {
                     Ok(CoordinatorAction::Continue)
                 } else {
@@ -207,6 +289,9 @@ impl CoordinatorApp for MarkdownPreviewDemo {
                 if let Some(copied_chars) = copied_chars {
                     self.show_toast(format!("Copied {} chars to clipboard", copied_chars));
                 }
+                if let MarkdownEvent::LinkClicked { url, .. } = &markdown_event {
+                    self.show_toast(format!("Link: {}", url));
+                }
                 if let Some((_line_number, _line_kind, content)) =
                     self.widget.take_last_double_click()
                 {
@@ -224,7 +309,8 @@ impl CoordinatorApp for MarkdownPreviewDemo {
                 }
             }
             CoordinatorEvent::Tick(_) => {
-                if self.clear_expired_toast() {
+                let reloaded = self.widget.reload_source_if_changed().unwrap_or(false);
+                if self.clear_expired_toast() || reloaded {
                     Ok(CoordinatorAction::Redraw)
                 } else {
                     Ok(CoordinatorAction::Continue)
@@ -250,7 +336,7 @@ impl CoordinatorApp for MarkdownPreviewDemo {
         self.markdown_area = markdown_area;
 
         let dev_text = format!(
-            " DEV | FPS {:>3} | REDRAWS {:>7} | MOUSE {:>4},{:<4} | q quit | ] TOC | wheel scroll | hover TOC | click TOC jump ",
+            " DEV | FPS {:>3} | REDRAWS {:>7} | MOUSE {:>4},{:<4} | q quit | ] TOC | c copy | e edit | wheel scroll | hover TOC | click TOC jump ",
             self.fps, self.redraws, self.mouse_x, self.mouse_y
         );
         frame.render_widget(
@@ -279,6 +365,8 @@ impl CoordinatorApp for MarkdownPreviewDemo {
             }
         }
 
+        self.copy_menu.render(frame, area);
+
         if !self.startup_reported {
             self.startup_reported = true;
             let ready_ms = self.startup_started_at.elapsed().as_secs_f64() * 1000.0;
@@ -290,25 +378,27 @@ impl CoordinatorApp for MarkdownPreviewDemo {
     }
 }
 
-fn load_demo_markdown() -> io::Result<String> {
+fn load_demo_markdown() -> io::Result<(String, Option<PathBuf>)> {
     if let Ok(path) = env::var("RATKIT_MD_DEMO_FILE") {
-        return std::fs::read_to_string(path);
+        let path = PathBuf::from(path);
+        return Ok((std::fs::read_to_string(&path)?, Some(path)));
     }
 
     let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
     path.push("skills");
     path.push("ratkit");
     path.push("SKILL.md");
-    std::fs::read_to_string(path)
+    Ok((std::fs::read_to_string(path)?, None))
 }
 
 fn main() -> io::Result<()> {
     let startup_started_at = Instant::now();
     let frontmatter_collapsed = env::args().any(|arg| arg == "--frontmatter-collapsed");
     let startup_probe = env::args().any(|arg| arg == "--startup-probe");
-    let markdown = load_demo_markdown()?;
+    let (markdown, markdown_path) = load_demo_markdown()?;
     let app = MarkdownPreviewDemo::new(
         markdown,
+        markdown_path,
         frontmatter_collapsed,
         startup_probe,
         startup_started_at,
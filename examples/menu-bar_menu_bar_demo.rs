@@ -1,41 +1,68 @@
-use crossterm::event::{KeyCode, MouseButton, MouseEventKind};
+use crossterm::event::{KeyCode, KeyEvent, KeyEventState, KeyModifiers};
 use ratatui::{
     layout::{Constraint, Direction, Layout},
     text::Line,
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
-use ratkit::primitives::menu_bar::{MenuBar, MenuItem};
+use ratkit::primitives::menu_bar::{MenuBar, MenuEvent, MenuItem};
 use ratkit::{
     run_with_diagnostics, CoordinatorAction, CoordinatorApp, CoordinatorEvent, MouseEvent,
     RunnerConfig,
 };
 
+fn ctrl(c: char) -> KeyEvent {
+    KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+}
+
 struct MenuBarDemo {
     menu: MenuBar,
     last_selected: Option<usize>,
+    last_event: String,
 }
 
 impl MenuBarDemo {
     fn new() -> Self {
         let menu = MenuBar::new(vec![
-            MenuItem::new("File", 0),
-            MenuItem::new("Edit", 1),
+            MenuItem::new("File", 0).with_children(vec![
+                MenuItem::new("New", 10).accelerator(ctrl('n'), "Ctrl+N"),
+                MenuItem::new("Open", 11).accelerator(ctrl('o'), "Ctrl+O"),
+                MenuItem::new("Open Recent", 12).with_children(vec![
+                    MenuItem::new("notes.md", 120),
+                    MenuItem::new("todo.md", 121),
+                ]),
+                MenuItem::separator(),
+                MenuItem::new("Save", 13)
+                    .accelerator(ctrl('s'), "Ctrl+S")
+                    .enabled(false),
+                MenuItem::new("Quit", 14),
+            ]),
+            MenuItem::new("Edit", 1).with_children(vec![
+                MenuItem::new("Undo", 20).accelerator(ctrl('z'), "Ctrl+Z"),
+                MenuItem::new("Redo", 21).accelerator(ctrl('y'), "Ctrl+Y"),
+                MenuItem::separator(),
+                MenuItem::new("Find", 22).accelerator(ctrl('f'), "Ctrl+F"),
+            ]),
             MenuItem::new("View", 2),
+            MenuItem::new("Help", 3).with_children(vec![MenuItem::new("About", 30)]),
         ])
         .with_selected(0);
 
         Self {
             menu,
             last_selected: Some(0),
+            last_event: "None".to_string(),
         }
     }
 
-    fn select_index(&mut self, index: usize) {
-        for (i, item) in self.menu.items.iter_mut().enumerate() {
-            item.selected = i == index;
+    fn record(&mut self, event: Option<MenuEvent>) {
+        let Some(event) = event else {
+            return;
+        };
+        if let MenuEvent::Selected { value, .. } = &event {
+            self.last_selected = Some(*value);
         }
-        self.last_selected = Some(index);
+        self.last_event = format!("{:?}", event);
     }
 }
 
@@ -43,32 +70,26 @@ impl CoordinatorApp for MenuBarDemo {
     fn on_event(&mut self, event: CoordinatorEvent) -> ratkit::LayoutResult<CoordinatorAction> {
         match event {
             CoordinatorEvent::Keyboard(keyboard) => {
-                match keyboard.key_code {
-                    KeyCode::Char('q') => return Ok(CoordinatorAction::Quit),
-                    KeyCode::Left => {
-                        let current = self.menu.selected().unwrap_or(0);
-                        let next = current.saturating_sub(1);
-                        self.select_index(next);
-                    }
-                    KeyCode::Right => {
-                        let current = self.menu.selected().unwrap_or(0);
-                        let next = (current + 1).min(self.menu.items.len().saturating_sub(1));
-                        self.select_index(next);
-                    }
-                    _ => {}
+                if keyboard.key_code == KeyCode::Char('q') {
+                    return Ok(CoordinatorAction::Quit);
                 }
+                let event = self.menu.handle_key(KeyEvent {
+                    code: keyboard.key_code,
+                    modifiers: keyboard.modifiers,
+                    kind: keyboard.kind,
+                    state: KeyEventState::NONE,
+                });
+                self.record(event);
                 Ok(CoordinatorAction::Redraw)
             }
             CoordinatorEvent::Mouse(mouse) => {
-                match mouse.kind {
-                    MouseEventKind::Moved => self.menu.update_hover(mouse.column, mouse.row),
-                    MouseEventKind::Down(MouseButton::Left) => {
-                        if let Some(index) = self.menu.handle_click(mouse.column, mouse.row) {
-                            self.last_selected = Some(index);
-                        }
-                    }
-                    _ => {}
-                }
+                let event = self.menu.handle_mouse_event(crossterm::event::MouseEvent {
+                    kind: mouse.kind,
+                    column: mouse.column,
+                    row: mouse.row,
+                    modifiers: mouse.modifiers,
+                });
+                self.record(event);
                 Ok(CoordinatorAction::Redraw)
             }
             _ => Ok(CoordinatorAction::Redraw),
@@ -85,11 +106,14 @@ impl CoordinatorApp for MenuBarDemo {
         self.menu.render(frame, chunks[0]);
 
         let info = Paragraph::new(Line::from(format!(
-            "Selected: {:?}  |  Use mouse or arrows  |  q to quit",
-            self.last_selected
+            "Selected: {:?}  |  Last event: {}  |  Arrows, Enter, Esc, Ctrl+key or mouse  |  q to quit",
+            self.last_selected, self.last_event
         )))
         .block(Block::default().borders(Borders::ALL).title(" Menu Bar "));
         frame.render_widget(info, chunks[1]);
+
+        // Dropdowns go last so they draw over the rest of the frame.
+        self.menu.render_dropdowns(frame);
     }
 }
 
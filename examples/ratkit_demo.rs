@@ -1,8 +1,8 @@
 use std::io;
 
-use crossterm::event::KeyCode;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventState, MouseEvent};
 use ratatui::{
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     text::Line,
     widgets::Paragraph,
     Frame,
@@ -10,16 +10,61 @@ use ratatui::{
 use ratkit::prelude::{
     run_with_diagnostics, CoordinatorAction, CoordinatorApp, CoordinatorEvent, RunnerConfig,
 };
+use ratkit::primitives::menu_bar::{MenuBar, MenuEvent, MenuItem};
+use ratkit::widgets::chrome_bar::{ChromeBar, StatusItem};
+use ratkit::widgets::hotkey_footer::HotkeyItem;
 use ratkit::widgets::{Button, Pane};
 
 struct RatkitDemo {
     button: Button,
+    chrome: ChromeBar,
+    last_event: String,
 }
 
 impl RatkitDemo {
     fn new() -> Self {
+        let menu = MenuBar::new(vec![
+            MenuItem::new("File", 0).with_children(vec![
+                MenuItem::new("Open", 10),
+                MenuItem::separator(),
+                MenuItem::new("Quit", 11),
+            ]),
+            MenuItem::new("View", 1).with_children(vec![
+                MenuItem::new("Zoom In", 20),
+                MenuItem::new("Zoom Out", 21),
+            ]),
+            MenuItem::new("Help", 2).with_children(vec![MenuItem::new("About", 30)]),
+        ])
+        .with_selected(0);
+
+        let chrome = ChromeBar::new(menu)
+            .mode(
+                StatusItem::new("DEMO", 0).style(
+                    Style::default()
+                        .fg(Color::Black)
+                        .bg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            )
+            .status_item(StatusItem::new("ratkit", 5))
+            .status_item(StatusItem::new("utf-8", 1))
+            .status_item(StatusItem::new("resize me", 9))
+            .hotkeys(vec![
+                HotkeyItem::new("q", "quit"),
+                HotkeyItem::new("Enter", "menu"),
+                HotkeyItem::new("←/→", "switch menu"),
+            ]);
+
         Self {
             button: Button::new("Run"),
+            chrome,
+            last_event: "None".to_string(),
+        }
+    }
+
+    fn record(&mut self, event: Option<MenuEvent>) {
+        if let Some(event) = event {
+            self.last_event = format!("{:?}", event);
         }
     }
 }
@@ -27,29 +72,62 @@ impl RatkitDemo {
 impl CoordinatorApp for RatkitDemo {
     fn on_event(&mut self, event: CoordinatorEvent) -> ratkit::LayoutResult<CoordinatorAction> {
         match event {
-            CoordinatorEvent::Keyboard(keyboard) if keyboard.key_code == KeyCode::Char('q') => {
+            CoordinatorEvent::Keyboard(keyboard)
+                if keyboard.key_code == KeyCode::Char('q') && !self.chrome.menu.is_open() =>
+            {
                 Ok(CoordinatorAction::Quit)
             }
+            CoordinatorEvent::Keyboard(keyboard) => {
+                let event = self.chrome.handle_key(KeyEvent {
+                    code: keyboard.key_code,
+                    modifiers: keyboard.modifiers,
+                    kind: keyboard.kind,
+                    state: KeyEventState::NONE,
+                });
+                self.record(event);
+                Ok(CoordinatorAction::Redraw)
+            }
+            CoordinatorEvent::Mouse(mouse) => {
+                let event = self.chrome.handle_mouse_event(MouseEvent {
+                    kind: mouse.kind,
+                    column: mouse.column,
+                    row: mouse.row,
+                    modifiers: mouse.modifiers,
+                });
+                self.record(event);
+                Ok(CoordinatorAction::Redraw)
+            }
             _ => Ok(CoordinatorAction::Redraw),
         }
     }
 
     fn on_draw(&mut self, frame: &mut Frame) {
-        let area = frame.area();
+        // The chrome bar collapses to one row on short terminals; resize
+        // the window to watch it switch layouts.
+        let area = self.chrome.render(frame, frame.area());
         let pane = Pane::new("ratkit")
             .with_icon("◎")
             .with_uniform_padding(1)
             .border_style(Style::default().fg(Color::Cyan));
 
         let button_line = self.button.render_with_title(area, "ratkit demo");
+        let layout = if self.chrome.layout(frame.area()).compact {
+            "single row"
+        } else {
+            "multi-row"
+        };
         let content = vec![
             button_line,
             Line::from("Press q to quit"),
+            Line::from(format!("Chrome layout: {}", layout)),
+            Line::from(format!("Last menu event: {}", self.last_event)),
             Line::from("(Runner-first + master widgets namespace demo)"),
         ];
 
         let inner = pane.render_block(frame, area).0;
         frame.render_widget(Paragraph::new(content), inner);
+
+        self.chrome.render_overlays(frame);
     }
 }
 
@@ -0,0 +1,60 @@
+//! The smallest scaffolded app: a header, a counter and a footer, built
+//! with the core runtime only.
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use ratkit::scaffold::{view_fn, AppBuilder, AppContext, SlotView};
+use ratkit::{CoordinatorAction, CoordinatorEvent};
+
+struct Counter {
+    count: i64,
+}
+
+impl SlotView for Counter {
+    fn render(&mut self, frame: &mut Frame, area: Rect, _ctx: &AppContext) {
+        let body = Paragraph::new(format!("Count: {}", self.count))
+            .block(Block::default().borders(Borders::ALL).title(" Counter "));
+        frame.render_widget(body, area);
+    }
+
+    fn handle_event(
+        &mut self,
+        event: &CoordinatorEvent,
+        _ctx: &mut AppContext,
+    ) -> CoordinatorAction {
+        match event {
+            CoordinatorEvent::Keyboard(key) if key.is_key_down() && key.is_char('+') => {
+                self.count += 1;
+                CoordinatorAction::Redraw
+            }
+            CoordinatorEvent::Keyboard(key) if key.is_key_down() && key.is_char('-') => {
+                self.count -= 1;
+                CoordinatorAction::Redraw
+            }
+            _ => CoordinatorAction::Continue,
+        }
+    }
+}
+
+fn main() -> std::io::Result<()> {
+    AppBuilder::new("scaffold-plain")
+        .slot_header(view_fn(
+            |frame: &mut Frame, area: Rect, ctx: &AppContext| {
+                let style = Style::default().fg(Color::Black).bg(Color::Cyan);
+                frame.render_widget(
+                    Paragraph::new(format!(" {}", ctx.name())).style(style),
+                    area,
+                );
+            },
+        ))
+        .slot_footer(view_fn(|frame: &mut Frame, area: Rect, _: &AppContext| {
+            let style = Style::default().fg(Color::DarkGray);
+            frame.render_widget(Paragraph::new(" +/- count  q quit").style(style), area);
+        }))
+        .content(Counter { count: 0 })
+        .run()
+}
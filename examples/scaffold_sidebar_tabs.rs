@@ -0,0 +1,149 @@
+//! A scaffolded app with a menu bar, a sidebar listing tabs, tabbed
+//! content and a hotkey footer. The chosen theme is restored on the next
+//! start.
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use crossterm::event::{KeyCode, MouseButton, MouseEventKind};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Tabs},
+    Frame,
+};
+use ratkit::primitives::menu_bar::MenuItem;
+use ratkit::scaffold::{AppBuilder, AppContext, HotkeyFooterElement, MenuBarElement, SlotView};
+use ratkit::widgets::hotkey_footer::HotkeyItem;
+use ratkit::{CoordinatorAction, CoordinatorEvent, Slot};
+
+const TABS: [&str; 3] = ["Overview", "Logs", "Settings"];
+const THEMES: [&str; 3] = ["gruvbox", "nord", "dracula"];
+
+const QUIT: usize = 1;
+const TOGGLE_SIDEBAR: usize = 2;
+const THEME: usize = 10;
+
+/// Lists the tabs; clicking one opens it.
+struct Sidebar {
+    tab: Rc<Cell<usize>>,
+}
+
+impl SlotView for Sidebar {
+    fn render(&mut self, frame: &mut Frame, area: Rect, _ctx: &AppContext) {
+        let items: Vec<ListItem> = TABS.iter().map(|tab| ListItem::new(*tab)).collect();
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::RIGHT).title(" Tabs "))
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+        let mut state = ListState::default().with_selected(Some(self.tab.get()));
+        frame.render_stateful_widget(list, area, &mut state);
+    }
+
+    fn handle_event(
+        &mut self,
+        event: &CoordinatorEvent,
+        ctx: &mut AppContext,
+    ) -> CoordinatorAction {
+        let CoordinatorEvent::Mouse(mouse) = event else {
+            return CoordinatorAction::Continue;
+        };
+        if mouse.kind != MouseEventKind::Down(MouseButton::Left) {
+            return CoordinatorAction::Continue;
+        }
+        // The list starts below the title row
+        let row = mouse.row.saturating_sub(ctx.area(Slot::Sidebar).y + 1) as usize;
+        if row < TABS.len() {
+            self.tab.set(row);
+        }
+        CoordinatorAction::Redraw
+    }
+}
+
+/// The open tab, switched with Tab and Shift+Tab.
+struct Content {
+    tab: Rc<Cell<usize>>,
+}
+
+impl SlotView for Content {
+    fn render(&mut self, frame: &mut Frame, area: Rect, ctx: &AppContext) {
+        let [tabs_area, body_area] =
+            Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(area);
+        let tabs = Tabs::new(TABS).select(self.tab.get()).highlight_style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        );
+        frame.render_widget(tabs, tabs_area);
+
+        let theme = ctx.theme().unwrap_or("none");
+        let body = Paragraph::new(vec![
+            Line::from(format!("This is the {} tab.", TABS[self.tab.get()])),
+            Line::from(format!("Theme: {theme} (saved on exit)")),
+        ])
+        .block(Block::default().borders(Borders::TOP));
+        frame.render_widget(body, body_area);
+    }
+
+    fn handle_event(
+        &mut self,
+        event: &CoordinatorEvent,
+        _ctx: &mut AppContext,
+    ) -> CoordinatorAction {
+        let CoordinatorEvent::Keyboard(key) = event else {
+            return CoordinatorAction::Continue;
+        };
+        if !key.is_key_down() {
+            return CoordinatorAction::Continue;
+        }
+        let tab = self.tab.get();
+        match key.key_code {
+            KeyCode::Tab => self.tab.set((tab + 1) % TABS.len()),
+            KeyCode::BackTab => self.tab.set((tab + TABS.len() - 1) % TABS.len()),
+            _ => return CoordinatorAction::Continue,
+        }
+        CoordinatorAction::Redraw
+    }
+}
+
+fn main() -> std::io::Result<()> {
+    let tab = Rc::new(Cell::new(0));
+    let themes = THEMES
+        .iter()
+        .enumerate()
+        .map(|(index, name)| MenuItem::new(*name, THEME + index))
+        .collect();
+    let menu = MenuBarElement::new(vec![
+        MenuItem::new("File", 0).with_children(vec![MenuItem::new("Quit", QUIT)]),
+        MenuItem::new("View", 0)
+            .with_children(vec![MenuItem::new("Toggle sidebar", TOGGLE_SIDEBAR)]),
+        MenuItem::new("Theme", 0).with_children(themes),
+    ])
+    .on_select(|value, ctx| match value {
+        QUIT => CoordinatorAction::Quit,
+        TOGGLE_SIDEBAR => {
+            ctx.toggle_slot(Slot::Sidebar);
+            CoordinatorAction::Redraw
+        }
+        theme => {
+            if let Some(name) = THEMES.get(theme - THEME) {
+                ctx.set_theme(*name);
+            }
+            CoordinatorAction::Redraw
+        }
+    });
+
+    AppBuilder::new("scaffold-sidebar-tabs")
+        .with_theme_persistence()
+        .default_theme("gruvbox")
+        .slot_header(menu)
+        .slot_sidebar(Sidebar { tab: tab.clone() })
+        .slot_footer(HotkeyFooterElement::new(vec![
+            HotkeyItem::new("q", "quit").pinned(true),
+            HotkeyItem::new("Tab", "next tab"),
+            HotkeyItem::new("Enter", "menu"),
+            HotkeyItem::new("←/→", "switch menu"),
+        ]))
+        .content(Content { tab })
+        .run()
+}
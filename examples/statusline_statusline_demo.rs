@@ -1,4 +1,5 @@
 use std::io;
+use std::time::{Duration, Instant};
 
 use ratatui::{
     layout::{Constraint, Direction, Layout},
@@ -6,7 +7,9 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
-use ratkit::primitives::statusline::{OperationalMode, StyledStatusLine};
+use ratkit::primitives::statusline::{
+    MessageLevel, OperationalMode, StatusLineState, StyledStatusLine,
+};
 use ratkit::{
     run_with_diagnostics, CoordinatorAction, CoordinatorApp, CoordinatorEvent, RunnerConfig,
 };
@@ -14,6 +17,7 @@ use ratkit::{
 struct StatusLineDemo {
     renders: usize,
     events: usize,
+    center: StatusLineState,
 }
 
 impl CoordinatorApp for StatusLineDemo {
@@ -25,6 +29,25 @@ impl CoordinatorApp for StatusLineDemo {
             {
                 Ok(CoordinatorAction::Quit)
             }
+            CoordinatorEvent::Keyboard(keyboard) if keyboard.is_key_down() => {
+                match keyboard.key_code {
+                    crossterm::event::KeyCode::Char('s') => {
+                        self.center.set_message("Saved", Duration::from_secs(2))
+                    }
+                    crossterm::event::KeyCode::Char('e') => self.center.set_message_with_level(
+                        "Write failed",
+                        Duration::from_secs(3),
+                        MessageLevel::Error,
+                    ),
+                    _ => {}
+                }
+                self.center.tick(Instant::now());
+                Ok(CoordinatorAction::Redraw)
+            }
+            CoordinatorEvent::Tick(_) => {
+                self.center.tick(Instant::now());
+                Ok(CoordinatorAction::Redraw)
+            }
             _ => Ok(CoordinatorAction::Redraw),
         }
     }
@@ -37,14 +60,17 @@ impl CoordinatorApp for StatusLineDemo {
             .constraints([Constraint::Min(0), Constraint::Length(1)])
             .split(area);
 
-        let body = Paragraph::new(Line::from("Statusline renders at the bottom."))
-            .block(Block::default().borders(Borders::ALL).title(" Statusline "));
+        let body = Paragraph::new(vec![
+            Line::from("Statusline renders at the bottom."),
+            Line::from("s: transient message, e: error message, q: quit"),
+        ])
+        .block(Block::default().borders(Borders::ALL).title(" Statusline "));
         frame.render_widget(body, chunks[0]);
 
         let status = StyledStatusLine::new()
             .title(" RATKIT ")
             .mode(OperationalMode::Operational)
-            .center_text("Runner loop active")
+            .center_text(self.center.text())
             .render_metrics(self.renders, 120)
             .event_metrics(self.events, 60)
             .message_count(self.events as u32)
@@ -58,6 +84,7 @@ fn main() -> io::Result<()> {
     let app = StatusLineDemo {
         renders: 0,
         events: 0,
+        center: StatusLineState::new("Runner loop active"),
     };
     run_with_diagnostics(app, RunnerConfig::default())
 }
@@ -0,0 +1,49 @@
+//! Measures the cost of drawing an embedded terminal every frame, with and
+//! without [`ScreenRenderer`]'s row cache.
+//!
+//! Two workloads are simulated without a PTY: an idle shell that printed a
+//! prompt once, and `yes` printing a line every frame.
+//!
+//! Run with `cargo run --release --example termtui_render_bench --features termtui`.
+
+use std::time::{Duration, Instant};
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratkit::primitives::termtui::{render_screen, Parser, ScreenRenderer};
+
+const FRAMES: u32 = 2_000;
+const AREA: Rect = Rect::new(0, 0, 160, 48);
+
+fn bench(output_per_frame: &[u8], cached: bool) -> Duration {
+    let mut parser = Parser::new(AREA.height, AREA.width, 10_000);
+    let mut events = Vec::new();
+    parser
+        .screen
+        .process(b"\x1b[1;32muser@host\x1b[0m:~/project$ ", &mut events);
+
+    let mut renderer = ScreenRenderer::new();
+    let mut buf = Buffer::empty(AREA);
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        parser.screen.process(output_per_frame, &mut events);
+        if cached {
+            renderer.render(parser.screen(), AREA, &mut buf);
+        } else {
+            render_screen(parser.screen(), AREA, &mut buf);
+        }
+    }
+    start.elapsed() / FRAMES
+}
+
+fn main() {
+    println!("{}x{} terminal, {} frames", AREA.width, AREA.height, FRAMES);
+    for (name, output) in [("idle shell", &b""[..]), ("yes", &b"y\r\n"[..])] {
+        let full = bench(output, false);
+        let cached = bench(output, true);
+        println!(
+            "{name:>10}: render_screen {full:>10.2?}/frame, ScreenRenderer {cached:>10.2?}/frame ({:.1}x)",
+            full.as_secs_f64() / cached.as_secs_f64().max(f64::EPSILON)
+        );
+    }
+}
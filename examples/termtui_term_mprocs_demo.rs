@@ -1,26 +1,33 @@
 use std::io::{Read, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
 
 use anyhow::Result;
 use crossterm::{cursor::SetCursorStyle, event::KeyCode, event::KeyModifiers, execute};
-use portable_pty::{native_pty_system, Child, CommandBuilder, MasterPty, PtySize};
+use portable_pty::{native_pty_system, Child, CommandBuilder, ExitStatus, MasterPty, PtySize};
 use ratatui::layout::Rect;
 use ratatui::style::{Color, Style};
 use ratatui::widgets::{Block, Borders};
 use ratatui::Frame;
-use ratkit::primitives::termtui::{render_screen, CursorStyle, Parser, VtEvent};
+use ratkit::primitives::termtui::{CursorStyle, Parser, ScreenRenderer, VtEvent};
 use ratkit::{
     run_with_diagnostics, CoordinatorAction, CoordinatorApp, CoordinatorEvent, KeyboardEvent,
     RedrawSignal, ResizeEvent, RunnerConfig,
 };
 
 struct TermMprocsTerminal {
+    command: String,
+    args: Vec<String>,
     parser: Arc<Mutex<Parser>>,
     writer: Arc<Mutex<Box<dyn Write + Send>>>,
     master: Arc<Mutex<Box<dyn MasterPty + Send>>>,
     redraw_signal: RedrawSignal,
-    _child: Box<dyn Child + Send + Sync>,
+    renderer: ScreenRenderer,
+    child: Box<dyn Child + Send + Sync>,
+    /// Set by the read thread once the program's output ends.
+    output_closed: Arc<AtomicBool>,
+    exit_status: Option<ExitStatus>,
 }
 
 impl TermMprocsTerminal {
@@ -57,6 +64,8 @@ impl TermMprocsTerminal {
         let redraw_signal = RedrawSignal::new();
         redraw_signal.request_redraw();
 
+        let output_closed = Arc::new(AtomicBool::new(false));
+        let output_closed_clone = Arc::clone(&output_closed);
         let parser_clone = Arc::clone(&parser);
         let writer_clone = Arc::clone(&writer);
         let redraw_signal_clone = redraw_signal.clone();
@@ -85,17 +94,57 @@ impl TermMprocsTerminal {
                     Err(_) => break,
                 }
             }
+            output_closed_clone.store(true, Ordering::SeqCst);
+            redraw_signal_clone.request_redraw();
         });
 
         Ok(Self {
+            command: command.to_string(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
             parser,
             writer,
             master: Arc::new(Mutex::new(master)),
             redraw_signal,
-            _child: child,
+            renderer: ScreenRenderer::new(),
+            child,
+            output_closed,
+            exit_status: None,
         })
     }
 
+    /// Whether the program is still running, judged by its output not
+    /// having ended.
+    fn is_running(&self) -> bool {
+        !self.output_closed.load(Ordering::SeqCst)
+    }
+
+    /// How the program exited, once it has.
+    fn exit_status(&mut self) -> Option<ExitStatus> {
+        if self.exit_status.is_none() {
+            self.exit_status = self.child.try_wait().ok().flatten();
+        }
+        self.exit_status.clone()
+    }
+
+    /// Runs the same command again in a fresh PTY and screen, stopping
+    /// the old program if it is still running.
+    fn restart(&mut self) -> Result<()> {
+        if self.exit_status().is_none() {
+            let _ = self.child.kill();
+            let _ = self.child.wait();
+        }
+        let (rows, cols) = match self.parser.lock() {
+            Ok(parser) => {
+                let size = parser.screen().size();
+                (size.height, size.width)
+            }
+            Err(_) => (24, 80),
+        };
+        let args: Vec<&str> = self.args.iter().map(String::as_str).collect();
+        *self = Self::spawn_with_command(&self.command, &args, rows, cols)?;
+        Ok(())
+    }
+
     fn resize(&mut self, rows: u16, cols: u16) {
         if rows == 0 || cols == 0 {
             return;
@@ -114,9 +163,10 @@ impl TermMprocsTerminal {
         self.redraw_signal.request_redraw();
     }
 
-    fn render(&self, frame: &mut Frame, area: Rect) {
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
         if let Ok(parser) = self.parser.lock() {
-            render_screen(parser.screen(), area, frame.buffer_mut());
+            self.renderer
+                .render(parser.screen(), area, frame.buffer_mut());
         }
     }
 
@@ -127,8 +177,13 @@ impl TermMprocsTerminal {
         }
     }
 
+    /// Whether the program printed something, or moved the cursor, that
+    /// hasn't been drawn yet.
     fn take_needs_redraw(&self) -> bool {
         self.redraw_signal.take_redraw_request()
+            && self.parser.lock().map_or(true, |parser| {
+                self.renderer.has_changes_since_last_render(parser.screen())
+            })
     }
 }
 
@@ -136,6 +191,7 @@ struct TermMprocsDemo {
     terminal: TermMprocsTerminal,
     last_area: Rect,
     terminal_focused: bool,
+    was_running: bool,
 }
 
 impl TermMprocsDemo {
@@ -144,6 +200,7 @@ impl TermMprocsDemo {
             terminal: TermMprocsTerminal::spawn_shell(24, 80)?,
             last_area: Rect::default(),
             terminal_focused: true,
+            was_running: true,
         })
     }
 }
@@ -169,6 +226,18 @@ impl CoordinatorApp for TermMprocsDemo {
                 if keyboard.is_char('q') && keyboard.modifiers.contains(KeyModifiers::CONTROL) {
                     return Ok(CoordinatorAction::Quit);
                 }
+                if !self.terminal.is_running() {
+                    if keyboard.is_char('r') && keyboard.modifiers.is_empty() {
+                        // Keep the old screen if the command cannot start
+                        let _ = self.terminal.restart();
+                        self.terminal_focused = true;
+                        return Ok(CoordinatorAction::Redraw);
+                    }
+                    if keyboard.is_char('q') && keyboard.modifiers.is_empty() {
+                        return Ok(CoordinatorAction::Quit);
+                    }
+                    return Ok(CoordinatorAction::Continue);
+                }
                 if !self.terminal_focused {
                     if keyboard.is_char('q') && keyboard.modifiers.is_empty() {
                         return Ok(CoordinatorAction::Quit);
@@ -188,6 +257,11 @@ impl CoordinatorApp for TermMprocsDemo {
                 Ok(CoordinatorAction::Continue)
             }
             CoordinatorEvent::Tick(_) => {
+                let running = self.terminal.is_running();
+                if running != self.was_running {
+                    self.was_running = running;
+                    return Ok(CoordinatorAction::Redraw);
+                }
                 if self.terminal.take_needs_redraw() {
                     Ok(CoordinatorAction::Redraw)
                 } else {
@@ -200,12 +274,21 @@ impl CoordinatorApp for TermMprocsDemo {
 
     fn on_draw(&mut self, frame: &mut Frame) {
         let area = frame.area();
-        let title = if self.terminal_focused {
-            " termtui demo (terminal focused) "
+        let running = self.terminal.is_running();
+        let title = if !running {
+            let status = match self.terminal.exit_status() {
+                Some(status) => format!("exited (code {})", status.exit_code()),
+                None => "exited".to_string(),
+            };
+            format!(" termtui demo ({status}, r restarts, q quits) ")
+        } else if self.terminal_focused {
+            " termtui demo (terminal focused) ".to_string()
         } else {
-            " termtui demo (wrapper focused) "
+            " termtui demo (wrapper focused) ".to_string()
         };
-        let border_style = if self.terminal_focused {
+        let border_style = if !running {
+            Style::default().fg(Color::Red)
+        } else if self.terminal_focused {
             Style::default().fg(Color::Cyan)
         } else {
             Style::default().fg(Color::DarkGray)
@@ -224,7 +307,7 @@ impl CoordinatorApp for TermMprocsDemo {
 
         self.terminal.render(frame, inner);
 
-        if self.terminal_focused {
+        if self.terminal_focused && running {
             if let Ok(parser) = self.terminal.parser.lock() {
                 let screen = parser.screen();
                 if !screen.hide_cursor() {
@@ -1,8 +1,12 @@
 use ratatui::{
+    style::Style,
     text::Line,
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
+use ratkit::widgets::markdown_preview::services::theme::{
+    loader, AppTheme, ThemeVariant, VariantSource, VariantWatcher,
+};
 use ratkit::widgets::theme_picker::{ThemePicker, ThemePickerEvent};
 use ratkit::{
     run_with_diagnostics, CoordinatorAction, CoordinatorApp, CoordinatorEvent, RunnerConfig,
@@ -10,16 +14,39 @@ use ratkit::{
 
 struct ThemePickerDemo {
     picker: ThemePicker,
+    theme_name: String,
+    variant: VariantWatcher,
+    theme: AppTheme,
     last_event: String,
 }
 
 impl ThemePickerDemo {
     fn new() -> Self {
-        let mut picker = ThemePicker::new();
+        let mut picker = ThemePicker::new().follow_system(true);
         picker.show();
-        Self {
+        let variant = VariantWatcher::new(VariantSource::Fixed(ThemeVariant::Dark));
+        let mut demo = Self {
             picker,
+            theme_name: "gruvbox".to_string(),
+            variant,
+            theme: AppTheme::default(),
             last_event: "Previewing themes".to_string(),
+        };
+        demo.reload_theme();
+        demo
+    }
+
+    fn reload_theme(&mut self) {
+        if let Ok(theme) = loader::load_builtin_theme(&self.theme_name, self.variant.variant()) {
+            self.theme = theme;
+        }
+    }
+
+    fn source_label(&self) -> &'static str {
+        match self.variant.source() {
+            VariantSource::Fixed(_) => "fixed",
+            VariantSource::FollowOs => "following system",
+            VariantSource::Schedule { .. } => "scheduled",
         }
     }
 }
@@ -43,17 +70,44 @@ impl CoordinatorApp for ThemePickerDemo {
                     return Ok(CoordinatorAction::Redraw);
                 }
 
+                if !self.picker.is_visible() && keyboard.key_code == KeyCode::Char('v') {
+                    let fixed = match self.variant.variant() {
+                        ThemeVariant::Dark => ThemeVariant::Light,
+                        ThemeVariant::Light => ThemeVariant::Dark,
+                    };
+                    self.variant.set_source(VariantSource::Fixed(fixed));
+                    self.reload_theme();
+                    return Ok(CoordinatorAction::Redraw);
+                }
+
                 if let Some(event) = self.picker.handle_key(&keyboard.key_code) {
                     self.last_event = match event {
-                        ThemePickerEvent::Selected(name) => format!("Selected: {}", name),
+                        ThemePickerEvent::Selected(name) => {
+                            self.theme_name = name.clone();
+                            self.reload_theme();
+                            format!("Selected: {}", name)
+                        }
                         ThemePickerEvent::Cancelled => "Cancelled".to_string(),
                         ThemePickerEvent::PreviewChanged(name) => {
                             format!("Preview: {}", name)
                         }
+                        ThemePickerEvent::FollowSystem => {
+                            self.variant.set_source(VariantSource::FollowOs);
+                            self.reload_theme();
+                            "Following system appearance".to_string()
+                        }
                     };
                 }
                 Ok(CoordinatorAction::Redraw)
             }
+            CoordinatorEvent::Tick(_) => {
+                if let Some(variant) = self.variant.poll() {
+                    self.reload_theme();
+                    self.last_event = format!("System switched to {}", variant);
+                    return Ok(CoordinatorAction::Redraw);
+                }
+                Ok(CoordinatorAction::Continue)
+            }
             _ => Ok(CoordinatorAction::Continue),
         }
     }
@@ -62,12 +116,25 @@ impl CoordinatorApp for ThemePickerDemo {
         let area = frame.area();
         let body = Paragraph::new(vec![
             Line::from("t: toggle picker"),
+            Line::from("v: fix the opposite variant"),
             Line::from("q: quit"),
+            Line::from(format!(
+                "Theme: {} ({}, {})",
+                self.theme_name,
+                self.variant.variant(),
+                self.source_label()
+            )),
             Line::from(self.last_event.clone()),
         ])
+        .style(
+            Style::default()
+                .fg(self.theme.text)
+                .bg(self.theme.background),
+        )
         .block(
             Block::default()
                 .borders(Borders::ALL)
+                .border_style(Style::default().fg(self.theme.border_active))
                 .title(" Theme Picker "),
         );
         frame.render_widget(body, area);
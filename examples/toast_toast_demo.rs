@@ -6,7 +6,7 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
-use ratkit::primitives::toast::{render_toasts, ToastManager};
+use ratkit::primitives::toast::{render_toasts, Toast, ToastLevel, ToastManager};
 use ratkit::{
     run_with_diagnostics, CoordinatorAction, CoordinatorApp, CoordinatorEvent, RunnerConfig,
 };
@@ -35,6 +35,21 @@ impl CoordinatorApp for ToastDemo {
                     KeyCode::Char('q') => return Ok(CoordinatorAction::Quit),
                     KeyCode::Char('t') => self.toasts.info("Background task finished"),
                     KeyCode::Char('e') => self.toasts.error("Something went wrong"),
+                    KeyCode::Char('w') => {
+                        for row in 1..=10 {
+                            self.toasts.add(Toast::grouped(
+                                "import",
+                                format!("Row {row}: missing column"),
+                                ToastLevel::Warning,
+                                None,
+                            ));
+                        }
+                    }
+                    KeyCode::Char('x') => {
+                        self.toasts.toggle_latest_group();
+                    }
+                    KeyCode::Up => self.toasts.scroll_expanded(-1),
+                    KeyCode::Down => self.toasts.scroll_expanded(1),
                     KeyCode::Char('c') => self.toasts.clear(),
                     _ => {}
                 }
@@ -49,6 +64,8 @@ impl CoordinatorApp for ToastDemo {
         let body = Paragraph::new(vec![
             Line::from("t: info toast"),
             Line::from("e: error toast"),
+            Line::from("w: batch of grouped warnings"),
+            Line::from("x: expand/collapse the latest group (Up/Down to scroll)"),
             Line::from("c: clear"),
             Line::from("q: quit"),
         ])
@@ -10,6 +10,8 @@ use crate::focus::{FocusManager, FocusRequest};
 use crate::layout::LayoutManager;
 use crate::mouse_router::{MouseRouter, MouseRouterConfig};
 use crate::registry::Element;
+use crate::shutdown::ShutdownHook;
+use crate::slots::{Slot, SlotSpec};
 use crate::types::{DiagnosticInfo, DirtyFlags, ElementId, ElementMetadata, Region, Visibility};
 
 #[derive(Debug, Clone, Copy)]
@@ -33,6 +35,14 @@ pub trait CoordinatorApp {
     fn on_event(&mut self, event: CoordinatorEvent) -> LayoutResult<CoordinatorAction>;
     fn on_draw(&mut self, frame: &mut ratatui::Frame);
     fn on_layout_changed(&mut self) {}
+
+    /// Hooks to poll after the app quits, in the order they should run.
+    ///
+    /// Called on every shutdown tick; return the same hooks in the same
+    /// order each time.
+    fn shutdown_hooks(&mut self) -> Vec<&mut dyn ShutdownHook> {
+        Vec::new()
+    }
 }
 
 #[derive(Clone)]
@@ -45,6 +55,8 @@ pub enum CoordinatorEvent {
     Register(ElementMetadata, Arc<dyn Element>),
     Unregister(ElementId),
     SetVisibility(ElementId, Visibility),
+    /// Show or hide a slot; see [`LayoutCoordinator::set_slot_visible`].
+    SetSlotVisibility(Slot, bool),
     RequestDiagnosticInfo,
 }
 
@@ -121,6 +133,79 @@ impl<A: CoordinatorApp> LayoutCoordinator<A> {
         &mut self.mouse
     }
 
+    /// Lay the screen out as named slots; elements registered with
+    /// [`ElementMetadata::in_slot`] fill their slot's rect.
+    pub fn define_slots(&mut self, spec: SlotSpec) -> LayoutResult<()> {
+        self.layout.define_slots(spec);
+        self.relayout()
+    }
+
+    /// Show or hide `slot`. The content slot takes the space of hidden
+    /// slots; elements in a hidden slot lose focus and get no mouse or
+    /// keyboard input.
+    ///
+    /// Returns whether that changed anything.
+    pub fn set_slot_visible(&mut self, slot: Slot, visible: bool) -> LayoutResult<bool> {
+        if !self.layout.set_slot_visible(slot, visible) {
+            return Ok(false);
+        }
+        if !visible {
+            if let Some(focused) = self.focus.focused() {
+                let in_slot = self
+                    .layout
+                    .registry()
+                    .get_metadata(focused)
+                    .is_ok_and(|metadata| metadata.slot == Some(slot));
+                if in_slot {
+                    // Move focus to a visible element, or clear it:
+                    // releasing could hand it back to an element in the
+                    // hidden slot
+                    let next = self
+                        .layout
+                        .registry()
+                        .focusable_elements()
+                        .into_iter()
+                        .find(|(_, metadata)| {
+                            metadata
+                                .slot
+                                .map_or(true, |slot| self.layout.is_slot_visible(slot))
+                        });
+                    match next {
+                        Some((id, _)) => {
+                            self.focus.handle_request(FocusRequest::To(id))?;
+                        }
+                        None => self.focus.clear_focus()?,
+                    }
+                }
+            }
+        }
+        self.relayout()?;
+        Ok(true)
+    }
+
+    /// Show `slot` if hidden, or hide it. Returns whether it is now shown.
+    pub fn toggle_slot(&mut self, slot: Slot) -> LayoutResult<bool> {
+        let visible = !self.layout.is_slot_visible(slot);
+        self.set_slot_visible(slot, visible)?;
+        Ok(self.layout.is_slot_visible(slot))
+    }
+
+    /// The rect of `slot` as of the last layout pass.
+    pub fn slot_area(&self, slot: Slot) -> ratatui::layout::Rect {
+        self.layout.slot_area(slot)
+    }
+
+    /// Recompute element rects now, once the terminal size is known.
+    fn relayout(&mut self) -> LayoutResult<()> {
+        let (width, height) = self.layout.terminal_size();
+        if width > 0 && height > 0 {
+            self.layout.recompute()?;
+            self.app.on_layout_changed();
+        }
+        self.dirty.set_layout_dirty();
+        Ok(())
+    }
+
     pub fn is_dirty(&self) -> bool {
         self.dirty.needs_redraw()
     }
@@ -164,12 +249,29 @@ impl<A: CoordinatorApp> LayoutCoordinator<A> {
             CoordinatorEvent::SetVisibility(id, visibility) => {
                 self.handle_set_visibility(id, visibility)
             }
+            CoordinatorEvent::SetSlotVisibility(slot, visible) => {
+                if self.set_slot_visible(slot, visible)? {
+                    Ok(CoordinatorAction::Redraw)
+                } else {
+                    Ok(CoordinatorAction::Continue)
+                }
+            }
             CoordinatorEvent::RequestDiagnosticInfo => self.handle_diagnostic_request(),
         }
     }
 
     fn handle_keyboard(&mut self, keyboard: KeyboardEvent) -> LayoutResult<CoordinatorAction> {
-        if let Some(focused_id) = self.focus.focused() {
+        let focused = self.focus.focused().filter(|&id| {
+            self.layout
+                .registry()
+                .get_metadata(id)
+                .map_or(true, |metadata| {
+                    metadata
+                        .slot
+                        .map_or(true, |slot| self.layout.is_slot_visible(slot))
+                })
+        });
+        if let Some(focused_id) = focused {
             if let Ok(element) = self.layout.registry().get_strong_ref(focused_id) {
                 if element.on_keyboard(&keyboard) {
                     self.invalidate_elements();
@@ -453,6 +555,108 @@ mod tests {
         assert_eq!(coordinator.layout.state().terminal_area.height, 24);
     }
 
+    #[test]
+    fn test_coordinator_slots() {
+        use ratatui::layout::{Constraint, Rect};
+
+        let mut coordinator = LayoutCoordinator::new(TestApp);
+        coordinator
+            .handle_event(CoordinatorEvent::Resize(ResizeEvent::new(80, 24)))
+            .unwrap();
+
+        let sidebar = ElementId::new();
+        let content = ElementId::new();
+        let mut elements = Vec::new();
+        for (id, slot) in [(sidebar, Slot::Sidebar), (content, Slot::Content)] {
+            let metadata = ElementMetadata::in_slot(id, slot).with_focusable(true);
+            let element: Arc<dyn Element> = Arc::new(DummyElement::new(id));
+            coordinator
+                .handle_event(CoordinatorEvent::Register(metadata, element.clone()))
+                .unwrap();
+            elements.push(element);
+        }
+        coordinator
+            .define_slots(SlotSpec {
+                header: Constraint::Length(3),
+                footer: Constraint::Length(1),
+                sidebar: Some(Constraint::Percentage(25)),
+                content: Constraint::Fill(1),
+            })
+            .unwrap();
+
+        let rect = |c: &LayoutCoordinator<TestApp>, id| c.layout().get_element_rect(id).unwrap();
+        assert_eq!(rect(&coordinator, sidebar), Rect::new(0, 3, 20, 20));
+        assert_eq!(rect(&coordinator, content), Rect::new(20, 3, 60, 20));
+        assert_eq!(coordinator.layout().hit_test_top(5, 10), Some(sidebar));
+
+        coordinator
+            .handle_event(CoordinatorEvent::Focus(FocusRequest::To(sidebar)))
+            .unwrap();
+        assert!(!coordinator.toggle_slot(Slot::Sidebar).unwrap());
+        assert_ne!(coordinator.focus().focused(), Some(sidebar));
+        assert!(rect(&coordinator, sidebar).is_empty());
+        assert_eq!(rect(&coordinator, content), Rect::new(0, 3, 80, 20));
+        assert_eq!(coordinator.layout().hit_test_top(5, 10), Some(content));
+
+        let action = coordinator
+            .handle_event(CoordinatorEvent::SetSlotVisibility(Slot::Sidebar, true))
+            .unwrap();
+        assert_eq!(action, CoordinatorAction::Redraw);
+        assert_eq!(
+            coordinator.slot_area(Slot::Sidebar),
+            Rect::new(0, 3, 20, 20)
+        );
+    }
+
+    #[test]
+    fn test_hiding_focused_slot_moves_focus_to_a_visible_element() {
+        use ratatui::layout::Constraint;
+
+        let spec = SlotSpec {
+            header: Constraint::Length(3),
+            footer: Constraint::Length(1),
+            sidebar: Some(Constraint::Percentage(25)),
+            content: Constraint::Fill(1),
+        };
+        let setup = |slots: &[Slot]| {
+            let mut coordinator = LayoutCoordinator::new(TestApp);
+            coordinator
+                .handle_event(CoordinatorEvent::Resize(ResizeEvent::new(80, 24)))
+                .unwrap();
+            // The registry only holds weak references.
+            let mut elements: Vec<Arc<dyn Element>> = Vec::new();
+            for &slot in slots {
+                let id = ElementId::new();
+                let metadata = ElementMetadata::in_slot(id, slot).with_focusable(true);
+                elements.push(Arc::new(DummyElement::new(id)));
+                coordinator
+                    .handle_event(CoordinatorEvent::Register(
+                        metadata,
+                        elements[elements.len() - 1].clone(),
+                    ))
+                    .unwrap();
+            }
+            coordinator.define_slots(spec).unwrap();
+            (coordinator, elements)
+        };
+
+        // Focus skips the other sidebar element and lands on the content.
+        let (mut coordinator, elements) = setup(&[Slot::Sidebar, Slot::Sidebar, Slot::Content]);
+        coordinator
+            .handle_event(CoordinatorEvent::Focus(FocusRequest::To(elements[0].id())))
+            .unwrap();
+        assert!(coordinator.set_slot_visible(Slot::Sidebar, false).unwrap());
+        assert_eq!(coordinator.focus().focused(), Some(elements[2].id()));
+
+        // With nothing visible to take it, focus is released.
+        let (mut coordinator, elements) = setup(&[Slot::Sidebar, Slot::Sidebar]);
+        coordinator
+            .handle_event(CoordinatorEvent::Focus(FocusRequest::To(elements[1].id())))
+            .unwrap();
+        assert!(coordinator.set_slot_visible(Slot::Sidebar, false).unwrap());
+        assert_eq!(coordinator.focus().focused(), None);
+    }
+
     #[test]
     fn test_coordinator_diagnostic() {
         let app = TestApp;
@@ -12,6 +12,8 @@ pub use crate::{
     mouse_router::MouseRouterConfig,
     redraw_signal::RedrawSignal,
     registry::{Element, ElementHandle},
+    shutdown::{ShutdownAction, ShutdownHook, ShutdownReport},
+    slots::{Slot, SlotAreas, SlotLayout, SlotSpec},
     types::{ElementId, ElementMetadata, Visibility},
 };
 pub use runner::{Runner, RunnerAction, RunnerConfig, RunnerEvent};
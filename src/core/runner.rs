@@ -1,7 +1,8 @@
 //! Core runner coordinating layout and event dispatch.
 
 use std::sync::Arc;
-use std::time::Duration;
+use std::thread;
+use std::time::{Duration, Instant};
 
 use crate::coordinator::{
     CoordinatorAction, CoordinatorApp, CoordinatorConfig, CoordinatorEvent, LayoutCoordinator,
@@ -11,8 +12,11 @@ use crate::events::{RunnerEvent as LayoutRunnerEvent, TickEvent};
 use crate::focus::FocusRequest;
 use crate::mouse_router::MouseRouterConfig;
 use crate::registry::Element;
+use crate::shutdown::{ShutdownAction, ShutdownReport};
+use crate::slots::{Slot, SlotSpec};
 use crate::types::{ElementId, ElementMetadata, Visibility};
 use ratatui::Frame;
+use tracing::warn;
 
 /// Runner events routed to the core runtime.
 pub type RunnerEvent = LayoutRunnerEvent;
@@ -26,6 +30,8 @@ pub struct RunnerConfig {
     pub layout_debounce: Duration,
     /// Mouse routing configuration.
     pub mouse_router_config: MouseRouterConfig,
+    /// Longest time to wait for shutdown hooks before exiting anyway.
+    pub shutdown_timeout: Duration,
 }
 
 impl Default for RunnerConfig {
@@ -35,6 +41,7 @@ impl Default for RunnerConfig {
             tick_rate: base.tick_rate,
             layout_debounce: base.layout_debounce,
             mouse_router_config: base.mouse_router_config,
+            shutdown_timeout: Duration::from_secs(2),
         }
     }
 }
@@ -66,6 +73,7 @@ pub struct Runner<A: CoordinatorApp> {
     coordinator: LayoutCoordinator<A>,
     config: RunnerConfig,
     tick_count: u64,
+    shutting_down: bool,
 }
 
 impl<A: CoordinatorApp> Runner<A> {
@@ -77,6 +85,7 @@ impl<A: CoordinatorApp> Runner<A> {
             coordinator,
             config,
             tick_count: 0,
+            shutting_down: false,
         }
     }
 
@@ -108,7 +117,14 @@ impl<A: CoordinatorApp> Runner<A> {
     }
 
     /// Handle a runner event and return the desired action.
+    ///
+    /// Once [`shutdown`](Self::shutdown) has started, events are dropped and
+    /// `Quit` is returned.
     pub fn handle_event(&mut self, event: RunnerEvent) -> LayoutResult<RunnerAction> {
+        if self.shutting_down {
+            return Ok(RunnerAction::Quit);
+        }
+
         if !self.is_layout_initialized() && !matches!(event, RunnerEvent::Resize(_)) {
             return Err(LayoutError::layout_computation(
                 "runner layout is not initialized; dispatch a resize event before handling events",
@@ -169,6 +185,17 @@ impl<A: CoordinatorApp> Runner<A> {
         self.handle_coordinator_event(CoordinatorEvent::SetVisibility(id, visibility))
     }
 
+    /// Lay the screen out as named slots.
+    pub fn define_slots(&mut self, spec: SlotSpec) -> LayoutResult<RunnerAction> {
+        self.coordinator.define_slots(spec)?;
+        Ok(RunnerAction::Redraw)
+    }
+
+    /// Show or hide a slot, giving its space to the content slot.
+    pub fn set_slot_visible(&mut self, slot: Slot, visible: bool) -> LayoutResult<RunnerAction> {
+        self.handle_coordinator_event(CoordinatorEvent::SetSlotVisibility(slot, visible))
+    }
+
     /// Apply a focus change request.
     pub fn request_focus(&mut self, request: FocusRequest) -> LayoutResult<RunnerAction> {
         self.handle_coordinator_event(CoordinatorEvent::Focus(request))
@@ -191,6 +218,58 @@ impl<A: CoordinatorApp> Runner<A> {
         Ok(())
     }
 
+    /// Whether [`shutdown`](Self::shutdown) has started.
+    pub fn is_shutting_down(&self) -> bool {
+        self.shutting_down
+    }
+
+    /// Poll the app's shutdown hooks until they all finish or
+    /// [`RunnerConfig::shutdown_timeout`] elapses.
+    ///
+    /// Hooks are polled in order once per tick, and a hook that returns
+    /// [`ShutdownAction::Done`] is not called again. Between polls,
+    /// `on_pending` receives the time elapsed so far, e.g. to draw a
+    /// progress frame. Hooks still pending at the timeout are logged and
+    /// listed in the report.
+    pub fn shutdown(&mut self, mut on_pending: impl FnMut(Duration)) -> ShutdownReport {
+        self.shutting_down = true;
+        let timeout = self.config.shutdown_timeout;
+        let started = Instant::now();
+        let mut finished: Vec<bool> = Vec::new();
+
+        loop {
+            let mut unfinished = Vec::new();
+            let hooks = self.coordinator.app_mut().shutdown_hooks();
+            finished.resize(finished.len().max(hooks.len()), false);
+            for (hook, done) in hooks.into_iter().zip(finished.iter_mut()) {
+                if *done {
+                    continue;
+                }
+                match hook.on_shutdown() {
+                    ShutdownAction::Done => *done = true,
+                    ShutdownAction::Pending => unfinished.push(hook.shutdown_name().to_string()),
+                }
+            }
+
+            let elapsed = started.elapsed();
+            if unfinished.is_empty() || elapsed >= timeout {
+                if !unfinished.is_empty() {
+                    warn!(
+                        hooks = ?unfinished,
+                        "shutdown timed out after {:?}; exiting with hooks pending", elapsed
+                    );
+                }
+                return ShutdownReport {
+                    elapsed,
+                    unfinished,
+                };
+            }
+
+            on_pending(elapsed);
+            thread::sleep(self.config.tick_rate.min(timeout - elapsed));
+        }
+    }
+
     fn render_visible_elements(&self) {
         let layout = self.coordinator.layout();
         let registry = layout.registry();
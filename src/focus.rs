@@ -260,6 +260,23 @@ impl FocusManager {
         Ok(())
     }
 
+    /// Clear the focus stack so no element has focus, unlike
+    /// [`FocusRequest::Release`], which falls back to another element.
+    /// A focus capture is left in place.
+    pub fn clear_focus(&mut self) -> LayoutResult<()> {
+        let previous = self.focused();
+        self.focus_stack.clear();
+        let current = self.focused();
+
+        debug!("Focus cleared: previous = {:?}", previous);
+
+        if previous != current {
+            self.notify_focus_change(previous, current)?;
+        }
+
+        Ok(())
+    }
+
     /// Remove an element from the focus stack and restore fallback focus.
     pub fn remove_element(&mut self, id: ElementId) -> LayoutResult<()> {
         if self.captured_by == Some(id) {
@@ -6,6 +6,7 @@ use tracing::{debug, trace};
 
 use crate::error::{LayoutError, LayoutResult};
 use crate::registry::ElementRegistry;
+use crate::slots::{Slot, SlotLayout, SlotSpec};
 use crate::types::{ElementId, LayoutState, Region, ResizeDebounceState};
 
 const MIN_TERMINAL_WIDTH: u16 = 10;
@@ -20,6 +21,7 @@ pub struct LayoutManager {
     dirty: bool,
     resize_debounce: Duration,
     debounce_state: ResizeDebounceState,
+    slots: Option<SlotLayout>,
 }
 
 impl Default for LayoutManager {
@@ -36,6 +38,7 @@ impl LayoutManager {
             dirty: true,
             resize_debounce: DEFAULT_RESIZE_DEBOUNCE,
             debounce_state: ResizeDebounceState::Idle,
+            slots: None,
         }
     }
 
@@ -72,6 +75,51 @@ impl LayoutManager {
         self.dirty = true;
     }
 
+    /// Lay the screen out as named slots.
+    ///
+    /// Header and footer replace the top and bottom regions, ignoring the
+    /// fixed heights of elements there, and center-region elements outside
+    /// a slot get the band between them. Slots hidden before stay hidden.
+    pub fn define_slots(&mut self, spec: SlotSpec) {
+        match self.slots.as_mut() {
+            Some(slots) => slots.set_spec(spec),
+            None => self.slots = Some(SlotLayout::new(spec)),
+        }
+        self.mark_dirty();
+    }
+
+    /// The slot layout, if slots are defined.
+    pub fn slots(&self) -> Option<&SlotLayout> {
+        self.slots.as_ref()
+    }
+
+    /// Show or hide `slot`, giving its space to the content slot. Returns
+    /// whether that changed anything.
+    pub fn set_slot_visible(&mut self, slot: Slot, visible: bool) -> bool {
+        let changed = self
+            .slots
+            .as_mut()
+            .is_some_and(|slots| slots.set_visible(slot, visible));
+        if changed {
+            self.mark_dirty();
+        }
+        changed
+    }
+
+    /// Whether elements in `slot` are laid out, i.e. slots are not defined
+    /// or `slot` is not hidden.
+    pub fn is_slot_visible(&self, slot: Slot) -> bool {
+        self.slots
+            .as_ref()
+            .map_or(true, |slots| slots.is_visible(slot))
+    }
+
+    /// The rect of `slot` as of the last layout pass; empty if slots are
+    /// not defined or the slot is hidden.
+    pub fn slot_area(&self, slot: Slot) -> Rect {
+        self.state.slot_areas.get(slot)
+    }
+
     /// Update layout for a terminal resize.
     pub fn on_resize(&mut self, width: u16, height: u16) -> LayoutResult<()> {
         if width < MIN_TERMINAL_WIDTH || height < MIN_TERMINAL_HEIGHT {
@@ -185,6 +233,28 @@ impl LayoutManager {
         }
 
         let area = self.state.terminal_area;
+        if let Some(slots) = &self.slots {
+            let slot_areas = slots.split(area);
+            if slot_areas.content.is_empty() {
+                return Err(LayoutError::layout_computation(
+                    "Insufficient space for content slot",
+                ));
+            }
+            let center_area = slot_areas.body();
+            self.state.top_area = slot_areas.header;
+            self.state.center_area = center_area;
+            self.state.bottom_area = slot_areas.footer;
+            self.state.top_height = slot_areas.header.height;
+            self.state.bottom_height = slot_areas.footer.height;
+            self.state.slot_areas = slot_areas;
+
+            self.assign_element_rects(slot_areas.header, center_area, slot_areas.footer)?;
+            self.dirty = false;
+
+            debug!("Layout recomputed with slots: {:?}", slot_areas);
+            return Ok(());
+        }
+
         let mut top_height: u16 = 0;
         let mut bottom_height: u16 = 0;
 
@@ -280,10 +350,13 @@ impl LayoutManager {
                 continue;
             }
 
-            metadata.rect = match metadata.region {
-                Region::Top => top_area,
-                Region::Center => center_area,
-                Region::Bottom => bottom_area,
+            metadata.rect = match (metadata.slot, &self.slots) {
+                (Some(slot), Some(_)) => self.state.slot_areas.get(slot),
+                _ => match metadata.region {
+                    Region::Top => top_area,
+                    Region::Center => center_area,
+                    Region::Bottom => bottom_area,
+                },
             };
 
             trace!("Assigned rect to element {}: {:?}", id, metadata.rect);
@@ -74,6 +74,8 @@ mod mouse_router;
 mod redraw_signal;
 mod registry;
 mod runner_helper;
+mod shutdown;
+mod slots;
 mod types;
 
 /// Core runtime pieces for ratkit.
@@ -88,20 +90,25 @@ pub mod widgets;
 /// Feature-gated service modules.
 pub mod services;
 
-pub use runner_helper::{run, run_with_diagnostics};
+/// App builder wiring the runner, slots, config and theme persistence.
+pub mod scaffold;
+
+pub use runner_helper::{run, run_with_diagnostics, suspend_terminal};
 
 pub use core::{
     CoordinatorAction, CoordinatorApp, CoordinatorConfig, CoordinatorEvent, Element, ElementHandle,
     ElementId, ElementMetadata, FocusRequest, KeyboardEvent, LayoutCoordinator, LayoutError,
     LayoutResult, MouseEvent, MouseRouterConfig, RedrawSignal, ResizeEvent, Runner, RunnerAction,
-    RunnerConfig, RunnerEvent, TickEvent, Visibility,
+    RunnerConfig, RunnerEvent, ShutdownAction, ShutdownHook, ShutdownReport, Slot, SlotAreas,
+    SlotLayout, SlotSpec, TickEvent, Visibility,
 };
 
 /// Runner-first imports for applications.
 pub mod prelude {
     pub use crate::{
-        run, run_with_diagnostics, CoordinatorAction, CoordinatorApp, CoordinatorConfig,
-        CoordinatorEvent, KeyboardEvent, LayoutResult, MouseEvent, MouseRouterConfig, ResizeEvent,
-        Runner, RunnerAction, RunnerConfig, RunnerEvent, TickEvent,
+        run, run_with_diagnostics, suspend_terminal, CoordinatorAction, CoordinatorApp,
+        CoordinatorConfig, CoordinatorEvent, KeyboardEvent, LayoutResult, MouseEvent,
+        MouseRouterConfig, ResizeEvent, Runner, RunnerAction, RunnerConfig, RunnerEvent,
+        ShutdownAction, ShutdownHook, Slot, SlotLayout, SlotSpec, TickEvent,
     };
 }
@@ -1,9 +1,9 @@
 use crate::primitives::dialog::types::{
-    Dialog, DialogActionsLayout, DialogBodyRenderer, DialogFooter, DialogKeymap, DialogModalMode,
-    DialogPadding, DialogShadow, DialogType, DialogWrap,
+    AnchorSide, Dialog, DialogActionsLayout, DialogAnchor, DialogBodyRenderer, DialogFooter,
+    DialogKeymap, DialogModalMode, DialogPadding, DialogShadow, DialogType, DialogWrap,
 };
 use crossterm::event::KeyCode;
-use ratatui::layout::Alignment;
+use ratatui::layout::{Alignment, Rect};
 use ratatui::style::{Color, Modifier, Style};
 
 impl<'a> Dialog<'a> {
@@ -16,6 +16,7 @@ impl<'a> Dialog<'a> {
             selected_button: 0,
             width_percent: 0.6,
             height_percent: 0.4,
+            anchor: None,
             footer: DialogFooter::Hidden,
             footer_style: Style::default().fg(Color::DarkGray),
             footer_alignment: Alignment::Center,
@@ -99,6 +100,22 @@ impl<'a> Dialog<'a> {
         self
     }
 
+    /// Places the dialog beside `rect`, such as the button, tree row or
+    /// menu item that opened it, instead of centering it.
+    ///
+    /// The dialog is kept inside the render area and, when it sits right
+    /// against the anchor, draws a notch on its border pointing at it.
+    pub fn anchored_to(mut self, rect: Rect, side: AnchorSide) -> Self {
+        self.anchor = Some(DialogAnchor { rect, side });
+        self
+    }
+
+    /// Centers the dialog in the render area, the default.
+    pub fn centered(mut self) -> Self {
+        self.anchor = None;
+        self
+    }
+
     pub fn footer(mut self, footer: &'a str) -> Self {
         self.footer = DialogFooter::Text(footer);
         self
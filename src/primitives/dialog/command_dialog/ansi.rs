@@ -0,0 +1,153 @@
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+
+use crate::primitives::termtui::ratatui_render::style_from_attrs;
+use crate::primitives::termtui::vt100::Parser;
+
+const MAX_COLUMNS: usize = 4096;
+const TAB_WIDTH: usize = 8;
+
+/// Turns raw output lines into styled lines by running them through the
+/// VT parser and reading back the SGR attributes.
+///
+/// The parser is kept between lines so color state carries over a line
+/// break like it would in a terminal.
+pub(crate) struct AnsiLineParser {
+    parser: Parser,
+    base_style: Style,
+}
+
+impl AnsiLineParser {
+    pub(crate) fn new(base_style: Style) -> Self {
+        Self {
+            parser: Parser::new(1, 1, 0),
+            base_style,
+        }
+    }
+
+    pub(crate) fn parse(&mut self, bytes: &[u8]) -> (Line<'static>, String) {
+        let tabs = bytes.iter().filter(|b| **b == b'\t').count();
+        let columns = (bytes.len() + tabs * TAB_WIDTH).clamp(1, MAX_COLUMNS);
+        self.parser.set_size(1, columns as u16);
+
+        let mut events = Vec::new();
+        self.parser.screen.process(b"\r\x1b[2K", &mut events);
+        self.parser.screen.process(bytes, &mut events);
+
+        let screen = self.parser.screen();
+        let mut spans: Vec<Span<'static>> = Vec::new();
+        let mut text = String::new();
+        let mut pending = String::new();
+        let mut pending_style = self.base_style;
+        let mut trailing_blank = 0;
+
+        let mut col = 0;
+        while col < columns {
+            let Some(cell) = screen.cell(0, col as u16) else {
+                break;
+            };
+            let contents = if cell.has_contents() {
+                cell.contents()
+            } else {
+                " "
+            };
+            let style = self.patch(style_from_attrs(*cell.attrs()));
+
+            if style != pending_style && !pending.is_empty() {
+                spans.push(Span::styled(std::mem::take(&mut pending), pending_style));
+            }
+            pending_style = style;
+            pending.push_str(contents);
+            text.push_str(contents);
+            trailing_blank = if contents == " " {
+                trailing_blank + 1
+            } else {
+                0
+            };
+
+            col += if cell.is_wide() { 2 } else { 1 };
+        }
+        if !pending.is_empty() {
+            spans.push(Span::styled(pending, pending_style));
+        }
+
+        trim_trailing(&mut spans, trailing_blank);
+        text.truncate(text.len() - trailing_blank.min(text.len()));
+        (Line::from(spans), text)
+    }
+
+    /// Drops terminal defaults so the line inherits the dialog style, then
+    /// applies the stream's base style.
+    fn patch(&self, mut style: Style) -> Style {
+        if style.fg == Some(Color::Reset) {
+            style.fg = self.base_style.fg;
+        }
+        if style.bg == Some(Color::Reset) {
+            style.bg = self.base_style.bg;
+        }
+        style
+    }
+}
+
+fn trim_trailing(spans: &mut Vec<Span<'static>>, mut blanks: usize) {
+    while blanks > 0 {
+        let Some(last) = spans.last_mut() else {
+            return;
+        };
+        let len = last.content.len();
+        if len > blanks {
+            last.content.to_mut().truncate(len - blanks);
+            return;
+        }
+        blanks -= len;
+        spans.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::{Color, Modifier, Style};
+
+    use super::AnsiLineParser;
+
+    #[test]
+    fn plain_text_keeps_base_style() {
+        let mut parser = AnsiLineParser::new(Style::default().fg(Color::Red));
+        let (line, text) = parser.parse(b"error: oops  ");
+
+        assert_eq!(text, "error: oops");
+        assert_eq!(line.spans.len(), 1);
+        assert_eq!(line.spans[0].content, "error: oops");
+        assert_eq!(line.spans[0].style.fg, Some(Color::Red));
+    }
+
+    #[test]
+    fn sgr_sequences_become_styles() {
+        let mut parser = AnsiLineParser::new(Style::default());
+        let (line, text) = parser.parse(b"\x1b[1;32mok\x1b[0m done");
+
+        assert_eq!(text, "ok done");
+        assert_eq!(line.spans[0].content, "ok");
+        assert_eq!(line.spans[0].style.fg, Some(Color::Green));
+        assert!(line.spans[0].style.add_modifier.contains(Modifier::BOLD));
+        assert_eq!(line.spans[1].content, " done");
+        assert_eq!(line.spans[1].style.fg, None);
+    }
+
+    #[test]
+    fn color_state_carries_over_lines() {
+        let mut parser = AnsiLineParser::new(Style::default());
+        parser.parse(b"\x1b[31mfirst");
+        let (line, _) = parser.parse(b"second");
+
+        assert_eq!(line.spans[0].style.fg, Some(Color::Red));
+    }
+
+    #[test]
+    fn carriage_return_overwrites_progress() {
+        let mut parser = AnsiLineParser::new(Style::default());
+        let (_, text) = parser.parse(b"10%\r100%");
+
+        assert_eq!(text, "100%");
+    }
+}
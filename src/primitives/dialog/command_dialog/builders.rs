@@ -0,0 +1,67 @@
+use std::io;
+use std::process::Command;
+use std::time::Instant;
+
+use ratatui::style::{Color, Style};
+
+use crate::primitives::dialog::command_dialog::process::RunningProcess;
+use crate::primitives::dialog::command_dialog::types::{CommandDialog, CommandStatus};
+
+impl CommandDialog {
+    /// Spawns `command` and returns a dialog streaming its output.
+    ///
+    /// Stdin is closed and stdout/stderr are piped, so commands that check
+    /// for a terminal may need flags such as `--color=always`.
+    pub fn spawn(command: Command) -> io::Result<Self> {
+        let command_line = std::iter::once(command.get_program())
+            .chain(command.get_args())
+            .map(|part| part.to_string_lossy())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let process = RunningProcess::spawn(command)?;
+
+        Ok(Self {
+            title: command_line.clone(),
+            command_line,
+            lines: Vec::new(),
+            status: CommandStatus::Running,
+            started_at: Instant::now(),
+            finished_at: None,
+            scroll: 0,
+            follow: true,
+            selected_button: 0,
+            button_areas: Vec::new(),
+            body_height: 0,
+            width_percent: 0.8,
+            height_percent: 0.7,
+            stderr_style: Style::default().fg(Color::LightRed),
+            process: Some(process),
+            stdout_parser: None,
+            stderr_parser: None,
+        })
+    }
+
+    /// Sets the dialog title.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Sets the dialog width as a fraction of the screen.
+    pub fn width_percent(mut self, width_percent: f32) -> Self {
+        self.width_percent = width_percent;
+        self
+    }
+
+    /// Sets the dialog height as a fraction of the screen.
+    pub fn height_percent(mut self, height_percent: f32) -> Self {
+        self.height_percent = height_percent;
+        self
+    }
+
+    /// Sets the base style for stderr lines.
+    pub fn stderr_style(mut self, style: Style) -> Self {
+        self.stderr_style = style;
+        self
+    }
+}
@@ -0,0 +1,363 @@
+use std::time::{Duration, Instant};
+
+use crossterm::event::KeyCode;
+use ratatui::style::Style;
+
+use crate::primitives::dialog::command_dialog::ansi::AnsiLineParser;
+use crate::primitives::dialog::command_dialog::types::{
+    CommandDialog, CommandDialogEvent, CommandOutputLine, CommandStatus, CommandStream,
+};
+use crate::{ShutdownAction, ShutdownHook};
+
+const RUNNING_BUTTONS: &[&str] = &["Cancel"];
+const FINISHED_BUTTONS: &[&str] = &["Copy output", "Close"];
+
+impl CommandDialog {
+    /// Returns true while the command has not exited or been cancelled.
+    pub fn is_running(&self) -> bool {
+        self.status == CommandStatus::Running
+    }
+
+    /// Returns the run time, frozen once the command finishes.
+    pub fn elapsed(&self) -> Duration {
+        self.finished_at
+            .unwrap_or_else(Instant::now)
+            .saturating_duration_since(self.started_at)
+    }
+
+    /// Returns the button labels for the current status.
+    pub fn buttons(&self) -> &'static [&'static str] {
+        if self.is_running() {
+            RUNNING_BUTTONS
+        } else {
+            FINISHED_BUTTONS
+        }
+    }
+
+    /// Returns the output as plain text, one line per output line.
+    pub fn output_text(&self) -> String {
+        self.lines
+            .iter()
+            .map(|line| line.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Reads pending output and checks whether the command finished.
+    ///
+    /// Call this on every tick while the dialog is open.
+    pub fn poll(&mut self) -> Option<CommandDialogEvent> {
+        let process = self.process.as_mut()?;
+        let lines = process.drain();
+        let finished = process.try_finish();
+
+        for (stream, bytes) in lines {
+            self.push_output(stream, &bytes);
+        }
+
+        let exit_code = match finished {
+            Ok(Some(status)) => status.code(),
+            Ok(None) => return None,
+            Err(_) => None,
+        };
+
+        self.process = None;
+        self.finish(CommandStatus::Exited(exit_code));
+        Some(CommandDialogEvent::Completed { exit_code })
+    }
+
+    /// Kills the command and its process group.
+    ///
+    /// Output read before the kill stays in the dialog.
+    pub fn cancel(&mut self) -> Option<CommandDialogEvent> {
+        let mut process = self.process.take()?;
+        process.kill();
+        for (stream, bytes) in process.drain() {
+            self.push_output(stream, &bytes);
+        }
+
+        self.finish(CommandStatus::Cancelled);
+        Some(CommandDialogEvent::Cancelled)
+    }
+
+    fn finish(&mut self, status: CommandStatus) {
+        self.status = status;
+        self.finished_at = Some(Instant::now());
+        self.selected_button = FINISHED_BUTTONS.len() - 1;
+    }
+
+    pub(crate) fn push_output(&mut self, stream: CommandStream, bytes: &[u8]) {
+        let (parser, base_style) = match stream {
+            CommandStream::Stdout => (&mut self.stdout_parser, Style::default()),
+            CommandStream::Stderr => (&mut self.stderr_parser, self.stderr_style),
+        };
+        let (line, text) = parser
+            .get_or_insert_with(|| AnsiLineParser::new(base_style))
+            .parse(bytes);
+
+        self.lines.push(CommandOutputLine { stream, line, text });
+    }
+
+    fn max_scroll(&self) -> usize {
+        self.lines.len().saturating_sub(self.body_height as usize)
+    }
+
+    /// Scrolls up and stops following new output.
+    pub fn scroll_up(&mut self, amount: usize) {
+        self.scroll = self.scroll.min(self.max_scroll()).saturating_sub(amount);
+        self.follow = false;
+    }
+
+    /// Scrolls down; reaching the bottom resumes following new output.
+    pub fn scroll_down(&mut self, amount: usize) {
+        let max = self.max_scroll();
+        self.scroll = self.scroll.saturating_add(amount).min(max);
+        self.follow = self.scroll == max;
+    }
+
+    /// Jumps to the newest output and resumes following it.
+    pub fn scroll_to_bottom(&mut self) {
+        self.scroll = self.max_scroll();
+        self.follow = true;
+    }
+
+    /// Runs the action of the button at `index`.
+    pub fn activate(&mut self, index: usize) -> Option<CommandDialogEvent> {
+        if self.is_running() {
+            return self.cancel();
+        }
+
+        match index {
+            0 => Some(CommandDialogEvent::CopyOutput(self.output_text())),
+            _ => Some(CommandDialogEvent::Close),
+        }
+    }
+
+    /// Handles scrolling, button focus, Enter and Esc.
+    pub fn handle_key_event(&mut self, key: KeyCode) -> Option<CommandDialogEvent> {
+        let page = (self.body_height as usize).max(1);
+        match key {
+            KeyCode::Up | KeyCode::Char('k') => self.scroll_up(1),
+            KeyCode::Down | KeyCode::Char('j') => self.scroll_down(1),
+            KeyCode::PageUp => self.scroll_up(page),
+            KeyCode::PageDown => self.scroll_down(page),
+            KeyCode::Home | KeyCode::Char('g') => self.scroll_up(self.lines.len()),
+            KeyCode::End | KeyCode::Char('G') => self.scroll_to_bottom(),
+            KeyCode::Tab | KeyCode::Right | KeyCode::Char('l') => {
+                self.selected_button = (self.selected_button + 1).min(self.buttons().len() - 1);
+            }
+            KeyCode::BackTab | KeyCode::Left | KeyCode::Char('h') => {
+                self.selected_button = self.selected_button.saturating_sub(1);
+            }
+            KeyCode::Enter => return self.activate(self.selected_button),
+            KeyCode::Esc if self.is_running() => return self.cancel(),
+            KeyCode::Esc | KeyCode::Char('q') => return Some(CommandDialogEvent::Close),
+            _ => {}
+        }
+        None
+    }
+
+    /// Activates the button under a mouse click, if any.
+    pub fn handle_click(&mut self, column: u16, row: u16) -> Option<CommandDialogEvent> {
+        let index = self.button_areas.iter().position(|area| {
+            column >= area.x
+                && column < area.x + area.width
+                && row >= area.y
+                && row < area.y + area.height
+        })?;
+        self.selected_button = index;
+        self.activate(index)
+    }
+}
+
+impl ShutdownHook for CommandDialog {
+    /// Terminates a running command's process group, then waits for it to
+    /// exit and its output to be read.
+    fn on_shutdown(&mut self) -> ShutdownAction {
+        let Some(process) = self.process.as_mut() else {
+            return ShutdownAction::Done;
+        };
+        process.terminate();
+
+        match self.poll() {
+            Some(_) => ShutdownAction::Done,
+            None => ShutdownAction::Pending,
+        }
+    }
+
+    fn shutdown_name(&self) -> &str {
+        &self.title
+    }
+}
+
+pub(crate) fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    if secs >= 3600 {
+        format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+    } else {
+        format!("{:02}:{:02}", secs / 60, secs % 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process::Command;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use crossterm::event::KeyCode;
+    use ratatui::style::Color;
+
+    use super::format_elapsed;
+    use crate::primitives::dialog::command_dialog::{
+        CommandDialog, CommandDialogEvent, CommandStatus, CommandStream,
+    };
+    use crate::{ShutdownAction, ShutdownHook};
+
+    fn wait_for_event(dialog: &mut CommandDialog) -> CommandDialogEvent {
+        let deadline = Instant::now() + Duration::from_secs(10);
+        loop {
+            if let Some(event) = dialog.poll() {
+                return event;
+            }
+            assert!(Instant::now() < deadline, "command did not finish");
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    fn sh(script: &str) -> Command {
+        let mut command = Command::new("sh");
+        command.args(["-c", script]);
+        command
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn streams_output_and_reports_exit_code() {
+        let mut dialog = CommandDialog::spawn(sh(
+            "echo out; printf '\\033[32mgreen\\033[0m\\n'; echo err >&2; exit 3",
+        ))
+        .unwrap();
+
+        assert_eq!(
+            wait_for_event(&mut dialog),
+            CommandDialogEvent::Completed { exit_code: Some(3) }
+        );
+        assert_eq!(dialog.status, CommandStatus::Exited(Some(3)));
+        assert_eq!(dialog.buttons(), ["Copy output", "Close"]);
+
+        let stdout: Vec<_> = dialog
+            .lines
+            .iter()
+            .filter(|line| line.stream == CommandStream::Stdout)
+            .map(|line| line.text.as_str())
+            .collect();
+        assert_eq!(stdout, ["out", "green"]);
+
+        let green = dialog
+            .lines
+            .iter()
+            .find(|line| line.text == "green")
+            .unwrap();
+        assert_eq!(green.line.spans[0].style.fg, Some(Color::Green));
+
+        let err = dialog.lines.iter().find(|line| line.text == "err").unwrap();
+        assert_eq!(err.stream, CommandStream::Stderr);
+        assert_eq!(err.line.spans[0].style.fg, Some(Color::LightRed));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn finishes_while_a_background_process_holds_the_output() {
+        let mut dialog = CommandDialog::spawn(sh("echo done; sleep 5 &")).unwrap();
+
+        let started = Instant::now();
+        assert_eq!(
+            wait_for_event(&mut dialog),
+            CommandDialogEvent::Completed { exit_code: Some(0) }
+        );
+        assert!(started.elapsed() < Duration::from_secs(3));
+        assert_eq!(dialog.output_text(), "done");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn cancel_kills_the_process_group() {
+        let mut dialog = CommandDialog::spawn(sh("sleep 30 & wait")).unwrap();
+        assert!(dialog.is_running());
+        assert_eq!(dialog.buttons(), ["Cancel"]);
+
+        let started = Instant::now();
+        assert_eq!(
+            dialog.handle_key_event(KeyCode::Esc),
+            Some(CommandDialogEvent::Cancelled)
+        );
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(dialog.status, CommandStatus::Cancelled);
+        assert_eq!(dialog.poll(), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn finished_dialog_buttons_copy_and_close() {
+        let mut dialog = CommandDialog::spawn(sh("echo one; echo two")).unwrap();
+        wait_for_event(&mut dialog);
+
+        assert_eq!(dialog.selected_button, 1);
+        assert_eq!(
+            dialog.activate(0),
+            Some(CommandDialogEvent::CopyOutput("one\ntwo".to_string()))
+        );
+        assert_eq!(
+            dialog.handle_key_event(KeyCode::Enter),
+            Some(CommandDialogEvent::Close)
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn scrolling_up_stops_following() {
+        let mut dialog = CommandDialog::spawn(sh("seq 1 20")).unwrap();
+        wait_for_event(&mut dialog);
+        dialog.body_height = 5;
+        dialog.scroll_to_bottom();
+        assert_eq!(dialog.scroll, 15);
+
+        dialog.handle_key_event(KeyCode::Up);
+        assert_eq!(dialog.scroll, 14);
+        assert!(!dialog.follow);
+
+        dialog.handle_key_event(KeyCode::Down);
+        assert_eq!(dialog.scroll, 15);
+        assert!(dialog.follow);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn shutdown_hook_terminates_the_process_group() {
+        let mut dialog =
+            CommandDialog::spawn(sh("trap 'echo bye; exit 0' TERM; sleep 30 & wait")).unwrap();
+        thread::sleep(Duration::from_millis(100));
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while dialog.on_shutdown() == ShutdownAction::Pending {
+            assert!(Instant::now() < deadline, "command ignored shutdown");
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        assert_eq!(dialog.status, CommandStatus::Exited(Some(0)));
+        assert!(dialog.lines.iter().any(|line| line.text == "bye"));
+        assert_eq!(dialog.on_shutdown(), ShutdownAction::Done);
+    }
+
+    #[test]
+    fn spawn_failure_is_an_error() {
+        assert!(CommandDialog::spawn(Command::new("ratkit-no-such-command")).is_err());
+    }
+
+    #[test]
+    fn elapsed_is_formatted_as_clock() {
+        assert_eq!(format_elapsed(Duration::from_secs(75)), "01:15");
+        assert_eq!(format_elapsed(Duration::from_secs(3725)), "1:02:05");
+    }
+}
@@ -0,0 +1,37 @@
+//! Command dialog
+//!
+//! A modal dialog that runs an external command and streams its output,
+//! with a live timer, a Cancel button while running, and the exit status
+//! once the command finishes.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::process::Command;
+//! use ratkit::primitives::dialog::command_dialog::{CommandDialog, CommandDialogEvent};
+//!
+//! let mut command = Command::new("cargo");
+//! command.args(["check", "--color=always"]);
+//! let mut dialog = CommandDialog::spawn(command).unwrap().title("cargo check");
+//!
+//! // On every tick:
+//! if let Some(CommandDialogEvent::Completed { exit_code }) = dialog.poll() {
+//!     println!("finished with {exit_code:?}");
+//! }
+//! ```
+
+mod ansi;
+/// Builder methods for [`CommandDialog`].
+pub mod builders;
+/// Polling, scrolling and input handling for [`CommandDialog`].
+pub mod methods;
+mod process;
+/// Rendering for [`CommandDialog`].
+pub mod render;
+/// Types used by the command dialog.
+pub mod types;
+
+pub use render::CommandDialogWidget;
+pub use types::{
+    CommandDialog, CommandDialogEvent, CommandOutputLine, CommandStatus, CommandStream,
+};
@@ -0,0 +1,167 @@
+use std::io::{self, BufRead, BufReader, Read};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::primitives::dialog::command_dialog::types::CommandStream;
+
+/// How long to keep reading output after the process exited, when
+/// something it started still holds its output open.
+const OUTPUT_GRACE: Duration = Duration::from_millis(200);
+
+pub(crate) enum ProcessMessage {
+    Line(CommandStream, Vec<u8>),
+    Closed,
+}
+
+/// A spawned command with reader threads for stdout and stderr.
+pub(crate) struct RunningProcess {
+    child: Child,
+    receiver: Receiver<ProcessMessage>,
+    open_streams: usize,
+    terminated: bool,
+    /// Exit status, and when it was first seen.
+    exited: Option<(ExitStatus, Instant)>,
+}
+
+fn read_lines<R: Read>(reader: R, stream: CommandStream, sender: Sender<ProcessMessage>) {
+    let mut reader = BufReader::new(reader);
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+        match reader.read_until(b'\n', &mut buf) {
+            Ok(0) | Err(_) => break,
+            Ok(_) => {
+                while matches!(buf.last(), Some(b'\n' | b'\r')) {
+                    buf.pop();
+                }
+                if sender
+                    .send(ProcessMessage::Line(stream, buf.clone()))
+                    .is_err()
+                {
+                    return;
+                }
+            }
+        }
+    }
+    let _ = sender.send(ProcessMessage::Closed);
+}
+
+impl RunningProcess {
+    /// Spawns `command` with piped output in its own process group.
+    pub(crate) fn spawn(mut command: Command) -> io::Result<Self> {
+        command
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::CommandExt;
+            command.process_group(0);
+        }
+
+        let mut child = command.spawn()?;
+        let (sender, receiver) = mpsc::channel();
+        let mut open_streams = 0;
+
+        if let Some(stdout) = child.stdout.take() {
+            let sender = sender.clone();
+            thread::spawn(move || read_lines(stdout, CommandStream::Stdout, sender));
+            open_streams += 1;
+        }
+        if let Some(stderr) = child.stderr.take() {
+            thread::spawn(move || read_lines(stderr, CommandStream::Stderr, sender));
+            open_streams += 1;
+        }
+
+        Ok(Self {
+            child,
+            receiver,
+            open_streams,
+            terminated: false,
+            exited: None,
+        })
+    }
+
+    /// Returns the output lines read so far without blocking.
+    pub(crate) fn drain(&mut self) -> Vec<(CommandStream, Vec<u8>)> {
+        let mut lines = Vec::new();
+        loop {
+            match self.receiver.try_recv() {
+                Ok(ProcessMessage::Line(stream, bytes)) => lines.push((stream, bytes)),
+                Ok(ProcessMessage::Closed) => {
+                    self.open_streams = self.open_streams.saturating_sub(1);
+                }
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    self.open_streams = 0;
+                    break;
+                }
+            }
+        }
+        lines
+    }
+
+    /// Returns the exit status once the process exited and its output was
+    /// read.
+    ///
+    /// Output is read until both pipes close, or for a short grace period
+    /// after the exit if a process it started in the background still
+    /// holds them open. Call [`drain`](Self::drain) first.
+    pub(crate) fn try_finish(&mut self) -> io::Result<Option<ExitStatus>> {
+        let (status, exited_at) = match self.exited {
+            Some(exited) => exited,
+            None => {
+                let Some(status) = self.child.try_wait()? else {
+                    return Ok(None);
+                };
+                *self.exited.insert((status, Instant::now()))
+            }
+        };
+        if self.open_streams > 0 && exited_at.elapsed() < OUTPUT_GRACE {
+            return Ok(None);
+        }
+        Ok(Some(status))
+    }
+
+    /// Asks the process group to exit (SIGTERM on Unix) without waiting.
+    ///
+    /// Only the first call sends the signal.
+    pub(crate) fn terminate(&mut self) {
+        if std::mem::replace(&mut self.terminated, true) {
+            return;
+        }
+        #[cfg(unix)]
+        self.signal_group(nix::sys::signal::Signal::SIGTERM);
+        #[cfg(not(unix))]
+        {
+            let _ = self.child.kill();
+        }
+    }
+
+    /// Kills the process group and reaps the child.
+    pub(crate) fn kill(&mut self) {
+        #[cfg(unix)]
+        self.signal_group(nix::sys::signal::Signal::SIGKILL);
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+
+    /// Sends `signal` to the process group the child leads.
+    #[cfg(unix)]
+    fn signal_group(&self, signal: nix::sys::signal::Signal) {
+        let Ok(pid) = i32::try_from(self.child.id()) else {
+            return;
+        };
+        let _ = nix::sys::signal::killpg(nix::unistd::Pid::from_raw(pid), signal);
+    }
+}
+
+impl Drop for RunningProcess {
+    fn drop(&mut self) {
+        if matches!(self.child.try_wait(), Ok(None)) {
+            self.kill();
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    widgets::Widget,
+};
+
+use crate::primitives::dialog::command_dialog::methods::format_elapsed;
+use crate::primitives::dialog::command_dialog::types::{
+    CommandDialog, CommandOutputLine, CommandStatus,
+};
+use crate::primitives::dialog::render::DialogWidget;
+use crate::primitives::dialog::types::{Dialog, DialogBodyRenderer, DialogType};
+use crate::primitives::progress::{SpinnerFrames, SpinnerState, DEFAULT_SPINNER_INTERVAL};
+
+/// Widget that renders a [`CommandDialog`].
+pub struct CommandDialogWidget<'a> {
+    /// The dialog to render; its scroll and button areas are updated.
+    pub dialog: &'a mut CommandDialog,
+}
+
+impl<'a> CommandDialogWidget<'a> {
+    /// Creates a widget for `dialog`.
+    pub fn new(dialog: &'a mut CommandDialog) -> Self {
+        Self { dialog }
+    }
+}
+
+struct OutputBody<'a> {
+    lines: &'a [CommandOutputLine],
+    scroll: &'a mut usize,
+    follow: bool,
+    body_height: &'a mut u16,
+}
+
+impl DialogBodyRenderer for OutputBody<'_> {
+    fn render_body(&mut self, area: Rect, buf: &mut Buffer) {
+        *self.body_height = area.height;
+        let max_scroll = self.lines.len().saturating_sub(area.height as usize);
+        if self.follow {
+            *self.scroll = max_scroll;
+        }
+        *self.scroll = (*self.scroll).min(max_scroll);
+
+        for (row, output) in self
+            .lines
+            .iter()
+            .skip(*self.scroll)
+            .take(area.height as usize)
+            .enumerate()
+        {
+            buf.set_line(area.x, area.y + row as u16, &output.line, area.width);
+        }
+    }
+}
+
+impl Widget for CommandDialogWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let elapsed = self.dialog.elapsed();
+        let title = match self.dialog.status {
+            CommandStatus::Running => {
+                let spinner = SpinnerState::from_elapsed(elapsed, DEFAULT_SPINNER_INTERVAL);
+                format!(
+                    " {} {} · {} ",
+                    SpinnerFrames::Braille.frame(spinner.frame()),
+                    self.dialog.title,
+                    format_elapsed(elapsed)
+                )
+            }
+            _ => format!(" {} · {} ", self.dialog.title, format_elapsed(elapsed)),
+        };
+        let (dialog_type, footer) = match self.dialog.status {
+            CommandStatus::Running => (DialogType::Info, "Running…".to_string()),
+            CommandStatus::Exited(Some(0)) => {
+                (DialogType::Success, "Exited with code 0".to_string())
+            }
+            CommandStatus::Exited(Some(code)) => {
+                (DialogType::Error, format!("Exited with code {code}"))
+            }
+            CommandStatus::Exited(None) => (DialogType::Error, "Terminated by signal".to_string()),
+            CommandStatus::Cancelled => (DialogType::Warning, "Cancelled".to_string()),
+        };
+        let buttons = self.dialog.buttons().to_vec();
+
+        let CommandDialog {
+            lines,
+            scroll,
+            follow,
+            body_height,
+            selected_button,
+            width_percent,
+            height_percent,
+            ..
+        } = &mut *self.dialog;
+
+        let body = OutputBody {
+            lines,
+            scroll,
+            follow: *follow,
+            body_height,
+        };
+        let mut dialog = Dialog::new(&title, "")
+            .dialog_type(dialog_type)
+            .buttons(buttons)
+            .default_selection(*selected_button)
+            .width_percent(*width_percent)
+            .height_percent(*height_percent)
+            .footer(&footer)
+            .footer_alignment(Alignment::Left)
+            .body_renderer(Box::new(body));
+
+        DialogWidget::new(&mut dialog).render(area, buf);
+        let button_areas = std::mem::take(&mut dialog.button_areas);
+        drop(dialog);
+
+        self.dialog.button_areas = button_areas;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::Widget;
+
+    use super::CommandDialogWidget;
+    use crate::primitives::dialog::command_dialog::{CommandDialog, CommandStream};
+
+    #[cfg(unix)]
+    #[test]
+    fn render_follows_output_and_records_buttons() {
+        let mut dialog = CommandDialog::spawn(std::process::Command::new("true"))
+            .unwrap()
+            .title("demo")
+            .width_percent(1.0)
+            .height_percent(1.0);
+        for i in 0..50 {
+            dialog.push_output(CommandStream::Stdout, format!("line {i}").as_bytes());
+        }
+
+        let area = Rect::new(0, 0, 40, 12);
+        let mut buf = Buffer::empty(area);
+        CommandDialogWidget::new(&mut dialog).render(area, &mut buf);
+
+        assert!(dialog.body_height > 0);
+        assert_eq!(dialog.scroll, 50 - dialog.body_height as usize);
+        assert_eq!(dialog.button_areas.len(), 1);
+
+        let rendered: String = (0..area.height)
+            .map(|y| {
+                (0..area.width)
+                    .map(|x| buf[(x, y)].symbol().to_string())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(rendered.contains("demo"));
+        assert!(rendered.contains("line 49"));
+        assert!(!rendered.contains("line 0 "));
+        dialog.cancel();
+    }
+}
@@ -0,0 +1,90 @@
+use std::time::Instant;
+
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::text::Line;
+
+use crate::primitives::dialog::command_dialog::ansi::AnsiLineParser;
+use crate::primitives::dialog::command_dialog::process::RunningProcess;
+
+/// Output stream a line was read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandStream {
+    /// Standard output.
+    Stdout,
+    /// Standard error.
+    Stderr,
+}
+
+/// A single line of command output.
+#[derive(Debug, Clone)]
+pub struct CommandOutputLine {
+    /// Stream the line was read from.
+    pub stream: CommandStream,
+    /// The line with ANSI colors applied.
+    pub line: Line<'static>,
+    /// The line as plain text, without escape sequences.
+    pub text: String,
+}
+
+/// Lifecycle of the command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandStatus {
+    /// The command is still running.
+    Running,
+    /// The command exited; `None` when it was terminated by a signal.
+    Exited(Option<i32>),
+    /// The command was killed by the user.
+    Cancelled,
+}
+
+/// Events produced by [`CommandDialog`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandDialogEvent {
+    /// The command finished on its own.
+    Completed {
+        /// Exit code, or `None` when terminated by a signal.
+        exit_code: Option<i32>,
+    },
+    /// The command was killed by the user.
+    Cancelled,
+    /// The user asked to copy the output; carries the plain text.
+    CopyOutput(String),
+    /// The user closed the dialog.
+    Close,
+}
+
+/// Modal dialog that runs a command and shows its output.
+pub struct CommandDialog {
+    /// Title shown in the dialog border; defaults to the command line.
+    pub title: String,
+    /// The spawned program and its arguments.
+    pub command_line: String,
+    /// Output lines read so far, in arrival order.
+    pub lines: Vec<CommandOutputLine>,
+    /// Current lifecycle state.
+    pub status: CommandStatus,
+    /// When the command was spawned.
+    pub started_at: Instant,
+    /// When the command exited or was cancelled.
+    pub finished_at: Option<Instant>,
+    /// Index of the first visible output line.
+    pub scroll: usize,
+    /// Whether the view sticks to the newest output.
+    pub follow: bool,
+    /// Index of the focused button.
+    pub selected_button: usize,
+    /// Button areas from the last render, for mouse hit testing.
+    pub button_areas: Vec<Rect>,
+    /// Height of the output area from the last render.
+    pub body_height: u16,
+    /// Dialog width as a fraction of the screen.
+    pub width_percent: f32,
+    /// Dialog height as a fraction of the screen.
+    pub height_percent: f32,
+    /// Base style for stderr lines.
+    pub stderr_style: Style,
+    pub(crate) process: Option<RunningProcess>,
+    pub(crate) stdout_parser: Option<AnsiLineParser>,
+    pub(crate) stderr_parser: Option<AnsiLineParser>,
+}
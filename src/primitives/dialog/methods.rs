@@ -1,5 +1,8 @@
-use crate::primitives::dialog::types::{Dialog, DialogAction, DialogEventResult, DialogType};
+use crate::primitives::dialog::types::{
+    AnchorSide, Dialog, DialogAction, DialogAnchor, DialogEventResult, DialogType,
+};
 use crossterm::event::KeyCode;
+use ratatui::layout::Rect;
 use ratatui::style::Color;
 
 impl<'a> Dialog<'a> {
@@ -57,6 +60,20 @@ impl<'a> Dialog<'a> {
         self.selected_button = index.min(self.buttons.len() - 1);
     }
 
+    /// Moves an anchored dialog to `rect`, e.g. after a resize moved the
+    /// control that opened it.
+    ///
+    /// The position is resolved on every render, so the dialog follows the
+    /// anchor and stays inside the current render area.
+    pub fn set_anchor(&mut self, rect: Rect, side: AnchorSide) {
+        self.anchor = Some(DialogAnchor { rect, side });
+    }
+
+    /// Centers the dialog again.
+    pub fn clear_anchor(&mut self) {
+        self.anchor = None;
+    }
+
     pub fn handle_click(&self, column: u16, row: u16) -> Option<usize> {
         for (idx, area) in self.button_areas.iter().enumerate() {
             if column >= area.x
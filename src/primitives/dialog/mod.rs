@@ -3,13 +3,18 @@
 //! Provides modal dialog widgets with customizable buttons and styles.
 
 pub mod builders;
+#[cfg(feature = "termtui")]
+pub mod command_dialog;
 pub mod methods;
+pub mod placement;
 pub mod render;
 pub mod types;
 
+#[cfg(feature = "termtui")]
+pub use command_dialog::{CommandDialog, CommandDialogEvent, CommandDialogWidget};
 pub use render::DialogWidget;
 pub use types::{
-    Dialog, DialogAction, DialogActionsLayout, DialogBodyRenderer, DialogEventResult, DialogFooter,
-    DialogKeymap, DialogModalMode, DialogPadding, DialogShadow, DialogState, DialogType,
-    DialogWrap,
+    AnchorSide, Dialog, DialogAction, DialogActionsLayout, DialogAnchor, DialogBodyRenderer,
+    DialogEventResult, DialogFooter, DialogKeymap, DialogModalMode, DialogPadding, DialogShadow,
+    DialogState, DialogType, DialogWrap,
 };
@@ -0,0 +1,219 @@
+//! Where a dialog is drawn: centered in the render area, or beside the
+//! rect of the control that opened it.
+
+use crate::primitives::dialog::types::{AnchorSide, DialogAnchor};
+use ratatui::layout::{Position, Rect};
+
+/// A dialog's resolved position within the render area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DialogPlacement {
+    /// Area the dialog, borders included, is drawn in.
+    pub area: Rect,
+    /// The side of the anchor the dialog went on, or `None` if centered.
+    pub side: Option<AnchorSide>,
+    /// The border cell pointing at the anchor and the symbol drawn there.
+    pub notch: Option<(Position, &'static str)>,
+}
+
+/// Places a `width` x `height` dialog in `area`, centered or beside
+/// `anchor`.
+///
+/// The dialog is clamped to `area`. A notch is only drawn when the dialog
+/// sits right against the anchor and the anchor's center lines up with the
+/// dialog's border, away from its corners.
+pub fn place_dialog(
+    area: Rect,
+    width: u16,
+    height: u16,
+    anchor: Option<DialogAnchor>,
+) -> DialogPlacement {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+
+    let Some(anchor) = anchor else {
+        return DialogPlacement {
+            area: Rect {
+                x: area.x + (area.width - width) / 2,
+                y: area.y + (area.height - height) / 2,
+                width,
+                height,
+            },
+            side: None,
+            notch: None,
+        };
+    };
+
+    let side = resolve_side(area, anchor.rect, width, height, anchor.side);
+    let target = anchor.rect;
+    let center_x = target.x.saturating_add(target.width / 2);
+    let center_y = target.y.saturating_add(target.height / 2);
+
+    let (x, y) = match side {
+        AnchorSide::Below => (center_x.saturating_sub(width / 2), target.bottom()),
+        AnchorSide::Above => (
+            center_x.saturating_sub(width / 2),
+            target.y.saturating_sub(height),
+        ),
+        AnchorSide::Right => (target.right(), center_y.saturating_sub(height / 2)),
+        AnchorSide::Left | AnchorSide::Auto => (
+            target.x.saturating_sub(width),
+            center_y.saturating_sub(height / 2),
+        ),
+    };
+    let dialog = Rect {
+        x: x.clamp(area.x, area.right() - width),
+        y: y.clamp(area.y, area.bottom() - height),
+        width,
+        height,
+    };
+
+    let along_x = (dialog.x + 1..dialog.right().saturating_sub(1)).contains(&center_x);
+    let along_y = (dialog.y + 1..dialog.bottom().saturating_sub(1)).contains(&center_y);
+    let notch = match side {
+        AnchorSide::Below if dialog.y == target.bottom() && along_x => {
+            Some((Position::new(center_x, dialog.y), "▲"))
+        }
+        AnchorSide::Above if dialog.bottom() == target.y && along_x => {
+            Some((Position::new(center_x, dialog.bottom() - 1), "▼"))
+        }
+        AnchorSide::Right if dialog.x == target.right() && along_y => {
+            Some((Position::new(dialog.x, center_y), "◀"))
+        }
+        AnchorSide::Left if dialog.right() == target.x && along_y => {
+            Some((Position::new(dialog.right() - 1, center_y), "▶"))
+        }
+        _ => None,
+    };
+
+    DialogPlacement {
+        area: dialog,
+        side: Some(side),
+        notch,
+    }
+}
+
+/// Resolves [`AnchorSide::Auto`] to the side with the most room relative
+/// to the dialog's size along that axis, preferring below, above, right
+/// and then left on ties.
+fn resolve_side(area: Rect, target: Rect, width: u16, height: u16, side: AnchorSide) -> AnchorSide {
+    if side != AnchorSide::Auto {
+        return side;
+    }
+
+    let room = [
+        (
+            AnchorSide::Below,
+            area.bottom().saturating_sub(target.bottom()),
+            height,
+        ),
+        (AnchorSide::Above, target.y.saturating_sub(area.y), height),
+        (
+            AnchorSide::Right,
+            area.right().saturating_sub(target.right()),
+            width,
+        ),
+        (AnchorSide::Left, target.x.saturating_sub(area.x), width),
+    ];
+    let mut best = room[0];
+    for candidate in &room[1..] {
+        // Compare space / size without dividing.
+        let (_, space, size) = *candidate;
+        let (_, best_space, best_size) = best;
+        if u32::from(space) * u32::from(best_size.max(1))
+            > u32::from(best_space) * u32::from(size.max(1))
+        {
+            best = *candidate;
+        }
+    }
+    best.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::dialog::{Dialog, DialogWidget};
+    use ratatui::buffer::Buffer;
+    use ratatui::widgets::Widget;
+
+    const FRAME: Rect = Rect::new(0, 0, 80, 24);
+
+    fn anchor(rect: Rect, side: AnchorSide) -> Option<DialogAnchor> {
+        Some(DialogAnchor { rect, side })
+    }
+
+    #[test]
+    fn test_unanchored_dialog_is_centered() {
+        let placement = place_dialog(FRAME, 40, 10, None);
+        assert_eq!(placement.area, Rect::new(20, 7, 40, 10));
+        assert_eq!(placement.notch, None);
+    }
+
+    #[test]
+    fn test_dialog_sits_beside_its_anchor() {
+        let button = Rect::new(30, 5, 10, 1);
+
+        let below = place_dialog(FRAME, 20, 6, anchor(button, AnchorSide::Below));
+        assert_eq!(below.area, Rect::new(25, 6, 20, 6));
+        assert_eq!(below.notch, Some((Position::new(35, 6), "▲")));
+
+        let above = place_dialog(FRAME, 20, 4, anchor(button, AnchorSide::Above));
+        assert_eq!(above.area, Rect::new(25, 1, 20, 4));
+        assert_eq!(above.notch, Some((Position::new(35, 4), "▼")));
+
+        let row = Rect::new(2, 10, 20, 1);
+        let right = place_dialog(FRAME, 20, 6, anchor(row, AnchorSide::Right));
+        assert_eq!(right.area, Rect::new(22, 7, 20, 6));
+        assert_eq!(right.notch, Some((Position::new(22, 10), "◀")));
+    }
+
+    #[test]
+    fn test_auto_picks_the_roomiest_side() {
+        let near_bottom = Rect::new(30, 21, 10, 1);
+        let placement = place_dialog(FRAME, 20, 6, anchor(near_bottom, AnchorSide::Auto));
+        assert_eq!(placement.side, Some(AnchorSide::Above));
+        assert_eq!(placement.area, Rect::new(25, 15, 20, 6));
+
+        let left_column = Rect::new(0, 0, 10, 24);
+        let placement = place_dialog(FRAME, 20, 6, anchor(left_column, AnchorSide::Auto));
+        assert_eq!(placement.side, Some(AnchorSide::Right));
+    }
+
+    #[test]
+    fn test_dialog_is_clamped_and_notch_dropped_when_detached() {
+        // Against the right edge, the dialog shifts left but still points
+        // at the anchor.
+        let corner_button = Rect::new(74, 0, 6, 1);
+        let placement = place_dialog(FRAME, 20, 6, anchor(corner_button, AnchorSide::Below));
+        assert_eq!(placement.area, Rect::new(60, 1, 20, 6));
+        assert_eq!(placement.notch, Some((Position::new(77, 1), "▲")));
+
+        // No room below: the dialog is pushed up over the anchor and loses
+        // its notch.
+        let last_row = Rect::new(30, 23, 10, 1);
+        let placement = place_dialog(FRAME, 20, 6, anchor(last_row, AnchorSide::Below));
+        assert_eq!(placement.area, Rect::new(25, 18, 20, 6));
+        assert_eq!(placement.notch, None);
+    }
+
+    #[test]
+    fn test_render_follows_resized_area() {
+        let button = Rect::new(10, 2, 8, 1);
+        let mut dialog = Dialog::confirm("Delete", "Sure?")
+            .width_percent(0.5)
+            .height_percent(0.5)
+            .anchored_to(button, AnchorSide::Below);
+
+        let area = Rect::new(0, 0, 40, 12);
+        let mut buf = Buffer::empty(area);
+        DialogWidget::new(&mut dialog).render(area, &mut buf);
+        assert_eq!(buf[(14, 3)].symbol(), "▲");
+
+        // After a resize the app re-sets the anchor; the dialog moves with it.
+        dialog.set_anchor(Rect::new(50, 5, 8, 1), AnchorSide::Below);
+        let area = Rect::new(0, 0, 60, 20);
+        let mut buf = Buffer::empty(area);
+        DialogWidget::new(&mut dialog).render(area, &mut buf);
+        assert_eq!(buf[(54, 6)].symbol(), "▲");
+        assert!(dialog.blocks_background_events());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::primitives::dialog::placement::place_dialog;
 use crate::primitives::dialog::types::Dialog;
 use ratatui::{
     buffer::Buffer,
@@ -27,15 +28,8 @@ impl Widget for DialogWidget<'_, '_> {
 
         let dialog_width = (area.width as f32 * self.dialog.width_percent) as u16;
         let dialog_height = (area.height as f32 * self.dialog.height_percent) as u16;
-        let dialog_x = (area.width.saturating_sub(dialog_width)) / 2;
-        let dialog_y = (area.height.saturating_sub(dialog_height)) / 2;
-
-        let dialog_area = Rect {
-            x: area.x + dialog_x,
-            y: area.y + dialog_y,
-            width: dialog_width,
-            height: dialog_height,
-        };
+        let placement = place_dialog(area, dialog_width, dialog_height, self.dialog.anchor);
+        let dialog_area = placement.area;
 
         if let Some((offset_x, offset_y, shadow_style)) = shadow_spec(self.dialog.shadow) {
             let shadow_area = Rect {
@@ -71,6 +65,12 @@ impl Widget for DialogWidget<'_, '_> {
         let inner = block.inner(dialog_area);
         block.render(dialog_area, buf);
 
+        if let Some((position, symbol)) = placement.notch {
+            buf[position]
+                .set_symbol(symbol)
+                .set_fg(self.dialog.get_border_color());
+        }
+
         let content_inner = inset_rect(
             inner,
             self.dialog.content_padding.horizontal,
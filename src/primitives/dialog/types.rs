@@ -44,6 +44,32 @@ pub enum DialogShadow {
     },
 }
 
+/// Side of its anchor rect that an anchored dialog is placed on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AnchorSide {
+    /// Above the anchor, with a notch on the bottom border.
+    Above,
+    /// Below the anchor, with a notch on the top border.
+    Below,
+    /// Left of the anchor, with a notch on the right border.
+    Left,
+    /// Right of the anchor, with a notch on the left border.
+    Right,
+    /// Whichever side has the most room for the dialog.
+    #[default]
+    Auto,
+}
+
+/// The rect of the control that opened a dialog, and the side of it the
+/// dialog is placed on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DialogAnchor {
+    /// Area of the control, in the same coordinates as the render area.
+    pub rect: Rect,
+    /// Side of the control the dialog goes on.
+    pub side: AnchorSide,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DialogPadding {
     pub horizontal: u16,
@@ -140,6 +166,8 @@ pub struct Dialog<'a> {
     pub selected_button: usize,
     pub width_percent: f32,
     pub height_percent: f32,
+    /// Places the dialog beside this rect instead of centering it.
+    pub anchor: Option<DialogAnchor>,
     pub footer: DialogFooter<'a>,
     pub footer_style: Style,
     pub footer_alignment: Alignment,
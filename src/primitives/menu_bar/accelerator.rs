@@ -0,0 +1,330 @@
+//! Keyboard accelerators that choose menu items while the menu is closed.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use thiserror::Error;
+
+use crate::primitives::menu_bar::event::MenuEvent;
+use crate::primitives::menu_bar::menu_bar::{MenuBar, MenuItem};
+
+/// A key combination bound to a menu item, with the hint shown for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Accelerator {
+    /// The key, with letters lowercased.
+    pub code: KeyCode,
+    /// Modifiers that must be held, including Shift for uppercase letters.
+    pub modifiers: KeyModifiers,
+    /// Hint drawn beside the item, e.g. `Ctrl+S`.
+    pub display: String,
+}
+
+impl Accelerator {
+    /// Creates an accelerator for `key`, shown as `display`.
+    pub fn new(key: KeyEvent, display: &str) -> Self {
+        let (code, modifiers) = normalize(key.code, key.modifiers);
+        Self {
+            code,
+            modifiers,
+            display: display.to_string(),
+        }
+    }
+
+    /// Returns true if `key` is this accelerator's key combination.
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        normalize(key.code, key.modifiers) == (self.code, self.modifiers)
+    }
+}
+
+/// Folds the ways terminals report the same combination into one form:
+/// uppercase letters become lowercase plus Shift, and lock-style
+/// modifiers are dropped.
+fn normalize(code: KeyCode, modifiers: KeyModifiers) -> (KeyCode, KeyModifiers) {
+    let modifiers = modifiers
+        & (KeyModifiers::SHIFT | KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SUPER);
+    match code {
+        KeyCode::Char(c) if c.is_uppercase() => (
+            KeyCode::Char(c.to_lowercase().next().unwrap_or(c)),
+            modifiers | KeyModifiers::SHIFT,
+        ),
+        KeyCode::BackTab => (KeyCode::Tab, modifiers | KeyModifiers::SHIFT),
+        _ => (code, modifiers),
+    }
+}
+
+/// Two menu items bound to the same key combination.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("accelerator {display} is bound to both {first:?} and {second:?}")]
+pub struct AcceleratorConflict {
+    /// Hint of the accelerator being bound.
+    pub display: String,
+    /// Path of the item that already has the accelerator.
+    pub first: Vec<usize>,
+    /// Path of the item the accelerator was bound to again.
+    pub second: Vec<usize>,
+}
+
+impl MenuItem {
+    /// Binds `key` to this item, showing `display` as its hint.
+    ///
+    /// The binding takes effect through [`MenuBar::handle_key`] while no
+    /// dropdown is open. Only items without children can have one.
+    pub fn accelerator(mut self, key: KeyEvent, display: &str) -> Self {
+        self.accelerator = Some(Accelerator::new(key, display));
+        self
+    }
+}
+
+/// Appends the path and accelerator of every item under `items`, depth
+/// first.
+fn collect<'a>(
+    items: &'a [MenuItem],
+    prefix: &mut Vec<usize>,
+    out: &mut Vec<(Vec<usize>, &'a Accelerator)>,
+) {
+    for (index, item) in items.iter().enumerate() {
+        prefix.push(index);
+        if let Some(accelerator) = &item.accelerator {
+            out.push((prefix.clone(), accelerator));
+        }
+        collect(&item.children, prefix, out);
+        prefix.pop();
+    }
+}
+
+impl MenuBar {
+    fn accelerators(&self) -> Vec<(Vec<usize>, &Accelerator)> {
+        let mut out = Vec::new();
+        collect(&self.items, &mut Vec::new(), &mut out);
+        out
+    }
+
+    /// Lists items whose accelerator is already bound to an item earlier in
+    /// the menu.
+    ///
+    /// Only the earlier item responds to a conflicting accelerator.
+    /// [`MenuBar::new`] logs these as warnings.
+    pub fn accelerator_conflicts(&self) -> Vec<AcceleratorConflict> {
+        let accelerators = self.accelerators();
+        accelerators
+            .iter()
+            .enumerate()
+            .filter_map(|(i, (second, accelerator))| {
+                let (first, _) = accelerators[..i].iter().find(|(_, earlier)| {
+                    (earlier.code, earlier.modifiers) == (accelerator.code, accelerator.modifiers)
+                })?;
+                Some(AcceleratorConflict {
+                    display: accelerator.display.clone(),
+                    first: first.clone(),
+                    second: second.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Binds `key` to the item at `path`, showing `display` as its hint.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AcceleratorConflict`] and leaves the item unchanged if
+    /// another item already has the same key combination.
+    pub fn set_accelerator(
+        &mut self,
+        path: &[usize],
+        key: KeyEvent,
+        display: &str,
+    ) -> Result<(), AcceleratorConflict> {
+        let accelerator = Accelerator::new(key, display);
+        if let Some((first, _)) = self.accelerators().into_iter().find(|(other, existing)| {
+            other.as_slice() != path
+                && (existing.code, existing.modifiers) == (accelerator.code, accelerator.modifiers)
+        }) {
+            return Err(AcceleratorConflict {
+                display: accelerator.display,
+                first,
+                second: path.to_vec(),
+            });
+        }
+
+        if let Some(item) = self.item_at_mut(path) {
+            item.accelerator = Some(accelerator);
+        }
+        Ok(())
+    }
+
+    /// Handles a key press, matching accelerators while no dropdown is open.
+    ///
+    /// An accelerator chooses its item as if it were clicked; disabled items
+    /// swallow their accelerator. Mnemonics come next, see
+    /// [`MenuItem::with_mnemonic`]. Other keys, and every key while a
+    /// dropdown is open, go to [`handle_key_event`](Self::handle_key_event).
+    pub fn handle_key(&mut self, key: KeyEvent) -> Option<MenuEvent> {
+        if key.kind == KeyEventKind::Release {
+            return None;
+        }
+        let mnemonic = match key.code {
+            KeyCode::Char(c) => {
+                let others = key.modifiers - KeyModifiers::ALT - KeyModifiers::SHIFT;
+                others.is_empty().then_some(c)
+            }
+            _ => None,
+        };
+        if !self.is_open() {
+            let path = self
+                .accelerators()
+                .into_iter()
+                .find(|(_, accelerator)| accelerator.matches(&key))
+                .map(|(path, _)| path);
+            if let Some(path) = path {
+                if self.item_at(&path).is_some_and(MenuItem::has_children) {
+                    return None;
+                }
+                return self.choose(path);
+            }
+        }
+        if let Some(c) = mnemonic {
+            let alt = key.modifiers.contains(KeyModifiers::ALT);
+            if let Some(event) = self.mnemonic_event(c, alt) {
+                return event;
+            }
+        }
+        self.handle_key_event(key.code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use ratatui::backend::TestBackend;
+    use ratatui::layout::Rect;
+    use ratatui::Terminal;
+
+    use crate::primitives::menu_bar::{MenuBar, MenuEvent, MenuItem};
+
+    fn ctrl(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+    }
+
+    fn menu() -> MenuBar {
+        MenuBar::new(vec![MenuItem::new("File", 0).with_children(vec![
+            MenuItem::new("Open", 10).accelerator(ctrl('o'), "Ctrl+O"),
+            MenuItem::new("Save", 11).accelerator(ctrl('s'), "Ctrl+S"),
+            MenuItem::new("Save As", 12).accelerator(
+                KeyEvent::new(KeyCode::Char('S'), KeyModifiers::CONTROL),
+                "Ctrl+Shift+S",
+            ),
+            MenuItem::new("Recent", 13).with_children(vec![MenuItem::new("a.md", 130)]),
+            MenuItem::new("Quit", 14).accelerator(ctrl('q'), "Ctrl+Q"),
+        ])])
+    }
+
+    fn selected_value(event: Option<MenuEvent>) -> Option<usize> {
+        match event {
+            Some(MenuEvent::Selected { value, .. }) => Some(value),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn accelerators_match_with_their_modifiers() {
+        let mut menu = menu();
+
+        assert_eq!(selected_value(menu.handle_key(ctrl('s'))), Some(11));
+        assert_eq!(
+            selected_value(menu.handle_key(KeyEvent::new(
+                KeyCode::Char('s'),
+                KeyModifiers::CONTROL | KeyModifiers::SHIFT
+            ))),
+            Some(12)
+        );
+        assert_eq!(
+            selected_value(menu.handle_key(KeyEvent::new(
+                KeyCode::Char('S'),
+                KeyModifiers::CONTROL | KeyModifiers::SHIFT
+            ))),
+            Some(12)
+        );
+        assert!(menu
+            .handle_key(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE))
+            .is_none());
+        assert!(menu
+            .handle_key(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::ALT))
+            .is_none());
+    }
+
+    #[test]
+    fn accelerators_are_ignored_while_a_dropdown_is_open() {
+        let mut menu = menu();
+        assert!(matches!(
+            menu.handle_key(KeyEvent::from(KeyCode::Enter)),
+            Some(MenuEvent::Opened { index: 0 })
+        ));
+        assert!(menu.handle_key(ctrl('q')).is_none());
+        assert!(menu.is_open());
+    }
+
+    #[test]
+    fn conflicting_accelerators_are_detected() {
+        let mut menu = MenuBar::new(vec![
+            MenuItem::new("File", 0).with_children(vec![
+                MenuItem::new("Save", 10).accelerator(ctrl('s'), "Ctrl+S")
+            ]),
+            MenuItem::new("Edit", 1).with_children(vec![
+                MenuItem::new("Search", 20).accelerator(ctrl('S'), "Ctrl+Shift+S"),
+                MenuItem::new("Select", 21).accelerator(ctrl('s'), "^S"),
+            ]),
+        ]);
+
+        let conflicts = menu.accelerator_conflicts();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].first, [0, 0]);
+        assert_eq!(conflicts[0].second, [1, 1]);
+        assert_eq!(selected_value(menu.handle_key(ctrl('s'))), Some(10));
+
+        let error = menu
+            .set_accelerator(&[1, 0], ctrl('s'), "Ctrl+S")
+            .unwrap_err();
+        assert_eq!((error.first, error.second), (vec![0, 0], vec![1, 0]));
+        assert_eq!(
+            menu.items[1].children[0]
+                .accelerator
+                .as_ref()
+                .map(|a| a.display.as_str()),
+            Some("Ctrl+Shift+S")
+        );
+
+        // Rebinding an item's own accelerator is not a conflict.
+        menu.set_accelerator(&[1, 1], ctrl('l'), "Ctrl+L").unwrap();
+        menu.set_accelerator(&[0, 0], ctrl('s'), "Ctrl+S").unwrap();
+        assert!(menu.accelerator_conflicts().is_empty());
+    }
+
+    #[test]
+    fn hints_are_right_aligned_in_one_column() {
+        let mut menu = menu();
+        let mut terminal = Terminal::new(TestBackend::new(40, 10)).unwrap();
+        menu.items[0].area = Some(Rect::new(1, 0, 6, 1));
+        menu.area = Some(Rect::new(0, 0, 40, 1));
+        menu.handle_key(KeyEvent::from(KeyCode::Enter));
+        terminal.draw(|frame| menu.render_dropdowns(frame)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let row = |y: u16| -> String {
+            (0..40)
+                .map(|x| buffer[(x, y)].symbol().to_string())
+                .collect()
+        };
+        let ends: Vec<usize> = ["Ctrl+O", "Ctrl+S", "Ctrl+Shift+S", "Ctrl+Q"]
+            .iter()
+            .zip([2, 3, 4, 6])
+            .map(|(hint, y)| {
+                let line = row(y);
+                let start = line
+                    .find(hint)
+                    .unwrap_or_else(|| panic!("{hint} in {line:?}"));
+                line[..start].chars().count() + hint.len()
+            })
+            .collect();
+        assert!(ends.windows(2).all(|pair| pair[0] == pair[1]), "{ends:?}");
+        assert!(row(5).contains("Recent"));
+        assert!(row(5).contains('▸'));
+    }
+}
@@ -0,0 +1,340 @@
+//! Dropdown placement and rendering for nested menus.
+
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, BorderType, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use crate::primitives::menu_bar::menu_bar::{MenuBar, MenuItem};
+use crate::primitives::menu_bar::mnemonic::mnemonic_spans;
+use crate::primitives::menu_bar::util::display_width;
+
+const SUBMENU_ARROW: &str = "▸";
+
+/// Width of the accelerator hint column of a dropdown listing `entries`.
+fn hint_width(entries: &[MenuItem]) -> usize {
+    entries
+        .iter()
+        .filter_map(|entry| entry.accelerator.as_ref())
+        .map(|accelerator| display_width(&accelerator.display))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Outer size of a dropdown listing `entries`: one row per entry, with
+/// room for padding, accelerator hints, a submenu arrow and the border.
+pub(crate) fn dropdown_size(entries: &[MenuItem]) -> (u16, u16) {
+    let label_width = entries
+        .iter()
+        .map(|entry| display_width(&entry.display_label()))
+        .max()
+        .unwrap_or(0);
+    let hints = match hint_width(entries) {
+        0 => 0,
+        width => width + 2,
+    };
+    let width = label_width + hints + 2 + 2 + 2;
+    (width as u16, entries.len() as u16 + 2)
+}
+
+/// Places a dropdown of `width` x `height` below `anchor`, starting at row
+/// `top`.
+///
+/// The dropdown is shifted left when it would run past the right edge of
+/// `bounds`, and cut short at the bottom.
+pub(crate) fn place_below(anchor: Rect, top: u16, width: u16, height: u16, bounds: Rect) -> Rect {
+    let width = width.min(bounds.width);
+    let right = bounds.x + bounds.width;
+    let x = anchor.x.saturating_sub(1).max(bounds.x).min(right - width);
+    let y = top.clamp(bounds.y, bounds.y + bounds.height);
+    let height = height.min(bounds.y + bounds.height - y);
+    Rect::new(x, y, width, height)
+}
+
+/// Places a submenu of `width` x `height` beside the parent entry `anchor`.
+///
+/// The submenu opens to the right, or to the left when there is no room
+/// on the right, and its first entry lines up with the parent entry unless
+/// that would run past the bottom of `bounds`.
+pub(crate) fn place_beside(anchor: Rect, width: u16, height: u16, bounds: Rect) -> Rect {
+    let width = width.min(bounds.width);
+    let height = height.min(bounds.height);
+    let right = bounds.x + bounds.width;
+    let bottom = bounds.y + bounds.height;
+
+    let x = if anchor.x + anchor.width + width <= right {
+        anchor.x + anchor.width
+    } else if anchor.x >= bounds.x + width {
+        anchor.x - width
+    } else {
+        right - width
+    };
+    let y = anchor
+        .y
+        .saturating_sub(1)
+        .max(bounds.y)
+        .min(bottom - height);
+    Rect::new(x, y, width, height)
+}
+
+/// Row of entry `index` in a dropdown at `area`, spanning the border.
+fn entry_row(area: Rect, index: usize) -> Rect {
+    Rect::new(area.x, area.y + 1 + index as u16, area.width, 1)
+}
+
+impl MenuBar {
+    /// Returns true while a dropdown is open.
+    pub fn is_open(&self) -> bool {
+        !self.open.is_empty()
+    }
+
+    /// Returns the index of the top-level item whose dropdown is open.
+    pub fn open_index(&self) -> Option<usize> {
+        self.open.first().copied()
+    }
+
+    /// Returns the path of the highlighted dropdown entry, if any.
+    pub fn highlighted_path(&self) -> Option<Vec<usize>> {
+        let mut path = self.open.clone();
+        path.push(self.highlighted?);
+        Some(path)
+    }
+
+    /// Closes every dropdown.
+    pub fn close(&mut self) {
+        self.open.clear();
+        self.highlighted = None;
+        self.dropdown_areas.clear();
+    }
+
+    pub(crate) fn item_at(&self, path: &[usize]) -> Option<&MenuItem> {
+        let (first, rest) = path.split_first()?;
+        let mut item = self.items.get(*first)?;
+        for &index in rest {
+            item = item.children.get(index)?;
+        }
+        Some(item)
+    }
+
+    pub(crate) fn item_at_mut(&mut self, path: &[usize]) -> Option<&mut MenuItem> {
+        let (first, rest) = path.split_first()?;
+        let mut item = self.items.get_mut(*first)?;
+        for &index in rest {
+            item = item.children.get_mut(index)?;
+        }
+        Some(item)
+    }
+
+    /// Entries of the open dropdown at `level` (0 is below the bar).
+    pub(crate) fn dropdown_entries(&self, level: usize) -> &[MenuItem] {
+        self.item_at(&self.open[..=level])
+            .map(|item| item.children.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Highlighted entry of the open dropdown at `level`: the entry whose
+    /// submenu is open, or the keyboard/hover highlight in the deepest one.
+    pub(crate) fn highlighted_at(&self, level: usize) -> Option<usize> {
+        match self.open.get(level + 1) {
+            Some(&child) => Some(child),
+            None => self.highlighted,
+        }
+    }
+
+    /// Returns the dropdown level and entry index under a cell.
+    pub(crate) fn dropdown_entry_at(&self, column: u16, row: u16) -> Option<(usize, usize)> {
+        self.dropdown_areas
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(level, area)| {
+                let inside = column >= area.x
+                    && column < area.x + area.width
+                    && row > area.y
+                    && row + 1 < area.y + area.height;
+                if !inside {
+                    return None;
+                }
+                let index = (row - area.y - 1) as usize;
+                (index < self.dropdown_entries(level).len()).then_some((level, index))
+            })
+    }
+
+    /// Returns true if the cell lies inside an open dropdown.
+    pub(crate) fn dropdown_contains(&self, column: u16, row: u16) -> bool {
+        self.dropdown_areas.iter().any(|area| {
+            column >= area.x
+                && column < area.x + area.width
+                && row >= area.y
+                && row < area.y + area.height
+        })
+    }
+
+    /// Computes where the open dropdowns go within `bounds`, usually the
+    /// frame area.
+    ///
+    /// Called by [`render_dropdowns`](Self::render_dropdowns); the areas are
+    /// used to hit-test mouse events.
+    pub fn layout_dropdowns(&mut self, bounds: Rect) {
+        self.dropdown_areas.clear();
+        let Some(anchor) = self
+            .open
+            .first()
+            .and_then(|&index| self.items.get(index)?.area)
+        else {
+            return;
+        };
+
+        let top = self
+            .area
+            .map_or(anchor.y + anchor.height, |bar| bar.y + bar.height);
+        let mut anchor = anchor;
+        for level in 0..self.open.len() {
+            let (width, height) = dropdown_size(self.dropdown_entries(level));
+            let area = if level == 0 {
+                place_below(anchor, top, width, height, bounds)
+            } else {
+                place_beside(anchor, width, height, bounds)
+            };
+            self.dropdown_areas.push(area);
+            if let Some(&child) = self.open.get(level + 1) {
+                anchor = entry_row(area, child);
+            }
+        }
+    }
+
+    /// Renders the open dropdowns over whatever is already in the frame.
+    ///
+    /// Call this after everything else has been drawn so the dropdowns stay
+    /// on top.
+    pub fn render_dropdowns(&mut self, frame: &mut Frame) {
+        self.layout_dropdowns(frame.area());
+
+        for (level, &area) in self.dropdown_areas.iter().enumerate() {
+            frame.render_widget(Clear, area);
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .style(self.normal_style);
+            let inner = block.inner(area);
+            frame.render_widget(block, area);
+
+            let highlighted = self.highlighted_at(level);
+            let hint_width = hint_width(self.dropdown_entries(level));
+            for (index, entry) in self
+                .dropdown_entries(level)
+                .iter()
+                .enumerate()
+                .take(inner.height as usize)
+            {
+                let row = Rect::new(inner.x, inner.y + index as u16, inner.width, 1);
+                if entry.separator {
+                    let rule = "─".repeat(inner.width as usize);
+                    frame.render_widget(Paragraph::new(rule).style(self.disabled_style), row);
+                    continue;
+                }
+
+                let style = if !entry.enabled {
+                    self.disabled_style
+                } else if highlighted == Some(index) {
+                    self.highlight_style
+                } else {
+                    self.normal_style
+                };
+                let label = format!(" {}", entry.display_label());
+                // Hints are right-aligned in a column shared by the whole
+                // dropdown, left of the submenu arrow's slot.
+                let hint = entry
+                    .accelerator
+                    .as_ref()
+                    .map_or("", |accelerator| accelerator.display.as_str());
+                let hint = format!("{:>width$}", hint, width = hint_width);
+                let arrow = if entry.has_children() {
+                    SUBMENU_ARROW
+                } else {
+                    " "
+                };
+                let padding = (inner.width as usize).saturating_sub(
+                    display_width(&label) + display_width(&hint) + display_width(arrow) + 1,
+                );
+                let mut spans = mnemonic_spans(label, entry.mnemonic);
+                spans.extend([
+                    Span::raw(" ".repeat(padding)),
+                    Span::raw(hint),
+                    Span::raw(arrow),
+                    Span::raw(" "),
+                ]);
+                let line = Line::from(spans);
+                frame.render_widget(Paragraph::new(line).style(style), row);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::layout::Rect;
+
+    use super::{dropdown_size, place_below, place_beside};
+    use crate::primitives::menu_bar::MenuItem;
+
+    const FRAME: Rect = Rect {
+        x: 0,
+        y: 0,
+        width: 80,
+        height: 24,
+    };
+
+    #[test]
+    fn dropdown_opens_below_its_item() {
+        let anchor = Rect::new(10, 1, 4, 1);
+        assert_eq!(place_below(anchor, 3, 20, 6, FRAME), Rect::new(9, 3, 20, 6));
+    }
+
+    #[test]
+    fn dropdown_near_right_edge_opens_leftwards() {
+        let anchor = Rect::new(74, 1, 4, 1);
+        assert_eq!(
+            place_below(anchor, 3, 20, 6, FRAME),
+            Rect::new(60, 3, 20, 6)
+        );
+    }
+
+    #[test]
+    fn dropdown_is_clamped_to_the_frame() {
+        let anchor = Rect::new(2, 1, 4, 1);
+        let small = Rect::new(0, 0, 12, 8);
+        assert_eq!(
+            place_below(anchor, 3, 20, 10, small),
+            Rect::new(0, 3, 12, 5)
+        );
+    }
+
+    #[test]
+    fn submenu_opens_right_then_left_and_stays_on_screen() {
+        let row = Rect::new(9, 5, 20, 1);
+        assert_eq!(place_beside(row, 15, 5, FRAME), Rect::new(29, 4, 15, 5));
+
+        let near_right = Rect::new(60, 5, 20, 1);
+        assert_eq!(
+            place_beside(near_right, 15, 5, FRAME),
+            Rect::new(45, 4, 15, 5)
+        );
+
+        let near_bottom = Rect::new(9, 22, 20, 1);
+        assert_eq!(
+            place_beside(near_bottom, 15, 5, FRAME),
+            Rect::new(29, 19, 15, 5)
+        );
+    }
+
+    #[test]
+    fn size_fits_the_widest_entry() {
+        let entries = vec![
+            MenuItem::new("Open", 0),
+            MenuItem::separator(),
+            MenuItem::new("Recent", 1).with_children(vec![MenuItem::new("a", 2)]),
+        ];
+        assert_eq!(dropdown_size(&entries), (12, 5));
+    }
+}
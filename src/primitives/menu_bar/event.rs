@@ -0,0 +1,35 @@
+//! Events returned by menu bar dropdown navigation.
+
+/// Result of keyboard or mouse input on a [`MenuBar`](super::MenuBar).
+pub enum MenuEvent {
+    /// The dropdown of the top-level item at `index` was opened.
+    Opened {
+        /// Index of the top-level item.
+        index: usize,
+    },
+    /// All dropdowns were closed without choosing an item.
+    Closed,
+    /// A leaf item was chosen; its dropdowns are closed.
+    Selected {
+        /// Indices from the top-level item down to the chosen item.
+        path: Vec<usize>,
+        /// The chosen item's `value`.
+        value: usize,
+        /// The chosen item's action, taken so it runs at most once.
+        action: Option<Box<dyn FnOnce() + Send>>,
+    },
+}
+
+impl std::fmt::Debug for MenuEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MenuEvent::Opened { index } => write!(f, "MenuEvent::Opened {{ index: {} }}", index),
+            MenuEvent::Closed => write!(f, "MenuEvent::Closed"),
+            MenuEvent::Selected { path, value, .. } => write!(
+                f,
+                "MenuEvent::Selected {{ path: {:?}, value: {} }}",
+                path, value
+            ),
+        }
+    }
+}
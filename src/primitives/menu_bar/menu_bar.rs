@@ -1,8 +1,11 @@
+use crate::primitives::menu_bar::accelerator::Accelerator;
+use crate::primitives::menu_bar::mnemonic::mnemonic_spans;
 use crate::primitives::menu_bar::util::display_width;
 use crate::primitives::widget_event::WidgetEvent;
 
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
 use ratatui::widgets::{Block, BorderType, Borders, Paragraph};
 use ratatui::Frame;
 #[cfg(feature = "theme")]
@@ -16,6 +19,16 @@ pub struct MenuItem {
     pub hovered: bool,
     pub area: Option<Rect>,
     pub action: Option<Box<dyn FnOnce() + Send>>,
+    /// Entries of the dropdown opened by this item; empty for a leaf.
+    pub children: Vec<MenuItem>,
+    /// Disabled items are drawn dimmed and cannot be chosen.
+    pub enabled: bool,
+    /// Separators draw a rule in a dropdown and cannot be chosen.
+    pub separator: bool,
+    /// Key combination that chooses this item while the menu is closed.
+    pub accelerator: Option<Accelerator>,
+    /// Letter underlined in the label that opens or chooses this item.
+    pub mnemonic: Option<char>,
 }
 
 impl MenuItem {
@@ -28,18 +41,18 @@ impl MenuItem {
             hovered: false,
             area: None,
             action: None,
+            children: Vec::new(),
+            enabled: true,
+            separator: false,
+            accelerator: None,
+            mnemonic: None,
         }
     }
 
     pub fn with_icon(name: impl Into<String>, icon: impl Into<String>, value: usize) -> Self {
         Self {
-            name: name.into(),
             icon: Some(icon.into()),
-            value,
-            selected: false,
-            hovered: false,
-            area: None,
-            action: None,
+            ..Self::new(name, value)
         }
     }
 
@@ -49,13 +62,8 @@ impl MenuItem {
         action: impl FnOnce() + Send + 'static,
     ) -> Self {
         Self {
-            name: name.into(),
-            icon: None,
-            value,
-            selected: false,
-            hovered: false,
-            area: None,
             action: Some(Box::new(action)),
+            ..Self::new(name, value)
         }
     }
 
@@ -66,16 +74,45 @@ impl MenuItem {
         action: impl FnOnce() + Send + 'static,
     ) -> Self {
         Self {
-            name: name.into(),
             icon: Some(icon.into()),
-            value,
-            selected: false,
-            hovered: false,
-            area: None,
             action: Some(Box::new(action)),
+            ..Self::new(name, value)
+        }
+    }
+
+    /// Creates a separator line for a dropdown.
+    pub fn separator() -> Self {
+        Self {
+            enabled: false,
+            separator: true,
+            ..Self::new("", 0)
         }
     }
 
+    /// Sets the entries of the dropdown this item opens.
+    ///
+    /// Entries may have children of their own, which open as submenus.
+    pub fn with_children(mut self, children: Vec<MenuItem>) -> Self {
+        self.children = children;
+        self
+    }
+
+    /// Enables or disables the item.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Returns true if the item can be highlighted and chosen.
+    pub fn is_selectable(&self) -> bool {
+        self.enabled && !self.separator
+    }
+
+    /// Returns true if the item opens a dropdown.
+    pub fn has_children(&self) -> bool {
+        !self.children.is_empty()
+    }
+
     pub fn display_label(&self) -> String {
         if let Some(ref icon) = self.icon {
             format!("{} {}", icon, self.name)
@@ -92,11 +129,24 @@ pub struct MenuBar {
     pub selected_style: Style,
     pub hover_style: Style,
     pub selected_hover_style: Style,
+    /// Style of the highlighted dropdown entry.
+    pub highlight_style: Style,
+    /// Style of disabled dropdown entries and separators.
+    pub disabled_style: Style,
+    /// Open dropdowns: the top-level item index, then the index of each
+    /// entry whose submenu is open.
+    pub(crate) open: Vec<usize>,
+    /// Highlighted entry in the deepest open dropdown.
+    pub(crate) highlighted: Option<usize>,
+    /// Outer area of each open dropdown, set by `layout_dropdowns`.
+    pub(crate) dropdown_areas: Vec<Rect>,
 }
 
 impl MenuBar {
+    /// Creates a menu bar, logging a warning for each conflicting
+    /// accelerator (see [`accelerator_conflicts`](Self::accelerator_conflicts)).
     pub fn new(items: Vec<MenuItem>) -> Self {
-        Self {
+        let menu = Self {
             items,
             area: None,
             normal_style: Style::default().fg(Color::White),
@@ -107,7 +157,16 @@ impl MenuBar {
             selected_hover_style: Style::default()
                 .fg(Color::Cyan)
                 .add_modifier(Modifier::BOLD),
+            highlight_style: Style::default().add_modifier(Modifier::REVERSED),
+            disabled_style: Style::default().fg(Color::DarkGray),
+            open: Vec::new(),
+            highlighted: None,
+            dropdown_areas: Vec::new(),
+        };
+        for conflict in menu.accelerator_conflicts() {
+            tracing::warn!("{conflict}");
         }
+        menu
     }
 
     pub fn with_selected(mut self, index: usize) -> Self {
@@ -137,6 +196,18 @@ impl MenuBar {
         self
     }
 
+    /// Sets the style of the highlighted dropdown entry.
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+
+    /// Sets the style of disabled dropdown entries and separators.
+    pub fn disabled_style(mut self, style: Style) -> Self {
+        self.disabled_style = style;
+        self
+    }
+
     #[cfg(feature = "theme")]
     pub fn with_theme(mut self, theme: &AppTheme) -> Self {
         self.normal_style = Style::default().fg(theme.text);
@@ -147,6 +218,8 @@ impl MenuBar {
         self.selected_hover_style = Style::default()
             .fg(theme.primary)
             .add_modifier(Modifier::BOLD);
+        self.highlight_style = Style::default().fg(theme.background).bg(theme.primary);
+        self.disabled_style = Style::default().fg(theme.text_muted);
         self
     }
 
@@ -160,6 +233,8 @@ impl MenuBar {
         self.selected_hover_style = Style::default()
             .fg(theme.primary)
             .add_modifier(Modifier::BOLD);
+        self.highlight_style = Style::default().fg(theme.background).bg(theme.primary);
+        self.disabled_style = Style::default().fg(theme.text_muted);
     }
 
     pub fn update_hover(&mut self, column: u16, row: u16) {
@@ -175,29 +250,6 @@ impl MenuBar {
         }
     }
 
-    pub fn handle_click(&mut self, column: u16, row: u16) -> Option<usize> {
-        let clicked_index = self.items.iter().enumerate().find_map(|(i, item)| {
-            if let Some(area) = item.area {
-                if column >= area.x
-                    && column < area.x + area.width
-                    && row >= area.y
-                    && row < area.y + area.height
-                {
-                    return Some(i);
-                }
-            }
-            None
-        });
-
-        if let Some(clicked) = clicked_index {
-            for (i, item) in self.items.iter_mut().enumerate() {
-                item.selected = i == clicked;
-            }
-        }
-
-        clicked_index
-    }
-
     pub fn handle_mouse(&mut self, column: u16, row: u16) -> WidgetEvent {
         let clicked_index = self.items.iter().enumerate().find_map(|(i, item)| {
             if let Some(area) = item.area {
@@ -299,7 +351,9 @@ impl MenuBar {
             } else {
                 label
             };
-            let paragraph = Paragraph::new(display_label).style(style);
+            let paragraph =
+                Paragraph::new(Line::from(mnemonic_spans(display_label, item.mnemonic)))
+                    .style(style);
             frame.render_widget(paragraph, item_area);
 
             x_offset += actual_item_width;
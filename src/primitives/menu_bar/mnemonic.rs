@@ -0,0 +1,192 @@
+//! Mnemonics: underlined letters that open or choose menu items.
+
+use crossterm::event::KeyCode;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::Span;
+
+use crate::primitives::menu_bar::event::MenuEvent;
+use crate::primitives::menu_bar::menu_bar::{MenuBar, MenuItem};
+
+fn same_letter(a: char, b: char) -> bool {
+    a.to_lowercase().eq(b.to_lowercase())
+}
+
+/// Splits `label` into spans with the first occurrence of `mnemonic`
+/// underlined.
+pub(crate) fn mnemonic_spans(label: String, mnemonic: Option<char>) -> Vec<Span<'static>> {
+    let found = mnemonic.and_then(|mnemonic| {
+        label
+            .char_indices()
+            .find(|&(_, c)| same_letter(c, mnemonic))
+    });
+    let Some((start, c)) = found else {
+        return vec![Span::raw(label)];
+    };
+    let end = start + c.len_utf8();
+    vec![
+        Span::raw(label[..start].to_string()),
+        Span::styled(
+            label[start..end].to_string(),
+            Style::default().add_modifier(Modifier::UNDERLINED),
+        ),
+        Span::raw(label[end..].to_string()),
+    ]
+}
+
+/// Index of the first selectable entry with mnemonic `c`.
+fn find(entries: &[MenuItem], c: char) -> Option<usize> {
+    entries.iter().position(|entry| {
+        entry.is_selectable()
+            && entry
+                .mnemonic
+                .is_some_and(|mnemonic| same_letter(mnemonic, c))
+    })
+}
+
+impl MenuItem {
+    /// Underlines the first occurrence of `c` in the label and lets the
+    /// letter open or choose the item: with Alt on the bar, or on its own
+    /// in an open dropdown.
+    ///
+    /// Letters match case-insensitively. Mnemonics should be unique among
+    /// the items of a bar or dropdown; when they are not, the first item
+    /// with the letter wins.
+    pub fn with_mnemonic(mut self, c: char) -> Self {
+        self.mnemonic = Some(c);
+        self
+    }
+}
+
+impl MenuBar {
+    /// Opens the dropdown of the top-level item with mnemonic `c`, or
+    /// chooses the item and runs its action if it has no dropdown. Call
+    /// this when Alt and `c` are pressed; [`handle_key`](Self::handle_key)
+    /// does so itself.
+    ///
+    /// Returns the index of the item, or `None` if no enabled item has the
+    /// mnemonic.
+    pub fn handle_mnemonic(&mut self, c: char) -> Option<usize> {
+        let index = find(&self.items, c)?;
+        self.close();
+        if let Some(MenuEvent::Selected {
+            action: Some(action),
+            ..
+        }) = self.activate_top(index)
+        {
+            action();
+        }
+        Some(index)
+    }
+
+    /// Handles a mnemonic key from [`handle_key`](Self::handle_key): `c`
+    /// with Alt on the bar, or `c` alone in the deepest open dropdown.
+    ///
+    /// Returns `None` if no item has the mnemonic, so the key is handled
+    /// as usual.
+    pub(crate) fn mnemonic_event(&mut self, c: char, alt: bool) -> Option<Option<MenuEvent>> {
+        if self.is_open() {
+            let level = self.open.len() - 1;
+            if let Some(index) = find(self.dropdown_entries(level), c) {
+                self.highlighted = Some(index);
+                return Some(self.handle_key_event(KeyCode::Enter));
+            }
+        }
+        if !alt {
+            return None;
+        }
+        let index = find(&self.items, c)?;
+        self.close();
+        Some(self.activate_top(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use ratatui::backend::TestBackend;
+    use ratatui::layout::Rect;
+    use ratatui::style::Modifier;
+    use ratatui::Terminal;
+
+    use crate::primitives::menu_bar::{MenuBar, MenuEvent, MenuItem};
+
+    fn alt(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::ALT)
+    }
+
+    fn menu(ran: Arc<AtomicBool>) -> MenuBar {
+        MenuBar::new(vec![
+            MenuItem::new("File", 0)
+                .with_mnemonic('f')
+                .with_children(vec![
+                    MenuItem::new("Open", 10).with_mnemonic('o'),
+                    MenuItem::new("Export", 11)
+                        .with_mnemonic('x')
+                        .with_children(vec![MenuItem::new("PDF", 110).with_mnemonic('p')]),
+                ]),
+            MenuItem::new("Format", 1)
+                .with_mnemonic('o')
+                .with_children(vec![MenuItem::new("Bold", 20)]),
+            MenuItem::with_action("Help", 2, move || ran.store(true, Ordering::SeqCst))
+                .with_mnemonic('H'),
+        ])
+    }
+
+    #[test]
+    fn alt_letters_open_the_bar_and_letters_choose_entries() {
+        let ran = Arc::new(AtomicBool::new(false));
+        let mut bar = menu(ran.clone());
+
+        assert!(matches!(
+            bar.handle_key(alt('F')),
+            Some(MenuEvent::Opened { index: 0 })
+        ));
+        // In the dropdown the letter alone picks the entry, before the
+        // bar item with the same mnemonic.
+        assert!(bar.handle_key(KeyEvent::from(KeyCode::Char('x'))).is_none());
+        assert_eq!(bar.open, [0, 1]);
+        match bar.handle_key(KeyEvent::from(KeyCode::Char('p'))) {
+            Some(MenuEvent::Selected { path, value, .. }) => {
+                assert_eq!((path, value), (vec![0, 1, 0], 110));
+            }
+            other => panic!("expected a selection, got {other:?}"),
+        }
+        assert!(!bar.is_open());
+
+        // Without Alt a letter does nothing while the menu is closed.
+        assert!(bar.handle_key(KeyEvent::from(KeyCode::Char('f'))).is_none());
+        assert_eq!(bar.handle_mnemonic('o'), Some(1));
+        assert_eq!(bar.open_index(), Some(1));
+        assert_eq!(bar.handle_mnemonic('h'), Some(2));
+        assert!(ran.load(Ordering::SeqCst));
+        assert!(!bar.is_open());
+        assert_eq!(bar.handle_mnemonic('z'), None);
+    }
+
+    #[test]
+    fn the_first_matching_letter_is_underlined() {
+        let mut bar = menu(Arc::default());
+        let mut terminal = Terminal::new(TestBackend::new(40, 8)).unwrap();
+        bar.handle_mnemonic('f');
+        terminal
+            .draw(|frame| {
+                bar.render(frame, Rect::new(0, 0, 40, 3));
+                bar.render_dropdowns(frame);
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let underlined: Vec<(u16, u16, String)> = (0..8)
+            .flat_map(|y| (0..40).map(move |x| (x, y)))
+            .filter(|&(x, y)| buffer[(x, y)].modifier.contains(Modifier::UNDERLINED))
+            .map(|(x, y)| (x, y, buffer[(x, y)].symbol().to_string()))
+            .collect();
+        // "File", "F[o]rmat" and "Help" on the bar, then "Open" and
+        // "E[x]port" in the dropdown.
+        let symbols: Vec<&str> = underlined.iter().map(|(_, _, s)| s.as_str()).collect();
+        assert_eq!(symbols, ["F", "o", "H", "O", "x"], "{underlined:?}");
+    }
+}
@@ -1,5 +1,12 @@
+pub mod accelerator;
+pub mod dropdown;
+pub mod event;
 pub mod menu_bar;
+pub mod mnemonic;
+pub mod navigation;
 pub mod util;
 
+pub use accelerator::{Accelerator, AcceleratorConflict};
+pub use event::MenuEvent;
 pub use menu_bar::{MenuBar, MenuItem};
 pub use util::display_width;
@@ -0,0 +1,446 @@
+//! Keyboard and mouse navigation of the bar and its dropdowns.
+
+use crossterm::event::{KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+
+use crate::primitives::menu_bar::event::MenuEvent;
+use crate::primitives::menu_bar::menu_bar::{MenuBar, MenuItem};
+
+/// Next selectable entry after `from`, moving by `step` and wrapping.
+fn next_selectable(entries: &[MenuItem], from: Option<usize>, step: isize) -> Option<usize> {
+    let len = entries.len() as isize;
+    let start = match from {
+        Some(index) => index as isize,
+        None if step > 0 => -1,
+        None => len,
+    };
+    (1..=len)
+        .map(|offset| (start + offset * step).rem_euclid(len) as usize)
+        .find(|&index| entries[index].is_selectable())
+}
+
+impl MenuBar {
+    fn top_item_at(&self, column: u16, row: u16) -> Option<usize> {
+        self.items.iter().position(|item| {
+            item.area.is_some_and(|area| {
+                column >= area.x
+                    && column < area.x + area.width
+                    && row >= area.y
+                    && row < area.y + area.height
+            })
+        })
+    }
+
+    /// Opens the dropdown of the top-level item at `index`, or chooses the
+    /// item if it has no dropdown.
+    pub(crate) fn activate_top(&mut self, index: usize) -> Option<MenuEvent> {
+        let item = self.items.get(index)?;
+        if !item.enabled {
+            return None;
+        }
+        for (i, item) in self.items.iter_mut().enumerate() {
+            item.selected = i == index;
+        }
+        if !self.items[index].has_children() {
+            return self.choose(vec![index]);
+        }
+
+        self.open = vec![index];
+        self.highlighted = next_selectable(&self.items[index].children, None, 1);
+        Some(MenuEvent::Opened { index })
+    }
+
+    /// Moves to the neighbouring top-level item, opening its dropdown if
+    /// one was open.
+    fn move_top(&mut self, step: isize) -> Option<MenuEvent> {
+        let current = self.open.first().copied().or_else(|| self.selected());
+        let next = next_selectable(&self.items, current, step)?;
+        if self.open.is_empty() {
+            for (i, item) in self.items.iter_mut().enumerate() {
+                item.selected = i == next;
+            }
+            return None;
+        }
+
+        self.close();
+        if self.items[next].has_children() {
+            self.activate_top(next)
+        } else {
+            for (i, item) in self.items.iter_mut().enumerate() {
+                item.selected = i == next;
+            }
+            Some(MenuEvent::Closed)
+        }
+    }
+
+    /// Opens the submenu of the highlighted entry.
+    fn open_submenu(&mut self) -> bool {
+        let Some(highlighted) = self.highlighted else {
+            return false;
+        };
+        let mut path = self.open.clone();
+        path.push(highlighted);
+        let Some(entry) = self.item_at(&path) else {
+            return false;
+        };
+        if !entry.has_children() || !entry.is_selectable() {
+            return false;
+        }
+
+        self.highlighted = next_selectable(&entry.children, None, 1);
+        self.open = path;
+        true
+    }
+
+    /// Closes all dropdowns and reports the leaf at `path` as chosen.
+    pub(crate) fn choose(&mut self, path: Vec<usize>) -> Option<MenuEvent> {
+        let item = self.item_at_mut(&path)?;
+        if !item.is_selectable() {
+            return None;
+        }
+        let value = item.value;
+        let action = item.action.take();
+        self.close();
+        Some(MenuEvent::Selected {
+            path,
+            value,
+            action,
+        })
+    }
+
+    /// Handles Left/Right on the bar and Up/Down/Enter/Esc in dropdowns.
+    ///
+    /// With no dropdown open, Enter or Down opens the selected item's
+    /// dropdown. In a dropdown, Up and Down skip separators and disabled
+    /// entries, Right opens a submenu, Left and Esc close one level, and
+    /// Enter chooses the highlighted entry.
+    pub fn handle_key_event(&mut self, key: KeyCode) -> Option<MenuEvent> {
+        if self.open.is_empty() {
+            return match key {
+                KeyCode::Left => self.move_top(-1),
+                KeyCode::Right => self.move_top(1),
+                KeyCode::Down | KeyCode::Enter => {
+                    let index = self.selected().unwrap_or(0);
+                    self.activate_top(index)
+                }
+                _ => None,
+            };
+        }
+
+        let level = self.open.len() - 1;
+        match key {
+            KeyCode::Up | KeyCode::Down => {
+                let step = if key == KeyCode::Up { -1 } else { 1 };
+                let entries = self.dropdown_entries(level);
+                self.highlighted = next_selectable(entries, self.highlighted, step);
+                None
+            }
+            KeyCode::Enter => {
+                if self.open_submenu() {
+                    return None;
+                }
+                let mut path = self.open.clone();
+                path.push(self.highlighted?);
+                self.choose(path)
+            }
+            KeyCode::Right => {
+                if self.open_submenu() || level > 0 {
+                    return None;
+                }
+                self.move_top(1)
+            }
+            KeyCode::Left if level == 0 => self.move_top(-1),
+            KeyCode::Left | KeyCode::Esc if level > 0 => {
+                self.highlighted = self.open.pop();
+                None
+            }
+            KeyCode::Esc => {
+                self.close();
+                Some(MenuEvent::Closed)
+            }
+            _ => None,
+        }
+    }
+
+    /// Handles hover and clicks on the bar and its open dropdowns.
+    ///
+    /// Hovering a dropdown entry highlights it and opens its submenu;
+    /// hovering another top-level item while a dropdown is open switches to
+    /// it. Clicking outside the open dropdowns closes them. Dropdown areas
+    /// come from the last [`render_dropdowns`](Self::render_dropdowns) or
+    /// [`layout_dropdowns`](Self::layout_dropdowns).
+    pub fn handle_mouse_event(&mut self, event: MouseEvent) -> Option<MenuEvent> {
+        let (column, row) = (event.column, event.row);
+        match event.kind {
+            MouseEventKind::Moved => {
+                self.update_hover(column, row);
+                if let Some((level, index)) = self.dropdown_entry_at(column, row) {
+                    self.hover_entry(level, index);
+                    return None;
+                }
+                let top = self.top_item_at(column, row)?;
+                let switch = self.open.first().is_some_and(|&open| open != top)
+                    && self.items[top].has_children();
+                if switch {
+                    self.close();
+                    return self.activate_top(top);
+                }
+                None
+            }
+            MouseEventKind::Down(MouseButton::Left) => {
+                if let Some((level, index)) = self.dropdown_entry_at(column, row) {
+                    self.hover_entry(level, index);
+                    if self.open.len() > level + 1 {
+                        // The entry opened a submenu.
+                        return None;
+                    }
+                    let mut path = self.open.clone();
+                    path.push(index);
+                    return self.choose(path);
+                }
+                if self.dropdown_contains(column, row) {
+                    return None;
+                }
+
+                match self.top_item_at(column, row) {
+                    Some(top) if self.open.first() == Some(&top) => {
+                        self.close();
+                        Some(MenuEvent::Closed)
+                    }
+                    Some(top) => {
+                        self.close();
+                        self.activate_top(top)
+                    }
+                    None if self.is_open() => {
+                        self.close();
+                        Some(MenuEvent::Closed)
+                    }
+                    None => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Handles a left click: opens or closes a dropdown, or chooses a leaf
+    /// item and runs its action.
+    ///
+    /// Returns the chosen item's `value`. Use
+    /// [`handle_mouse_event`](Self::handle_mouse_event) to also learn when
+    /// dropdowns open and close.
+    pub fn handle_click(&mut self, column: u16, row: u16) -> Option<usize> {
+        let click = MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        };
+        match self.handle_mouse_event(click)? {
+            MenuEvent::Selected { value, action, .. } => {
+                if let Some(action) = action {
+                    action();
+                }
+                Some(value)
+            }
+            _ => None,
+        }
+    }
+
+    /// Highlights an entry of the dropdown at `level`, closing deeper
+    /// submenus and opening the entry's own.
+    fn hover_entry(&mut self, level: usize, index: usize) {
+        let Some(entry) = self.dropdown_entries(level).get(index) else {
+            return;
+        };
+        if !entry.is_selectable() {
+            return;
+        }
+
+        self.open.truncate(level + 1);
+        self.dropdown_areas.truncate(level + 1);
+        self.highlighted = Some(index);
+        if self.open_submenu() {
+            self.highlighted = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    use crossterm::event::{KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+    use ratatui::layout::Rect;
+
+    use crate::primitives::menu_bar::{MenuBar, MenuEvent, MenuItem};
+
+    fn menu(ran: Arc<AtomicBool>) -> MenuBar {
+        let file = MenuItem::new("File", 0).with_children(vec![
+            MenuItem::new("New", 10),
+            MenuItem::separator(),
+            MenuItem::new("Save", 11).enabled(false),
+            MenuItem::new("Recent", 12).with_children(vec![
+                MenuItem::new("notes.md", 120),
+                MenuItem::with_action("todo.md", 121, move || ran.store(true, Ordering::SeqCst)),
+            ]),
+            MenuItem::new("Quit", 13),
+        ]);
+        let help = MenuItem::new("Help", 1).with_children(vec![MenuItem::new("About", 20)]);
+        let mut bar = MenuBar::new(vec![file, help]);
+
+        // Item areas as `render` lays out a bar at the top of an 80x24 frame.
+        bar.area = Some(Rect::new(0, 0, 80, 3));
+        bar.items[0].area = Some(Rect::new(2, 1, 4, 1));
+        bar.items[1].area = Some(Rect::new(9, 1, 4, 1));
+        bar
+    }
+
+    fn mouse(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    fn selected(event: Option<MenuEvent>) -> (Vec<usize>, usize) {
+        match event {
+            Some(MenuEvent::Selected { path, value, .. }) => (path, value),
+            other => panic!("expected a selection, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn keyboard_navigation_skips_separators_and_disabled_entries() {
+        let mut bar = menu(Arc::default());
+
+        assert!(matches!(
+            bar.handle_key_event(KeyCode::Enter),
+            Some(MenuEvent::Opened { index: 0 })
+        ));
+        assert_eq!(bar.highlighted_path(), Some(vec![0, 0]));
+
+        bar.handle_key_event(KeyCode::Down);
+        assert_eq!(bar.highlighted_path(), Some(vec![0, 3]));
+        bar.handle_key_event(KeyCode::Up);
+        assert_eq!(bar.highlighted_path(), Some(vec![0, 0]));
+        bar.handle_key_event(KeyCode::Up);
+        assert_eq!(bar.highlighted_path(), Some(vec![0, 4]));
+
+        assert_eq!(
+            selected(bar.handle_key_event(KeyCode::Enter)),
+            (vec![0, 4], 13)
+        );
+        assert!(!bar.is_open());
+    }
+
+    #[test]
+    fn submenus_open_and_close_with_the_keyboard() {
+        let ran = Arc::new(AtomicBool::new(false));
+        let mut bar = menu(ran.clone());
+        bar.handle_key_event(KeyCode::Down);
+        bar.handle_key_event(KeyCode::Down);
+
+        assert!(bar.handle_key_event(KeyCode::Right).is_none());
+        assert_eq!(bar.highlighted_path(), Some(vec![0, 3, 0]));
+        bar.handle_key_event(KeyCode::Esc);
+        assert_eq!(bar.highlighted_path(), Some(vec![0, 3]));
+
+        bar.handle_key_event(KeyCode::Enter);
+        bar.handle_key_event(KeyCode::Down);
+        match bar.handle_key_event(KeyCode::Enter) {
+            Some(MenuEvent::Selected {
+                path,
+                value,
+                action: Some(action),
+            }) => {
+                assert_eq!((path, value), (vec![0, 3, 1], 121));
+                action();
+            }
+            other => panic!("expected a selection with an action, got {:?}", other),
+        }
+        assert!(ran.load(Ordering::SeqCst));
+
+        bar.handle_key_event(KeyCode::Enter);
+        bar.handle_key_event(KeyCode::Right);
+        assert_eq!(bar.highlighted_path(), Some(vec![1, 0]));
+        assert!(matches!(
+            bar.handle_key_event(KeyCode::Esc),
+            Some(MenuEvent::Closed)
+        ));
+    }
+
+    #[test]
+    fn mouse_hover_highlights_and_click_selects() {
+        let mut bar = menu(Arc::default());
+        let bounds = Rect::new(0, 0, 80, 24);
+
+        assert!(matches!(
+            bar.handle_mouse_event(mouse(MouseEventKind::Down(MouseButton::Left), 3, 1)),
+            Some(MenuEvent::Opened { index: 0 })
+        ));
+        bar.layout_dropdowns(bounds);
+        let dropdown = bar.dropdown_areas[0];
+        assert_eq!((dropdown.x, dropdown.y), (1, 3));
+
+        // Hovering "Recent" opens its submenu beside the dropdown.
+        bar.handle_mouse_event(mouse(MouseEventKind::Moved, 4, dropdown.y + 4));
+        bar.layout_dropdowns(bounds);
+        assert_eq!(bar.open, [0, 3]);
+        let submenu = bar.dropdown_areas[1];
+        assert_eq!(submenu.x, dropdown.x + dropdown.width);
+
+        // Disabled entries are not highlighted.
+        bar.handle_mouse_event(mouse(MouseEventKind::Moved, 4, dropdown.y + 3));
+        assert_eq!(bar.open, [0, 3]);
+
+        let (path, value) = selected(bar.handle_mouse_event(mouse(
+            MouseEventKind::Down(MouseButton::Left),
+            submenu.x + 2,
+            submenu.y + 1,
+        )));
+        assert_eq!((path, value), (vec![0, 3, 0], 120));
+        assert!(!bar.is_open());
+    }
+
+    #[test]
+    fn handle_click_opens_dropdowns_and_returns_the_chosen_leaf() {
+        let ran = Arc::new(AtomicBool::new(false));
+        let mut bar = menu(ran.clone());
+        let bounds = Rect::new(0, 0, 80, 24);
+
+        assert_eq!(bar.handle_click(10, 1), None);
+        assert_eq!(bar.open_index(), Some(1));
+        assert_eq!(bar.handle_click(3, 1), None);
+        assert_eq!(bar.open_index(), Some(0));
+        bar.layout_dropdowns(bounds);
+        let dropdown = bar.dropdown_areas[0];
+
+        // A submenu entry opens the submenu; its leaf is chosen.
+        assert_eq!(bar.handle_click(4, dropdown.y + 4), None);
+        bar.layout_dropdowns(bounds);
+        let submenu = bar.dropdown_areas[1];
+        assert_eq!(bar.handle_click(submenu.x + 2, submenu.y + 2), Some(121));
+        assert!(ran.load(Ordering::SeqCst));
+        assert_eq!(bar.open_index(), None);
+
+        // Clicking the open item again closes it.
+        bar.handle_click(3, 1);
+        assert_eq!(bar.handle_click(3, 1), None);
+        assert!(!bar.is_open());
+    }
+
+    #[test]
+    fn clicking_outside_closes_the_dropdown() {
+        let mut bar = menu(Arc::default());
+        bar.handle_key_event(KeyCode::Enter);
+        bar.layout_dropdowns(Rect::new(0, 0, 80, 24));
+
+        assert!(matches!(
+            bar.handle_mouse_event(mouse(MouseEventKind::Down(MouseButton::Left), 60, 20)),
+            Some(MenuEvent::Closed)
+        ));
+        assert!(!bar.is_open());
+    }
+}
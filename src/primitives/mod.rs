@@ -10,6 +10,9 @@ pub mod menu_bar;
 #[cfg(feature = "pane")]
 pub mod pane;
 
+#[cfg(feature = "progress")]
+pub mod progress;
+
 #[cfg(feature = "resizable-grid")]
 pub mod resizable_grid;
 
@@ -22,6 +25,9 @@ pub mod statusline;
 #[cfg(feature = "termtui")]
 pub mod termtui;
 
+#[cfg(feature = "text-input")]
+pub mod text_input;
+
 #[cfg(feature = "toast")]
 pub mod toast;
 
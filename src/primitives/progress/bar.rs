@@ -0,0 +1,314 @@
+//! A one-row progress bar.
+
+use std::time::Duration;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::text::Span;
+use ratatui::widgets::Widget;
+
+use crate::primitives::progress::ProgressState;
+
+/// Columns the bar keeps before the label and texts around it are dropped.
+const MIN_BAR_WIDTH: u16 = 3;
+
+/// Characters a [`ProgressBar`] is drawn with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BarGlyphs {
+    /// A filled cell.
+    pub filled: char,
+    /// An empty cell.
+    pub empty: char,
+    /// Partly filled cells, least filled first. Each cell is split into
+    /// `partial.len() + 1` steps, so more glyphs give a smoother edge.
+    pub partial: &'static [char],
+}
+
+impl BarGlyphs {
+    /// Full blocks with eighth-block edges on a shaded track.
+    pub const BLOCKS: Self = Self {
+        filled: '█',
+        empty: '░',
+        partial: &['▏', '▎', '▍', '▌', '▋', '▊', '▉'],
+    };
+
+    /// Braille dots filled one dot column at a time on a dotted track.
+    pub const BRAILLE: Self = Self {
+        filled: '⣿',
+        empty: '⣀',
+        partial: &['⣇'],
+    };
+
+    /// A heavy line over a light one.
+    pub const LINE: Self = Self {
+        filled: '━',
+        empty: '─',
+        partial: &[],
+    };
+
+    /// Plain ASCII, for terminals without box drawing glyphs.
+    pub const ASCII: Self = Self {
+        filled: '#',
+        empty: '-',
+        partial: &[],
+    };
+}
+
+impl Default for BarGlyphs {
+    fn default() -> Self {
+        Self::BLOCKS
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    /// Done fraction in `0.0..=1.0`.
+    Determinate(f64),
+    /// Animation step of the bouncing segment.
+    Indeterminate(usize),
+}
+
+/// A one-row progress bar with an optional label, percentage and ETA.
+///
+/// Renders as `label ███▌░░░ 42% ETA 0:12` in the first row of its area.
+/// When the area is narrow the ETA, then the label, then the percentage
+/// are left out so the bar keeps at least three columns.
+#[derive(Debug, Clone)]
+pub struct ProgressBar<'a> {
+    mode: Mode,
+    label: Option<Span<'a>>,
+    show_percentage: bool,
+    eta: Option<Duration>,
+    glyphs: BarGlyphs,
+    filled_style: Style,
+    empty_style: Style,
+    text_style: Style,
+}
+
+impl<'a> ProgressBar<'a> {
+    fn with_mode(mode: Mode) -> Self {
+        Self {
+            mode,
+            label: None,
+            show_percentage: false,
+            eta: None,
+            glyphs: BarGlyphs::default(),
+            filled_style: Style::default(),
+            empty_style: Style::default(),
+            text_style: Style::default(),
+        }
+    }
+
+    /// Creates a bar filled to `ratio`, clamped to `0.0..=1.0`.
+    pub fn new(ratio: f64) -> Self {
+        let ratio = if ratio.is_nan() {
+            0.0
+        } else {
+            ratio.clamp(0.0, 1.0)
+        };
+        Self::with_mode(Mode::Determinate(ratio))
+    }
+
+    /// Creates a bar for work of unknown size: a segment that bounces
+    /// between the ends, one column per `tick`.
+    pub fn indeterminate(tick: usize) -> Self {
+        Self::with_mode(Mode::Indeterminate(tick))
+    }
+
+    /// Creates a bar showing `state`'s fraction and ETA, or bouncing with
+    /// its position when it has no total.
+    pub fn from_state(state: &ProgressState) -> Self {
+        match state.fraction() {
+            Some(fraction) => Self::new(fraction).eta(state.eta()),
+            None => Self::indeterminate(state.position() as usize),
+        }
+    }
+
+    /// Sets the text shown before the bar.
+    pub fn label(mut self, label: impl Into<Span<'a>>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Shows the done percentage after the bar of a determinate bar.
+    pub fn show_percentage(mut self, show: bool) -> Self {
+        self.show_percentage = show;
+        self
+    }
+
+    /// Sets the time left shown after the bar.
+    pub fn eta(mut self, eta: Option<Duration>) -> Self {
+        self.eta = eta;
+        self
+    }
+
+    /// Sets the characters the bar is drawn with.
+    pub fn glyphs(mut self, glyphs: BarGlyphs) -> Self {
+        self.glyphs = glyphs;
+        self
+    }
+
+    /// Sets the style of the filled part.
+    pub fn filled_style(mut self, style: Style) -> Self {
+        self.filled_style = style;
+        self
+    }
+
+    /// Sets the style of the empty part.
+    pub fn empty_style(mut self, style: Style) -> Self {
+        self.empty_style = style;
+        self
+    }
+
+    /// Sets the style of the percentage and ETA; the label keeps its own.
+    pub fn text_style(mut self, style: Style) -> Self {
+        self.text_style = style;
+        self
+    }
+
+    fn render_bar(&self, area: Rect, buf: &mut Buffer) {
+        let width = usize::from(area.width);
+        match self.mode {
+            Mode::Determinate(ratio) => {
+                let steps = self.glyphs.partial.len() + 1;
+                let filled = (ratio * (width * steps) as f64).floor() as usize;
+                for column in 0..width {
+                    let units = filled.saturating_sub(column * steps).min(steps);
+                    let (glyph, style) = match units {
+                        0 => (self.glyphs.empty, self.empty_style),
+                        units if units == steps => (self.glyphs.filled, self.filled_style),
+                        units => (self.glyphs.partial[units - 1], self.filled_style),
+                    };
+                    buf[(area.x + column as u16, area.y)]
+                        .set_char(glyph)
+                        .set_style(style);
+                }
+            }
+            Mode::Indeterminate(tick) => {
+                let segment = (width / 4).max(1);
+                let travel = width - segment;
+                let start = if travel == 0 {
+                    0
+                } else {
+                    let step = tick % (2 * travel);
+                    step.min(2 * travel - step)
+                };
+                for column in 0..width {
+                    let (glyph, style) = if (start..start + segment).contains(&column) {
+                        (self.glyphs.filled, self.filled_style)
+                    } else {
+                        (self.glyphs.empty, self.empty_style)
+                    };
+                    buf[(area.x + column as u16, area.y)]
+                        .set_char(glyph)
+                        .set_style(style);
+                }
+            }
+        }
+    }
+}
+
+impl Widget for ProgressBar<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+
+        let percentage = match self.mode {
+            Mode::Determinate(ratio) if self.show_percentage => {
+                Some(format!(" {}%", (ratio * 100.0).floor() as u32))
+            }
+            _ => None,
+        };
+        let eta = self.eta.map(|eta| format!(" ETA {}", format_eta(eta)));
+
+        // Keep the texts in order of importance while the bar stays usable
+        let mut used: u16 = 0;
+        let mut fits = |width: usize| {
+            let width = u16::try_from(width).unwrap_or(u16::MAX);
+            let fits = area.width.saturating_sub(used.saturating_add(width)) >= MIN_BAR_WIDTH;
+            if fits {
+                used += width;
+            }
+            fits
+        };
+        let percentage = percentage.filter(|text| fits(text.len()));
+        let label = self.label.as_ref().filter(|label| fits(label.width() + 1));
+        let eta = eta.filter(|text| fits(text.len()));
+
+        let mut x = area.x;
+        if let Some(label) = label {
+            let end = buf.set_span(x, area.y, label, area.width).0;
+            x = end + 1;
+        }
+        let suffix_width =
+            percentage.as_ref().map_or(0, String::len) + eta.as_ref().map_or(0, String::len);
+        let bar_width = area.right() - x - suffix_width as u16;
+        self.render_bar(Rect::new(x, area.y, bar_width, 1), buf);
+        x += bar_width;
+        for text in [percentage, eta].into_iter().flatten() {
+            x = buf
+                .set_stringn(x, area.y, &text, text.len(), self.text_style)
+                .0;
+        }
+    }
+}
+
+/// Formats a duration as `m:ss`, or `h:mm:ss` from an hour up.
+pub fn format_eta(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes}:{seconds:02}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(bar: ProgressBar<'_>, width: u16) -> String {
+        let area = Rect::new(0, 0, width, 1);
+        let mut buf = Buffer::empty(area);
+        bar.render(area, &mut buf);
+        (0..width).map(|x| buf[(x, 0)].symbol()).collect()
+    }
+
+    #[test]
+    fn test_bar_fills_sub_cells_and_drops_texts_when_narrow() {
+        let bar = || {
+            ProgressBar::new(0.4375)
+                .label("Copy")
+                .show_percentage(true)
+                .eta(Some(Duration::from_secs(75)))
+        };
+        assert_eq!(render(bar(), 26), "Copy ███▌░░░░ 43% ETA 1:15");
+        assert_eq!(render(bar(), 18), "Copy ███▉░░░░░ 43%");
+        assert_eq!(render(bar(), 8), "█▊░░ 43%");
+        assert_eq!(render(bar(), 3), "█▎░");
+
+        let braille = |ratio| render(ProgressBar::new(ratio).glyphs(BarGlyphs::BRAILLE), 4);
+        assert_eq!(braille(0.375), "⣿⣇⣀⣀");
+        assert_eq!(braille(1.0), "⣿⣿⣿⣿");
+        assert_eq!(braille(f64::NAN), "⣀⣀⣀⣀");
+
+        let ascii = |tick| render(ProgressBar::indeterminate(tick).glyphs(BarGlyphs::ASCII), 8);
+        assert_eq!(ascii(0), "##------");
+        assert_eq!(ascii(6), "------##");
+        assert_eq!(ascii(8), "----##--");
+        assert_eq!(
+            render(ProgressBar::indeterminate(5).glyphs(BarGlyphs::ASCII), 3),
+            "-#-"
+        );
+    }
+
+    #[test]
+    fn test_format_eta() {
+        assert_eq!(format_eta(Duration::from_secs(9)), "0:09");
+        assert_eq!(format_eta(Duration::from_secs(3725)), "1:02:05");
+    }
+}
@@ -0,0 +1,42 @@
+//! Progress bars and spinners.
+//!
+//! [`ProgressBar`] draws a one-row bar for work of known size, or a
+//! bouncing segment when the size is unknown. [`Spinner`] cycles through a
+//! frame set for work with no measurable progress. [`ProgressState`]
+//! tracks a position over time and estimates the rate and remaining time.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use ratatui::buffer::Buffer;
+//! use ratatui::layout::Rect;
+//! use ratatui::widgets::{StatefulWidget, Widget};
+//! use ratkit::primitives::progress::{
+//!     BarGlyphs, ProgressBar, ProgressState, Spinner, SpinnerFrames, SpinnerState,
+//! };
+//!
+//! let mut progress = ProgressState::new(200);
+//! progress.inc(50);
+//!
+//! let mut buf = Buffer::empty(Rect::new(0, 0, 40, 2));
+//! ProgressBar::from_state(&progress)
+//!     .label("Downloading")
+//!     .show_percentage(true)
+//!     .glyphs(BarGlyphs::BRAILLE)
+//!     .render(Rect::new(0, 0, 40, 1), &mut buf);
+//!
+//! let mut spinner = SpinnerState::new();
+//! spinner.tick();
+//! Spinner::new()
+//!     .frames(SpinnerFrames::Dots)
+//!     .label("Indexing")
+//!     .render(Rect::new(0, 1, 40, 1), &mut buf, &mut spinner);
+//! ```
+
+mod bar;
+mod spinner;
+mod state;
+
+pub use bar::{format_eta, BarGlyphs, ProgressBar};
+pub use spinner::{Spinner, SpinnerFrames, SpinnerState, DEFAULT_SPINNER_INTERVAL};
+pub use state::{ProgressState, DEFAULT_SAMPLE_WINDOW};
@@ -0,0 +1,207 @@
+//! A tick-driven activity spinner.
+
+use std::time::{Duration, Instant};
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::text::Span;
+use ratatui::widgets::StatefulWidget;
+
+/// Time between frames for [`SpinnerState::tick_at`].
+pub const DEFAULT_SPINNER_INTERVAL: Duration = Duration::from_millis(80);
+
+/// The frames a [`Spinner`] cycles through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SpinnerFrames {
+    /// A dot circling a braille cell: `⠋⠙⠹⠸…`.
+    #[default]
+    Braille,
+    /// A gap circling a full braille cell: `⣾⣽⣻⢿…`.
+    Dots,
+    /// A turning line: `-\|/`.
+    Line,
+    /// Frames of your own, each one column wide.
+    Custom(&'static [&'static str]),
+}
+
+impl SpinnerFrames {
+    /// The frames, in order.
+    pub fn frames(self) -> &'static [&'static str] {
+        match self {
+            Self::Braille => &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+            Self::Dots => &["⣾", "⣽", "⣻", "⢿", "⡿", "⣟", "⣯", "⣷"],
+            Self::Line => &["-", "\\", "|", "/"],
+            Self::Custom(frames) => frames,
+        }
+    }
+
+    /// The frame shown at step `frame`, wrapping around.
+    pub fn frame(self, frame: usize) -> &'static str {
+        let frames = self.frames();
+        if frames.is_empty() {
+            return " ";
+        }
+        frames[frame % frames.len()]
+    }
+}
+
+/// Animation step of a [`Spinner`].
+#[derive(Debug, Clone)]
+pub struct SpinnerState {
+    frame: usize,
+    interval: Duration,
+    last_tick: Option<Instant>,
+}
+
+impl Default for SpinnerState {
+    fn default() -> Self {
+        Self {
+            frame: 0,
+            interval: DEFAULT_SPINNER_INTERVAL,
+            last_tick: None,
+        }
+    }
+}
+
+impl SpinnerState {
+    /// Creates a state at the first frame.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The state `elapsed` into an animation at one frame per `interval`,
+    /// for spinners drawn from a clock rather than ticked.
+    pub fn from_elapsed(elapsed: Duration, interval: Duration) -> Self {
+        let frame = elapsed.as_millis() / interval.as_millis().max(1);
+        Self {
+            frame: frame as usize,
+            interval,
+            last_tick: None,
+        }
+    }
+
+    /// Sets the time between frames for [`tick_at`](Self::tick_at).
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// The current animation step.
+    pub fn frame(&self) -> usize {
+        self.frame
+    }
+
+    /// Advances one frame.
+    pub fn tick(&mut self) {
+        self.frame = self.frame.wrapping_add(1);
+    }
+
+    /// Advances one frame per interval passed since the last frame, e.g.
+    /// from a tick event that fires more often than the interval. Returns
+    /// whether the frame changed.
+    pub fn tick_at(&mut self, now: Instant) -> bool {
+        let Some(last) = self.last_tick else {
+            self.last_tick = Some(now);
+            return false;
+        };
+        let interval = self.interval.as_nanos().max(1);
+        let frames = now.saturating_duration_since(last).as_nanos() / interval;
+        if frames == 0 {
+            return false;
+        }
+        self.frame = self.frame.wrapping_add(frames as usize);
+        self.last_tick = Some(last + self.interval * frames as u32);
+        true
+    }
+}
+
+/// A spinner frame followed by an optional label, on one row.
+#[derive(Debug, Clone, Default)]
+pub struct Spinner<'a> {
+    frames: SpinnerFrames,
+    label: Option<Span<'a>>,
+    style: Style,
+}
+
+impl<'a> Spinner<'a> {
+    /// Creates a braille spinner without a label.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the frames to cycle through.
+    pub fn frames(mut self, frames: SpinnerFrames) -> Self {
+        self.frames = frames;
+        self
+    }
+
+    /// Sets the text shown after the spinner.
+    pub fn label(mut self, label: impl Into<Span<'a>>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the style of the spinner frame.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The frame shown for `state`.
+    pub fn symbol(&self, state: &SpinnerState) -> &'static str {
+        self.frames.frame(state.frame)
+    }
+}
+
+impl StatefulWidget for Spinner<'_> {
+    type State = SpinnerState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let area = area.intersection(buf.area);
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+        let (x, _) = buf.set_stringn(area.x, area.y, self.symbol(state), 1, self.style);
+        if let Some(label) = &self.label {
+            if area.width > 2 {
+                buf.set_span(x + 1, area.y, label, area.width - 2);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spinner_ticks_through_frames() {
+        let start = Instant::now();
+        let mut state = SpinnerState::new().with_interval(Duration::from_millis(100));
+        assert!(!state.tick_at(start));
+        assert!(!state.tick_at(start + Duration::from_millis(90)));
+        assert!(state.tick_at(start + Duration::from_millis(250)));
+        assert_eq!(state.frame(), 2);
+        // The remainder carries over to the next frame.
+        assert!(state.tick_at(start + Duration::from_millis(300)));
+        assert_eq!(state.frame(), 3);
+
+        let spinner = Spinner::new().frames(SpinnerFrames::Line).label("Indexing");
+        assert_eq!(spinner.symbol(&state), "/");
+        state.tick();
+        assert_eq!(spinner.symbol(&state), "-");
+
+        for (width, expected) in [(3, "- I"), (6, "- Inde"), (12, "- Indexing  ")] {
+            let area = Rect::new(0, 0, width, 1);
+            let mut buf = Buffer::empty(area);
+            spinner.clone().render(area, &mut buf, &mut state);
+            let row: String = (0..width).map(|x| buf[(x, 0)].symbol()).collect();
+            assert_eq!(row, expected);
+        }
+
+        let clock =
+            SpinnerState::from_elapsed(Duration::from_millis(450), Duration::from_millis(80));
+        assert_eq!(SpinnerFrames::Braille.frame(clock.frame()), "⠴");
+    }
+}
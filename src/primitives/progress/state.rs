@@ -0,0 +1,170 @@
+//! Tracking progress over time.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Number of recent position samples the rate is averaged over.
+pub const DEFAULT_SAMPLE_WINDOW: usize = 10;
+
+/// The position of a running task, with recent samples to estimate how
+/// fast it moves and when it will finish.
+///
+/// Without a total the task is indeterminate: it has a position and a
+/// rate but no fraction or ETA.
+#[derive(Debug, Clone)]
+pub struct ProgressState {
+    position: u64,
+    total: Option<u64>,
+    /// Recent `(time, position)` samples, oldest first.
+    samples: VecDeque<(Instant, u64)>,
+    window: usize,
+}
+
+impl Default for ProgressState {
+    fn default() -> Self {
+        Self {
+            position: 0,
+            total: None,
+            samples: VecDeque::new(),
+            window: DEFAULT_SAMPLE_WINDOW,
+        }
+    }
+}
+
+impl ProgressState {
+    /// Creates a state for a task of `total` units.
+    pub fn new(total: u64) -> Self {
+        Self {
+            total: Some(total),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a state for a task of unknown size.
+    pub fn indeterminate() -> Self {
+        Self::default()
+    }
+
+    /// Averages the rate over the last `window` samples (at least 2).
+    /// Larger windows give steadier but slower-reacting estimates.
+    pub fn with_sample_window(mut self, window: usize) -> Self {
+        self.window = window.max(2);
+        self
+    }
+
+    /// Units done so far.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Size of the task, if known.
+    pub fn total(&self) -> Option<u64> {
+        self.total
+    }
+
+    /// Sets or clears the size of the task.
+    pub fn set_total(&mut self, total: Option<u64>) {
+        self.total = total;
+    }
+
+    /// Moves to `position` now.
+    pub fn set_position(&mut self, position: u64) {
+        self.set_position_at(position, Instant::now());
+    }
+
+    /// Moves `delta` units forward now.
+    pub fn inc(&mut self, delta: u64) {
+        self.set_position(self.position.saturating_add(delta));
+    }
+
+    /// Moves to `position` at `now`. Moving backwards restarts the rate
+    /// estimate.
+    pub fn set_position_at(&mut self, position: u64, now: Instant) {
+        if position < self.position {
+            self.samples.clear();
+        }
+        self.position = position;
+        self.samples.push_back((now, position));
+        while self.samples.len() > self.window {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Done fraction in `0.0..=1.0`, or `None` without a total. An empty
+    /// task counts as done.
+    pub fn fraction(&self) -> Option<f64> {
+        self.total.map(|total| {
+            if total == 0 {
+                1.0
+            } else {
+                (self.position as f64 / total as f64).min(1.0)
+            }
+        })
+    }
+
+    /// Whether the position reached the total.
+    pub fn is_finished(&self) -> bool {
+        self.total.is_some_and(|total| self.position >= total)
+    }
+
+    /// Units per second over the sample window, or `None` until two
+    /// samples some time apart moved forward.
+    pub fn rate(&self) -> Option<f64> {
+        let (first_time, first_position) = *self.samples.front()?;
+        let (last_time, last_position) = *self.samples.back()?;
+        let elapsed = last_time.duration_since(first_time).as_secs_f64();
+        let moved = last_position.saturating_sub(first_position);
+        (elapsed > 0.0 && moved > 0).then(|| moved as f64 / elapsed)
+    }
+
+    /// Estimated time left at the current [`rate`](Self::rate), or `None`
+    /// without a total or a rate.
+    pub fn eta(&self) -> Option<Duration> {
+        let total = self.total?;
+        if self.position >= total {
+            return Some(Duration::ZERO);
+        }
+        let remaining = (total - self.position) as f64;
+        Some(Duration::from_secs_f64(remaining / self.rate()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eta_is_smoothed_over_the_sample_window() {
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+        let mut state = ProgressState::new(100).with_sample_window(3);
+        state.set_position_at(0, at(0));
+        assert_eq!(state.rate(), None);
+        assert_eq!(state.eta(), None);
+
+        state.set_position_at(10, at(1000));
+        assert_eq!(state.rate(), Some(10.0));
+        assert_eq!(state.eta(), Some(Duration::from_secs(9)));
+
+        // A burst is averaged with the samples before it.
+        state.set_position_at(40, at(2000));
+        assert_eq!(state.rate(), Some(20.0));
+        assert_eq!(state.eta(), Some(Duration::from_secs(3)));
+        // The oldest sample leaves the window.
+        state.set_position_at(50, at(3000));
+        assert_eq!(state.rate(), Some(20.0));
+        assert_eq!(state.fraction(), Some(0.5));
+
+        state.set_position_at(5, at(4000));
+        assert_eq!(state.rate(), None);
+
+        state.set_position_at(120, at(5000));
+        assert!(state.is_finished());
+        assert_eq!(state.fraction(), Some(1.0));
+        assert_eq!(state.eta(), Some(Duration::ZERO));
+
+        let indeterminate = ProgressState::indeterminate();
+        assert_eq!(indeterminate.fraction(), None);
+        assert_eq!(indeterminate.eta(), None);
+    }
+}
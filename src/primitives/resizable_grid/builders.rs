@@ -1,6 +1,10 @@
 //! Constructors for `ResizableGrid`.
 
-use crate::primitives::resizable_grid::types::{LayoutNode, PaneId, ResizableGrid};
+use std::collections::HashMap;
+
+use crate::primitives::resizable_grid::types::{
+    LayoutNode, PaneId, ResizableGrid, DEFAULT_MIN_PANE_COLS, DEFAULT_MIN_PANE_ROWS,
+};
 
 impl ResizableGrid {
     /// Creates a new grid with a single pane as the root.
@@ -37,6 +41,12 @@ impl ResizableGrid {
             hovered_split: None,
             dragging_split: None,
             hit_threshold: 2,
+            min_sizes: HashMap::new(),
+            default_min_size: (DEFAULT_MIN_PANE_COLS, DEFAULT_MIN_PANE_ROWS),
+            focused_pane: pane_id,
+            zoomed_pane: None,
+            #[cfg(all(feature = "serde", feature = "serde_json"))]
+            pane_descriptors: HashMap::new(),
         }
     }
 
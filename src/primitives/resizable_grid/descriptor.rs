@@ -0,0 +1,194 @@
+//! Content descriptors for the panes of a `ResizableGrid`.
+//!
+//! A descriptor is a JSON value an app attaches to a pane to record what
+//! the pane shows, such as a markdown file or a terminal command. Saved
+//! layouts keep the descriptors (see [`persist`](super::persist)), so after
+//! restoring a grid the app can rebuild each pane's content with
+//! [`ResizableGrid::rehydrate_panes`].
+//!
+//! [`PaneDescriptor`] covers the widgets this crate ships. Apps can store
+//! any other JSON value; a descriptor that does not parse as what the app
+//! expects should leave its pane empty rather than fail the restore.
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::primitives::resizable_grid::types::{LayoutNode, PaneId, ResizableGrid};
+
+/// A markdown document shown in a pane.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MarkdownDescriptor {
+    /// Path of the markdown file.
+    pub path: PathBuf,
+    /// Line the view was scrolled to.
+    #[serde(default)]
+    pub scroll: usize,
+}
+
+/// A command running in a terminal pane.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TerminalDescriptor {
+    /// Program to run.
+    pub command: String,
+    /// Arguments passed to the program.
+    #[serde(default)]
+    pub args: Vec<String>,
+}
+
+/// A diff between two revisions shown in a pane.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiffDescriptor {
+    /// Revision the diff starts from.
+    pub base: String,
+    /// Revision the diff goes to.
+    pub head: String,
+    /// File the diff is limited to, if any.
+    #[serde(default)]
+    pub file: Option<PathBuf>,
+}
+
+/// Content of a pane showing one of the widgets this crate ships.
+///
+/// Serializes with a `kind` field naming the variant, e.g.
+/// `{"kind": "markdown", "path": "README.md", "scroll": 0}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PaneDescriptor {
+    /// A markdown document.
+    Markdown(MarkdownDescriptor),
+    /// A terminal command.
+    Terminal(TerminalDescriptor),
+    /// A diff between two revisions.
+    Diff(DiffDescriptor),
+}
+
+impl PaneDescriptor {
+    /// Parses a stored descriptor.
+    ///
+    /// Returns `None` for values of an unknown kind or with missing
+    /// fields, so the pane can be restored empty.
+    pub fn from_value(value: &Value) -> Option<Self> {
+        Self::deserialize(value).ok()
+    }
+
+    /// The descriptor as a JSON value, for
+    /// [`ResizableGrid::set_pane_descriptor`].
+    pub fn to_value(&self) -> Value {
+        serde_json::to_value(self).unwrap_or(Value::Null)
+    }
+}
+
+impl From<MarkdownDescriptor> for PaneDescriptor {
+    fn from(descriptor: MarkdownDescriptor) -> Self {
+        Self::Markdown(descriptor)
+    }
+}
+
+impl From<TerminalDescriptor> for PaneDescriptor {
+    fn from(descriptor: TerminalDescriptor) -> Self {
+        Self::Terminal(descriptor)
+    }
+}
+
+impl From<DiffDescriptor> for PaneDescriptor {
+    fn from(descriptor: DiffDescriptor) -> Self {
+        Self::Diff(descriptor)
+    }
+}
+
+impl ResizableGrid {
+    /// Attaches a content descriptor to a pane, replacing its previous one.
+    ///
+    /// Returns `false` if no pane has the given ID.
+    pub fn set_pane_descriptor(&mut self, pane_id: PaneId, descriptor: Value) -> bool {
+        if self.find_pane_node_index(pane_id).is_none() {
+            return false;
+        }
+        self.pane_descriptors.insert(pane_id, descriptor);
+        true
+    }
+
+    /// The content descriptor of a pane, if it has one.
+    pub fn pane_descriptor(&self, pane_id: PaneId) -> Option<&Value> {
+        self.pane_descriptors.get(&pane_id)
+    }
+
+    /// Removes a pane's content descriptor, returning it.
+    pub fn clear_pane_descriptor(&mut self, pane_id: PaneId) -> Option<Value> {
+        self.pane_descriptors.remove(&pane_id)
+    }
+
+    /// Calls `rehydrate` with each pane and its descriptor, in layout order
+    /// from the top left.
+    ///
+    /// Use this after restoring a grid to rebuild the content of its panes.
+    /// Panes without a descriptor are passed `None`.
+    pub fn rehydrate_panes(&self, mut rehydrate: impl FnMut(PaneId, Option<&Value>)) {
+        let mut stack = vec![self.root_index];
+        while let Some(index) = stack.pop() {
+            match self.nodes.get(index) {
+                Some(LayoutNode::Pane { id }) => rehydrate(*id, self.pane_descriptor(*id)),
+                Some(LayoutNode::Split { first, second, .. }) => {
+                    stack.push(*second);
+                    stack.push(*first);
+                }
+                None => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn descriptors_survive_a_save_and_restore() {
+        let mut grid = ResizableGrid::new(0);
+        let terminal = grid.split_pane_vertically(0).unwrap();
+        let empty = grid.split_pane_horizontally(terminal).unwrap();
+        let markdown = PaneDescriptor::from(MarkdownDescriptor {
+            path: PathBuf::from("README.md"),
+            scroll: 12,
+        });
+        assert!(grid.set_pane_descriptor(0, markdown.to_value()));
+        assert!(grid.set_pane_descriptor(terminal, json!({"kind": "terminal"})));
+        assert!(!grid.set_pane_descriptor(99, json!(null)));
+
+        let json = serde_json::to_string(&grid).unwrap();
+        let restored: ResizableGrid = serde_json::from_str(&json).unwrap();
+
+        let mut panes = Vec::new();
+        restored.rehydrate_panes(|id, descriptor| {
+            panes.push((id, descriptor.and_then(PaneDescriptor::from_value)));
+        });
+        // The terminal descriptor lacks its command, so that pane
+        // restores empty like the one without a descriptor.
+        assert_eq!(
+            panes,
+            vec![(0, Some(markdown)), (terminal, None), (empty, None)]
+        );
+        assert!(restored.pane_descriptor(terminal).is_some());
+    }
+
+    #[test]
+    fn closing_a_pane_drops_its_descriptor() {
+        let mut grid = ResizableGrid::new(0);
+        let right = grid.split_pane_vertically(0).unwrap();
+        grid.set_pane_descriptor(right, json!({"kind": "custom"}));
+        grid.close_pane(right).unwrap();
+        assert!(grid.pane_descriptors.is_empty());
+
+        let json = r#"{
+            "nodes": [{"Pane": {"id": 3}}],
+            "pane_descriptors": {"3": {"kind": "custom"}, "8": {"kind": "custom"}}
+        }"#;
+        let grid: ResizableGrid = serde_json::from_str(json).unwrap();
+        assert_eq!(grid.pane_descriptor(3), Some(&json!({"kind": "custom"})));
+        assert_eq!(grid.pane_descriptors.len(), 1);
+    }
+}
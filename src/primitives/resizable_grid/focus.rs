@@ -0,0 +1,221 @@
+//! Keyboard focus for the panes of a resizable grid.
+//!
+//! One pane of a grid has focus. [`ResizableGrid::focus_direction`] moves
+//! it to the neighbouring pane on screen, so it works for any nesting of
+//! splits.
+
+use std::cmp::Reverse;
+
+use ratatui::layout::Rect;
+
+use crate::primitives::resizable_grid::types::{PaneId, ResizableGrid};
+
+/// A direction to move pane focus in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusDirection {
+    /// Towards the left edge.
+    Left,
+    /// Towards the right edge.
+    Right,
+    /// Towards the top edge.
+    Up,
+    /// Towards the bottom edge.
+    Down,
+}
+
+impl FocusDirection {
+    /// The direction pointing the other way.
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+            Self::Up => Self::Down,
+            Self::Down => Self::Up,
+        }
+    }
+}
+
+impl ResizableGrid {
+    /// The pane with keyboard focus.
+    pub fn focused_pane(&self) -> PaneId {
+        self.focused_pane
+    }
+
+    /// Gives keyboard focus to a pane.
+    ///
+    /// Returns `false` if no pane has the given ID.
+    pub fn focus_pane(&mut self, pane_id: PaneId) -> bool {
+        if self.find_pane_node_index(pane_id).is_none() {
+            return false;
+        }
+        self.focused_pane = pane_id;
+        true
+    }
+
+    /// Moves focus to the pane next to the focused one in `direction`, as
+    /// laid out in `area`.
+    ///
+    /// Returns the newly focused pane, or `None` if focus stayed where it
+    /// was. See [`pane_in_direction`](Self::pane_in_direction) for how the
+    /// pane is chosen.
+    pub fn focus_direction(
+        &mut self,
+        direction: FocusDirection,
+        area: Rect,
+        wrap: bool,
+    ) -> Option<PaneId> {
+        let target = self.pane_in_direction(self.focused_pane, direction, area, wrap)?;
+        self.focused_pane = target;
+        Some(target)
+    }
+
+    /// The pane next to `from` in `direction` when the grid is laid out in
+    /// `area`.
+    ///
+    /// Only panes that share some rows (for left and right) or columns
+    /// (for up and down) with `from` count. The nearest wins; among equally
+    /// near panes, the one sharing the most rows or columns wins. With
+    /// `wrap`, a pane at the edge moves to the farthest pane on the other
+    /// side instead of staying put.
+    pub fn pane_in_direction(
+        &self,
+        from: PaneId,
+        direction: FocusDirection,
+        area: Rect,
+        wrap: bool,
+    ) -> Option<PaneId> {
+        let panes = self.layout_panes(area);
+        let current = panes.iter().find(|pane| pane.pane_id() == from)?.area();
+        let beside = |direction| {
+            panes.iter().filter_map(move |pane| {
+                let area = pane.area();
+                let gap = gap(current, area, direction)?;
+                let overlap = overlap(current, area, direction);
+                (overlap > 0).then_some((pane.pane_id(), gap, overlap, start(area, direction)))
+            })
+        };
+
+        let nearest = beside(direction)
+            .min_by_key(|&(_, gap, overlap, start)| (gap, Reverse(overlap), start));
+        let target = match nearest {
+            Some(nearest) => Some(nearest),
+            None if wrap => beside(direction.opposite())
+                .min_by_key(|&(_, gap, overlap, start)| (Reverse(gap), Reverse(overlap), start)),
+            None => None,
+        };
+        target.map(|(id, ..)| id)
+    }
+}
+
+/// How far `to` lies beyond `from` in `direction`, if it is entirely past
+/// that edge of `from`.
+fn gap(from: Rect, to: Rect, direction: FocusDirection) -> Option<u16> {
+    match direction {
+        FocusDirection::Left => from.left().checked_sub(to.right()),
+        FocusDirection::Right => to.left().checked_sub(from.right()),
+        FocusDirection::Up => from.top().checked_sub(to.bottom()),
+        FocusDirection::Down => to.top().checked_sub(from.bottom()),
+    }
+}
+
+/// How many rows (for left and right) or columns (for up and down) `a`
+/// and `b` share.
+fn overlap(a: Rect, b: Rect, direction: FocusDirection) -> u16 {
+    match direction {
+        FocusDirection::Left | FocusDirection::Right => a
+            .bottom()
+            .min(b.bottom())
+            .saturating_sub(a.top().max(b.top())),
+        FocusDirection::Up | FocusDirection::Down => a
+            .right()
+            .min(b.right())
+            .saturating_sub(a.left().max(b.left())),
+    }
+}
+
+/// Where `area` starts across `direction`, to break remaining ties
+/// towards the top or left.
+fn start(area: Rect, direction: FocusDirection) -> u16 {
+    match direction {
+        FocusDirection::Left | FocusDirection::Right => area.top(),
+        FocusDirection::Up | FocusDirection::Down => area.left(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use FocusDirection::{Down, Left, Right, Up};
+
+    const AREA: Rect = Rect::new(0, 0, 80, 40);
+
+    fn neighbours(grid: &ResizableGrid, from: PaneId, wrap: bool) -> [Option<PaneId>; 4] {
+        [Left, Right, Up, Down].map(|direction| grid.pane_in_direction(from, direction, AREA, wrap))
+    }
+
+    #[test]
+    fn test_focus_moves_around_a_two_by_two_grid() {
+        // 0 | 1
+        // --+--
+        // 2 | 3
+        let mut grid = ResizableGrid::new(0);
+        let right = grid.split_pane_vertically(0).unwrap();
+        grid.split_pane_horizontally(0).unwrap();
+        grid.split_pane_horizontally(right).unwrap();
+
+        assert_eq!(neighbours(&grid, 0, false), [None, Some(1), None, Some(2)]);
+        assert_eq!(neighbours(&grid, 1, false), [Some(0), None, None, Some(3)]);
+        assert_eq!(neighbours(&grid, 2, false), [None, Some(3), Some(0), None]);
+        assert_eq!(neighbours(&grid, 3, false), [Some(2), None, Some(1), None]);
+        assert_eq!(
+            neighbours(&grid, 3, true),
+            [Some(2), Some(2), Some(1), Some(1)]
+        );
+
+        assert_eq!(grid.focused_pane(), 0);
+        assert_eq!(grid.focus_direction(Down, AREA, false), Some(2));
+        assert_eq!(grid.focus_direction(Right, AREA, false), Some(3));
+        assert_eq!(grid.focus_direction(Right, AREA, false), None);
+        assert_eq!(grid.focused_pane(), 3);
+
+        // Closing the focused pane moves focus to the pane taking its space.
+        assert_eq!(grid.close_pane(3), Ok(1));
+        assert_eq!(grid.focused_pane(), 1);
+        assert!(!grid.focus_pane(3));
+    }
+
+    #[test]
+    fn test_ties_go_to_the_pane_sharing_the_most_rows() {
+        //   | 1
+        // 0 |---
+        //   | 2
+        let mut grid = ResizableGrid::new(0);
+        let top = grid.split_pane_vertically(0).unwrap();
+        let bottom = grid.split_pane_horizontally(top).unwrap();
+
+        grid.resize_divider(top, 70);
+        assert_eq!(neighbours(&grid, 0, false), [None, Some(top), None, None]);
+        grid.resize_divider(top, 30);
+        assert_eq!(
+            neighbours(&grid, 0, false),
+            [None, Some(bottom), None, None]
+        );
+        // An even split goes to the upper pane.
+        grid.resize_divider(top, 50);
+        assert_eq!(grid.pane_in_direction(0, Right, AREA, false), Some(top));
+
+        assert_eq!(
+            neighbours(&grid, top, false),
+            [Some(0), None, None, Some(bottom)]
+        );
+        assert_eq!(
+            neighbours(&grid, bottom, true),
+            [Some(0), Some(0), Some(top), Some(top)]
+        );
+        // Nothing shares columns with pane 0, even when wrapping.
+        assert_eq!(
+            neighbours(&grid, 0, true),
+            [Some(top), Some(top), None, None]
+        );
+    }
+}
@@ -3,7 +3,7 @@
 //! Provides methods for calculating pane and divider layouts from a resizable grid.
 
 use crate::primitives::resizable_grid::types::{
-    LayoutNode, PaneId, PaneInfo, ResizableGrid, SplitAreas, SplitAxis, SplitDividerLayout,
+    LayoutNode, PaneId, PaneInfo, ResizableGrid, SplitAreas, SplitDividerLayout,
 };
 use ratatui::layout::Rect;
 
@@ -131,6 +131,12 @@ impl PaneLayout {
 impl ResizableGrid {
     /// Calculates pane rectangles for the current split tree.
     ///
+    /// Splits follow their ratios, moved where needed to keep panes at
+    /// their [minimum size](Self::set_min_size). When `area` is too small
+    /// for every minimum, panes with larger minimums shrink first. While
+    /// the grid is [zoomed](Self::toggle_zoom), the zoomed pane fills
+    /// `area` alone.
+    ///
     /// # Arguments
     /// - `area`: The available rectangle to divide among panes.
     ///
@@ -158,6 +164,10 @@ impl ResizableGrid {
     /// let panes = grid.layout_panes(Rect::new(0, 0, 120, 40));
     /// ```
     pub fn layout_panes(&self, area: Rect) -> Vec<PaneLayout> {
+        if let Some(pane_id) = self.zoomed_pane {
+            return vec![PaneLayout::new(pane_id, area)];
+        }
+
         let mut layouts = Vec::new();
         let mut stack = vec![(self.root_index, area)];
 
@@ -175,44 +185,12 @@ impl ResizableGrid {
                     ratio,
                     first,
                     second,
-                } => match axis {
-                    SplitAxis::Vertical => {
-                        let first_width = ((node_area.width as u32 * *ratio as u32) / 100) as u16;
-                        let second_width = node_area.width.saturating_sub(first_width);
-                        let first_area = Rect {
-                            x: node_area.x,
-                            y: node_area.y,
-                            width: first_width,
-                            height: node_area.height,
-                        };
-                        let second_area = Rect {
-                            x: node_area.x.saturating_add(first_width),
-                            y: node_area.y,
-                            width: second_width,
-                            height: node_area.height,
-                        };
-                        stack.push((*second, second_area));
-                        stack.push((*first, first_area));
-                    }
-                    SplitAxis::Horizontal => {
-                        let first_height = ((node_area.height as u32 * *ratio as u32) / 100) as u16;
-                        let second_height = node_area.height.saturating_sub(first_height);
-                        let first_area = Rect {
-                            x: node_area.x,
-                            y: node_area.y,
-                            width: node_area.width,
-                            height: first_height,
-                        };
-                        let second_area = Rect {
-                            x: node_area.x,
-                            y: node_area.y.saturating_add(first_height),
-                            width: node_area.width,
-                            height: second_height,
-                        };
-                        stack.push((*second, second_area));
-                        stack.push((*first, first_area));
-                    }
-                },
+                } => {
+                    let (first_area, second_area, _) =
+                        self.split_child_areas(node_area, *axis, *ratio, *first, *second);
+                    stack.push((*second, second_area));
+                    stack.push((*first, first_area));
+                }
             }
         }
 
@@ -221,6 +199,8 @@ impl ResizableGrid {
 
     /// Calculates divider metadata for the current split tree.
     ///
+    /// There are no dividers while the grid is [zoomed](Self::toggle_zoom).
+    ///
     /// # Arguments
     /// - `area`: The available rectangle to divide among panes.
     ///
@@ -248,6 +228,10 @@ impl ResizableGrid {
     /// let dividers = grid.layout_dividers(Rect::new(0, 0, 120, 40));
     /// ```
     pub fn layout_dividers(&self, area: Rect) -> Vec<SplitDividerLayout> {
+        if self.zoomed_pane.is_some() {
+            return Vec::new();
+        }
+
         let mut dividers = Vec::new();
         let mut stack = vec![(self.root_index, area)];
 
@@ -263,51 +247,17 @@ impl ResizableGrid {
                 second,
             } = node
             {
+                let (first_area, second_area, offset) =
+                    self.split_child_areas(node_area, *axis, *ratio, *first, *second);
                 dividers.push(SplitDividerLayout {
                     split_index: node_index,
                     axis: *axis,
                     area: node_area,
                     ratio: *ratio,
+                    offset,
                 });
-
-                match axis {
-                    SplitAxis::Vertical => {
-                        let first_width = ((node_area.width as u32 * *ratio as u32) / 100) as u16;
-                        let second_width = node_area.width.saturating_sub(first_width);
-                        let first_area = Rect {
-                            x: node_area.x,
-                            y: node_area.y,
-                            width: first_width,
-                            height: node_area.height,
-                        };
-                        let second_area = Rect {
-                            x: node_area.x.saturating_add(first_width),
-                            y: node_area.y,
-                            width: second_width,
-                            height: node_area.height,
-                        };
-                        stack.push((*second, second_area));
-                        stack.push((*first, first_area));
-                    }
-                    SplitAxis::Horizontal => {
-                        let first_height = ((node_area.height as u32 * *ratio as u32) / 100) as u16;
-                        let second_height = node_area.height.saturating_sub(first_height);
-                        let first_area = Rect {
-                            x: node_area.x,
-                            y: node_area.y,
-                            width: node_area.width,
-                            height: first_height,
-                        };
-                        let second_area = Rect {
-                            x: node_area.x,
-                            y: node_area.y.saturating_add(first_height),
-                            width: node_area.width,
-                            height: second_height,
-                        };
-                        stack.push((*second, second_area));
-                        stack.push((*first, first_area));
-                    }
-                }
+                stack.push((*second, second_area));
+                stack.push((*first, first_area));
             }
         }
 
@@ -0,0 +1,275 @@
+//! Minimum pane sizes for resizable grids.
+//!
+//! Every pane has a minimum width and height, either its own or the grid's
+//! default. Layout moves dividers away from their ratios where needed to
+//! keep panes at their minimums, and [`ResizableGrid::resize_split_within`]
+//! stops a divider at the point where a pane would get smaller.
+
+use ratatui::layout::Rect;
+
+use crate::primitives::resizable_grid::types::{
+    LayoutNode, PaneId, ResizableGrid, SplitAxis, MAX_SPLIT_PERCENT, MIN_SPLIT_PERCENT,
+};
+
+impl ResizableGrid {
+    /// Sets the minimum width and height of a pane, in cells.
+    ///
+    /// Returns `false` if no pane has the given ID.
+    pub fn set_min_size(&mut self, pane_id: PaneId, min_cols: u16, min_rows: u16) -> bool {
+        if self.find_pane_node_index(pane_id).is_none() {
+            return false;
+        }
+        self.min_sizes.insert(pane_id, (min_cols, min_rows));
+        true
+    }
+
+    /// Sets the minimum width and height, in cells, of panes without one of
+    /// their own.
+    pub fn set_default_min_size(&mut self, min_cols: u16, min_rows: u16) {
+        self.default_min_size = (min_cols, min_rows);
+    }
+
+    /// The minimum columns and rows of a pane.
+    pub fn min_size(&self, pane_id: PaneId) -> (u16, u16) {
+        self.min_sizes
+            .get(&pane_id)
+            .copied()
+            .unwrap_or(self.default_min_size)
+    }
+
+    /// Sets a split's ratio like [`resize_split`](Self::resize_split), but
+    /// never past the point where a pane of the grid laid out in `area`
+    /// would fall below its minimum size.
+    ///
+    /// Dragging a divider into a pane's minimum leaves it at the limit.
+    /// When the split has no room to spare, the ratio is only clamped to
+    /// the usual percentage range.
+    pub fn resize_split_within(&mut self, split_index: usize, percent: u16, area: Rect) -> bool {
+        let Some(LayoutNode::Split {
+            axis,
+            first,
+            second,
+            ..
+        }) = self.nodes.get(split_index).cloned()
+        else {
+            return false;
+        };
+        let Some(divider) = self
+            .layout_dividers(area)
+            .into_iter()
+            .find(|divider| divider.split_index() == split_index)
+        else {
+            return self.resize_split(split_index, percent);
+        };
+
+        let extent = u32::from(axis_extent(divider.area(), axis));
+        let first_min = u32::from(self.min_extent(first, axis));
+        let second_min = u32::from(self.min_extent(second, axis));
+        if extent == 0 || first_min + second_min > extent {
+            return self.resize_split(split_index, percent);
+        }
+        // The layout rounds the first child's size down, so round the
+        // lowest ratio up to still cover `first_min`.
+        let lowest = ((first_min * 100 + extent - 1) / extent) as u16;
+        let highest = ((extent - second_min) * 100 / extent) as u16;
+        let lowest = lowest.max(MIN_SPLIT_PERCENT);
+        let highest = highest.min(MAX_SPLIT_PERCENT);
+        if lowest > highest {
+            return self.resize_split(split_index, percent);
+        }
+        self.resize_split(split_index, percent.clamp(lowest, highest))
+    }
+
+    /// Areas of a split's children, and the size of the first along the
+    /// split's axis.
+    pub(super) fn split_child_areas(
+        &self,
+        area: Rect,
+        axis: SplitAxis,
+        ratio: u16,
+        first: usize,
+        second: usize,
+    ) -> (Rect, Rect, u16) {
+        let extent = axis_extent(area, axis);
+        let offset = first_extent(
+            extent,
+            ratio,
+            self.min_extent(first, axis),
+            self.min_extent(second, axis),
+        );
+        let rest = extent - offset;
+        match axis {
+            SplitAxis::Vertical => (
+                Rect {
+                    width: offset,
+                    ..area
+                },
+                Rect {
+                    x: area.x.saturating_add(offset),
+                    width: rest,
+                    ..area
+                },
+                offset,
+            ),
+            SplitAxis::Horizontal => (
+                Rect {
+                    height: offset,
+                    ..area
+                },
+                Rect {
+                    y: area.y.saturating_add(offset),
+                    height: rest,
+                    ..area
+                },
+                offset,
+            ),
+        }
+    }
+
+    /// The fewest cells along `axis` that keep every pane under
+    /// `node_index` at its minimum size.
+    fn min_extent(&self, node_index: usize, axis: SplitAxis) -> u16 {
+        match self.nodes.get(node_index) {
+            None => 0,
+            Some(LayoutNode::Pane { id }) => {
+                let (cols, rows) = self.min_size(*id);
+                match axis {
+                    SplitAxis::Vertical => cols,
+                    SplitAxis::Horizontal => rows,
+                }
+            }
+            Some(LayoutNode::Split {
+                axis: split_axis,
+                first,
+                second,
+                ..
+            }) => {
+                let first = self.min_extent(*first, axis);
+                let second = self.min_extent(*second, axis);
+                if *split_axis == axis {
+                    first.saturating_add(second)
+                } else {
+                    first.max(second)
+                }
+            }
+        }
+    }
+}
+
+/// Width of `area` for vertical splits, height for horizontal ones.
+fn axis_extent(area: Rect, axis: SplitAxis) -> u16 {
+    match axis {
+        SplitAxis::Vertical => area.width,
+        SplitAxis::Horizontal => area.height,
+    }
+}
+
+/// Cells given to the first child of a split `extent` cells long.
+///
+/// The ratio decides, clamped so both children keep their minimums. If
+/// they cannot both fit, the side with the smaller minimum keeps it while
+/// it is no larger than the other side, so the smallest panes are
+/// squeezed last.
+fn first_extent(extent: u16, ratio: u16, first_min: u16, second_min: u16) -> u16 {
+    let wanted = (u32::from(extent) * u32::from(ratio) / 100) as u16;
+    if first_min.saturating_add(second_min) <= extent {
+        return wanted.clamp(first_min, extent - second_min);
+    }
+    let smaller = first_min.min(second_min).min(extent / 2);
+    if first_min <= second_min {
+        smaller
+    } else {
+        extent - smaller
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn areas(grid: &ResizableGrid, area: Rect) -> Vec<(PaneId, Rect)> {
+        grid.layout_panes(area)
+            .iter()
+            .map(|pane| (pane.pane_id(), pane.area()))
+            .collect()
+    }
+
+    /// Left pane 0, and on the right pane 1 above panes 2 and 3 side by side.
+    fn nested() -> ResizableGrid {
+        let mut grid = ResizableGrid::new(0);
+        let right = grid.split_pane_vertically(0).unwrap();
+        let bottom = grid.split_pane_horizontally(right).unwrap();
+        grid.split_pane_vertically(bottom).unwrap();
+        grid
+    }
+
+    #[test]
+    fn test_small_areas_keep_panes_at_their_minimums() {
+        let mut grid = nested();
+        grid.set_min_size(0, 30, 3);
+        grid.resize_split(0, 10);
+        grid.resize_divider(1, 90);
+
+        // Pane 0 keeps 30 columns despite its 10% ratio, and the bottom
+        // row gets 3 rows despite its 10%.
+        let panes = areas(&grid, Rect::new(0, 0, 60, 12));
+        assert_eq!(
+            panes,
+            [
+                (0, Rect::new(0, 0, 30, 12)),
+                (1, Rect::new(30, 0, 30, 9)),
+                (2, Rect::new(30, 9, 15, 3)),
+                (3, Rect::new(45, 9, 15, 3)),
+            ]
+        );
+        // The divider is drawn where the layout put it.
+        let dividers = grid.layout_dividers(Rect::new(0, 0, 60, 12));
+        assert_eq!(dividers[0].ratio(), MIN_SPLIT_PERCENT);
+        assert_eq!(dividers[0].offset(), 30);
+
+        // Shrinking the window keeps every minimum while they fit: 30
+        // columns for pane 0 and 10 each for panes 2 and 3.
+        for width in 50..60 {
+            let area = Rect::new(0, 0, width, 6);
+            for (id, pane) in areas(&grid, area) {
+                let (cols, rows) = grid.min_size(id);
+                assert!(
+                    pane.width >= cols && pane.height >= rows,
+                    "{width}: {pane:?}"
+                );
+            }
+        }
+
+        // Past that, the pane with the larger minimum gives way first.
+        let panes = areas(&grid, Rect::new(0, 0, 40, 6));
+        let widths: Vec<u16> = panes.iter().map(|(_, pane)| pane.width).collect();
+        assert_eq!(widths, [20, 20, 10, 10]);
+        let panes = areas(&grid, Rect::new(0, 0, 8, 2));
+        assert!(panes
+            .iter()
+            .all(|(_, pane)| pane.width <= 8 && pane.height <= 2));
+    }
+
+    #[test]
+    fn test_resize_within_stops_at_the_minimum() {
+        let mut grid = nested();
+        grid.set_default_min_size(12, 2);
+        let area = Rect::new(0, 0, 40, 10);
+
+        // 12 of 40 columns is 30%; pane 0 stops there.
+        assert!(grid.resize_split_within(0, 15, area));
+        assert_eq!(grid.get_split_ratio(0), Some(30));
+        assert_eq!(areas(&grid, area)[0].1.width, 12);
+
+        // The right side needs 24 columns for panes 2 and 3.
+        assert!(grid.resize_split_within(0, 80, area));
+        assert_eq!(grid.get_split_ratio(0), Some(40));
+        assert_eq!(areas(&grid, area)[1].1.width, 24);
+
+        // Without room to spare only the percentage range applies.
+        assert!(grid.resize_split_within(0, 95, Rect::new(0, 0, 30, 10)));
+        assert_eq!(grid.get_split_ratio(0), Some(MAX_SPLIT_PERCENT));
+        assert!(!grid.resize_split_within(7, 50, area));
+        assert!(!grid.set_min_size(9, 1, 1));
+    }
+}
@@ -12,12 +12,27 @@
 //! ```
 
 pub mod builders;
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+pub mod descriptor;
+pub mod focus;
 pub mod layout;
+pub mod min_size;
 pub mod operations;
+#[cfg(feature = "serde")]
+pub mod persist;
 pub mod types;
 pub mod widget;
+pub mod zoom;
 
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+pub use descriptor::{DiffDescriptor, MarkdownDescriptor, PaneDescriptor, TerminalDescriptor};
+pub use focus::FocusDirection;
 pub use layout::PaneLayout;
-pub use types::{PaneId, PaneInfo, ResizableGrid, SplitAreas, SplitAxis, SplitDividerLayout};
+#[cfg(feature = "serde")]
+pub use persist::{SavedLayoutError, LAYOUT_VERSION};
+pub use types::{
+    PaneError, PaneId, PaneInfo, ResizableGrid, SplitAreas, SplitAxis, SplitDividerLayout,
+    DEFAULT_MIN_PANE_COLS, DEFAULT_MIN_PANE_ROWS,
+};
 
-pub use widget::{ResizableGridWidget, ResizableGridWidgetState};
+pub use widget::{PaneFocusKeymap, ResizableGridWidget, ResizableGridWidgetState};
@@ -1,12 +1,13 @@
 use crate::primitives::resizable_grid::types::{
-    LayoutNode, PaneId, ResizableGrid, SplitAxis, SplitDividerLayout, DEFAULT_SPLIT_PERCENT,
-    MAX_SPLIT_PERCENT, MIN_SPLIT_PERCENT,
+    LayoutNode, PaneError, PaneId, ResizableGrid, SplitAxis, SplitDividerLayout,
+    DEFAULT_SPLIT_PERCENT, MAX_SPLIT_PERCENT, MIN_SPLIT_PERCENT,
 };
 use ratatui::layout::Rect;
 
 impl ResizableGrid {
     pub(super) fn split_pane(&mut self, pane_id: PaneId, axis: SplitAxis) -> Option<PaneId> {
         let pane_index = self.find_pane_node_index(pane_id)?;
+        self.zoomed_pane = None;
         let new_pane_id = self.allocate_pane_id();
         let first_index = self.nodes.len();
         let second_index = self.nodes.len().saturating_add(1);
@@ -32,6 +33,9 @@ impl ResizableGrid {
     }
 
     pub fn resize_split(&mut self, split_index: usize, percent: u16) -> bool {
+        if self.zoomed_pane.is_some() {
+            return false;
+        }
         let Some(LayoutNode::Split { ratio, .. }) = self.nodes.get_mut(split_index) else {
             return false;
         };
@@ -41,6 +45,9 @@ impl ResizableGrid {
     }
 
     pub fn resize_divider(&mut self, pane_id: PaneId, percent: u16) -> bool {
+        if self.zoomed_pane.is_some() {
+            return false;
+        }
         let Some((parent_index, is_first)) = self.find_parent_split(pane_id) else {
             return false;
         };
@@ -135,24 +142,87 @@ impl ResizableGrid {
 
         self.nodes[source_index] = LayoutNode::Pane { id: target_id };
         self.nodes[target_index] = LayoutNode::Pane { id: source_id };
+        self.zoomed_pane = None;
 
         true
     }
 
+    /// Exchanges the places of two panes in the tree, at any depth.
+    ///
+    /// Split ratios stay with their places, so each pane takes the other's
+    /// size. Minimum sizes and focus stay with the panes. Swapping ends a
+    /// [zoom](Self::toggle_zoom). Returns `false` if either pane does not
+    /// exist.
+    pub fn swap_panes(&mut self, a: PaneId, b: PaneId) -> bool {
+        self.move_pane(a, b)
+    }
+
+    /// Removes a pane, giving its space to its sibling.
+    ///
+    /// Same as [`close_pane`](Self::close_pane), but only reports whether
+    /// the pane was removed.
     pub fn remove_pane(&mut self, pane_id: PaneId) -> bool {
+        self.close_pane(pane_id).is_ok()
+    }
+
+    /// Closes a pane and gives its space to the sibling subtree.
+    ///
+    /// The parent split is collapsed into the sibling. Returns the ID of the
+    /// first pane in the surviving sibling, which also takes keyboard focus
+    /// if the closed pane had it.
+    ///
+    /// # Errors
+    /// - [`PaneError::PaneNotFound`] if no pane has the given ID.
+    /// - [`PaneError::LastPane`] if the pane is the only pane in the layout.
+    pub fn close_pane(&mut self, pane_id: PaneId) -> Result<PaneId, PaneError> {
+        let Some(pane_index) = self.find_pane_node_index(pane_id) else {
+            return Err(PaneError::PaneNotFound(pane_id));
+        };
+        if pane_index == self.root_index {
+            return Err(PaneError::LastPane(pane_id));
+        }
         let Some((parent_index, is_first)) = self.find_parent_split(pane_id) else {
-            return false;
+            return Err(PaneError::PaneNotFound(pane_id));
         };
         let Some(LayoutNode::Split { first, second, .. }) = self.nodes.get(parent_index) else {
-            return false;
+            return Err(PaneError::PaneNotFound(pane_id));
         };
         let sibling_index = if is_first { *second } else { *first };
         let Some(sibling_node) = self.nodes.get(sibling_index).cloned() else {
-            return false;
+            return Err(PaneError::PaneNotFound(pane_id));
+        };
+        let Some(surviving_id) = self.first_pane_id(sibling_index) else {
+            return Err(PaneError::PaneNotFound(pane_id));
         };
 
         self.nodes[parent_index] = sibling_node;
-        true
+        self.min_sizes.remove(&pane_id);
+        #[cfg(all(feature = "serde", feature = "serde_json"))]
+        self.pane_descriptors.remove(&pane_id);
+        self.zoomed_pane = None;
+        if self.focused_pane == pane_id {
+            self.focused_pane = surviving_id;
+        }
+
+        // Split indices inside the collapsed subtree moved, so drop stale interaction state.
+        for split in [&mut self.hovered_split, &mut self.dragging_split] {
+            if matches!(*split, Some(index) if index == parent_index || index == sibling_index) {
+                *split = None;
+            }
+        }
+
+        Ok(surviving_id)
+    }
+
+    pub(super) fn first_pane_id(&self, node_index: usize) -> Option<PaneId> {
+        let mut node_index = node_index;
+
+        loop {
+            match self.nodes.get(node_index)? {
+                LayoutNode::Pane { id } => return Some(*id),
+                LayoutNode::Split { first, .. } => node_index = *first,
+            }
+        }
     }
 
     pub(super) fn allocate_pane_id(&mut self) -> PaneId {
@@ -215,9 +285,7 @@ impl ResizableGrid {
             let rect = divider.area();
             match divider.axis() {
                 SplitAxis::Vertical => {
-                    let divider_x = rect.x.saturating_add(
-                        ((rect.width as u32 * divider.ratio() as u32) / 100) as u16,
-                    );
+                    let divider_x = rect.x.saturating_add(divider.offset());
                     let distance = divider_x.abs_diff(column);
                     if distance <= threshold
                         && column <= divider_x.saturating_add(threshold)
@@ -228,9 +296,7 @@ impl ResizableGrid {
                     }
                 }
                 SplitAxis::Horizontal => {
-                    let divider_y = rect.y.saturating_add(
-                        ((rect.height as u32 * divider.ratio() as u32) / 100) as u16,
-                    );
+                    let divider_y = rect.y.saturating_add(divider.offset());
                     let distance = divider_y.abs_diff(row);
                     if distance <= threshold
                         && row <= divider_y.saturating_add(threshold)
@@ -267,7 +333,7 @@ impl ResizableGrid {
                     if content_width > 0 {
                         let relative_x = mouse_column.saturating_sub(rect.x);
                         let percent = ((relative_x as u32 * 100) / content_width as u32) as u16;
-                        let _ = self.resize_split(split_index, percent);
+                        let _ = self.resize_split_within(split_index, percent, area);
                     }
                 }
                 SplitAxis::Horizontal => {
@@ -275,7 +341,7 @@ impl ResizableGrid {
                     if content_height > 0 {
                         let relative_y = mouse_row.saturating_sub(rect.y);
                         let percent = ((relative_y as u32 * 100) / content_height as u32) as u16;
-                        let _ = self.resize_split(split_index, percent);
+                        let _ = self.resize_split_within(split_index, percent, area);
                     }
                 }
             }
@@ -283,69 +349,112 @@ impl ResizableGrid {
     }
 
     fn layout_dividers_internal(&self, area: Rect) -> Vec<SplitDividerLayout> {
-        let mut dividers = Vec::new();
-        let mut stack = vec![(self.root_index, area)];
+        self.layout_dividers(area)
+    }
+}
 
-        while let Some((node_index, node_area)) = stack.pop() {
-            let Some(node) = self.nodes.get(node_index) else {
-                continue;
-            };
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-            if let LayoutNode::Split {
-                axis,
-                ratio,
-                first,
-                second,
-            } = node
-            {
-                dividers.push(SplitDividerLayout {
-                    split_index: node_index,
-                    axis: *axis,
-                    area: node_area,
-                    ratio: *ratio,
-                });
-
-                match axis {
-                    SplitAxis::Vertical => {
-                        let first_width = ((node_area.width as u32 * *ratio as u32) / 100) as u16;
-                        let second_width = node_area.width.saturating_sub(first_width);
-                        let first_area = Rect {
-                            x: node_area.x,
-                            y: node_area.y,
-                            width: first_width,
-                            height: node_area.height,
-                        };
-                        let second_area = Rect {
-                            x: node_area.x.saturating_add(first_width),
-                            y: node_area.y,
-                            width: second_width,
-                            height: node_area.height,
-                        };
-                        stack.push((*second, second_area));
-                        stack.push((*first, first_area));
-                    }
-                    SplitAxis::Horizontal => {
-                        let first_height = ((node_area.height as u32 * *ratio as u32) / 100) as u16;
-                        let second_height = node_area.height.saturating_sub(first_height);
-                        let first_area = Rect {
-                            x: node_area.x,
-                            y: node_area.y,
-                            width: node_area.width,
-                            height: first_height,
-                        };
-                        let second_area = Rect {
-                            x: node_area.x,
-                            y: node_area.y.saturating_add(first_height),
-                            width: node_area.width,
-                            height: second_height,
-                        };
-                        stack.push((*second, second_area));
-                        stack.push((*first, first_area));
-                    }
-                }
-            }
-        }
+    fn pane_ids(grid: &ResizableGrid, area: Rect) -> Vec<PaneId> {
+        grid.layout_panes(area)
+            .iter()
+            .map(|pane| pane.pane_id())
+            .collect()
+    }
+
+    #[test]
+    fn test_close_leaf_pane_reclaims_space() {
+        let mut grid = ResizableGrid::new(0);
+        let pane_1 = grid.split_pane_vertically(0).unwrap();
+        let area = Rect::new(0, 0, 100, 40);
+
+        assert_eq!(grid.close_pane(pane_1), Ok(0));
+
+        let panes = grid.layout_panes(area);
+        assert_eq!(panes.len(), 1);
+        assert_eq!(panes[0].pane_id(), 0);
+        assert_eq!(panes[0].area(), area);
+        assert!(grid.layout_dividers(area).is_empty());
+    }
+
+    #[test]
+    fn test_close_pane_with_split_sibling_returns_first_leaf() {
+        let mut grid = ResizableGrid::new(0);
+        let pane_1 = grid.split_pane_vertically(0).unwrap();
+        let pane_2 = grid.split_pane_horizontally(pane_1).unwrap();
+        let area = Rect::new(0, 0, 100, 40);
+
+        assert_eq!(grid.close_pane(0), Ok(pane_1));
+
+        assert_eq!(pane_ids(&grid, area), vec![pane_1, pane_2]);
+        let dividers = grid.layout_dividers(area);
+        assert_eq!(dividers.len(), 1);
+        assert_eq!(dividers[0].axis(), SplitAxis::Horizontal);
+        assert_eq!(dividers[0].area(), area);
+    }
+
+    #[test]
+    fn test_close_inner_pane_keeps_outer_split() {
+        let mut grid = ResizableGrid::new(0);
+        let pane_1 = grid.split_pane_vertically(0).unwrap();
+        let pane_2 = grid.split_pane_horizontally(pane_1).unwrap();
+        let area = Rect::new(0, 0, 100, 40);
+
+        assert_eq!(grid.close_pane(pane_2), Ok(pane_1));
+
+        let panes = grid.layout_panes(area);
+        assert_eq!(panes.len(), 2);
+        assert_eq!(panes[0].pane_id(), 0);
+        assert_eq!(panes[1].pane_id(), pane_1);
+        assert_eq!(panes[1].area(), Rect::new(50, 0, 50, 40));
+        let dividers = grid.layout_dividers(area);
+        assert_eq!(dividers.len(), 1);
+        assert_eq!(dividers[0].axis(), SplitAxis::Vertical);
+    }
+
+    #[test]
+    fn test_remove_pane_clears_state_like_close_pane() {
+        let mut grid = ResizableGrid::new(0);
+        let pane_1 = grid.split_pane_vertically(0).unwrap();
+        grid.set_min_size(pane_1, 30, 5);
+        grid.focus_pane(pane_1);
+        grid.hovered_split = Some(0);
+
+        assert!(grid.remove_pane(pane_1));
+        assert!(!grid.min_sizes.contains_key(&pane_1));
+        assert_eq!(grid.focused_pane(), 0);
+        assert_eq!(grid.hovered_split, None);
+        assert!(!grid.remove_pane(0));
+        assert!(!grid.remove_pane(pane_1));
+    }
+
+    #[test]
+    fn test_close_last_pane_is_error() {
+        let mut grid = ResizableGrid::new(0);
+
+        assert_eq!(grid.close_pane(0), Err(PaneError::LastPane(0)));
+        assert_eq!(pane_ids(&grid, Rect::new(0, 0, 10, 10)), vec![0]);
+    }
+
+    #[test]
+    fn test_close_unknown_pane_is_error() {
+        let mut grid = ResizableGrid::new(0);
+        let _pane_1 = grid.split_pane_vertically(0).unwrap();
+
+        assert_eq!(grid.close_pane(42), Err(PaneError::PaneNotFound(42)));
+    }
+
+    #[test]
+    fn test_closed_pane_cannot_be_closed_again() {
+        let mut grid = ResizableGrid::new(0);
+        let pane_1 = grid.split_pane_vertically(0).unwrap();
 
-        dividers
+        assert!(grid.close_pane(pane_1).is_ok());
+        assert_eq!(
+            grid.close_pane(pane_1),
+            Err(PaneError::PaneNotFound(pane_1))
+        );
     }
 }
@@ -0,0 +1,250 @@
+//! Saving and restoring a `ResizableGrid` with serde.
+//!
+//! A grid serializes as its pane tree: the split nodes with their axes and
+//! ratios, the panes with their IDs, and the pane minimum sizes. With the
+//! `serde_json` feature the panes' content descriptors are saved too.
+//! Hover, drag, focus and zoom state is not saved; a restored grid is
+//! unzoomed and focuses its first pane. Layouts saved by other versions
+//! load as far as they can: unknown fields are ignored, missing ones take
+//! their defaults, and ratios outside the allowed range are clamped into
+//! it.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::primitives::resizable_grid::types::{
+    LayoutNode, PaneId, ResizableGrid, MAX_SPLIT_PERCENT, MIN_SPLIT_PERCENT,
+};
+
+/// Version of the saved layout format written by this crate.
+pub const LAYOUT_VERSION: u32 = 1;
+
+/// Reasons a saved layout cannot be restored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum SavedLayoutError {
+    /// The layout has no nodes.
+    #[error("Layout has no panes")]
+    Empty,
+
+    /// The root or a split refers to a node that does not exist.
+    #[error("Layout node {0} does not exist")]
+    MissingNode(usize),
+
+    /// A node is reached from more than one split, so the nodes do not
+    /// form a tree.
+    #[error("Layout node {0} is used more than once")]
+    SharedNode(usize),
+}
+
+/// The saved form of a [`ResizableGrid`].
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+struct SavedGrid {
+    version: u32,
+    root_index: usize,
+    nodes: Vec<LayoutNode>,
+    next_pane_id: Option<PaneId>,
+    hit_threshold: u16,
+    min_sizes: HashMap<PaneId, (u16, u16)>,
+    default_min_size: (u16, u16),
+    #[cfg(all(feature = "serde", feature = "serde_json"))]
+    pane_descriptors: HashMap<PaneId, serde_json::Value>,
+}
+
+impl Default for SavedGrid {
+    fn default() -> Self {
+        let grid = ResizableGrid::new(0);
+        Self {
+            version: LAYOUT_VERSION,
+            root_index: grid.root_index,
+            nodes: grid.nodes,
+            next_pane_id: None,
+            hit_threshold: grid.hit_threshold,
+            min_sizes: grid.min_sizes,
+            default_min_size: grid.default_min_size,
+            #[cfg(all(feature = "serde", feature = "serde_json"))]
+            pane_descriptors: grid.pane_descriptors,
+        }
+    }
+}
+
+impl From<ResizableGrid> for SavedGrid {
+    fn from(grid: ResizableGrid) -> Self {
+        Self {
+            version: LAYOUT_VERSION,
+            root_index: grid.root_index,
+            nodes: grid.nodes,
+            next_pane_id: Some(grid.next_pane_id),
+            hit_threshold: grid.hit_threshold,
+            min_sizes: grid.min_sizes,
+            default_min_size: grid.default_min_size,
+            #[cfg(all(feature = "serde", feature = "serde_json"))]
+            pane_descriptors: grid.pane_descriptors,
+        }
+    }
+}
+
+impl TryFrom<SavedGrid> for ResizableGrid {
+    type Error = SavedLayoutError;
+
+    fn try_from(saved: SavedGrid) -> Result<Self, Self::Error> {
+        let SavedGrid {
+            root_index,
+            mut nodes,
+            next_pane_id,
+            hit_threshold,
+            mut min_sizes,
+            default_min_size,
+            #[cfg(all(feature = "serde", feature = "serde_json"))]
+            mut pane_descriptors,
+            ..
+        } = saved;
+        if nodes.is_empty() {
+            return Err(SavedLayoutError::Empty);
+        }
+
+        // Every node reachable from the root must exist and be reached once.
+        let mut seen = vec![false; nodes.len()];
+        let mut stack = vec![root_index];
+        let mut max_id = None;
+        let mut first_id = None;
+        while let Some(index) = stack.pop() {
+            let seen = seen
+                .get_mut(index)
+                .ok_or(SavedLayoutError::MissingNode(index))?;
+            if std::mem::replace(seen, true) {
+                return Err(SavedLayoutError::SharedNode(index));
+            }
+            match &mut nodes[index] {
+                LayoutNode::Pane { id } => {
+                    max_id = max_id.max(Some(*id));
+                    first_id = first_id.or(Some(*id));
+                }
+                LayoutNode::Split {
+                    ratio,
+                    first,
+                    second,
+                    ..
+                } => {
+                    *ratio = (*ratio).clamp(MIN_SPLIT_PERCENT, MAX_SPLIT_PERCENT);
+                    stack.push(*second);
+                    stack.push(*first);
+                }
+            }
+        }
+
+        // Drop minimums and descriptors of panes the layout does not have.
+        let has_pane = |id: &PaneId| {
+            nodes
+                .iter()
+                .any(|node| matches!(node, LayoutNode::Pane { id: pane } if pane == id))
+        };
+        min_sizes.retain(|id, _| has_pane(id));
+        #[cfg(all(feature = "serde", feature = "serde_json"))]
+        pane_descriptors.retain(|id, _| has_pane(id));
+
+        // New panes must not reuse a saved pane's ID.
+        let after_max = max_id.map_or(0, |id: PaneId| id.saturating_add(1));
+        Ok(Self {
+            root_index,
+            nodes,
+            next_pane_id: next_pane_id.unwrap_or(after_max).max(after_max),
+            hovered_split: None,
+            dragging_split: None,
+            hit_threshold,
+            min_sizes,
+            default_min_size,
+            focused_pane: first_id.unwrap_or_default(),
+            zoomed_pane: None,
+            #[cfg(all(feature = "serde", feature = "serde_json"))]
+            pane_descriptors,
+        })
+    }
+}
+
+impl Serialize for ResizableGrid {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SavedGrid::from(self.clone()).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ResizableGrid {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let saved = SavedGrid::deserialize(deserializer)?;
+        Self::try_from(saved).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::layout::Rect;
+
+    use super::*;
+    use crate::primitives::resizable_grid::ResizableGridWidgetState;
+
+    fn panes(grid: &ResizableGrid) -> Vec<(PaneId, Rect)> {
+        grid.layout_panes(Rect::new(0, 0, 120, 40))
+            .iter()
+            .map(|pane| (pane.pane_id(), pane.area()))
+            .collect()
+    }
+
+    #[test]
+    fn nested_layouts_round_trip() {
+        let mut grid = ResizableGrid::new(0);
+        let right = grid.split_pane_vertically(0).unwrap();
+        let bottom = grid.split_pane_horizontally(right).unwrap();
+        grid.split_pane_vertically(bottom).unwrap();
+        grid.resize_divider(0, 30);
+        grid.resize_divider(right, 70);
+        grid.hovered_split = Some(0);
+
+        let json = serde_json::to_string(&grid).unwrap();
+        let restored: ResizableGrid = serde_json::from_str(&json).unwrap();
+        assert_eq!(panes(&restored).len(), 4);
+        assert_eq!(panes(&restored), panes(&grid));
+        assert_eq!(restored.next_pane_id, grid.next_pane_id);
+        assert_eq!(restored.hovered_split, None);
+
+        let state = ResizableGridWidgetState {
+            focused_divider: Some(1),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&state).unwrap();
+        let restored: ResizableGridWidgetState = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.focused_divider, Some(1));
+    }
+
+    #[test]
+    fn other_versions_load_with_defaults_and_clamped_ratios() {
+        let json = r#"{
+            "version": 2,
+            "root_index": 0,
+            "nodes": [
+                {"Split": {"axis": "Vertical", "ratio": 99, "first": 1, "second": 2}},
+                {"Pane": {"id": 4}},
+                {"Pane": {"id": 7}}
+            ],
+            "pane_titles": ["left", "right"]
+        }"#;
+        let grid: ResizableGrid = serde_json::from_str(json).unwrap();
+        assert_eq!(grid.get_split_ratio(0), Some(MAX_SPLIT_PERCENT));
+        assert_eq!(grid.next_pane_id, 8);
+        assert_eq!(grid.hit_threshold, 2);
+
+        let state: ResizableGridWidgetState =
+            serde_json::from_str(r#"{"focused_divider": 0, "zoomed": true}"#).unwrap();
+        assert_eq!(state.focused_divider, Some(0));
+
+        let cyclic = r#"{"nodes": [
+            {"Split": {"axis": "Horizontal", "ratio": 50, "first": 1, "second": 0}},
+            {"Pane": {"id": 0}}
+        ]}"#;
+        let error = serde_json::from_str::<ResizableGrid>(cyclic).unwrap_err();
+        assert!(error.to_string().contains("used more than once"), "{error}");
+        let dangling = r#"{"root_index": 3}"#;
+        assert!(serde_json::from_str::<ResizableGrid>(dangling).is_err());
+    }
+}
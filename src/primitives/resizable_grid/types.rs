@@ -1,4 +1,7 @@
+use std::collections::HashMap;
+
 use ratatui::layout::Rect;
+use thiserror::Error;
 
 /// Axis for splitting panes.
 ///
@@ -12,6 +15,7 @@ use ratatui::layout::Rect;
 /// let axis = SplitAxis::Vertical;
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SplitAxis {
     /// Vertical split (left/right panes).
     Vertical,
@@ -35,6 +39,18 @@ pub use SplitAxis as SplitDirection;
 /// is modified. Each new pane is assigned a unique ID automatically.
 pub type PaneId = u32;
 
+/// Errors returned by pane operations on a `ResizableGrid`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum PaneError {
+    /// No pane with the given ID exists in the layout.
+    #[error("Pane not found: {0}")]
+    PaneNotFound(PaneId),
+
+    /// The pane is the only pane in the layout and cannot be removed.
+    #[error("Cannot close the last remaining pane: {0}")]
+    LastPane(PaneId),
+}
+
 /// Default percentage for new splits.
 pub const DEFAULT_SPLIT_PERCENT: u16 = 50;
 
@@ -44,11 +60,18 @@ pub const MIN_SPLIT_PERCENT: u16 = 10;
 /// Maximum percentage for a split pane.
 pub const MAX_SPLIT_PERCENT: u16 = 90;
 
+/// Default minimum width of a pane, in columns.
+pub const DEFAULT_MIN_PANE_COLS: u16 = 10;
+
+/// Default minimum height of a pane, in rows.
+pub const DEFAULT_MIN_PANE_ROWS: u16 = 3;
+
 /// Node within the split layout tree.
 ///
 /// The tree is composed of leaf nodes (panes) and internal split nodes.
 /// Split nodes reference their children by index in the nodes vector.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LayoutNode {
     /// Leaf pane with a stable identifier.
     Pane {
@@ -78,6 +101,7 @@ pub struct SplitDividerLayout {
     pub(crate) axis: SplitAxis,
     pub(crate) area: Rect,
     pub(crate) ratio: u16,
+    pub(crate) offset: u16,
 }
 
 impl SplitDividerLayout {
@@ -100,6 +124,15 @@ impl SplitDividerLayout {
     pub fn ratio(&self) -> u16 {
         self.ratio
     }
+
+    /// Distance in cells from the start of [`area`](Self::area) to the
+    /// divider: the width or height given to the first child.
+    ///
+    /// This follows the ratio unless a pane's minimum size moved the
+    /// divider.
+    pub fn offset(&self) -> u16 {
+        self.offset
+    }
 }
 
 /// A grid-based layout for arranging multiple resizable panes.
@@ -113,6 +146,14 @@ impl SplitDividerLayout {
 ///
 /// let mut grid = ResizableGrid::new(0);
 /// ```
+///
+/// Panes are kept at least [`DEFAULT_MIN_PANE_COLS`] by
+/// [`DEFAULT_MIN_PANE_ROWS`] cells while there is room; see
+/// [`set_min_size`](Self::set_min_size).
+///
+/// With the `serde` feature the grid can be saved and restored; see
+/// [`persist`](super::persist). With `serde_json` as well, panes can carry
+/// content descriptors; see [`descriptor`](super::descriptor).
 #[derive(Debug, Clone)]
 pub struct ResizableGrid {
     pub root_index: usize,
@@ -121,6 +162,17 @@ pub struct ResizableGrid {
     pub hovered_split: Option<usize>,
     pub dragging_split: Option<usize>,
     pub hit_threshold: u16,
+    /// Minimum columns and rows of panes that set their own.
+    pub min_sizes: HashMap<PaneId, (u16, u16)>,
+    /// Minimum columns and rows of every other pane.
+    pub default_min_size: (u16, u16),
+    /// The pane with keyboard focus.
+    pub focused_pane: PaneId,
+    /// The pane shown alone while the grid is zoomed.
+    pub zoomed_pane: Option<PaneId>,
+    /// Content descriptors of panes that have one.
+    #[cfg(all(feature = "serde", feature = "serde_json"))]
+    pub pane_descriptors: HashMap<PaneId, serde_json::Value>,
 }
 
 /// Panel areas returned from split calculation.
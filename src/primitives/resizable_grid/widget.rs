@@ -3,6 +3,8 @@
 //! This widget wraps the ResizableGrid primitive and provides:
 //! - Mouse hover detection on dividers
 //! - Drag-to-resize functionality
+//! - Keyboard-driven divider focus and resizing
+//! - Directional pane focus with a configurable keymap
 //! - Optional styling for dividers and panes
 //! - Rendering support for pane borders and overlays
 //!
@@ -63,13 +65,18 @@
 //! Don't:
 //! - Expect `ResizableGrid` to handle mouse events by itself.
 
+use crate::primitives::resizable_grid::focus::FocusDirection;
 use crate::primitives::resizable_grid::layout::PaneLayout;
-use crate::primitives::resizable_grid::types::{ResizableGrid, SplitAxis, SplitDividerLayout};
-use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+use crate::primitives::resizable_grid::types::{
+    PaneId, ResizableGrid, SplitAxis, SplitDividerLayout,
+};
+use crossterm::event::{
+    KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
-    text::Line,
+    text::{Line, Span},
     widgets::{Block, BorderType, Borders, Widget},
 };
 
@@ -78,11 +85,82 @@ use ratatui::{
 /// This can be stored in app state to preserve hover and drag
 /// information across frames.
 #[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct ResizableGridWidgetState {
     /// Index of the divider currently being hovered
     pub hovered_divider: Option<usize>,
     /// Index of the divider currently being dragged
     pub dragging_divider: Option<usize>,
+    /// Index of the divider currently focused for keyboard resizing
+    pub focused_divider: Option<usize>,
+}
+
+impl ResizableGridWidgetState {
+    /// Set the divider focused for keyboard resizing.
+    ///
+    /// Pass `None` to clear keyboard focus.
+    pub fn focus_divider(&mut self, split_index: Option<usize>) {
+        self.focused_divider = split_index;
+    }
+
+    /// Get the divider focused for keyboard resizing, if any.
+    pub fn focused_divider(&self) -> Option<usize> {
+        self.focused_divider
+    }
+}
+
+/// Default percentage step applied per key press when resizing with the keyboard.
+pub const DEFAULT_KEYBOARD_STEP: u16 = 2;
+
+/// Keys that move pane focus in each direction.
+///
+/// A key matches when its code and modifiers both match. The default is
+/// Ctrl with h, j, k and l.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaneFocusKeymap {
+    /// Keys that focus the pane to the left
+    pub left: Vec<KeyEvent>,
+    /// Keys that focus the pane below
+    pub down: Vec<KeyEvent>,
+    /// Keys that focus the pane above
+    pub up: Vec<KeyEvent>,
+    /// Keys that focus the pane to the right
+    pub right: Vec<KeyEvent>,
+}
+
+impl Default for PaneFocusKeymap {
+    fn default() -> Self {
+        let ctrl = |c| vec![KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)];
+        Self {
+            left: ctrl('h'),
+            down: ctrl('j'),
+            up: ctrl('k'),
+            right: ctrl('l'),
+        }
+    }
+}
+
+impl PaneFocusKeymap {
+    /// The direction `key` moves focus in, if any.
+    pub fn direction(&self, key: &KeyEvent) -> Option<FocusDirection> {
+        let matches = |keys: &[KeyEvent]| {
+            keys.iter()
+                .any(|bound| bound.code == key.code && bound.modifiers == key.modifiers)
+        };
+        [
+            (&self.left, FocusDirection::Left),
+            (&self.down, FocusDirection::Down),
+            (&self.up, FocusDirection::Up),
+            (&self.right, FocusDirection::Right),
+        ]
+        .into_iter()
+        .find(|(keys, _)| matches(keys))
+        .map(|(_, direction)| direction)
+    }
 }
 
 /// A widget that wraps ResizableGrid with mouse interaction support.
@@ -115,6 +193,8 @@ pub struct ResizableGridWidget {
     divider_width: u16,
     /// Hit detection threshold in columns/rows
     hit_threshold: u16,
+    /// Percentage step applied per key press when resizing with the keyboard
+    keyboard_step: u16,
     /// Style for hovered dividers
     hover_style: Style,
     /// Style for dragging dividers
@@ -125,6 +205,12 @@ pub struct ResizableGridWidget {
     block: Option<Block<'static>>,
     /// Whether to show pane borders
     show_pane_borders: bool,
+    /// Border style for the focused pane
+    focus_style: Style,
+    /// Keys that move pane focus
+    focus_keymap: PaneFocusKeymap,
+    /// Whether pane focus wraps around at the edges
+    wrap_focus: bool,
 }
 
 impl ResizableGridWidget {
@@ -135,6 +221,7 @@ impl ResizableGridWidget {
             state: ResizableGridWidgetState::default(),
             divider_width: 1,
             hit_threshold: 2,
+            keyboard_step: DEFAULT_KEYBOARD_STEP,
             hover_style: Style::default()
                 .fg(Color::Cyan)
                 .add_modifier(Modifier::BOLD),
@@ -144,6 +231,9 @@ impl ResizableGridWidget {
             divider_style: Style::default(),
             block: None,
             show_pane_borders: true,
+            focus_style: Style::default().fg(Color::Cyan),
+            focus_keymap: PaneFocusKeymap::default(),
+            wrap_focus: false,
         }
     }
 
@@ -199,7 +289,15 @@ impl ResizableGridWidget {
         self
     }
 
+    /// Set the percentage step used when resizing dividers with the keyboard.
+    pub fn with_keyboard_step(mut self, step: u16) -> Self {
+        self.keyboard_step = step.max(1);
+        self
+    }
+
     /// Set the style for hovered dividers.
+    ///
+    /// This style is also used for the keyboard-focused divider.
     pub fn with_hover_style(mut self, style: Style) -> Self {
         self.hover_style = style;
         self
@@ -229,6 +327,55 @@ impl ResizableGridWidget {
         self
     }
 
+    /// Set the border style of the focused pane.
+    pub fn with_focus_style(mut self, style: Style) -> Self {
+        self.focus_style = style;
+        self
+    }
+
+    /// Set the keys that move pane focus.
+    pub fn with_focus_keymap(mut self, keymap: PaneFocusKeymap) -> Self {
+        self.focus_keymap = keymap;
+        self
+    }
+
+    /// Let pane focus wrap around to the far side at the edges.
+    pub fn with_focus_wrap(mut self, wrap: bool) -> Self {
+        self.wrap_focus = wrap;
+        self
+    }
+
+    /// Get the pane with keyboard focus.
+    pub fn focused_pane(&self) -> PaneId {
+        self.layout.focused_pane()
+    }
+
+    /// Handle a key from the pane focus keymap.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key event to handle
+    /// * `area` - The area the widget is rendered in
+    ///
+    /// # Returns
+    ///
+    /// The newly focused pane, or `None` if the key is not in the keymap
+    /// or there is no pane in its direction.
+    pub fn handle_focus_key(&mut self, key: KeyEvent, area: Rect) -> Option<PaneId> {
+        if key.kind == KeyEventKind::Release {
+            return None;
+        }
+        let direction = self.focus_keymap.direction(&key)?;
+        let area = self.pane_area(area);
+        self.layout
+            .focus_direction(direction, area, self.wrap_focus)
+    }
+
+    /// The area inside the outer block, where the panes are laid out.
+    fn pane_area(&self, area: Rect) -> Rect {
+        self.block.as_ref().map_or(area, |block| block.inner(area))
+    }
+
     /// Check if currently hovering over any divider.
     pub fn is_hovering(&self) -> bool {
         self.state.hovered_divider.is_some()
@@ -292,6 +439,95 @@ impl ResizableGridWidget {
         }
     }
 
+    /// Get the divider focused for keyboard resizing, if any.
+    pub fn focused_divider(&self) -> Option<usize> {
+        self.state.focused_divider
+    }
+
+    /// Handle a key event for keyboard-driven divider resizing.
+    ///
+    /// - Tab / BackTab: Cycle focus forward / backward between dividers
+    /// - Left / Right: Move a focused vertical divider
+    /// - Up / Down: Move a focused horizontal divider
+    ///
+    /// Arrow keys only apply when a divider is focused and match its axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key event to handle
+    ///
+    /// # Returns
+    ///
+    /// `true` if the key was handled, `false` otherwise.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Tab => self.cycle_divider_focus(true),
+            KeyCode::BackTab => self.cycle_divider_focus(false),
+            KeyCode::Left => self.step_focused_divider(SplitAxis::Vertical, false),
+            KeyCode::Right => self.step_focused_divider(SplitAxis::Vertical, true),
+            KeyCode::Up => self.step_focused_divider(SplitAxis::Horizontal, false),
+            KeyCode::Down => self.step_focused_divider(SplitAxis::Horizontal, true),
+            _ => false,
+        }
+    }
+
+    /// Move keyboard focus to the next or previous divider in layout order.
+    fn cycle_divider_focus(&mut self, forward: bool) -> bool {
+        let split_indices: Vec<usize> = self
+            .layout
+            .layout_dividers(Rect::default())
+            .iter()
+            .map(|divider| divider.split_index())
+            .collect();
+
+        if split_indices.is_empty() {
+            self.state.focused_divider = None;
+            return false;
+        }
+
+        let current = self
+            .state
+            .focused_divider
+            .and_then(|focused| split_indices.iter().position(|index| *index == focused));
+        let count = split_indices.len();
+        let next = match (current, forward) {
+            (Some(position), true) => (position + 1) % count,
+            (Some(position), false) => (position + count - 1) % count,
+            (None, true) => 0,
+            (None, false) => count - 1,
+        };
+
+        self.state.focused_divider = Some(split_indices[next]);
+        true
+    }
+
+    /// Adjust the focused divider by one keyboard step if it matches `axis`.
+    fn step_focused_divider(&mut self, axis: SplitAxis, increase: bool) -> bool {
+        let Some(split_index) = self.state.focused_divider else {
+            return false;
+        };
+        let Some(divider) = self
+            .layout
+            .layout_dividers(Rect::default())
+            .into_iter()
+            .find(|divider| divider.split_index() == split_index)
+        else {
+            return false;
+        };
+
+        if divider.axis() != axis {
+            return false;
+        }
+
+        let ratio = if increase {
+            divider.ratio().saturating_add(self.keyboard_step)
+        } else {
+            divider.ratio().saturating_sub(self.keyboard_step)
+        };
+
+        self.layout.resize_split(split_index, ratio)
+    }
+
     /// Find which split divider the mouse is over.
     ///
     /// Returns the split index if mouse is near a divider, or None otherwise.
@@ -304,9 +540,7 @@ impl ResizableGridWidget {
             let rect = divider.area();
             match divider.axis() {
                 SplitAxis::Vertical => {
-                    let divider_x = rect.x.saturating_add(
-                        ((rect.width as u32 * divider.ratio() as u32) / 100) as u16,
-                    );
+                    let divider_x = rect.x.saturating_add(divider.offset());
                     let distance = divider_x.abs_diff(column);
                     if distance <= threshold
                         && column <= divider_x.saturating_add(threshold)
@@ -326,9 +560,7 @@ impl ResizableGridWidget {
                     }
                 }
                 SplitAxis::Horizontal => {
-                    let divider_y = rect.y.saturating_add(
-                        ((rect.height as u32 * divider.ratio() as u32) / 100) as u16,
-                    );
+                    let divider_y = rect.y.saturating_add(divider.offset());
                     let distance = divider_y.abs_diff(row);
                     if distance <= threshold
                         && row <= divider_y.saturating_add(threshold)
@@ -355,8 +587,9 @@ impl ResizableGridWidget {
 
     /// Resize a divider based on mouse position.
     ///
-    /// Calculates new split percentage based on mouse position and calls
-    /// resize_divider on the ResizableGrid.
+    /// Calculates new split percentage based on mouse position and applies
+    /// it with `ResizableGrid::resize_split_within`, so the divider stops
+    /// where a pane reaches its minimum size.
     fn resize_divider(&mut self, split_index: usize, column: u16, row: u16, area: Rect) {
         let layouts = self.layout.layout_dividers(area);
         let divider_layout = layouts
@@ -371,7 +604,7 @@ impl ResizableGridWidget {
                     if content_width > 0 {
                         let relative_x = column.saturating_sub(rect.x);
                         let percent = ((relative_x as u32 * 100) / content_width as u32) as u16;
-                        let _ = self.layout.resize_split(split_index, percent);
+                        let _ = self.layout.resize_split_within(split_index, percent, area);
                     }
                 }
                 SplitAxis::Horizontal => {
@@ -379,7 +612,7 @@ impl ResizableGridWidget {
                     if content_height > 0 {
                         let relative_y = row.saturating_sub(rect.y);
                         let percent = ((relative_y as u32 * 100) / content_height as u32) as u16;
-                        let _ = self.layout.resize_split(split_index, percent);
+                        let _ = self.layout.resize_split_within(split_index, percent, area);
                     }
                 }
             }
@@ -414,7 +647,11 @@ impl Widget for ResizableGridWidget {
             let pane_id = pane_layout.pane_id();
             let pane_area = pane_layout.area();
 
-            let border_style = self.divider_style;
+            let border_style = if pane_id == self.layout.focused_pane() {
+                self.focus_style
+            } else {
+                self.divider_style
+            };
 
             // Render pane border
             if self.show_pane_borders {
@@ -422,7 +659,7 @@ impl Widget for ResizableGridWidget {
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
                     .border_style(border_style)
-                    .title(Line::from(format!(" {}", pane_id)));
+                    .title(self.pane_title(pane_id));
 
                 pane_block.render(pane_area, buf);
             }
@@ -433,7 +670,9 @@ impl Widget for ResizableGridWidget {
         for divider in &divider_layouts {
             let divider_style = if self.state.dragging_divider == Some(divider.split_index()) {
                 self.drag_style
-            } else if self.state.hovered_divider == Some(divider.split_index()) {
+            } else if self.state.hovered_divider == Some(divider.split_index())
+                || self.state.focused_divider == Some(divider.split_index())
+            {
                 self.hover_style
             } else {
                 continue;
@@ -445,6 +684,19 @@ impl Widget for ResizableGridWidget {
 }
 
 impl ResizableGridWidget {
+    /// Title for a pane's border, with a badge while the pane is zoomed.
+    fn pane_title(&self, pane_id: PaneId) -> Line<'static> {
+        let mut title = Line::from(format!(" {}", pane_id));
+        if self.layout.zoomed_pane() == Some(pane_id) {
+            title.push_span(Span::raw(" "));
+            title.push_span(Span::styled(
+                " ZOOMED ",
+                self.hover_style.add_modifier(Modifier::REVERSED),
+            ));
+        }
+        title
+    }
+
     /// Render a visual overlay on the divider to indicate it's active.
     fn render_divider_overlay(
         &self,
@@ -457,9 +709,7 @@ impl ResizableGridWidget {
 
         match divider.axis() {
             SplitAxis::Vertical => {
-                let divider_x = rect
-                    .x
-                    .saturating_add(((rect.width as u32 * divider.ratio() as u32) / 100) as u16);
+                let divider_x = rect.x.saturating_add(divider.offset());
                 for y in rect.top()..rect.bottom() {
                     for dx in 0..width {
                         let x = divider_x.saturating_sub(dx);
@@ -471,9 +721,7 @@ impl ResizableGridWidget {
                 }
             }
             SplitAxis::Horizontal => {
-                let divider_y = rect
-                    .y
-                    .saturating_add(((rect.height as u32 * divider.ratio() as u32) / 100) as u16);
+                let divider_y = rect.y.saturating_add(divider.offset());
                 for x in rect.left()..rect.right() {
                     for dy in 0..width {
                         let y = divider_y.saturating_sub(dy);
@@ -491,8 +739,22 @@ impl ResizableGridWidget {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::primitives::resizable_grid::types::MAX_SPLIT_PERCENT;
     use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::empty())
+    }
+
+    fn mouse(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::empty(),
+        }
+    }
+
     #[test]
     fn test_widget_creation() {
         let layout = ResizableGrid::new(0);
@@ -574,4 +836,172 @@ mod tests {
         assert!(!widget.is_dragging());
         assert!(widget.dragging_divider().is_none());
     }
+
+    #[test]
+    fn test_keyboard_resize_vertical_divider() {
+        let mut layout = ResizableGrid::new(0);
+        let _pane_1 = layout.split_pane_vertically(0).unwrap();
+
+        let mut widget = ResizableGridWidget::new(layout);
+
+        assert!(widget.handle_key(key(KeyCode::Tab)));
+        assert_eq!(widget.focused_divider(), Some(0));
+
+        assert!(widget.handle_key(key(KeyCode::Right)));
+        assert_eq!(widget.layout().get_split_ratio(0), Some(52));
+
+        assert!(widget.handle_key(key(KeyCode::Left)));
+        assert!(widget.handle_key(key(KeyCode::Left)));
+        assert_eq!(widget.layout().get_split_ratio(0), Some(48));
+
+        // Up/Down do not apply to a vertical divider
+        assert!(!widget.handle_key(key(KeyCode::Up)));
+        assert_eq!(widget.layout().get_split_ratio(0), Some(48));
+    }
+
+    #[test]
+    fn test_keyboard_resize_horizontal_divider() {
+        let mut layout = ResizableGrid::new(0);
+        let _pane_1 = layout.split_pane_horizontally(0).unwrap();
+
+        let mut widget = ResizableGridWidget::new(layout).with_keyboard_step(5);
+        let mut state = ResizableGridWidgetState::default();
+        state.focus_divider(Some(0));
+        widget = widget.with_state(state);
+
+        assert!(widget.handle_key(key(KeyCode::Down)));
+        assert_eq!(widget.layout().get_split_ratio(0), Some(55));
+
+        assert!(widget.handle_key(key(KeyCode::Up)));
+        assert!(widget.handle_key(key(KeyCode::Up)));
+        assert_eq!(widget.layout().get_split_ratio(0), Some(45));
+
+        // Left/Right do not apply to a horizontal divider
+        assert!(!widget.handle_key(key(KeyCode::Right)));
+        assert_eq!(widget.layout().get_split_ratio(0), Some(45));
+    }
+
+    #[test]
+    fn test_keyboard_resize_clamps_ratio() {
+        let mut layout = ResizableGrid::new(0);
+        let _pane_1 = layout.split_pane_vertically(0).unwrap();
+
+        let mut widget = ResizableGridWidget::new(layout).with_keyboard_step(30);
+        widget.handle_key(key(KeyCode::Tab));
+        widget.handle_key(key(KeyCode::Right));
+        widget.handle_key(key(KeyCode::Right));
+
+        assert_eq!(widget.layout().get_split_ratio(0), Some(MAX_SPLIT_PERCENT));
+    }
+
+    #[test]
+    fn test_tab_cycles_divider_focus() {
+        let mut layout = ResizableGrid::new(0);
+        let pane_1 = layout.split_pane_vertically(0).unwrap();
+        let _pane_2 = layout.split_pane_horizontally(pane_1).unwrap();
+
+        let mut widget = ResizableGridWidget::new(layout);
+        let order: Vec<usize> = widget
+            .layout()
+            .layout_dividers(Rect::new(0, 0, 80, 24))
+            .iter()
+            .map(|divider| divider.split_index())
+            .collect();
+        assert_eq!(order.len(), 2);
+
+        widget.handle_key(key(KeyCode::Tab));
+        assert_eq!(widget.focused_divider(), Some(order[0]));
+        widget.handle_key(key(KeyCode::Tab));
+        assert_eq!(widget.focused_divider(), Some(order[1]));
+        widget.handle_key(key(KeyCode::Tab));
+        assert_eq!(widget.focused_divider(), Some(order[0]));
+        widget.handle_key(key(KeyCode::BackTab));
+        assert_eq!(widget.focused_divider(), Some(order[1]));
+    }
+
+    #[test]
+    fn test_focused_divider_renders_with_hover_style() {
+        let mut layout = ResizableGrid::new(0);
+        let _pane_1 = layout.split_pane_vertically(0).unwrap();
+
+        let area = Rect::new(0, 0, 20, 6);
+        let mut widget = ResizableGridWidget::new(layout)
+            .with_pane_borders(false)
+            .with_hover_style(Style::default().fg(Color::Magenta));
+        widget.handle_key(key(KeyCode::Tab));
+
+        let mut buf = ratatui::buffer::Buffer::empty(area);
+        widget.render(area, &mut buf);
+
+        assert_eq!(buf[(10, 2)].fg, Color::Magenta);
+        assert_eq!(buf[(10, 2)].symbol(), "│");
+    }
+
+    #[test]
+    fn test_keys_ignored_on_single_pane() {
+        let layout = ResizableGrid::new(0);
+        let mut widget = ResizableGridWidget::new(layout);
+
+        assert!(!widget.handle_key(key(KeyCode::Tab)));
+        assert!(!widget.handle_key(key(KeyCode::Right)));
+        assert!(widget.focused_divider().is_none());
+    }
+
+    #[test]
+    fn test_focus_keys_move_focus_and_style_the_border() {
+        let mut layout = ResizableGrid::new(0);
+        let right = layout.split_pane_vertically(0).unwrap();
+        let area = Rect::new(0, 0, 40, 10);
+        let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+        let focus_style = Style::default().fg(Color::Magenta);
+        let mut widget = ResizableGridWidget::new(layout).with_focus_style(focus_style);
+
+        assert_eq!(widget.handle_focus_key(ctrl('l'), area), Some(right));
+        assert_eq!(widget.handle_focus_key(ctrl('l'), area), None);
+        assert_eq!(widget.handle_focus_key(key(KeyCode::Char('h')), area), None);
+        assert_eq!(widget.focused_pane(), right);
+
+        let mut buf = ratatui::buffer::Buffer::empty(area);
+        widget.clone().render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].fg, Color::Reset);
+        assert_eq!(buf[(20, 0)].fg, Color::Magenta);
+
+        let keymap = PaneFocusKeymap {
+            left: vec![key(KeyCode::Char('a'))],
+            ..PaneFocusKeymap::default()
+        };
+        let mut widget = widget.with_focus_keymap(keymap).with_focus_wrap(true);
+        assert_eq!(widget.handle_focus_key(ctrl('h'), area), None);
+        assert_eq!(widget.handle_focus_key(ctrl('l'), area), Some(0));
+        assert_eq!(
+            widget.handle_focus_key(key(KeyCode::Char('a')), area),
+            Some(right)
+        );
+    }
+
+    #[test]
+    fn test_zoomed_pane_has_a_badge_and_no_dividers() {
+        let mut layout = ResizableGrid::new(0);
+        let right = layout.split_pane_vertically(0).unwrap();
+        layout.toggle_zoom(right).unwrap();
+        let area = Rect::new(0, 0, 40, 10);
+        let mut widget = ResizableGridWidget::new(layout);
+
+        // The old divider position neither hovers nor drags.
+        widget.handle_mouse(mouse(MouseEventKind::Moved, 20, 5), area);
+        widget.handle_mouse(mouse(MouseEventKind::Down(MouseButton::Left), 20, 5), area);
+        assert!(!widget.is_hovering());
+        assert!(!widget.is_dragging());
+
+        let mut buf = ratatui::buffer::Buffer::empty(area);
+        widget.clone().render(area, &mut buf);
+        let top: String = (0..area.width).map(|x| buf[(x, 0)].symbol()).collect();
+        assert!(top.contains(" 1  ZOOMED "), "{top}");
+
+        widget.layout_mut().toggle_zoom(right).unwrap();
+        let mut buf = ratatui::buffer::Buffer::empty(area);
+        widget.render(area, &mut buf);
+        let top: String = (0..area.width).map(|x| buf[(x, 0)].symbol()).collect();
+        assert!(!top.contains("ZOOMED"), "{top}");
+    }
 }
@@ -0,0 +1,128 @@
+//! Zooming one pane of a resizable grid to fill the whole area.
+//!
+//! Zooming leaves the pane tree alone; it only changes what
+//! [`ResizableGrid::layout_panes`] presents. Toggling the zoom off brings
+//! back the layout exactly as it was, divider ratios included.
+
+use crate::primitives::resizable_grid::types::{PaneError, PaneId, ResizableGrid};
+
+impl ResizableGrid {
+    /// Zooms `pane_id` to fill the grid's area, or restores the layout if
+    /// the grid is already zoomed.
+    ///
+    /// The zoomed pane takes focus. While zoomed, there are no dividers,
+    /// and resizing splits is rejected so the restored layout matches the
+    /// one that was zoomed. Splitting, closing or swapping panes ends the
+    /// zoom.
+    ///
+    /// Returns whether the grid is zoomed afterwards.
+    ///
+    /// # Errors
+    /// - [`PaneError::PaneNotFound`] if the grid is not zoomed and no pane
+    ///   has the given ID.
+    pub fn toggle_zoom(&mut self, pane_id: PaneId) -> Result<bool, PaneError> {
+        if self.zoomed_pane.take().is_some() {
+            return Ok(false);
+        }
+        if self.find_pane_node_index(pane_id).is_none() {
+            return Err(PaneError::PaneNotFound(pane_id));
+        }
+        self.zoomed_pane = Some(pane_id);
+        self.focused_pane = pane_id;
+        self.hovered_split = None;
+        self.dragging_split = None;
+        Ok(true)
+    }
+
+    /// Whether one pane is zoomed to fill the grid.
+    pub fn is_zoomed(&self) -> bool {
+        self.zoomed_pane.is_some()
+    }
+
+    /// The zoomed pane, if any.
+    pub fn zoomed_pane(&self) -> Option<PaneId> {
+        self.zoomed_pane
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::layout::Rect;
+
+    use super::*;
+
+    const AREA: Rect = Rect::new(0, 0, 80, 40);
+
+    fn areas(grid: &ResizableGrid) -> Vec<(PaneId, Rect)> {
+        grid.layout_panes(AREA)
+            .iter()
+            .map(|pane| (pane.pane_id(), pane.area()))
+            .collect()
+    }
+
+    /// Left pane 0, and on the right pane 1 above panes 2 and 3 side by side.
+    fn nested() -> ResizableGrid {
+        let mut grid = ResizableGrid::new(0);
+        let right = grid.split_pane_vertically(0).unwrap();
+        let bottom = grid.split_pane_horizontally(right).unwrap();
+        grid.split_pane_vertically(bottom).unwrap();
+        grid.resize_split(0, 30);
+        grid.resize_divider(right, 70);
+        grid
+    }
+
+    #[test]
+    fn test_zoom_restores_the_layout_exactly() {
+        let mut grid = nested();
+        let before = areas(&grid);
+
+        assert_eq!(grid.toggle_zoom(2), Ok(true));
+        assert_eq!(areas(&grid), [(2, AREA)]);
+        assert!(grid.layout_dividers(AREA).is_empty());
+        assert_eq!(grid.focused_pane(), 2);
+
+        // Resizing is rejected while zoomed.
+        assert!(!grid.resize_split(0, 60));
+        assert!(!grid.resize_divider(1, 20));
+        assert!(!grid.resize_split_within(0, 60, AREA));
+        assert_eq!(grid.find_divider_at(24, 5, AREA), None);
+
+        assert_eq!(grid.toggle_zoom(2), Ok(false));
+        assert_eq!(areas(&grid), before);
+        assert_eq!(grid.get_split_ratio(0), Some(30));
+
+        // Splitting ends the zoom.
+        assert_eq!(grid.toggle_zoom(9), Err(PaneError::PaneNotFound(9)));
+        grid.toggle_zoom(0).unwrap();
+        let new = grid.split_pane_horizontally(0).unwrap();
+        assert!(!grid.is_zoomed());
+        assert_eq!(areas(&grid).len(), 5);
+        assert!(areas(&grid).iter().any(|&(id, _)| id == new));
+    }
+
+    #[test]
+    fn test_swapping_panes_at_different_depths() {
+        let mut grid = nested();
+        let before = areas(&grid);
+        let place = |areas: &[(PaneId, Rect)], id| {
+            areas
+                .iter()
+                .find(|&&(pane, _)| pane == id)
+                .map(|&(_, area)| area)
+        };
+
+        grid.toggle_zoom(3).unwrap();
+        assert!(grid.swap_panes(0, 3));
+        assert!(!grid.is_zoomed());
+        let after = areas(&grid);
+        assert_eq!(place(&after, 3), place(&before, 0));
+        assert_eq!(place(&after, 0), place(&before, 3));
+        assert_eq!(place(&after, 1), place(&before, 1));
+
+        assert!(grid.swap_panes(1, 0));
+        let after = areas(&grid);
+        assert_eq!(place(&after, 0), place(&before, 1));
+        assert_eq!(place(&after, 1), place(&before, 3));
+        assert!(!grid.swap_panes(1, 7));
+    }
+}
@@ -1,7 +1,10 @@
-use ratatui::style::Style;
-use ratatui::text::Line;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
 
-use crate::primitives::statusline::{OperationalMode, StatusLineStacked, StyledStatusLine};
+use crate::primitives::statusline::{
+    OperationalMode, StackedItem, StatusItemId, StatusLineStacked, StyledStatusLine, SLANT_BL_TR,
+    SLANT_TL_BR,
+};
 
 impl<'a> StatusLineStacked<'a> {
     pub fn new() -> Self {
@@ -9,8 +12,9 @@ impl<'a> StatusLineStacked<'a> {
             style: Style::default(),
             left: Vec::new(),
             center_margin: 0,
-            center: Line::default(),
+            center: StackedItem::default().truncate(true),
             right: Vec::new(),
+            overflow_indicator: false,
             phantom: std::marker::PhantomData,
         }
     }
@@ -21,12 +25,29 @@ impl<'a> StatusLineStacked<'a> {
     }
 
     pub fn start(mut self, text: impl Into<Line<'a>>, gap: impl Into<Line<'a>>) -> Self {
-        self.left.push((text.into(), gap.into()));
+        self.left.push(StackedItem::new(text).gap(gap));
         self
     }
 
     pub fn start_bare(mut self, text: impl Into<Line<'a>>) -> Self {
-        self.left.push((text.into(), "".into()));
+        self.left.push(StackedItem::new(text));
+        self
+    }
+
+    /// Stacks a powerline segment after the existing left indicators:
+    /// `text` in `fg` on `bg`, then a [`SLANT_TL_BR`] separator from `bg`
+    /// into the background of the next item shown.
+    ///
+    /// Use [`start`](Self::start) to style the separator yourself.
+    pub fn segment_start(mut self, text: impl Into<Line<'a>>, fg: Color, bg: Color) -> Self {
+        self.left
+            .push(StackedItem::segment(text, fg, bg, SLANT_TL_BR));
+        self
+    }
+
+    /// Stacks `item` after the existing left indicators.
+    pub fn start_item(mut self, item: StackedItem<'a>) -> Self {
+        self.left.push(item);
         self
     }
 
@@ -36,17 +57,97 @@ impl<'a> StatusLineStacked<'a> {
     }
 
     pub fn center(mut self, text: impl Into<Line<'a>>) -> Self {
-        self.center = text.into();
+        self.center.text = text.into();
+        self
+    }
+
+    /// Sets the center message along with its overflow behaviour.
+    ///
+    /// The message set by [`center`](Self::center) truncates with priority 0.
+    pub fn center_item(mut self, item: StackedItem<'a>) -> Self {
+        self.center = item;
         self
     }
 
     pub fn end(mut self, text: impl Into<Line<'a>>, gap: impl Into<Line<'a>>) -> Self {
-        self.right.push((text.into(), gap.into()));
+        self.right.push(StackedItem::new(text).gap(gap));
         self
     }
 
     pub fn end_bare(mut self, text: impl Into<Line<'a>>) -> Self {
-        self.right.push((text.into(), "".into()));
+        self.right.push(StackedItem::new(text));
+        self
+    }
+
+    /// Stacks a powerline segment inside the existing right indicators:
+    /// `text` in `fg` on `bg`, preceded by a [`SLANT_BL_TR`] separator from
+    /// `bg` into the background of the next item shown.
+    ///
+    /// Use [`end`](Self::end) to style the separator yourself.
+    pub fn segment_end(mut self, text: impl Into<Line<'a>>, fg: Color, bg: Color) -> Self {
+        self.right
+            .push(StackedItem::segment(text, fg, bg, SLANT_BL_TR));
+        self
+    }
+
+    /// Stacks `item` inside the existing right indicators.
+    pub fn end_item(mut self, item: StackedItem<'a>) -> Self {
+        self.right.push(item);
+        self
+    }
+
+    /// Shows `…` in place of the indicators dropped from each side.
+    pub fn overflow_indicator(mut self, show: bool) -> Self {
+        self.overflow_indicator = show;
+        self
+    }
+}
+
+impl<'a> StackedItem<'a> {
+    /// Creates an indicator with no gap, priority 0, that is dropped rather
+    /// than truncated.
+    pub fn new(text: impl Into<Line<'a>>) -> Self {
+        Self {
+            text: text.into(),
+            ..Self::default()
+        }
+    }
+
+    fn segment(text: impl Into<Line<'a>>, fg: Color, bg: Color, separator: &'static str) -> Self {
+        let mut item = Self::new(text.into().patch_style(Style::new().fg(fg).bg(bg)))
+            .gap(Span::styled(separator, Style::new().fg(bg)));
+        item.segment_bg = Some(bg);
+        item
+    }
+
+    /// Makes the indicator clickable, reporting `id` from
+    /// [`StatusLineStacked::handle_click`].
+    pub fn id(mut self, id: StatusItemId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the separator drawn on the inner side of the indicator.
+    pub fn gap(mut self, gap: impl Into<Line<'a>>) -> Self {
+        self.gap = gap.into();
+        self
+    }
+
+    /// Sets the priority; lower priorities are dropped first.
+    pub fn priority(mut self, priority: u8) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets the narrowest width, in columns, the text may be truncated to.
+    pub fn min_width(mut self, width: u16) -> Self {
+        self.min_width = width;
+        self
+    }
+
+    /// Lets the text be shortened with `…` before the indicator is dropped.
+    pub fn truncate(mut self, truncate: bool) -> Self {
+        self.truncate = truncate;
         self
     }
 }
@@ -63,6 +164,7 @@ impl<'a> StyledStatusLine<'a> {
             event_time_us: 0,
             message_count: 0,
             use_slants: true,
+            metrics_visible: false,
         }
     }
 
@@ -102,4 +204,11 @@ impl<'a> StyledStatusLine<'a> {
         self.use_slants = use_slants;
         self
     }
+
+    /// Shows [`metrics_line`](Self::metrics_line) in the center, after
+    /// the center text if there is any.
+    pub fn with_metrics_visible(mut self, visible: bool) -> Self {
+        self.metrics_visible = visible;
+        self
+    }
 }
@@ -0,0 +1,77 @@
+//! Finding the indicator under the mouse.
+
+use ratatui::layout::{Position, Rect};
+
+use crate::primitives::statusline::{StatusItemId, StatusLineStacked};
+
+impl StatusLineStacked<'_> {
+    /// The id of the indicator drawn at `column`, `row` when the line is
+    /// rendered in `area`.
+    ///
+    /// Only an indicator's text counts, not its gap. Returns `None` when
+    /// the click misses every indicator with an id, or lands on one
+    /// without; dropped indicators cannot be clicked.
+    pub fn handle_click(&self, area: Rect, column: u16, row: u16) -> Option<StatusItemId> {
+        let position = Position::new(column, row);
+        self.item_areas(area)
+            .into_iter()
+            .find(|(_, item_area)| item_area.contains(position))
+            .map(|(id, _)| id)
+    }
+
+    /// Where the text of the indicator with `id` is drawn when the line is
+    /// rendered in `area`, e.g. to open a picker below it. Returns `None`
+    /// when no shown indicator has the id.
+    pub fn item_area(&self, area: Rect, id: StatusItemId) -> Option<Rect> {
+        self.item_areas(area)
+            .into_iter()
+            .find(|&(item_id, _)| item_id == id)
+            .map(|(_, item_area)| item_area)
+    }
+
+    /// Ids and text areas of the shown indicators that have an id.
+    fn item_areas(&self, area: Rect) -> Vec<(StatusItemId, Rect)> {
+        let placement = self.place(area);
+        let sides = self
+            .left
+            .iter()
+            .zip(&placement.left)
+            .chain(self.right.iter().zip(&placement.right))
+            .filter_map(|(item, areas)| Some((item.id?, areas.as_ref()?.0)));
+        let center = self.center.id.zip(placement.center);
+        sides.chain(center).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::layout::Rect;
+
+    use crate::primitives::statusline::{StackedItem, StatusLineStacked};
+
+    #[test]
+    fn clicks_report_the_id_of_the_shown_indicator() {
+        let line = StatusLineStacked::new()
+            .start_item(StackedItem::new(" NORMAL ").priority(9))
+            .start_item(StackedItem::new(" main ").gap(">").id(1).priority(5))
+            .center_item(StackedItem::new("2 errors").id(2))
+            .end_item(StackedItem::new(" utf-8 ").id(3).priority(1));
+        let area = Rect::new(5, 2, 40, 1);
+        // " NORMAL  main >2 errors ... utf-8 "
+        assert_eq!(line.handle_click(area, 5, 2), None);
+        assert_eq!(line.handle_click(area, 13, 2), Some(1));
+        assert_eq!(line.handle_click(area, 18, 2), Some(1));
+        // The gap is not part of the indicator
+        assert_eq!(line.handle_click(area, 19, 2), None);
+        assert_eq!(line.handle_click(area, 20, 2), Some(2));
+        assert_eq!(line.handle_click(area, 44, 2), Some(3));
+        assert_eq!(line.handle_click(area, 44, 3), None);
+        assert_eq!(line.item_area(area, 3), Some(Rect::new(38, 2, 7, 1)));
+
+        // Dropped indicators are not clickable.
+        let narrow = Rect::new(0, 0, 16, 1);
+        assert_eq!(line.item_area(narrow, 1), Some(Rect::new(8, 0, 6, 1)));
+        assert_eq!(line.item_area(narrow, 2), None);
+        assert_eq!(line.item_area(narrow, 3), None);
+    }
+}
@@ -0,0 +1,286 @@
+//! Fitting the indicators of a [`StatusLineStacked`] into a width.
+
+use ratatui::text::{Line, Span};
+use unicode_width::UnicodeWidthChar;
+
+use crate::primitives::statusline::{StackedItem, StatusLineStacked};
+
+/// Marker for truncated text and for dropped indicators.
+pub(super) const ELLIPSIS: &str = "…";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Slot {
+    Left(usize),
+    Center,
+    Right(usize),
+}
+
+/// Columns granted to the text of each indicator; `None` when dropped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Fit {
+    pub(super) left: Vec<Option<u16>>,
+    pub(super) center: Option<u16>,
+    pub(super) right: Vec<Option<u16>>,
+}
+
+impl Fit {
+    fn slot_mut(&mut self, slot: Slot) -> &mut Option<u16> {
+        match slot {
+            Slot::Left(index) => &mut self.left[index],
+            Slot::Center => &mut self.center,
+            Slot::Right(index) => &mut self.right[index],
+        }
+    }
+}
+
+impl StackedItem<'_> {
+    fn text_width(&self) -> u16 {
+        self.text.width() as u16
+    }
+
+    fn gap_width(&self) -> u16 {
+        self.gap.width() as u16
+    }
+
+    /// Fewest columns the text can take; a truncated text keeps at least
+    /// its `…`.
+    fn narrowest(&self) -> u16 {
+        if self.truncate {
+            self.min_width.max(1).min(self.text_width())
+        } else {
+            self.text_width()
+        }
+    }
+}
+
+impl<'a> StatusLineStacked<'a> {
+    fn item(&self, slot: Slot) -> &StackedItem<'a> {
+        match slot {
+            Slot::Left(index) => &self.left[index],
+            Slot::Center => &self.center,
+            Slot::Right(index) => &self.right[index],
+        }
+    }
+
+    /// Slots in the order they are dropped: lowest priority first, and
+    /// within a priority the center, then the right indicators from the
+    /// outermost inward, then the innermost left indicators.
+    fn drop_order(&self) -> Vec<Slot> {
+        let mut ranked = vec![(self.center.priority, 0, Slot::Center)];
+        for (index, item) in self.right.iter().enumerate() {
+            ranked.push((item.priority, 1 + index, Slot::Right(index)));
+        }
+        for (index, item) in self.left.iter().enumerate() {
+            let rank = 1 + self.right.len() + (self.left.len() - 1 - index);
+            ranked.push((item.priority, rank, Slot::Left(index)));
+        }
+        ranked.sort_by_key(|&(priority, rank, _)| (priority, rank));
+        ranked.into_iter().map(|(_, _, slot)| slot).collect()
+    }
+
+    fn side_width(&self, items: &[StackedItem<'a>], widths: &[Option<u16>]) -> u16 {
+        let mut total = 0;
+        let mut dropped = false;
+        for (item, width) in items.iter().zip(widths) {
+            match width {
+                Some(width) => total += width + item.gap_width(),
+                None => dropped = true,
+            }
+        }
+        if dropped && self.overflow_indicator {
+            total += Line::from(ELLIPSIS).width() as u16;
+        }
+        total
+    }
+
+    fn required_width(&self, fit: &Fit) -> u16 {
+        let center = match fit.center {
+            Some(width) if width > 0 => width + 2 * self.center_margin,
+            _ => 0,
+        };
+        self.side_width(&self.left, &fit.left) + self.side_width(&self.right, &fit.right) + center
+    }
+
+    /// Decides which indicators to show in `width` columns and how wide
+    /// each one's text may be.
+    ///
+    /// Indicators are visited in drop order until everything fits. A
+    /// truncatable indicator is first shortened towards its minimum width
+    /// and only dropped if that is not enough.
+    pub(super) fn fit(&self, width: u16) -> Fit {
+        let mut fit = Fit {
+            left: self
+                .left
+                .iter()
+                .map(|item| Some(item.text_width()))
+                .collect(),
+            center: Some(self.center.text_width()),
+            right: self
+                .right
+                .iter()
+                .map(|item| Some(item.text_width()))
+                .collect(),
+        };
+
+        for slot in self.drop_order() {
+            let excess = self.required_width(&fit).saturating_sub(width);
+            if excess == 0 {
+                break;
+            }
+            let item = self.item(slot);
+            if let Some(granted) = fit.slot_mut(slot) {
+                *granted -= excess.min(*granted - item.narrowest());
+            }
+            if self.required_width(&fit) > width {
+                *fit.slot_mut(slot) = None;
+            }
+        }
+        fit
+    }
+}
+
+/// Shortens `line` to `width` columns, ending it with `…` when anything
+/// was cut.
+pub(super) fn truncate_line<'a>(line: &Line<'a>, width: u16) -> Line<'a> {
+    let width = width as usize;
+    if line.width() <= width {
+        return line.clone();
+    }
+
+    let mut spans = Vec::new();
+    let mut used = 0;
+    let mut last_style = line.style;
+    'spans: for span in &line.spans {
+        last_style = span.style;
+        let mut kept = String::new();
+        for c in span.content.chars() {
+            let char_width = c.width().unwrap_or(0);
+            if used + char_width >= width {
+                spans.push(Span::styled(kept, span.style));
+                break 'spans;
+            }
+            used += char_width;
+            kept.push(c);
+        }
+        spans.push(Span::styled(kept, span.style));
+    }
+    if width > 0 {
+        spans.push(Span::styled(ELLIPSIS, last_style));
+    }
+
+    let mut truncated = Line::from(spans).style(line.style);
+    truncated.alignment = line.alignment;
+    truncated
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::text::Line;
+    use ratatui::widgets::Widget;
+
+    use super::truncate_line;
+    use crate::primitives::statusline::{StackedItem, StatusLineStacked};
+
+    fn statusline(overflow_indicator: bool) -> StatusLineStacked<'static> {
+        StatusLineStacked::new()
+            .start_item(StackedItem::new(" NORMAL ").priority(9))
+            .start_item(
+                StackedItem::new(" main.rs ")
+                    .priority(5)
+                    .truncate(true)
+                    .min_width(3),
+            )
+            .center_margin(1)
+            .center_item(StackedItem::new("3 warnings").priority(1))
+            .end_item(StackedItem::new(" Ln 12 ").priority(8))
+            .end_item(StackedItem::new(" utf-8 ").priority(2))
+            .end_item(StackedItem::new(" LF ").priority(0))
+            .overflow_indicator(overflow_indicator)
+    }
+
+    fn render(line: StatusLineStacked<'_>, width: u16) -> String {
+        let area = Rect::new(0, 0, width, 1);
+        let mut buf = Buffer::empty(area);
+        line.render(area, &mut buf);
+        (0..width).map(|x| buf[(x, 0)].symbol()).collect()
+    }
+
+    #[test]
+    fn everything_fits_at_80() {
+        let row = render(statusline(true), 80);
+        for text in ["NORMAL", "main.rs", "3 warnings", "Ln 12", "utf-8", "LF"] {
+            assert!(row.contains(text), "{text} missing from {row:?}");
+        }
+        assert!(!row.contains('…'));
+    }
+
+    #[test]
+    fn lowest_priorities_drop_first_at_40() {
+        let line = statusline(true);
+        let fit = line.fit(40);
+        assert_eq!(fit.left, vec![Some(8), Some(9)]);
+        assert_eq!(fit.center, None);
+        assert_eq!(fit.right, vec![Some(7), Some(7), None]);
+
+        let row = render(line, 40);
+        assert!(row.starts_with(" NORMAL  main.rs "), "{row:?}");
+        assert!(row.ends_with("… utf-8  Ln 12 "), "{row:?}");
+        assert!(!row.contains("warnings"));
+    }
+
+    #[test]
+    fn truncates_before_dropping_at_20() {
+        let row = render(statusline(false), 20);
+        assert_eq!(row, " NORMAL  mai… Ln 12 ");
+
+        let row = render(statusline(true), 20);
+        assert_eq!(row, " NORMAL  ma…… Ln 12 ");
+    }
+
+    #[test]
+    fn equal_priorities_drop_center_then_outermost_right() {
+        let line = StatusLineStacked::new()
+            .start_item(StackedItem::new("aaaa"))
+            .start_item(StackedItem::new("bbbb"))
+            .center("cccc")
+            .end_item(StackedItem::new("dddd"))
+            .end_item(StackedItem::new("eeee"));
+        // The center shrinks to its "…", then goes, then "dddd", "eeee"
+        // and the inner left indicator.
+        assert_eq!(render(line.clone(), 17), "aaaabbbb…eeeedddd");
+        assert_eq!(render(line.clone(), 16), "aaaabbbbeeeedddd");
+        assert_eq!(render(line.clone(), 12), "aaaabbbbeeee");
+        assert_eq!(render(line.clone(), 8), "aaaabbbb");
+        assert_eq!(render(line, 7), "aaaa   ");
+    }
+
+    #[test]
+    fn center_truncates_to_the_space_between_stacks() {
+        let line = StatusLineStacked::new()
+            .start_bare(" A ")
+            .center_margin(1)
+            .center("hello world")
+            .end_bare(" B ")
+            .end_bare(" C ");
+        assert_eq!(render(line.clone(), 22), " A  hello world  C  B ");
+        assert_eq!(render(line.clone(), 21), " A  hello wor…  C  B ");
+        assert_eq!(render(line.clone(), 16), " A  hell…  C  B ");
+        assert_eq!(render(line.clone(), 12), " A  …  C  B ");
+        // No room for the center and its margins.
+        assert_eq!(render(line.clone(), 11), " A    C  B ");
+        // The stacks alone are too wide: the outermost right item goes.
+        assert_eq!(render(line.clone(), 8), " A    C ");
+        assert_eq!(render(line, 5), " A   ");
+    }
+
+    #[test]
+    fn truncation_uses_display_width() {
+        let line = Line::from("日本語テキスト");
+        let truncated = truncate_line(&line, 6);
+        assert_eq!(truncated.to_string(), "日本…");
+        assert_eq!(truncated.width(), 5);
+        assert_eq!(truncate_line(&line, 14).to_string(), "日本語テキスト");
+    }
+}
@@ -1,73 +1,216 @@
+mod click;
+mod fit;
+
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
-use ratatui::style::Style;
-use ratatui::text::Span;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
 use ratatui::widgets::Widget;
 
+use fit::{truncate_line, Fit, ELLIPSIS};
+
 use crate::primitives::statusline::{
-    OperationalMode, StatusLineStacked, StyledStatusLine, SLANT_BL_TR, SLANT_TL_BR,
+    OperationalMode, StackedItem, StatusLineStacked, StyledStatusLine, SLANT_BL_TR, SLANT_TL_BR,
 };
 
-impl<'a> Widget for StatusLineStacked<'a> {
-    fn render(self, area: Rect, buf: &mut Buffer) {
+impl StackedItem<'_> {
+    /// Background the start of the text is drawn on.
+    fn background(&self) -> Option<Color> {
+        self.segment_bg.or_else(|| {
+            let first = self.text.spans.first().map(|span| span.style);
+            self.text.style.patch(first.unwrap_or_default()).bg
+        })
+    }
+}
+
+impl<'a> StatusLineStacked<'a> {
+    /// The gaps of one side's items, outermost first. A segment's
+    /// separator gets the background of the next item shown further in,
+    /// or of the line when it is the innermost one.
+    fn side_gaps(&self, items: &[StackedItem<'a>], widths: &[Option<u16>]) -> Vec<Line<'a>> {
+        let mut next_bg = self.style.bg;
+        let mut gaps = vec![Line::default(); items.len()];
+        for (index, item) in items.iter().enumerate().rev() {
+            if widths[index].is_none() {
+                continue;
+            }
+            gaps[index] = match (item.segment_bg, next_bg) {
+                (Some(_), Some(bg)) => item.gap.clone().patch_style(Style::new().bg(bg)),
+                _ => item.gap.clone(),
+            };
+            next_bg = item.background();
+        }
+        gaps
+    }
+}
+
+/// Where [`StatusLineStacked`] draws each part in an area.
+struct Placement {
+    fit: Fit,
+    /// Text and gap areas of each left indicator; `None` when dropped.
+    left: Vec<Option<(Rect, Rect)>>,
+    /// Text and gap areas of each right indicator; `None` when dropped.
+    right: Vec<Option<(Rect, Rect)>>,
+    left_overflow: Option<Rect>,
+    right_overflow: Option<Rect>,
+    /// Everything between the stacks.
+    between: Rect,
+    /// Area of the center text; `None` when dropped.
+    center: Option<Rect>,
+}
+
+impl<'a> StatusLineStacked<'a> {
+    /// Lays the indicators out in `area`, as [`render`](Widget::render)
+    /// draws them.
+    fn place(&self, area: Rect) -> Placement {
+        let fit = self.fit(area.width);
+        let indicator_width = Line::from(ELLIPSIS).width() as u16;
+
         let mut x_end = area.right();
-        for (status, gap) in self.right.iter() {
-            let width = status.width() as u16;
-            status.render(
-                Rect::new(x_end.saturating_sub(width), area.y, width, 1),
-                buf,
-            );
+        let mut right = Vec::with_capacity(self.right.len());
+        for (item, width) in self.right.iter().zip(&fit.right) {
+            let Some(width) = *width else {
+                right.push(None);
+                continue;
+            };
+            let text = Rect::new(x_end.saturating_sub(width), area.y, width, 1);
             x_end = x_end.saturating_sub(width);
-
-            let width = gap.width() as u16;
-            gap.render(
-                Rect::new(x_end.saturating_sub(width), area.y, width, 1),
-                buf,
-            );
+            let width = item.gap.width() as u16;
+            let gap = Rect::new(x_end.saturating_sub(width), area.y, width, 1);
+            x_end = x_end.saturating_sub(width);
+            right.push(Some((text.intersection(area), gap.intersection(area))));
+        }
+        let mut right_overflow = None;
+        if self.overflow_indicator && fit.right.contains(&None) {
+            let width = indicator_width;
+            right_overflow =
+                Some(Rect::new(x_end.saturating_sub(width), area.y, width, 1).intersection(area));
             x_end = x_end.saturating_sub(width);
         }
 
         let mut x_start = area.x;
-        for (status, gap) in self.left.iter() {
-            let width = status.width() as u16;
-            status.render(Rect::new(x_start, area.y, width, 1), buf);
+        let mut left = Vec::with_capacity(self.left.len());
+        for (item, width) in self.left.iter().zip(&fit.left) {
+            let Some(width) = *width else {
+                left.push(None);
+                continue;
+            };
+            let text = Rect::new(x_start, area.y, width, 1);
             x_start += width;
-
-            let width = gap.width() as u16;
-            gap.render(Rect::new(x_start, area.y, width, 1), buf);
+            let width = item.gap.width() as u16;
+            let gap = Rect::new(x_start, area.y, width, 1);
+            x_start += width;
+            left.push(Some((text.intersection(area), gap.intersection(area))));
+        }
+        let mut left_overflow = None;
+        if self.overflow_indicator && fit.left.contains(&None) {
+            let width = indicator_width;
+            left_overflow = Some(Rect::new(x_start, area.y, width, 1).intersection(area));
             x_start += width;
         }
 
-        buf.set_style(
-            Rect::new(x_start, area.y, x_end.saturating_sub(x_start), 1),
-            self.style,
-        );
-
-        let center_width = x_end
-            .saturating_sub(x_start)
-            .saturating_sub(self.center_margin * 2);
+        let between =
+            Rect::new(x_start, area.y, x_end.saturating_sub(x_start), 1).intersection(area);
+        let center = fit.center.map(|_| {
+            let width = x_end
+                .saturating_sub(x_start)
+                .saturating_sub(self.center_margin * 2);
+            Rect::new(x_start + self.center_margin, area.y, width, 1).intersection(area)
+        });
 
-        self.center.render(
-            Rect::new(x_start + self.center_margin, area.y, center_width, 1),
-            buf,
-        );
+        Placement {
+            fit,
+            left,
+            right,
+            left_overflow,
+            right_overflow,
+            between,
+            center,
+        }
     }
 }
 
-impl<'a> StyledStatusLine<'a> {
-    pub fn build(self) -> StatusLineStacked<'a> {
-        use ratatui::style::Color;
+impl<'a> Widget for StatusLineStacked<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let placement = self.place(area);
+        let fit = &placement.fit;
+        let indicator = Line::from(Span::styled(ELLIPSIS, self.style));
+        let right_gaps = self.side_gaps(&self.right, &fit.right);
+        let left_gaps = self.side_gaps(&self.left, &fit.left);
 
-        let color_title = Color::Rgb(70, 73, 77);
-        let color_mode = match self.mode {
+        let right = self.right.iter().zip(&fit.right).zip(&placement.right);
+        for (((item, width), areas), gap) in right.zip(right_gaps) {
+            if let (Some(width), Some((text_area, gap_area))) = (width, areas) {
+                truncate_line(&item.text, *width).render(*text_area, buf);
+                gap.render(*gap_area, buf);
+            }
+        }
+        if let Some(overflow) = placement.right_overflow {
+            indicator.clone().render(overflow, buf);
+        }
+
+        let left = self.left.iter().zip(&fit.left).zip(&placement.left);
+        for (((item, width), areas), gap) in left.zip(left_gaps) {
+            if let (Some(width), Some((text_area, gap_area))) = (width, areas) {
+                truncate_line(&item.text, *width).render(*text_area, buf);
+                gap.render(*gap_area, buf);
+            }
+        }
+        if let Some(overflow) = placement.left_overflow {
+            indicator.render(overflow, buf);
+        }
+
+        buf.set_style(placement.between, self.style);
+
+        if let Some(center) = placement.center {
+            truncate_line(&self.center.text, center.width).render(center, buf);
+        }
+    }
+}
+
+impl OperationalMode {
+    /// The color this mode is drawn in.
+    pub fn color(self) -> Color {
+        match self {
             OperationalMode::Operational => Color::Rgb(42, 193, 138),
             OperationalMode::Dire => Color::Rgb(255, 210, 88),
             OperationalMode::Evacuate => Color::Rgb(246, 90, 90),
-        };
+        }
+    }
+}
+
+impl<'a> StyledStatusLine<'a> {
+    /// The render and event metrics as one compact line, e.g.
+    /// `r:1234 12µs | e:56 3µs`.
+    pub fn metrics_line(&self) -> String {
+        format!(
+            "r:{} {}µs | e:{} {}µs",
+            self.render_count, self.render_time_us, self.event_count, self.event_time_us
+        )
+    }
+
+    /// The center text, with the metrics line when they are visible.
+    fn center_line(&self) -> String {
+        if !self.metrics_visible {
+            return self.center_text.clone();
+        }
+        if self.center_text.is_empty() {
+            self.metrics_line()
+        } else {
+            format!("{} | {}", self.center_text, self.metrics_line())
+        }
+    }
+
+    /// Builds the stacked status line with the title, mode and metrics.
+    pub fn build(self) -> StatusLineStacked<'a> {
+        let color_title = Color::Rgb(70, 73, 77);
+        let color_mode = self.mode.color();
         let color_info = Color::Rgb(44, 163, 170);
         let color_dark = Color::Rgb(80, 202, 210);
         let text_black = Color::Rgb(16, 19, 23);
 
+        let center = self.center_line();
+
         let mode_str = match self.mode {
             OperationalMode::Operational => " OPERATIONAL ",
             OperationalMode::Dire => " DIRE ",
@@ -86,7 +229,7 @@ impl<'a> StyledStatusLine<'a> {
                     Span::from(SLANT_TL_BR).style(Style::new().fg(color_mode)),
                 )
                 .center_margin(1)
-                .center(self.center_text)
+                .center(center)
                 .end(
                     Span::from(format!(
                         "R[{}][{}µs] ",
@@ -124,7 +267,7 @@ impl<'a> StyledStatusLine<'a> {
                 )
                 .start_bare(Span::from(mode_str).style(Style::new().fg(text_black).bg(color_mode)))
                 .center_margin(1)
-                .center(self.center_text)
+                .center(center)
                 .end_bare(
                     Span::from(format!(
                         "R[{}][{}µs] ",
@@ -146,3 +289,98 @@ impl<'a> StyledStatusLine<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::style::{Color, Style};
+    use ratatui::widgets::Widget;
+
+    use crate::primitives::statusline::{
+        StatusLineStacked, StyledStatusLine, SLANT_BL_TR, SLANT_TL_BR,
+    };
+
+    /// Symbol, foreground and background of each cell.
+    fn render(width: u16) -> Vec<(String, Option<Color>, Option<Color>)> {
+        let area = Rect::new(0, 0, width, 1);
+        let mut buf = Buffer::empty(area);
+        StatusLineStacked::new()
+            .style(Style::new().bg(Color::DarkGray))
+            .segment_start(" A ", Color::Black, Color::Red)
+            .segment_start(" B ", Color::Black, Color::Green)
+            .segment_end(" C ", Color::White, Color::Blue)
+            .render(area, &mut buf);
+        (0..width)
+            .map(|x| {
+                let cell = &buf[(x, 0)];
+                let fg = (cell.fg != Color::Reset).then_some(cell.fg);
+                let bg = (cell.bg != Color::Reset).then_some(cell.bg);
+                (cell.symbol().to_string(), fg, bg)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn segment_separators_blend_into_the_next_shown_item() {
+        let cells = render(16);
+        assert_eq!(
+            cells[1],
+            ("A".to_string(), Some(Color::Black), Some(Color::Red))
+        );
+        assert_eq!(
+            cells[3],
+            (
+                SLANT_TL_BR.to_string(),
+                Some(Color::Red),
+                Some(Color::Green)
+            )
+        );
+        assert_eq!(
+            cells[7],
+            (
+                SLANT_TL_BR.to_string(),
+                Some(Color::Green),
+                Some(Color::DarkGray)
+            )
+        );
+        assert_eq!(
+            cells[12],
+            (
+                SLANT_BL_TR.to_string(),
+                Some(Color::Blue),
+                Some(Color::DarkGray)
+            )
+        );
+        assert_eq!(
+            cells[14],
+            ("C".to_string(), Some(Color::White), Some(Color::Blue))
+        );
+
+        // With the inner left segment dropped too, the outer one meets the line.
+        let cells = render(7);
+        assert_eq!(
+            cells[3],
+            (
+                SLANT_TL_BR.to_string(),
+                Some(Color::Red),
+                Some(Color::DarkGray)
+            )
+        );
+        assert_eq!(cells[5].0, " ");
+    }
+
+    #[test]
+    fn metrics_line_is_shown_in_the_center() {
+        let status = StyledStatusLine::new()
+            .render_metrics(1234, 12)
+            .event_metrics(56, 3);
+        assert_eq!(status.metrics_line(), "r:1234 12µs | e:56 3µs");
+        assert_eq!(status.center_line(), "");
+
+        let status = status.with_metrics_visible(true);
+        assert_eq!(status.center_line(), "r:1234 12µs | e:56 3µs");
+        let status = status.center_text("Ready");
+        assert_eq!(status.center_line(), "Ready | r:1234 12µs | e:56 3µs");
+    }
+}
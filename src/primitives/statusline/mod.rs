@@ -28,12 +28,32 @@
 //!         Span::from(SLANT_BL_TR).style(Style::new().fg(Color::Cyan)),
 //!     );
 //! ```
+//!
+//! The same line with segments, whose separators are colored for you:
+//!
+//! ```rust,no_run
+//! use ratatui::style::Color;
+//! use ratatui_toolkit::statusline_stacked::StatusLineStacked;
+//!
+//! StatusLineStacked::new()
+//!     .segment_start(" STATUS ", Color::Black, Color::DarkGray)
+//!     .segment_start(" OPERATIONAL ", Color::Black, Color::Green)
+//!     .center("Some status message...")
+//!     .segment_end(" INFO ", Color::Black, Color::Cyan);
+//! ```
 
 pub mod constructors;
 pub mod methods;
+#[cfg(feature = "toast")]
+pub mod notification_badge;
+pub mod state;
 pub mod traits;
 
-use ratatui::style::Style;
+#[cfg(feature = "toast")]
+pub use notification_badge::{NotificationBadge, NotificationBadgeState};
+pub use state::{MessageLevel, StatusLineState};
+
+use ratatui::style::{Color, Style};
 use ratatui::text::Line;
 use std::marker::PhantomData;
 
@@ -52,16 +72,44 @@ pub const SLANT_BL_TR: &str = "\u{e0ba}";
 /// - Left: Stack indicators from left to right
 /// - Center: Centered status message
 /// - Right: Stack indicators from right to left
+///
+/// The center message gets the space left between the stacks, less
+/// `center_margin` on each side, and is shortened with `…` to fit it.
+///
+/// When the line is too narrow for everything, items are dropped in order
+/// of [`StackedItem::priority`], lowest first. Among equal priorities the
+/// center goes first, then the right items from the outermost inward, then
+/// the left items from the innermost outward.
+/// An item marked [`StackedItem::truncate`] is shortened with `…` when its
+/// turn comes, and only dropped if that is not enough.
 #[derive(Debug, Clone)]
 pub struct StatusLineStacked<'a> {
     style: Style,
-    left: Vec<(Line<'a>, Line<'a>)>,
+    left: Vec<StackedItem<'a>>,
     center_margin: u16,
-    center: Line<'a>,
-    right: Vec<(Line<'a>, Line<'a>)>,
+    center: StackedItem<'a>,
+    right: Vec<StackedItem<'a>>,
+    overflow_indicator: bool,
     phantom: PhantomData<&'a ()>,
 }
 
+/// Identifies a [`StackedItem`] for [`StatusLineStacked::handle_click`].
+pub type StatusItemId = u32;
+
+/// One indicator of a [`StatusLineStacked`], with its overflow behaviour.
+#[derive(Debug, Clone, Default)]
+pub struct StackedItem<'a> {
+    id: Option<StatusItemId>,
+    text: Line<'a>,
+    gap: Line<'a>,
+    priority: u8,
+    min_width: u16,
+    truncate: bool,
+    /// Background of a segment built by `segment_start`/`segment_end`,
+    /// whose separator is colored into the next shown item's background.
+    segment_bg: Option<Color>,
+}
+
 /// Operational mode for styled statusline
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum OperationalMode {
@@ -87,4 +135,5 @@ pub struct StyledStatusLine<'a> {
     event_time_us: u64,
     message_count: u32,
     use_slants: bool,
+    metrics_visible: bool,
 }
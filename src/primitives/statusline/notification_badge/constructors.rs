@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use ratatui::style::{Color, Modifier, Style};
+
+use crate::primitives::statusline::notification_badge::{
+    NotificationBadge, BELL_ICON, DEFAULT_BADGE_HIGHLIGHT,
+};
+
+impl NotificationBadge {
+    /// Creates a badge with the default bell icon and styles.
+    pub fn new() -> Self {
+        Self {
+            icon: BELL_ICON.to_string(),
+            style: Style::new().fg(Color::DarkGray),
+            unread_style: Style::new().fg(Color::Yellow),
+            highlight_style: Style::new()
+                .fg(Color::Black)
+                .bg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+            highlight_duration: DEFAULT_BADGE_HIGHLIGHT,
+            quiet: false,
+            toasts_hidden: false,
+        }
+    }
+
+    /// Sets the icon shown before the unread count.
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = icon.into();
+        self
+    }
+
+    /// Style used when there are no unread toasts.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Style used when there are unread toasts.
+    pub fn unread_style(mut self, style: Style) -> Self {
+        self.unread_style = style;
+        self
+    }
+
+    /// Style used briefly after a toast arrives unseen.
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+
+    /// Sets how long the new-notification highlight lasts.
+    pub fn highlight_duration(mut self, duration: Duration) -> Self {
+        self.highlight_duration = duration;
+        self
+    }
+
+    /// Render nothing while there are no unread toasts.
+    pub fn quiet(mut self, quiet: bool) -> Self {
+        self.quiet = quiet;
+        self
+    }
+
+    /// Tell the badge that toast popups are currently not drawn.
+    ///
+    /// New toasts then trigger the highlight, same as with do-not-disturb.
+    pub fn toasts_hidden(mut self, hidden: bool) -> Self {
+        self.toasts_hidden = hidden;
+        self
+    }
+}
+
+impl Default for NotificationBadge {
+    fn default() -> Self {
+        Self::new()
+    }
+}
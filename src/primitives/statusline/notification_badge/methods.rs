@@ -0,0 +1,225 @@
+use std::time::Instant;
+
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+
+use crate::primitives::statusline::notification_badge::{
+    NotificationBadge, NotificationBadgeState,
+};
+use crate::primitives::toast::ToastManager;
+
+impl NotificationBadge {
+    /// Picks up new toasts by comparing the manager's generation counter.
+    ///
+    /// Call once per frame before [`Self::line`]. The first call only
+    /// records the current generation.
+    pub fn sync(&self, state: &mut NotificationBadgeState, toasts: &ToastManager, now: Instant) {
+        let generation = toasts.generation();
+        let Some(seen) = state.seen_generation else {
+            state.seen_generation = Some(generation);
+            return;
+        };
+        if seen == generation {
+            return;
+        }
+
+        state.seen_generation = Some(generation);
+        if toasts.is_do_not_disturb() || self.toasts_hidden {
+            state.highlight_until = Some(now + self.highlight_duration);
+        }
+    }
+
+    /// Builds the segment, or `None` when quiet and nothing is unread.
+    pub fn line(
+        &self,
+        state: &NotificationBadgeState,
+        toasts: &ToastManager,
+        now: Instant,
+    ) -> Option<Line<'static>> {
+        let unread = toasts.unread_count();
+        if unread == 0 && self.quiet {
+            return None;
+        }
+
+        let style = if state.is_highlighted(now) {
+            self.highlight_style
+        } else if unread > 0 {
+            self.unread_style
+        } else {
+            self.style
+        };
+
+        Some(Line::from(Span::styled(
+            format!(" {} {} ", self.icon, unread),
+            style,
+        )))
+    }
+
+    /// Area of the badge when pushed as the first `end` segment of a status line.
+    pub fn area(&self, toasts: &ToastManager, statusline_area: Rect) -> Rect {
+        let unread = toasts.unread_count();
+        if unread == 0 && self.quiet {
+            return Rect::new(statusline_area.right(), statusline_area.y, 0, 0);
+        }
+
+        let width = Line::from(format!(" {} {} ", self.icon, unread)).width() as u16;
+        let width = width.min(statusline_area.width);
+        Rect::new(
+            statusline_area.right().saturating_sub(width),
+            statusline_area.y,
+            width,
+            statusline_area.height.min(1),
+        )
+    }
+
+    /// Toggles the history overlay when the click lands on the badge.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the click was on the badge.
+    pub fn handle_click(
+        &self,
+        state: &mut NotificationBadgeState,
+        toasts: &mut ToastManager,
+        statusline_area: Rect,
+        column: u16,
+        row: u16,
+    ) -> bool {
+        let area = self.area(toasts, statusline_area);
+        if column < area.x || column >= area.right() || row < area.y || row >= area.bottom() {
+            return false;
+        }
+
+        self.activate(state, toasts);
+        true
+    }
+
+    /// Toggles the history overlay, marking all toasts read when opening it.
+    ///
+    /// Bind this to a key for keyboard access.
+    pub fn activate(&self, state: &mut NotificationBadgeState, toasts: &mut ToastManager) {
+        state.history_open = !state.history_open;
+        if state.history_open {
+            toasts.mark_all_read();
+            state.highlight_until = None;
+        }
+    }
+}
+
+impl NotificationBadgeState {
+    /// Whether the toast history overlay should be drawn.
+    ///
+    /// Render it with [`render_toast_history`](crate::primitives::toast::render_toast_history)
+    /// anchored to [`NotificationBadge::area`].
+    pub fn is_history_open(&self) -> bool {
+        self.history_open
+    }
+
+    /// Hides the toast history overlay.
+    pub fn close_history(&mut self) {
+        self.history_open = false;
+    }
+
+    /// Whether the new-notification highlight is still running at `now`.
+    pub fn is_highlighted(&self, now: Instant) -> bool {
+        self.highlight_until.is_some_and(|until| now < until)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn badge_text(line: &Line) -> String {
+        line.spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn test_badge_counts_unread_toasts() {
+        let mut toasts = ToastManager::new();
+        let badge = NotificationBadge::new().icon("B");
+        let state = NotificationBadgeState::default();
+        let now = Instant::now();
+
+        toasts.info("one");
+        toasts.error("two");
+
+        let line = badge.line(&state, &toasts, now).unwrap();
+        assert_eq!(badge_text(&line), " B 2 ");
+    }
+
+    #[test]
+    fn test_quiet_badge_hides_when_nothing_unread() {
+        let mut toasts = ToastManager::new();
+        let badge = NotificationBadge::new().quiet(true);
+        let state = NotificationBadgeState::default();
+        let now = Instant::now();
+
+        assert!(badge.line(&state, &toasts, now).is_none());
+        assert!(NotificationBadge::new()
+            .line(&state, &toasts, now)
+            .is_some());
+
+        toasts.info("hello");
+        assert!(badge.line(&state, &toasts, now).is_some());
+    }
+
+    #[test]
+    fn test_highlight_only_when_toasts_unseen() {
+        let mut toasts = ToastManager::new();
+        let badge = NotificationBadge::new();
+        let mut state = NotificationBadgeState::default();
+        let now = Instant::now();
+        badge.sync(&mut state, &toasts, now);
+
+        toasts.info("visible");
+        badge.sync(&mut state, &toasts, now);
+        assert!(!state.is_highlighted(now));
+
+        toasts.set_do_not_disturb(true);
+        toasts.info("muted");
+        badge.sync(&mut state, &toasts, now);
+        assert!(state.is_highlighted(now));
+        assert!(!state.is_highlighted(now + Duration::from_secs(5)));
+        assert_eq!(toasts.get_active().len(), 1);
+        assert_eq!(toasts.history().len(), 2);
+    }
+
+    #[test]
+    fn test_hidden_toast_area_triggers_highlight() {
+        let mut toasts = ToastManager::new();
+        let badge = NotificationBadge::new().toasts_hidden(true);
+        let mut state = NotificationBadgeState::default();
+        let now = Instant::now();
+        badge.sync(&mut state, &toasts, now);
+
+        toasts.warning("unseen");
+        badge.sync(&mut state, &toasts, now);
+
+        assert!(state.is_highlighted(now));
+    }
+
+    #[test]
+    fn test_click_opens_history_and_marks_read() {
+        let mut toasts = ToastManager::new();
+        let badge = NotificationBadge::new().icon("B");
+        let mut state = NotificationBadgeState::default();
+        let statusline = Rect::new(0, 20, 40, 1);
+
+        toasts.info("one");
+        let area = badge.area(&toasts, statusline);
+        assert_eq!(area, Rect::new(35, 20, 5, 1));
+
+        assert!(!badge.handle_click(&mut state, &mut toasts, statusline, 10, 20));
+        assert!(badge.handle_click(&mut state, &mut toasts, statusline, 36, 20));
+        assert!(state.is_history_open());
+        assert_eq!(toasts.unread_count(), 0);
+
+        badge.activate(&mut state, &mut toasts);
+        assert!(!state.is_history_open());
+    }
+}
@@ -0,0 +1,63 @@
+//! Notification badge segment backed by the toast history.
+//!
+//! Shows a bell icon with the unread toast count. The badge flashes when a
+//! toast arrives while its popup would not be seen (do-not-disturb or a
+//! hidden toast area), and toggles the toast history overlay when activated.
+//!
+//! Push the badge as the first `end` segment so it is the rightmost item and
+//! the last one covered when the status line runs out of room.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::time::Instant;
+//! use ratkit::primitives::statusline::{NotificationBadge, NotificationBadgeState, StatusLineStacked};
+//! use ratkit::primitives::toast::ToastManager;
+//!
+//! let toasts = ToastManager::new();
+//! let badge = NotificationBadge::new().quiet(true);
+//! let mut badge_state = NotificationBadgeState::default();
+//!
+//! let now = Instant::now();
+//! badge.sync(&mut badge_state, &toasts, now);
+//!
+//! let mut status = StatusLineStacked::new();
+//! if let Some(line) = badge.line(&badge_state, &toasts, now) {
+//!     status = status.end_bare(line);
+//! }
+//! ```
+
+mod constructors;
+mod methods;
+
+use std::time::{Duration, Instant};
+
+use ratatui::style::Style;
+
+/// Nerd Font bell glyph used by default.
+pub const BELL_ICON: &str = "\u{f0f3}";
+
+/// Default duration of the new-notification highlight.
+pub const DEFAULT_BADGE_HIGHLIGHT: Duration = Duration::from_millis(1500);
+
+/// Status line segment showing the unread toast count.
+#[derive(Debug, Clone)]
+pub struct NotificationBadge {
+    icon: String,
+    style: Style,
+    unread_style: Style,
+    highlight_style: Style,
+    highlight_duration: Duration,
+    quiet: bool,
+    toasts_hidden: bool,
+}
+
+/// Per-frame state for a [`NotificationBadge`].
+///
+/// Persist this across frames alongside the `ToastManager`.
+#[derive(Debug, Clone, Default)]
+pub struct NotificationBadgeState {
+    seen_generation: Option<u64>,
+    highlight_until: Option<Instant>,
+    history_open: bool,
+}
@@ -0,0 +1,11 @@
+use crate::primitives::statusline::state::StatusLineState;
+
+impl StatusLineState {
+    /// Creates a state showing `baseline` with no message.
+    pub fn new(baseline: impl Into<String>) -> Self {
+        Self {
+            baseline: baseline.into(),
+            message: None,
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use std::time::{Duration, Instant};
+
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+
+use crate::primitives::statusline::state::{
+    MessageLevel, StatusLineState, TransientMessage, FADE_FRACTION,
+};
+use crate::primitives::statusline::{OperationalMode, StatusLineStacked};
+
+impl MessageLevel {
+    /// The operational mode whose color this level uses.
+    pub fn mode(self) -> OperationalMode {
+        match self {
+            MessageLevel::Info => OperationalMode::Operational,
+            MessageLevel::Warn => OperationalMode::Dire,
+            MessageLevel::Error => OperationalMode::Evacuate,
+        }
+    }
+}
+
+impl StatusLineState {
+    /// Replaces the text shown when there is no message.
+    pub fn set_baseline(&mut self, baseline: impl Into<String>) {
+        self.baseline = baseline.into();
+    }
+
+    /// The text shown when there is no message.
+    pub fn baseline(&self) -> &str {
+        &self.baseline
+    }
+
+    /// Shows an info message for `timeout`.
+    pub fn set_message(&mut self, text: impl Into<String>, timeout: Duration) {
+        self.set_message_with_level(text, timeout, MessageLevel::Info);
+    }
+
+    /// Shows a message for `timeout`, replacing any current message.
+    ///
+    /// The timeout starts at the next [`tick`](Self::tick).
+    pub fn set_message_with_level(
+        &mut self,
+        text: impl Into<String>,
+        timeout: Duration,
+        level: MessageLevel,
+    ) {
+        self.message = Some(TransientMessage {
+            text: text.into(),
+            level,
+            timeout,
+            shown_at: None,
+            fading: false,
+        });
+    }
+
+    /// Removes the current message, if any.
+    pub fn clear_message(&mut self) {
+        self.message = None;
+    }
+
+    /// Advances the message timer to `now`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the displayed text or its style changed and the status line
+    /// should be redrawn.
+    pub fn tick(&mut self, now: Instant) -> bool {
+        let Some(message) = self.message.as_mut() else {
+            return false;
+        };
+        let shown_at = *message.shown_at.get_or_insert(now);
+        let elapsed = now.saturating_duration_since(shown_at);
+        if elapsed >= message.timeout {
+            self.message = None;
+            return true;
+        }
+
+        let fading = elapsed >= message.timeout - message.timeout / FADE_FRACTION;
+        let changed = fading != message.fading;
+        message.fading = fading;
+        changed
+    }
+
+    /// The text currently shown: the message if there is one, otherwise
+    /// the baseline.
+    pub fn text(&self) -> &str {
+        self.message
+            .as_ref()
+            .map_or(self.baseline.as_str(), |message| message.text.as_str())
+    }
+
+    /// The level of the current message, if any.
+    pub fn message_level(&self) -> Option<MessageLevel> {
+        self.message.as_ref().map(|message| message.level)
+    }
+
+    /// The center line to render: the baseline unstyled, or the message in
+    /// its level's color.
+    pub fn line(&self) -> Line<'static> {
+        let Some(message) = &self.message else {
+            return Line::from(self.baseline.clone());
+        };
+        let mut style = Style::new()
+            .fg(message.level.mode().color())
+            .add_modifier(Modifier::BOLD);
+        if message.fading {
+            style = style.add_modifier(Modifier::DIM);
+        }
+        Line::from(Span::styled(message.text.clone(), style))
+    }
+}
+
+impl<'a> StatusLineStacked<'a> {
+    /// Sets the center to the effective text of `state`.
+    pub fn center_state(self, state: &StatusLineState) -> Self {
+        self.center(state.line())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use ratatui::style::Modifier;
+
+    use crate::primitives::statusline::{MessageLevel, StatusLineState};
+
+    #[test]
+    fn test_message_reverts_to_baseline_after_timeout() {
+        let mut state = StatusLineState::new("main.rs");
+        let start = Instant::now();
+
+        state.set_message("Saved", Duration::from_secs(2));
+        assert_eq!(state.text(), "Saved");
+        state.tick(start);
+        assert!(!state.tick(start + Duration::from_millis(1000)));
+        assert_eq!(state.text(), "Saved");
+
+        assert!(state.tick(start + Duration::from_secs(2)));
+        assert_eq!(state.text(), "main.rs");
+        assert_eq!(state.message_level(), None);
+        assert!(!state.tick(start + Duration::from_secs(3)));
+    }
+
+    #[test]
+    fn test_new_message_replaces_and_restarts_timer() {
+        let mut state = StatusLineState::new("ready");
+        let start = Instant::now();
+
+        state.set_message("1 match", Duration::from_secs(2));
+        state.tick(start);
+        state.set_message_with_level(
+            "No write since last change",
+            Duration::from_secs(2),
+            MessageLevel::Warn,
+        );
+        state.tick(start + Duration::from_millis(1500));
+
+        // Past the first message's timeout, within the second's.
+        state.tick(start + Duration::from_millis(2500));
+        assert_eq!(state.text(), "No write since last change");
+        assert_eq!(state.message_level(), Some(MessageLevel::Warn));
+
+        state.tick(start + Duration::from_millis(3500));
+        assert_eq!(state.text(), "ready");
+    }
+
+    #[test]
+    fn test_message_dims_before_expiring() {
+        let mut state = StatusLineState::new("");
+        let start = Instant::now();
+        state.set_message_with_level("Write failed", Duration::from_secs(5), MessageLevel::Error);
+        state.tick(start);
+
+        let style = |state: &StatusLineState| state.line().spans[0].style;
+        assert!(!style(&state).add_modifier.contains(Modifier::DIM));
+        assert_eq!(style(&state).fg, Some(MessageLevel::Error.mode().color()));
+
+        assert!(state.tick(start + Duration::from_secs(4)));
+        assert!(style(&state).add_modifier.contains(Modifier::DIM));
+    }
+}
@@ -0,0 +1,65 @@
+//! Transient messages for the center of a [`StatusLineStacked`].
+//!
+//! [`StatusLineState`] holds a persistent baseline text and, like vim's
+//! command line, can briefly replace it with a message such as "Saved" or
+//! "3 matches". A message is shown until its timeout passes, dimming for the
+//! last part of its life, and a new message replaces the current one and
+//! restarts the timer.
+//!
+//! The state has no clock of its own: call [`StatusLineState::tick`] from
+//! the app's tick handler, and the timeout starts counting at the first
+//! tick after [`StatusLineState::set_message`].
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::time::{Duration, Instant};
+//! use ratkit::primitives::statusline::{MessageLevel, StatusLineStacked, StatusLineState};
+//!
+//! let mut state = StatusLineState::new("README.md");
+//! state.set_message_with_level("Write failed", Duration::from_secs(3), MessageLevel::Error);
+//!
+//! // On every tick:
+//! state.tick(Instant::now());
+//! let status = StatusLineStacked::new().center_state(&state);
+//! ```
+//!
+//! [`StatusLineStacked`]: crate::primitives::statusline::StatusLineStacked
+
+mod constructors;
+mod methods;
+
+use std::time::{Duration, Instant};
+
+/// Fraction of a message's timeout during which it is drawn dimmed.
+const FADE_FRACTION: u32 = 5;
+
+/// Severity of a transient message, which picks its color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MessageLevel {
+    /// Routine feedback, drawn in the `Operational` color.
+    #[default]
+    Info,
+    /// Something needs attention, drawn in the `Dire` color.
+    Warn,
+    /// Something failed, drawn in the `Evacuate` color.
+    Error,
+}
+
+/// Center text of a status line: a baseline plus an optional transient
+/// message.
+#[derive(Debug, Clone, Default)]
+pub struct StatusLineState {
+    baseline: String,
+    message: Option<TransientMessage>,
+}
+
+#[derive(Debug, Clone)]
+struct TransientMessage {
+    text: String,
+    level: MessageLevel,
+    timeout: Duration,
+    /// When the message was first ticked; `None` until then.
+    shown_at: Option<Instant>,
+    fading: bool,
+}
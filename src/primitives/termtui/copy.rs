@@ -0,0 +1,65 @@
+//! Copying what the terminal shows.
+
+use crate::primitives::termtui::vt100::Screen;
+use crate::services::clipboard::{ClipboardSource, CopyPayload, CopyProvider};
+
+/// Offers the visible screen, without trailing blank lines, and the line
+/// the cursor is on.
+impl CopyProvider for Screen {
+    fn copy_payloads(&self) -> Vec<CopyPayload> {
+        let size = self.size();
+        if size.height == 0 || size.width == 0 {
+            return Vec::new();
+        }
+        let last_col = i32::from(size.width) - 1;
+
+        let screen = self.get_selected_text(0, 0, last_col, i32::from(size.height) - 1);
+        let screen = screen
+            .lines()
+            .map(str::trim_end)
+            .collect::<Vec<_>>()
+            .join("\n");
+        let screen = screen.trim_end();
+
+        let mut payloads = Vec::new();
+        if !screen.is_empty() {
+            payloads.push(CopyPayload::new("Screen", screen));
+        }
+        let (row, _) = self.cursor_position();
+        let row = i32::from(row);
+        let line = self.get_selected_text(0, row, last_col, row);
+        let line = line.trim_end();
+        if !line.is_empty() {
+            payloads.push(CopyPayload::new("Cursor line", line));
+        }
+        payloads
+    }
+
+    fn copy_source(&self) -> ClipboardSource {
+        ClipboardSource::Terminal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::primitives::termtui::Parser;
+    use crate::services::clipboard::{CopyPayload, CopyProvider};
+
+    #[test]
+    fn screen_offers_its_text_and_the_cursor_line() {
+        let mut parser = Parser::new(5, 20, 0);
+        assert!(parser.screen().copy_payloads().is_empty());
+
+        let mut events = Vec::new();
+        parser
+            .screen
+            .process(b"$ cargo test\r\nok\r\n$ ls", &mut events);
+        assert_eq!(
+            parser.screen().copy_payloads(),
+            [
+                CopyPayload::new("Screen", "$ cargo test\nok\n$ ls"),
+                CopyPayload::new("Cursor line", "$ ls"),
+            ]
+        );
+    }
+}
@@ -1,13 +1,19 @@
 //! VT100 terminal emulation extracted from mprocs.
 
+#[cfg(feature = "clipboard")]
+mod copy;
 pub mod io;
+pub mod open_targets;
 pub mod protocol;
 pub mod ratatui_render;
+pub mod search;
 pub mod vt100;
 
 pub use io::write_screen_diff;
+pub use open_targets::OpenTargets;
 pub use protocol::CursorStyle;
-pub use ratatui_render::render_screen;
+pub use ratatui_render::{render_screen, CursorRenderer, ScreenRenderer};
+pub use search::{ScrollbackMatch, ScrollbackSearch};
 pub use vt100::{
     attrs, cell, grid, parser, row, screen, screen_differ, size, Attrs, BorderType, BufferView,
     Cell, Color, Grid, Margin, MouseProtocolMode, Parser, Pos, Rect, Screen, ScreenDiffer, Size,
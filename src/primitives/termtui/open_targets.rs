@@ -0,0 +1,232 @@
+//! Finding openable file positions, such as compiler errors, on screen.
+
+use std::fmt;
+use std::ops::Range;
+
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use ratatui::style::{Modifier, Style};
+
+use crate::primitives::termtui::vt100::Screen;
+use crate::services::external_opener::{default_patterns, find_targets, OpenTarget, TargetPattern};
+
+/// Finds file positions in a [`Screen`]'s text, underlines the one under
+/// the mouse, and turns a click on it into an [`OpenTarget`].
+///
+/// Recognizes `path:line:column` and Python tracebacks by default; add
+/// patterns with [`with_pattern`](Self::with_pattern). Positions are only
+/// found within a row, not across a line the terminal wrapped.
+#[derive(Clone)]
+pub struct OpenTargets {
+    patterns: Vec<TargetPattern>,
+    /// Row, columns and target under the mouse.
+    hovered: Option<(u16, Range<u16>, OpenTarget)>,
+}
+
+impl Default for OpenTargets {
+    fn default() -> Self {
+        Self {
+            patterns: default_patterns(),
+            hovered: None,
+        }
+    }
+}
+
+impl fmt::Debug for OpenTargets {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OpenTargets")
+            .field("patterns", &self.patterns.len())
+            .field("hovered", &self.hovered)
+            .finish()
+    }
+}
+
+impl OpenTargets {
+    /// Creates a finder with the default patterns.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also recognizes the positions `pattern` finds. Earlier patterns win
+    /// where matches overlap.
+    #[must_use]
+    pub fn with_pattern(mut self, pattern: TargetPattern) -> Self {
+        self.patterns.push(pattern);
+        self
+    }
+
+    /// Replaces the patterns, e.g. to drop the defaults.
+    #[must_use]
+    pub fn with_patterns(mut self, patterns: Vec<TargetPattern>) -> Self {
+        self.patterns = patterns;
+        self
+    }
+
+    /// The target shown at `row`, `col` of `screen`, if any.
+    #[must_use]
+    pub fn target_at(&self, screen: &Screen, row: u16, col: u16) -> Option<OpenTarget> {
+        self.find_at(screen, row, col).map(|(_, target)| target)
+    }
+
+    /// The target under the terminal's cursor, e.g. for a keypress that
+    /// opens what a copy mode cursor is on.
+    #[must_use]
+    pub fn target_at_cursor(&self, screen: &Screen) -> Option<OpenTarget> {
+        let (row, col) = screen.cursor_position();
+        self.target_at(screen, row, col)
+    }
+
+    /// The target under the mouse.
+    #[must_use]
+    pub fn hovered(&self) -> Option<&OpenTarget> {
+        self.hovered.as_ref().map(|(_, _, target)| target)
+    }
+
+    /// Underlines the target at `row`, `col`, or none if `None`. Returns
+    /// whether the underlined target changed.
+    pub fn set_hover(&mut self, screen: &Screen, position: Option<(u16, u16)>) -> bool {
+        let hovered = position.and_then(|(row, col)| {
+            let (cols, target) = self.find_at(screen, row, col)?;
+            Some((row, cols, target))
+        });
+        let changed = hovered != self.hovered;
+        self.hovered = hovered;
+        changed
+    }
+
+    /// Handles a mouse event over a screen drawn in `area`: moving the
+    /// mouse updates the underline and a left click returns the target
+    /// under it.
+    pub fn handle_mouse(
+        &mut self,
+        screen: &Screen,
+        area: Rect,
+        event: MouseEvent,
+    ) -> Option<OpenTarget> {
+        let position = area
+            .contains(Position::new(event.column, event.row))
+            .then(|| (event.row - area.y, event.column - area.x));
+        match event.kind {
+            MouseEventKind::Moved | MouseEventKind::Drag(_) => {
+                self.set_hover(screen, position);
+                None
+            }
+            MouseEventKind::Down(MouseButton::Left) => {
+                self.set_hover(screen, position);
+                self.hovered().cloned()
+            }
+            _ => None,
+        }
+    }
+
+    /// Underlines the hovered target in a screen drawn in `area` of `buf`.
+    /// Call it after rendering the screen.
+    pub fn render_hover(&self, area: Rect, buf: &mut Buffer) {
+        let Some((row, cols, _)) = &self.hovered else {
+            return;
+        };
+        if *row >= area.height {
+            return;
+        }
+        let width = area.width.min(cols.end).saturating_sub(cols.start);
+        let underline = Rect::new(area.x + cols.start, area.y + row, width, 1);
+        buf.set_style(
+            underline.intersection(area),
+            Style::default().add_modifier(Modifier::UNDERLINED),
+        );
+    }
+
+    /// The columns and target of the match at `row`, `col`.
+    fn find_at(&self, screen: &Screen, row: u16, col: u16) -> Option<(Range<u16>, OpenTarget)> {
+        let (text, columns) = row_text(screen, row)?;
+        let column_of = |byte: usize| columns.get(byte).copied().unwrap_or(screen.size().width);
+        find_targets(&text, &self.patterns)
+            .into_iter()
+            .map(|found| {
+                let cols = column_of(found.range.start)..column_of(found.range.end);
+                (cols, found.target)
+            })
+            .find(|(cols, _)| cols.contains(&col))
+    }
+}
+
+/// The text of visible row `row` and the column each byte of it is drawn
+/// in, with one extra entry for the end of the text.
+fn row_text(screen: &Screen, row: u16) -> Option<(String, Vec<u16>)> {
+    screen.cell(row, 0)?;
+    let mut text = String::new();
+    let mut columns = Vec::new();
+    let mut wide = false;
+    for col in 0..screen.size().width {
+        let Some(cell) = screen.cell(row, col) else {
+            break;
+        };
+        let contents = if cell.has_contents() {
+            cell.contents()
+        } else if wide {
+            // Second half of a wide character
+            wide = false;
+            continue;
+        } else {
+            " "
+        };
+        wide = cell.is_wide();
+        text.push_str(contents);
+        columns.extend(std::iter::repeat(col).take(contents.len()));
+    }
+    columns.push(screen.size().width);
+    Some((text, columns))
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::KeyModifiers;
+
+    use super::*;
+    use crate::primitives::termtui::vt100::Parser;
+
+    #[test]
+    fn test_hover_underlines_and_click_opens_error_location() {
+        let mut parser = Parser::new(3, 40, 0);
+        parser.screen.process(
+            "error: oops\r\n  --> 日本/lib.rs:42:7 here".as_bytes(),
+            &mut Vec::new(),
+        );
+        let screen = parser.screen();
+        let mut targets = OpenTargets::new();
+        let area = Rect::new(2, 1, 40, 3);
+        let mouse = |kind, column, row| MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        };
+
+        assert_eq!(targets.target_at(screen, 0, 3), None);
+        assert!(targets
+            .handle_mouse(screen, area, mouse(MouseEventKind::Moved, 10, 2))
+            .is_none());
+        let expected = OpenTarget::new("日本/lib.rs", 42).with_column(7);
+        assert_eq!(targets.hovered(), Some(&expected));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 45, 5));
+        targets.render_hover(area, &mut buf);
+        let underlined: Vec<u16> = (0..45)
+            .filter(|&x| buf[(x, 2)].modifier.contains(Modifier::UNDERLINED))
+            .collect();
+        assert_eq!(underlined, (8..24).collect::<Vec<_>>());
+
+        assert_eq!(
+            targets.handle_mouse(
+                screen,
+                area,
+                mouse(MouseEventKind::Down(MouseButton::Left), 23, 2)
+            ),
+            Some(expected)
+        );
+        targets.handle_mouse(screen, area, mouse(MouseEventKind::Moved, 30, 2));
+        assert_eq!(targets.hovered(), None);
+    }
+}
@@ -1,9 +1,12 @@
+use std::collections::HashMap;
+
 use ratatui::{
-    buffer::Buffer,
+    buffer::{Buffer, Cell as BufferCell},
     layout::Rect,
     style::{Color as RatColor, Modifier, Style},
 };
 
+use crate::primitives::termtui::protocol::CursorStyle;
 use crate::primitives::termtui::vt100::{Attrs, Color, Screen};
 
 pub fn render_screen(screen: &Screen, area: Rect, buf: &mut Buffer) {
@@ -16,20 +19,253 @@ pub fn render_screen(screen: &Screen, area: Rect, buf: &mut Buffer) {
             let Some(buf_cell) = buf.cell_mut((area.x + col, area.y + row)) else {
                 continue;
             };
-            if let Some(cell) = screen.cell(row, col) {
-                let symbol = if cell.has_contents() {
-                    cell.contents()
+            match screen_cell(screen, row, col) {
+                Some((symbol, style)) => {
+                    buf_cell.set_symbol(symbol);
+                    buf_cell.set_style(style);
+                }
+                None => {
+                    buf_cell.set_symbol("?");
+                }
+            }
+        }
+    }
+
+    render_scrollback_label(screen, area, buf);
+}
+
+/// Renders a [`Screen`] like [`render_screen`], but keeps the converted
+/// cells of the last frame and only converts rows that were written to
+/// since. Rows that merely moved, because the screen scrolled, are reused.
+/// Cells are copied over whatever the buffer held, where `render_screen`
+/// patches their style.
+///
+/// Keep one renderer per terminal and render it every frame. Resizing,
+/// scrolling and switching to the alternate screen are picked up on their
+/// own; call [`invalidate`](Self::invalidate) after changing anything else
+/// that affects how cells are drawn, such as the theme.
+#[derive(Debug, Clone, Default)]
+pub struct ScreenRenderer {
+    /// Converted cells per row, with the [row stamp](Screen::row_stamp)
+    /// they were converted from.
+    rows: Vec<(Option<u64>, CachedRow)>,
+    /// Width the cached rows were converted for.
+    width: u16,
+    /// Screen generation and cursor state at the last render.
+    rendered: Option<(u64, (u16, u16), bool)>,
+}
+
+impl ScreenRenderer {
+    /// Creates a renderer with an empty cache.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether `screen` changed, or its cursor moved, since the last
+    /// [`render`](Self::render).
+    ///
+    /// Hosts can skip requesting a redraw while this is `false`.
+    #[must_use]
+    pub fn has_changes_since_last_render(&self, screen: &Screen) -> bool {
+        self.rendered != Some(render_state(screen))
+    }
+
+    /// Drops the cache so the next render converts every row.
+    pub fn invalidate(&mut self) {
+        self.rows.clear();
+        self.rendered = None;
+    }
+
+    /// Draws `screen` into `area` of `buf`.
+    pub fn render(&mut self, screen: &Screen, area: Rect, buf: &mut Buffer) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+
+        if self.width != area.width {
+            self.width = area.width;
+            self.rows.clear();
+        }
+
+        let mut previous = std::mem::take(&mut self.rows);
+        let mut previous_rows: Option<HashMap<u64, usize>> = None;
+        for row in 0..area.height {
+            let stamp = screen.row_stamp(row);
+            let index = usize::from(row);
+            let reused = stamp.and_then(|stamp| {
+                if previous
+                    .get(index)
+                    .is_some_and(|(cached, _)| *cached == Some(stamp))
+                {
+                    return Some(index);
+                }
+                previous_rows
+                    .get_or_insert_with(|| {
+                        previous
+                            .iter()
+                            .enumerate()
+                            .filter_map(|(index, (cached, _))| Some(((*cached)?, index)))
+                            .collect()
+                    })
+                    .get(&stamp)
+                    .copied()
+            });
+            let cells = match reused {
+                Some(index) => std::mem::take(&mut previous[index].1),
+                None => convert_row(screen, row, area.width),
+            };
+
+            for (col, cell) in (0..area.width).zip(cells.iter()) {
+                if let Some(buf_cell) = buf.cell_mut((area.x + col, area.y + row)) {
+                    buf_cell.clone_from(cell);
+                }
+            }
+            self.rows.push((stamp, cells));
+        }
+
+        render_scrollback_label(screen, area, buf);
+        self.rendered = Some(render_state(screen));
+    }
+}
+
+/// Draws a [`Screen`]'s cursor into a buffer, in the shape the program
+/// chose with DECSCUSR: a block, an underline or a bar.
+///
+/// Hosts that show the real cursor with `Frame::set_cursor_position` do
+/// not need this. It is for drawing the cursor as part of the buffer, e.g.
+/// when several terminals are on screen. Render it after the screen, and
+/// skip it while the terminal is unfocused or in copy mode. Nothing is
+/// drawn while the program hides the cursor.
+///
+/// Blinking cursors blink as the host calls [`tick`](Self::tick), for
+/// example every 500 ms. The default shape blinks, as in xterm.
+#[derive(Debug, Clone)]
+pub struct CursorRenderer {
+    /// Whether a blinking cursor is in the shown half of its blink.
+    blink_on: bool,
+}
+
+impl Default for CursorRenderer {
+    fn default() -> Self {
+        Self { blink_on: true }
+    }
+}
+
+impl CursorRenderer {
+    /// Creates a renderer whose blinking cursors start out shown.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Advances the blink, showing or hiding blinking cursors. Redraw
+    /// after calling it.
+    pub fn tick(&mut self) {
+        self.blink_on = !self.blink_on;
+    }
+
+    /// Shows a blinking cursor again straight away, e.g. after a key
+    /// press, so it stays visible while the user types.
+    pub fn reset_blink(&mut self) {
+        self.blink_on = true;
+    }
+
+    /// Whether the cursor of `screen` is drawn at the moment.
+    #[must_use]
+    pub fn is_visible(&self, screen: &Screen) -> bool {
+        let blinks = matches!(
+            screen.cursor_style(),
+            CursorStyle::Default
+                | CursorStyle::BlinkingBlock
+                | CursorStyle::BlinkingUnderline
+                | CursorStyle::BlinkingBar
+        );
+        !screen.hide_cursor() && (self.blink_on || !blinks)
+    }
+
+    /// Draws the cursor of `screen`, rendered in `area` of `buf`.
+    ///
+    /// A block reverses the cell and an underline underlines it. A bar is
+    /// drawn as `▏` over a blank cell, and as an underline over text so
+    /// the text stays readable.
+    pub fn render(&self, screen: &Screen, area: Rect, buf: &mut Buffer) {
+        if !self.is_visible(screen) {
+            return;
+        }
+        let (row, col) = screen.cursor_position();
+        // Scrolling back moves the screen, and the cursor with it, down.
+        let Some(row) = u16::try_from(screen.scrollback())
+            .ok()
+            .and_then(|scrollback| row.checked_add(scrollback))
+        else {
+            return;
+        };
+        if row >= area.height || col >= area.width {
+            return;
+        }
+        let Some(cell) = buf.cell_mut((area.x + col, area.y + row)) else {
+            return;
+        };
+        match screen.cursor_style() {
+            CursorStyle::Default | CursorStyle::BlinkingBlock | CursorStyle::SteadyBlock => {
+                cell.modifier.toggle(Modifier::REVERSED);
+            }
+            CursorStyle::BlinkingUnderline | CursorStyle::SteadyUnderline => {
+                cell.modifier.insert(Modifier::UNDERLINED);
+            }
+            CursorStyle::BlinkingBar | CursorStyle::SteadyBar => {
+                if cell.symbol() == " " {
+                    cell.set_symbol("▏");
                 } else {
-                    " "
-                };
-                buf_cell.set_symbol(symbol);
-                buf_cell.set_style(style_from_attrs(*cell.attrs()));
-            } else {
-                buf_cell.set_symbol("?");
+                    cell.modifier.insert(Modifier::UNDERLINED);
+                }
             }
         }
     }
+}
+
+fn convert_row(screen: &Screen, row: u16, width: u16) -> CachedRow {
+    (0..width)
+        .map(|col| {
+            let mut cell = BufferCell::default();
+            match screen_cell(screen, row, col) {
+                Some((symbol, style)) => {
+                    cell.set_symbol(symbol);
+                    cell.set_style(style);
+                }
+                None => {
+                    cell.set_symbol("?");
+                }
+            }
+            cell
+        })
+        .collect()
+}
+
+/// A row's cells, ready to copy into the buffer.
+type CachedRow = Vec<BufferCell>;
+
+fn render_state(screen: &Screen) -> (u64, (u16, u16), bool) {
+    (
+        screen.generation(),
+        screen.cursor_position(),
+        screen.hide_cursor(),
+    )
+}
 
+/// The symbol and style of a screen cell, or `None` outside the screen.
+fn screen_cell(screen: &Screen, row: u16, col: u16) -> Option<(&str, Style)> {
+    let cell = screen.cell(row, col)?;
+    let symbol = if cell.has_contents() {
+        cell.contents()
+    } else {
+        " "
+    };
+    Some((symbol, style_from_attrs(*cell.attrs())))
+}
+
+fn render_scrollback_label(screen: &Screen, area: Rect, buf: &mut Buffer) {
     let scrollback = screen.scrollback();
     if scrollback > 0 {
         let label = format!(" -{} ", scrollback);
@@ -43,7 +279,7 @@ pub fn render_screen(screen: &Screen, area: Rect, buf: &mut Buffer) {
     }
 }
 
-fn style_from_attrs(attrs: Attrs) -> Style {
+pub(crate) fn style_from_attrs(attrs: Attrs) -> Style {
     let mut style = Style::default()
         .fg(to_ratatui_color(attrs.fgcolor))
         .bg(to_ratatui_color(attrs.bgcolor));
@@ -91,3 +327,134 @@ fn to_ratatui_color(color: Color) -> RatColor {
         Color::Rgb(r, g, b) => RatColor::Rgb(r, g, b),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::termtui::vt100::Parser;
+
+    fn process(parser: &mut Parser, data: &str) {
+        parser.screen.process(data.as_bytes(), &mut Vec::new());
+    }
+
+    /// Renders with `renderer` and checks the result against a full
+    /// [`render_screen`], returning the rows that were converted again.
+    fn render(renderer: &mut ScreenRenderer, parser: &Parser) -> Vec<u16> {
+        let area = Rect::new(0, 0, 20, 5);
+        let cached: Vec<Option<u64>> = renderer.rows.iter().map(|(stamp, _)| *stamp).collect();
+        let mut buf = Buffer::empty(area);
+        renderer.render(parser.screen(), area, &mut buf);
+
+        let mut expected = Buffer::empty(area);
+        render_screen(parser.screen(), area, &mut expected);
+        assert_eq!(buf, expected);
+
+        (0..area.height)
+            .filter(|&row| !cached.contains(&renderer.rows[usize::from(row)].0))
+            .collect()
+    }
+
+    #[test]
+    fn test_only_changed_rows_are_converted() {
+        let mut parser = Parser::new(5, 20, 100);
+        let mut renderer = ScreenRenderer::new();
+        process(&mut parser, "$ ");
+        assert!(renderer.has_changes_since_last_render(parser.screen()));
+        assert_eq!(render(&mut renderer, &parser), [0, 1, 2, 3, 4]);
+
+        // Idle: nothing to redraw.
+        assert!(!renderer.has_changes_since_last_render(parser.screen()));
+        assert!(render(&mut renderer, &parser).is_empty());
+
+        process(&mut parser, "ls\r\nfile.txt");
+        assert!(renderer.has_changes_since_last_render(parser.screen()));
+        assert_eq!(render(&mut renderer, &parser), [0, 1]);
+
+        // A cursor move alone needs a redraw but no rows.
+        process(&mut parser, "\x1b[1;1H");
+        assert!(renderer.has_changes_since_last_render(parser.screen()));
+        assert!(render(&mut renderer, &parser).is_empty());
+        assert!(!renderer.has_changes_since_last_render(parser.screen()));
+    }
+
+    #[test]
+    fn test_scrolled_rows_are_reused_and_screen_switches_repaint() {
+        let mut parser = Parser::new(5, 20, 100);
+        let mut renderer = ScreenRenderer::new();
+        for i in 0..8 {
+            process(&mut parser, &format!("line {i}\r\n"));
+        }
+        render(&mut renderer, &parser);
+
+        // Output scrolls every row up; only the written and the new row
+        // are converted.
+        process(&mut parser, "more\r\n");
+        assert!(renderer.has_changes_since_last_render(parser.screen()));
+        assert_eq!(render(&mut renderer, &parser), [3, 4]);
+
+        parser.set_scrollback(2);
+        assert!(renderer.has_changes_since_last_render(parser.screen()));
+        assert_eq!(render(&mut renderer, &parser), [0, 1]);
+        parser.set_scrollback(0);
+        assert_eq!(render(&mut renderer, &parser), [3, 4]);
+
+        process(&mut parser, "\x1b[?1049h");
+        assert_eq!(render(&mut renderer, &parser), [0, 1, 2, 3, 4]);
+        process(&mut parser, "\x1b[?1049l");
+        assert_eq!(render(&mut renderer, &parser), [0, 1, 2, 3, 4]);
+
+        parser.set_size(5, 12);
+        assert_eq!(render(&mut renderer, &parser), [0, 1, 2, 3, 4]);
+
+        renderer.invalidate();
+        assert!(renderer.has_changes_since_last_render(parser.screen()));
+        assert_eq!(render(&mut renderer, &parser), [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_cursor_is_drawn_in_its_shape_and_blinks() {
+        let mut parser = Parser::new(3, 10, 100);
+        let mut cursor = CursorRenderer::new();
+        let area = Rect::new(0, 0, 10, 3);
+        let draw = |parser: &Parser, cursor: &CursorRenderer| {
+            let mut buf = Buffer::empty(area);
+            render_screen(parser.screen(), area, &mut buf);
+            cursor.render(parser.screen(), area, &mut buf);
+            buf
+        };
+
+        process(&mut parser, "ab");
+        let buf = draw(&parser, &cursor);
+        assert!(buf[(2, 0)].modifier.contains(Modifier::REVERSED));
+        assert!(!buf[(1, 0)].modifier.contains(Modifier::REVERSED));
+
+        // The default cursor blinks; a steady one ignores ticks.
+        cursor.tick();
+        assert!(!cursor.is_visible(parser.screen()));
+        assert_eq!(draw(&parser, &cursor)[(2, 0)].modifier, Modifier::empty());
+        process(&mut parser, "\x1b[2 q");
+        assert!(draw(&parser, &cursor)[(2, 0)]
+            .modifier
+            .contains(Modifier::REVERSED));
+        cursor.reset_blink();
+
+        process(&mut parser, "\x1b[4 q");
+        let buf = draw(&parser, &cursor);
+        assert_eq!(buf[(2, 0)].modifier, Modifier::UNDERLINED);
+
+        process(&mut parser, "\x1b[6 q");
+        assert_eq!(draw(&parser, &cursor)[(2, 0)].symbol(), "▏");
+        process(&mut parser, "\x1b[1;1H");
+        let buf = draw(&parser, &cursor);
+        assert_eq!(buf[(0, 0)].symbol(), "a");
+        assert_eq!(buf[(0, 0)].modifier, Modifier::UNDERLINED);
+
+        process(&mut parser, "\x1b[?25l");
+        assert!(!cursor.is_visible(parser.screen()));
+        assert_eq!(draw(&parser, &cursor), {
+            let mut buf = Buffer::empty(area);
+            render_screen(parser.screen(), area, &mut buf);
+            buf
+        });
+    }
+}
@@ -0,0 +1,447 @@
+//! Searching a [`Screen`]'s scrollback.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+
+use crate::primitives::termtui::vt100::Screen;
+
+/// Where a [`ScrollbackSearch`] match is, from its first cell to just past
+/// its last.
+///
+/// Rows count from the oldest row kept in the scrollback, so a match keeps
+/// its position while the view scrolls. A match in a line the terminal
+/// wrapped can end on a later row than it starts.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ScrollbackMatch {
+    /// Row and column of the first cell.
+    pub start: (usize, u16),
+    /// Row of the last cell, and the column just past it.
+    pub end: (usize, u16),
+}
+
+/// Finds text in a [`Screen`] and its scrollback, highlights the matches
+/// and moves the view between them.
+///
+/// Matching ignores case and follows lines across the rows the terminal
+/// wrapped them onto. Enter search with [`start`](Self::start) from
+/// whatever key the host binds, then feed keys to
+/// [`handle_key`](Self::handle_key): typing edits the query, Enter keeps
+/// it, `n` and `N` move to the next match up or down, `/` edits the query
+/// again and Esc leaves search.
+///
+/// Matches refer to the screen as it was searched. To search a frozen
+/// copy, such as a copy mode snapshot, pass the copy; to search a live
+/// screen, call [`refresh`](Self::refresh) when output arrives.
+#[derive(Clone, Debug)]
+pub struct ScrollbackSearch {
+    query: String,
+    active: bool,
+    editing: bool,
+    matches: Vec<ScrollbackMatch>,
+    current: Option<usize>,
+    match_style: Style,
+    current_style: Style,
+}
+
+impl Default for ScrollbackSearch {
+    fn default() -> Self {
+        Self {
+            query: String::new(),
+            active: false,
+            editing: false,
+            matches: Vec::new(),
+            current: None,
+            match_style: Style::default().fg(Color::Black).bg(Color::Yellow),
+            current_style: Style::default().fg(Color::Black).bg(Color::LightRed),
+        }
+    }
+}
+
+impl ScrollbackSearch {
+    /// Creates an inactive search.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the style of matches other than the current one.
+    #[must_use]
+    pub fn with_match_style(mut self, style: Style) -> Self {
+        self.match_style = style;
+        self
+    }
+
+    /// Sets the style of the current match.
+    #[must_use]
+    pub fn with_current_style(mut self, style: Style) -> Self {
+        self.current_style = style;
+        self
+    }
+
+    /// Enters search with an empty query, ready for typing.
+    pub fn start(&mut self) {
+        self.active = true;
+        self.editing = true;
+        self.query.clear();
+        self.matches.clear();
+        self.current = None;
+    }
+
+    /// Leaves search, dropping the query and its matches.
+    pub fn close(&mut self) {
+        self.active = false;
+        self.editing = false;
+        self.query.clear();
+        self.matches.clear();
+        self.current = None;
+    }
+
+    /// Whether search is on, so keys should go to
+    /// [`handle_key`](Self::handle_key).
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Whether typing edits the query, rather than `n` and `N` moving
+    /// between matches.
+    #[must_use]
+    pub fn is_editing(&self) -> bool {
+        self.editing
+    }
+
+    /// The text searched for.
+    #[must_use]
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Searches `screen` for `query` and shows the match nearest the bottom
+    /// of the view, scrolling to it if needed.
+    pub fn set_query(&mut self, screen: &mut Screen, query: impl Into<String>) {
+        self.active = true;
+        self.query = query.into();
+        self.matches = find_matches(screen, &self.query);
+        let bottom = view_top(screen) + usize::from(screen.size().height);
+        self.current = self
+            .matches
+            .iter()
+            .rposition(|found| found.start.0 < bottom)
+            .or_else(|| self.matches.len().checked_sub(1));
+        self.scroll_to_current(screen);
+    }
+
+    /// Searches `screen` again for the query, e.g. after more output.
+    ///
+    /// The current match stays the same distance from the newest match, so
+    /// it follows its text as new rows push old ones out of the
+    /// scrollback.
+    pub fn refresh(&mut self, screen: &Screen) {
+        let from_end = self.current.map(|current| self.matches.len() - current);
+        self.matches = find_matches(screen, &self.query);
+        self.current = from_end
+            .and_then(|from_end| self.matches.len().checked_sub(from_end))
+            .or_else(|| self.matches.len().checked_sub(1));
+    }
+
+    /// Every match, from the top of the scrollback down.
+    #[must_use]
+    pub fn matches(&self) -> &[ScrollbackMatch] {
+        &self.matches
+    }
+
+    /// Index into [`matches`](Self::matches) of the current match. For a
+    /// "3/7" status show it plus one and the number of matches.
+    #[must_use]
+    pub fn current_index(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// The current match.
+    #[must_use]
+    pub fn current(&self) -> Option<&ScrollbackMatch> {
+        self.current.and_then(|current| self.matches.get(current))
+    }
+
+    /// Moves to the match above the current one, wrapping to the bottom,
+    /// and scrolls it into view. Returns the new current match.
+    pub fn next_match(&mut self, screen: &mut Screen) -> Option<&ScrollbackMatch> {
+        let last = self.matches.len().checked_sub(1)?;
+        self.current = Some(match self.current {
+            Some(0) | None => last,
+            Some(current) => current - 1,
+        });
+        self.scroll_to_current(screen);
+        self.current()
+    }
+
+    /// Moves to the match below the current one, wrapping to the top, and
+    /// scrolls it into view. Returns the new current match.
+    pub fn prev_match(&mut self, screen: &mut Screen) -> Option<&ScrollbackMatch> {
+        let last = self.matches.len().checked_sub(1)?;
+        self.current = Some(match self.current {
+            Some(current) if current < last => current + 1,
+            _ => 0,
+        });
+        self.scroll_to_current(screen);
+        self.current()
+    }
+
+    /// Handles a key while search [is active](Self::is_active). Returns
+    /// whether the key was used.
+    pub fn handle_key(&mut self, screen: &mut Screen, key: KeyEvent) -> bool {
+        if !self.active || key.kind == KeyEventKind::Release {
+            return false;
+        }
+        if key.code == KeyCode::Esc {
+            self.close();
+            return true;
+        }
+        if self.editing {
+            let mut query = self.query.clone();
+            match key.code {
+                KeyCode::Enter => {
+                    self.editing = false;
+                    return true;
+                }
+                KeyCode::Backspace => {
+                    query.pop();
+                }
+                KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    query.push(c);
+                }
+                _ => return false,
+            }
+            self.set_query(screen, query);
+            return true;
+        }
+        match key.code {
+            KeyCode::Char('n') => {
+                self.next_match(screen);
+            }
+            KeyCode::Char('N') => {
+                self.prev_match(screen);
+            }
+            KeyCode::Char('/') => self.editing = true,
+            _ => return false,
+        }
+        true
+    }
+
+    /// Highlights the visible matches in a screen drawn in `area` of
+    /// `buf`. Call it after rendering the screen.
+    pub fn render(&self, screen: &Screen, area: Rect, buf: &mut Buffer) {
+        let top = view_top(screen);
+        let bottom = top + usize::from(area.height.min(screen.size().height));
+        for (index, found) in self.matches.iter().enumerate() {
+            if found.end.0 < top || found.start.0 >= bottom {
+                continue;
+            }
+            let style = if self.current == Some(index) {
+                self.current_style
+            } else {
+                self.match_style
+            };
+            for row in found.start.0.max(top)..=found.end.0.min(bottom - 1) {
+                let start = if row == found.start.0 {
+                    found.start.1
+                } else {
+                    0
+                };
+                let end = if row == found.end.0 {
+                    found.end.1
+                } else {
+                    screen.size().width
+                };
+                let width = area.width.min(end).saturating_sub(start);
+                // `row - top` is below the area's height
+                let y = area.y + (row - top) as u16;
+                let cells = Rect::new(area.x + start, y, width, 1);
+                buf.set_style(cells.intersection(area), style);
+            }
+        }
+    }
+
+    /// Scrolls `screen` the least needed to show all of the current match.
+    fn scroll_to_current(&self, screen: &mut Screen) {
+        let Some(found) = self.current().copied() else {
+            return;
+        };
+        let history = screen.history_len();
+        let height = usize::from(screen.size().height);
+        let top = view_top(screen);
+        if found.start.0 < top {
+            screen.set_scrollback(history - found.start.0);
+        } else if found.end.0 >= top + height {
+            let top = (found.end.0 + 1).saturating_sub(height);
+            screen.set_scrollback(history.saturating_sub(top));
+        }
+    }
+}
+
+/// Row of the scrollback at the top of the view.
+fn view_top(screen: &Screen) -> usize {
+    screen.history_len() - screen.scrollback()
+}
+
+/// Every case-insensitive match of `query` in `screen`'s rows, joining rows
+/// the terminal wrapped.
+fn find_matches(screen: &Screen, query: &str) -> Vec<ScrollbackMatch> {
+    let query = query.to_lowercase();
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let mut matches = Vec::new();
+    // Text of the current logical line, and the row, column and width of
+    // the cell each byte of it comes from
+    let mut text = String::new();
+    let mut cells: Vec<(usize, u16, u16)> = Vec::new();
+    for (index, row) in screen.all_rows().enumerate() {
+        let cols = if row.wrapped() {
+            row.cols()
+        } else {
+            (0..row.cols())
+                .rev()
+                .find(|&col| row.get(col).is_some_and(|cell| cell.has_contents()))
+                .map_or(0, |col| col + 1)
+        };
+        let mut wide = false;
+        for col in 0..cols {
+            let Some(cell) = row.get(col) else {
+                break;
+            };
+            let contents = if cell.has_contents() {
+                cell.contents().to_lowercase()
+            } else if wide {
+                // Second half of a wide character
+                wide = false;
+                continue;
+            } else {
+                " ".to_string()
+            };
+            wide = cell.is_wide();
+            text.push_str(&contents);
+            cells.extend(std::iter::repeat((index, col, cell.width().max(1))).take(contents.len()));
+        }
+        if row.wrapped() {
+            continue;
+        }
+        for (start, found) in text.match_indices(&query) {
+            let (row, col, _) = cells[start];
+            let (end_row, end_col, width) = cells[start + found.len() - 1];
+            matches.push(ScrollbackMatch {
+                start: (row, col),
+                end: (end_row, end_col + width),
+            });
+        }
+        text.clear();
+        cells.clear();
+    }
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::termtui::vt100::Parser;
+
+    fn key(c: char) -> KeyEvent {
+        KeyEvent::from(KeyCode::Char(c))
+    }
+
+    /// A 3x10 terminal that printed eight lines, one wrapped.
+    fn parser() -> Parser {
+        let mut parser = Parser::new(3, 10, 100);
+        parser.screen.process(
+            b"Error one\r\nok\r\nxx ERR\r\nfine\r\nlong line err\r\nor here\r\nerror\r\nend",
+            &mut Vec::new(),
+        );
+        parser
+    }
+
+    #[test]
+    fn test_matches_ignore_case_and_cross_wrapped_rows() {
+        let mut parser = parser();
+        // Rows: 0 "Error one", 1 "ok", 2 "xx ERR", 3 "fine",
+        // 4 "long line " wrapped onto 5 "err", 6 "or here", 7 "error",
+        // 8 "end"; rows 6 to 8 are on screen.
+        assert_eq!(parser.screen().history_len(), 6);
+        let mut search = ScrollbackSearch::new();
+        search.set_query(&mut parser.screen, "ERROR");
+        let starts: Vec<_> = search.matches().iter().map(|m| m.start).collect();
+        assert_eq!(starts, [(0, 0), (7, 0)]);
+
+        search.set_query(&mut parser.screen, "line er");
+        assert_eq!(
+            search.matches(),
+            [ScrollbackMatch {
+                start: (4, 5),
+                end: (5, 2),
+            }]
+        );
+        // Scrolled so the match starts at the top of the view
+        assert_eq!(parser.screen().scrollback(), 2);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+        search.render(parser.screen(), buf.area, &mut buf);
+        let highlighted: Vec<(u16, u16)> = (0..3)
+            .flat_map(|y| (0..10).map(move |x| (x, y)))
+            .filter(|&(x, y)| buf[(x, y)].bg == Color::LightRed)
+            .collect();
+        assert_eq!(
+            highlighted,
+            [(5, 0), (6, 0), (7, 0), (8, 0), (9, 0), (0, 1), (1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_keys_edit_the_query_and_step_through_matches() {
+        let mut parser = parser();
+        let screen = &mut parser.screen;
+        let mut search = ScrollbackSearch::new();
+        assert!(!search.handle_key(screen, key('e')));
+
+        search.start();
+        for c in "err".chars() {
+            assert!(search.handle_key(screen, key(c)));
+        }
+        // "Error", "ERR", "err" wrapped, "error"; the newest is current.
+        let rows: Vec<usize> = search.matches().iter().map(|m| m.start.0).collect();
+        assert_eq!(rows, [0, 2, 5, 7]);
+        assert_eq!(search.current_index(), Some(3));
+        assert!(search.handle_key(screen, KeyEvent::from(KeyCode::Enter)));
+        assert!(!search.is_editing());
+
+        // n goes up, scrolling the view, and wraps to the bottom.
+        search.handle_key(screen, key('n'));
+        assert_eq!(search.current().map(|m| m.start), Some((5, 0)));
+        assert_eq!(screen.scrollback(), 1);
+        search.handle_key(screen, key('n'));
+        search.handle_key(screen, key('n'));
+        assert_eq!(search.current().map(|m| m.start), Some((0, 0)));
+        assert_eq!(screen.scrollback(), 6);
+        search.handle_key(screen, key('n'));
+        assert_eq!(search.current_index(), Some(3));
+        assert_eq!(screen.scrollback(), 1);
+        search.handle_key(screen, key('N'));
+        assert_eq!(search.current_index(), Some(0));
+
+        // A frozen copy is searched as it was, and the current match
+        // follows its text as new output pushes rows out.
+        let frozen = screen.clone();
+        screen.process(b"\r\nmore\r\nlines", &mut Vec::new());
+        search.refresh(&frozen);
+        assert_eq!(search.matches().len(), 4);
+        search.refresh(screen);
+        assert_eq!(search.current().map(|m| m.start), Some((0, 0)));
+
+        search.handle_key(screen, key('/'));
+        search.handle_key(screen, KeyEvent::from(KeyCode::Backspace));
+        assert_eq!(search.query(), "er");
+        assert!(search.handle_key(screen, KeyEvent::from(KeyCode::Esc)));
+        assert!(!search.is_active());
+        assert!(search.matches().is_empty());
+    }
+}
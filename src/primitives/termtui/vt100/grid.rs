@@ -8,7 +8,11 @@ use unicode_width::UnicodeWidthStr;
 
 use crate::primitives::termtui::{protocol::CursorStyle, vt100::Size};
 
-use super::{attrs::Attrs, row::Row, Cell};
+use super::{
+    attrs::Attrs,
+    row::{next_stamp, Row},
+    Cell,
+};
 
 #[derive(Clone, Debug)]
 pub struct Grid {
@@ -25,6 +29,8 @@ pub struct Grid {
     saved_origin_mode: bool,
     scrollback_len: usize,
     scrollback_offset: usize,
+    /// Changes whenever anything visible changes; see [`Grid::generation`].
+    generation: u64,
 
     pub cursor_pos: Option<Pos>,
     pub cursor_style: CursorStyle,
@@ -49,6 +55,7 @@ impl Grid {
             saved_origin_mode: false,
             scrollback_len,
             scrollback_offset: 0,
+            generation: next_stamp(),
 
             cursor_pos: None,
             cursor_style: CursorStyle::Default,
@@ -198,6 +205,7 @@ impl Grid {
         while self.rows.len() < self.size.height.into() {
             self.rows.push_back(self.new_row());
         }
+        self.touch();
     }
 
     pub fn pos(&self) -> Pos {
@@ -232,12 +240,27 @@ impl Grid {
         self.rows.iter().skip(self.row0() - self.scrollback_offset)
     }
 
+    /// Every row kept, from the oldest scrollback row to the bottom of the
+    /// screen.
+    pub fn all_rows(&self) -> impl Iterator<Item = &Row> {
+        self.rows.iter()
+    }
+
+    /// The number of rows kept above the screen.
+    pub fn history_len(&self) -> usize {
+        self.row0()
+    }
+
     pub fn drawing_rows(&self) -> impl Iterator<Item = &Row> {
         self.rows.iter().skip(self.row0())
     }
 
     pub fn drawing_rows_mut(&mut self) -> impl Iterator<Item = &mut Row> {
+        self.touch();
         let row0 = self.row0();
+        for row in self.rows.iter_mut().skip(row0) {
+            row.restamp();
+        }
         self.rows.iter_mut().skip(row0)
     }
 
@@ -250,7 +273,11 @@ impl Grid {
     }
 
     pub fn drawing_row_mut(&mut self, row: u16) -> Option<&mut Row> {
-        self.drawing_rows_mut().nth(usize::from(row))
+        self.touch();
+        let row0 = self.row0();
+        let row = self.rows.get_mut(row0 + usize::from(row))?;
+        row.restamp();
+        Some(row)
     }
 
     pub fn current_row_mut(&mut self) -> &mut Row {
@@ -281,8 +308,54 @@ impl Grid {
         self.scrollback_offset
     }
 
+    /// Keeps at most `rows` rows of history, dropping the oldest ones
+    /// beyond it.
+    pub fn set_scrollback_len(&mut self, rows: usize) {
+        self.scrollback_len = rows;
+        let excess = self.row0().saturating_sub(rows);
+        if excess > 0 {
+            self.rows.drain(..excess);
+            self.scrollback_offset = self.scrollback_offset.min(self.row0());
+            self.touch();
+        }
+    }
+
+    /// Drops every row of history, keeping the screen.
+    pub fn clear_scrollback(&mut self) {
+        let row0 = self.row0();
+        if row0 > 0 {
+            self.rows.drain(..row0);
+            self.scrollback_offset = 0;
+            self.touch();
+        }
+    }
+
     pub fn set_scrollback(&mut self, rows: usize) {
-        self.scrollback_offset = rows.min(self.row0());
+        let offset = rows.min(self.row0());
+        if offset != self.scrollback_offset {
+            self.scrollback_offset = offset;
+            self.touch();
+        }
+    }
+
+    /// A value that changes whenever the visible contents change.
+    ///
+    /// Rows handed out for drawing, scrolling, resizing and moving through
+    /// the scrollback all change it; cursor movement does not.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Marks the grid as changed.
+    pub(crate) fn touch(&mut self) {
+        self.generation = next_stamp();
+    }
+
+    /// The [stamp](Row::stamp) of visible row `row`, taking the scrollback
+    /// offset into account.
+    pub fn visible_row_stamp(&self, row: u16) -> Option<u64> {
+        let index = (self.row0() - self.scrollback_offset).checked_add(usize::from(row))?;
+        self.rows.get(index).map(Row::stamp)
     }
 
     pub fn erase_all(&mut self, attrs: Attrs) {
@@ -363,6 +436,9 @@ impl Grid {
     }
 
     pub fn insert_lines(&mut self, count: u16) {
+        if count > 0 {
+            self.touch();
+        }
         let row0 = self.row0();
         for _ in 0..count {
             self.rows.remove(row0 + usize::from(self.scroll_bottom));
@@ -374,6 +450,9 @@ impl Grid {
     }
 
     pub fn delete_lines(&mut self, count: u16, blank_attrs: Attrs) {
+        if count > 0 {
+            self.touch();
+        }
         let row0 = self.row0();
         for _ in 0..(count.min(self.size.height - self.pos.row)) {
             let row = Row::new_with_attrs(self.size.width, blank_attrs);
@@ -384,6 +463,9 @@ impl Grid {
     }
 
     pub fn scroll_up(&mut self, count: u16) {
+        if count > 0 {
+            self.touch();
+        }
         for _ in 0..(count.min(self.size.height - self.scroll_top)) {
             let row0 = self.row0();
             self.rows
@@ -405,6 +487,9 @@ impl Grid {
     }
 
     pub fn scroll_down(&mut self, count: u16) {
+        if count > 0 {
+            self.touch();
+        }
         for _ in 0..count {
             let row0 = self.row0();
             self.rows.remove(row0 + usize::from(self.scroll_bottom));
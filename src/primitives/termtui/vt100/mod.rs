@@ -14,6 +14,7 @@
 pub mod attrs;
 pub mod cell;
 pub mod grid;
+mod osc;
 pub mod parser;
 pub mod row;
 pub mod screen;
@@ -0,0 +1,84 @@
+//! Operating System Commands the screen acts on.
+
+/// An OSC sequence understood by [`Screen`](super::Screen).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Osc {
+    /// OSC 0 or 2: set the window title.
+    Title(String),
+    /// OSC 52: set the clipboard to the decoded bytes.
+    Clipboard(Vec<u8>),
+}
+
+/// Parses the body of an OSC sequence, between `ESC ]` and its
+/// terminator. Returns `None` for commands that are not handled, clipboard
+/// queries, and clipboard payloads that are empty or not valid base64.
+pub fn parse(body: &[u8]) -> Option<Osc> {
+    let (command, rest) = split_param(body);
+    match command {
+        b"0" | b"2" => Some(Osc::Title(String::from_utf8_lossy(rest).into_owned())),
+        b"52" => {
+            // 52 ; selections ; base64 data, or `?` to query
+            let (_selections, data) = split_param(rest);
+            if data.is_empty() || data == b"?" {
+                return None;
+            }
+            decode_base64(data).map(Osc::Clipboard)
+        }
+        _ => None,
+    }
+}
+
+/// Splits off the text before the first `;`.
+fn split_param(text: &[u8]) -> (&[u8], &[u8]) {
+    text.iter()
+        .position(|&byte| byte == b';')
+        .map_or((text, &[][..]), |at| (&text[..at], &text[at + 1..]))
+}
+
+/// Decodes standard base64, with or without padding.
+fn decode_base64(text: &[u8]) -> Option<Vec<u8>> {
+    let text = text
+        .strip_suffix(b"==")
+        .or_else(|| text.strip_suffix(b"="))
+        .unwrap_or(text);
+    let mut decoded = Vec::with_capacity(text.len() / 4 * 3 + 2);
+    let mut bits: u32 = 0;
+    let mut count = 0;
+    for &byte in text {
+        let value = match byte {
+            b'A'..=b'Z' => byte - b'A',
+            b'a'..=b'z' => byte - b'a' + 26,
+            b'0'..=b'9' => byte - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        };
+        bits = bits << 6 | u32::from(value);
+        count += 6;
+        if count >= 8 {
+            count -= 8;
+            decoded.push(u8::try_from(bits >> count).ok()?);
+            bits &= (1 << count) - 1;
+        }
+    }
+    // A single character left over cannot encode a whole byte
+    (count < 6).then_some(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_decodes_with_and_without_padding() {
+        assert_eq!(decode_base64(b"aGk=").as_deref(), Some(&b"hi"[..]));
+        assert_eq!(decode_base64(b"aGk").as_deref(), Some(&b"hi"[..]));
+        assert_eq!(
+            decode_base64(b"aGVsbG8gd29ybGQ=").as_deref(),
+            Some(&b"hello world"[..])
+        );
+        assert_eq!(decode_base64(b"4pyT").as_deref(), Some("✓".as_bytes()));
+        assert_eq!(decode_base64(b"aGVsb"), None);
+        assert_eq!(decode_base64(b"not base64!"), None);
+    }
+}
@@ -1,9 +1,14 @@
-use crate::primitives::termtui::vt100::Size;
+use crate::primitives::termtui::vt100::{Size, VtEvent};
 
 /// A parser for terminal output which produces an in-memory representation of
 /// the terminal contents.
 pub struct Parser {
     pub screen: crate::primitives::termtui::vt100::screen::Screen,
+    events: Vec<VtEvent>,
+    allow_osc52: bool,
+    osc52_clipboard: Option<String>,
+    #[cfg(feature = "clipboard")]
+    apply_clipboard: bool,
 }
 
 impl Parser {
@@ -19,9 +24,71 @@ impl Parser {
                 },
                 scrollback_len,
             ),
+            events: Vec::new(),
+            allow_osc52: false,
+            osc52_clipboard: None,
+            #[cfg(feature = "clipboard")]
+            apply_clipboard: false,
         }
     }
 
+    /// Processes output from the program, keeping the events it raises
+    /// for [`take_events`](Self::take_events).
+    pub fn process(&mut self, data: &[u8]) {
+        let start = self.events.len();
+        self.screen.process(data, &mut self.events);
+        for event in self.events.split_off(start) {
+            if let VtEvent::ClipboardSet(data) = &event {
+                if !self.allow_osc52 {
+                    continue;
+                }
+                let text = String::from_utf8_lossy(data).into_owned();
+                #[cfg(feature = "clipboard")]
+                if self.apply_clipboard {
+                    let source = crate::services::clipboard::ClipboardSource::Terminal;
+                    if let Err(err) = crate::services::clipboard::copy(&text, source) {
+                        log::warn!("Could not set the clipboard: {err}");
+                    }
+                }
+                self.osc52_clipboard = Some(text);
+            }
+            self.events.push(event);
+        }
+    }
+
+    /// Returns the events raised since the last call, oldest first: bells,
+    /// replies to write back to the program, title changes and, when
+    /// [allowed](Self::allow_osc52_clipboard), clipboard sets.
+    pub fn take_events(&mut self) -> Vec<VtEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Whether programs may set the clipboard with OSC 52. Off by default,
+    /// since any program in the terminal could otherwise replace what the
+    /// user copied; while off, OSC 52 sequences are dropped.
+    pub fn allow_osc52_clipboard(&mut self, allow: bool) {
+        self.allow_osc52 = allow;
+        if !allow {
+            self.osc52_clipboard = None;
+        }
+    }
+
+    /// Returns the text the program last put on the clipboard with OSC 52,
+    /// if it did so since the last call. Write it to the system clipboard
+    /// to pass the copy through to the host.
+    pub fn take_osc52_clipboard(&mut self) -> Option<String> {
+        self.osc52_clipboard.take()
+    }
+
+    /// Whether [`process`](Self::process) also copies what the program
+    /// puts on the clipboard with OSC 52 to the system clipboard itself.
+    /// Off by default, and only applies while OSC 52 is
+    /// [allowed](Self::allow_osc52_clipboard).
+    #[cfg(feature = "clipboard")]
+    pub fn set_apply_clipboard(&mut self, apply: bool) {
+        self.apply_clipboard = apply;
+    }
+
     /// Resizes the terminal.
     pub fn set_size(&mut self, rows: u16, cols: u16) {
         self.screen.set_size(rows, cols);
@@ -49,3 +116,99 @@ impl Parser {
         &self.screen
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn osc_titles_and_clipboard_sets_are_raised_as_events() {
+        let mut parser = Parser::new(2, 20, 0);
+        parser.allow_osc52_clipboard(true);
+        parser.process(b"\x1b]2;build: ok\x07$ \x1b]52;c;aGVsbG8=\x1b\\");
+        // A malformed payload and a query are ignored; the title may also
+        // arrive in pieces.
+        parser.process(b"\x1b]52;c;%%%\x07\x1b]52;c;?\x07\x1b]0;vim");
+        parser.process(b"\x1b\\done");
+
+        let events = parser.take_events();
+        let described: Vec<String> = events
+            .iter()
+            .map(|event| match event {
+                VtEvent::TitleChanged(title) => format!("title {title}"),
+                VtEvent::ClipboardSet(data) => {
+                    format!("clipboard {}", String::from_utf8_lossy(data))
+                }
+                other => format!("{other:?}"),
+            })
+            .collect();
+        assert_eq!(
+            described,
+            ["title build: ok", "clipboard hello", "title vim"]
+        );
+        assert_eq!(parser.screen().title(), "vim");
+        assert!(parser.take_events().is_empty());
+
+        let row = parser.screen().get_selected_text(0, 0, 19, 0);
+        assert_eq!(row, "$ done");
+    }
+
+    #[test]
+    fn osc52_clipboard_is_passed_through_only_when_allowed() {
+        let mut parser = Parser::new(2, 20, 0);
+        // "copied" in base64, terminated by BEL
+        let copy = b"\x1b]52;c;Y29waWVk\x07";
+        parser.process(copy);
+        assert_eq!(parser.take_osc52_clipboard(), None);
+        assert!(parser.take_events().is_empty());
+
+        parser.allow_osc52_clipboard(true);
+        parser.process(copy);
+        parser.process(b"\x1b]52;c;bGFzdA==\x1b\\");
+        assert_eq!(parser.take_osc52_clipboard().as_deref(), Some("last"));
+        assert_eq!(parser.take_osc52_clipboard(), None);
+        assert_eq!(parser.take_events().len(), 2);
+
+        parser.process(copy);
+        parser.allow_osc52_clipboard(false);
+        assert_eq!(parser.take_osc52_clipboard(), None);
+    }
+
+    /// The text of the top visible row.
+    fn top_row(parser: &Parser) -> String {
+        (0..parser.screen().size().width)
+            .filter_map(|col| parser.screen().cell(0, col))
+            .map(crate::primitives::termtui::vt100::Cell::contents)
+            .collect()
+    }
+
+    #[test]
+    fn scrollback_is_bounded_and_can_be_cleared() {
+        let mut parser = Parser::new(2, 10, 3);
+        for n in 1..=8 {
+            parser.process(format!("line {n}\r\n").as_bytes());
+        }
+        // Lines 1 to 4 went past the limit of three.
+        let screen = parser.screen();
+        assert_eq!((screen.scrollback_len(), screen.history_len()), (3, 3));
+        parser.set_scrollback(3);
+        assert_eq!(top_row(&parser), "line 5");
+
+        parser.screen.set_scrollback_len(1);
+        assert_eq!(parser.screen().history_len(), 1);
+        assert_eq!(parser.screen().scrollback(), 1);
+        assert_eq!(top_row(&parser), "line 7");
+
+        parser.screen.clear_scrollback();
+        assert_eq!(parser.screen().history_len(), 0);
+        assert_eq!(parser.screen().scrollback(), 0);
+        assert_eq!(top_row(&parser), "line 8");
+
+        // Programs clear it with CSI 3 J, leaving the screen alone.
+        parser.process(b"more\r\nagain\r\n");
+        assert_eq!(parser.screen().history_len(), 1);
+        parser.process(b"\x1b[3J");
+        assert_eq!(parser.screen().history_len(), 0);
+        assert_eq!(top_row(&parser), "again");
+    }
+}
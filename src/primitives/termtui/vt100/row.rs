@@ -1,10 +1,21 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
 use super::Cell;
 
+static NEXT_STAMP: AtomicU64 = AtomicU64::new(1);
+
+/// Returns a value never returned before, for telling apart rows, and
+/// versions of a row, across every screen.
+pub(crate) fn next_stamp() -> u64 {
+    NEXT_STAMP.fetch_add(1, Ordering::Relaxed)
+}
+
 #[derive(Clone, Debug)]
 pub struct Row {
     pub cells: Vec<crate::primitives::termtui::vt100::cell::Cell>,
     size: u16,
     wrapped: bool,
+    stamp: u64,
 }
 
 impl Row {
@@ -16,6 +27,7 @@ impl Row {
             ],
             size: 0,
             wrapped: false,
+            stamp: next_stamp(),
         }
     }
 
@@ -29,9 +41,24 @@ impl Row {
             cells: vec![cell; usize::from(cols)],
             size: 0,
             wrapped: false,
+            stamp: next_stamp(),
         }
     }
 
+    /// Identifies the row's current contents.
+    ///
+    /// The stamp is unique to this row and changes whenever the grid hands
+    /// the row out for drawing, but stays the same when the row only moves,
+    /// such as when the screen scrolls.
+    #[must_use]
+    pub fn stamp(&self) -> u64 {
+        self.stamp
+    }
+
+    pub(crate) fn restamp(&mut self) {
+        self.stamp = next_stamp();
+    }
+
     pub fn cols(&self) -> u16 {
         self.cells
             .len()
@@ -2,7 +2,11 @@ use std::fmt::Debug;
 
 use crate::primitives::termtui::{
     protocol::CursorStyle,
-    vt100::{attrs::Attrs, Color, Size},
+    vt100::{
+        attrs::Attrs,
+        osc::{self, Osc},
+        Color, Size,
+    },
 };
 use compact_str::CompactString;
 use unicode_width::UnicodeWidthChar as _;
@@ -99,6 +103,8 @@ pub struct Screen {
     audible_bell_count: usize,
     visual_bell_count: usize,
 
+    title: String,
+
     errors: usize,
 }
 
@@ -132,6 +138,8 @@ impl Screen {
             audible_bell_count: 0,
             visual_bell_count: 0,
 
+            title: String::new(),
+
             errors: 0,
         }
     }
@@ -164,11 +172,25 @@ impl Screen {
         self.grid().scrollback()
     }
 
+    /// Returns the most rows of history kept above the screen. See
+    /// [`history_len`](Self::history_len) for how many there are.
     #[must_use]
     pub fn scrollback_len(&self) -> usize {
         self.grid().scrollback_len()
     }
 
+    /// Keeps at most `rows` rows of history above the normal screen,
+    /// dropping the oldest ones as more output arrives.
+    pub fn set_scrollback_len(&mut self, rows: usize) {
+        self.grid.set_scrollback_len(rows);
+    }
+
+    /// Discards the history above the normal screen, keeping what is on
+    /// it. Programs do the same with `CSI 3 J`.
+    pub fn clear_scrollback(&mut self) {
+        self.grid.clear_scrollback();
+    }
+
     pub fn set_scrollback(&mut self, rows: usize) {
         self.grid_mut().set_scrollback(rows);
     }
@@ -204,6 +226,12 @@ impl Screen {
             .visible_cell(crate::primitives::termtui::vt100::grid::Pos { row, col })
     }
 
+    /// Returns the window title last set by the program, with OSC 0 or 2.
+    #[must_use]
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
     #[must_use]
     pub fn cursor_style(&self) -> CursorStyle {
         self.grid.cursor_style
@@ -227,6 +255,42 @@ impl Screen {
         self.mouse_protocol_mode
     }
 
+    /// Returns a value that changes whenever the visible contents change:
+    /// output, scrolling, resizing, moving through the scrollback, or
+    /// switching to or from the alternate screen.
+    ///
+    /// Cursor movement does not change it; compare
+    /// [`cursor_position`](Self::cursor_position) for that.
+    #[must_use]
+    pub fn generation(&self) -> u64 {
+        self.grid().generation()
+    }
+
+    /// Returns a value identifying the contents of visible row `row`, or
+    /// `None` past the bottom of the screen.
+    ///
+    /// The value changes when the row is written to and moves with the row
+    /// when the screen scrolls, so a renderer can reuse what it drew for a
+    /// row wherever it ends up.
+    #[must_use]
+    pub fn row_stamp(&self, row: u16) -> Option<u64> {
+        self.grid().visible_row_stamp(row)
+    }
+
+    /// Every row kept, from the oldest scrollback row to the bottom of the
+    /// screen.
+    pub(crate) fn all_rows(
+        &self,
+    ) -> impl Iterator<Item = &crate::primitives::termtui::vt100::row::Row> {
+        self.grid().all_rows()
+    }
+
+    /// Returns the number of rows of history kept above the screen.
+    #[must_use]
+    pub fn history_len(&self) -> usize {
+        self.grid().history_len()
+    }
+
     fn grid(&self) -> &crate::primitives::termtui::vt100::grid::Grid {
         if self.mode(MODE_ALTERNATE_SCREEN) {
             &self.alternate_grid
@@ -246,10 +310,12 @@ impl Screen {
     fn enter_alternate_grid(&mut self) {
         self.grid_mut().set_scrollback(0);
         self.set_mode(MODE_ALTERNATE_SCREEN);
+        self.grid_mut().touch();
     }
 
     fn exit_alternate_grid(&mut self) {
         self.clear_mode(MODE_ALTERNATE_SCREEN);
+        self.grid_mut().touch();
     }
 
     fn save_cursor(&mut self) {
@@ -574,6 +640,7 @@ impl Screen {
             0 => self.grid_mut().erase_all_forward(attrs),
             1 => self.grid_mut().erase_all_backward(attrs),
             2 => self.grid_mut().erase_all(attrs),
+            3 => self.clear_scrollback(),
             n => {
                 log::warn!("Unhandled ED mode: {n}");
             }
@@ -608,6 +675,11 @@ impl Screen {
 pub enum VtEvent {
     Bell,
     Reply(CompactString),
+    /// The program set the window title with OSC 0 or 2.
+    TitleChanged(String),
+    /// The program set the clipboard with OSC 52; holds the decoded
+    /// bytes.
+    ClipboardSet(Vec<u8>),
 }
 
 impl Screen {
@@ -812,8 +884,8 @@ impl Screen {
                                     s = Some(&buf[start..pos]);
                                     pos += 2;
                                 }
-                                if let Some(_s) = s {
-                                    // TODO: Handle OSC
+                                if let Some(s) = s {
+                                    self.process_osc(events, s);
                                     break 'osc;
                                 }
 
@@ -876,6 +948,17 @@ impl Screen {
         self.feed_buf.drain(0..consumed);
     }
 
+    fn process_osc(&mut self, events: &mut Vec<VtEvent>, body: &[u8]) {
+        match osc::parse(body) {
+            Some(Osc::Title(title)) => {
+                self.title.clone_from(&title);
+                events.push(VtEvent::TitleChanged(title));
+            }
+            Some(Osc::Clipboard(data)) => events.push(VtEvent::ClipboardSet(data)),
+            None => {}
+        }
+    }
+
     fn process_csi(
         &mut self,
         events: &mut Vec<VtEvent>,
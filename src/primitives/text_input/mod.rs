@@ -0,0 +1,415 @@
+//! Text editing shared by the picker, filter and chat inputs.
+//!
+//! [`TextInputState`] keeps its value in Unicode normalization form C and
+//! its cursor on a grapheme boundary, so emoji with ZWJ sequences, flags and
+//! letters with combining marks move and delete as one character. Dead-key
+//! accents typed on their own are held back until the next key, which
+//! composes with them (`´` then `e` gives `é`).
+//!
+//! # Example
+//!
+//! ```rust
+//! use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+//! use ratkit::primitives::text_input::TextInputState;
+//!
+//! let mut input = TextInputState::new();
+//! for c in ['c', 'a', 'f', '\u{b4}'] {
+//!     input.handle_key(&KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+//! }
+//! assert_eq!(input.pending(), Some('\u{b4}'));
+//! assert_eq!(input.display_value(), "caf\u{b4}");
+//!
+//! input.handle_key(&KeyEvent::new(KeyCode::Char('e'), KeyModifiers::NONE));
+//! assert_eq!(input.value(), "caf\u{e9}");
+//! ```
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Spacing accents some keyboard layouts send for a dead key, and the
+/// combining mark each one adds to the next letter.
+const DEAD_KEYS: &[(char, char)] = &[
+    ('\u{b4}', '\u{301}'),  // acute
+    ('\u{a8}', '\u{308}'),  // diaeresis
+    ('\u{b8}', '\u{327}'),  // cedilla
+    ('\u{2c6}', '\u{302}'), // circumflex
+    ('\u{2dc}', '\u{303}'), // tilde
+    ('\u{2c7}', '\u{30c}'), // caron
+    ('\u{2d8}', '\u{306}'), // breve
+    ('\u{2da}', '\u{30a}'), // ring above
+    ('\u{2dd}', '\u{30b}'), // double acute
+    ('\u{2db}', '\u{328}'), // ogonek
+];
+
+/// Whether `c` is a spacing accent that [`TextInputState`] holds back to
+/// compose with the next character.
+pub fn is_dead_key(c: char) -> bool {
+    combining_mark(c).is_some()
+}
+
+fn combining_mark(dead: char) -> Option<char> {
+    DEAD_KEYS
+        .iter()
+        .find(|(key, _)| *key == dead)
+        .map(|(_, mark)| *mark)
+}
+
+/// Text being typed into an input, with a cursor.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextInputState {
+    /// The value, always in NFC.
+    value: String,
+    /// Byte offset of the cursor, always on a grapheme boundary.
+    cursor: usize,
+    /// Dead key waiting for the character it composes with.
+    pending: Option<char>,
+}
+
+impl TextInputState {
+    /// Create an empty input.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an input holding `value`, with the cursor at the end.
+    pub fn with_value(value: &str) -> Self {
+        let mut input = Self::new();
+        input.set_value(value);
+        input
+    }
+
+    /// The value, in NFC.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Replace the value, moving the cursor to the end and dropping a
+    /// pending dead key.
+    pub fn set_value(&mut self, value: &str) {
+        self.value = value.nfc().collect();
+        self.cursor = self.value.len();
+        self.pending = None;
+    }
+
+    /// Whether the value is empty and no dead key is pending.
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty() && self.pending.is_none()
+    }
+
+    /// Clear the value and any pending dead key.
+    pub fn clear(&mut self) {
+        self.value.clear();
+        self.cursor = 0;
+        self.pending = None;
+    }
+
+    /// Byte offset of the cursor in [`value`](Self::value).
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Display width of the text before the cursor, i.e. the terminal
+    /// column the cursor goes in.
+    pub fn cursor_column(&self) -> usize {
+        self.value[..self.cursor].width()
+    }
+
+    /// The dead key waiting for the next character, if any.
+    pub fn pending(&self) -> Option<char> {
+        self.pending
+    }
+
+    /// The value with a pending dead key shown at the cursor, for drawing.
+    pub fn display_value(&self) -> String {
+        let mut display = self.value.clone();
+        if let Some(dead) = self.pending {
+            display.insert(self.cursor, dead);
+        }
+        display
+    }
+
+    /// Type `c` at the cursor.
+    ///
+    /// A dead key is held back; the next character composes with it if it
+    /// has an accented form, or follows the accent otherwise. A space or a
+    /// second press of the same dead key types the accent itself.
+    pub fn insert_char(&mut self, c: char) {
+        let Some(dead) = self.pending.take() else {
+            if is_dead_key(c) {
+                self.pending = Some(c);
+            } else {
+                self.insert(c.encode_utf8(&mut [0; 4]));
+            }
+            return;
+        };
+
+        if c == ' ' || c == dead {
+            self.insert(dead.encode_utf8(&mut [0; 4]));
+            return;
+        }
+        let mark = combining_mark(dead).unwrap_or(dead);
+        let composed: String = [c, mark].into_iter().nfc().collect();
+        if composed.chars().count() == 1 {
+            self.insert(&composed);
+        } else {
+            self.insert(dead.encode_utf8(&mut [0; 4]));
+            self.insert_char(c);
+        }
+    }
+
+    /// Insert `text` at the cursor as is, e.g. pasted text. A pending dead
+    /// key is typed first.
+    pub fn insert_str(&mut self, text: &str) {
+        if let Some(dead) = self.pending.take() {
+            self.insert(dead.encode_utf8(&mut [0; 4]));
+        }
+        self.insert(text);
+    }
+
+    /// Delete the grapheme before the cursor, or drop a pending dead key.
+    /// Returns whether anything was deleted.
+    pub fn backspace(&mut self) -> bool {
+        if self.pending.take().is_some() {
+            return true;
+        }
+        let start = self.previous_boundary();
+        if start == self.cursor {
+            return false;
+        }
+        self.value.replace_range(start..self.cursor, "");
+        self.cursor = start;
+        true
+    }
+
+    /// Delete the grapheme after the cursor. Returns whether anything was
+    /// deleted.
+    pub fn delete(&mut self) -> bool {
+        let end = self.next_boundary();
+        if end == self.cursor {
+            return false;
+        }
+        self.value.replace_range(self.cursor..end, "");
+        true
+    }
+
+    /// Move the cursor to byte offset `cursor`, or to the start of the
+    /// grapheme it falls in. Returns whether it moved.
+    pub fn set_cursor(&mut self, cursor: usize) -> bool {
+        let cursor = cursor.min(self.value.len());
+        let start = self
+            .value
+            .grapheme_indices(true)
+            .map(|(start, _)| start)
+            .take_while(|&start| start <= cursor)
+            .last()
+            .unwrap_or(0);
+        let start = if cursor == self.value.len() {
+            cursor
+        } else {
+            start
+        };
+        self.move_to(start)
+    }
+
+    /// Move the cursor one grapheme left. Returns whether it moved.
+    pub fn move_left(&mut self) -> bool {
+        self.move_to(self.previous_boundary())
+    }
+
+    /// Move the cursor one grapheme right. Returns whether it moved.
+    pub fn move_right(&mut self) -> bool {
+        self.move_to(self.next_boundary())
+    }
+
+    /// Move the cursor to the start. Returns whether it moved.
+    pub fn move_home(&mut self) -> bool {
+        self.move_to(0)
+    }
+
+    /// Move the cursor to the end. Returns whether it moved.
+    pub fn move_end(&mut self) -> bool {
+        self.move_to(self.value.len())
+    }
+
+    /// Edit the value for a key press: characters, Backspace, Delete,
+    /// Left, Right, Home and End.
+    ///
+    /// Characters typed with Ctrl or Alt and key releases are ignored.
+    /// Returns whether the key was handled.
+    pub fn handle_key(&mut self, key: &KeyEvent) -> bool {
+        if key.kind == KeyEventKind::Release {
+            return false;
+        }
+        match key.code {
+            KeyCode::Char(c)
+                if !key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                self.insert_char(c);
+                true
+            }
+            KeyCode::Backspace => {
+                self.backspace();
+                true
+            }
+            KeyCode::Delete => {
+                self.delete();
+                true
+            }
+            KeyCode::Left => {
+                self.move_left();
+                true
+            }
+            KeyCode::Right => {
+                self.move_right();
+                true
+            }
+            KeyCode::Home => {
+                self.move_home();
+                true
+            }
+            KeyCode::End => {
+                self.move_end();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Insert `text` at the cursor, normalizing it together with the text
+    /// before the cursor so a combining mark composes with its letter.
+    fn insert(&mut self, text: &str) {
+        let before: String = self.value[..self.cursor]
+            .chars()
+            .chain(text.chars())
+            .nfc()
+            .collect();
+        let after = self.value.split_off(self.cursor);
+        self.cursor = before.len();
+        self.value = before + &after;
+        // Inserted text can join the grapheme after it, e.g. a ZWJ
+        if let Some((start, grapheme)) = self
+            .value
+            .grapheme_indices(true)
+            .find(|(start, grapheme)| start + grapheme.len() > self.cursor)
+        {
+            if start < self.cursor {
+                self.cursor = start + grapheme.len();
+            }
+        }
+    }
+
+    fn move_to(&mut self, cursor: usize) -> bool {
+        self.pending = None;
+        let moved = cursor != self.cursor;
+        self.cursor = cursor;
+        moved
+    }
+
+    fn previous_boundary(&self) -> usize {
+        self.value[..self.cursor]
+            .grapheme_indices(true)
+            .next_back()
+            .map_or(0, |(index, _)| index)
+    }
+
+    fn next_boundary(&self) -> usize {
+        self.cursor
+            + self.value[self.cursor..]
+                .graphemes(true)
+                .next()
+                .map_or(0, str::len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn typed(keys: &str) -> TextInputState {
+        let mut input = TextInputState::new();
+        for c in keys.chars() {
+            input.insert_char(c);
+        }
+        input
+    }
+
+    #[test]
+    fn test_graphemes_move_and_delete_as_one() {
+        let family = "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}";
+        let mut input = TextInputState::with_value(&format!("a{family}b"));
+        assert!(input.move_left());
+        assert!(input.move_left());
+        assert_eq!(input.cursor(), 1);
+        assert_eq!(input.cursor_column(), 1);
+        assert!(input.delete());
+        assert_eq!(input.value(), "ab");
+
+        let mut input = TextInputState::with_value("\u{1f1eb}\u{1f1f7}x\u{1f44b}\u{1f3fd}");
+        assert!(input.backspace());
+        assert_eq!(input.value(), "\u{1f1eb}\u{1f1f7}x");
+        input.move_home();
+        input.move_right();
+        assert_eq!(input.cursor(), 8);
+        input.backspace();
+        assert_eq!(input.value(), "x");
+        assert!(!input.backspace());
+    }
+
+    #[test]
+    fn test_combining_marks_are_normalized() {
+        let input = TextInputState::with_value("Cafe\u{301}");
+        assert_eq!(input.value(), "Caf\u{e9}");
+
+        let mut input = typed("e\u{301}");
+        assert_eq!(input.value(), "\u{e9}");
+        input.move_home();
+        input.insert_str("a\u{308}");
+        assert_eq!(input.value(), "\u{e4}\u{e9}");
+        assert_eq!(input.cursor(), 2);
+
+        // A mark with no precomposed form stays with its letter
+        let mut input = typed("q\u{301}");
+        assert_eq!(input.value(), "q\u{301}");
+        assert!(input.backspace());
+        assert!(input.value().is_empty());
+    }
+
+    #[test]
+    fn test_dead_keys_compose_with_next_char() {
+        let mut input = typed("\u{b4}e\u{a8}u");
+        assert_eq!(input.value(), "\u{e9}\u{fc}");
+
+        input.insert_char('\u{2c6}');
+        assert_eq!(input.pending(), Some('\u{2c6}'));
+        assert_eq!(input.display_value(), "\u{e9}\u{fc}\u{2c6}");
+        assert!(input.backspace());
+        assert_eq!(input.pending(), None);
+        assert_eq!(input.value(), "\u{e9}\u{fc}");
+
+        assert_eq!(typed("\u{b4} ").value(), "\u{b4}");
+        assert_eq!(typed("\u{b4}\u{b4}").value(), "\u{b4}");
+        assert_eq!(typed("\u{b8}x").value(), "\u{b8}x");
+        assert_eq!(typed("\u{2dc}n").value(), "\u{f1}");
+
+        let mut input = typed("\u{b4}");
+        input.insert_str("pasted");
+        assert_eq!(input.value(), "\u{b4}pasted");
+    }
+
+    #[test]
+    fn test_handle_key_ignores_control_chords_and_releases() {
+        let mut input = TextInputState::new();
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+        assert!(input.handle_key(&key(KeyCode::Char('x'))));
+        assert!(!input.handle_key(&KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL)));
+        let mut release = key(KeyCode::Char('y'));
+        release.kind = KeyEventKind::Release;
+        assert!(!input.handle_key(&release));
+        assert!(!input.handle_key(&key(KeyCode::Enter)));
+        assert_eq!(input.value(), "x");
+    }
+}
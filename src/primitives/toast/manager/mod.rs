@@ -1,6 +1,11 @@
-use crate::primitives::toast::{Toast, ToastLevel, ToastManager};
-use ratatui::layout::Rect;
-use std::time::Duration;
+use crate::primitives::toast::render::{fanout_area, stack_areas};
+use crate::primitives::toast::{
+    Toast, ToastLevel, ToastManager, ToastStack, TOAST_GROUP_LINGER, TOAST_GROUP_MAX_ROWS,
+};
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::{Position, Rect};
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
 
 impl Toast {
     pub fn is_expired(&self) -> bool {
@@ -13,9 +18,29 @@ impl Toast {
             level,
             created_at: std::time::Instant::now(),
             duration: duration.unwrap_or(super::DEFAULT_TOAST_DURATION),
+            group: None,
         }
     }
 
+    /// Creates a toast stacked with every other active toast that has the
+    /// same `group` key, e.g. the warnings from one batch import.
+    pub fn grouped(
+        group: impl Into<String>,
+        message: impl Into<String>,
+        level: ToastLevel,
+        duration: Option<Duration>,
+    ) -> Self {
+        Self {
+            group: Some(group.into()),
+            ..Self::new(message, level, duration)
+        }
+    }
+
+    /// When the toast expires.
+    pub fn expires_at(&self) -> Instant {
+        self.created_at + self.duration
+    }
+
     pub fn with_duration(
         message: impl Into<String>,
         level: ToastLevel,
@@ -26,6 +51,7 @@ impl Toast {
             level,
             created_at: std::time::Instant::now(),
             duration,
+            group: None,
         }
     }
 
@@ -36,28 +62,90 @@ impl Toast {
     }
 }
 
+impl ToastStack<'_> {
+    /// The newest toast, whose message the stacked card shows.
+    pub fn latest(&self) -> &Toast {
+        self.toasts[self.toasts.len() - 1]
+    }
+
+    /// Number of toasts behind the latest one, for the "(+N more)" line.
+    pub fn hidden_count(&self) -> usize {
+        self.toasts.len() - 1
+    }
+
+    /// Whether every toast in the stack has expired, including the linger
+    /// after the last member joined.
+    pub fn is_expired(&self) -> bool {
+        Instant::now() >= self.expires_at
+    }
+}
+
 impl ToastManager {
     pub fn new() -> Self {
         Self {
             toasts: Vec::new(),
             max_toasts: 5,
+            history: Vec::new(),
+            max_history: super::DEFAULT_TOAST_HISTORY,
+            unread: 0,
+            generation: 0,
+            do_not_disturb: false,
+            group_lingers: HashMap::new(),
+            expanded: None,
+            expanded_scroll: 0,
         }
     }
 
     pub fn add(&mut self, toast: Toast) {
         self.remove_expired();
 
+        self.history.push(toast.clone());
+        if self.history.len() > self.max_history {
+            self.history.drain(0..self.history.len() - self.max_history);
+        }
+        self.unread = self.unread.saturating_add(1).min(self.history.len());
+        self.generation = self.generation.wrapping_add(1);
+
+        if self.do_not_disturb {
+            tracing::debug!("Toast recorded while do-not-disturb is enabled");
+            return;
+        }
+
+        if let Some(group) = &toast.group {
+            if self.toasts.iter().any(|t| t.group.as_ref() == Some(group)) {
+                self.group_lingers
+                    .insert(group.clone(), Instant::now() + TOAST_GROUP_LINGER);
+            }
+        }
         self.toasts.push(toast);
 
-        if self.toasts.len() > self.max_toasts {
-            self.toasts.drain(0..self.toasts.len() - self.max_toasts);
+        // A group counts as one toast towards the limit.
+        loop {
+            let stacks = self.stack_indices();
+            if stacks.len() <= self.max_toasts {
+                break;
+            }
+            let oldest: HashSet<usize> = stacks[0].iter().copied().collect();
+            let mut index = 0;
+            self.toasts.retain(|_| {
+                index += 1;
+                !oldest.contains(&(index - 1))
+            });
         }
+        self.forget_missing_groups();
 
         tracing::debug!("Toast added, total toasts: {}", self.toasts.len());
     }
 
     pub fn clear(&mut self) {
         self.toasts.clear();
+        self.forget_missing_groups();
+    }
+
+    /// Clears the notification history and the unread count.
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+        self.unread = 0;
     }
 
     pub fn error(&mut self, message: impl Into<String>) {
@@ -68,44 +156,241 @@ impl ToastManager {
         &self.toasts
     }
 
-    pub fn handle_click(&mut self, x: u16, y: u16, frame_area: Rect) -> bool {
-        const TOAST_WIDTH: u16 = 40;
-        const TOAST_HEIGHT: u16 = 3;
-        const TOAST_MARGIN: u16 = 2;
-        const TOAST_SPACING: u16 = 1;
+    /// The active toasts as they are shown, oldest stack first.
+    ///
+    /// Toasts sharing a group key form one stack, placed by its newest
+    /// member. A stack expires when its last member does, and not before
+    /// [`TOAST_GROUP_LINGER`] has passed since a member last joined it.
+    pub fn stacks(&self) -> Vec<ToastStack<'_>> {
+        self.stack_indices()
+            .into_iter()
+            .map(|indices| {
+                let toasts: Vec<&Toast> = indices.iter().map(|&i| &self.toasts[i]).collect();
+                let group = toasts[0].group.as_deref();
+                let linger = group.and_then(|group| self.group_lingers.get(group).copied());
+                let expires_at = toasts
+                    .iter()
+                    .map(|toast| toast.expires_at())
+                    .chain(linger)
+                    .max()
+                    .unwrap_or_else(Instant::now);
+                ToastStack {
+                    group,
+                    toasts,
+                    expires_at,
+                }
+            })
+            .collect()
+    }
+
+    /// Indices into `toasts` of each stack's members, ordered by each
+    /// stack's newest member.
+    fn stack_indices(&self) -> Vec<Vec<usize>> {
+        let mut stacks: Vec<Vec<usize>> = Vec::new();
+        let mut groups: HashMap<&str, usize> = HashMap::new();
+        for (index, toast) in self.toasts.iter().enumerate() {
+            match toast.group.as_deref() {
+                Some(group) => match groups.get(group) {
+                    Some(&stack) => stacks[stack].push(index),
+                    None => {
+                        groups.insert(group, stacks.len());
+                        stacks.push(vec![index]);
+                    }
+                },
+                None => stacks.push(vec![index]),
+            }
+        }
+        stacks.sort_by_key(|stack| stack[stack.len() - 1]);
+        stacks
+    }
+
+    /// Drops linger deadlines and the expanded group once their group has
+    /// no active members left.
+    fn forget_missing_groups(&mut self) {
+        let toasts = &self.toasts;
+        let active = |group: &str| toasts.iter().any(|t| t.group.as_deref() == Some(group));
+        self.group_lingers.retain(|group, _| active(group));
+        if self.expanded.as_deref().is_some_and(|group| !active(group)) {
+            self.expanded = None;
+            self.expanded_scroll = 0;
+        }
+    }
+
+    /// Dismisses every active member of `group`.
+    pub fn dismiss_group(&mut self, group: &str) {
+        self.toasts.retain(|t| t.group.as_deref() != Some(group));
+        self.forget_missing_groups();
+    }
 
-        let active_count = self.toasts.iter().filter(|t| !t.is_expired()).count();
-        if active_count == 0 {
+    /// Fans out the members of `group` in a scrollable list, newest first.
+    ///
+    /// Returns `false` if the group has no active members.
+    pub fn expand_group(&mut self, group: &str) -> bool {
+        if !self
+            .toasts
+            .iter()
+            .any(|t| t.group.as_deref() == Some(group))
+        {
             return false;
         }
+        self.expanded = Some(group.to_string());
+        self.expanded_scroll = 0;
+        true
+    }
 
-        let mut y_offset = frame_area.height.saturating_sub(TOAST_MARGIN);
+    /// Expands the newest group, or collapses the expanded one. Meant to
+    /// be bound to a key.
+    ///
+    /// Returns `false` if there was no group to expand.
+    pub fn toggle_latest_group(&mut self) -> bool {
+        if self.expanded.is_some() {
+            self.collapse_group();
+            return true;
+        }
+        let latest = self.toasts.iter().rev().find_map(|t| t.group.clone());
+        match latest {
+            Some(group) => self.expand_group(&group),
+            None => false,
+        }
+    }
 
-        for i in (0..self.toasts.len()).rev() {
-            if self.toasts[i].is_expired() {
-                continue;
-            }
+    /// Closes the expanded group's list.
+    pub fn collapse_group(&mut self) {
+        self.expanded = None;
+        self.expanded_scroll = 0;
+    }
 
-            let toast_y = y_offset.saturating_sub(TOAST_HEIGHT);
-            let toast_x = frame_area.width.saturating_sub(TOAST_WIDTH + TOAST_MARGIN);
+    /// The group whose members are fanned out, if any.
+    pub fn expanded_group(&self) -> Option<&str> {
+        self.expanded.as_deref()
+    }
 
-            if x >= toast_x
-                && x < toast_x + TOAST_WIDTH
-                && y >= toast_y
-                && y < toast_y + TOAST_HEIGHT
-            {
-                self.toasts.remove(i);
-                return true;
-            }
+    /// How many of the expanded group's newest members are scrolled past.
+    pub fn expanded_scroll(&self) -> usize {
+        self.expanded_scroll
+    }
 
-            y_offset = toast_y.saturating_sub(TOAST_SPACING);
+    /// Scrolls the expanded group's list by `delta` rows.
+    pub fn scroll_expanded(&mut self, delta: isize) {
+        let Some(group) = self.expanded.as_deref() else {
+            return;
+        };
+        let members = self
+            .toasts
+            .iter()
+            .filter(|t| t.group.as_deref() == Some(group))
+            .count();
+        let max = members.saturating_sub(TOAST_GROUP_MAX_ROWS);
+        self.expanded_scroll = self.expanded_scroll.saturating_add_signed(delta).min(max);
+    }
 
-            if toast_y == 0 || toast_x == 0 {
-                break;
+    /// Handles a key while a group is expanded: arrows or `j`/`k` scroll,
+    /// `d` or Delete dismisses the group, Esc or Enter collapses it.
+    ///
+    /// Returns `true` if the key was used.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let Some(group) = self.expanded.clone() else {
+            return false;
+        };
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => self.scroll_expanded(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.scroll_expanded(1),
+            KeyCode::PageUp => self.scroll_expanded(-(TOAST_GROUP_MAX_ROWS as isize)),
+            KeyCode::PageDown => self.scroll_expanded(TOAST_GROUP_MAX_ROWS as isize),
+            KeyCode::Char('d') | KeyCode::Delete => self.dismiss_group(&group),
+            KeyCode::Esc | KeyCode::Enter => self.collapse_group(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Returns every recorded toast, oldest first, including expired ones.
+    pub fn history(&self) -> &[Toast] {
+        &self.history
+    }
+
+    /// Number of history entries added since the last [`Self::mark_all_read`].
+    pub fn unread_count(&self) -> usize {
+        self.unread
+    }
+
+    /// Marks every history entry as read.
+    pub fn mark_all_read(&mut self) {
+        self.unread = 0;
+    }
+
+    /// Counter incremented on every added toast.
+    ///
+    /// Status UI can compare this against a stored value each frame to
+    /// detect new notifications without polling the history.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Sets the maximum number of toasts kept in the history.
+    pub fn set_max_history(&mut self, max_history: usize) {
+        self.max_history = max_history;
+        if self.history.len() > max_history {
+            self.history.drain(0..self.history.len() - max_history);
+        }
+        self.unread = self.unread.min(self.history.len());
+    }
+
+    /// Enables or disables do-not-disturb.
+    ///
+    /// While enabled, new toasts are only recorded in the history and are
+    /// not shown as popups.
+    pub fn set_do_not_disturb(&mut self, enabled: bool) {
+        self.do_not_disturb = enabled;
+    }
+
+    /// Whether do-not-disturb is enabled.
+    pub fn is_do_not_disturb(&self) -> bool {
+        self.do_not_disturb
+    }
+
+    /// Handles a click on the toasts.
+    ///
+    /// Clicking a toast dismisses it; clicking a group's card dismisses the
+    /// whole group, except on its "(+N more)" line, which expands it. While
+    /// a group is expanded, clicks inside its list are swallowed and clicks
+    /// outside collapse it.
+    ///
+    /// Returns `true` if the click was used.
+    pub fn handle_click(&mut self, x: u16, y: u16, frame_area: Rect) -> bool {
+        let position = Position::new(x, y);
+
+        if let Some(group) = self.expanded.clone() {
+            let members = self
+                .toasts
+                .iter()
+                .filter(|t| t.group.as_deref() == Some(group.as_str()))
+                .count();
+            if !fanout_area(members, frame_area).contains(position) {
+                self.collapse_group();
             }
+            return true;
         }
 
-        false
+        let stacks = self.stacks();
+        let hit = stack_areas(&stacks, frame_area)
+            .into_iter()
+            .find(|(_, area)| area.contains(position));
+        let Some((stack, area)) = hit else {
+            return false;
+        };
+
+        match stacks[stack].group.map(str::to_string) {
+            Some(group) if y == area.y + 2 => {
+                self.expand_group(&group);
+            }
+            Some(group) => self.dismiss_group(&group),
+            None => {
+                let index = self.stack_indices()[stack][0];
+                self.toasts.remove(index);
+            }
+        }
+        true
     }
 
     pub fn has_toasts(&self) -> bool {
@@ -116,9 +401,21 @@ impl ToastManager {
         self.add(Toast::new(message, ToastLevel::Info, None));
     }
 
+    /// Removes expired toasts. Members of a group stay until the whole
+    /// group expires.
     pub fn remove_expired(&mut self) {
+        let live_groups: HashSet<String> = self
+            .stacks()
+            .iter()
+            .filter(|stack| !stack.is_expired())
+            .filter_map(|stack| stack.group.map(str::to_string))
+            .collect();
         let before = self.toasts.len();
-        self.toasts.retain(|toast| !toast.is_expired());
+        self.toasts.retain(|toast| match &toast.group {
+            Some(group) => live_groups.contains(group),
+            None => !toast.is_expired(),
+        });
+        self.forget_missing_groups();
         let removed = before - self.toasts.len();
         if removed > 0 {
             tracing::debug!("Removed {} expired toasts", removed);
@@ -133,3 +430,87 @@ impl ToastManager {
         self.add(Toast::new(message, ToastLevel::Warning, None));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn warn(group: &str, message: &str, duration: Duration) -> Toast {
+        Toast::grouped(group, message, ToastLevel::Warning, Some(duration))
+    }
+
+    #[test]
+    fn test_grouped_toasts_stack_into_one_card() {
+        let mut toasts = ToastManager::new();
+        for i in 0..6 {
+            toasts.info(format!("info {i}"));
+        }
+        for i in 0..10 {
+            toasts.add(warn("import", &format!("row {i}"), Duration::from_secs(10)));
+        }
+
+        let stacks = toasts.stacks();
+        assert_eq!(stacks.len(), 5);
+        let group = &stacks[4];
+        assert_eq!(group.group, Some("import"));
+        assert_eq!(group.latest().message, "row 9");
+        assert_eq!(group.hidden_count(), 9);
+        assert_eq!(stacks[0].latest().message, "info 2");
+
+        let history = toasts.history();
+        assert_eq!(history.len(), 16);
+        assert_eq!(history[15].group.as_deref(), Some("import"));
+        assert_eq!(history[0].group, None);
+
+        toasts.dismiss_group("import");
+        assert_eq!(toasts.stacks().len(), 4);
+        assert_eq!(toasts.history().len(), 16);
+    }
+
+    #[test]
+    fn test_group_expires_with_its_last_member_and_lingers_on_join() {
+        let mut toasts = ToastManager::new();
+        toasts.add(warn("sync", "first", Duration::from_secs(1)));
+        toasts.add(warn("sync", "second", Duration::ZERO));
+
+        let first = toasts.get_active()[0].expires_at();
+        let stacks = toasts.stacks();
+        assert!(stacks[0].expires_at > first);
+        assert!(!stacks[0].is_expired());
+
+        toasts.remove_expired();
+        assert_eq!(toasts.get_active().len(), 2);
+    }
+
+    #[test]
+    fn test_click_and_keys_expand_scroll_and_dismiss_a_group() {
+        let frame = Rect::new(0, 0, 80, 30);
+        let mut toasts = ToastManager::new();
+        toasts.info("alone");
+        for i in 0..10 {
+            toasts.add(warn("import", &format!("row {i}"), Duration::from_secs(10)));
+        }
+
+        let areas = stack_areas(&toasts.stacks(), frame);
+        let (_, card) = areas[0];
+        assert_eq!(card.height, 4);
+        assert!(toasts.handle_click(card.x + 1, card.y + 2, frame));
+        assert_eq!(toasts.expanded_group(), Some("import"));
+
+        for _ in 0..5 {
+            toasts.handle_key(KeyEvent::from(KeyCode::Down));
+        }
+        assert_eq!(toasts.expanded_scroll(), 10 - TOAST_GROUP_MAX_ROWS);
+        assert!(toasts.handle_key(KeyEvent::from(KeyCode::Esc)));
+        assert_eq!(toasts.expanded_group(), None);
+        assert!(!toasts.handle_key(KeyEvent::from(KeyCode::Esc)));
+
+        assert!(toasts.toggle_latest_group());
+        assert!(toasts.handle_click(0, 0, frame));
+        assert_eq!(toasts.expanded_group(), None);
+
+        assert!(toasts.handle_click(card.x + 1, card.y + 1, frame));
+        assert_eq!(toasts.get_active().len(), 1);
+        assert_eq!(toasts.get_active()[0].message, "alone");
+    }
+}
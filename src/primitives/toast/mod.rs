@@ -1,7 +1,12 @@
 //! Toast notification component
 //!
 //! Provides toast notifications with different levels (success, error, info, warning).
+//!
+//! Toasts created with [`Toast::grouped`] that share a group key are shown
+//! as one stacked card with the latest message and a "(+N more)" line.
+//! Expanding the group fans its members out in a scrollable list.
 
+use std::collections::HashMap;
 use std::time::{Duration, Instant};
 
 pub mod level;
@@ -11,6 +16,16 @@ pub mod render;
 /// Default toast display duration (3 seconds).
 pub const DEFAULT_TOAST_DURATION: Duration = Duration::from_secs(3);
 
+/// Default number of toasts kept in the notification history.
+pub const DEFAULT_TOAST_HISTORY: usize = 50;
+
+/// How long a visible group stays up after a new member joins it, even if
+/// the new member's own duration is shorter.
+pub const TOAST_GROUP_LINGER: Duration = Duration::from_secs(2);
+
+/// Most members listed at once when a group is expanded.
+pub const TOAST_GROUP_MAX_ROWS: usize = 8;
+
 /// Toast notification level
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ToastLevel {
@@ -27,12 +42,37 @@ pub struct Toast {
     pub level: ToastLevel,
     pub created_at: Instant,
     pub duration: Duration,
+    /// Key of the group the toast is stacked with, if any.
+    pub group: Option<String>,
+}
+
+/// The active toasts as they are shown: an ungrouped toast on its own, or
+/// the active members of a group stacked into one card.
+#[derive(Debug, Clone)]
+pub struct ToastStack<'a> {
+    /// The group key, or `None` for an ungrouped toast.
+    pub group: Option<&'a str>,
+    /// The toasts in the stack, oldest first. Never empty.
+    pub toasts: Vec<&'a Toast>,
+    /// When the whole stack goes away.
+    pub expires_at: Instant,
 }
 
 /// Manages multiple toast notifications
+///
+/// Every added toast is also recorded in a bounded history with an unread
+/// count, so status UI can show notifications that were missed.
 #[derive(Debug, Default)]
 pub struct ToastManager {
     toasts: Vec<Toast>,
     max_toasts: usize,
+    history: Vec<Toast>,
+    max_history: usize,
+    unread: usize,
+    generation: u64,
+    do_not_disturb: bool,
+    group_lingers: HashMap<String, Instant>,
+    expanded: Option<String>,
+    expanded_scroll: usize,
 }
-pub use render::render_toasts;
+pub use render::{render_toast_history, render_toasts};
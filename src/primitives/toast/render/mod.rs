@@ -1,40 +1,84 @@
-use crate::primitives::toast::ToastManager;
+use crate::primitives::toast::{ToastManager, ToastStack, TOAST_GROUP_MAX_ROWS};
 use ratatui::layout::{Alignment, Rect};
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap};
 use ratatui::Frame;
 
-pub fn render_toasts(frame: &mut Frame, toasts: &ToastManager) {
-    let active_toasts = toasts.get_active();
-    if active_toasts.is_empty() {
-        return;
-    }
-
-    let area = frame.area();
-
-    const TOAST_WIDTH: u16 = 40;
-    const TOAST_HEIGHT: u16 = 3;
-    const TOAST_MARGIN: u16 = 2;
-    const TOAST_SPACING: u16 = 1;
+const TOAST_WIDTH: u16 = 40;
+const TOAST_HEIGHT: u16 = 3;
+const TOAST_MARGIN: u16 = 2;
+const TOAST_SPACING: u16 = 1;
 
+/// Where each of `stacks` is drawn, as indices into `stacks` with their
+/// areas, newest at the bottom right of `area`. Expired stacks and stacks
+/// that do not fit are left out.
+pub(crate) fn stack_areas(stacks: &[ToastStack<'_>], area: Rect) -> Vec<(usize, Rect)> {
+    let mut areas = Vec::new();
     let mut y_offset = area.height.saturating_sub(TOAST_MARGIN);
 
-    for toast in active_toasts.iter().rev() {
-        let toast_y = y_offset.saturating_sub(TOAST_HEIGHT);
-        let toast_x = area.width.saturating_sub(TOAST_WIDTH + TOAST_MARGIN);
+    for (index, stack) in stacks.iter().enumerate().rev() {
+        if stack.is_expired() {
+            continue;
+        }
 
-        let toast_area = Rect {
-            x: toast_x,
-            y: toast_y,
-            width: TOAST_WIDTH,
-            height: TOAST_HEIGHT,
+        // A group's card has an extra line for "(+N more)".
+        let height = if stack.group.is_some() {
+            TOAST_HEIGHT + 1
+        } else {
+            TOAST_HEIGHT
         };
+        let toast_y = y_offset.saturating_sub(height);
+        let toast_x = area.width.saturating_sub(TOAST_WIDTH + TOAST_MARGIN);
 
         if toast_y == 0 || toast_x == 0 {
             break;
         }
 
+        areas.push((
+            index,
+            Rect {
+                x: toast_x,
+                y: toast_y,
+                width: TOAST_WIDTH,
+                height,
+            },
+        ));
+
+        y_offset = toast_y.saturating_sub(TOAST_SPACING);
+    }
+
+    areas
+}
+
+/// Where the list of an expanded group with `members` toasts is drawn,
+/// over the bottom right of `area`.
+pub(crate) fn fanout_area(members: usize, area: Rect) -> Rect {
+    let rows = members.clamp(1, TOAST_GROUP_MAX_ROWS) as u16;
+    let width = TOAST_WIDTH.min(area.width);
+    let height = (rows + 2).min(area.height.saturating_sub(TOAST_MARGIN));
+    Rect {
+        x: area.width.saturating_sub(TOAST_WIDTH + TOAST_MARGIN),
+        y: area.height.saturating_sub(TOAST_MARGIN + height),
+        width,
+        height,
+    }
+}
+
+/// Renders the active toasts stacked at the bottom right of the frame,
+/// with the list of an expanded group over them.
+pub fn render_toasts(frame: &mut Frame, toasts: &ToastManager) {
+    let stacks = toasts.stacks();
+    if stacks.is_empty() {
+        return;
+    }
+
+    let area = frame.area();
+
+    for (index, toast_area) in stack_areas(&stacks, area) {
+        let stack = &stacks[index];
+        let toast = stack.latest();
+
         frame.render_widget(Clear, toast_area);
 
         let color = toast.level.color();
@@ -56,13 +100,203 @@ pub fn render_toasts(frame: &mut Frame, toasts: &ToastManager) {
             .border_type(BorderType::Rounded)
             .border_style(Style::default().fg(color));
 
-        let paragraph = Paragraph::new(text)
-            .block(block)
-            .alignment(Alignment::Left)
-            .wrap(Wrap { trim: true });
+        let paragraph = if stack.group.is_some() {
+            let more = Line::from(Span::styled(
+                format!("     (+{} more)", stack.hidden_count()),
+                Style::default().add_modifier(Modifier::DIM),
+            ));
+            Paragraph::new(vec![text, more])
+        } else {
+            Paragraph::new(text).wrap(Wrap { trim: true })
+        };
 
-        frame.render_widget(paragraph, toast_area);
+        frame.render_widget(
+            paragraph.block(block).alignment(Alignment::Left),
+            toast_area,
+        );
+    }
 
-        y_offset = toast_y.saturating_sub(TOAST_SPACING);
+    if let Some(group) = toasts.expanded_group() {
+        if let Some(stack) = stacks.iter().find(|stack| stack.group == Some(group)) {
+            render_group_fanout(frame, stack, toasts.expanded_scroll(), area);
+        }
+    }
+}
+
+/// Lists the members of an expanded group, newest first, with the rows
+/// scrolled out of view counted in the border.
+fn render_group_fanout(frame: &mut Frame, stack: &ToastStack<'_>, scroll: usize, area: Rect) {
+    let popup_area = fanout_area(stack.toasts.len(), area);
+    if popup_area.width == 0 || popup_area.height <= 2 {
+        return;
+    }
+
+    let rows = usize::from(popup_area.height - 2);
+    let scroll = scroll.min(stack.toasts.len().saturating_sub(rows));
+    let below = stack.toasts.len().saturating_sub(scroll + rows);
+
+    let lines: Vec<Line> = stack
+        .toasts
+        .iter()
+        .rev()
+        .skip(scroll)
+        .take(rows)
+        .map(|toast| {
+            Line::from(vec![
+                Span::raw(" "),
+                Span::styled(
+                    toast.level.icon(),
+                    Style::default()
+                        .fg(toast.level.color())
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(" "),
+                Span::raw(toast.message.as_str()),
+            ])
+        })
+        .collect();
+
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(stack.latest().level.color()))
+        .title(format!(
+            " {} ({}) ",
+            stack.group.unwrap_or_default(),
+            stack.toasts.len()
+        ));
+    if scroll > 0 {
+        block = block.title_bottom(Line::from(format!(" ↑ {scroll} more ")).left_aligned());
+    }
+    if below > 0 {
+        block = block.title_bottom(Line::from(format!(" ↓ {below} more ")).right_aligned());
+    }
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(Paragraph::new(lines).block(block), popup_area);
+}
+
+/// Renders the toast history as a popup anchored to `anchor`.
+///
+/// The popup is right-aligned with the anchor and placed above it when there
+/// is room (e.g. for a status line segment), otherwise below it. Newest
+/// entries are listed first.
+pub fn render_toast_history(frame: &mut Frame, toasts: &ToastManager, anchor: Rect) {
+    const HISTORY_WIDTH: u16 = 48;
+    const HISTORY_MAX_ROWS: u16 = 10;
+
+    let area = frame.area();
+    let history = toasts.history();
+
+    let rows = (history.len() as u16).clamp(1, HISTORY_MAX_ROWS);
+    let width = HISTORY_WIDTH.min(area.width);
+    let height = rows.saturating_add(2).min(area.height);
+    if width == 0 || height == 0 {
+        return;
+    }
+
+    let x = anchor
+        .right()
+        .min(area.right())
+        .saturating_sub(width)
+        .max(area.x);
+    let y = if anchor.y.saturating_sub(area.y) >= height {
+        anchor.y - height
+    } else {
+        anchor.bottom().min(area.bottom().saturating_sub(height))
+    };
+    let popup_area = Rect {
+        x,
+        y,
+        width,
+        height,
+    };
+
+    let lines: Vec<Line> = if history.is_empty() {
+        vec![Line::from(Span::styled(
+            "  No notifications",
+            Style::default().add_modifier(Modifier::DIM),
+        ))]
+    } else {
+        history
+            .iter()
+            .rev()
+            .take(rows as usize)
+            .map(|toast| {
+                Line::from(vec![
+                    Span::raw(" "),
+                    Span::styled(
+                        toast.level.icon(),
+                        Style::default()
+                            .fg(toast.level.color())
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::raw(" "),
+                    Span::styled(
+                        toast
+                            .group
+                            .as_ref()
+                            .map(|group| format!("[{group}] "))
+                            .unwrap_or_default(),
+                        Style::default().add_modifier(Modifier::DIM),
+                    ),
+                    Span::raw(toast.message.as_str()),
+                ])
+            })
+            .collect()
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(" Notifications ");
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(Paragraph::new(lines).block(block), popup_area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::toast::{Toast, ToastLevel};
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    fn screen(toasts: &ToastManager) -> Vec<String> {
+        let mut terminal = Terminal::new(TestBackend::new(50, 20)).unwrap();
+        terminal.draw(|frame| render_toasts(frame, toasts)).unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..20)
+            .map(|y| (0..50).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_group_renders_as_card_and_caps_fanout() {
+        let mut toasts = ToastManager::new();
+        for i in 0..12 {
+            toasts.add(Toast::grouped(
+                "import",
+                format!("row {i}"),
+                ToastLevel::Warning,
+                None,
+            ));
+        }
+
+        let rows = screen(&toasts);
+        assert!(rows.iter().any(|row| row.contains("row 11")));
+        assert!(rows.iter().any(|row| row.contains("(+11 more)")));
+        assert!(!rows.iter().any(|row| row.contains("row 10")));
+
+        toasts.expand_group("import");
+        toasts.scroll_expanded(1);
+        let rows = screen(&toasts);
+        let listed = rows.iter().filter(|row| row.contains(" row ")).count();
+        assert_eq!(listed, TOAST_GROUP_MAX_ROWS);
+        assert!(rows.iter().any(|row| row.contains("import (12)")));
+        assert!(rows.iter().any(|row| row.contains("row 10")));
+        assert!(!rows.iter().any(|row| row.contains("row 11")));
+        let footer = rows.iter().find(|row| row.contains("↑ 1 more")).unwrap();
+        assert!(footer.contains("↓ 3 more"), "{footer}");
     }
 }
@@ -0,0 +1,34 @@
+//! Look up a node by its path.
+
+use crate::primitives::tree_view::TreeNode;
+
+/// Gets the node at a path of child indices.
+///
+/// # Arguments
+///
+/// * `nodes` - The tree nodes
+/// * `path` - The path to the node (indices from root)
+///
+/// # Returns
+///
+/// A reference to the node at the path, or `None` if the path is empty or
+/// leads past the tree.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_toolkit::tree_view::{helpers::get_node_at_path, TreeNode};
+///
+/// let nodes = vec![TreeNode::with_children("src", vec![TreeNode::new("lib.rs")])];
+/// assert_eq!(get_node_at_path(&nodes, &[0, 0]).map(|n| n.data), Some("lib.rs"));
+/// assert!(get_node_at_path(&nodes, &[0, 1]).is_none());
+/// assert!(get_node_at_path(&nodes, &[]).is_none());
+/// ```
+pub fn get_node_at_path<'a, T>(
+    nodes: &'a [TreeNode<T>],
+    path: &[usize],
+) -> Option<&'a TreeNode<T>> {
+    let (&first, rest) = path.split_first()?;
+    rest.iter()
+        .try_fold(nodes.get(first)?, |node, &idx| node.children.get(idx))
+}
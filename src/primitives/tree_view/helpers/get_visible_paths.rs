@@ -5,7 +5,9 @@ use crate::primitives::tree_view::{TreeNode, TreeViewState};
 /// Gets all visible paths (flattened tree with expansion state).
 ///
 /// Returns paths for all nodes that are currently visible, respecting
-/// the expansion state of parent nodes.
+/// the expansion state of parent nodes. An expanded node whose children
+/// are still loading contributes a single placeholder row at its first
+/// child path, matching what the widgets render.
 ///
 /// # Arguments
 ///
@@ -29,8 +31,16 @@ pub fn get_visible_paths<T>(nodes: &[TreeNode<T>], state: &TreeViewState) -> Vec
             path.push(idx);
             paths.push(path.clone());
 
+            if !state.is_expanded(&path) {
+                continue;
+            }
+
             // If expanded, recurse into children
-            if state.is_expanded(&path) && !node.children.is_empty() {
+            if node.needs_children() {
+                let mut placeholder = path;
+                placeholder.push(0);
+                paths.push(placeholder);
+            } else if !node.children.is_empty() {
                 traverse(&node.children, path, state, paths);
             }
         }
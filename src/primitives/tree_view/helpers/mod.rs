@@ -1,9 +1,11 @@
 //! Helper functions for tree view.
 
+mod get_node_at_path;
 mod get_visible_paths;
 mod get_visible_paths_filtered;
 mod matches_filter;
 
+pub use get_node_at_path::get_node_at_path;
 pub use get_visible_paths::get_visible_paths;
 pub use get_visible_paths_filtered::get_visible_paths_filtered;
 pub use matches_filter::matches_filter;
@@ -1,6 +1,6 @@
 use crossterm::event::KeyCode;
 
-use crate::primitives::tree_view::keybindings::TreeKeyBindings;
+use crate::primitives::tree_view::keybindings::{TreeKeyBindings, DEFAULT_TYPE_AHEAD_TIMEOUT};
 
 impl Default for TreeKeyBindings {
     fn default() -> Self {
@@ -12,6 +12,9 @@ impl Default for TreeKeyBindings {
             toggle: vec![KeyCode::Enter],
             goto_top: vec![KeyCode::Char('g')],
             goto_bottom: vec![KeyCode::Char('G')],
+            toggle_check: vec![KeyCode::Char(' ')],
+            type_ahead: false,
+            type_ahead_timeout: DEFAULT_TYPE_AHEAD_TIMEOUT,
         }
     }
 }
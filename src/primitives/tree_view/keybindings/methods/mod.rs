@@ -5,3 +5,6 @@ mod with_goto_top;
 mod with_next;
 mod with_previous;
 mod with_toggle;
+mod with_toggle_check;
+mod with_type_ahead;
+mod with_type_ahead_timeout;
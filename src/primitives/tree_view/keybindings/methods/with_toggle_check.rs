@@ -0,0 +1,11 @@
+use crossterm::event::KeyCode;
+
+use crate::primitives::tree_view::keybindings::TreeKeyBindings;
+
+impl TreeKeyBindings {
+    /// Set custom keybindings for checking/unchecking the selected item
+    pub fn with_toggle_check(mut self, keys: Vec<KeyCode>) -> Self {
+        self.toggle_check = keys;
+        self
+    }
+}
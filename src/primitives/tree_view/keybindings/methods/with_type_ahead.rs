@@ -0,0 +1,9 @@
+use crate::primitives::tree_view::keybindings::TreeKeyBindings;
+
+impl TreeKeyBindings {
+    /// Enable or disable jumping to nodes by typing a label prefix
+    pub fn with_type_ahead(mut self, enabled: bool) -> Self {
+        self.type_ahead = enabled;
+        self
+    }
+}
@@ -0,0 +1,11 @@
+use std::time::Duration;
+
+use crate::primitives::tree_view::keybindings::TreeKeyBindings;
+
+impl TreeKeyBindings {
+    /// Set the idle time after which the type-ahead buffer starts over
+    pub fn with_type_ahead_timeout(mut self, timeout: Duration) -> Self {
+        self.type_ahead_timeout = timeout;
+        self
+    }
+}
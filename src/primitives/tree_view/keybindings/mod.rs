@@ -1,8 +1,13 @@
 mod constructors;
 mod methods;
 
+use std::time::Duration;
+
 use crossterm::event::KeyCode;
 
+/// Default idle time after which the type-ahead buffer starts over.
+pub const DEFAULT_TYPE_AHEAD_TIMEOUT: Duration = Duration::from_millis(700);
+
 /// Configurable keybindings for tree navigation
 #[derive(Debug, Clone)]
 pub struct TreeKeyBindings {
@@ -13,4 +18,10 @@ pub struct TreeKeyBindings {
     pub toggle: Vec<KeyCode>,
     pub goto_top: Vec<KeyCode>,
     pub goto_bottom: Vec<KeyCode>,
+    /// Keys that check or uncheck the selected item
+    pub toggle_check: Vec<KeyCode>,
+    /// Whether typed characters jump to nodes by label prefix
+    pub type_ahead: bool,
+    /// Idle time after which the type-ahead buffer starts over
+    pub type_ahead_timeout: Duration,
 }
@@ -4,10 +4,13 @@
 //! - `TreeNode` - Hierarchical data structure for tree nodes
 //! - `NodeState` - State information for rendering nodes
 //! - `TreeViewState` - Selection, expansion, and filter state
-//! - `TreeView` - The main widget (takes ownership of nodes)
+//! - `TreeView` - The main widget (takes ownership of nodes), with optional
+//!   variable-height rows
+//! - `VisibleRow` - A visible node yielded by `TreeView::visible_rows`
 //! - `TreeViewRef` - Widget that borrows nodes (avoids cloning)
 //! - `TreeNavigator` - Keyboard navigation with configurable keybindings
 //! - `TreeKeyBindings` - Customizable keybindings for navigation
+//! - `TreeNavigatorConfig` - Navigation behavior (auto-expand, scroll margin)
 //!
 //! # Example
 //!
@@ -31,17 +34,24 @@
 
 pub mod helpers;
 mod keybindings;
+mod navigator_config;
 mod node_state;
+mod row_heights;
 mod tree_navigator;
 mod tree_node;
 mod tree_view_ref;
 mod tree_view_state;
+mod visible_row;
 mod widget;
 
 // Re-export keybindings
-pub use keybindings::TreeKeyBindings;
+pub use keybindings::{TreeKeyBindings, DEFAULT_TYPE_AHEAD_TIMEOUT};
+
+// Re-export navigator_config
+pub use navigator_config::{ExpandBehavior, RevealMode, TreeNavigatorConfig};
 
 // Re-export helpers
+pub use helpers::get_node_at_path;
 pub use helpers::get_visible_paths;
 pub use helpers::get_visible_paths_filtered;
 pub use helpers::matches_filter;
@@ -56,6 +66,8 @@ pub use tree_navigator::TreeNavigator;
 pub use tree_node::TreeNode;
 
 // Re-export widget
+pub use widget::NodeDetailFn;
+pub use widget::NodeHeightFn;
 pub use widget::NodeRenderFn;
 pub use widget::TreeView;
 
@@ -66,3 +78,6 @@ pub use tree_view_ref::TreeViewRef;
 
 // Re-export tree_view_state
 pub use tree_view_state::TreeViewState;
+
+// Re-export visible_row
+pub use visible_row::{VisibleRow, VisibleRows};
@@ -0,0 +1,8 @@
+use crate::primitives::tree_view::navigator_config::TreeNavigatorConfig;
+
+impl TreeNavigatorConfig {
+    /// Create a config with the default behavior
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
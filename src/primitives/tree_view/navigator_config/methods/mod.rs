@@ -0,0 +1,5 @@
+mod with_auto_expand_on_select;
+mod with_cascade_check;
+mod with_expand_on_right_when_expanded;
+mod with_reveal_mode;
+mod with_scroll_margin;
@@ -0,0 +1,9 @@
+use crate::primitives::tree_view::navigator_config::TreeNavigatorConfig;
+
+impl TreeNavigatorConfig {
+    /// Expand collapsed nodes when the selection moves onto them
+    pub fn with_auto_expand_on_select(mut self, enabled: bool) -> Self {
+        self.auto_expand_on_select = enabled;
+        self
+    }
+}
@@ -0,0 +1,9 @@
+use crate::primitives::tree_view::navigator_config::TreeNavigatorConfig;
+
+impl TreeNavigatorConfig {
+    /// Apply check toggles to the visible descendants of the toggled node
+    pub fn with_cascade_check(mut self, enabled: bool) -> Self {
+        self.cascade_check = enabled;
+        self
+    }
+}
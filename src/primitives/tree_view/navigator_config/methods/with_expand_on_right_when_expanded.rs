@@ -0,0 +1,9 @@
+use crate::primitives::tree_view::navigator_config::{ExpandBehavior, TreeNavigatorConfig};
+
+impl TreeNavigatorConfig {
+    /// Set what the expand key does on an already expanded node
+    pub fn with_expand_on_right_when_expanded(mut self, behavior: ExpandBehavior) -> Self {
+        self.expand_on_right_when_expanded = behavior;
+        self
+    }
+}
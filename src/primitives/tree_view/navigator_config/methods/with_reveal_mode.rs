@@ -0,0 +1,9 @@
+use crate::primitives::tree_view::navigator_config::{RevealMode, TreeNavigatorConfig};
+
+impl TreeNavigatorConfig {
+    /// Set where the selection is placed when revealed
+    pub fn with_reveal_mode(mut self, mode: RevealMode) -> Self {
+        self.reveal_mode = mode;
+        self
+    }
+}
@@ -0,0 +1,9 @@
+use crate::primitives::tree_view::navigator_config::TreeNavigatorConfig;
+
+impl TreeNavigatorConfig {
+    /// Set the rows of context kept above and below the selection
+    pub fn with_scroll_margin(mut self, margin: u16) -> Self {
+        self.scroll_margin = margin;
+        self
+    }
+}
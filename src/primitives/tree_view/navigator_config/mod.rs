@@ -0,0 +1,55 @@
+//! Behavior configuration for TreeNavigator.
+
+mod constructors;
+mod methods;
+
+/// What the expand key does on a node that is already expanded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExpandBehavior {
+    /// Keep the selection on the node.
+    #[default]
+    Stay,
+    /// Move the selection to the node's first child.
+    FirstChild,
+    /// Move the selection to the node's next sibling, if any.
+    NextSibling,
+}
+
+/// Where the selected row is placed when the navigator scrolls to reveal it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RevealMode {
+    /// Scroll the minimum amount, keeping `scroll_margin` rows of context.
+    #[default]
+    Nearest,
+    /// Place the selection at the top, below `scroll_margin` rows of context.
+    Top,
+    /// Place the selection in the middle of the viewport.
+    Center,
+}
+
+/// Behavior options for tree navigation.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_toolkit::tree_view::{ExpandBehavior, TreeNavigator, TreeNavigatorConfig};
+///
+/// let config = TreeNavigatorConfig::new()
+///     .with_auto_expand_on_select(true)
+///     .with_expand_on_right_when_expanded(ExpandBehavior::FirstChild)
+///     .with_scroll_margin(3);
+/// let navigator = TreeNavigator::new().with_config(config);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TreeNavigatorConfig {
+    /// Expand collapsed nodes when the selection moves onto them.
+    pub auto_expand_on_select: bool,
+    /// What the expand key does on an already expanded node.
+    pub expand_on_right_when_expanded: ExpandBehavior,
+    /// Rows of context kept above and below the selection when scrolling.
+    pub scroll_margin: u16,
+    /// Where the selection is placed when revealed.
+    pub reveal_mode: RevealMode,
+    /// Apply check toggles to the visible descendants of the toggled node.
+    pub cascade_check: bool,
+}
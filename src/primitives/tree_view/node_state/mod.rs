@@ -14,6 +14,7 @@
 /// let state = NodeState {
 ///     is_selected: true,
 ///     is_expanded: false,
+///     is_checked: false,
 ///     level: 0,
 ///     has_children: true,
 ///     path: vec![0],
@@ -25,6 +26,8 @@ pub struct NodeState {
     pub is_selected: bool,
     /// Whether this node is expanded
     pub is_expanded: bool,
+    /// Whether this node is checked (multi-select)
+    pub is_checked: bool,
     /// Depth level in the tree (0 = root)
     pub level: usize,
     /// Whether this node has children
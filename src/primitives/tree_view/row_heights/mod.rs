@@ -0,0 +1,219 @@
+//! Prefix-sum index over the heights of visible tree rows.
+
+use std::collections::HashMap;
+
+/// Heights of the visible rows, indexed for O(log n) line and row lookups.
+///
+/// Rows whose height has not been measured yet count as the estimated
+/// height. Measured heights are cached by node path, so re-syncing after an
+/// expand, collapse or filter change only measures rows that were not
+/// visible before.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RowHeights {
+    /// Visible row paths the index was built for
+    paths: Vec<Vec<usize>>,
+    /// Current height of each row (measured or estimated)
+    heights: Vec<usize>,
+    /// Fenwick tree over `heights`
+    tree: Vec<usize>,
+    /// Measured heights by node path
+    measured: HashMap<Vec<usize>, usize>,
+    /// Height assumed for rows that have not been measured
+    estimate: usize,
+    /// Width the measured heights were computed for
+    width: u16,
+}
+
+impl RowHeights {
+    /// Rebuilds the index if the visible rows, estimate or width changed.
+    ///
+    /// A width change drops every measured height.
+    pub(crate) fn sync<'p>(
+        &mut self,
+        paths: impl IntoIterator<Item = &'p Vec<usize>>,
+        estimate: usize,
+        width: u16,
+    ) {
+        let estimate = estimate.max(1);
+        if width != self.width {
+            self.measured.clear();
+            self.paths.clear();
+            self.width = width;
+        }
+
+        let paths: Vec<&Vec<usize>> = paths.into_iter().collect();
+        let unchanged = estimate == self.estimate
+            && paths.len() == self.paths.len()
+            && paths.iter().zip(&self.paths).all(|(new, old)| *new == old);
+        if unchanged && self.heights.len() == self.paths.len() {
+            return;
+        }
+
+        self.estimate = estimate;
+        self.paths = paths.into_iter().cloned().collect();
+        self.heights = self
+            .paths
+            .iter()
+            .map(|path| self.measured.get(path).copied().unwrap_or(estimate))
+            .collect();
+        self.rebuild_tree();
+    }
+
+    /// Drops every measured height and forces the next sync to rebuild.
+    pub(crate) fn invalidate(&mut self) {
+        self.measured.clear();
+        self.paths.clear();
+        self.heights.clear();
+        self.tree.clear();
+    }
+
+    /// Number of rows in the index.
+    pub(crate) fn len(&self) -> usize {
+        self.heights.len()
+    }
+
+    /// Path of the node shown on `row`.
+    pub(crate) fn path(&self, row: usize) -> Option<&Vec<usize>> {
+        self.paths.get(row)
+    }
+
+    /// Width the index was last synced for.
+    pub(crate) fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Current height of `row`, measured or estimated.
+    pub(crate) fn height(&self, row: usize) -> usize {
+        self.heights.get(row).copied().unwrap_or(0)
+    }
+
+    /// Whether `row` has a measured height.
+    pub(crate) fn is_measured(&self, row: usize) -> bool {
+        self.paths
+            .get(row)
+            .is_some_and(|path| self.measured.contains_key(path))
+    }
+
+    /// Records the measured height of `row`.
+    pub(crate) fn set_measured(&mut self, row: usize, height: usize) {
+        let Some(path) = self.paths.get(row) else {
+            return;
+        };
+        let height = height.max(1);
+        self.measured.insert(path.clone(), height);
+
+        let old = std::mem::replace(&mut self.heights[row], height);
+        if old == height {
+            return;
+        }
+        let mut i = row + 1;
+        while i <= self.heights.len() {
+            self.tree[i - 1] = self.tree[i - 1] - old + height;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Total height of all rows in lines.
+    pub(crate) fn total(&self) -> usize {
+        self.offset_of(self.heights.len())
+    }
+
+    /// Line at which `row` starts, i.e. the total height of the rows above it.
+    pub(crate) fn offset_of(&self, row: usize) -> usize {
+        let mut sum = 0;
+        let mut i = row.min(self.heights.len());
+        while i > 0 {
+            sum += self.tree[i - 1];
+            i -= i & i.wrapping_neg();
+        }
+        sum
+    }
+
+    /// Row covering `line`, or `None` past the last row.
+    pub(crate) fn row_at(&self, line: usize) -> Option<usize> {
+        let len = self.heights.len();
+        let mut row = 0;
+        let mut remaining = line;
+        let mut step = len.checked_next_power_of_two()?;
+        while step > 0 {
+            let next = row + step;
+            if next <= len && self.tree[next - 1] <= remaining {
+                row = next;
+                remaining -= self.tree[next - 1];
+            }
+            step >>= 1;
+        }
+        (row < len).then_some(row)
+    }
+
+    fn rebuild_tree(&mut self) {
+        self.tree = self.heights.clone();
+        for i in 1..=self.tree.len() {
+            let parent = i + (i & i.wrapping_neg());
+            if parent <= self.tree.len() {
+                self.tree[parent - 1] += self.tree[i - 1];
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RowHeights;
+
+    fn paths(count: usize) -> Vec<Vec<usize>> {
+        (0..count).map(|i| vec![i]).collect()
+    }
+
+    #[test]
+    fn maps_lines_to_rows_and_back() {
+        let paths = paths(5);
+        let mut heights = RowHeights::default();
+        heights.sync(&paths, 1, 20);
+        heights.set_measured(1, 3);
+        heights.set_measured(3, 2);
+
+        // Rows: 0 -> line 0, 1 -> lines 1..4, 2 -> line 4, 3 -> lines 5..7, 4 -> line 7
+        assert_eq!(heights.total(), 8);
+        assert_eq!(
+            (0..=5)
+                .map(|row| heights.offset_of(row))
+                .collect::<Vec<_>>(),
+            [0, 1, 4, 5, 7, 8]
+        );
+        assert_eq!(
+            (0..9).map(|line| heights.row_at(line)).collect::<Vec<_>>(),
+            [
+                Some(0),
+                Some(1),
+                Some(1),
+                Some(1),
+                Some(2),
+                Some(3),
+                Some(3),
+                Some(4),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn resync_keeps_measured_heights_by_path() {
+        let mut heights = RowHeights::default();
+        heights.sync(&paths(3), 2, 20);
+        heights.set_measured(2, 5);
+        assert_eq!(heights.total(), 9);
+
+        // A row is inserted above the measured one, e.g. by expanding a node.
+        let expanded = vec![vec![0], vec![0, 0], vec![1], vec![2]];
+        heights.sync(&expanded, 2, 20);
+        assert!(heights.is_measured(3));
+        assert!(!heights.is_measured(1));
+        assert_eq!(heights.height(3), 5);
+        assert_eq!(heights.total(), 11);
+
+        heights.sync(&expanded, 2, 30);
+        assert!(!heights.is_measured(3));
+        assert_eq!(heights.total(), 8);
+    }
+}
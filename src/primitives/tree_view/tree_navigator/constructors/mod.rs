@@ -1,4 +1,5 @@
 //! Constructor functions for TreeNavigator.
 
 pub mod new;
+pub mod with_config;
 pub mod with_keybindings;
@@ -1,6 +1,7 @@
 //! TreeNavigator::new constructor.
 
 use crate::primitives::tree_view::keybindings::TreeKeyBindings;
+use crate::primitives::tree_view::navigator_config::TreeNavigatorConfig;
 use crate::primitives::tree_view::tree_navigator::TreeNavigator;
 
 impl TreeNavigator {
@@ -20,6 +21,7 @@ impl TreeNavigator {
     pub fn new() -> Self {
         Self {
             keybindings: TreeKeyBindings::default(),
+            config: TreeNavigatorConfig::default(),
         }
     }
 }
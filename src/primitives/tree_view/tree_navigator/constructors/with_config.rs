@@ -0,0 +1,30 @@
+//! TreeNavigator::with_config constructor.
+
+use crate::primitives::tree_view::navigator_config::TreeNavigatorConfig;
+use crate::primitives::tree_view::tree_navigator::TreeNavigator;
+
+impl TreeNavigator {
+    /// Sets the navigation behavior options.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The behavior options to use.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_toolkit::tree_view::{TreeNavigator, TreeNavigatorConfig};
+    ///
+    /// let navigator = TreeNavigator::new()
+    ///     .with_config(TreeNavigatorConfig::new().with_scroll_margin(2));
+    /// assert_eq!(navigator.config.scroll_margin, 2);
+    /// ```
+    pub fn with_config(mut self, config: TreeNavigatorConfig) -> Self {
+        self.config = config;
+        self
+    }
+}
@@ -1,6 +1,7 @@
 //! TreeNavigator::with_keybindings constructor.
 
 use crate::primitives::tree_view::keybindings::TreeKeyBindings;
+use crate::primitives::tree_view::navigator_config::TreeNavigatorConfig;
 use crate::primitives::tree_view::tree_navigator::TreeNavigator;
 
 impl TreeNavigator {
@@ -23,6 +24,9 @@ impl TreeNavigator {
     /// let navigator = TreeNavigator::with_keybindings(keybindings);
     /// ```
     pub fn with_keybindings(keybindings: TreeKeyBindings) -> Self {
-        Self { keybindings }
+        Self {
+            keybindings,
+            config: TreeNavigatorConfig::default(),
+        }
     }
 }
@@ -52,6 +52,9 @@ impl TreeNavigator {
         } else if self.keybindings.goto_bottom.contains(&code) {
             self.goto_bottom(nodes, state);
             true
+        } else if self.keybindings.toggle_check.contains(&code) {
+            self.toggle_checked(nodes, state);
+            true
         } else {
             false
         }
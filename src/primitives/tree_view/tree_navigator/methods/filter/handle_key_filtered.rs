@@ -62,6 +62,9 @@ impl TreeNavigator {
         } else if self.keybindings.goto_bottom.contains(&code) {
             self.goto_bottom_filtered(nodes, state, |data, filter| matcher(data, filter));
             true
+        } else if self.keybindings.toggle_check.contains(&code) {
+            self.toggle_checked_filtered(nodes, state, |data, filter| matcher(data, filter));
+            true
         } else {
             false
         }
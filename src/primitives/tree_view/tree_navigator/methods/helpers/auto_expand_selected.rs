@@ -0,0 +1,46 @@
+//! TreeNavigator::auto_expand_selected helper method.
+
+use crate::primitives::tree_view::helpers::get_node_at_path;
+use crate::primitives::tree_view::tree_navigator::TreeNavigator;
+use crate::primitives::tree_view::tree_node::TreeNode;
+use crate::primitives::tree_view::tree_view_state::TreeViewState;
+
+impl TreeNavigator {
+    /// Expands the selected node after a selection move, if configured.
+    ///
+    /// Does nothing unless `auto_expand_on_select` is enabled and the
+    /// selection actually changed, so a collapsed node stays collapsed
+    /// when a move is a no-op at the ends of the tree.
+    ///
+    /// # Arguments
+    ///
+    /// * `nodes` - The tree nodes.
+    /// * `state` - The tree view state to update.
+    /// * `previous` - The selected path before the move.
+    pub(crate) fn auto_expand_selected<T>(
+        &self,
+        nodes: &[TreeNode<T>],
+        state: &mut TreeViewState,
+        previous: Option<&[usize]>,
+    ) {
+        if !self.config.auto_expand_on_select {
+            return;
+        }
+
+        let Some(path) = state.selected_path.clone() else {
+            return;
+        };
+        if previous == Some(path.as_slice()) || state.is_expanded(&path) {
+            return;
+        }
+
+        if let Some(node) = get_node_at_path(nodes, &path) {
+            if node.needs_children() {
+                state.request_children(path.clone());
+                state.expand(path);
+            } else if !node.children.is_empty() {
+                state.expand(path);
+            }
+        }
+    }
+}
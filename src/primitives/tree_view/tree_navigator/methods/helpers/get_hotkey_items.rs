@@ -27,6 +27,7 @@ impl TreeNavigator {
         let format_keys = |keys: &[KeyCode]| -> String {
             keys.iter()
                 .map(|k| match k {
+                    KeyCode::Char(' ') => "Space".to_string(),
                     KeyCode::Char(c) => c.to_string(),
                     KeyCode::Up => "\u{2191}".to_string(),
                     KeyCode::Down => "\u{2193}".to_string(),
@@ -46,6 +47,7 @@ impl TreeNavigator {
         items.push((format_keys(&self.keybindings.toggle), "Toggle"));
         items.push((format_keys(&self.keybindings.goto_top), "Top"));
         items.push((format_keys(&self.keybindings.goto_bottom), "Bottom"));
+        items.push((format_keys(&self.keybindings.toggle_check), "Check"));
 
         items
     }
@@ -1,4 +1,5 @@
 //! Helper methods for TreeNavigator.
 
+pub mod auto_expand_selected;
 pub mod get_hotkey_items;
-pub mod get_node_at_path;
+pub mod reveal_offset;
@@ -0,0 +1,53 @@
+//! TreeNavigator::reveal_offset helper method.
+
+use crate::primitives::tree_view::navigator_config::RevealMode;
+use crate::primitives::tree_view::tree_navigator::TreeNavigator;
+
+impl TreeNavigator {
+    /// Computes the scroll offset that reveals a row.
+    ///
+    /// Applies the configured `reveal_mode` and `scroll_margin`. The margin
+    /// is capped so it never exceeds half the viewport, and the result is
+    /// clamped so the last row never scrolls above the bottom edge.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The current scroll offset.
+    /// * `index` - The visible row index to reveal.
+    /// * `row_count` - The total number of visible rows.
+    /// * `viewport_height` - The number of rows available for the tree.
+    ///
+    /// # Returns
+    ///
+    /// The new scroll offset.
+    pub(crate) fn reveal_offset(
+        &self,
+        offset: usize,
+        index: usize,
+        row_count: usize,
+        viewport_height: usize,
+    ) -> usize {
+        if viewport_height == 0 {
+            return offset;
+        }
+
+        let margin = (self.config.scroll_margin as usize).min((viewport_height - 1) / 2);
+        let max_offset = row_count.saturating_sub(viewport_height);
+
+        let target = match self.config.reveal_mode {
+            RevealMode::Nearest => {
+                if index < offset + margin {
+                    index.saturating_sub(margin)
+                } else if index + margin >= offset + viewport_height {
+                    index + margin + 1 - viewport_height
+                } else {
+                    offset
+                }
+            }
+            RevealMode::Top => index.saturating_sub(margin),
+            RevealMode::Center => index.saturating_sub(viewport_height / 2),
+        };
+
+        target.min(max_offset)
+    }
+}
@@ -3,3 +3,4 @@
 pub mod filter;
 pub mod helpers;
 pub mod navigation;
+pub mod search;
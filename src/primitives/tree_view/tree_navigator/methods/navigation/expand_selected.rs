@@ -1,5 +1,7 @@
 //! TreeNavigator::expand_selected method.
 
+use crate::primitives::tree_view::helpers::get_node_at_path;
+use crate::primitives::tree_view::navigator_config::ExpandBehavior;
 use crate::primitives::tree_view::tree_navigator::TreeNavigator;
 use crate::primitives::tree_view::tree_node::TreeNode;
 use crate::primitives::tree_view::tree_view_state::TreeViewState;
@@ -7,7 +9,11 @@ use crate::primitives::tree_view::tree_view_state::TreeViewState;
 impl TreeNavigator {
     /// Expands the selected node.
     ///
-    /// Only expands if the node has children.
+    /// Only expands if the node has children. On a node that is already
+    /// expanded, the selection moves as configured by
+    /// `expand_on_right_when_expanded`. Expanding a node whose
+    /// children are not loaded yet also queues a load request, see
+    /// [`TreeViewState::take_pending_expansions`].
     ///
     /// # Arguments
     ///
@@ -30,11 +36,42 @@ impl TreeNavigator {
     pub fn expand_selected<T>(&self, nodes: &[TreeNode<T>], state: &mut TreeViewState) {
         if let Some(path) = state.selected_path.clone() {
             // Check if node has children
-            if let Some(node) = self.get_node_at_path(nodes, &path) {
-                if !node.children.is_empty() {
+            if let Some(node) = get_node_at_path(nodes, &path) {
+                if state.is_expanded(&path) {
+                    self.move_from_expanded(nodes, state, path, node);
+                } else if node.needs_children() {
+                    state.request_children(path.clone());
+                    state.expand(path);
+                } else if !node.children.is_empty() {
                     state.expand(path);
                 }
             }
         }
     }
+
+    fn move_from_expanded<T>(
+        &self,
+        nodes: &[TreeNode<T>],
+        state: &mut TreeViewState,
+        mut path: Vec<usize>,
+        node: &TreeNode<T>,
+    ) {
+        match self.config.expand_on_right_when_expanded {
+            ExpandBehavior::Stay => {}
+            ExpandBehavior::FirstChild => {
+                if !node.children.is_empty() {
+                    path.push(0);
+                    state.select(path);
+                }
+            }
+            ExpandBehavior::NextSibling => {
+                if let Some(last) = path.last_mut() {
+                    *last += 1;
+                }
+                if get_node_at_path(nodes, &path).is_some() {
+                    state.select(path);
+                }
+            }
+        }
+    }
 }
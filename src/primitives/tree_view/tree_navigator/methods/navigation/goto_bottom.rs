@@ -25,9 +25,12 @@ impl TreeNavigator {
     /// assert_eq!(state.selected_path, Some(vec![1]));
     /// ```
     pub fn goto_bottom<T>(&self, nodes: &[TreeNode<T>], state: &mut TreeViewState) {
+        let previous = state.selected_path.clone();
         let visible_paths = get_visible_paths(nodes, state);
         if !visible_paths.is_empty() {
             state.select(visible_paths[visible_paths.len() - 1].clone());
         }
+
+        self.auto_expand_selected(nodes, state, previous.as_deref());
     }
 }
@@ -26,9 +26,12 @@ impl TreeNavigator {
     ) where
         F: Fn(&T, &Option<String>) -> bool,
     {
+        let previous = state.selected_path.clone();
         let visible_paths = get_visible_paths_filtered(nodes, state, matcher);
         if !visible_paths.is_empty() {
             state.select(visible_paths[0].clone());
         }
+
+        self.auto_expand_selected(nodes, state, previous.as_deref());
     }
 }
@@ -6,8 +6,10 @@ pub mod goto_bottom;
 pub mod goto_bottom_filtered;
 pub mod goto_top;
 pub mod goto_top_filtered;
+pub mod reveal;
 pub mod select_next;
 pub mod select_next_filtered;
 pub mod select_previous;
 pub mod select_previous_filtered;
+pub mod toggle_checked;
 pub mod toggle_selected;
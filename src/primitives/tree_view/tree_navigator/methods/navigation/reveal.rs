@@ -0,0 +1,288 @@
+//! TreeNavigator::reveal methods.
+
+use crate::primitives::tree_view::helpers::{get_visible_paths, get_visible_paths_filtered};
+use crate::primitives::tree_view::tree_navigator::TreeNavigator;
+use crate::primitives::tree_view::tree_node::TreeNode;
+use crate::primitives::tree_view::tree_view_state::TreeViewState;
+
+impl TreeNavigator {
+    /// Scrolls the viewport so the selected node is visible.
+    ///
+    /// Uses the configured `reveal_mode` and keeps `scroll_margin` rows
+    /// of context around the selection where the tree allows it. Call
+    /// this after handling a key with the height the tree is rendered at.
+    ///
+    /// # Arguments
+    ///
+    /// * `nodes` - The tree nodes.
+    /// * `state` - The tree view state to update.
+    /// * `viewport_height` - The number of rows available for the tree.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the selected node is visible in the tree, `false` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_toolkit::tree_view::{TreeNavigator, TreeNavigatorConfig, TreeNode, TreeViewState};
+    ///
+    /// let navigator = TreeNavigator::new()
+    ///     .with_config(TreeNavigatorConfig::new().with_scroll_margin(2));
+    /// let nodes: Vec<_> = (0..20).map(TreeNode::new).collect();
+    /// let mut state = TreeViewState::new();
+    /// state.select(vec![8]);
+    /// navigator.reveal(&nodes, &mut state, 5);
+    /// assert_eq!(state.scroll_offset(), 6);
+    /// ```
+    pub fn reveal<T>(
+        &self,
+        nodes: &[TreeNode<T>],
+        state: &mut TreeViewState,
+        viewport_height: usize,
+    ) -> bool {
+        let visible_paths = get_visible_paths(nodes, state);
+        self.reveal_in(&visible_paths, state, viewport_height)
+    }
+
+    /// Scrolls the viewport so the selected node is visible with filtering support.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The node data type.
+    /// * `F` - The filter matcher function type.
+    ///
+    /// # Arguments
+    ///
+    /// * `nodes` - The tree nodes.
+    /// * `state` - The tree view state to update.
+    /// * `viewport_height` - The number of rows available for the tree.
+    /// * `matcher` - A function that takes node data and filter, returns true if matches.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the selected node is visible in the filtered tree, `false` otherwise.
+    pub fn reveal_filtered<T, F>(
+        &self,
+        nodes: &[TreeNode<T>],
+        state: &mut TreeViewState,
+        viewport_height: usize,
+        matcher: F,
+    ) -> bool
+    where
+        F: Fn(&T, &Option<String>) -> bool,
+    {
+        let visible_paths = get_visible_paths_filtered(nodes, state, matcher);
+        self.reveal_in(&visible_paths, state, viewport_height)
+    }
+
+    fn reveal_in(
+        &self,
+        visible_paths: &[Vec<usize>],
+        state: &mut TreeViewState,
+        viewport_height: usize,
+    ) -> bool {
+        let Some(selected) = &state.selected_path else {
+            return false;
+        };
+        let Some(index) = visible_paths.iter().position(|p| p == selected) else {
+            return false;
+        };
+
+        state.offset =
+            self.reveal_offset(state.offset, index, visible_paths.len(), viewport_height);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::primitives::tree_view::{
+        ExpandBehavior, RevealMode, TreeNavigator, TreeNavigatorConfig, TreeNode, TreeViewState,
+    };
+
+    fn flat_tree() -> Vec<TreeNode<String>> {
+        (0..20)
+            .map(|i| TreeNode::new(format!("item-{i}")))
+            .collect()
+    }
+
+    fn navigator(config: TreeNavigatorConfig) -> TreeNavigator {
+        TreeNavigator::new().with_config(config)
+    }
+
+    #[test]
+    fn reveal_keeps_margin_when_moving_down() {
+        let nav = navigator(TreeNavigatorConfig::new().with_scroll_margin(2));
+        let nodes = flat_tree();
+        let mut state = TreeViewState::new();
+
+        // Rows 0..=7 visible with a viewport of 8; row 5 is the last row
+        // that still leaves two rows of context below.
+        state.select(vec![5]);
+        assert!(nav.reveal(&nodes, &mut state, 8));
+        assert_eq!(state.scroll_offset(), 0);
+
+        state.select(vec![6]);
+        assert!(nav.reveal(&nodes, &mut state, 8));
+        assert_eq!(state.scroll_offset(), 1);
+    }
+
+    #[test]
+    fn reveal_keeps_margin_when_moving_up() {
+        let nav = navigator(TreeNavigatorConfig::new().with_scroll_margin(2));
+        let nodes = flat_tree();
+        let mut state = TreeViewState::new();
+        state.offset = 10;
+
+        state.select(vec![11]);
+        assert!(nav.reveal(&nodes, &mut state, 8));
+        assert_eq!(state.scroll_offset(), 9);
+    }
+
+    #[test]
+    fn reveal_margin_is_clamped_at_top_boundary() {
+        let nav = navigator(TreeNavigatorConfig::new().with_scroll_margin(3));
+        let nodes = flat_tree();
+        let mut state = TreeViewState::new();
+        state.offset = 4;
+
+        state.select(vec![0]);
+        assert!(nav.reveal(&nodes, &mut state, 8));
+        assert_eq!(state.scroll_offset(), 0);
+
+        state.select(vec![1]);
+        assert!(nav.reveal(&nodes, &mut state, 8));
+        assert_eq!(state.scroll_offset(), 0);
+    }
+
+    #[test]
+    fn reveal_margin_is_clamped_at_bottom_boundary() {
+        let nav = navigator(TreeNavigatorConfig::new().with_scroll_margin(3));
+        let nodes = flat_tree();
+        let mut state = TreeViewState::new();
+
+        state.select(vec![19]);
+        assert!(nav.reveal(&nodes, &mut state, 8));
+        assert_eq!(state.scroll_offset(), 12);
+
+        state.select(vec![18]);
+        assert!(nav.reveal(&nodes, &mut state, 8));
+        assert_eq!(state.scroll_offset(), 12);
+    }
+
+    #[test]
+    fn reveal_margin_never_exceeds_half_the_viewport() {
+        let nav = navigator(TreeNavigatorConfig::new().with_scroll_margin(10));
+        let nodes = flat_tree();
+        let mut state = TreeViewState::new();
+
+        state.select(vec![10]);
+        assert!(nav.reveal(&nodes, &mut state, 5));
+        assert_eq!(state.scroll_offset(), 8);
+    }
+
+    #[test]
+    fn reveal_top_and_center_modes() {
+        let nodes = flat_tree();
+        let mut state = TreeViewState::new();
+        state.select(vec![10]);
+
+        let top = navigator(
+            TreeNavigatorConfig::new()
+                .with_reveal_mode(RevealMode::Top)
+                .with_scroll_margin(1),
+        );
+        assert!(top.reveal(&nodes, &mut state, 6));
+        assert_eq!(state.scroll_offset(), 9);
+
+        let center = navigator(TreeNavigatorConfig::new().with_reveal_mode(RevealMode::Center));
+        assert!(center.reveal(&nodes, &mut state, 6));
+        assert_eq!(state.scroll_offset(), 7);
+
+        state.select(vec![19]);
+        assert!(center.reveal(&nodes, &mut state, 6));
+        assert_eq!(state.scroll_offset(), 14);
+    }
+
+    #[test]
+    fn reveal_without_visible_selection_returns_false() {
+        let nav = TreeNavigator::new();
+        let nodes = vec![TreeNode::with_children(
+            "parent".to_string(),
+            vec![TreeNode::new("child".to_string())],
+        )];
+        let mut state = TreeViewState::new();
+
+        assert!(!nav.reveal(&nodes, &mut state, 4));
+        state.select(vec![0, 0]);
+        assert!(!nav.reveal(&nodes, &mut state, 4));
+    }
+
+    #[test]
+    fn auto_expand_on_select_expands_collapsed_nodes() {
+        let nav = navigator(TreeNavigatorConfig::new().with_auto_expand_on_select(true));
+        let nodes = vec![
+            TreeNode::new("file".to_string()),
+            TreeNode::with_children("dir".to_string(), vec![TreeNode::new("child".to_string())]),
+        ];
+        let mut state = TreeViewState::new();
+        state.select(vec![0]);
+
+        nav.select_next(&nodes, &mut state);
+        assert_eq!(state.selected_path, Some(vec![1]));
+        assert!(state.is_expanded(&[1]));
+
+        // A no-op move at the bottom does not re-expand a collapsed node.
+        nav.select_next(&nodes, &mut state);
+        nav.select_next(&nodes, &mut state);
+        assert_eq!(state.selected_path, Some(vec![1, 0]));
+        nav.select_previous(&nodes, &mut state);
+        state.collapse(vec![1]);
+        nav.select_next(&nodes, &mut state);
+        assert!(!state.is_expanded(&[1]));
+    }
+
+    #[test]
+    fn auto_expand_on_select_is_off_by_default() {
+        let nav = TreeNavigator::new();
+        let nodes = vec![
+            TreeNode::new("file".to_string()),
+            TreeNode::with_children("dir".to_string(), vec![TreeNode::new("child".to_string())]),
+        ];
+        let mut state = TreeViewState::new();
+        state.select(vec![0]);
+
+        nav.select_next(&nodes, &mut state);
+        assert!(!state.is_expanded(&[1]));
+    }
+
+    #[test]
+    fn expand_on_expanded_node_follows_behavior() {
+        let nodes = vec![
+            TreeNode::with_children("a".to_string(), vec![TreeNode::new("a0".to_string())]),
+            TreeNode::new("b".to_string()),
+        ];
+
+        let mut state = TreeViewState::new();
+        state.select(vec![0]);
+        state.expand(vec![0]);
+        TreeNavigator::new().expand_selected(&nodes, &mut state);
+        assert_eq!(state.selected_path, Some(vec![0]));
+
+        let first_child = navigator(
+            TreeNavigatorConfig::new()
+                .with_expand_on_right_when_expanded(ExpandBehavior::FirstChild),
+        );
+        first_child.expand_selected(&nodes, &mut state);
+        assert_eq!(state.selected_path, Some(vec![0, 0]));
+
+        let next_sibling = navigator(
+            TreeNavigatorConfig::new()
+                .with_expand_on_right_when_expanded(ExpandBehavior::NextSibling),
+        );
+        state.select(vec![0]);
+        next_sibling.expand_selected(&nodes, &mut state);
+        assert_eq!(state.selected_path, Some(vec![1]));
+    }
+}
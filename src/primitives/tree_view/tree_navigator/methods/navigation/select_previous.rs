@@ -26,6 +26,7 @@ impl TreeNavigator {
     /// assert_eq!(state.selected_path, Some(vec![0]));
     /// ```
     pub fn select_previous<T>(&self, nodes: &[TreeNode<T>], state: &mut TreeViewState) {
+        let previous = state.selected_path.clone();
         let visible_paths = get_visible_paths(nodes, state);
         if visible_paths.is_empty() {
             return;
@@ -41,5 +42,7 @@ impl TreeNavigator {
             // Select first item
             state.select(visible_paths[0].clone());
         }
+
+        self.auto_expand_selected(nodes, state, previous.as_deref());
     }
 }
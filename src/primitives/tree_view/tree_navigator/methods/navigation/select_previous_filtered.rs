@@ -26,6 +26,7 @@ impl TreeNavigator {
     ) where
         F: Fn(&T, &Option<String>) -> bool,
     {
+        let previous = state.selected_path.clone();
         let visible_paths = get_visible_paths_filtered(nodes, state, matcher);
         if visible_paths.is_empty() {
             return;
@@ -40,5 +41,7 @@ impl TreeNavigator {
         } else {
             state.select(visible_paths[0].clone());
         }
+
+        self.auto_expand_selected(nodes, state, previous.as_deref());
     }
 }
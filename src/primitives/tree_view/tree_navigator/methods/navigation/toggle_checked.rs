@@ -0,0 +1,156 @@
+//! TreeNavigator::toggle_checked methods.
+
+use crate::primitives::tree_view::helpers::{
+    get_node_at_path, get_visible_paths, get_visible_paths_filtered,
+};
+use crate::primitives::tree_view::tree_navigator::TreeNavigator;
+use crate::primitives::tree_view::tree_node::TreeNode;
+use crate::primitives::tree_view::tree_view_state::TreeViewState;
+
+impl TreeNavigator {
+    /// Toggles the checked state of the selected node.
+    ///
+    /// With `cascade_check` enabled, the new state is also applied to all
+    /// visible descendants of the node.
+    ///
+    /// # Arguments
+    ///
+    /// * `nodes` - The tree nodes.
+    /// * `state` - The tree view state to update.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_toolkit::tree_view::{TreeNavigator, TreeNavigatorConfig, TreeNode, TreeViewState};
+    ///
+    /// let navigator = TreeNavigator::new()
+    ///     .with_config(TreeNavigatorConfig::new().with_cascade_check(true));
+    /// let nodes = vec![TreeNode::with_children("src", vec![TreeNode::new("lib.rs")])];
+    /// let mut state = TreeViewState::new();
+    /// state.expand(vec![0]);
+    /// state.select(vec![0]);
+    /// navigator.toggle_checked(&nodes, &mut state);
+    /// assert_eq!(state.checked_paths(), vec![vec![0], vec![0, 0]]);
+    /// ```
+    pub fn toggle_checked<T>(&self, nodes: &[TreeNode<T>], state: &mut TreeViewState) {
+        let visible_paths = get_visible_paths(nodes, state);
+        self.toggle_checked_in(nodes, &visible_paths, state);
+    }
+
+    /// Toggles the checked state of the selected node with filtering support.
+    ///
+    /// Cascading only reaches descendants that pass the filter.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The node data type.
+    /// * `F` - The filter matcher function type.
+    ///
+    /// # Arguments
+    ///
+    /// * `nodes` - The tree nodes.
+    /// * `state` - The tree view state to update.
+    /// * `matcher` - A function that takes node data and filter, returns true if matches.
+    pub fn toggle_checked_filtered<T, F>(
+        &self,
+        nodes: &[TreeNode<T>],
+        state: &mut TreeViewState,
+        matcher: F,
+    ) where
+        F: Fn(&T, &Option<String>) -> bool,
+    {
+        let visible_paths = get_visible_paths_filtered(nodes, state, matcher);
+        self.toggle_checked_in(nodes, &visible_paths, state);
+    }
+
+    fn toggle_checked_in<T>(
+        &self,
+        nodes: &[TreeNode<T>],
+        visible_paths: &[Vec<usize>],
+        state: &mut TreeViewState,
+    ) {
+        let Some(path) = state.selected_path.clone() else {
+            return;
+        };
+        // Loading placeholders are not real nodes
+        if get_node_at_path(nodes, &path).is_none() {
+            return;
+        }
+        let Some(checked) = state.toggle_checked() else {
+            return;
+        };
+
+        if self.config.cascade_check {
+            for descendant in visible_paths
+                .iter()
+                .filter(|p| p.len() > path.len() && p.starts_with(&path))
+            {
+                if get_node_at_path(nodes, descendant).is_some() {
+                    state.set_checked(descendant.clone(), checked);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::primitives::tree_view::{
+        TreeNavigator, TreeNavigatorConfig, TreeNode, TreeViewState,
+    };
+
+    fn tree() -> Vec<TreeNode<&'static str>> {
+        vec![
+            TreeNode::with_children(
+                "src",
+                vec![
+                    TreeNode::new("lib.rs"),
+                    TreeNode::with_children("bin", vec![TreeNode::new("main.rs")]),
+                ],
+            ),
+            TreeNode::new("README.md"),
+        ]
+    }
+
+    #[test]
+    fn toggle_checked_without_cascade_only_marks_selected() {
+        let nav = TreeNavigator::new();
+        let nodes = tree();
+        let mut state = TreeViewState::new();
+        state.expand(vec![0]);
+        state.select(vec![0]);
+
+        nav.toggle_checked(&nodes, &mut state);
+        assert_eq!(state.checked_paths(), vec![vec![0]]);
+    }
+
+    #[test]
+    fn cascade_check_applies_to_visible_descendants_only() {
+        let nav =
+            TreeNavigator::new().with_config(TreeNavigatorConfig::new().with_cascade_check(true));
+        let nodes = tree();
+        let mut state = TreeViewState::new();
+        state.expand(vec![0]);
+        state.select(vec![0]);
+
+        nav.toggle_checked(&nodes, &mut state);
+        // "bin" is collapsed, so "main.rs" is not visible and not checked.
+        assert_eq!(state.checked_paths(), vec![vec![0], vec![0, 0], vec![0, 1]]);
+
+        nav.toggle_checked(&nodes, &mut state);
+        assert!(state.checked_paths().is_empty());
+    }
+
+    #[test]
+    fn toggle_check_key_uses_keybinding() {
+        use crossterm::event::{KeyCode, KeyEvent};
+
+        let nav = TreeNavigator::new();
+        let nodes = tree();
+        let mut state = TreeViewState::new();
+        state.select(vec![1]);
+
+        assert!(nav.handle_key(KeyEvent::from(KeyCode::Char(' ')), &nodes, &mut state));
+        assert!(state.is_checked(&[1]));
+    }
+}
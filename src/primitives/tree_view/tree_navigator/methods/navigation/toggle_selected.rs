@@ -1,5 +1,6 @@
 //! TreeNavigator::toggle_selected method.
 
+use crate::primitives::tree_view::helpers::get_node_at_path;
 use crate::primitives::tree_view::tree_navigator::TreeNavigator;
 use crate::primitives::tree_view::tree_node::TreeNode;
 use crate::primitives::tree_view::tree_view_state::TreeViewState;
@@ -8,7 +9,9 @@ impl TreeNavigator {
     /// Toggles expansion of the selected node.
     ///
     /// Expands if collapsed, collapses if expanded.
-    /// Only works on nodes with children.
+    /// Only works on nodes with children. Expanding a node whose children
+    /// are not loaded yet also queues a load request, see
+    /// [`TreeViewState::take_pending_expansions`].
     ///
     /// # Arguments
     ///
@@ -33,8 +36,13 @@ impl TreeNavigator {
     pub fn toggle_selected<T>(&self, nodes: &[TreeNode<T>], state: &mut TreeViewState) {
         if let Some(path) = state.selected_path.clone() {
             // Check if node has children
-            if let Some(node) = self.get_node_at_path(nodes, &path) {
-                if !node.children.is_empty() {
+            if let Some(node) = get_node_at_path(nodes, &path) {
+                if node.needs_children() {
+                    if !state.is_expanded(&path) {
+                        state.request_children(path.clone());
+                    }
+                    state.toggle_expansion(path);
+                } else if !node.children.is_empty() {
                     state.toggle_expansion(path);
                 }
             }
@@ -0,0 +1,55 @@
+//! TreeNavigator::handle_type_ahead_key method.
+
+use std::time::Instant;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+use crate::primitives::tree_view::tree_navigator::TreeNavigator;
+use crate::primitives::tree_view::tree_node::TreeNode;
+use crate::primitives::tree_view::tree_view_state::TreeViewState;
+
+impl TreeNavigator {
+    /// Handles a key event as type-ahead input.
+    ///
+    /// Printable characters without Ctrl or Alt are fed to
+    /// [`TreeNavigator::type_ahead`]. Call this before
+    /// [`TreeNavigator::handle_key`]; with type-ahead enabled, bind
+    /// navigation to non-character keys (arrows, Home, End) so typed
+    /// letters are not shadowed.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key event.
+    /// * `nodes` - The tree nodes.
+    /// * `state` - The tree view state to update.
+    /// * `now` - The time the key was pressed.
+    /// * `label` - Returns the text to match for a node's data.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the key was consumed as type-ahead input.
+    pub fn handle_type_ahead_key<T, L>(
+        &self,
+        key: KeyEvent,
+        nodes: &[TreeNode<T>],
+        state: &mut TreeViewState,
+        now: Instant,
+        label: L,
+    ) -> bool
+    where
+        L: Fn(&T) -> String,
+    {
+        if key.kind != KeyEventKind::Press
+            || key
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        {
+            return false;
+        }
+
+        match key.code {
+            KeyCode::Char(c) if !c.is_control() => self.type_ahead(c, nodes, state, now, label),
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,4 @@
+//! Search methods for TreeNavigator.
+
+pub mod handle_type_ahead_key;
+pub mod type_ahead;
@@ -0,0 +1,206 @@
+//! TreeNavigator::type_ahead method.
+
+use std::time::Instant;
+
+use crate::primitives::tree_view::tree_navigator::TreeNavigator;
+use crate::primitives::tree_view::tree_node::TreeNode;
+use crate::primitives::tree_view::tree_view_state::TreeViewState;
+
+impl TreeNavigator {
+    /// Jumps to the next visible node whose label starts with the typed prefix.
+    ///
+    /// Characters typed within `type_ahead_timeout` of each other build up a
+    /// prefix, matched as described in [`TreeViewState::type_ahead_with`].
+    ///
+    /// Does nothing unless `type_ahead` is enabled in the keybindings, and
+    /// never triggers while filter mode is active.
+    ///
+    /// # Arguments
+    ///
+    /// * `c` - The typed character.
+    /// * `nodes` - The tree nodes.
+    /// * `state` - The tree view state to update.
+    /// * `now` - The time the character was typed.
+    /// * `label` - Returns the text to match for a node's data.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the character was consumed, even when nothing matched.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Instant;
+    /// use ratatui_toolkit::tree_view::{TreeKeyBindings, TreeNavigator, TreeNode, TreeViewState};
+    ///
+    /// let navigator =
+    ///     TreeNavigator::with_keybindings(TreeKeyBindings::new().with_type_ahead(true));
+    /// let nodes = vec![TreeNode::new("apple"), TreeNode::new("cargo")];
+    /// let mut state = TreeViewState::new();
+    /// navigator.type_ahead('c', &nodes, &mut state, Instant::now(), |s| s.to_string());
+    /// assert_eq!(state.selected_path, Some(vec![1]));
+    /// ```
+    pub fn type_ahead<T, L>(
+        &self,
+        c: char,
+        nodes: &[TreeNode<T>],
+        state: &mut TreeViewState,
+        now: Instant,
+        label: L,
+    ) -> bool
+    where
+        L: Fn(&T) -> String,
+    {
+        if !self.keybindings.type_ahead {
+            return false;
+        }
+
+        state.type_ahead_with(c, nodes, now, self.keybindings.type_ahead_timeout, label)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    use crate::primitives::tree_view::{
+        TreeKeyBindings, TreeNavigator, TreeNode, TreeViewState, DEFAULT_TYPE_AHEAD_TIMEOUT,
+    };
+
+    fn navigator() -> TreeNavigator {
+        TreeNavigator::with_keybindings(TreeKeyBindings::new().with_type_ahead(true))
+    }
+
+    fn nodes() -> Vec<TreeNode<&'static str>> {
+        vec![
+            TreeNode::new("Cat"),
+            TreeNode::new("apple"),
+            TreeNode::new("car"),
+            TreeNode::new("cargo"),
+            TreeNode::new("dog"),
+        ]
+    }
+
+    fn label(data: &&str) -> String {
+        data.to_string()
+    }
+
+    #[test]
+    fn type_ahead_accumulates_buffer() {
+        let nav = navigator();
+        let nodes = nodes();
+        let mut state = TreeViewState::new();
+        let t0 = Instant::now();
+
+        assert!(nav.type_ahead('c', &nodes, &mut state, t0, label));
+        assert_eq!(state.selected_path, Some(vec![0]));
+
+        let t1 = t0 + Duration::from_millis(100);
+        assert!(nav.type_ahead('a', &nodes, &mut state, t1, label));
+        assert_eq!(state.selected_path, Some(vec![0]));
+
+        let t2 = t1 + Duration::from_millis(100);
+        assert!(nav.type_ahead('R', &nodes, &mut state, t2, label));
+        assert_eq!(state.type_ahead_buffer, "caR");
+        assert_eq!(state.selected_path, Some(vec![2]));
+
+        let t3 = t2 + Duration::from_millis(100);
+        assert!(nav.type_ahead('g', &nodes, &mut state, t3, label));
+        assert_eq!(state.selected_path, Some(vec![3]));
+    }
+
+    #[test]
+    fn type_ahead_resets_after_timeout() {
+        let nav = navigator();
+        let nodes = nodes();
+        let mut state = TreeViewState::new();
+        let t0 = Instant::now();
+
+        nav.type_ahead('c', &nodes, &mut state, t0, label);
+        let later = t0 + DEFAULT_TYPE_AHEAD_TIMEOUT + Duration::from_millis(1);
+        nav.type_ahead('d', &nodes, &mut state, later, label);
+
+        assert_eq!(state.type_ahead_buffer, "d");
+        assert_eq!(state.selected_path, Some(vec![4]));
+    }
+
+    #[test]
+    fn type_ahead_timeout_is_configurable() {
+        let nav = TreeNavigator::with_keybindings(
+            TreeKeyBindings::new()
+                .with_type_ahead(true)
+                .with_type_ahead_timeout(Duration::from_secs(3)),
+        );
+        let nodes = nodes();
+        let mut state = TreeViewState::new();
+        let t0 = Instant::now();
+
+        nav.type_ahead('c', &nodes, &mut state, t0, label);
+        nav.type_ahead('a', &nodes, &mut state, t0 + Duration::from_secs(2), label);
+        assert_eq!(state.type_ahead_buffer, "ca");
+    }
+
+    #[test]
+    fn type_ahead_wraps_and_skips_current() {
+        let nav = navigator();
+        let nodes = nodes();
+        let mut state = TreeViewState::new();
+        state.select(vec![3]);
+        let mut now = Instant::now();
+
+        nav.type_ahead('c', &nodes, &mut state, now, label);
+        assert_eq!(state.selected_path, Some(vec![0]));
+
+        now += Duration::from_secs(1);
+        nav.type_ahead('c', &nodes, &mut state, now, label);
+        assert_eq!(state.selected_path, Some(vec![2]));
+
+        now += Duration::from_secs(1);
+        nav.type_ahead('a', &nodes, &mut state, now, label);
+        assert_eq!(state.selected_path, Some(vec![1]));
+    }
+
+    #[test]
+    fn type_ahead_without_match_keeps_selection() {
+        let nav = navigator();
+        let nodes = nodes();
+        let mut state = TreeViewState::new();
+        state.select(vec![1]);
+
+        assert!(nav.type_ahead('z', &nodes, &mut state, Instant::now(), label));
+        assert_eq!(state.selected_path, Some(vec![1]));
+    }
+
+    #[test]
+    fn type_ahead_is_disabled_by_flag_and_filter_mode() {
+        let nodes = nodes();
+        let mut state = TreeViewState::new();
+
+        let disabled = TreeNavigator::new();
+        assert!(!disabled.type_ahead('d', &nodes, &mut state, Instant::now(), label));
+        assert_eq!(state.selected_path, None);
+
+        state.enter_filter_mode();
+        assert!(!navigator().type_ahead('d', &nodes, &mut state, Instant::now(), label));
+        assert_eq!(state.selected_path, None);
+    }
+
+    #[test]
+    fn handle_type_ahead_key_ignores_modified_keys() {
+        let nav = navigator();
+        let nodes = nodes();
+        let mut state = TreeViewState::new();
+        let now = Instant::now();
+
+        let ctrl_d = KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL);
+        assert!(!nav.handle_type_ahead_key(ctrl_d, &nodes, &mut state, now, label));
+        let down = KeyEvent::from(KeyCode::Down);
+        assert!(!nav.handle_type_ahead_key(down, &nodes, &mut state, now, label));
+
+        let d = KeyEvent::from(KeyCode::Char('d'));
+        assert!(nav.handle_type_ahead_key(d, &nodes, &mut state, now, label));
+        assert_eq!(state.selected_path, Some(vec![4]));
+    }
+}
@@ -5,6 +5,7 @@ pub mod methods;
 pub mod traits;
 
 use crate::primitives::tree_view::keybindings::TreeKeyBindings;
+use crate::primitives::tree_view::navigator_config::TreeNavigatorConfig;
 
 /// Tree navigator with configurable keybindings.
 ///
@@ -24,4 +25,6 @@ use crate::primitives::tree_view::keybindings::TreeKeyBindings;
 pub struct TreeNavigator {
     /// The keybindings for navigation.
     pub keybindings: TreeKeyBindings,
+    /// The navigation behavior options.
+    pub config: TreeNavigatorConfig,
 }
@@ -0,0 +1,41 @@
+//! TreeNode::has_children_hint constructor.
+
+use crate::primitives::tree_view::tree_node::TreeNode;
+
+impl<T> TreeNode<T> {
+    /// Marks the node as having children that are not loaded yet.
+    ///
+    /// The node renders as expandable without populating `children`.
+    /// Expanding it through [`TreeNavigator`](crate::primitives::tree_view::TreeNavigator)
+    /// queues a load request that the application drains with
+    /// [`TreeViewState::take_pending_expansions`](crate::primitives::tree_view::TreeViewState::take_pending_expansions)
+    /// and fulfils with [`TreeNode::set_children`].
+    ///
+    /// # Arguments
+    ///
+    /// * `has_children` - Whether the node has unloaded children.
+    ///
+    /// # Returns
+    ///
+    /// The node with the hint applied.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_toolkit::tree_view::TreeNode;
+    ///
+    /// let node = TreeNode::new("node_modules").has_children_hint(true);
+    /// assert!(node.expandable);
+    /// assert!(node.needs_children());
+    /// ```
+    pub fn has_children_hint(mut self, has_children: bool) -> Self {
+        if has_children {
+            self.expandable = true;
+            self.children_loaded = !self.children.is_empty();
+        } else {
+            self.expandable = !self.children.is_empty();
+            self.children_loaded = true;
+        }
+        self
+    }
+}
@@ -1,4 +1,5 @@
 //! Constructor functions for TreeNode.
 
+pub mod has_children_hint;
 pub mod new;
 pub mod with_children;
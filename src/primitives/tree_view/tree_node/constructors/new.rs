@@ -29,6 +29,7 @@ impl<T> TreeNode<T> {
             data,
             children: Vec::new(),
             expandable: false,
+            children_loaded: true,
         }
     }
 }
@@ -32,6 +32,7 @@ impl<T> TreeNode<T> {
             data,
             children,
             expandable,
+            children_loaded: true,
         }
     }
 }
@@ -0,0 +1,4 @@
+//! Methods for TreeNode.
+
+pub mod needs_children;
+pub mod set_children;
@@ -0,0 +1,26 @@
+//! TreeNode::needs_children method.
+
+use crate::primitives::tree_view::tree_node::TreeNode;
+
+impl<T> TreeNode<T> {
+    /// Checks whether the node's children still have to be loaded.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the node was marked with [`TreeNode::has_children_hint`]
+    /// and [`TreeNode::set_children`] has not been called yet.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_toolkit::tree_view::TreeNode;
+    ///
+    /// let mut node = TreeNode::new("remote").has_children_hint(true);
+    /// assert!(node.needs_children());
+    /// node.set_children(vec![TreeNode::new("child")]);
+    /// assert!(!node.needs_children());
+    /// ```
+    pub fn needs_children(&self) -> bool {
+        self.expandable && !self.children_loaded
+    }
+}
@@ -0,0 +1,32 @@
+//! TreeNode::set_children method.
+
+use crate::primitives::tree_view::tree_node::TreeNode;
+
+impl<T> TreeNode<T> {
+    /// Replaces the node's children and marks them as loaded.
+    ///
+    /// Used to fulfil a lazy load request. Expansion state and selection
+    /// are stored as index paths in
+    /// [`TreeViewState`](crate::primitives::tree_view::TreeViewState), so they
+    /// remain valid after the children are inserted.
+    ///
+    /// # Arguments
+    ///
+    /// * `children` - The loaded child nodes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_toolkit::tree_view::TreeNode;
+    ///
+    /// let mut node = TreeNode::new("dir").has_children_hint(true);
+    /// node.set_children(vec![TreeNode::new("file.txt")]);
+    /// assert_eq!(node.children.len(), 1);
+    /// assert!(node.expandable);
+    /// ```
+    pub fn set_children(&mut self, children: Vec<TreeNode<T>>) {
+        self.expandable = !children.is_empty();
+        self.children = children;
+        self.children_loaded = true;
+    }
+}
@@ -1,6 +1,7 @@
 //! Tree node type for hierarchical data representation.
 
 mod constructors;
+mod methods;
 
 /// A node in the tree.
 ///
@@ -28,4 +29,9 @@ pub struct TreeNode<T> {
     pub children: Vec<TreeNode<T>>,
     /// Whether this node can be expanded (has children)
     pub expandable: bool,
+    /// Whether `children` has been populated.
+    ///
+    /// `false` for lazily loaded nodes created with
+    /// [`TreeNode::has_children_hint`] until [`TreeNode::set_children`] is called.
+    pub children_loaded: bool,
 }
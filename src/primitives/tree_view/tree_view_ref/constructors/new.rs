@@ -34,9 +34,11 @@ impl<'a, 'b, T> TreeViewRef<'a, 'b, T> {
             render_fn: Box::new(|_data, _state| Line::from("Node")),
             expand_icon: "\u{25b6}",
             collapse_icon: "\u{25bc}",
+            loading_text: "Loading\u{2026}",
             highlight_style: None,
             icon_style: Style::default().fg(Color::DarkGray),
             show_filter_ui: false,
+            show_checkboxes: false,
         }
     }
 }
@@ -0,0 +1,33 @@
+//! TreeViewRef::loading_text method.
+
+use crate::primitives::tree_view::tree_view_ref::TreeViewRef;
+
+impl<'a, 'b, T> TreeViewRef<'a, 'b, T> {
+    /// Sets the placeholder text shown while a node's children are loading.
+    ///
+    /// The placeholder is rendered as a single child row under expanded
+    /// nodes marked with [`TreeNode::has_children_hint`](crate::primitives::tree_view::TreeNode::has_children_hint)
+    /// until their children are inserted.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The placeholder text.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_toolkit::tree_view::{TreeNode, TreeViewRef};
+    ///
+    /// let nodes = vec![TreeNode::new("Item").has_children_hint(true)];
+    /// let tree = TreeViewRef::new(&nodes)
+    ///     .loading_text("fetching...");
+    /// ```
+    pub fn loading_text(mut self, text: &'a str) -> Self {
+        self.loading_text = text;
+        self
+    }
+}
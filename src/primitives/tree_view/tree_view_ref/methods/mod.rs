@@ -6,5 +6,7 @@ pub mod flatten_tree;
 pub mod highlight_style;
 pub mod icon_style;
 pub mod icons;
+pub mod loading_text;
 pub mod render_fn;
+pub mod with_checkboxes;
 pub mod with_filter_ui;
@@ -49,10 +49,13 @@ pub struct TreeViewRef<'a, 'b, T> {
     pub(crate) expand_icon: &'a str,
     /// Default collapse icon
     pub(crate) collapse_icon: &'a str,
+    /// Placeholder text shown under expanded nodes whose children are loading
+    pub(crate) loading_text: &'a str,
     /// Style for selected row background (full-width highlight)
     pub(crate) highlight_style: Option<Style>,
     /// Style for expand/collapse icons
     pub(crate) icon_style: Style,
     /// Whether to show built-in filter UI
     pub(crate) show_filter_ui: bool,
+    pub(crate) show_checkboxes: bool,
 }
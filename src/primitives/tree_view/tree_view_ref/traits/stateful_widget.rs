@@ -32,11 +32,7 @@ impl<'a, 'b, T> StatefulWidget for TreeViewRef<'a, 'b, T> {
         // Adjust scroll offset to ensure selected item is visible
         if let Some(ref selected) = state.selected_path {
             if let Some(selected_idx) = items.iter().position(|(_, path)| path == selected) {
-                if selected_idx < state.offset {
-                    state.offset = selected_idx;
-                } else if selected_idx >= state.offset + visible_height {
-                    state.offset = selected_idx.saturating_sub(visible_height - 1);
-                }
+                state.scroll_index_into_view(selected_idx, visible_height);
             }
         }
 
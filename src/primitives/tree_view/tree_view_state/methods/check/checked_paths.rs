@@ -0,0 +1,27 @@
+//! TreeViewState::checked_paths method.
+
+use crate::primitives::tree_view::tree_view_state::TreeViewState;
+
+impl TreeViewState {
+    /// Gets the paths of all checked nodes.
+    ///
+    /// # Returns
+    ///
+    /// The checked paths in tree order (parents before their children).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_toolkit::tree_view::TreeViewState;
+    ///
+    /// let mut state = TreeViewState::new();
+    /// state.set_checked(vec![1], true);
+    /// state.set_checked(vec![0, 2], true);
+    /// assert_eq!(state.checked_paths(), vec![vec![0, 2], vec![1]]);
+    /// ```
+    pub fn checked_paths(&self) -> Vec<Vec<usize>> {
+        let mut paths: Vec<Vec<usize>> = self.checked.iter().cloned().collect();
+        paths.sort();
+        paths
+    }
+}
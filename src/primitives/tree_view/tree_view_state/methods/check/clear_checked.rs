@@ -0,0 +1,21 @@
+//! TreeViewState::clear_checked method.
+
+use crate::primitives::tree_view::tree_view_state::TreeViewState;
+
+impl TreeViewState {
+    /// Unchecks all nodes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_toolkit::tree_view::TreeViewState;
+    ///
+    /// let mut state = TreeViewState::new();
+    /// state.set_checked(vec![0], true);
+    /// state.clear_checked();
+    /// assert!(state.checked_paths().is_empty());
+    /// ```
+    pub fn clear_checked(&mut self) {
+        self.checked.clear();
+    }
+}
@@ -0,0 +1,28 @@
+//! TreeViewState::is_checked method.
+
+use crate::primitives::tree_view::tree_view_state::TreeViewState;
+
+impl TreeViewState {
+    /// Checks if a node at the given path is checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the node to check.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the node is checked, `false` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_toolkit::tree_view::TreeViewState;
+    ///
+    /// let mut state = TreeViewState::new();
+    /// state.set_checked(vec![0], true);
+    /// assert!(state.is_checked(&[0]));
+    /// ```
+    pub fn is_checked(&self, path: &[usize]) -> bool {
+        self.checked.contains(path)
+    }
+}
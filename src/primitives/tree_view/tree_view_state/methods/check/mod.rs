@@ -0,0 +1,7 @@
+//! Check (multi-select) methods for TreeViewState.
+
+pub mod checked_paths;
+pub mod clear_checked;
+pub mod is_checked;
+pub mod set_checked;
+pub mod toggle_checked;
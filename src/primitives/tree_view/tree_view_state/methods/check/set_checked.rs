@@ -0,0 +1,30 @@
+//! TreeViewState::set_checked method.
+
+use crate::primitives::tree_view::tree_view_state::TreeViewState;
+
+impl TreeViewState {
+    /// Checks or unchecks a node at the given path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the node.
+    /// * `checked` - Whether the node should be checked.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_toolkit::tree_view::TreeViewState;
+    ///
+    /// let mut state = TreeViewState::new();
+    /// state.set_checked(vec![0, 1], true);
+    /// state.set_checked(vec![0, 1], false);
+    /// assert!(!state.is_checked(&[0, 1]));
+    /// ```
+    pub fn set_checked(&mut self, path: Vec<usize>, checked: bool) {
+        if checked {
+            self.checked.insert(path);
+        } else {
+            self.checked.remove(&path);
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//! TreeViewState::toggle_checked method.
+
+use crate::primitives::tree_view::tree_view_state::TreeViewState;
+
+impl TreeViewState {
+    /// Toggles the checked state of the selected node.
+    ///
+    /// Does nothing when no node is selected. To cascade to children,
+    /// use [`TreeNavigator::toggle_checked`](crate::primitives::tree_view::TreeNavigator::toggle_checked).
+    ///
+    /// # Returns
+    ///
+    /// The new checked state, or `None` if no node is selected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_toolkit::tree_view::TreeViewState;
+    ///
+    /// let mut state = TreeViewState::new();
+    /// state.select(vec![0]);
+    /// assert_eq!(state.toggle_checked(), Some(true));
+    /// assert_eq!(state.checked_paths(), vec![vec![0]]);
+    /// ```
+    pub fn toggle_checked(&mut self) -> Option<bool> {
+        let path = self.selected_path.clone()?;
+        let checked = !self.is_checked(&path);
+        self.set_checked(path, checked);
+        Some(checked)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::primitives::tree_view::TreeViewState;
+
+    #[test]
+    fn toggle_checked_without_selection_does_nothing() {
+        let mut state = TreeViewState::new();
+        assert_eq!(state.toggle_checked(), None);
+        assert!(state.checked_paths().is_empty());
+    }
+
+    #[test]
+    fn toggle_checked_flips_selected_node() {
+        let mut state = TreeViewState::new();
+        state.select(vec![2]);
+        assert_eq!(state.toggle_checked(), Some(true));
+        state.select(vec![0, 1]);
+        assert_eq!(state.toggle_checked(), Some(true));
+        assert_eq!(state.checked_paths(), vec![vec![0, 1], vec![2]]);
+
+        assert_eq!(state.toggle_checked(), Some(false));
+        assert_eq!(state.checked_paths(), vec![vec![2]]);
+    }
+}
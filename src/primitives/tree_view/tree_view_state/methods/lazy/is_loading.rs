@@ -0,0 +1,30 @@
+//! TreeViewState::is_loading method.
+
+use crate::primitives::tree_view::tree_view_state::TreeViewState;
+
+impl TreeViewState {
+    /// Checks if a node has a queued load request that was not drained yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the node to check.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the path is in the pending expansion queue.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_toolkit::tree_view::TreeViewState;
+    ///
+    /// let mut state = TreeViewState::new();
+    /// state.request_children(vec![1]);
+    /// assert!(state.is_loading(&[1]));
+    /// ```
+    pub fn is_loading(&self, path: &[usize]) -> bool {
+        self.pending_expansions
+            .iter()
+            .any(|pending| pending == path)
+    }
+}
@@ -0,0 +1,5 @@
+//! Lazy loading methods for TreeViewState.
+
+pub mod is_loading;
+pub mod request_children;
+pub mod take_pending_expansions;
@@ -0,0 +1,29 @@
+//! TreeViewState::request_children method.
+
+use crate::primitives::tree_view::tree_view_state::TreeViewState;
+
+impl TreeViewState {
+    /// Queues a load request for the children of a lazily loaded node.
+    ///
+    /// Duplicate requests for the same path are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the node whose children should be loaded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_toolkit::tree_view::TreeViewState;
+    ///
+    /// let mut state = TreeViewState::new();
+    /// state.request_children(vec![0]);
+    /// state.request_children(vec![0]);
+    /// assert_eq!(state.take_pending_expansions(), vec![vec![0]]);
+    /// ```
+    pub fn request_children(&mut self, path: Vec<usize>) {
+        if !self.pending_expansions.contains(&path) {
+            self.pending_expansions.push(path);
+        }
+    }
+}
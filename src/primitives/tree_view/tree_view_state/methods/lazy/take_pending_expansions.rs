@@ -0,0 +1,126 @@
+//! TreeViewState::take_pending_expansions method.
+
+use crate::primitives::tree_view::tree_view_state::TreeViewState;
+
+impl TreeViewState {
+    /// Drains the queued lazy load requests.
+    ///
+    /// Each returned path points at a node marked with
+    /// [`TreeNode::has_children_hint`](crate::primitives::tree_view::TreeNode::has_children_hint)
+    /// that was expanded. Load its children (possibly asynchronously) and
+    /// insert them with
+    /// [`TreeNode::set_children`](crate::primitives::tree_view::TreeNode::set_children).
+    /// The node stays expanded and renders a loading placeholder until then.
+    ///
+    /// # Returns
+    ///
+    /// The pending paths in request order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_toolkit::tree_view::TreeViewState;
+    ///
+    /// let mut state = TreeViewState::new();
+    /// state.request_children(vec![0, 2]);
+    /// assert_eq!(state.take_pending_expansions(), vec![vec![0, 2]]);
+    /// assert!(state.take_pending_expansions().is_empty());
+    /// ```
+    pub fn take_pending_expansions(&mut self) -> Vec<Vec<usize>> {
+        std::mem::take(&mut self.pending_expansions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::primitives::tree_view::{get_visible_paths, TreeNavigator, TreeNode, TreeViewState};
+
+    fn lazy_tree() -> Vec<TreeNode<&'static str>> {
+        vec![
+            TreeNode::new("remote").has_children_hint(true),
+            TreeNode::new("local"),
+        ]
+    }
+
+    #[test]
+    fn expanding_unloaded_node_queues_request() {
+        let navigator = TreeNavigator::new();
+        let nodes = lazy_tree();
+        let mut state = TreeViewState::new();
+        state.select(vec![0]);
+
+        navigator.expand_selected(&nodes, &mut state);
+
+        assert!(state.is_expanded(&[0]));
+        assert!(state.is_loading(&[0]));
+        assert_eq!(state.take_pending_expansions(), vec![vec![0]]);
+        assert!(!state.is_loading(&[0]));
+    }
+
+    #[test]
+    fn unloaded_expanded_node_shows_placeholder_row() {
+        let nodes = lazy_tree();
+        let mut state = TreeViewState::new();
+        state.expand(vec![0]);
+
+        assert_eq!(
+            get_visible_paths(&nodes, &state),
+            vec![vec![0], vec![0, 0], vec![1]]
+        );
+    }
+
+    #[test]
+    fn toggling_unloaded_node_queues_request_once() {
+        let navigator = TreeNavigator::new();
+        let nodes = lazy_tree();
+        let mut state = TreeViewState::new();
+        state.select(vec![0]);
+
+        navigator.toggle_selected(&nodes, &mut state);
+        navigator.toggle_selected(&nodes, &mut state);
+        navigator.toggle_selected(&nodes, &mut state);
+
+        assert!(state.is_expanded(&[0]));
+        assert_eq!(state.take_pending_expansions(), vec![vec![0]]);
+    }
+
+    #[test]
+    fn inserted_children_keep_expansion_and_selection() {
+        let navigator = TreeNavigator::new();
+        let mut nodes = lazy_tree();
+        let mut state = TreeViewState::new();
+        state.select(vec![0]);
+        navigator.expand_selected(&nodes, &mut state);
+
+        for path in state.take_pending_expansions() {
+            assert_eq!(path, vec![0]);
+            nodes[path[0]].set_children(vec![TreeNode::new("a"), TreeNode::new("b")]);
+        }
+
+        assert!(state.is_expanded(&[0]));
+        assert_eq!(state.selected_path, Some(vec![0]));
+        assert_eq!(
+            get_visible_paths(&nodes, &state),
+            vec![vec![0], vec![0, 0], vec![0, 1], vec![1]]
+        );
+
+        navigator.select_next(&nodes, &mut state);
+        assert_eq!(state.selected_path, Some(vec![0, 0]));
+        navigator.select_next(&nodes, &mut state);
+        assert_eq!(state.selected_path, Some(vec![0, 1]));
+        navigator.select_next(&nodes, &mut state);
+        assert_eq!(state.selected_path, Some(vec![1]));
+    }
+
+    #[test]
+    fn loaded_empty_children_clear_placeholder() {
+        let mut nodes = lazy_tree();
+        let mut state = TreeViewState::new();
+        state.expand(vec![0]);
+
+        nodes[0].set_children(Vec::new());
+
+        assert!(!nodes[0].expandable);
+        assert_eq!(get_visible_paths(&nodes, &state), vec![vec![0], vec![1]]);
+    }
+}
@@ -1,5 +1,9 @@
 //! Methods for TreeViewState.
 
+pub mod check;
 pub mod expansion;
 pub mod filter;
+pub mod lazy;
+pub mod scroll;
+pub mod search;
 pub mod selection;
@@ -0,0 +1,44 @@
+//! TreeViewState::content_height and related methods.
+
+use crate::primitives::tree_view::tree_view_state::TreeViewState;
+
+impl TreeViewState {
+    /// Gets the total height of the visible rows in lines, as of the last render.
+    ///
+    /// Rows that have not been on screen yet count as their estimated
+    /// height. Feed this to a scrollbar as the content length instead of the
+    /// row count when rows can be taller than one line.
+    ///
+    /// # Returns
+    ///
+    /// The content height in lines, or 0 before the first render.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_toolkit::tree_view::TreeViewState;
+    ///
+    /// let state = TreeViewState::new();
+    /// assert_eq!(state.content_height(), 0);
+    /// ```
+    pub fn content_height(&self) -> usize {
+        self.row_heights.total()
+    }
+
+    /// Gets the number of lines above the viewport, as of the last render.
+    ///
+    /// This is the scrollbar position matching [`content_height`](Self::content_height).
+    /// Refining an estimated height above the viewport can shift it slightly,
+    /// but the rows on screen stay put because the offset is anchored to a row.
+    pub fn scroll_position(&self) -> usize {
+        self.row_heights.offset_of(self.offset)
+    }
+
+    /// Forgets every measured row height.
+    ///
+    /// Call this after changing node data in a way that affects the height
+    /// callback; heights are otherwise cached by node path.
+    pub fn invalidate_row_heights(&mut self) {
+        self.row_heights.invalidate();
+    }
+}
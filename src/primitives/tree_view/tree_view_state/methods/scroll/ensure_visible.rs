@@ -0,0 +1,157 @@
+//! TreeViewState::ensure_visible methods.
+
+use crate::primitives::tree_view::tree_node::TreeNode;
+use crate::primitives::tree_view::tree_view_state::TreeViewState;
+
+impl TreeViewState {
+    /// Scrolls so the node at `path` is inside a viewport of the given height.
+    ///
+    /// The offset only changes when the node is above or below the
+    /// viewport, and then by the minimum amount.
+    ///
+    /// # Arguments
+    ///
+    /// * `nodes` - The tree nodes.
+    /// * `path` - The path to the node to reveal.
+    /// * `viewport_height` - The number of rows available for the tree.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the node is visible in the tree, `false` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_toolkit::tree_view::{TreeNode, TreeViewState};
+    ///
+    /// let nodes: Vec<_> = (0..10).map(TreeNode::new).collect();
+    /// let mut state = TreeViewState::new();
+    /// state.ensure_visible(&nodes, &[7], 3);
+    /// assert_eq!(state.scroll_offset(), 5);
+    /// ```
+    pub fn ensure_visible<T>(
+        &mut self,
+        nodes: &[TreeNode<T>],
+        path: &[usize],
+        viewport_height: usize,
+    ) -> bool {
+        let Some(index) = self.visible_index_of(nodes, path) else {
+            return false;
+        };
+
+        self.scroll_index_into_view(index, viewport_height);
+        true
+    }
+
+    /// Scrolls so the node at `path` is inside the viewport with filtering support.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The node data type.
+    /// * `F` - The filter matcher function type.
+    ///
+    /// # Arguments
+    ///
+    /// * `nodes` - The tree nodes.
+    /// * `path` - The path to the node to reveal.
+    /// * `viewport_height` - The number of rows available for the tree.
+    /// * `matcher` - A function that takes node data and filter, returns true if matches.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the node is visible in the filtered tree, `false` otherwise.
+    pub fn ensure_visible_filtered<T, F>(
+        &mut self,
+        nodes: &[TreeNode<T>],
+        path: &[usize],
+        viewport_height: usize,
+        matcher: F,
+    ) -> bool
+    where
+        F: Fn(&T, &Option<String>) -> bool,
+    {
+        let Some(index) = self.visible_index_of_filtered(nodes, path, matcher) else {
+            return false;
+        };
+
+        self.scroll_index_into_view(index, viewport_height);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::primitives::tree_view::{matches_filter, TreeNode, TreeViewState};
+
+    fn flat_tree() -> Vec<TreeNode<String>> {
+        (0..10)
+            .map(|i| TreeNode::new(format!("item-{i}")))
+            .collect()
+    }
+
+    #[test]
+    fn ensure_visible_scrolls_up_when_above_viewport() {
+        let nodes = flat_tree();
+        let mut state = TreeViewState::new();
+        state.offset = 6;
+
+        assert!(state.ensure_visible(&nodes, &[2], 3));
+        assert_eq!(state.scroll_offset(), 2);
+    }
+
+    #[test]
+    fn ensure_visible_scrolls_down_when_below_viewport() {
+        let nodes = flat_tree();
+        let mut state = TreeViewState::new();
+
+        assert!(state.ensure_visible(&nodes, &[8], 3));
+        assert_eq!(state.scroll_offset(), 6);
+    }
+
+    #[test]
+    fn ensure_visible_keeps_offset_when_inside_viewport() {
+        let nodes = flat_tree();
+        let mut state = TreeViewState::new();
+        state.offset = 3;
+
+        assert!(state.ensure_visible(&nodes, &[3], 4));
+        assert!(state.ensure_visible(&nodes, &[6], 4));
+        assert_eq!(state.scroll_offset(), 3);
+    }
+
+    #[test]
+    fn ensure_visible_ignores_hidden_nodes() {
+        let nodes = vec![TreeNode::with_children(
+            "parent".to_string(),
+            vec![TreeNode::new("child".to_string())],
+        )];
+        let mut state = TreeViewState::new();
+
+        assert_eq!(state.visible_index_of(&nodes, &[0, 0]), None);
+        assert!(!state.ensure_visible(&nodes, &[0, 0], 1));
+        assert_eq!(state.scroll_offset(), 0);
+    }
+
+    #[test]
+    fn ensure_visible_filtered_uses_filtered_rows() {
+        let nodes: Vec<TreeNode<String>> = (0..10)
+            .map(|i| TreeNode::new(format!("{}{i}", if i % 2 == 0 { "a" } else { "b" })))
+            .collect();
+        let mut state = TreeViewState::new();
+        state.set_filter("b".to_string());
+        let matcher = |data: &String, filter: &Option<String>| matches_filter(data, filter);
+
+        // Only b1, b3, b5, b7 and b9 match, so b9 is the fifth filtered row.
+        assert_eq!(
+            state.visible_index_of_filtered(&nodes, &[9], matcher),
+            Some(4)
+        );
+        assert_eq!(state.visible_index_of(&nodes, &[9]), Some(9));
+
+        assert!(state.ensure_visible_filtered(&nodes, &[9], 2, matcher));
+        assert_eq!(state.scroll_offset(), 3);
+
+        assert!(!state.ensure_visible_filtered(&nodes, &[0], 2, matcher));
+        assert_eq!(state.scroll_offset(), 3);
+    }
+}
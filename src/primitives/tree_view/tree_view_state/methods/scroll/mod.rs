@@ -0,0 +1,6 @@
+//! Scroll methods for TreeViewState.
+
+pub mod content_height;
+pub mod ensure_visible;
+pub mod scroll_offset;
+pub mod visible_index_of;
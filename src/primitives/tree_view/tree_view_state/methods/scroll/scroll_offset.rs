@@ -0,0 +1,39 @@
+//! TreeViewState::scroll_offset method.
+
+use crate::primitives::tree_view::tree_view_state::TreeViewState;
+
+impl TreeViewState {
+    /// Gets the index of the first visible row.
+    ///
+    /// # Returns
+    ///
+    /// The vertical scroll offset in rows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_toolkit::tree_view::TreeViewState;
+    ///
+    /// let state = TreeViewState::new();
+    /// assert_eq!(state.scroll_offset(), 0);
+    /// ```
+    pub fn scroll_offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Adjusts the scroll offset so the row at `index` fits in the viewport.
+    ///
+    /// Scrolls the minimum amount required; does nothing if the row is
+    /// already visible or the viewport has no height.
+    pub(crate) fn scroll_index_into_view(&mut self, index: usize, viewport_height: usize) {
+        if viewport_height == 0 {
+            return;
+        }
+
+        if index < self.offset {
+            self.offset = index;
+        } else if index >= self.offset + viewport_height {
+            self.offset = index + 1 - viewport_height;
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! TreeViewState::visible_index_of methods.
+
+use crate::primitives::tree_view::helpers::{get_visible_paths, get_visible_paths_filtered};
+use crate::primitives::tree_view::tree_node::TreeNode;
+use crate::primitives::tree_view::tree_view_state::TreeViewState;
+
+impl TreeViewState {
+    /// Gets the flat row index of a node among the visible rows.
+    ///
+    /// Uses [`get_visible_paths`], so the index matches the row the
+    /// node is rendered on (before applying the scroll offset).
+    ///
+    /// # Arguments
+    ///
+    /// * `nodes` - The tree nodes.
+    /// * `path` - The path to the node.
+    ///
+    /// # Returns
+    ///
+    /// The row index, or `None` if the node is not visible.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_toolkit::tree_view::{TreeNode, TreeViewState};
+    ///
+    /// let nodes = vec![
+    ///     TreeNode::with_children("Parent", vec![TreeNode::new("Child")]),
+    ///     TreeNode::new("Sibling"),
+    /// ];
+    /// let mut state = TreeViewState::new();
+    /// assert_eq!(state.visible_index_of(&nodes, &[1]), Some(1));
+    /// state.expand(vec![0]);
+    /// assert_eq!(state.visible_index_of(&nodes, &[1]), Some(2));
+    /// ```
+    pub fn visible_index_of<T>(&self, nodes: &[TreeNode<T>], path: &[usize]) -> Option<usize> {
+        get_visible_paths(nodes, self)
+            .iter()
+            .position(|visible| visible == path)
+    }
+
+    /// Gets the flat row index of a node among the visible rows with filtering support.
+    ///
+    /// Uses [`get_visible_paths_filtered`], so rows hidden by the active
+    /// filter are not counted.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The node data type.
+    /// * `F` - The filter matcher function type.
+    ///
+    /// # Arguments
+    ///
+    /// * `nodes` - The tree nodes.
+    /// * `path` - The path to the node.
+    /// * `matcher` - A function that takes node data and filter, returns true if matches.
+    ///
+    /// # Returns
+    ///
+    /// The row index, or `None` if the node is not visible.
+    pub fn visible_index_of_filtered<T, F>(
+        &self,
+        nodes: &[TreeNode<T>],
+        path: &[usize],
+        matcher: F,
+    ) -> Option<usize>
+    where
+        F: Fn(&T, &Option<String>) -> bool,
+    {
+        get_visible_paths_filtered(nodes, self, matcher)
+            .iter()
+            .position(|visible| visible == path)
+    }
+}
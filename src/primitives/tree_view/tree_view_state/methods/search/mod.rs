@@ -0,0 +1,5 @@
+//! Search methods for TreeViewState.
+
+pub mod push_type_ahead;
+pub mod type_ahead;
+pub mod type_ahead_buffer;
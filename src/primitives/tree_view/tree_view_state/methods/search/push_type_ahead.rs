@@ -0,0 +1,34 @@
+//! TreeViewState::push_type_ahead method.
+
+use std::time::{Duration, Instant};
+
+use crate::primitives::tree_view::tree_view_state::TreeViewState;
+
+impl TreeViewState {
+    /// Appends a character to the type-ahead buffer.
+    ///
+    /// The buffer starts over when more than `timeout` has passed since
+    /// the previous character.
+    ///
+    /// # Arguments
+    ///
+    /// * `c` - The typed character.
+    /// * `now` - The time the character was typed.
+    /// * `timeout` - The idle time after which the buffer starts over.
+    ///
+    /// # Returns
+    ///
+    /// The buffer contents after appending.
+    pub(crate) fn push_type_ahead(&mut self, c: char, now: Instant, timeout: Duration) -> &str {
+        let expired = self
+            .type_ahead_at
+            .map_or(true, |at| now.saturating_duration_since(at) > timeout);
+        if expired {
+            self.type_ahead_buffer.clear();
+        }
+
+        self.type_ahead_buffer.push(c);
+        self.type_ahead_at = Some(now);
+        &self.type_ahead_buffer
+    }
+}
@@ -0,0 +1,216 @@
+//! TreeViewState::type_ahead methods.
+
+use std::time::{Duration, Instant};
+
+use crate::primitives::tree_view::helpers::{get_node_at_path, get_visible_paths};
+use crate::primitives::tree_view::tree_node::TreeNode;
+use crate::primitives::tree_view::tree_view_state::TreeViewState;
+
+impl TreeViewState {
+    /// Idle time after which [`type_ahead`](Self::type_ahead) starts a new
+    /// prefix.
+    pub const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_millis(800);
+
+    /// Jumps to the next visible node whose label starts with the typed prefix.
+    ///
+    /// Characters typed less than [`TYPE_AHEAD_TIMEOUT`](Self::TYPE_AHEAD_TIMEOUT)
+    /// apart build up a prefix, see [`type_ahead_with`](Self::type_ahead_with)
+    /// for the matching rules.
+    /// Unlike filter mode, non-matching nodes stay visible.
+    ///
+    /// # Arguments
+    ///
+    /// * `c` - The typed character.
+    /// * `nodes` - The tree nodes.
+    /// * `label` - Returns the text to match for a node's data.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the character was consumed, even when nothing matched.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_toolkit::tree_view::{TreeNode, TreeViewState};
+    ///
+    /// let nodes = vec![TreeNode::new("Cargo.toml"), TreeNode::new("src")];
+    /// let mut state = TreeViewState::new();
+    /// state.type_ahead('s', &nodes, |s| s.to_string());
+    /// assert_eq!(state.selected_path, Some(vec![1]));
+    /// ```
+    pub fn type_ahead<T, L>(&mut self, c: char, nodes: &[TreeNode<T>], label: L) -> bool
+    where
+        L: Fn(&T) -> String,
+    {
+        self.type_ahead_with(c, nodes, Instant::now(), Self::TYPE_AHEAD_TIMEOUT, label)
+    }
+
+    /// Jumps to a node by typed prefix, with an explicit time and timeout.
+    ///
+    /// The prefix is matched case-insensitively and the search wraps around
+    /// the visible rows. A new single-character prefix starts after the
+    /// current node, so repeating a letter cycles through matches; a longer
+    /// prefix keeps the current node if it still matches. Never triggers
+    /// while filter mode is active.
+    ///
+    /// # Arguments
+    ///
+    /// * `c` - The typed character.
+    /// * `nodes` - The tree nodes.
+    /// * `now` - The time the character was typed.
+    /// * `timeout` - The idle time after which the buffer starts over.
+    /// * `label` - Returns the text to match for a node's data.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the character was consumed, even when nothing matched.
+    pub fn type_ahead_with<T, L>(
+        &mut self,
+        c: char,
+        nodes: &[TreeNode<T>],
+        now: Instant,
+        timeout: Duration,
+        label: L,
+    ) -> bool
+    where
+        L: Fn(&T) -> String,
+    {
+        if self.filter_mode {
+            return false;
+        }
+
+        let prefix = self.push_type_ahead(c, now, timeout).to_lowercase();
+
+        let visible_paths = get_visible_paths(nodes, self);
+        if visible_paths.is_empty() {
+            return true;
+        }
+
+        let current = self
+            .selected_path
+            .as_ref()
+            .and_then(|selected| visible_paths.iter().position(|p| p == selected));
+        let start = match current {
+            Some(idx) if prefix.chars().count() > 1 => idx,
+            Some(idx) => idx + 1,
+            None => 0,
+        };
+
+        let found = (0..visible_paths.len())
+            .map(|i| &visible_paths[(start + i) % visible_paths.len()])
+            .find(|path| {
+                get_node_at_path(nodes, path)
+                    .is_some_and(|node| label(&node.data).to_lowercase().starts_with(&prefix))
+            });
+
+        if let Some(path) = found {
+            self.select(path.clone());
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crate::primitives::tree_view::{TreeNode, TreeViewState};
+
+    fn label(data: &&str) -> String {
+        data.to_string()
+    }
+
+    fn tree() -> Vec<TreeNode<&'static str>> {
+        vec![
+            TreeNode::with_children(
+                "docs",
+                vec![TreeNode::new("design.md"), TreeNode::new("readme.md")],
+            ),
+            TreeNode::new("deny.toml"),
+            TreeNode::new("README.md"),
+        ]
+    }
+
+    #[test]
+    fn type_ahead_wraps_around_visible_nodes() {
+        let nodes = tree();
+        let mut state = TreeViewState::new();
+        state.expand(vec![0]);
+        state.select(vec![2]);
+        let timeout = TreeViewState::TYPE_AHEAD_TIMEOUT;
+        let mut now = Instant::now();
+
+        assert!(state.type_ahead_with('d', &nodes, now, timeout, label));
+        assert_eq!(state.selected_path, Some(vec![0]));
+
+        now += timeout * 2;
+        state.type_ahead_with('d', &nodes, now, timeout, label);
+        assert_eq!(state.selected_path, Some(vec![0, 0]));
+
+        now += timeout * 2;
+        state.type_ahead_with('d', &nodes, now, timeout, label);
+        assert_eq!(state.selected_path, Some(vec![1]));
+
+        now += timeout * 2;
+        state.type_ahead_with('r', &nodes, now, timeout, label);
+        assert_eq!(state.selected_path, Some(vec![2]));
+    }
+
+    #[test]
+    fn type_ahead_skips_collapsed_children() {
+        let nodes = tree();
+        let mut state = TreeViewState::new();
+        state.select(vec![0]);
+
+        state.type_ahead_with(
+            'd',
+            &nodes,
+            Instant::now(),
+            TreeViewState::TYPE_AHEAD_TIMEOUT,
+            label,
+        );
+        assert_eq!(state.selected_path, Some(vec![1]));
+    }
+
+    #[test]
+    fn type_ahead_buffer_resets_after_timeout() {
+        let nodes = tree();
+        let mut state = TreeViewState::new();
+        let timeout = TreeViewState::TYPE_AHEAD_TIMEOUT;
+        let t0 = Instant::now();
+
+        state.type_ahead_with('r', &nodes, t0, timeout, label);
+        state.type_ahead_with('e', &nodes, t0 + Duration::from_millis(500), timeout, label);
+        assert_eq!(state.type_ahead_buffer, "re");
+        assert_eq!(state.selected_path, Some(vec![2]));
+
+        let late = t0 + Duration::from_millis(500) + timeout + Duration::from_millis(1);
+        state.type_ahead_with('d', &nodes, late, timeout, label);
+        assert_eq!(state.type_ahead_buffer, "d");
+        assert_eq!(state.selected_path, Some(vec![0]));
+    }
+
+    #[test]
+    fn type_ahead_buffer_accessor_hides_expired_buffer() {
+        let nodes = tree();
+        let mut state = TreeViewState::new();
+
+        assert_eq!(state.type_ahead_buffer(), "");
+        state.type_ahead('d', &nodes, label);
+        assert_eq!(state.type_ahead_buffer(), "d");
+
+        state.type_ahead_at = Some(Instant::now() - TreeViewState::TYPE_AHEAD_TIMEOUT * 2);
+        assert_eq!(state.type_ahead_buffer(), "");
+    }
+
+    #[test]
+    fn type_ahead_is_ignored_in_filter_mode() {
+        let nodes = tree();
+        let mut state = TreeViewState::new();
+        state.enter_filter_mode();
+
+        assert!(!state.type_ahead('d', &nodes, label));
+        assert_eq!(state.selected_path, None);
+        assert_eq!(state.type_ahead_buffer(), "");
+    }
+}
@@ -0,0 +1,30 @@
+//! TreeViewState::type_ahead_buffer method.
+
+use crate::primitives::tree_view::tree_view_state::TreeViewState;
+
+impl TreeViewState {
+    /// Gets the prefix typed so far for type-ahead navigation.
+    ///
+    /// # Returns
+    ///
+    /// The typed prefix, or an empty string once
+    /// [`TYPE_AHEAD_TIMEOUT`](Self::TYPE_AHEAD_TIMEOUT) has passed since the
+    /// last character.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_toolkit::tree_view::{TreeNode, TreeViewState};
+    ///
+    /// let nodes = vec![TreeNode::new("src"), TreeNode::new("target")];
+    /// let mut state = TreeViewState::new();
+    /// state.type_ahead('t', &nodes, |s| s.to_string());
+    /// assert_eq!(state.type_ahead_buffer(), "t");
+    /// ```
+    pub fn type_ahead_buffer(&self) -> &str {
+        match self.type_ahead_at {
+            Some(at) if at.elapsed() <= Self::TYPE_AHEAD_TIMEOUT => &self.type_ahead_buffer,
+            _ => "",
+        }
+    }
+}
@@ -5,6 +5,9 @@ pub mod methods;
 pub mod traits;
 
 use std::collections::HashSet;
+use std::time::Instant;
+
+use crate::primitives::tree_view::row_heights::RowHeights;
 
 /// Tree view state for StatefulWidget pattern.
 ///
@@ -26,10 +29,20 @@ pub struct TreeViewState {
     pub selected_path: Option<Vec<usize>>,
     /// Set of expanded node paths
     pub expanded: HashSet<Vec<usize>>,
-    /// Vertical scroll offset
+    /// Set of checked node paths (multi-select)
+    pub checked: HashSet<Vec<usize>>,
+    /// Vertical scroll offset (index of the first visible row)
     pub offset: usize,
     /// Current filter text
     pub filter: Option<String>,
     /// Whether filter mode is active
     pub filter_mode: bool,
+    /// Paths of lazily loaded nodes that were expanded and need children
+    pub pending_expansions: Vec<Vec<usize>>,
+    /// Characters typed so far for type-ahead navigation
+    pub type_ahead_buffer: String,
+    /// When the last type-ahead character was typed
+    pub type_ahead_at: Option<Instant>,
+    /// Row heights from the last layout, for variable-height rows
+    pub(crate) row_heights: RowHeights,
 }
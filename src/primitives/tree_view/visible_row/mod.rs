@@ -0,0 +1,36 @@
+//! Visible row type for custom tree rendering.
+
+mod visible_rows;
+
+pub use visible_rows::VisibleRows;
+
+use crate::primitives::tree_view::tree_node::TreeNode;
+
+/// A node that is currently visible in the tree.
+///
+/// Yielded by [`TreeView::visible_rows`](crate::primitives::tree_view::TreeView::visible_rows)
+/// in display order, following the same expansion rules as the widget.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of data stored in tree nodes.
+#[derive(Debug)]
+pub struct VisibleRow<'a, T> {
+    /// The visible node
+    pub node: &'a TreeNode<T>,
+    /// Path to this node (indices from root)
+    pub path: Vec<usize>,
+    /// Depth level in the tree (0 = root)
+    pub depth: usize,
+    /// Whether this node is expanded
+    pub is_expanded: bool,
+    /// Whether this node is the selected row
+    pub is_selected: bool,
+    /// Whether this node is checked (multi-select)
+    pub is_checked: bool,
+    /// Whether this node is expanded but its children are still loading
+    ///
+    /// The widget draws a loading placeholder below such nodes; the
+    /// placeholder itself is not yielded as a row.
+    pub is_loading: bool,
+}
@@ -0,0 +1,67 @@
+//! Iterator over visible tree rows.
+
+use std::iter::Enumerate;
+use std::slice::Iter;
+
+use crate::primitives::tree_view::tree_node::TreeNode;
+use crate::primitives::tree_view::tree_view_state::TreeViewState;
+use crate::primitives::tree_view::visible_row::VisibleRow;
+
+/// Iterator returned by [`TreeView::visible_rows`](crate::primitives::tree_view::TreeView::visible_rows).
+///
+/// Walks the tree lazily in display order, descending only into expanded
+/// nodes whose children are loaded.
+pub struct VisibleRows<'a, T> {
+    state: &'a TreeViewState,
+    /// One sibling iterator per depth level currently being walked
+    stack: Vec<Enumerate<Iter<'a, TreeNode<T>>>>,
+    /// Path of the parent of the nodes at the top of `stack`
+    parent_path: Vec<usize>,
+}
+
+impl<'a, T> VisibleRows<'a, T> {
+    pub(crate) fn new(nodes: &'a [TreeNode<T>], state: &'a TreeViewState) -> Self {
+        Self {
+            state,
+            stack: vec![nodes.iter().enumerate()],
+            parent_path: Vec::new(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for VisibleRows<'a, T> {
+    type Item = VisibleRow<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let depth = self.stack.len().checked_sub(1)?;
+            let Some((idx, node)) = self.stack[depth].next() else {
+                self.stack.pop();
+                self.parent_path.pop();
+                continue;
+            };
+
+            let mut path = self.parent_path.clone();
+            path.push(idx);
+
+            let is_expanded = self.state.is_expanded(&path);
+            let is_loading = is_expanded && node.needs_children();
+            let row = VisibleRow {
+                node,
+                depth,
+                is_expanded,
+                is_selected: self.state.selected_path.as_ref() == Some(&path),
+                is_checked: self.state.is_checked(&path),
+                is_loading,
+                path,
+            };
+
+            if is_expanded && !is_loading && !node.children.is_empty() {
+                self.parent_path.push(idx);
+                self.stack.push(node.children.iter().enumerate());
+            }
+
+            return Some(row);
+        }
+    }
+}
@@ -29,10 +29,15 @@ impl<'a, T> TreeView<'a, T> {
             nodes,
             block: None,
             render_fn: Box::new(|_data, _state| Line::from("Node")),
+            height_fn: None,
+            detail_fn: None,
+            estimated_row_height: 1,
             expand_icon: "\u{25b6}",
             collapse_icon: "\u{25bc}",
+            loading_text: "Loading\u{2026}",
             highlight_style: None,
             show_filter_ui: false,
+            show_checkboxes: false,
         }
     }
 }
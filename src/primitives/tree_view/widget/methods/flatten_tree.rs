@@ -1,7 +1,7 @@
 //! TreeView::flatten_tree method.
 
 use ratatui::{
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
 };
 
@@ -29,6 +29,8 @@ impl<'a, T> TreeView<'a, T> {
             render_fn: &'b dyn Fn(&T, &NodeState) -> Line<'a>,
             expand_icon: &'b str,
             collapse_icon: &'b str,
+            loading_text: &'b str,
+            show_checkboxes: bool,
         }
 
         fn traverse<'a, T>(
@@ -44,10 +46,12 @@ impl<'a, T> TreeView<'a, T> {
 
                 let is_expanded = ctx.state.is_expanded(&path);
                 let is_selected = ctx.state.selected_path.as_ref() == Some(&path);
+                let is_checked = ctx.state.is_checked(&path);
 
                 let node_state = NodeState {
                     is_selected,
                     is_expanded,
+                    is_checked,
                     level,
                     has_children: !node.children.is_empty(),
                     path: path.clone(),
@@ -76,10 +80,30 @@ impl<'a, T> TreeView<'a, T> {
                         Style::default().fg(Color::DarkGray),
                     ),
                 ];
+                if ctx.show_checkboxes {
+                    spans.push(Span::raw(if is_checked { "[x] " } else { "[ ] " }));
+                }
                 spans.extend(custom_line.spans);
 
                 items.push((Line::from(spans), path.clone()));
 
+                // Render a placeholder while lazily loaded children are pending
+                if is_expanded && node.needs_children() {
+                    let mut placeholder_path = path.clone();
+                    placeholder_path.push(0);
+                    let placeholder = Line::from(vec![
+                        Span::raw("  ".repeat(level + 1)),
+                        Span::styled(
+                            format!("  {}", ctx.loading_text),
+                            Style::default()
+                                .fg(Color::DarkGray)
+                                .add_modifier(Modifier::ITALIC),
+                        ),
+                    ]);
+                    items.push((placeholder, placeholder_path));
+                    continue;
+                }
+
                 // Recursively render children if expanded
                 if is_expanded && !node.children.is_empty() {
                     traverse(&node.children, path, level + 1, ctx, items);
@@ -92,6 +116,8 @@ impl<'a, T> TreeView<'a, T> {
             render_fn: &self.render_fn,
             expand_icon: self.expand_icon,
             collapse_icon: self.collapse_icon,
+            loading_text: self.loading_text,
+            show_checkboxes: self.show_checkboxes,
         };
 
         traverse(&self.nodes, Vec::new(), 0, &ctx, &mut items);
@@ -101,7 +101,7 @@ impl<'a, T> TreeView<'a, T> {
     /// Handle a mouse event and return a WidgetEvent.
     ///
     /// This method processes mouse input for the tree view, including:
-    /// - Click to select items
+    /// - Click to select items (any line of a taller row selects its node)
     /// - Scroll wheel to scroll
     ///
     /// # Arguments
@@ -133,10 +133,15 @@ impl<'a, T> TreeView<'a, T> {
             return WidgetEvent::None;
         }
 
-        let row = (y - inner_area.y + state.offset as u16) as usize;
         let items = self.flatten_tree(state);
+        self.sync_row_heights(&mut state.row_heights, &items, inner_area.width);
+        let row = self.row_at_line(
+            &mut state.row_heights,
+            state.offset,
+            (y - inner_area.y) as usize,
+        );
 
-        if let Some((_, path)) = items.get(row) {
+        if let Some((_, path)) = row.and_then(|row| items.get(row)) {
             state.selected_path = Some(path.clone());
             return WidgetEvent::Selected { path: path.clone() };
         }
@@ -0,0 +1,33 @@
+//! TreeView::loading_text method.
+
+use crate::primitives::tree_view::widget::TreeView;
+
+impl<'a, T> TreeView<'a, T> {
+    /// Sets the placeholder text shown while a node's children are loading.
+    ///
+    /// The placeholder is rendered as a single child row under expanded
+    /// nodes marked with [`TreeNode::has_children_hint`](crate::primitives::tree_view::TreeNode::has_children_hint)
+    /// until their children are inserted.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The placeholder text.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_toolkit::tree_view::{TreeNode, TreeView};
+    ///
+    /// let nodes = vec![TreeNode::new("Item").has_children_hint(true)];
+    /// let tree = TreeView::new(nodes)
+    ///     .loading_text("fetching...");
+    /// ```
+    pub fn loading_text(mut self, text: &'a str) -> Self {
+        self.loading_text = text;
+        self
+    }
+}
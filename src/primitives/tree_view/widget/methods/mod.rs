@@ -5,7 +5,12 @@ pub mod flatten_tree;
 pub mod handle_event;
 pub mod highlight_style;
 pub mod icons;
+pub mod loading_text;
 pub mod node_at_row;
 pub mod render_fn;
+pub mod row_height_fn;
+pub mod row_layout;
 pub mod visible_item_count;
+pub mod visible_rows;
+pub mod with_checkboxes;
 pub mod with_filter_ui;
@@ -9,7 +9,8 @@ impl<'a, T> TreeView<'a, T> {
     /// # Arguments
     ///
     /// * `state` - The tree view state.
-    /// * `row` - The row index (0-based, relative to visible area).
+    /// * `row` - The screen line (0-based, relative to visible area). With
+    ///   variable-height rows, any line of a node's row maps to that node.
     ///
     /// # Returns
     ///
@@ -27,6 +28,10 @@ impl<'a, T> TreeView<'a, T> {
     /// ```
     pub fn node_at_row(&self, state: &TreeViewState, row: usize) -> Option<Vec<usize>> {
         let items = self.flatten_tree(state);
-        items.get(row + state.offset).map(|(_, path)| path.clone())
+        let mut heights = state.row_heights.clone();
+        let width = heights.width();
+        self.sync_row_heights(&mut heights, &items, width);
+        let row = self.row_at_line(&mut heights, state.offset, row)?;
+        items.get(row).map(|(_, path)| path.clone())
     }
 }
@@ -0,0 +1,86 @@
+//! TreeView::row_height_fn and related methods.
+
+use ratatui::text::Line;
+
+use crate::primitives::tree_view::tree_node::TreeNode;
+use crate::primitives::tree_view::widget::TreeView;
+
+impl<'a, T> TreeView<'a, T> {
+    /// Sets a callback giving the height of each node's row in lines.
+    ///
+    /// The callback receives the node and the width of the tree area. The
+    /// node's line is drawn on the first line of its row; the remaining
+    /// lines hold the output of [`row_detail_fn`](Self::row_detail_fn).
+    /// Heights are at least 1, and loading placeholders are always 1 line.
+    ///
+    /// Only rows that are scrolled into view are measured. Measured heights
+    /// are cached in the [`TreeViewState`](crate::primitives::tree_view::TreeViewState)
+    /// by node path until the width changes or
+    /// [`invalidate_row_heights`](crate::primitives::tree_view::TreeViewState::invalidate_row_heights)
+    /// is called; other rows count as [`estimated_row_height`](Self::estimated_row_height).
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A function that takes a node and the available width, returns its height.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::text::Line;
+    /// use ratatui_toolkit::tree_view::{TreeNode, TreeView};
+    ///
+    /// let nodes = vec![TreeNode::new(("main.rs", "Entry point"))];
+    /// let tree = TreeView::new(nodes)
+    ///     .render_fn(|(name, _), _state| Line::from(*name))
+    ///     .row_height_fn(|_node, _width| 2)
+    ///     .row_detail_fn(|node, _width| vec![Line::from(format!("    {}", node.data.1))]);
+    /// ```
+    pub fn row_height_fn<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&TreeNode<T>, u16) -> u16 + 'a,
+    {
+        self.height_fn = Some(Box::new(f));
+        self
+    }
+
+    /// Sets a callback producing the lines drawn below a node's line.
+    ///
+    /// Lines beyond the row height from [`row_height_fn`](Self::row_height_fn)
+    /// are clipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A function that takes a node and the available width, returns the detail lines.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn row_detail_fn<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&TreeNode<T>, u16) -> Vec<Line<'a>> + 'a,
+    {
+        self.detail_fn = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the height assumed for rows that have not been measured yet.
+    ///
+    /// A close estimate keeps the scrollbar steady as rows are measured.
+    /// Defaults to 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `height` - The estimated row height in lines.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn estimated_row_height(mut self, height: u16) -> Self {
+        self.estimated_row_height = height.max(1);
+        self
+    }
+}
@@ -0,0 +1,257 @@
+//! Variable-height row layout for TreeView.
+
+use ratatui::{buffer::Buffer, layout::Rect, text::Line};
+
+use crate::primitives::tree_view::helpers::get_node_at_path;
+use crate::primitives::tree_view::row_heights::RowHeights;
+use crate::primitives::tree_view::tree_view_state::TreeViewState;
+use crate::primitives::tree_view::widget::TreeView;
+
+impl<'a, T> TreeView<'a, T> {
+    /// Syncs a row height index with the flattened rows.
+    pub(crate) fn sync_row_heights(
+        &self,
+        heights: &mut RowHeights,
+        items: &[(Line<'a>, Vec<usize>)],
+        width: u16,
+    ) {
+        let estimate = match self.height_fn {
+            Some(_) => self.estimated_row_height as usize,
+            None => 1,
+        };
+        heights.sync(items.iter().map(|(_, path)| path), estimate, width);
+    }
+
+    /// Measures `row` if it has not been measured yet and returns its height.
+    pub(crate) fn measure_row(&self, heights: &mut RowHeights, row: usize) -> usize {
+        let Some(height_fn) = &self.height_fn else {
+            return heights.height(row);
+        };
+        if heights.is_measured(row) {
+            return heights.height(row);
+        }
+
+        let height = heights
+            .path(row)
+            .and_then(|path| get_node_at_path(&self.nodes, path))
+            .map_or(1, |node| height_fn(node, heights.width()) as usize);
+        heights.set_measured(row, height);
+        heights.height(row)
+    }
+
+    /// Measures the rows shown in a viewport of `viewport_height` lines
+    /// scrolled to row `offset`.
+    pub(crate) fn measure_viewport(
+        &self,
+        heights: &mut RowHeights,
+        offset: usize,
+        viewport_height: usize,
+    ) {
+        let mut used = 0;
+        let mut row = offset;
+        while used < viewport_height && row < heights.len() {
+            used += self.measure_row(heights, row);
+            row += 1;
+        }
+    }
+
+    /// Adjusts the scroll offset so row `index` fits in the viewport.
+    ///
+    /// Like [`TreeViewState::scroll_index_into_view`], but measures rows so
+    /// the whole row fits when rows are taller than one line. Only rows
+    /// between the viewport and the target are measured.
+    pub(crate) fn scroll_row_into_view(
+        &self,
+        state: &mut TreeViewState,
+        index: usize,
+        viewport_height: usize,
+    ) {
+        if viewport_height == 0 || index >= state.row_heights.len() {
+            return;
+        }
+        if index <= state.offset {
+            state.offset = index;
+            return;
+        }
+
+        let mut used = 0;
+        for row in state.offset..=index {
+            used += self.measure_row(&mut state.row_heights, row);
+            if used > viewport_height {
+                break;
+            }
+        }
+        if used <= viewport_height {
+            return;
+        }
+
+        // Put the row at the bottom of the viewport, or at the top if it is
+        // taller than the viewport.
+        let mut offset = index;
+        let mut used = self.measure_row(&mut state.row_heights, index);
+        while offset > 0 {
+            let height = self.measure_row(&mut state.row_heights, offset - 1);
+            if used + height > viewport_height {
+                break;
+            }
+            used += height;
+            offset -= 1;
+        }
+        state.offset = offset;
+    }
+
+    /// Draws the rows from the scroll offset down to the bottom of `area`.
+    pub(crate) fn render_rows(
+        &self,
+        items: &[(Line<'a>, Vec<usize>)],
+        state: &mut TreeViewState,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let bottom = area.y + area.height;
+        let mut y = area.y;
+
+        for (row, (line, path)) in items.iter().enumerate().skip(state.offset) {
+            if y >= bottom {
+                break;
+            }
+            let height = self
+                .measure_row(&mut state.row_heights, row)
+                .min((bottom - y) as usize) as u16;
+
+            let is_selected = state.selected_path.as_ref() == Some(path);
+            if let (true, Some(style)) = (is_selected, self.highlight_style) {
+                buf.set_style(Rect::new(area.x, y, area.width, height), style);
+            }
+
+            buf.set_line(area.x, y, line, area.width);
+            if height > 1 {
+                if let Some(detail_fn) = &self.detail_fn {
+                    if let Some(node) = get_node_at_path(&self.nodes, path) {
+                        let details = detail_fn(node, area.width);
+                        for (i, detail) in details.iter().take(height as usize - 1).enumerate() {
+                            buf.set_line(area.x, y + 1 + i as u16, detail, area.width);
+                        }
+                    }
+                }
+            }
+
+            y += height;
+        }
+    }
+
+    /// Gets the row covering `line` lines below the top of a viewport
+    /// scrolled to row `offset`.
+    pub(crate) fn row_at_line(
+        &self,
+        heights: &mut RowHeights,
+        offset: usize,
+        line: usize,
+    ) -> Option<usize> {
+        self.measure_viewport(heights, offset, line + 1);
+        heights.row_at(heights.offset_of(offset) + line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+    use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::StatefulWidget};
+
+    use crate::primitives::tree_view::{TreeNode, TreeView, TreeViewState};
+
+    /// Nodes whose data is their row height.
+    fn tree<'a>(heights: &[u16], measured: &'a Cell<usize>) -> TreeView<'a, u16> {
+        TreeView::new(heights.iter().copied().map(TreeNode::new).collect())
+            .render_fn(|height, _| Line::from(format!("node {height}")))
+            .row_height_fn(move |node, _width| {
+                measured.set(measured.get() + 1);
+                node.data
+            })
+            .row_detail_fn(|node, _width| vec![Line::from(format!("detail {}", node.data)); 5])
+    }
+
+    fn render(tree: TreeView<'_, u16>, state: &mut TreeViewState, height: u16) -> Vec<String> {
+        let area = Rect::new(0, 0, 12, height);
+        let mut buf = Buffer::empty(area);
+        tree.render(area, &mut buf, state);
+        (0..height)
+            .map(|y| {
+                (0..area.width)
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn renders_tall_rows_and_measures_only_visible_nodes() {
+        let measured = Cell::new(0);
+        let heights: Vec<u16> = [1, 3, 2].into_iter().cycle().take(300).collect();
+        let mut state = TreeViewState::new();
+
+        let lines = render(tree(&heights, &measured), &mut state, 5);
+        assert_eq!(
+            lines,
+            ["  node 1", "  node 3", "detail 3", "detail 3", "  node 2"]
+        );
+        assert_eq!(measured.get(), 3);
+        // 297 unmeasured rows count as the default estimate of one line.
+        assert_eq!(state.content_height(), 1 + 3 + 2 + 297);
+
+        // Cached heights are not measured again on the next frame.
+        render(tree(&heights, &measured), &mut state, 5);
+        assert_eq!(measured.get(), 3);
+    }
+
+    #[test]
+    fn selecting_a_distant_row_fits_it_in_the_viewport() {
+        let measured = Cell::new(0);
+        let heights: Vec<u16> = [1, 3, 2].into_iter().cycle().take(300).collect();
+        let mut state = TreeViewState::new();
+        render(tree(&heights, &measured), &mut state, 5);
+
+        state.select(vec![250]);
+        let lines = render(tree(&heights, &measured), &mut state, 5);
+        // Row 250 (height 3) fits below row 249 (height 1); row 251 is clipped.
+        assert_eq!(state.offset, 249);
+        assert_eq!(
+            lines,
+            ["  node 1", "  node 3", "detail 3", "detail 3", "  node 2"]
+        );
+        assert!(measured.get() < 10);
+        // Rows 0-2 and 248 are measured (1 + 3 + 2 + 2), the other 245 above are estimated.
+        assert_eq!(state.scroll_position(), 253);
+
+        state.select(vec![248]);
+        render(tree(&heights, &measured), &mut state, 5);
+        assert_eq!(state.offset, 248);
+    }
+
+    #[test]
+    fn clicking_a_detail_line_selects_its_node() {
+        let measured = Cell::new(0);
+        let heights = [1, 3, 2];
+        let mut state = TreeViewState::new();
+        render(tree(&heights, &measured), &mut state, 6);
+
+        let mut tree = tree(&heights, &measured);
+        let click = |row| MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: 0,
+            row,
+            modifiers: KeyModifiers::NONE,
+        };
+        let area = Rect::new(0, 0, 12, 6);
+
+        tree.handle_mouse_event(click(3), &mut state, area);
+        assert_eq!(state.selected_path, Some(vec![1]));
+        tree.handle_mouse_event(click(5), &mut state, area);
+        assert_eq!(state.selected_path, Some(vec![2]));
+        assert_eq!(tree.node_at_row(&state, 4), Some(vec![2]));
+    }
+}
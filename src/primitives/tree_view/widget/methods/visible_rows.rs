@@ -0,0 +1,115 @@
+//! TreeView::visible_rows method.
+
+use crate::primitives::tree_view::tree_view_state::TreeViewState;
+use crate::primitives::tree_view::visible_row::VisibleRows;
+use crate::primitives::tree_view::widget::TreeView;
+
+impl<'a, T> TreeView<'a, T> {
+    /// Iterates over the visible nodes in display order.
+    ///
+    /// Each [`VisibleRow`](crate::primitives::tree_view::VisibleRow) carries the
+    /// node, its path and depth, and its expanded, selected and checked state,
+    /// so custom renderers don't have to re-walk the tree. Visibility follows
+    /// the same rules as the widget, except that loading placeholders are not
+    /// yielded; the expanded node is flagged with `is_loading` instead.
+    ///
+    /// The iterator borrows the tree and the state immutably, so neither can be
+    /// modified while it is alive. Collect the rows first if you need to.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The tree view state (for expansion and selection info).
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the visible rows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_toolkit::tree_view::{TreeNode, TreeView, TreeViewState};
+    ///
+    /// let nodes = vec![TreeNode::with_children("src", vec![TreeNode::new("main.rs")])];
+    /// let tree = TreeView::new(nodes);
+    /// let mut state = TreeViewState::new();
+    /// state.expand(vec![0]);
+    ///
+    /// for row in tree.visible_rows(&state) {
+    ///     let indent = "  ".repeat(row.depth);
+    ///     println!("{indent}{}", row.node.data);
+    /// }
+    /// ```
+    pub fn visible_rows<'t>(&'t self, state: &'t TreeViewState) -> VisibleRows<'t, T> {
+        VisibleRows::new(&self.nodes, state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::primitives::tree_view::{get_visible_paths, TreeNode, TreeView, TreeViewState};
+
+    fn sample_tree() -> TreeView<'static, &'static str> {
+        TreeView::new(vec![
+            TreeNode::with_children(
+                "src",
+                vec![
+                    TreeNode::with_children("widgets", vec![TreeNode::new("tree.rs")]),
+                    TreeNode::new("lib.rs"),
+                ],
+            ),
+            TreeNode::new("remote").has_children_hint(true),
+            TreeNode::new("README.md"),
+        ])
+    }
+
+    #[test]
+    fn yields_visible_nodes_with_depth_and_state() {
+        let tree = sample_tree();
+        let mut state = TreeViewState::new();
+        state.expand(vec![0]);
+        state.select(vec![0, 1]);
+        state.set_checked(vec![2], true);
+
+        let rows: Vec<_> = tree.visible_rows(&state).collect();
+        let summary: Vec<_> = rows
+            .iter()
+            .map(|row| (row.node.data, row.depth, row.is_expanded))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("src", 0, true),
+                ("widgets", 1, false),
+                ("lib.rs", 1, false),
+                ("remote", 0, false),
+                ("README.md", 0, false),
+            ]
+        );
+        assert_eq!(rows[2].path, [0, 1]);
+        assert!(rows[2].is_selected);
+        assert_eq!(rows.iter().filter(|row| row.is_selected).count(), 1);
+        assert!(rows[4].is_checked);
+    }
+
+    #[test]
+    fn matches_visible_paths_except_loading_placeholders() {
+        let tree = sample_tree();
+        let mut state = TreeViewState::new();
+        state.expand(vec![0]);
+        state.expand(vec![0, 0]);
+        state.expand(vec![1]);
+
+        let rows: Vec<_> = tree.visible_rows(&state).collect();
+        let loading: Vec<_> = rows
+            .iter()
+            .filter(|row| row.is_loading)
+            .map(|row| row.path.clone())
+            .collect();
+        assert_eq!(loading, [vec![1]]);
+
+        let mut expected = get_visible_paths(&tree.nodes, &state);
+        expected.retain(|path| path != &[1, 0]);
+        let paths: Vec<_> = rows.into_iter().map(|row| row.path).collect();
+        assert_eq!(paths, expected);
+    }
+}
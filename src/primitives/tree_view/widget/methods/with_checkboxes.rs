@@ -0,0 +1,22 @@
+//! TreeView::with_checkboxes method.
+
+use crate::primitives::tree_view::widget::TreeView;
+
+impl<'a, T> TreeView<'a, T> {
+    /// Shows a checkbox column before each node.
+    ///
+    /// Checked nodes are tracked in [`TreeViewState`](crate::primitives::tree_view::TreeViewState)
+    /// and toggled with [`TreeViewState::toggle_checked`](crate::primitives::tree_view::TreeViewState::toggle_checked).
+    ///
+    /// # Arguments
+    ///
+    /// * `show` - Whether to render the checkbox column.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_checkboxes(mut self, show: bool) -> Self {
+        self.show_checkboxes = show;
+        self
+    }
+}
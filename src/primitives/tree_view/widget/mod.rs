@@ -7,10 +7,17 @@ pub mod traits;
 use ratatui::{style::Style, text::Line, widgets::Block};
 
 use crate::primitives::tree_view::node_state::NodeState;
+use crate::primitives::tree_view::tree_node::TreeNode;
 
 /// Type alias for node render function to reduce complexity.
 pub type NodeRenderFn<'a, T> = Box<dyn Fn(&T, &NodeState) -> Line<'a> + 'a>;
 
+/// Type alias for the row height callback: node and available width to lines.
+pub type NodeHeightFn<'a, T> = Box<dyn Fn(&TreeNode<T>, u16) -> u16 + 'a>;
+
+/// Type alias for the callback drawing lines below a node in a taller row.
+pub type NodeDetailFn<'a, T> = Box<dyn Fn(&TreeNode<T>, u16) -> Vec<Line<'a>> + 'a>;
+
 /// Tree view widget.
 ///
 /// A widget for rendering hierarchical tree data with expand/collapse
@@ -33,17 +40,26 @@ pub type NodeRenderFn<'a, T> = Box<dyn Fn(&T, &NodeState) -> Line<'a> + 'a>;
 /// ```
 pub struct TreeView<'a, T> {
     /// Root nodes of the tree
-    pub(crate) nodes: Vec<crate::primitives::tree_view::tree_node::TreeNode<T>>,
+    pub(crate) nodes: Vec<TreeNode<T>>,
     /// Block to wrap the tree
     pub(crate) block: Option<Block<'a>>,
     /// Render callback for custom node display
     pub(crate) render_fn: NodeRenderFn<'a, T>,
+    /// Row height callback; every row is one line when unset
+    pub(crate) height_fn: Option<NodeHeightFn<'a, T>>,
+    /// Callback for the lines below a node in a taller row
+    pub(crate) detail_fn: Option<NodeDetailFn<'a, T>>,
+    /// Height assumed for rows that have not been measured
+    pub(crate) estimated_row_height: u16,
     /// Default expand icon
     pub(crate) expand_icon: &'a str,
     /// Default collapse icon
     pub(crate) collapse_icon: &'a str,
+    /// Placeholder text shown under expanded nodes whose children are loading
+    pub(crate) loading_text: &'a str,
     /// Style for selected row background (full-width highlight)
     pub(crate) highlight_style: Option<Style>,
     /// Whether to show built-in filter UI
     pub(crate) show_filter_ui: bool,
+    pub(crate) show_checkboxes: bool,
 }
@@ -43,35 +43,15 @@ impl<'a, T> StatefulWidget for TreeView<'a, T> {
 
         let items = self.flatten_tree(state);
         let visible_height = tree_area.height as usize;
+        self.sync_row_heights(&mut state.row_heights, &items, tree_area.width);
 
         if let Some(ref selected) = state.selected_path {
             if let Some(selected_idx) = items.iter().position(|(_, path)| path == selected) {
-                if selected_idx < state.offset {
-                    state.offset = selected_idx;
-                } else if selected_idx >= state.offset + visible_height {
-                    state.offset = selected_idx.saturating_sub(visible_height - 1);
-                }
+                self.scroll_row_into_view(state, selected_idx, visible_height);
             }
         }
 
-        for (i, (line, path)) in items
-            .iter()
-            .skip(state.offset)
-            .take(visible_height)
-            .enumerate()
-        {
-            let y = tree_area.y + i as u16;
-
-            let is_selected = state.selected_path.as_ref() == Some(path);
-            if is_selected && self.highlight_style.is_some() {
-                let style = self.highlight_style.unwrap();
-                for x in tree_area.x..(tree_area.x + tree_area.width) {
-                    buf[(x, y)].set_style(style);
-                }
-            }
-
-            buf.set_line(tree_area.x, y, line, tree_area.width);
-        }
+        self.render_rows(&items, state, tree_area, buf);
 
         if show_filter_line && area.height > 1 {
             self.render_filter_line(area, buf, state);
@@ -7,7 +7,7 @@ use crate::primitives::tree_view::widget::TreeView;
 
 impl<'a, T> Widget for &TreeView<'a, T> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let state = TreeViewState::default();
+        let mut state = TreeViewState::default();
 
         let area = match &self.block {
             Some(ref b) => {
@@ -23,17 +23,7 @@ impl<'a, T> Widget for &TreeView<'a, T> {
         }
 
         let items = self.flatten_tree(&state);
-        let visible_height = area.height as usize;
-
-        // Render visible items
-        for (i, (line, _)) in items
-            .iter()
-            .skip(state.offset)
-            .take(visible_height)
-            .enumerate()
-        {
-            let y = area.y + i as u16;
-            buf.set_line(area.x, y, line, area.width);
-        }
+        self.sync_row_heights(&mut state.row_heights, &items, area.width);
+        self.render_rows(&items, &mut state, area, buf);
     }
 }
@@ -1,6 +1,7 @@
 //! Terminal runner for ratkit core runtime applications.
 
 use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
 
 use crossterm::{
@@ -15,7 +16,7 @@ use ratatui::{
     layout::Rect,
     style::{Color, Style},
     text::Line,
-    widgets::Paragraph,
+    widgets::{Clear, Paragraph},
     Frame, Terminal,
 };
 
@@ -24,6 +25,13 @@ use crate::core::{
     RunnerEvent, TickEvent,
 };
 
+/// How long shutdown hooks may run before a "shutting down…" frame is drawn.
+const SHUTDOWN_NOTICE_DELAY: Duration = Duration::from_millis(200);
+
+/// Set by [`suspend_terminal`] so the run loop repaints the whole screen,
+/// which another program has drawn over.
+static REPAINT_REQUESTED: AtomicBool = AtomicBool::new(false);
+
 /// Run a coordinator application with the ratkit core runtime.
 ///
 /// This function sets up the terminal, creates a Runner, and runs the event loop
@@ -80,6 +88,61 @@ pub fn run<A: CoordinatorApp>(app: A, config: RunnerConfig) -> io::Result<()> {
     result
 }
 
+/// Hands the terminal to another program, such as an editor, while `f`
+/// runs.
+///
+/// Leaves the alternate screen, raw mode and mouse capture, runs `f`, and
+/// restores them. Call it from [`CoordinatorApp::on_event`] while [`run`]
+/// or [`run_with_diagnostics`] is running; the next frame repaints the
+/// whole screen.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::process::Command;
+///
+/// let status = ratkit::suspend_terminal(|| Command::new("less").arg("README.md").status())?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the terminal modes could not be changed. Errors
+/// from `f` are part of its result.
+pub fn suspend_terminal<T>(f: impl FnOnce() -> T) -> io::Result<T> {
+    let mut stdout = io::stdout();
+    execute!(
+        stdout,
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        Print("\x1b[?1003l\x1b[?1006l"),
+        Show
+    )?;
+    disable_raw_mode()?;
+
+    let result = f();
+
+    enable_raw_mode()?;
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        Print("\x1b[?1006h\x1b[?1003h")
+    )?;
+    REPAINT_REQUESTED.store(true, Ordering::Release);
+    Ok(result)
+}
+
+/// Clears the screen if [`suspend_terminal`] handed it to another program,
+/// so the next draw repaints every cell. Returns whether it did.
+fn repaint_if_requested(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> io::Result<bool> {
+    if REPAINT_REQUESTED.swap(false, Ordering::AcqRel) {
+        terminal.clear()?;
+        return Ok(true);
+    }
+    Ok(false)
+}
+
 /// Run a coordinator application with diagnostics overlay enabled.
 pub fn run_with_diagnostics<A: CoordinatorApp>(app: A, config: RunnerConfig) -> io::Result<()> {
     install_panic_hook();
@@ -153,12 +216,15 @@ fn run_loop<A: CoordinatorApp>(
 
             let runner_event = convert_event(crossterm_event);
 
-            let action = runner
+            let mut action = runner
                 .handle_event(runner_event)
                 .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+            if repaint_if_requested(terminal)? && action == RunnerAction::Continue {
+                action = RunnerAction::Redraw;
+            }
 
             match action {
-                RunnerAction::Quit => return Ok(()),
+                RunnerAction::Quit => return shutdown(terminal, &mut runner),
                 RunnerAction::Redraw => {
                     terminal.draw(|frame| {
                         let _ = runner.render(frame);
@@ -176,12 +242,15 @@ fn run_loop<A: CoordinatorApp>(
         if last_tick.elapsed() >= tick_rate {
             tick_count += 1;
             let tick_event = RunnerEvent::Tick(TickEvent::new(tick_count));
-            let action = runner
+            let mut action = runner
                 .handle_event(tick_event)
                 .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+            if repaint_if_requested(terminal)? && action == RunnerAction::Continue {
+                action = RunnerAction::Redraw;
+            }
 
             match action {
-                RunnerAction::Quit => return Ok(()),
+                RunnerAction::Quit => return shutdown(terminal, &mut runner),
                 RunnerAction::Redraw => {
                     terminal.draw(|frame| {
                         let _ = runner.render(frame);
@@ -209,6 +278,38 @@ fn run_loop<A: CoordinatorApp>(
     }
 }
 
+/// Runs the app's shutdown hooks, showing a notice if they take a while.
+fn shutdown<A: CoordinatorApp>(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    runner: &mut Runner<A>,
+) -> io::Result<()> {
+    let mut draw_result = Ok(());
+    let mut notice_drawn = false;
+    runner.shutdown(|elapsed| {
+        if notice_drawn || elapsed < SHUTDOWN_NOTICE_DELAY {
+            return;
+        }
+        notice_drawn = true;
+        draw_result = terminal.draw(draw_shutdown_notice).map(|_| ());
+    });
+    draw_result
+}
+
+fn draw_shutdown_notice(frame: &mut Frame) {
+    let area = frame.area();
+    let text = "Shutting down…";
+    let width = (text.chars().count() as u16 + 2).min(area.width);
+    let rect = Rect {
+        x: area.x + area.width.saturating_sub(width) / 2,
+        y: area.y + area.height / 2,
+        width,
+        height: 1.min(area.height),
+    };
+    frame.render_widget(Clear, area);
+    let style = Style::default().fg(Color::DarkGray);
+    frame.render_widget(Paragraph::new(format!(" {} ", text)).style(style), rect);
+}
+
 fn convert_event(event: Event) -> RunnerEvent {
     match event {
         Event::Key(key) => RunnerEvent::Keyboard(KeyboardEvent::from_crossterm(key)),
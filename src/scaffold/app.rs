@@ -0,0 +1,351 @@
+//! The [`CoordinatorApp`] that routes events to slot views.
+
+use std::collections::HashMap;
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::layout::Rect;
+use ratatui::Frame;
+
+use crate::coordinator::{CoordinatorAction, CoordinatorApp, CoordinatorEvent};
+use crate::error::LayoutResult;
+use crate::events::KeyboardEvent;
+use crate::scaffold::config::ThemeStore;
+use crate::scaffold::context::AppContext;
+use crate::scaffold::view::SlotView;
+use crate::shutdown::ShutdownHook;
+use crate::slots::Slot;
+
+/// Order slots are drawn in, and offered keys in when none captures them.
+const DRAW_ORDER: [Slot; 4] = [Slot::Header, Slot::Sidebar, Slot::Content, Slot::Footer];
+const KEY_ORDER: [Slot; 4] = [Slot::Content, Slot::Sidebar, Slot::Header, Slot::Footer];
+
+/// An app assembled by [`AppBuilder`](super::AppBuilder).
+///
+/// Keys go to a view that [captures](SlotView::is_capturing) them, else
+/// to content, sidebar, header and footer in turn until one handles them;
+/// unhandled quit keys quit. Mouse events go to the header, whose
+/// dropdowns may cover other slots, then to the slot under the pointer.
+/// Other events go to every view.
+pub struct ScaffoldApp {
+    pub(crate) ctx: AppContext,
+    pub(crate) views: HashMap<Slot, Box<dyn SlotView>>,
+    pub(crate) quit_keys: Vec<(KeyCode, KeyModifiers)>,
+    pub(crate) theme_store: Option<ThemeStore>,
+    pub(crate) shutdown_hooks: Vec<Box<dyn ShutdownHook>>,
+}
+
+impl ScaffoldApp {
+    /// State shared with the views.
+    pub fn context(&self) -> &AppContext {
+        &self.ctx
+    }
+
+    /// Mutable access to the state shared with the views.
+    pub fn context_mut(&mut self) -> &mut AppContext {
+        &mut self.ctx
+    }
+
+    fn is_quit_key(&self, key: &KeyboardEvent) -> bool {
+        key.is_key_down()
+            && self
+                .quit_keys
+                .iter()
+                .any(|&(code, modifiers)| key.key_code == code && key.modifiers == modifiers)
+    }
+
+    /// Offers `event` to the views of `slots` in order until one handles it.
+    fn offer(
+        &mut self,
+        event: &CoordinatorEvent,
+        slots: impl IntoIterator<Item = Slot>,
+    ) -> CoordinatorAction {
+        for slot in slots {
+            if !self.ctx.slots.is_visible(slot) {
+                continue;
+            }
+            if let Some(view) = self.views.get_mut(&slot) {
+                let action = view.handle_event(event, &mut self.ctx);
+                if action != CoordinatorAction::Continue {
+                    return action;
+                }
+            }
+        }
+        CoordinatorAction::Continue
+    }
+
+    /// Gives `event` to every view, quitting or redrawing if any asks to.
+    fn broadcast(&mut self, event: &CoordinatorEvent) -> CoordinatorAction {
+        let mut result = CoordinatorAction::Continue;
+        for slot in DRAW_ORDER {
+            if let Some(view) = self.views.get_mut(&slot) {
+                match view.handle_event(event, &mut self.ctx) {
+                    CoordinatorAction::Quit => result = CoordinatorAction::Quit,
+                    CoordinatorAction::Redraw if result == CoordinatorAction::Continue => {
+                        result = CoordinatorAction::Redraw;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        result
+    }
+}
+
+fn contains(area: Rect, x: u16, y: u16) -> bool {
+    x >= area.x && x < area.x + area.width && y >= area.y && y < area.y + area.height
+}
+
+impl CoordinatorApp for ScaffoldApp {
+    fn on_event(&mut self, event: CoordinatorEvent) -> LayoutResult<CoordinatorAction> {
+        let action = match &event {
+            CoordinatorEvent::Keyboard(key) => {
+                let capturing = DRAW_ORDER.into_iter().filter(|slot| {
+                    self.ctx.slots.is_visible(*slot)
+                        && self.views.get(slot).is_some_and(|view| view.is_capturing())
+                });
+                let order: Vec<Slot> = capturing.chain(KEY_ORDER).collect();
+                match self.offer(&event, order) {
+                    CoordinatorAction::Continue if self.is_quit_key(key) => CoordinatorAction::Quit,
+                    action => action,
+                }
+            }
+            CoordinatorEvent::Mouse(mouse) => {
+                let (x, y) = mouse.position();
+                let under = [Slot::Content, Slot::Sidebar, Slot::Footer]
+                    .into_iter()
+                    .find(|&slot| contains(self.ctx.area(slot), x, y));
+                self.offer(&event, std::iter::once(Slot::Header).chain(under))
+            }
+            _ => self.broadcast(&event),
+        };
+        Ok(action)
+    }
+
+    fn on_draw(&mut self, frame: &mut Frame) {
+        self.ctx.areas = self.ctx.slots.split(frame.area());
+        for slot in DRAW_ORDER {
+            let area = self.ctx.area(slot);
+            if area.is_empty() {
+                continue;
+            }
+            if let Some(view) = self.views.get_mut(&slot) {
+                view.render(frame, area, &self.ctx);
+            }
+        }
+        for slot in DRAW_ORDER {
+            if self.ctx.area(slot).is_empty() {
+                continue;
+            }
+            if let Some(view) = self.views.get_mut(&slot) {
+                view.render_overlays(frame, &self.ctx);
+            }
+        }
+    }
+
+    fn shutdown_hooks(&mut self) -> Vec<&mut dyn ShutdownHook> {
+        let mut hooks: Vec<&mut dyn ShutdownHook> = Vec::new();
+        if let Some(store) = &mut self.theme_store {
+            store.current = self.ctx.theme.clone();
+            hooks.push(store);
+        }
+        hooks.extend(
+            self.shutdown_hooks
+                .iter_mut()
+                .map(|hook| hook.as_mut() as &mut dyn ShutdownHook),
+        );
+        hooks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use crossterm::event::{KeyEvent, KeyEventKind};
+    use ratatui::backend::TestBackend;
+    use ratatui::widgets::Paragraph;
+    use ratatui::Terminal;
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::core::{Runner, RunnerAction, RunnerEvent};
+    use crate::events::ResizeEvent;
+    use crate::scaffold::{view_fn, AppBuilder, AppConfig};
+
+    /// Counts `+` presses and shows the count.
+    struct Counter;
+
+    impl SlotView for Counter {
+        fn render(&mut self, frame: &mut Frame, area: Rect, ctx: &AppContext) {
+            let count = ctx.config::<Count>().map_or(0, |count| count.0);
+            frame.render_widget(Paragraph::new(format!("count {count}")), area);
+        }
+
+        fn handle_event(
+            &mut self,
+            event: &CoordinatorEvent,
+            ctx: &mut AppContext,
+        ) -> CoordinatorAction {
+            match event {
+                CoordinatorEvent::Keyboard(key) if key.is_char('+') => {
+                    if let Some(count) = ctx.config_mut::<Count>() {
+                        count.0 += 1;
+                    }
+                    CoordinatorAction::Redraw
+                }
+                CoordinatorEvent::Keyboard(key) if key.is_char('t') => {
+                    ctx.set_theme("nord");
+                    CoordinatorAction::Redraw
+                }
+                _ => CoordinatorAction::Continue,
+            }
+        }
+    }
+
+    #[derive(Default)]
+    struct Count(u32);
+
+    impl AppConfig for Count {
+        const FILE_NAME: &'static str = "count";
+
+        fn parse(text: &str) -> Result<Self, Box<dyn Error + Send + Sync>> {
+            Ok(Count(text.trim().parse()?))
+        }
+    }
+
+    fn key(c: char) -> RunnerEvent {
+        RunnerEvent::Keyboard(KeyboardEvent::from_crossterm(KeyEvent::new_with_kind(
+            KeyCode::Char(c),
+            KeyModifiers::NONE,
+            KeyEventKind::Press,
+        )))
+    }
+
+    fn runner(app: ScaffoldApp) -> Runner<ScaffoldApp> {
+        let mut runner = Runner::new(app);
+        runner
+            .handle_event(RunnerEvent::Resize(ResizeEvent::new(20, 6)))
+            .unwrap();
+        runner
+    }
+
+    fn draw(runner: &mut Runner<ScaffoldApp>) -> Vec<String> {
+        let mut terminal = Terminal::new(TestBackend::new(20, 6)).unwrap();
+        terminal
+            .draw(|frame| runner.render(frame).unwrap())
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..6)
+            .map(|y| {
+                (0..20)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn slots_are_laid_out_and_unhandled_quit_keys_quit() {
+        let dir = tempdir().unwrap();
+        std::fs::write(dir.path().join("count"), "41\n").unwrap();
+        let app = AppBuilder::new("test")
+            .config_dir(dir.path())
+            .with_config::<Count>()
+            .slot_header(view_fn(
+                |frame: &mut Frame, area: Rect, ctx: &AppContext| {
+                    frame.render_widget(Paragraph::new(ctx.name().to_string()), area);
+                },
+            ))
+            .slot_footer(view_fn(|frame: &mut Frame, area: Rect, _: &AppContext| {
+                frame.render_widget(Paragraph::new("q quit"), area);
+            }))
+            .content(Counter)
+            .build()
+            .unwrap();
+        let mut runner = runner(app);
+        assert_eq!(
+            draw(&mut runner),
+            ["test", "count 41", "", "", "", "q quit"]
+        );
+
+        assert_eq!(runner.handle_event(key('+')).unwrap(), RunnerAction::Redraw);
+        assert_eq!(
+            draw(&mut runner),
+            ["test", "count 42", "", "", "", "q quit"]
+        );
+
+        runner
+            .coordinator_mut()
+            .app_mut()
+            .context_mut()
+            .toggle_slot(Slot::Header);
+        assert_eq!(draw(&mut runner), ["count 42", "", "", "", "", "q quit"]);
+
+        assert_eq!(
+            runner.handle_event(key('x')).unwrap(),
+            RunnerAction::Continue
+        );
+        assert_eq!(runner.handle_event(key('q')).unwrap(), RunnerAction::Quit);
+    }
+
+    #[test]
+    fn app_macro_builds_a_runnable_app() {
+        let dir = tempdir().unwrap();
+        let app = crate::app!(
+            @builder "noop",
+            view_fn(|_: &mut Frame, _: Rect, _: &AppContext| {}),
+            config_dir = dir.path(),
+            quit_keys = [(KeyCode::Char('x'), KeyModifiers::NONE)],
+        )
+        .build()
+        .unwrap();
+        assert_eq!(app.context().name(), "noop");
+
+        let mut runner = runner(app);
+        assert_eq!(draw(&mut runner), ["", "", "", "", "", ""]);
+        assert_eq!(
+            runner.handle_event(key('q')).unwrap(),
+            RunnerAction::Continue
+        );
+        assert_eq!(runner.handle_event(key('x')).unwrap(), RunnerAction::Quit);
+        assert!(runner.shutdown(|_| {}).is_complete());
+    }
+
+    #[test]
+    fn theme_is_restored_from_the_last_run() {
+        let dir = tempdir().unwrap();
+        let build = || {
+            AppBuilder::new("test")
+                .config_dir(dir.path())
+                .with_theme_persistence()
+                .default_theme("gruvbox")
+                .content(Counter)
+                .build()
+                .unwrap()
+        };
+
+        let mut runner = runner(build());
+        assert_eq!(
+            runner.coordinator().app().context().theme(),
+            Some("gruvbox")
+        );
+        runner.handle_event(key('t')).unwrap();
+        assert!(runner.shutdown(|_| {}).is_complete());
+
+        let app = build();
+        assert_eq!(app.context().theme(), Some("nord"));
+
+        // A config that does not parse is an error.
+        std::fs::write(dir.path().join("count"), "many").unwrap();
+        let result = AppBuilder::new("test")
+            .config_dir(dir.path())
+            .with_config::<Count>()
+            .build();
+        assert_eq!(
+            result.err().map(|e| e.kind()),
+            Some(std::io::ErrorKind::InvalidData)
+        );
+    }
+}
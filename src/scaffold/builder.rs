@@ -0,0 +1,239 @@
+//! The builder assembling a [`ScaffoldApp`] and running it.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::layout::Constraint;
+
+use crate::core::RunnerConfig;
+use crate::runner_helper::{run, run_with_diagnostics};
+use crate::scaffold::app::ScaffoldApp;
+use crate::scaffold::config::{default_config_dir, load_config, AppConfig, ThemeStore};
+use crate::scaffold::context::AppContext;
+use crate::scaffold::view::SlotView;
+use crate::shutdown::ShutdownHook;
+use crate::slots::{Slot, SlotAreas, SlotLayout, SlotSpec};
+
+type ConfigLoader = Box<dyn FnOnce(Option<&Path>) -> io::Result<Box<dyn Any>>>;
+type PanicHandler = Box<dyn Fn(&str) + Send + Sync>;
+
+/// Builds a [`ScaffoldApp`] from slot views and runs it.
+///
+/// By default the app quits on `q` or `Ctrl+C` when no view handles the
+/// key, header and footer are one row tall and the sidebar takes a
+/// quarter of the width. Slots without a view are left out.
+pub struct AppBuilder {
+    name: String,
+    config_dir: Option<PathBuf>,
+    config_loader: Option<ConfigLoader>,
+    theme_persistence: bool,
+    default_theme: Option<String>,
+    spec: Option<SlotSpec>,
+    views: HashMap<Slot, Box<dyn SlotView>>,
+    quit_keys: Vec<(KeyCode, KeyModifiers)>,
+    runner_config: RunnerConfig,
+    diagnostics: bool,
+    panic_handler: Option<PanicHandler>,
+    shutdown_hooks: Vec<Box<dyn ShutdownHook>>,
+}
+
+impl AppBuilder {
+    /// Starts an app called `name`, which also names its config directory.
+    pub fn new(name: impl Into<String>) -> Self {
+        let name = name.into();
+        Self {
+            config_dir: default_config_dir(&name),
+            name,
+            config_loader: None,
+            theme_persistence: false,
+            default_theme: None,
+            spec: None,
+            views: HashMap::new(),
+            quit_keys: vec![
+                (KeyCode::Char('q'), KeyModifiers::NONE),
+                (KeyCode::Char('c'), KeyModifiers::CONTROL),
+            ],
+            runner_config: RunnerConfig::default(),
+            diagnostics: false,
+            panic_handler: None,
+            shutdown_hooks: Vec::new(),
+        }
+    }
+
+    /// Reads config and theme files from `dir` instead of
+    /// [`default_config_dir`](super::default_config_dir).
+    pub fn config_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.config_dir = Some(dir.into());
+        self
+    }
+
+    /// Loads a `C` from [`C::FILE_NAME`](AppConfig::FILE_NAME) in the config
+    /// directory when the app is built, falling back to its default if the
+    /// file does not exist. Views read it with [`AppContext::config`].
+    pub fn with_config<C: AppConfig>(mut self) -> Self {
+        self.config_loader = Some(Box::new(load_config::<C>));
+        self
+    }
+
+    /// Restores the theme name saved in the config directory on start, and
+    /// saves [`AppContext::theme`] there on shutdown.
+    pub fn with_theme_persistence(mut self) -> Self {
+        self.theme_persistence = true;
+        self
+    }
+
+    /// Theme used when none was persisted.
+    pub fn default_theme(mut self, name: impl Into<String>) -> Self {
+        self.default_theme = Some(name.into());
+        self
+    }
+
+    /// Shows `view` in the header slot.
+    pub fn slot_header(self, view: impl SlotView + 'static) -> Self {
+        self.slot(Slot::Header, view)
+    }
+
+    /// Shows `view` in the footer slot.
+    pub fn slot_footer(self, view: impl SlotView + 'static) -> Self {
+        self.slot(Slot::Footer, view)
+    }
+
+    /// Shows `view` in the sidebar slot.
+    pub fn slot_sidebar(self, view: impl SlotView + 'static) -> Self {
+        self.slot(Slot::Sidebar, view)
+    }
+
+    /// Shows `view` in the content slot.
+    pub fn content(self, view: impl SlotView + 'static) -> Self {
+        self.slot(Slot::Content, view)
+    }
+
+    /// Shows `view` in `slot`, replacing any view already there.
+    pub fn slot(mut self, slot: Slot, view: impl SlotView + 'static) -> Self {
+        self.views.insert(slot, Box::new(view));
+        self
+    }
+
+    /// Sets the slot sizes instead of the defaults.
+    pub fn slots(mut self, spec: SlotSpec) -> Self {
+        self.spec = Some(spec);
+        self
+    }
+
+    /// Keys that quit the app when no view handles them, replacing `q` and
+    /// `Ctrl+C`. An empty list leaves quitting to the views.
+    pub fn quit_keys(mut self, keys: impl IntoIterator<Item = (KeyCode, KeyModifiers)>) -> Self {
+        self.quit_keys = keys.into_iter().collect();
+        self
+    }
+
+    /// Sets the runner's tick rate, debounce and shutdown timeout.
+    pub fn runner_config(mut self, config: RunnerConfig) -> Self {
+        self.runner_config = config;
+        self
+    }
+
+    /// Draws the FPS and mouse diagnostics overlay.
+    pub fn diagnostics(mut self, enabled: bool) -> Self {
+        self.diagnostics = enabled;
+        self
+    }
+
+    /// Calls `handler` with the panic message after the terminal has been
+    /// restored and the default hook has printed it.
+    pub fn on_panic(mut self, handler: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        self.panic_handler = Some(Box::new(handler));
+        self
+    }
+
+    /// Adds a hook that runs after the app quits; see [`ShutdownHook`].
+    pub fn on_shutdown(mut self, hook: impl ShutdownHook + 'static) -> Self {
+        self.shutdown_hooks.push(Box::new(hook));
+        self
+    }
+
+    /// Slot sizes for the slots that have views.
+    fn default_spec(&self) -> SlotSpec {
+        let row = |slot| {
+            if self.views.contains_key(&slot) {
+                Constraint::Length(1)
+            } else {
+                Constraint::Length(0)
+            }
+        };
+        SlotSpec {
+            header: row(Slot::Header),
+            footer: row(Slot::Footer),
+            sidebar: self
+                .views
+                .contains_key(&Slot::Sidebar)
+                .then_some(Constraint::Percentage(25)),
+            content: Constraint::Fill(1),
+        }
+    }
+
+    /// Loads the config and persisted theme and assembles the app without
+    /// running it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config file cannot be read or parsed.
+    pub fn build(self) -> io::Result<ScaffoldApp> {
+        let spec = self.spec.unwrap_or_else(|| self.default_spec());
+        let dir = self.config_dir.as_deref();
+        let config = self.config_loader.map(|load| load(dir)).transpose()?;
+        let theme_store = if self.theme_persistence {
+            dir.map(ThemeStore::open)
+        } else {
+            None
+        };
+        let theme = theme_store
+            .as_ref()
+            .and_then(|store| store.loaded().map(str::to_string))
+            .or(self.default_theme);
+
+        let ctx = AppContext {
+            name: self.name,
+            config,
+            slots: SlotLayout::new(spec),
+            areas: SlotAreas::default(),
+            theme,
+        };
+        Ok(ScaffoldApp {
+            ctx,
+            views: self.views,
+            quit_keys: self.quit_keys,
+            theme_store,
+            shutdown_hooks: self.shutdown_hooks,
+        })
+    }
+
+    /// Builds the app and runs it until it quits.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config cannot be loaded or the terminal
+    /// fails.
+    pub fn run(mut self) -> io::Result<()> {
+        if let Some(handler) = self.panic_handler.take() {
+            // The runner's hook, installed later, restores the terminal
+            // before calling this one.
+            let previous = std::panic::take_hook();
+            std::panic::set_hook(Box::new(move |info| {
+                previous(info);
+                handler(&info.to_string());
+            }));
+        }
+        let config = self.runner_config;
+        let diagnostics = self.diagnostics;
+        let app = self.build()?;
+        if diagnostics {
+            run_with_diagnostics(app, config)
+        } else {
+            run(app, config)
+        }
+    }
+}
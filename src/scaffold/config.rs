@@ -0,0 +1,181 @@
+//! Config and theme files under the app's config directory.
+
+use std::any::Any;
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::shutdown::{ShutdownAction, ShutdownHook};
+
+/// File the theme name is persisted in, under the config directory.
+const THEME_FILE: &str = "theme";
+
+/// App settings loaded by [`AppBuilder::with_config`](super::AppBuilder::with_config).
+///
+/// Parsing is left to the app, so the builder does not depend on a config
+/// format. With `serde` and `toml` in the app:
+///
+/// ```rust,ignore
+/// impl AppConfig for MyConfig {
+///     fn parse(text: &str) -> Result<Self, Box<dyn Error + Send + Sync>> {
+///         Ok(toml::from_str(text)?)
+///     }
+/// }
+/// ```
+pub trait AppConfig: Default + 'static {
+    /// File name under the config directory.
+    const FILE_NAME: &'static str = "config.toml";
+
+    /// Parses the file's contents.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the contents are not a valid config.
+    fn parse(text: &str) -> Result<Self, Box<dyn Error + Send + Sync>>;
+}
+
+/// Config directory of the app `name`: `$XDG_CONFIG_HOME/name`, else
+/// `~/.config/name`, else `%APPDATA%\name`. `None` if none of these are
+/// set.
+pub fn default_config_dir(name: &str) -> Option<PathBuf> {
+    let non_empty = |var: &str| std::env::var_os(var).filter(|value| !value.is_empty());
+    let base = non_empty("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| non_empty("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .or_else(|| non_empty("APPDATA").map(PathBuf::from))?;
+    Some(base.join(name))
+}
+
+/// Loads `C` from its file in `dir`, or its default if there is no
+/// directory or file.
+pub(crate) fn load_config<C: AppConfig>(dir: Option<&Path>) -> io::Result<Box<dyn Any>> {
+    let Some(path) = dir.map(|dir| dir.join(C::FILE_NAME)) else {
+        return Ok(Box::new(C::default()));
+    };
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Box::new(C::default())),
+        Err(e) => return Err(e),
+    };
+    let config = C::parse(&text).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {}", path.display(), e),
+        )
+    })?;
+    Ok(Box::new(config))
+}
+
+/// The persisted theme name, saved on shutdown when it changed.
+#[derive(Debug)]
+pub(crate) struct ThemeStore {
+    path: PathBuf,
+    loaded: Option<String>,
+    /// Theme to save, set before the hook is polled.
+    pub(crate) current: Option<String>,
+}
+
+impl ThemeStore {
+    /// Reads the theme name persisted in `dir`, if any.
+    pub(crate) fn open(dir: &Path) -> Self {
+        let path = dir.join(THEME_FILE);
+        let loaded = fs::read_to_string(&path)
+            .ok()
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty());
+        Self {
+            path,
+            current: loaded.clone(),
+            loaded,
+        }
+    }
+
+    /// The persisted theme name.
+    pub(crate) fn loaded(&self) -> Option<&str> {
+        self.loaded.as_deref()
+    }
+}
+
+impl ShutdownHook for ThemeStore {
+    fn on_shutdown(&mut self) -> ShutdownAction {
+        if self.current == self.loaded {
+            return ShutdownAction::Done;
+        }
+        let Some(name) = &self.current else {
+            return ShutdownAction::Done;
+        };
+        let result = self
+            .path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|()| fs::write(&self.path, format!("{}\n", name)));
+        match result {
+            Ok(()) => self.loaded = self.current.clone(),
+            Err(e) => tracing::warn!("failed to save theme to {}: {e}", self.path.display()),
+        }
+        ShutdownAction::Done
+    }
+
+    fn shutdown_name(&self) -> &str {
+        "theme persistence"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use super::*;
+
+    #[derive(Debug, Default, PartialEq)]
+    struct Width(u16);
+
+    impl AppConfig for Width {
+        const FILE_NAME: &'static str = "width";
+
+        fn parse(text: &str) -> Result<Self, Box<dyn Error + Send + Sync>> {
+            Ok(Width(text.trim().parse()?))
+        }
+    }
+
+    fn load(dir: Option<&Path>) -> io::Result<Width> {
+        load_config::<Width>(dir).map(|config| *config.downcast::<Width>().unwrap())
+    }
+
+    #[test]
+    fn missing_config_loads_the_default() {
+        let dir = tempdir().unwrap();
+        assert_eq!(load(Some(dir.path())).unwrap(), Width::default());
+        assert_eq!(load(None).unwrap(), Width::default());
+
+        fs::write(dir.path().join("width"), "80\n").unwrap();
+        assert_eq!(load(Some(dir.path())).unwrap(), Width(80));
+    }
+
+    #[test]
+    fn malformed_config_is_an_error() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("width"), "wide").unwrap();
+        let error = load(Some(dir.path())).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().contains("width"), "{error}");
+    }
+
+    #[test]
+    fn theme_store_saves_on_shutdown_and_reads_it_back() {
+        let dir = tempdir().unwrap();
+        let dir = dir.path().join("app");
+        let mut store = ThemeStore::open(&dir);
+        assert_eq!(store.loaded(), None);
+
+        store.current = Some("nord".to_string());
+        assert_eq!(store.on_shutdown(), ShutdownAction::Done);
+        assert_eq!(ThemeStore::open(&dir).loaded(), Some("nord"));
+
+        // An unchanged theme is not written again.
+        fs::write(dir.join(THEME_FILE), "gruvbox").unwrap();
+        store.on_shutdown();
+        assert_eq!(ThemeStore::open(&dir).loaded(), Some("gruvbox"));
+    }
+}
@@ -0,0 +1,67 @@
+//! State shared by the slot views of a scaffolded app.
+
+use std::any::Any;
+
+use ratatui::layout::Rect;
+
+use crate::slots::{Slot, SlotAreas, SlotLayout};
+
+/// The app's name, config, slot layout and theme, passed to every
+/// [`SlotView`](super::SlotView).
+pub struct AppContext {
+    pub(crate) name: String,
+    pub(crate) config: Option<Box<dyn Any>>,
+    pub(crate) slots: SlotLayout,
+    pub(crate) areas: SlotAreas,
+    pub(crate) theme: Option<String>,
+}
+
+impl AppContext {
+    /// The name given to [`AppBuilder::new`](super::AppBuilder::new).
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The config loaded by [`AppBuilder::with_config`](super::AppBuilder::with_config),
+    /// or `None` if none was loaded or it is not a `C`.
+    pub fn config<C: 'static>(&self) -> Option<&C> {
+        self.config.as_ref()?.downcast_ref()
+    }
+
+    /// Mutable access to the loaded config.
+    pub fn config_mut<C: 'static>(&mut self) -> Option<&mut C> {
+        self.config.as_mut()?.downcast_mut()
+    }
+
+    /// The slot sizes and which slots are hidden.
+    pub fn slots(&self) -> &SlotLayout {
+        &self.slots
+    }
+
+    /// Shows or hides `slot`. Returns whether that changed anything.
+    pub fn set_slot_visible(&mut self, slot: Slot, visible: bool) -> bool {
+        self.slots.set_visible(slot, visible)
+    }
+
+    /// Shows `slot` if hidden, or hides it. Returns whether it is now shown.
+    pub fn toggle_slot(&mut self, slot: Slot) -> bool {
+        self.slots.toggle(slot)
+    }
+
+    /// The rect `slot` was drawn in on the last frame.
+    pub fn area(&self, slot: Slot) -> Rect {
+        self.areas.get(slot)
+    }
+
+    /// Name of the current theme, if one was set or restored.
+    pub fn theme(&self) -> Option<&str> {
+        self.theme.as_deref()
+    }
+
+    /// Sets the current theme by name. With
+    /// [`with_theme_persistence`](super::AppBuilder::with_theme_persistence)
+    /// it is saved on shutdown and restored on the next start.
+    pub fn set_theme(&mut self, name: impl Into<String>) {
+        self.theme = Some(name.into());
+    }
+}
@@ -0,0 +1,195 @@
+//! Slot views for the footer and menu bar widgets.
+//!
+//! Each adapter is compiled only with its widget's feature.
+
+#[cfg(feature = "hotkey-footer")]
+pub use hotkey_footer::HotkeyFooterElement;
+#[cfg(feature = "menu-bar")]
+pub use menu_bar::MenuBarElement;
+
+#[cfg(feature = "hotkey-footer")]
+mod hotkey_footer {
+    use ratatui::layout::Rect;
+    use ratatui::Frame;
+
+    use crate::scaffold::context::AppContext;
+    use crate::scaffold::view::SlotView;
+    use crate::widgets::hotkey_footer::{HotkeyFooter, HotkeyItem};
+
+    /// A [`HotkeyFooter`] in a slot.
+    #[derive(Clone, Debug)]
+    pub struct HotkeyFooterElement {
+        footer: HotkeyFooter,
+    }
+
+    impl HotkeyFooterElement {
+        /// Shows `items` with the footer's default colors.
+        pub fn new(items: Vec<HotkeyItem>) -> Self {
+            Self::from(HotkeyFooter::new(items))
+        }
+
+        /// Shows the hotkeys of `registry` active in `scope`; see
+        /// [`HotkeyFooter::from_registry`].
+        #[cfg(feature = "hotkey-service")]
+        pub fn from_registry(
+            registry: &crate::services::hotkey_service::HotkeyRegistry,
+            scope: &crate::services::hotkey_service::HotkeyScope,
+        ) -> Self {
+            Self::from(HotkeyFooter::from_registry(registry, scope))
+        }
+
+        /// The footer shown.
+        pub fn footer(&self) -> &HotkeyFooter {
+            &self.footer
+        }
+
+        /// Mutable access to the footer, e.g. to change its items.
+        pub fn footer_mut(&mut self) -> &mut HotkeyFooter {
+            &mut self.footer
+        }
+    }
+
+    impl From<HotkeyFooter> for HotkeyFooterElement {
+        fn from(footer: HotkeyFooter) -> Self {
+            Self { footer }
+        }
+    }
+
+    impl SlotView for HotkeyFooterElement {
+        fn render(&mut self, frame: &mut Frame, area: Rect, _ctx: &AppContext) {
+            self.footer.render(frame, area);
+        }
+    }
+}
+
+#[cfg(feature = "menu-bar")]
+mod menu_bar {
+    use crossterm::event::{KeyEvent, MouseEvent, MouseEventKind};
+    use ratatui::layout::Rect;
+    use ratatui::Frame;
+
+    use crate::coordinator::{CoordinatorAction, CoordinatorEvent};
+    use crate::primitives::menu_bar::{MenuBar, MenuEvent, MenuItem};
+    use crate::scaffold::context::AppContext;
+    use crate::scaffold::view::SlotView;
+
+    type SelectHandler = Box<dyn FnMut(usize, &mut AppContext) -> CoordinatorAction>;
+
+    /// A [`MenuBar`] in a slot, with its dropdowns drawn above every slot.
+    ///
+    /// Keys reach the bar when no other view handles them, or first while
+    /// a dropdown is open. Choosing an item runs its action and then the
+    /// [`on_select`](Self::on_select) handler with the item's value.
+    pub struct MenuBarElement {
+        menu: MenuBar,
+        on_select: Option<SelectHandler>,
+        area: Rect,
+    }
+
+    impl MenuBarElement {
+        /// Shows a bar of `items`.
+        pub fn new(items: Vec<MenuItem>) -> Self {
+            Self::from(MenuBar::new(items))
+        }
+
+        /// Calls `handler` with the value of each chosen item; its result
+        /// is the app's next action, so it can quit.
+        pub fn on_select(
+            mut self,
+            handler: impl FnMut(usize, &mut AppContext) -> CoordinatorAction + 'static,
+        ) -> Self {
+            self.on_select = Some(Box::new(handler));
+            self
+        }
+
+        /// The bar shown.
+        pub fn menu(&self) -> &MenuBar {
+            &self.menu
+        }
+
+        /// Mutable access to the bar.
+        pub fn menu_mut(&mut self) -> &mut MenuBar {
+            &mut self.menu
+        }
+
+        fn dispatch(&mut self, event: MenuEvent, ctx: &mut AppContext) -> CoordinatorAction {
+            match event {
+                MenuEvent::Selected { value, action, .. } => {
+                    if let Some(action) = action {
+                        action();
+                    }
+                    match &mut self.on_select {
+                        Some(handler) => match handler(value, ctx) {
+                            CoordinatorAction::Continue => CoordinatorAction::Redraw,
+                            action => action,
+                        },
+                        None => CoordinatorAction::Redraw,
+                    }
+                }
+                _ => CoordinatorAction::Redraw,
+            }
+        }
+    }
+
+    impl From<MenuBar> for MenuBarElement {
+        fn from(menu: MenuBar) -> Self {
+            Self {
+                menu,
+                on_select: None,
+                area: Rect::default(),
+            }
+        }
+    }
+
+    impl SlotView for MenuBarElement {
+        fn render(&mut self, frame: &mut Frame, area: Rect, _ctx: &AppContext) {
+            self.area = area;
+            self.menu.render(frame, area);
+        }
+
+        fn render_overlays(&mut self, frame: &mut Frame, _ctx: &AppContext) {
+            self.menu.render_dropdowns(frame);
+        }
+
+        fn handle_event(
+            &mut self,
+            event: &CoordinatorEvent,
+            ctx: &mut AppContext,
+        ) -> CoordinatorAction {
+            let was_open = self.menu.is_open();
+            let menu_event =
+                match event {
+                    CoordinatorEvent::Keyboard(key) => self.menu.handle_key(
+                        KeyEvent::new_with_kind(key.key_code, key.modifiers, key.kind),
+                    ),
+                    CoordinatorEvent::Mouse(mouse) => {
+                        let over_bar = mouse.column >= self.area.x
+                            && mouse.column < self.area.x + self.area.width
+                            && mouse.row >= self.area.y
+                            && mouse.row < self.area.y + self.area.height;
+                        let event = self.menu.handle_mouse_event(MouseEvent {
+                            kind: mouse.kind,
+                            column: mouse.column,
+                            row: mouse.row,
+                            modifiers: mouse.modifiers,
+                        });
+                        if event.is_none() && over_bar && mouse.kind == MouseEventKind::Moved {
+                            // Hovering changes the highlighted item
+                            return CoordinatorAction::Redraw;
+                        }
+                        event
+                    }
+                    _ => return CoordinatorAction::Continue,
+                };
+            match menu_event {
+                Some(menu_event) => self.dispatch(menu_event, ctx),
+                None if was_open || self.menu.is_open() => CoordinatorAction::Redraw,
+                None => CoordinatorAction::Continue,
+            }
+        }
+
+        fn is_capturing(&self) -> bool {
+            self.menu.is_open()
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! High-level builder that wires a ratkit app from its slots.
+//!
+//! [`AppBuilder`] sets up the terminal runner, lays the screen out as
+//! header, footer, sidebar and content [slots](crate::Slot), loads the
+//! app's config and persisted theme, chains a panic hook and runs shutdown
+//! hooks. Each slot holds a [`SlotView`]; the builder routes events to
+//! them and draws them in their slot's rect.
+//!
+//! Every piece can be replaced: [`AppBuilder::slots`] changes the slot
+//! sizes, [`AppBuilder::quit_keys`] the quit keys and
+//! [`AppBuilder::runner_config`] the runner; [`AppBuilder::build`] returns
+//! the [`CoordinatorApp`](crate::CoordinatorApp) for apps that drive the
+//! runner themselves.
+//!
+//! The builder only needs the core runtime. Adapters for feature-gated
+//! widgets, such as [`HotkeyFooterElement`] with `hotkey-footer` and
+//! [`MenuBarElement`] with `menu-bar`, are compiled with their widget.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use ratatui::{layout::Rect, widgets::Paragraph, Frame};
+//! use ratkit::scaffold::{view_fn, AppBuilder, AppContext};
+//!
+//! fn main() -> std::io::Result<()> {
+//!     AppBuilder::new("myapp")
+//!         .slot_header(view_fn(|frame: &mut Frame, area: Rect, _: &AppContext| {
+//!             frame.render_widget(Paragraph::new(" myapp"), area);
+//!         }))
+//!         .content(view_fn(|frame: &mut Frame, area: Rect, _: &AppContext| {
+//!             frame.render_widget(Paragraph::new("Hello! Press q to quit."), area);
+//!         }))
+//!         .run()
+//! }
+//! ```
+//!
+//! The [`app!`](crate::app) macro shortens the same thing to one call.
+
+mod app;
+mod builder;
+mod config;
+mod context;
+mod elements;
+mod view;
+
+pub use app::ScaffoldApp;
+pub use builder::AppBuilder;
+pub use config::{default_config_dir, AppConfig};
+pub use context::AppContext;
+#[cfg(feature = "hotkey-footer")]
+pub use elements::HotkeyFooterElement;
+#[cfg(feature = "menu-bar")]
+pub use elements::MenuBarElement;
+pub use view::{view_fn, FnView, SlotView};
+
+/// Builds and runs an app from its content view and optional slots.
+///
+/// Expands to an [`AppBuilder`] chain: every `method = value` pair calls
+/// that builder method, and the result is run.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use ratatui::{layout::Rect, widgets::Paragraph, Frame};
+/// use ratkit::scaffold::{view_fn, AppContext};
+///
+/// fn main() -> std::io::Result<()> {
+///     ratkit::app!(
+///         "myapp",
+///         view_fn(|frame: &mut Frame, area: Rect, _: &AppContext| {
+///             frame.render_widget(Paragraph::new("Hello!"), area);
+///         }),
+///         slot_header = view_fn(|frame: &mut Frame, area: Rect, _: &AppContext| {
+///             frame.render_widget(Paragraph::new(" myapp"), area);
+///         }),
+///     )
+/// }
+/// ```
+#[macro_export]
+macro_rules! app {
+    // The builder chain without running it, for tests.
+    (@builder $name:expr, $content:expr $(, $method:ident = $value:expr)* $(,)?) => {
+        $crate::scaffold::AppBuilder::new($name)
+            $(.$method($value))*
+            .content($content)
+    };
+    ($name:expr, $content:expr $(, $method:ident = $value:expr)* $(,)?) => {
+        $crate::app!(@builder $name, $content $(, $method = $value)*).run()
+    };
+}
@@ -0,0 +1,56 @@
+//! What a slot shows and how it reacts to events.
+
+use ratatui::layout::Rect;
+use ratatui::Frame;
+
+use crate::coordinator::{CoordinatorAction, CoordinatorEvent};
+use crate::scaffold::context::AppContext;
+
+/// Content of a slot of a [`ScaffoldApp`](super::ScaffoldApp).
+pub trait SlotView {
+    /// Draws the view into its slot's rect.
+    fn render(&mut self, frame: &mut Frame, area: Rect, ctx: &AppContext);
+
+    /// Draws anything that goes above every slot, such as dropdowns.
+    ///
+    /// Called after every slot has been rendered.
+    fn render_overlays(&mut self, _frame: &mut Frame, _ctx: &AppContext) {}
+
+    /// Handles an event.
+    ///
+    /// Return [`CoordinatorAction::Continue`] for events the view ignores,
+    /// so other slots and the quit keys can handle them.
+    fn handle_event(
+        &mut self,
+        _event: &CoordinatorEvent,
+        _ctx: &mut AppContext,
+    ) -> CoordinatorAction {
+        CoordinatorAction::Continue
+    }
+
+    /// Whether the view takes keys before every other slot, such as while
+    /// a dropdown is open.
+    fn is_capturing(&self) -> bool {
+        false
+    }
+}
+
+/// A [`SlotView`] that only draws, made with [`view_fn`].
+pub struct FnView<F>(F);
+
+/// Makes a view that draws with `render` and ignores events.
+pub fn view_fn<F>(render: F) -> FnView<F>
+where
+    F: FnMut(&mut Frame, Rect, &AppContext),
+{
+    FnView(render)
+}
+
+impl<F> SlotView for FnView<F>
+where
+    F: FnMut(&mut Frame, Rect, &AppContext),
+{
+    fn render(&mut self, frame: &mut Frame, area: Rect, ctx: &AppContext) {
+        (self.0)(frame, area, ctx);
+    }
+}
@@ -0,0 +1,135 @@
+//! Clipboard service with a history ring.
+//!
+//! Widgets copy through [`copy`] instead of talking to the system clipboard
+//! directly, so every copy also lands in a process-wide [`ClipboardRing`].
+//! The ring keeps the most recent entries with the widget they came from,
+//! coalesces consecutive duplicates and can optionally persist itself
+//! between sessions.
+//!
+//! Widgets with more than one useful thing to copy, such as a file's
+//! relative and absolute path, implement [`CopyProvider`] to list them.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use ratkit::services::clipboard::{self, ClipboardSource};
+//!
+//! clipboard::copy("cargo test", ClipboardSource::Terminal).ok();
+//! // Passwords and tokens can skip the history.
+//! clipboard::copy_sensitive("hunter2").ok();
+//!
+//! clipboard::with_ring(|ring| {
+//!     for entry in ring.entries() {
+//!         println!("{} {}", entry.source.icon(), entry.preview(40));
+//!     }
+//! });
+//! ```
+
+mod provider;
+mod ring;
+mod service;
+
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+pub use provider::{CopyPayload, CopyProvider};
+pub use ring::{ClipboardRing, DEFAULT_RING_CAPACITY};
+pub use service::{copy, copy_sensitive, recopy, with_ring};
+
+/// Widget an entry was copied from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ClipboardSource {
+    /// An embedded terminal.
+    Terminal,
+    /// A markdown document.
+    Markdown,
+    /// An AI chat transcript or input.
+    Chat,
+    /// A code diff.
+    Diff,
+    /// A dialog.
+    Dialog,
+    /// Any other widget, identified by name.
+    Other(String),
+}
+
+impl ClipboardSource {
+    /// Icon shown next to entries from this source.
+    pub fn icon(&self) -> &'static str {
+        match self {
+            ClipboardSource::Terminal => "",
+            ClipboardSource::Markdown => "",
+            ClipboardSource::Chat => "󰭹",
+            ClipboardSource::Diff => "",
+            ClipboardSource::Dialog => "󰍜",
+            ClipboardSource::Other(_) => "󰅍",
+        }
+    }
+
+    /// Human-readable name of the source.
+    pub fn label(&self) -> &str {
+        match self {
+            ClipboardSource::Terminal => "terminal",
+            ClipboardSource::Markdown => "markdown",
+            ClipboardSource::Chat => "chat",
+            ClipboardSource::Diff => "diff",
+            ClipboardSource::Dialog => "dialog",
+            ClipboardSource::Other(name) => name,
+        }
+    }
+}
+
+/// A copied text with where and when it was copied.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClipboardEntry {
+    /// The copied text.
+    pub text: String,
+    /// Widget the text was copied from.
+    pub source: ClipboardSource,
+    /// When the text was (last) copied.
+    pub copied_at: SystemTime,
+}
+
+impl ClipboardEntry {
+    /// Creates an entry copied now.
+    pub fn new(text: impl Into<String>, source: ClipboardSource) -> Self {
+        Self {
+            text: text.into(),
+            source,
+            copied_at: SystemTime::now(),
+        }
+    }
+
+    /// First line of the text, truncated to `max_chars` characters with an
+    /// ellipsis. Multi-line entries get a trailing ellipsis too.
+    pub fn preview(&self, max_chars: usize) -> String {
+        let mut lines = self.text.lines();
+        let first = lines.next().unwrap_or("").trim_end();
+        let more_lines = lines.next().is_some();
+
+        if first.chars().count() > max_chars {
+            let mut preview: String = first.chars().take(max_chars.saturating_sub(1)).collect();
+            preview.push('…');
+            preview
+        } else if more_lines {
+            format!("{first}…")
+        } else {
+            first.to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ClipboardEntry, ClipboardSource};
+
+    #[test]
+    fn preview_truncates_by_chars_and_marks_more_lines() {
+        let entry = |text| ClipboardEntry::new(text, ClipboardSource::Chat);
+
+        assert_eq!(entry("short").preview(10), "short");
+        assert_eq!(entry("héllo wörld").preview(6), "héllo…");
+        assert_eq!(entry("first\nsecond").preview(10), "first…");
+    }
+}
@@ -0,0 +1,41 @@
+//! Texts widgets offer to copy.
+
+use crate::services::clipboard::ClipboardSource;
+
+/// A text a widget offers to copy, such as a file's absolute path or the
+/// hunk under the cursor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CopyPayload {
+    /// What the text is, e.g. `"Absolute path"`. Also the kind a
+    /// [`CopyMenu`](crate::widgets::copy_menu::CopyMenu) remembers, so
+    /// widgets of one type should keep their labels stable.
+    pub label: String,
+    /// The text to copy.
+    pub text: String,
+}
+
+impl CopyPayload {
+    /// Creates a payload.
+    pub fn new(label: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            text: text.into(),
+        }
+    }
+}
+
+/// A widget that offers texts to copy from its selection or cursor.
+///
+/// Implementing this instead of a widget-specific copy key gives users one
+/// copy flow across the toolkit; see
+/// [`CopyMenu`](crate::widgets::copy_menu::CopyMenu).
+pub trait CopyProvider {
+    /// The texts that can be copied right now, most useful first. Empty
+    /// when there is nothing to copy.
+    fn copy_payloads(&self) -> Vec<CopyPayload>;
+
+    /// Source recorded in the clipboard ring for copied payloads.
+    fn copy_source(&self) -> ClipboardSource {
+        ClipboardSource::Other("widget".to_string())
+    }
+}
@@ -0,0 +1,244 @@
+//! Bounded history of copied entries.
+
+use std::collections::VecDeque;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::services::clipboard::{ClipboardEntry, ClipboardSource};
+
+/// Number of entries kept by [`ClipboardRing::default`].
+pub const DEFAULT_RING_CAPACITY: usize = 50;
+
+/// Bounded, most-recent-first history of copied entries.
+///
+/// Pushing the same text as the newest entry refreshes that entry instead of
+/// adding a duplicate. When full, the oldest entry is dropped.
+#[derive(Debug, Clone)]
+pub struct ClipboardRing {
+    entries: VecDeque<ClipboardEntry>,
+    capacity: usize,
+    persistence: Option<Persistence>,
+}
+
+#[derive(Debug, Clone)]
+struct Persistence {
+    path: PathBuf,
+    max_bytes: usize,
+}
+
+impl Default for ClipboardRing {
+    fn default() -> Self {
+        Self::new(DEFAULT_RING_CAPACITY)
+    }
+}
+
+impl ClipboardRing {
+    /// Creates an empty ring holding at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            capacity: capacity.max(1),
+            persistence: None,
+        }
+    }
+
+    /// Maximum number of entries kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Changes the capacity, dropping the oldest entries if needed.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity.max(1);
+        self.entries.truncate(self.capacity);
+    }
+
+    /// Entries from newest to oldest.
+    pub fn entries(&self) -> impl ExactSizeIterator<Item = &ClipboardEntry> {
+        self.entries.iter()
+    }
+
+    /// Entry at `index`, where 0 is the newest.
+    pub fn get(&self, index: usize) -> Option<&ClipboardEntry> {
+        self.entries.get(index)
+    }
+
+    /// Number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if nothing has been copied.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Records a copy.
+    ///
+    /// Empty text is ignored. If `text` equals the newest entry, that entry's
+    /// source and timestamp are updated instead of adding a duplicate.
+    pub fn push(&mut self, text: impl Into<String>, source: ClipboardSource) {
+        self.push_entry(ClipboardEntry::new(text, source));
+    }
+
+    /// Records an existing entry, coalescing it with the newest one.
+    pub fn push_entry(&mut self, entry: ClipboardEntry) {
+        if entry.text.is_empty() {
+            return;
+        }
+
+        match self.entries.front_mut() {
+            Some(newest) if newest.text == entry.text => *newest = entry,
+            _ => {
+                self.entries.push_front(entry);
+                self.entries.truncate(self.capacity);
+            }
+        }
+        self.save();
+    }
+
+    /// Moves the entry at `index` to the front and returns it.
+    pub fn promote(&mut self, index: usize) -> Option<ClipboardEntry> {
+        let mut entry = self.entries.remove(index)?;
+        entry.copied_at = std::time::SystemTime::now();
+        self.entries.push_front(entry.clone());
+        self.save();
+        Some(entry)
+    }
+
+    /// Removes every entry, including the persisted copy if any.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.save();
+    }
+
+    /// Persists the ring to `path`, loading any entries already saved there.
+    ///
+    /// The ring is rewritten after every change. Only the newest entries
+    /// whose text adds up to at most `max_bytes` are written. Persistence is
+    /// off unless this is called.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the existing file cannot be read or parsed.
+    pub fn enable_persistence(
+        &mut self,
+        path: impl Into<PathBuf>,
+        max_bytes: usize,
+    ) -> io::Result<()> {
+        let path = path.into();
+        match fs::read_to_string(&path) {
+            Ok(json) => {
+                let saved: Vec<ClipboardEntry> = serde_json::from_str(&json).map_err(|e| {
+                    io::Error::new(io::ErrorKind::InvalidData, format!("JSON error: {}", e))
+                })?;
+                for entry in saved.into_iter().rev() {
+                    if !self
+                        .entries
+                        .iter()
+                        .any(|existing| existing.text == entry.text)
+                    {
+                        self.entries.push_back(entry);
+                    }
+                }
+                self.entries
+                    .make_contiguous()
+                    .sort_by_key(|entry| std::cmp::Reverse(entry.copied_at));
+                self.entries.truncate(self.capacity);
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+
+        self.persistence = Some(Persistence { path, max_bytes });
+        self.save();
+        Ok(())
+    }
+
+    /// Stops persisting; the saved file is left in place.
+    pub fn disable_persistence(&mut self) {
+        self.persistence = None;
+    }
+
+    fn save(&self) {
+        let Some(persistence) = &self.persistence else {
+            return;
+        };
+
+        let mut bytes = 0;
+        let kept: Vec<&ClipboardEntry> = self
+            .entries
+            .iter()
+            .take_while(|entry| {
+                bytes += entry.text.len();
+                bytes <= persistence.max_bytes
+            })
+            .collect();
+
+        let result = serde_json::to_string(&kept)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            .and_then(|json| {
+                if let Some(parent) = persistence.path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(&persistence.path, json)
+            });
+        if let Err(e) = result {
+            tracing::warn!("failed to save clipboard history: {e}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ClipboardRing;
+    use crate::services::clipboard::ClipboardSource;
+
+    fn texts(ring: &ClipboardRing) -> Vec<&str> {
+        ring.entries().map(|entry| entry.text.as_str()).collect()
+    }
+
+    #[test]
+    fn keeps_newest_entries_and_coalesces_duplicates() {
+        let mut ring = ClipboardRing::new(3);
+        ring.push("a", ClipboardSource::Markdown);
+        ring.push("b", ClipboardSource::Markdown);
+        ring.push("b", ClipboardSource::Terminal);
+        ring.push("", ClipboardSource::Terminal);
+        assert_eq!(texts(&ring), ["b", "a"]);
+        assert_eq!(ring.get(0).unwrap().source, ClipboardSource::Terminal);
+
+        ring.push("c", ClipboardSource::Chat);
+        ring.push("d", ClipboardSource::Chat);
+        assert_eq!(texts(&ring), ["d", "c", "b"]);
+
+        // Only consecutive duplicates are coalesced.
+        ring.push("c", ClipboardSource::Chat);
+        assert_eq!(texts(&ring), ["c", "d", "c"]);
+
+        assert_eq!(ring.promote(1).unwrap().text, "d");
+        assert_eq!(texts(&ring), ["d", "c", "c"]);
+    }
+
+    #[test]
+    fn persistence_is_capped_and_reloaded() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("history.json");
+
+        let mut ring = ClipboardRing::default();
+        ring.push("old", ClipboardSource::Diff);
+        ring.enable_persistence(&path, 8).unwrap();
+        ring.push("newer", ClipboardSource::Diff);
+        ring.push("newest", ClipboardSource::Other("notes".to_string()));
+
+        // "newest" (6 bytes) fits the 8 byte cap, "newer" would exceed it.
+        let mut reloaded = ClipboardRing::default();
+        reloaded.enable_persistence(&path, 8).unwrap();
+        assert_eq!(texts(&reloaded), ["newest"]);
+        assert_eq!(
+            reloaded.get(0).unwrap().source,
+            ClipboardSource::Other("notes".to_string())
+        );
+    }
+}
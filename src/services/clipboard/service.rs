@@ -0,0 +1,63 @@
+//! Process-wide clipboard access.
+
+use std::sync::{Mutex, OnceLock};
+
+use crate::services::clipboard::{ClipboardEntry, ClipboardRing, ClipboardSource};
+
+fn ring() -> &'static Mutex<ClipboardRing> {
+    static RING: OnceLock<Mutex<ClipboardRing>> = OnceLock::new();
+    RING.get_or_init(Mutex::default)
+}
+
+/// Runs `f` with the process-wide clipboard ring.
+///
+/// Use this to read the history, change its capacity or enable persistence.
+pub fn with_ring<R>(f: impl FnOnce(&mut ClipboardRing) -> R) -> R {
+    let mut ring = ring()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    f(&mut ring)
+}
+
+fn set_system_clipboard(text: &str) -> Result<(), arboard::Error> {
+    arboard::Clipboard::new()?.set_text(text)
+}
+
+/// Copies `text` to the system clipboard and records it in the ring.
+///
+/// The entry is recorded even if the system clipboard is unavailable (e.g.
+/// over SSH), so the history can still be used to paste within the app.
+///
+/// # Errors
+///
+/// Returns an error if the system clipboard could not be set.
+pub fn copy(text: &str, source: ClipboardSource) -> Result<(), arboard::Error> {
+    with_ring(|ring| ring.push(text, source));
+    set_system_clipboard(text)
+}
+
+/// Copies `text` to the system clipboard without recording it in the ring.
+///
+/// Use this for passwords, tokens and other text that should not show up in
+/// the clipboard history or be persisted.
+///
+/// # Errors
+///
+/// Returns an error if the system clipboard could not be set.
+pub fn copy_sensitive(text: &str) -> Result<(), arboard::Error> {
+    set_system_clipboard(text)
+}
+
+/// Copies the ring entry at `index` to the system clipboard again and moves
+/// it to the front of the ring.
+///
+/// Returns the entry so the caller can paste it directly, or `None` if
+/// `index` is out of range. A failure to set the system clipboard is
+/// logged; the entry is still returned.
+pub fn recopy(index: usize) -> Option<ClipboardEntry> {
+    let entry = with_ring(|ring| ring.promote(index))?;
+    if let Err(e) = set_system_clipboard(&entry.text) {
+        tracing::warn!("failed to set system clipboard: {e}");
+    }
+    Some(entry)
+}
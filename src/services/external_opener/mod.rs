@@ -0,0 +1,254 @@
+//! Opening a file at a line in an external tool.
+//!
+//! Widgets that show file positions emit an [`OpenTarget`] when the user
+//! asks to open one: [`CodeDiff`](crate::widgets::code_diff::CodeDiff) for
+//! the line under its cursor, and
+//! [`OpenTargets`](crate::primitives::termtui::OpenTargets) for compiler
+//! errors and tracebacks printed in an embedded terminal. An
+//! [`ExternalOpener`] turns the target into a command from a template and
+//! runs it through [`crate::suspend_terminal`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use ratkit::services::external_opener::{ExternalOpener, OpenTarget};
+//!
+//! let mut opener = ExternalOpener::new().with_override("py", "code -g {path}:{line}:{column}");
+//! opener.open(&OpenTarget::new("src/lib.rs", 42).with_column(7))?;
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+mod patterns;
+
+use std::env;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus};
+
+pub use patterns::{
+    default_patterns, find_targets, path_line_column, python_traceback, TargetMatch, TargetPattern,
+};
+
+/// Template used when no override matches: the user's editor with the
+/// line passed as `+<line>`, which most terminal editors understand.
+pub const DEFAULT_TEMPLATE: &str = "{editor} +{line} {path}";
+
+/// A position in a file to open.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OpenTarget {
+    /// The file, relative to the opener's working directory unless
+    /// absolute.
+    pub path: PathBuf,
+    /// Line in the file (1-indexed).
+    pub line: usize,
+    /// Column in the line (1-indexed), if known.
+    pub column: Option<usize>,
+}
+
+impl OpenTarget {
+    /// Creates a target at `line` (1-indexed) of `path`.
+    pub fn new(path: impl Into<PathBuf>, line: usize) -> Self {
+        Self {
+            path: path.into(),
+            line,
+            column: None,
+        }
+    }
+
+    /// Sets the column (1-indexed).
+    pub fn with_column(mut self, column: usize) -> Self {
+        self.column = Some(column);
+        self
+    }
+}
+
+/// Formats commands that open an [`OpenTarget`] and runs them.
+///
+/// A template is split into arguments at whitespace, then the placeholders
+/// in each argument are replaced, so a path with spaces stays one argument:
+///
+/// - `{editor}` - `$VISUAL`, then `$EDITOR`, then `vi`; as a whole
+///   argument it may expand to several, e.g. `code --wait`
+/// - `{path}` - the target's path
+/// - `{line}` - the line, at least 1
+/// - `{column}` - the column, or 1 if unknown
+#[derive(Debug, Clone)]
+pub struct ExternalOpener {
+    template: String,
+    /// Templates by file extension, checked before `template`.
+    overrides: Vec<(String, String)>,
+    working_dir: Option<PathBuf>,
+    dry_run: bool,
+    /// Commands formatted in dry-run mode, oldest first.
+    dry_runs: Vec<Vec<String>>,
+}
+
+impl Default for ExternalOpener {
+    fn default() -> Self {
+        Self {
+            template: DEFAULT_TEMPLATE.to_string(),
+            overrides: Vec::new(),
+            working_dir: None,
+            dry_run: false,
+            dry_runs: Vec::new(),
+        }
+    }
+}
+
+impl ExternalOpener {
+    /// Creates an opener using [`DEFAULT_TEMPLATE`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the template used when no override matches.
+    pub fn with_template(mut self, template: impl Into<String>) -> Self {
+        self.template = template.into();
+        self
+    }
+
+    /// Uses `template` for files with extension `extension` (without the
+    /// dot), e.g. to open notebooks or images in another tool. A later
+    /// override for the same extension replaces the earlier one.
+    pub fn with_override(mut self, extension: &str, template: impl Into<String>) -> Self {
+        self.overrides.retain(|(ext, _)| ext != extension);
+        self.overrides
+            .push((extension.to_string(), template.into()));
+        self
+    }
+
+    /// Runs commands in `dir` and resolves relative paths against it,
+    /// e.g. the directory an embedded terminal's shell started in.
+    pub fn with_working_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.working_dir = Some(dir.into());
+        self
+    }
+
+    /// In dry-run mode [`open`](Self::open) records the command in
+    /// [`dry_runs`](Self::dry_runs) instead of running it.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Commands [`open`](Self::open) formatted in dry-run mode, oldest
+    /// first, as program and arguments.
+    pub fn dry_runs(&self) -> &[Vec<String>] {
+        &self.dry_runs
+    }
+
+    /// The template that applies to `path`.
+    pub fn template_for(&self, path: &Path) -> &str {
+        let extension = path.extension().and_then(|ext| ext.to_str());
+        self.overrides
+            .iter()
+            .find(|(ext, _)| Some(ext.as_str()) == extension)
+            .map_or(self.template.as_str(), |(_, template)| template)
+    }
+
+    /// The program and arguments that open `target`.
+    pub fn args(&self, target: &OpenTarget) -> Vec<String> {
+        let path = match &self.working_dir {
+            Some(dir) if target.path.is_relative() => dir.join(&target.path),
+            _ => target.path.clone(),
+        };
+        let path = path.to_string_lossy();
+        let line = target.line.max(1).to_string();
+        let column = target.column.unwrap_or(1).max(1).to_string();
+
+        let mut args = Vec::new();
+        for word in self.template_for(&target.path).split_whitespace() {
+            if word == "{editor}" {
+                args.extend(editor().split_whitespace().map(str::to_string));
+                continue;
+            }
+            let word = word
+                .replace("{path}", &path)
+                .replace("{line}", &line)
+                .replace("{column}", &column);
+            let word = if word.contains("{editor}") {
+                word.replace("{editor}", &editor())
+            } else {
+                word
+            };
+            args.push(word);
+        }
+        args
+    }
+
+    /// The command that opens `target`, or `None` if the template is
+    /// empty.
+    pub fn command(&self, target: &OpenTarget) -> Option<Command> {
+        let args = self.args(target);
+        let (program, args) = args.split_first()?;
+        let mut command = Command::new(program);
+        command.args(args);
+        if let Some(dir) = &self.working_dir {
+            command.current_dir(dir);
+        }
+        Some(command)
+    }
+
+    /// Opens `target` and waits for the tool to exit, handing it the
+    /// terminal in the meantime. Returns `None` in dry-run mode.
+    ///
+    /// Must be called while [`crate::run`] is running, like
+    /// [`crate::suspend_terminal`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the template is empty, the terminal could not be
+    /// handed over or the tool could not be started.
+    pub fn open(&mut self, target: &OpenTarget) -> io::Result<Option<ExitStatus>> {
+        if self.dry_run {
+            self.dry_runs.push(self.args(target));
+            return Ok(None);
+        }
+        let mut command = self.command(target).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "empty open command template")
+        })?;
+        crate::suspend_terminal(|| command.status())?.map(Some)
+    }
+}
+
+/// The user's editor: `$VISUAL`, then `$EDITOR`, then `vi`.
+fn editor() -> String {
+    ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|name| env::var(name).ok())
+        .find(|editor| !editor.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_templates_format_arguments_per_target() {
+        let mut opener = ExternalOpener::new()
+            .with_template("code -g {path}:{line}:{column}")
+            .with_override("py", "pyeditor --line={line} {path}")
+            .with_working_dir("/work")
+            .with_dry_run(true);
+
+        opener
+            .open(&OpenTarget::new("src/my lib.rs", 42).with_column(7))
+            .unwrap();
+        opener.open(&OpenTarget::new("/abs/x.py", 10)).unwrap();
+        assert_eq!(
+            opener.dry_runs(),
+            [
+                vec!["code", "-g", "/work/src/my lib.rs:42:7"],
+                vec!["pyeditor", "--line=10", "/abs/x.py"],
+            ]
+        );
+
+        let args = ExternalOpener::new().args(&OpenTarget::new("a.rs", 3));
+        assert_eq!(args[args.len() - 2..], ["+3", "a.rs"]);
+        assert!(ExternalOpener::new()
+            .with_template("")
+            .command(&OpenTarget::new("a.rs", 3))
+            .is_none());
+    }
+}
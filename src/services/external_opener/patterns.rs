@@ -0,0 +1,170 @@
+//! Recognizing file positions in tool output.
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use super::OpenTarget;
+
+/// A file position found in a line of text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TargetMatch {
+    /// Byte range of the matched text in the line.
+    pub range: Range<usize>,
+    /// The position the text names.
+    pub target: OpenTarget,
+}
+
+/// Finds the file positions in a line of text.
+pub type TargetPattern = Arc<dyn Fn(&str) -> Vec<TargetMatch> + Send + Sync>;
+
+/// The patterns [`find_targets`] is usually given: [`path_line_column`]
+/// and [`python_traceback`].
+pub fn default_patterns() -> Vec<TargetPattern> {
+    vec![Arc::new(path_line_column), Arc::new(python_traceback)]
+}
+
+/// Finds the file positions `patterns` recognize in `line`, in order,
+/// skipping any that overlap an earlier one.
+pub fn find_targets(line: &str, patterns: &[TargetPattern]) -> Vec<TargetMatch> {
+    let mut matches: Vec<TargetMatch> = patterns.iter().flat_map(|pattern| pattern(line)).collect();
+    matches.sort_by_key(|found| (found.range.start, std::cmp::Reverse(found.range.end)));
+    let mut end = 0;
+    matches.retain(|found| {
+        let keep = found.range.start >= end;
+        if keep {
+            end = found.range.end;
+        }
+        keep
+    });
+    matches
+}
+
+/// Recognizes `path:line` and `path:line:column`, as printed by rustc,
+/// gcc, eslint, grep -n and most other tools.
+///
+/// The path must contain a `.` or `/`, so `error:12` is not a match.
+pub fn path_line_column(line: &str) -> Vec<TargetMatch> {
+    let bytes = line.as_bytes();
+    let mut matches = Vec::new();
+    let mut search = 0;
+    while let Some(offset) = line[search..].find(':') {
+        let colon = search + offset;
+        search = colon + 1;
+        let Some((line_number, after_line)) = number_at(line, colon + 1) else {
+            continue;
+        };
+        let start = line[..colon]
+            .char_indices()
+            .rev()
+            .take_while(|&(_, c)| is_path_char(c))
+            .last()
+            .map_or(colon, |(index, _)| index);
+        let path = &line[start..colon];
+        let after_url_scheme = start > 0 && bytes[start - 1] == b':';
+        if path.is_empty() || !path.contains(['.', '/']) || after_url_scheme {
+            continue;
+        }
+
+        let mut target = OpenTarget::new(path, line_number);
+        let mut end = after_line;
+        if bytes.get(after_line) == Some(&b':') {
+            if let Some((column, after_column)) = number_at(line, after_line + 1) {
+                target = target.with_column(column);
+                end = after_column;
+            }
+        }
+        matches.push(TargetMatch {
+            range: start..end,
+            target,
+        });
+        search = end;
+    }
+    matches
+}
+
+/// Recognizes Python traceback lines: `File "x.py", line 10`.
+pub fn python_traceback(line: &str) -> Vec<TargetMatch> {
+    const PREFIX: &str = "File \"";
+    const LINE: &str = "\", line ";
+    let mut matches = Vec::new();
+    let mut search = 0;
+    while let Some(offset) = line[search..].find(PREFIX) {
+        let start = search + offset;
+        let path_start = start + PREFIX.len();
+        search = path_start;
+        let Some(path_len) = line[path_start..].find('"') else {
+            break;
+        };
+        let path_end = path_start + path_len;
+        if !line[path_end..].starts_with(LINE) {
+            continue;
+        }
+        let Some((line_number, end)) = number_at(line, path_end + LINE.len()) else {
+            continue;
+        };
+        matches.push(TargetMatch {
+            range: start..end,
+            target: OpenTarget::new(&line[path_start..path_end], line_number),
+        });
+        search = end;
+    }
+    matches
+}
+
+/// The decimal number starting at byte `start` of `text` and the byte
+/// after it.
+fn number_at(text: &str, start: usize) -> Option<(usize, usize)> {
+    let digits = text
+        .get(start..)?
+        .bytes()
+        .take_while(u8::is_ascii_digit)
+        .count();
+    let number = text[start..start + digits].parse().ok()?;
+    Some((number, start + digits))
+}
+
+fn is_path_char(c: char) -> bool {
+    !c.is_whitespace()
+        && !matches!(
+            c,
+            ':' | '"' | '\'' | '(' | ')' | '[' | ']' | '<' | '>' | ',' | '`'
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn targets(line: &str) -> Vec<(&str, OpenTarget)> {
+        find_targets(line, &default_patterns())
+            .into_iter()
+            .map(|found| (&line[found.range], found.target))
+            .collect()
+    }
+
+    #[test]
+    fn test_default_patterns_find_compiler_and_traceback_positions() {
+        assert_eq!(
+            targets("  --> src/lib.rs:42:7"),
+            [(
+                "src/lib.rs:42:7",
+                OpenTarget::new("src/lib.rs", 42).with_column(7)
+            )]
+        );
+        assert_eq!(
+            targets("  File \"app/main.py\", line 10, in <module>"),
+            [(
+                "File \"app/main.py\", line 10",
+                OpenTarget::new("app/main.py", 10)
+            )]
+        );
+        assert_eq!(
+            targets("a.c:3: warning (see (b.h:9))"),
+            [
+                ("a.c:3", OpenTarget::new("a.c", 3)),
+                ("b.h:9", OpenTarget::new("b.h", 9)),
+            ]
+        );
+        assert!(targets("error:12 at http://localhost:8080/x").is_empty());
+    }
+}
@@ -25,6 +25,7 @@ impl FileWatcher {
         Self::with_config(WatchConfig {
             mode: WatchMode::Recursive,
             debounce_ms: 200,
+            ..WatchConfig::default()
         })
     }
 }
@@ -24,6 +24,7 @@ impl FileWatcher {
         Self::with_config(WatchConfig {
             mode: WatchMode::File,
             debounce_ms: 100,
+            ..WatchConfig::default()
         })
     }
 }
@@ -39,10 +39,12 @@ impl FileWatcher {
         )?;
 
         Ok(Self {
-            watcher,
+            watcher: Some(watcher),
             rx,
             config,
             changed_paths: Vec::new(),
+            pending_changes: Vec::new(),
+            last_change_at: None,
         })
     }
 }
@@ -0,0 +1,89 @@
+//! Classify the paths of a file system event.
+
+use notify::event::{ModifyKind, RenameMode};
+use notify::{Event, EventKind};
+
+use crate::services::file_watcher::helpers::is_relevant_event;
+use crate::services::file_watcher::{ChangeKind, ChangedPath};
+
+/// Turn an event into the changes it describes.
+///
+/// Renames become a removal of the old path and a creation of the new one.
+/// Events that [`is_relevant_event`] rejects produce no changes.
+pub fn changes_from_event(event: &Event) -> Vec<ChangedPath> {
+    if !is_relevant_event(event) {
+        return Vec::new();
+    }
+
+    let change = |path: &std::path::PathBuf, kind| ChangedPath {
+        path: path.clone(),
+        kind,
+    };
+    match event.kind {
+        EventKind::Create(_) => event
+            .paths
+            .iter()
+            .map(|p| change(p, ChangeKind::Created))
+            .collect(),
+        EventKind::Remove(_) => event
+            .paths
+            .iter()
+            .map(|p| change(p, ChangeKind::Removed))
+            .collect(),
+        EventKind::Modify(ModifyKind::Name(RenameMode::From)) => event
+            .paths
+            .iter()
+            .map(|p| change(p, ChangeKind::Removed))
+            .collect(),
+        EventKind::Modify(ModifyKind::Name(RenameMode::To)) => event
+            .paths
+            .iter()
+            .map(|p| change(p, ChangeKind::Created))
+            .collect(),
+        EventKind::Modify(ModifyKind::Name(RenameMode::Both)) if event.paths.len() == 2 => vec![
+            change(&event.paths[0], ChangeKind::Removed),
+            change(&event.paths[1], ChangeKind::Created),
+        ],
+        _ => event
+            .paths
+            .iter()
+            .map(|p| change(p, ChangeKind::Modified))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::{AccessKind, CreateKind, DataChange};
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_rename_both_is_remove_then_create() {
+        let event = Event::new(EventKind::Modify(ModifyKind::Name(RenameMode::Both)))
+            .add_path(PathBuf::from("old.md"))
+            .add_path(PathBuf::from("new.md"));
+        let kinds: Vec<_> = changes_from_event(&event)
+            .into_iter()
+            .map(|c| (c.path, c.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (PathBuf::from("old.md"), ChangeKind::Removed),
+                (PathBuf::from("new.md"), ChangeKind::Created),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_kinds_follow_event_kind() {
+        let created = Event::new(EventKind::Create(CreateKind::File)).add_path("a".into());
+        let modified = Event::new(EventKind::Modify(ModifyKind::Data(DataChange::Content)))
+            .add_path("a".into());
+        let accessed = Event::new(EventKind::Access(AccessKind::Read)).add_path("a".into());
+        assert_eq!(changes_from_event(&created)[0].kind, ChangeKind::Created);
+        assert_eq!(changes_from_event(&modified)[0].kind, ChangeKind::Modified);
+        assert!(changes_from_event(&accessed).is_empty());
+    }
+}
@@ -1,5 +1,9 @@
 //! Helper functions for file watching.
 
+mod changes_from_event;
 mod is_relevant_event;
+mod shut_down_error;
 
+pub use changes_from_event::changes_from_event;
 pub use is_relevant_event::is_relevant_event;
+pub use shut_down_error::shut_down_error;
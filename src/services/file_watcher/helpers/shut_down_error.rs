@@ -0,0 +1,7 @@
+//! Error returned by a watcher that has been shut down.
+
+/// Error for operations on a [`FileWatcher`](crate::services::file_watcher::FileWatcher)
+/// whose shutdown hook has already run.
+pub fn shut_down_error() -> notify::Error {
+    notify::Error::generic("file watcher has shut down")
+}
@@ -42,9 +42,12 @@ impl FileWatcher {
             match self.rx.try_recv() {
                 Ok(Ok(event)) => {
                     if is_relevant_event(&event) {
-                        has_changes = true;
                         // Collect the paths that changed
                         for path in event.paths {
+                            if !self.config.matches_path(&path) {
+                                continue;
+                            }
+                            has_changes = true;
                             if !self.changed_paths.contains(&path) {
                                 self.changed_paths.push(path);
                             }
@@ -0,0 +1,194 @@
+//! Collect debounced, per-path changes.
+
+use std::sync::mpsc::TryRecvError;
+use std::time::{Duration, Instant};
+
+use crate::services::file_watcher::helpers::changes_from_event;
+use crate::services::file_watcher::{ChangeKind, ChangedPath, FileWatcher};
+
+impl FileWatcher {
+    /// Take the distinct paths that changed since the last call.
+    ///
+    /// Changes are held back until no new event has arrived for the
+    /// configured [`debounce_ms`](crate::services::file_watcher::WatchConfig::debounce_ms),
+    /// so the burst of events an editor produces when saving comes back as
+    /// one entry per path. Until the window has passed this returns an empty
+    /// vector.
+    ///
+    /// Repeated events for a path are merged: a path created and then
+    /// written is reported as created, a path created and then removed is
+    /// not reported at all, and a path removed and then recreated is
+    /// reported as modified.
+    ///
+    /// This reads from the same queue as
+    /// [`check_for_changes`](Self::check_for_changes); use one or the other.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_toolkit::services::file_watcher::{ChangeKind, FileWatcher};
+    /// use std::path::Path;
+    ///
+    /// let mut watcher = FileWatcher::for_directory().unwrap();
+    /// watcher.watch(Path::new("./docs")).unwrap();
+    ///
+    /// // In your event loop:
+    /// for change in watcher.drain_changes() {
+    ///     match change.kind {
+    ///         ChangeKind::Removed => println!("Closed {}", change.path.display()),
+    ///         _ => println!("Reloaded {}", change.path.display()),
+    ///     }
+    /// }
+    /// ```
+    pub fn drain_changes(&mut self) -> Vec<ChangedPath> {
+        self.drain_changes_at(Instant::now())
+    }
+
+    fn drain_changes_at(&mut self, now: Instant) -> Vec<ChangedPath> {
+        loop {
+            match self.rx.try_recv() {
+                Ok(Ok(event)) => {
+                    let mut changes = changes_from_event(&event);
+                    changes.retain(|change| self.config.matches_path(&change.path));
+                    if !changes.is_empty() {
+                        self.last_change_at = Some(now);
+                    }
+                    for change in changes {
+                        self.record_change(change);
+                    }
+                }
+                Ok(Err(_)) => {
+                    // Watcher error, ignore
+                }
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => break,
+            }
+        }
+
+        let window = Duration::from_millis(self.config.debounce_ms);
+        match self.last_change_at {
+            Some(last) if now.saturating_duration_since(last) >= window => {
+                self.last_change_at = None;
+                std::mem::take(&mut self.pending_changes)
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn record_change(&mut self, change: ChangedPath) {
+        let Some(index) = self
+            .pending_changes
+            .iter()
+            .position(|pending| pending.path == change.path)
+        else {
+            self.pending_changes.push(change);
+            return;
+        };
+
+        let merged = match (self.pending_changes[index].kind, change.kind) {
+            (ChangeKind::Created, ChangeKind::Removed) => None,
+            (_, ChangeKind::Removed) => Some(ChangeKind::Removed),
+            (ChangeKind::Created, _) => Some(ChangeKind::Created),
+            _ => Some(ChangeKind::Modified),
+        };
+        match merged {
+            Some(kind) => self.pending_changes[index].kind = kind,
+            None => {
+                self.pending_changes.remove(index);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::sync::mpsc::{channel, Sender};
+    use std::time::{Duration, Instant};
+
+    use notify::event::{CreateKind, DataChange, ModifyKind, RemoveKind};
+    use notify::{Event, EventKind};
+
+    use crate::services::file_watcher::{ChangeKind, FileWatcher, WatchConfig};
+
+    type EventSender = Sender<Result<Event, notify::Error>>;
+
+    fn watcher() -> (FileWatcher, EventSender) {
+        let (tx, rx) = channel();
+        let watcher = FileWatcher {
+            watcher: None,
+            rx,
+            config: WatchConfig::new().debounce_ms(100),
+            changed_paths: Vec::new(),
+            pending_changes: Vec::new(),
+            last_change_at: None,
+        };
+        (watcher, tx)
+    }
+
+    fn send(tx: &EventSender, kind: EventKind, path: &str) {
+        tx.send(Ok(Event::new(kind).add_path(PathBuf::from(path))))
+            .unwrap();
+    }
+
+    fn write() -> EventKind {
+        EventKind::Modify(ModifyKind::Data(DataChange::Content))
+    }
+
+    #[test]
+    fn test_changes_wait_for_the_debounce_window() {
+        let (mut watcher, tx) = watcher();
+        let start = Instant::now();
+
+        send(&tx, write(), "a.md");
+        assert!(watcher.drain_changes_at(start).is_empty());
+
+        // A new event restarts the window.
+        send(&tx, write(), "a.md");
+        send(&tx, write(), "b.md");
+        let later = start + Duration::from_millis(80);
+        assert!(watcher.drain_changes_at(later).is_empty());
+        assert!(watcher
+            .drain_changes_at(later + Duration::from_millis(99))
+            .is_empty());
+
+        let changes = watcher.drain_changes_at(later + Duration::from_millis(100));
+        let paths: Vec<_> = changes.iter().map(|c| c.path.clone()).collect();
+        assert_eq!(paths, vec![PathBuf::from("a.md"), PathBuf::from("b.md")]);
+        assert!(changes.iter().all(|c| c.kind == ChangeKind::Modified));
+
+        assert!(watcher
+            .drain_changes_at(later + Duration::from_secs(1))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_repeated_events_for_a_path_are_merged() {
+        let (mut watcher, tx) = watcher();
+        let start = Instant::now();
+
+        send(&tx, EventKind::Create(CreateKind::File), "new.md");
+        send(&tx, write(), "new.md");
+        send(&tx, EventKind::Create(CreateKind::File), "tmp.swp");
+        send(&tx, EventKind::Remove(RemoveKind::File), "tmp.swp");
+        send(&tx, EventKind::Remove(RemoveKind::File), "saved.md");
+        send(&tx, EventKind::Create(CreateKind::File), "saved.md");
+        send(&tx, write(), "gone.md");
+        send(&tx, EventKind::Remove(RemoveKind::File), "gone.md");
+        watcher.drain_changes_at(start);
+
+        let changes: Vec<_> = watcher
+            .drain_changes_at(start + Duration::from_millis(100))
+            .into_iter()
+            .map(|c| (c.path, c.kind))
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                (PathBuf::from("new.md"), ChangeKind::Created),
+                (PathBuf::from("saved.md"), ChangeKind::Modified),
+                (PathBuf::from("gone.md"), ChangeKind::Removed),
+            ]
+        );
+    }
+}
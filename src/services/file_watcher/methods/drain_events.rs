@@ -7,7 +7,8 @@ use crate::services::file_watcher::FileWatcher;
 impl FileWatcher {
     /// Drain all pending events without processing them.
     ///
-    /// This clears the event queue and the changed paths list,
+    /// This clears the event queue, the changed paths list and any changes
+    /// waiting for [`drain_changes`](Self::drain_changes),
     /// useful when you want to ignore accumulated changes
     /// (e.g., after a batch operation).
     ///
@@ -32,5 +33,7 @@ impl FileWatcher {
             }
         }
         self.changed_paths.clear();
+        self.pending_changes.clear();
+        self.last_change_at = None;
     }
 }
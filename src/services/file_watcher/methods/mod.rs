@@ -1,6 +1,7 @@
 //! Methods for FileWatcher.
 
 pub mod check_for_changes;
+pub mod drain_changes;
 pub mod drain_events;
 pub mod get_changed_paths;
 pub mod unwatch;
@@ -3,6 +3,7 @@
 use notify::Watcher;
 use std::path::Path;
 
+use crate::services::file_watcher::helpers::shut_down_error;
 use crate::services::file_watcher::FileWatcher;
 
 impl FileWatcher {
@@ -14,7 +15,8 @@ impl FileWatcher {
     ///
     /// # Errors
     ///
-    /// Returns a `notify::Error` if the path cannot be unwatched.
+    /// Returns a `notify::Error` if the path cannot be unwatched or the watcher
+    /// has been shut down.
     ///
     /// # Example
     ///
@@ -29,6 +31,9 @@ impl FileWatcher {
     /// watcher.unwatch(path).unwrap();
     /// ```
     pub fn unwatch(&mut self, path: &Path) -> Result<(), notify::Error> {
-        self.watcher.unwatch(path)
+        self.watcher
+            .as_mut()
+            .ok_or_else(shut_down_error)?
+            .unwatch(path)
     }
 }
@@ -3,6 +3,7 @@
 use notify::{RecursiveMode, Watcher};
 use std::path::Path;
 
+use crate::services::file_watcher::helpers::shut_down_error;
 use crate::services::file_watcher::{FileWatcher, WatchMode};
 
 impl FileWatcher {
@@ -17,7 +18,8 @@ impl FileWatcher {
     ///
     /// # Errors
     ///
-    /// Returns a `notify::Error` if the path cannot be watched.
+    /// Returns a `notify::Error` if the path cannot be watched or the watcher
+    /// has been shut down.
     ///
     /// # Example
     ///
@@ -33,6 +35,9 @@ impl FileWatcher {
             WatchMode::File => RecursiveMode::NonRecursive,
             WatchMode::Recursive => RecursiveMode::Recursive,
         };
-        self.watcher.watch(path, mode)
+        self.watcher
+            .as_mut()
+            .ok_or_else(shut_down_error)?
+            .watch(path, mode)
     }
 }
@@ -28,6 +28,11 @@
 //! for path in changed {
 //!     println!("Changed: {}", path.display());
 //! }
+//!
+//! // Or get debounced changes, one per path, with how each changed
+//! for change in dir_watcher.drain_changes() {
+//!     println!("{:?}: {}", change.kind, change.path.display());
+//! }
 //! ```
 
 mod constructors;
@@ -36,11 +41,14 @@ mod methods;
 mod traits;
 
 use notify::{Event, RecommendedWatcher};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::mpsc::Receiver;
+use std::time::Instant;
 
 pub use constructors::{for_directory, for_file, new, with_config};
-pub use methods::{check_for_changes, drain_events, get_changed_paths, unwatch, watch};
+pub use methods::{
+    check_for_changes, drain_changes, drain_events, get_changed_paths, unwatch, watch,
+};
 
 /// Mode for file watching.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -49,16 +57,46 @@ pub enum WatchMode {
     #[default]
     File,
     /// Watch a directory tree recursively.
+    ///
+    /// Subdirectories created after the watch starts are watched too.
     Recursive,
 }
 
+/// How a path changed, as reported by [`FileWatcher::drain_changes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChangeKind {
+    /// The path did not exist before and does now.
+    Created,
+    /// The path's contents changed.
+    Modified,
+    /// The path no longer exists.
+    Removed,
+}
+
+/// A path that changed, with how it changed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ChangedPath {
+    /// The path that changed.
+    pub path: PathBuf,
+    /// How the path changed.
+    pub kind: ChangeKind,
+}
+
 /// Configuration for the file watcher.
 #[derive(Debug, Clone)]
 pub struct WatchConfig {
     /// Watch mode - single file or recursive directory.
     pub mode: WatchMode,
     /// Debounce interval in milliseconds.
+    ///
+    /// [`FileWatcher::drain_changes`] holds changes back until no new event
+    /// has arrived for this long.
     pub debounce_ms: u64,
+    /// File extensions to report, without the leading dot.
+    ///
+    /// Empty reports every path. Otherwise events for paths with any other
+    /// extension are dropped before they reach the caller.
+    pub extensions: Vec<String>,
 }
 
 impl Default for WatchConfig {
@@ -66,6 +104,7 @@ impl Default for WatchConfig {
         Self {
             mode: WatchMode::File,
             debounce_ms: 100,
+            extensions: Vec::new(),
         }
     }
 }
@@ -87,6 +126,31 @@ impl WatchConfig {
         self.debounce_ms = ms;
         self
     }
+
+    /// Only report paths with one of these extensions, e.g. `&["md", "rs"]`.
+    ///
+    /// Extensions match case-insensitively, with or without a leading dot.
+    pub fn extensions(mut self, extensions: &[&str]) -> Self {
+        self.extensions = extensions
+            .iter()
+            .map(|ext| ext.trim_start_matches('.').to_lowercase())
+            .collect();
+        self
+    }
+
+    /// Whether events for `path` pass the [`extensions`](Self::extensions)
+    /// filter.
+    pub fn matches_path(&self, path: &Path) -> bool {
+        self.extensions.is_empty()
+            || path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| {
+                    self.extensions
+                        .iter()
+                        .any(|wanted| wanted.eq_ignore_ascii_case(ext))
+                })
+    }
 }
 
 /// A file watcher for detecting file system changes.
@@ -95,12 +159,107 @@ impl WatchConfig {
 /// for changes. Provides a non-blocking interface suitable for use
 /// in TUI event loops.
 pub struct FileWatcher {
-    /// The underlying file system watcher.
-    pub(crate) watcher: RecommendedWatcher,
+    /// The underlying file system watcher; `None` once shut down.
+    pub(crate) watcher: Option<RecommendedWatcher>,
     /// Receiver for file change events.
     pub(crate) rx: Receiver<Result<Event, notify::Error>>,
     /// Configuration for the watcher.
     pub(crate) config: WatchConfig,
     /// Paths that have changed since last check.
     pub(crate) changed_paths: Vec<PathBuf>,
+    /// Changes waiting for the debounce window, in first-seen order.
+    pub(crate) pending_changes: Vec<ChangedPath>,
+    /// When the most recent pending change arrived.
+    pub(crate) last_change_at: Option<Instant>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use tempfile::tempdir;
+
+    /// Collects changes until `done` accepts them or a few seconds pass.
+    fn wait_for_changes(
+        watcher: &mut FileWatcher,
+        done: impl Fn(&[ChangedPath]) -> bool,
+    ) -> Vec<ChangedPath> {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let mut changes = Vec::new();
+        while Instant::now() < deadline && !done(&changes) {
+            changes.extend(watcher.drain_changes());
+            thread::sleep(Duration::from_millis(20));
+        }
+        changes
+    }
+
+    fn recursive_watcher(root: &Path, extensions: &[&str]) -> FileWatcher {
+        let config = WatchConfig::new()
+            .mode(WatchMode::Recursive)
+            .debounce_ms(10)
+            .extensions(extensions);
+        let mut watcher = FileWatcher::with_config(config).unwrap();
+        watcher.watch(root).unwrap();
+        watcher
+    }
+
+    #[test]
+    fn test_extensions_filter_paths() {
+        let config = WatchConfig::new().extensions(&[".md", "RS"]);
+        assert!(config.matches_path(Path::new("docs/README.MD")));
+        assert!(config.matches_path(Path::new("src/lib.rs")));
+        assert!(!config.matches_path(Path::new("Cargo.toml")));
+        assert!(!config.matches_path(Path::new("docs")));
+        assert!(WatchConfig::new().matches_path(Path::new("docs")));
+    }
+
+    #[test]
+    fn test_recursive_watch_reports_nested_matching_files() {
+        let dir = tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        fs::create_dir_all(root.join("a/b")).unwrap();
+        let nested = root.join("a/b/notes.md");
+        fs::write(&nested, "one").unwrap();
+
+        let mut watcher = recursive_watcher(&root, &["md"]);
+        fs::write(root.join("a/b/scratch.txt"), "ignored").unwrap();
+        fs::write(&nested, "two").unwrap();
+
+        let changes = wait_for_changes(&mut watcher, |changes| {
+            changes.iter().any(|c| c.path == nested)
+        });
+        assert!(changes
+            .iter()
+            .any(|c| c.path == nested && c.kind == ChangeKind::Modified));
+        assert!(changes
+            .iter()
+            .all(|c| c.path.extension().is_some_and(|ext| ext == "md")));
+    }
+
+    #[test]
+    fn test_recursive_watch_follows_new_subdirectories() {
+        let dir = tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        let mut watcher = recursive_watcher(&root, &["rs"]);
+
+        fs::create_dir_all(root.join("new/deeper")).unwrap();
+        // Give the backend a moment to add watches for the new directories.
+        thread::sleep(Duration::from_millis(200));
+        let created = root.join("new/deeper/main.rs");
+        fs::write(&created, "fn main() {}").unwrap();
+
+        let changes = wait_for_changes(&mut watcher, |changes| {
+            changes.iter().any(|c| c.path == created)
+        });
+        assert!(changes.iter().any(|c| c.path == created), "{changes:?}");
+        assert!(!changes.iter().any(|c| c.path == root.join("new")));
+
+        assert!(!watcher.check_for_changes());
+        fs::write(root.join("new/deeper/README"), "no extension").unwrap();
+        thread::sleep(Duration::from_millis(200));
+        assert!(!watcher.check_for_changes());
+    }
 }
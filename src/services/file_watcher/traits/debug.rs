@@ -9,6 +9,7 @@ impl fmt::Debug for FileWatcher {
         f.debug_struct("FileWatcher")
             .field("config", &self.config)
             .field("changed_paths", &self.changed_paths)
+            .field("pending_changes", &self.pending_changes)
             .finish_non_exhaustive()
     }
 }
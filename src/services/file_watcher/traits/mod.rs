@@ -1,3 +1,4 @@
 //! Trait implementations for FileWatcher.
 
 mod debug;
+mod shutdown_hook;
@@ -0,0 +1,17 @@
+//! ShutdownHook trait implementation for FileWatcher.
+
+use crate::services::file_watcher::FileWatcher;
+use crate::{ShutdownAction, ShutdownHook};
+
+impl ShutdownHook for FileWatcher {
+    /// Stops the watcher's background thread and drops any pending events.
+    fn on_shutdown(&mut self) -> ShutdownAction {
+        self.watcher = None;
+        self.drain_events();
+        ShutdownAction::Done
+    }
+
+    fn shutdown_name(&self) -> &str {
+        "FileWatcher"
+    }
+}
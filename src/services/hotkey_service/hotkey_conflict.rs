@@ -0,0 +1,62 @@
+//! Conflicting hotkey registrations.
+
+use std::fmt;
+
+use crate::services::hotkey_service::Hotkey;
+use crate::services::hotkey_service::HotkeyScope;
+
+/// A key registered more than once where the registrations are active
+/// together.
+///
+/// Reported by [`HotkeyRegistry::conflicts`](crate::services::hotkey_service::HotkeyRegistry::conflicts).
+/// The `Display` output is meant to be shown to users, e.g. in a dialog:
+///
+/// ```text
+/// "j" in tab "Markdown": 2 hotkeys
+///   -> Move down [tab "Markdown", priority 0]
+///      Scroll down [global, priority 0]
+/// ```
+#[derive(Debug, Clone)]
+pub struct HotkeyConflict {
+    /// The shared key, as registered by the winning hotkey.
+    pub key: String,
+    /// The scope in which the hotkeys are active together.
+    pub scope: HotkeyScope,
+    /// The conflicting hotkeys, in resolution order: the first one wins.
+    pub hotkeys: Vec<Hotkey>,
+    /// Whether the winner shares its priority and scope specificity with the
+    /// runner-up, so it only wins by registration order.
+    pub tied: bool,
+}
+
+impl HotkeyConflict {
+    /// The hotkey that [`HotkeyRegistry::resolve`](crate::services::hotkey_service::HotkeyRegistry::resolve)
+    /// picks in this conflict's scope.
+    pub fn winner(&self) -> &Hotkey {
+        &self.hotkeys[0]
+    }
+}
+
+impl fmt::Display for HotkeyConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "\"{}\" in {}: {} hotkeys",
+            self.key,
+            self.scope,
+            self.hotkeys.len()
+        )?;
+        if self.tied {
+            write!(f, " (tied, first registered wins)")?;
+        }
+        for (i, hotkey) in self.hotkeys.iter().enumerate() {
+            let marker = if i == 0 { "->" } else { "  " };
+            write!(
+                f,
+                "\n  {} {} [{}, priority {}]",
+                marker, hotkey.description, hotkey.scope, hotkey.priority
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 use crate::services::hotkey_service::Hotkey;
 use crate::services::hotkey_service::HotkeyScope;
+use crate::services::hotkey_service::{ChordParseError, KeyChord};
 
 impl Hotkey {
     /// Create a new hotkey.
@@ -26,9 +27,40 @@ impl Hotkey {
             description: description.to_string(),
             scope: HotkeyScope::Global,
             priority: 0,
+            id: None,
+            footer_visible: true,
         }
     }
 
+    /// Create a hotkey from a key spec such as `"Ctrl+Shift+P"` or `"F5"`.
+    ///
+    /// The spec is parsed as a [`KeyChord`], accepting `Ctrl`, `Alt`,
+    /// `Shift` and `Cmd` in any case, and stored in its normalized form, so
+    /// the hotkey shows as `"ctrl+shift+p"` and matches key events with
+    /// [`HotkeyRegistry::match_event`](crate::services::hotkey_service::HotkeyRegistry::match_event).
+    ///
+    /// # Arguments
+    ///
+    /// * `spec` - Modifiers and a key joined by `+`
+    /// * `description` - Human-readable description
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ChordParseError`] if the spec cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratkit::services::hotkey_service::Hotkey;
+    ///
+    /// let hotkey = Hotkey::from_spec("Ctrl+Shift+P", "Command palette").unwrap();
+    /// assert_eq!(hotkey.key, "ctrl+shift+p");
+    /// ```
+    pub fn from_spec(spec: &str, description: &str) -> Result<Self, ChordParseError> {
+        let chord = KeyChord::parse(spec)?;
+        Ok(Self::new(&chord.to_string(), description))
+    }
+
     /// Set the scope for this hotkey.
     ///
     /// # Arguments
@@ -56,4 +88,41 @@ impl Hotkey {
         self.priority = priority;
         self
     }
+
+    /// Set whether footers built from the registry show this hotkey.
+    ///
+    /// Hide internal bindings so the footer lists only the ones users
+    /// need; see `HotkeyFooter::from_registry`.
+    ///
+    /// # Arguments
+    ///
+    /// * `visible` - `false` to leave the hotkey out of footers
+    ///
+    /// # Returns
+    ///
+    /// The hotkey with the flag set.
+    pub fn footer_visible(mut self, visible: bool) -> Self {
+        self.footer_visible = visible;
+        self
+    }
+
+    /// Set the action id for this hotkey.
+    ///
+    /// The id names the action rather than the key, e.g.
+    /// `"palette.open"`, so it should stay the same across releases: user
+    /// keymaps loaded with
+    /// [`HotkeyRegistry::load_overrides`](crate::services::hotkey_service::HotkeyRegistry::load_overrides)
+    /// refer to hotkeys by it.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The action id
+    ///
+    /// # Returns
+    ///
+    /// The hotkey with the id set.
+    pub fn id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
 }
@@ -0,0 +1,86 @@
+use crate::services::hotkey_service::Hotkey;
+use crate::services::hotkey_service::KeyChord;
+use crossterm::event::{KeyCode, KeyEvent};
+
+impl Hotkey {
+    /// Check whether a pressed key triggers this hotkey.
+    ///
+    /// Keys are compared case-insensitively, and `"esc"` and `"escape"`
+    /// both match [`KeyCode::Esc`].
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key code that was pressed
+    ///
+    /// # Returns
+    ///
+    /// `true` if the key matches this hotkey.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratkit::services::hotkey_service::Hotkey;
+    /// use crossterm::event::KeyCode;
+    ///
+    /// let hotkey = Hotkey::new("Esc", "Close");
+    /// assert!(hotkey.matches_key(&KeyCode::Esc));
+    /// ```
+    pub fn matches_key(&self, key: &KeyCode) -> bool {
+        let hotkey_key = self.key.to_lowercase();
+        match key {
+            KeyCode::Char(c) => hotkey_key == c.to_string().to_lowercase(),
+            KeyCode::Tab => hotkey_key == "tab",
+            KeyCode::Enter => hotkey_key == "enter",
+            KeyCode::Esc => hotkey_key == "escape" || hotkey_key == "esc",
+            KeyCode::Up => hotkey_key == "up",
+            KeyCode::Down => hotkey_key == "down",
+            KeyCode::Left => hotkey_key == "left",
+            KeyCode::Right => hotkey_key == "right",
+            KeyCode::Backspace => hotkey_key == "backspace",
+            _ => false,
+        }
+    }
+
+    /// The key parsed as a chord, or `None` if it is not a valid key spec.
+    pub fn chord(&self) -> Option<KeyChord> {
+        KeyChord::parse(&self.key).ok()
+    }
+
+    /// Check whether a key event, modifiers included, triggers this
+    /// hotkey. See [`KeyChord::matches`] for how events are compared.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The key event that was received
+    ///
+    /// # Returns
+    ///
+    /// `true` if the key is a valid spec that matches the event.
+    pub fn matches_event(&self, event: &KeyEvent) -> bool {
+        self.chord().is_some_and(|chord| chord.matches(event))
+    }
+
+    /// Whether two hotkeys are bound to the same key.
+    ///
+    /// Keys that parse as chords are compared the way
+    /// [`matches_event`](Self::matches_event) matches them, so `"ctrl+s"`
+    /// and `"Ctrl+S"` are the same key but `"s"` is not. Other keys are
+    /// compared by [`normalized_key`](Self::normalized_key).
+    pub(crate) fn same_key(&self, other: &Self) -> bool {
+        match (self.chord(), other.chord()) {
+            (Some(chord), Some(other)) => chord.same_keys_as(&other),
+            _ => self.normalized_key() == other.normalized_key(),
+        }
+    }
+
+    /// The key in the form used to compare hotkeys with each other:
+    /// lowercased, with `"escape"` spelled `"esc"`.
+    pub(crate) fn normalized_key(&self) -> String {
+        let key = self.key.to_lowercase();
+        if key == "escape" {
+            "esc".to_string()
+        } else {
+            key
+        }
+    }
+}
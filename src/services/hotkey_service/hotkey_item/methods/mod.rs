@@ -1 +1 @@
-
+mod matches_key;
@@ -22,4 +22,8 @@ pub struct Hotkey {
     pub scope: HotkeyScope,
     /// Priority for conflict resolution (higher = more important).
     pub priority: u32,
+    /// Stable action id that user keymaps rebind the hotkey by.
+    pub id: Option<String>,
+    /// Whether footers built from the registry show this hotkey.
+    pub footer_visible: bool,
 }
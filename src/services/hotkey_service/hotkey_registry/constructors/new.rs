@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use crate::services::hotkey_service::HotkeyRegistry;
 
 impl HotkeyRegistry {
@@ -18,6 +20,7 @@ impl HotkeyRegistry {
         Self {
             hotkeys: Vec::new(),
             active_scope: None,
+            overrides: BTreeMap::new(),
         }
     }
 }
@@ -16,7 +16,9 @@ impl HotkeyRegistry {
     ///
     /// # Returns
     ///
-    /// `Some(&Hotkey)` if found, `None` otherwise.
+    /// `Some(&Hotkey)` if found, `None` otherwise. When several hotkeys
+    /// match, this is the first one registered; use
+    /// [`resolve`](HotkeyRegistry::resolve) to honour priorities.
     ///
     /// # Example
     ///
@@ -31,33 +33,9 @@ impl HotkeyRegistry {
     /// assert!(found.is_some());
     /// ```
     pub fn lookup(&self, key: &KeyCode, scope: &HotkeyScope) -> Option<&Hotkey> {
-        self.hotkeys.iter().find(|hotkey| {
-            let key_matches = match key {
-                KeyCode::Char(c) => hotkey.key.to_lowercase() == c.to_string().to_lowercase(),
-                KeyCode::Tab => hotkey.key.to_lowercase() == "tab",
-                KeyCode::Enter => hotkey.key.to_lowercase() == "enter",
-                KeyCode::Esc => {
-                    hotkey.key.to_lowercase() == "escape" || hotkey.key.to_lowercase() == "esc"
-                }
-                KeyCode::Up => hotkey.key.to_lowercase() == "up",
-                KeyCode::Down => hotkey.key.to_lowercase() == "down",
-                KeyCode::Left => hotkey.key.to_lowercase() == "left",
-                KeyCode::Right => hotkey.key.to_lowercase() == "right",
-                KeyCode::Backspace => hotkey.key.to_lowercase() == "backspace",
-                _ => false,
-            };
-
-            if !key_matches {
-                return false;
-            }
-
-            match &hotkey.scope {
-                HotkeyScope::Global => true,
-                HotkeyScope::Modal(m) => matches!(scope, HotkeyScope::Modal(s) if s == m),
-                HotkeyScope::Tab(t) => matches!(scope, HotkeyScope::Tab(s) if s == t),
-                HotkeyScope::Custom(c) => matches!(scope, HotkeyScope::Custom(s) if s == c),
-            }
-        })
+        self.hotkeys
+            .iter()
+            .find(|hotkey| hotkey.matches_key(key) && hotkey.scope.is_active_in(scope))
     }
 
     /// Get hotkeys filtered by scope.
@@ -86,12 +64,7 @@ impl HotkeyRegistry {
     pub fn get_by_scope(&self, scope: &HotkeyScope) -> Vec<&Hotkey> {
         self.hotkeys
             .iter()
-            .filter(|hotkey| match &hotkey.scope {
-                HotkeyScope::Global => true,
-                HotkeyScope::Modal(m) => matches!(scope, HotkeyScope::Modal(s) if s == m),
-                HotkeyScope::Tab(t) => matches!(scope, HotkeyScope::Tab(s) if s == t),
-                HotkeyScope::Custom(c) => matches!(scope, HotkeyScope::Custom(s) if s == c),
-            })
+            .filter(|hotkey| hotkey.scope.is_active_in(scope))
             .collect()
     }
 
@@ -1,5 +1,8 @@
 pub mod get_active_scope;
 pub mod get_hotkeys;
 pub mod lookup;
+pub mod overrides;
 pub mod register;
+pub mod resolve;
 pub mod set_active_scope;
+pub mod unregister;
@@ -0,0 +1,219 @@
+//! Loading and saving user keymap overrides.
+
+use std::collections::BTreeMap;
+
+use crate::services::hotkey_service::HotkeyRegistry;
+use crate::services::hotkey_service::KeyChord;
+use crate::services::hotkey_service::{KeymapError, KeymapWarning};
+
+impl HotkeyRegistry {
+    /// Rebind hotkeys from a user keymap.
+    ///
+    /// The keymap is TOML mapping action ids, set with
+    /// [`Hotkey::id`](crate::services::hotkey_service::Hotkey::id), to key
+    /// chords. Dotted ids can be written quoted or as tables:
+    ///
+    /// ```toml
+    /// quit = "ctrl+q"
+    /// "palette.open" = "ctrl+shift+p"
+    ///
+    /// [search]
+    /// next = "f3"
+    /// ```
+    ///
+    /// Every hotkey with a matching id gets the new key and keeps its
+    /// description, scope and priority. Hotkeys registered later pick up
+    /// the overrides too, and loading more keymaps adds to the overrides.
+    ///
+    /// # Arguments
+    ///
+    /// * `toml` - The keymap source
+    ///
+    /// # Returns
+    ///
+    /// Warnings for entries that were skipped: ids no registered hotkey
+    /// has. Their overrides are still kept for hotkeys registered later.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`KeymapError`] if the TOML is invalid, an entry is not a
+    /// string, or a chord cannot be parsed. The registry is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratkit::services::hotkey_service::{Hotkey, HotkeyRegistry};
+    ///
+    /// let mut registry = HotkeyRegistry::new();
+    /// registry.register(Hotkey::new("q", "Quit").id("quit"));
+    ///
+    /// let warnings = registry.load_overrides("quit = \"ctrl+q\"").unwrap();
+    /// assert!(warnings.is_empty());
+    /// assert_eq!(registry.get_hotkeys()[0].key, "ctrl+q");
+    /// ```
+    pub fn load_overrides(&mut self, toml: &str) -> Result<Vec<KeymapWarning>, KeymapError> {
+        let table: toml::Table = toml
+            .parse()
+            .map_err(|err: toml::de::Error| KeymapError::Toml(err.message().to_string()))?;
+        let mut overrides = BTreeMap::new();
+        collect_chords(&table, "", &mut overrides)?;
+
+        let mut warnings = Vec::new();
+        for (id, chord) in overrides {
+            let mut found = false;
+            for hotkey in &mut self.hotkeys {
+                if hotkey.id.as_deref() == Some(id.as_str()) {
+                    hotkey.key = chord.to_string();
+                    found = true;
+                }
+            }
+            if !found {
+                warnings.push(KeymapWarning::UnknownAction(id.clone()));
+            }
+            self.overrides.insert(id, chord);
+        }
+        Ok(warnings)
+    }
+
+    /// Write the loaded keymap overrides back out as TOML.
+    ///
+    /// Ids are sorted and chords written in the form
+    /// [`KeyChord`]'s `Display` produces, so the output loads back into the
+    /// same overrides.
+    ///
+    /// # Returns
+    ///
+    /// The keymap as TOML, empty if no overrides were loaded.
+    pub fn export_overrides(&self) -> String {
+        self.overrides
+            .iter()
+            .map(|(id, chord)| (id.clone(), toml::Value::String(chord.to_string())))
+            .collect::<toml::Table>()
+            .to_string()
+    }
+}
+
+/// Adds the chords in `table` to `out`, joining nested table names onto
+/// `prefix` with dots.
+fn collect_chords(
+    table: &toml::Table,
+    prefix: &str,
+    out: &mut BTreeMap<String, KeyChord>,
+) -> Result<(), KeymapError> {
+    for (key, value) in table {
+        let id = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        match value {
+            toml::Value::String(chord) => {
+                let chord = KeyChord::parse(chord).map_err(|source| KeymapError::InvalidChord {
+                    id: id.clone(),
+                    source,
+                })?;
+                out.insert(id, chord);
+            }
+            toml::Value::Table(table) => collect_chords(table, &id, out)?,
+            _ => return Err(KeymapError::NotAString(id)),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::hotkey_service::{ChordParseError, Hotkey, HotkeyScope};
+
+    fn registry() -> HotkeyRegistry {
+        let mut registry = HotkeyRegistry::new();
+        registry.register(Hotkey::new("q", "Quit").id("quit"));
+        registry.register(
+            Hotkey::new("p", "Command palette")
+                .id("palette.open")
+                .scope(HotkeyScope::Tab("Editor"))
+                .priority(2),
+        );
+        registry.register(Hotkey::new("?", "Help"));
+        registry
+    }
+
+    fn keys(registry: &HotkeyRegistry) -> Vec<&str> {
+        registry
+            .get_hotkeys()
+            .iter()
+            .map(|hotkey| hotkey.key.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_overrides_replace_keys_and_round_trip() {
+        let mut registry = registry();
+        let warnings = registry
+            .load_overrides(
+                "quit = \"Ctrl+Q\"\nmissing = \"x\"\n\n[palette]\nopen = \"shift+ctrl+p\"\n",
+            )
+            .unwrap();
+        assert_eq!(
+            warnings,
+            [KeymapWarning::UnknownAction("missing".to_string())]
+        );
+        assert_eq!(keys(&registry), ["ctrl+q", "ctrl+shift+p", "?"]);
+
+        let palette = &registry.get_hotkeys()[1];
+        assert_eq!(palette.description, "Command palette");
+        assert_eq!(palette.scope, HotkeyScope::Tab("Editor"));
+        assert_eq!(palette.priority, 2);
+
+        let exported = registry.export_overrides();
+        assert_eq!(
+            exported,
+            "missing = \"x\"\n\"palette.open\" = \"ctrl+shift+p\"\nquit = \"ctrl+q\"\n"
+        );
+
+        let mut reloaded = self::registry();
+        reloaded.load_overrides(&exported).unwrap();
+        assert_eq!(keys(&reloaded), keys(&registry));
+        assert_eq!(reloaded.export_overrides(), exported);
+
+        // Hotkeys registered after loading pick up their override.
+        reloaded.register(Hotkey::new("m", "Missing").id("missing"));
+        assert_eq!(reloaded.get_hotkeys()[3].key, "x");
+    }
+
+    #[test]
+    fn test_load_errors_leave_registry_unchanged() {
+        let mut registry = registry();
+
+        let err = registry
+            .load_overrides("quit = \"ctrl+q\"\n\"palette.open\" = \"ctrl+ctrl+p\"")
+            .unwrap_err();
+        assert_eq!(
+            err,
+            KeymapError::InvalidChord {
+                id: "palette.open".to_string(),
+                source: ChordParseError::DuplicateModifier {
+                    modifier: "ctrl".to_string(),
+                    chord: "ctrl+ctrl+p".to_string(),
+                },
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "invalid key chord for \"palette.open\": modifier \"ctrl\" appears twice in \"ctrl+ctrl+p\""
+        );
+
+        assert_eq!(
+            registry.load_overrides("quit = 5"),
+            Err(KeymapError::NotAString("quit".to_string()))
+        );
+        assert!(matches!(
+            registry.load_overrides("quit = "),
+            Err(KeymapError::Toml(_))
+        ));
+
+        assert_eq!(keys(&registry), ["q", "p", "?"]);
+        assert!(registry.export_overrides().is_empty());
+    }
+}
@@ -4,6 +4,9 @@ use crate::services::hotkey_service::HotkeyRegistry;
 impl HotkeyRegistry {
     /// Register a hotkey in the registry.
     ///
+    /// If a loaded keymap overrides the hotkey's action id, the hotkey is
+    /// registered with the overriding key.
+    ///
     /// # Arguments
     ///
     /// * `hotkey` - The hotkey to register
@@ -16,7 +19,10 @@ impl HotkeyRegistry {
     /// let mut registry = HotkeyRegistry::new();
     /// registry.register(Hotkey::new("q", "Quit").scope(HotkeyScope::Global));
     /// ```
-    pub fn register(&mut self, hotkey: Hotkey) {
+    pub fn register(&mut self, mut hotkey: Hotkey) {
+        if let Some(chord) = hotkey.id.as_ref().and_then(|id| self.overrides.get(id)) {
+            hotkey.key = chord.to_string();
+        }
         self.hotkeys.push(hotkey);
     }
 }
@@ -0,0 +1,368 @@
+//! Resolving which hotkey a key press triggers, and finding conflicts.
+
+use std::cmp::Ordering;
+
+use crate::services::hotkey_service::Hotkey;
+use crate::services::hotkey_service::HotkeyConflict;
+use crate::services::hotkey_service::HotkeyRegistry;
+use crate::services::hotkey_service::HotkeyScope;
+use crossterm::event::{KeyCode, KeyEvent};
+
+impl HotkeyRegistry {
+    /// Pick the hotkey a key press triggers in the active scope.
+    ///
+    /// Of the hotkeys matching `key` that are active in `active_scope`, the
+    /// one with the highest priority wins. Equal priorities go to the more
+    /// specific scope, so a tab hotkey shadows a global one, and after that
+    /// to the hotkey registered first.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key code that was pressed
+    /// * `active_scope` - The scope that currently has focus
+    ///
+    /// # Returns
+    ///
+    /// `Some(&Hotkey)` for the winning hotkey, `None` if nothing matches.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratkit::services::hotkey_service::{Hotkey, HotkeyRegistry, HotkeyScope};
+    /// use crossterm::event::KeyCode;
+    ///
+    /// let mut registry = HotkeyRegistry::new();
+    /// registry.register(Hotkey::new("j", "Scroll down").scope(HotkeyScope::Global));
+    /// registry.register(Hotkey::new("j", "Next item").scope(HotkeyScope::Tab("List")));
+    ///
+    /// let hotkey = registry.resolve(&KeyCode::Char('j'), &HotkeyScope::Tab("List"));
+    /// assert_eq!(hotkey.unwrap().description, "Next item");
+    /// ```
+    pub fn resolve(&self, key: &KeyCode, active_scope: &HotkeyScope) -> Option<&Hotkey> {
+        self.hotkeys
+            .iter()
+            .enumerate()
+            .filter(|(_, hotkey)| {
+                hotkey.matches_key(key) && hotkey.scope.is_active_in(active_scope)
+            })
+            .min_by(|a, b| resolution_order(*a, *b))
+            .map(|(_, hotkey)| hotkey)
+    }
+
+    /// Pick the hotkey a key event triggers in the active scope.
+    ///
+    /// Like [`resolve`](Self::resolve), but compares modifiers too, so
+    /// `"ctrl+s"` matches Ctrl+S and not a plain `s`. Hotkeys whose key is
+    /// not a valid spec, see [`Hotkey::from_spec`], never match.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The key event that was received
+    /// * `active_scope` - The scope that currently has focus
+    ///
+    /// # Returns
+    ///
+    /// `Some(&Hotkey)` for the winning hotkey, `None` if nothing matches or
+    /// the event is a key release.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratkit::services::hotkey_service::{Hotkey, HotkeyRegistry, HotkeyScope};
+    /// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    ///
+    /// let mut registry = HotkeyRegistry::new();
+    /// registry.register(Hotkey::from_spec("Ctrl+S", "Save").unwrap());
+    ///
+    /// let event = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL);
+    /// let hotkey = registry.match_event(&event, &HotkeyScope::Global);
+    /// assert_eq!(hotkey.unwrap().description, "Save");
+    /// ```
+    pub fn match_event(&self, event: &KeyEvent, active_scope: &HotkeyScope) -> Option<&Hotkey> {
+        self.hotkeys
+            .iter()
+            .enumerate()
+            .filter(|(_, hotkey)| {
+                hotkey.scope.is_active_in(active_scope) && hotkey.matches_event(event)
+            })
+            .min_by(|a, b| resolution_order(*a, *b))
+            .map(|(_, hotkey)| hotkey)
+    }
+
+    /// Find keys registered more than once where the registrations are
+    /// active together.
+    ///
+    /// Keys are compared the way [`match_event`](Self::match_event) matches
+    /// them when they are valid specs, so `"ctrl+s"` and `"s"` do not
+    /// conflict. Two global hotkeys conflict everywhere, and a global hotkey conflicts
+    /// with a hotkey for the same key in any other scope, as do two hotkeys
+    /// in the same scope. Each conflict is reported once per scope, with its
+    /// hotkeys in the order [`resolve`](Self::resolve) ranks them.
+    ///
+    /// # Returns
+    ///
+    /// The conflicts, in the order their keys were first registered.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratkit::services::hotkey_service::{Hotkey, HotkeyRegistry, HotkeyScope};
+    ///
+    /// let mut registry = HotkeyRegistry::new();
+    /// registry.register(Hotkey::new("q", "Quit").scope(HotkeyScope::Global));
+    /// registry.register(Hotkey::new("q", "Close tab").scope(HotkeyScope::Tab("Editor")));
+    ///
+    /// for conflict in registry.conflicts() {
+    ///     println!("{conflict}");
+    /// }
+    /// ```
+    pub fn conflicts(&self) -> Vec<HotkeyConflict> {
+        let mut keys: Vec<Vec<usize>> = Vec::new();
+        for (index, hotkey) in self.hotkeys.iter().enumerate() {
+            match keys
+                .iter_mut()
+                .find(|indices| self.hotkeys[indices[0]].same_key(hotkey))
+            {
+                Some(indices) => indices.push(index),
+                None => keys.push(vec![index]),
+            }
+        }
+
+        let mut conflicts = Vec::new();
+        for indices in keys {
+            let mut scopes: Vec<&HotkeyScope> = vec![&HotkeyScope::Global];
+            for &index in &indices {
+                let scope = &self.hotkeys[index].scope;
+                if !scopes.contains(&scope) {
+                    scopes.push(scope);
+                }
+            }
+
+            for scope in scopes {
+                let mut members: Vec<(usize, &Hotkey)> = indices
+                    .iter()
+                    .map(|&index| (index, &self.hotkeys[index]))
+                    .filter(|(_, hotkey)| hotkey.scope.is_active_in(scope))
+                    .collect();
+                if members.len() < 2 {
+                    continue;
+                }
+                members.sort_by(|a, b| resolution_order(*a, *b));
+
+                let tied = rank(members[0].1) == rank(members[1].1);
+                conflicts.push(HotkeyConflict {
+                    key: members[0].1.key.clone(),
+                    scope: scope.clone(),
+                    hotkeys: members
+                        .into_iter()
+                        .map(|(_, hotkey)| hotkey.clone())
+                        .collect(),
+                    tied,
+                });
+            }
+        }
+        conflicts
+    }
+}
+
+/// Priority, then whether the scope is more specific than global.
+fn rank(hotkey: &Hotkey) -> (u32, bool) {
+    (hotkey.priority, hotkey.scope != HotkeyScope::Global)
+}
+
+/// Orders `(registration index, hotkey)` pairs with the winner first.
+fn resolution_order(a: (usize, &Hotkey), b: (usize, &Hotkey)) -> Ordering {
+    rank(b.1).cmp(&rank(a.1)).then(a.0.cmp(&b.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIST: HotkeyScope = HotkeyScope::Tab("List");
+
+    fn registry(hotkeys: &[Hotkey]) -> HotkeyRegistry {
+        let mut registry = HotkeyRegistry::new();
+        for hotkey in hotkeys {
+            registry.register(hotkey.clone());
+        }
+        registry
+    }
+
+    fn descriptions(conflict: &HotkeyConflict) -> Vec<&str> {
+        conflict
+            .hotkeys
+            .iter()
+            .map(|hotkey| hotkey.description.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_tab_hotkey_shadows_global() {
+        let registry = registry(&[
+            Hotkey::new("j", "Scroll down"),
+            Hotkey::new("j", "Next item").scope(LIST),
+            Hotkey::new("k", "Scroll up"),
+        ]);
+        let key = KeyCode::Char('j');
+
+        assert_eq!(
+            registry.resolve(&key, &LIST).unwrap().description,
+            "Next item"
+        );
+        assert_eq!(
+            registry
+                .resolve(&key, &HotkeyScope::Tab("Other"))
+                .unwrap()
+                .description,
+            "Scroll down"
+        );
+
+        let conflicts = registry.conflicts();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].scope, LIST);
+        assert_eq!(descriptions(&conflicts[0]), ["Next item", "Scroll down"]);
+        assert!(!conflicts[0].tied);
+        assert_eq!(
+            conflicts[0].to_string(),
+            "\"j\" in tab \"List\": 2 hotkeys\n  -> Next item [tab \"List\", priority 0]\n     Scroll down [global, priority 0]"
+        );
+    }
+
+    #[test]
+    fn test_priority_beats_specificity_and_ties_go_to_first_registered() {
+        let registry = registry(&[
+            Hotkey::new("Esc", "Close popup").scope(LIST),
+            Hotkey::new("escape", "Quit").priority(5),
+            Hotkey::new("q", "Quit"),
+            Hotkey::new("Q", "Query"),
+        ]);
+
+        assert_eq!(
+            registry.resolve(&KeyCode::Esc, &LIST).unwrap().description,
+            "Quit"
+        );
+        assert_eq!(
+            registry
+                .resolve(&KeyCode::Char('q'), &HotkeyScope::Global)
+                .unwrap()
+                .description,
+            "Quit"
+        );
+
+        let conflicts = registry.conflicts();
+        assert_eq!(conflicts.len(), 2);
+        assert_eq!(descriptions(&conflicts[0]), ["Quit", "Close popup"]);
+        assert!(!conflicts[0].tied);
+        assert_eq!(conflicts[1].scope, HotkeyScope::Global);
+        assert_eq!(descriptions(&conflicts[1]), ["Quit", "Query"]);
+        assert!(conflicts[1].tied);
+        assert!(conflicts[1]
+            .to_string()
+            .contains("(tied, first registered wins)"));
+    }
+
+    #[test]
+    fn test_same_scope_twice_and_unregister_clears_conflict() {
+        let mut registry = registry(&[
+            Hotkey::new("d", "Delete").scope(LIST),
+            Hotkey::new("d", "Duplicate").scope(LIST).priority(1),
+            Hotkey::new("d", "Diff").scope(HotkeyScope::Tab("Editor")),
+        ]);
+
+        let conflicts = registry.conflicts();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].winner().description, "Duplicate");
+
+        let removed = registry.unregister("D", &LIST);
+        assert_eq!(removed.len(), 2);
+        assert!(registry.conflicts().is_empty());
+        assert_eq!(
+            registry
+                .resolve(&KeyCode::Char('d'), &HotkeyScope::Tab("Editor"))
+                .unwrap()
+                .description,
+            "Diff"
+        );
+        assert!(registry.resolve(&KeyCode::Char('d'), &LIST).is_none());
+    }
+
+    #[test]
+    fn test_conflicts_and_unregister_compare_chords() {
+        let mut registry = registry(&[
+            Hotkey::new("s", "Search"),
+            Hotkey::new("ctrl+s", "Save"),
+            Hotkey::new("Control+S", "Save all"),
+            Hotkey::new("shift+tab", "Previous field"),
+            Hotkey::new("BackTab", "Back"),
+            Hotkey::new("←/→", "Switch"),
+            Hotkey::new("←/→", "Move"),
+        ]);
+
+        let conflicts = registry.conflicts();
+        let groups: Vec<Vec<&str>> = conflicts.iter().map(descriptions).collect();
+        assert_eq!(
+            groups,
+            [
+                vec!["Save", "Save all"],
+                vec!["Previous field", "Back"],
+                vec!["Switch", "Move"],
+            ]
+        );
+
+        let removed = registry.unregister("CTRL+S", &HotkeyScope::Global);
+        assert_eq!(removed.len(), 2);
+        assert_eq!(registry.get_hotkeys().len(), 5);
+        assert_eq!(registry.unregister("←/→", &HotkeyScope::Global).len(), 2);
+        assert_eq!(
+            registry
+                .resolve(&KeyCode::Char('s'), &HotkeyScope::Global)
+                .unwrap()
+                .description,
+            "Search"
+        );
+    }
+
+    #[test]
+    fn test_match_event_compares_modifiers_and_follows_overrides() {
+        use crossterm::event::KeyModifiers;
+
+        let mut registry = registry(&[
+            Hotkey::new("s", "Search"),
+            Hotkey::from_spec("Ctrl+S", "Save").unwrap(),
+            Hotkey::from_spec("cmd+shift+p", "Palette")
+                .unwrap()
+                .scope(LIST)
+                .id("palette"),
+            Hotkey::new("←/→", "Switch"),
+        ]);
+        let ctrl_s = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL);
+        let plain_s = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE);
+        let palette = KeyEvent::new(KeyCode::Char('P'), KeyModifiers::SUPER);
+
+        assert_eq!(
+            registry.match_event(&ctrl_s, &LIST).unwrap().description,
+            "Save"
+        );
+        assert_eq!(
+            registry.match_event(&plain_s, &LIST).unwrap().description,
+            "Search"
+        );
+        assert_eq!(
+            registry.match_event(&palette, &LIST).unwrap().description,
+            "Palette"
+        );
+        assert!(registry
+            .match_event(&palette, &HotkeyScope::Global)
+            .is_none());
+
+        registry.load_overrides("palette = \"f1\"").unwrap();
+        assert!(registry.match_event(&palette, &LIST).is_none());
+        let f1 = KeyEvent::new(KeyCode::F(1), KeyModifiers::NONE);
+        assert_eq!(
+            registry.match_event(&f1, &LIST).unwrap().description,
+            "Palette"
+        );
+        assert!(Hotkey::from_spec("Ctrl+Hyper+P", "Nothing").is_err());
+    }
+}
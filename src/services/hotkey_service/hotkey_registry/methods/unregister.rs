@@ -0,0 +1,44 @@
+//! Removing registered hotkeys.
+
+use crate::services::hotkey_service::Hotkey;
+use crate::services::hotkey_service::HotkeyRegistry;
+use crate::services::hotkey_service::HotkeyScope;
+
+impl HotkeyRegistry {
+    /// Remove the hotkeys registered for a key in a scope.
+    ///
+    /// Keys are compared the same way hotkeys are matched: as chords when
+    /// they are valid specs, so `"Ctrl+S"` removes `"ctrl+s"` but not `"s"`,
+    /// and otherwise case-insensitively, with `"esc"` and `"escape"` treated
+    /// as the same key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Key combination the hotkeys were registered with
+    /// * `scope` - Scope the hotkeys were registered in
+    ///
+    /// # Returns
+    ///
+    /// The removed hotkeys, in registration order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratkit::services::hotkey_service::{Hotkey, HotkeyRegistry, HotkeyScope};
+    ///
+    /// let mut registry = HotkeyRegistry::new();
+    /// registry.register(Hotkey::new("q", "Quit").scope(HotkeyScope::Global));
+    ///
+    /// let removed = registry.unregister("Q", &HotkeyScope::Global);
+    /// assert_eq!(removed.len(), 1);
+    /// assert!(registry.get_hotkeys().is_empty());
+    /// ```
+    pub fn unregister(&mut self, key: &str, scope: &HotkeyScope) -> Vec<Hotkey> {
+        let key = Hotkey::new(key, "");
+        let (removed, kept) = std::mem::take(&mut self.hotkeys)
+            .into_iter()
+            .partition(|hotkey| hotkey.same_key(&key) && &hotkey.scope == scope);
+        self.hotkeys = kept;
+        removed
+    }
+}
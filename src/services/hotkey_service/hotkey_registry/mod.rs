@@ -1,5 +1,8 @@
+use std::collections::BTreeMap;
+
 use crate::services::hotkey_service::Hotkey;
 use crate::services::hotkey_service::HotkeyScope;
+use crate::services::hotkey_service::KeyChord;
 
 pub mod constructors;
 pub mod methods;
@@ -14,4 +17,6 @@ pub struct HotkeyRegistry {
     pub(crate) hotkeys: Vec<Hotkey>,
     /// Active scope for filtering.
     pub(crate) active_scope: Option<HotkeyScope>,
+    /// User keymap overrides by action id.
+    pub(crate) overrides: BTreeMap<String, KeyChord>,
 }
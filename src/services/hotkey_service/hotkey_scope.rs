@@ -1,3 +1,5 @@
+use std::fmt;
+
 /// Scope/context for a hotkey.
 ///
 /// Defines the context in which a hotkey is active, allowing
@@ -13,3 +15,24 @@ pub enum HotkeyScope {
     /// Custom scope.
     Custom(&'static str),
 }
+
+impl HotkeyScope {
+    /// Check whether a hotkey in this scope is active while `active` is the
+    /// current scope.
+    ///
+    /// Global hotkeys are active everywhere; any other scope only in itself.
+    pub fn is_active_in(&self, active: &HotkeyScope) -> bool {
+        matches!(self, HotkeyScope::Global) || self == active
+    }
+}
+
+impl fmt::Display for HotkeyScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HotkeyScope::Global => write!(f, "global"),
+            HotkeyScope::Modal(name) => write!(f, "modal \"{}\"", name),
+            HotkeyScope::Tab(name) => write!(f, "tab \"{}\"", name),
+            HotkeyScope::Custom(name) => write!(f, "custom \"{}\"", name),
+        }
+    }
+}
@@ -0,0 +1,346 @@
+//! Key chords written as strings, e.g. `"ctrl+shift+p"`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use thiserror::Error;
+
+/// A key together with the modifiers held while pressing it.
+///
+/// Parsed from strings like `"ctrl+shift+p"`, `"alt+enter"` or `"f5"`:
+/// modifiers first, the key last, joined by `+`. Names are
+/// case-insensitive, and `"ctrl++"` binds the `+` key itself.
+///
+/// # Example
+///
+/// ```rust
+/// use ratkit::services::hotkey_service::KeyChord;
+/// use crossterm::event::{KeyCode, KeyModifiers};
+///
+/// let chord: KeyChord = "Ctrl+Shift+P".parse().unwrap();
+/// assert_eq!(chord.code, KeyCode::Char('p'));
+/// assert_eq!(chord.modifiers, KeyModifiers::CONTROL | KeyModifiers::SHIFT);
+/// assert_eq!(chord.to_string(), "ctrl+shift+p");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    /// The key, with letters lowercased.
+    pub code: KeyCode,
+    /// Modifiers that must be held.
+    pub modifiers: KeyModifiers,
+}
+
+/// Why a key chord string could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ChordParseError {
+    /// The string was empty.
+    #[error("empty key chord")]
+    Empty,
+    /// The string ended in a modifier, or had an empty part.
+    #[error("missing key in \"{0}\"")]
+    MissingKey(String),
+    /// The last part was not a known key name.
+    #[error("unknown key name \"{name}\" in \"{chord}\"")]
+    UnknownKey {
+        /// The unrecognised name.
+        name: String,
+        /// The whole chord string.
+        chord: String,
+    },
+    /// A part before the key was not a known modifier.
+    #[error("unknown modifier \"{name}\" in \"{chord}\"")]
+    UnknownModifier {
+        /// The unrecognised name.
+        name: String,
+        /// The whole chord string.
+        chord: String,
+    },
+    /// The same modifier was given twice.
+    #[error("modifier \"{modifier}\" appears twice in \"{chord}\"")]
+    DuplicateModifier {
+        /// The repeated modifier, as written the second time.
+        modifier: String,
+        /// The whole chord string.
+        chord: String,
+    },
+}
+
+impl KeyChord {
+    /// Creates a chord from a key and modifiers.
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self { code, modifiers }
+    }
+
+    /// Parses a chord string such as `"ctrl+shift+p"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ChordParseError`] naming the part that could not be
+    /// understood.
+    pub fn parse(chord: &str) -> Result<Self, ChordParseError> {
+        let trimmed = chord.trim();
+        if trimmed.is_empty() {
+            return Err(ChordParseError::Empty);
+        }
+
+        // A trailing "+" after a separator is the plus key itself.
+        let (modifier_part, key_name) = if trimmed == "+" {
+            ("", "+")
+        } else if let Some(rest) = trimmed.strip_suffix("++") {
+            (rest, "+")
+        } else {
+            match trimmed.rsplit_once('+') {
+                Some((modifiers, key)) => (modifiers, key),
+                None => ("", trimmed),
+            }
+        };
+
+        let mut modifiers = KeyModifiers::NONE;
+        if !modifier_part.is_empty() {
+            for name in modifier_part.split('+') {
+                let name = name.trim();
+                if name.is_empty() {
+                    return Err(ChordParseError::MissingKey(chord.to_string()));
+                }
+                let modifier =
+                    parse_modifier(name).ok_or_else(|| ChordParseError::UnknownModifier {
+                        name: name.to_string(),
+                        chord: chord.to_string(),
+                    })?;
+                if modifiers.contains(modifier) {
+                    return Err(ChordParseError::DuplicateModifier {
+                        modifier: name.to_string(),
+                        chord: chord.to_string(),
+                    });
+                }
+                modifiers |= modifier;
+            }
+        }
+
+        let key_name = key_name.trim();
+        if key_name.is_empty() {
+            return Err(ChordParseError::MissingKey(chord.to_string()));
+        }
+        if parse_modifier(key_name).is_some() {
+            return Err(ChordParseError::MissingKey(chord.to_string()));
+        }
+        let code = parse_key(key_name).ok_or_else(|| ChordParseError::UnknownKey {
+            name: key_name.to_string(),
+            chord: chord.to_string(),
+        })?;
+
+        Ok(Self { code, modifiers })
+    }
+
+    /// Whether a key press or repeat is this chord.
+    ///
+    /// Terminals differ in how they report shifted keys, so both sides are
+    /// compared in one form: an uppercase letter is its lowercase letter
+    /// with shift, shift is ignored on other characters (`"?"` matches
+    /// however `?` was typed), shift+tab is back-tab, and modifiers other
+    /// than ctrl, alt, shift and super are ignored. Releases never match.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratkit::services::hotkey_service::KeyChord;
+    /// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    ///
+    /// let chord = KeyChord::parse("ctrl+shift+p").unwrap();
+    /// assert!(chord.matches(&KeyEvent::new(KeyCode::Char('P'), KeyModifiers::CONTROL)));
+    /// ```
+    pub fn matches(&self, event: &KeyEvent) -> bool {
+        event.kind != KeyEventKind::Release
+            && normalize(self.code, self.modifiers) == normalize(event.code, event.modifiers)
+    }
+
+    /// Whether both chords match the same key presses.
+    pub(crate) fn same_keys_as(&self, other: &Self) -> bool {
+        normalize(self.code, self.modifiers) == normalize(other.code, other.modifiers)
+    }
+}
+
+/// The form [`KeyChord::matches`] compares keys in.
+fn normalize(code: KeyCode, modifiers: KeyModifiers) -> (KeyCode, KeyModifiers) {
+    let modifiers = modifiers
+        & (KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT | KeyModifiers::SUPER);
+    match code {
+        KeyCode::Char(c) if c.is_uppercase() => (
+            KeyCode::Char(c.to_lowercase().next().unwrap_or(c)),
+            modifiers | KeyModifiers::SHIFT,
+        ),
+        KeyCode::Char(c) if !c.is_alphabetic() => (code, modifiers - KeyModifiers::SHIFT),
+        KeyCode::Tab if modifiers.contains(KeyModifiers::SHIFT) => {
+            (KeyCode::BackTab, modifiers - KeyModifiers::SHIFT)
+        }
+        KeyCode::BackTab => (code, modifiers - KeyModifiers::SHIFT),
+        _ => (code, modifiers),
+    }
+}
+
+impl FromStr for KeyChord {
+    type Err = ChordParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for KeyChord {
+    /// Writes the chord in the form [`KeyChord::parse`] reads, with
+    /// modifiers in the order ctrl, alt, shift, super.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (modifier, name) in [
+            (KeyModifiers::CONTROL, "ctrl"),
+            (KeyModifiers::ALT, "alt"),
+            (KeyModifiers::SHIFT, "shift"),
+            (KeyModifiers::SUPER, "super"),
+        ] {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{name}+")?;
+            }
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "space"),
+            KeyCode::Char(c) => write!(f, "{c}"),
+            KeyCode::F(n) => write!(f, "f{n}"),
+            KeyCode::Tab => write!(f, "tab"),
+            KeyCode::BackTab => write!(f, "backtab"),
+            KeyCode::Enter => write!(f, "enter"),
+            KeyCode::Esc => write!(f, "esc"),
+            KeyCode::Backspace => write!(f, "backspace"),
+            KeyCode::Delete => write!(f, "delete"),
+            KeyCode::Insert => write!(f, "insert"),
+            KeyCode::Home => write!(f, "home"),
+            KeyCode::End => write!(f, "end"),
+            KeyCode::PageUp => write!(f, "pageup"),
+            KeyCode::PageDown => write!(f, "pagedown"),
+            KeyCode::Up => write!(f, "up"),
+            KeyCode::Down => write!(f, "down"),
+            KeyCode::Left => write!(f, "left"),
+            KeyCode::Right => write!(f, "right"),
+            other => write!(f, "{other:?}"),
+        }
+    }
+}
+
+fn parse_modifier(name: &str) -> Option<KeyModifiers> {
+    match name.to_lowercase().as_str() {
+        "ctrl" | "control" => Some(KeyModifiers::CONTROL),
+        "alt" | "meta" | "option" => Some(KeyModifiers::ALT),
+        "shift" => Some(KeyModifiers::SHIFT),
+        "super" | "cmd" | "win" => Some(KeyModifiers::SUPER),
+        _ => None,
+    }
+}
+
+fn parse_key(name: &str) -> Option<KeyCode> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(KeyCode::Char(c.to_lowercase().next().unwrap_or(c)));
+    }
+
+    let lower = name.to_lowercase();
+    let code = match lower.as_str() {
+        "space" => KeyCode::Char(' '),
+        "plus" => KeyCode::Char('+'),
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "enter" | "return" => KeyCode::Enter,
+        "esc" | "escape" => KeyCode::Esc,
+        "backspace" => KeyCode::Backspace,
+        "delete" | "del" => KeyCode::Delete,
+        "insert" | "ins" => KeyCode::Insert,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" | "pgup" => KeyCode::PageUp,
+        "pagedown" | "pgdn" => KeyCode::PageDown,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        _ => {
+            let n: u8 = lower.strip_prefix('f')?.parse().ok()?;
+            if !(1..=24).contains(&n) {
+                return None;
+            }
+            KeyCode::F(n)
+        }
+    };
+    Some(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display() {
+        let cases = [
+            ("ctrl+shift+p", "ctrl+shift+p"),
+            ("Shift+Ctrl+P", "ctrl+shift+p"),
+            ("alt+Enter", "alt+enter"),
+            ("F5", "f5"),
+            ("escape", "esc"),
+            ("ctrl++", "ctrl++"),
+            ("ctrl+space", "ctrl+space"),
+            ("cmd + k", "super+k"),
+        ];
+        for (input, canonical) in cases {
+            let chord = KeyChord::parse(input).unwrap();
+            assert_eq!(chord.to_string(), canonical, "{input}");
+            assert_eq!(KeyChord::parse(canonical), Ok(chord));
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(KeyChord::parse(" "), Err(ChordParseError::Empty));
+        assert_eq!(
+            KeyChord::parse("ctrl+shift").unwrap_err().to_string(),
+            "missing key in \"ctrl+shift\""
+        );
+        assert_eq!(
+            KeyChord::parse("ctrl++p"),
+            Err(ChordParseError::MissingKey("ctrl++p".to_string()))
+        );
+        assert_eq!(
+            KeyChord::parse("ctrl+pageupp").unwrap_err().to_string(),
+            "unknown key name \"pageupp\" in \"ctrl+pageupp\""
+        );
+        assert_eq!(
+            KeyChord::parse("hyper+p").unwrap_err().to_string(),
+            "unknown modifier \"hyper\" in \"hyper+p\""
+        );
+        assert_eq!(
+            KeyChord::parse("ctrl+Control+p").unwrap_err().to_string(),
+            "modifier \"Control\" appears twice in \"ctrl+Control+p\""
+        );
+        assert!(KeyChord::parse("f25").is_err());
+    }
+
+    #[test]
+    fn test_matches_events_however_shift_is_reported() {
+        let event = |code, modifiers| KeyEvent::new(code, modifiers);
+        let ctrl_shift_p = KeyChord::parse("Ctrl+Shift+P").unwrap();
+        assert!(ctrl_shift_p.matches(&event(KeyCode::Char('P'), KeyModifiers::CONTROL)));
+        assert!(ctrl_shift_p.matches(&event(
+            KeyCode::Char('p'),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT
+        )));
+        assert!(!ctrl_shift_p.matches(&event(KeyCode::Char('p'), KeyModifiers::CONTROL)));
+
+        let question = KeyChord::parse("?").unwrap();
+        assert!(question.matches(&event(KeyCode::Char('?'), KeyModifiers::SHIFT)));
+        let back = KeyChord::parse("shift+tab").unwrap();
+        assert!(back.matches(&event(KeyCode::BackTab, KeyModifiers::SHIFT)));
+        assert!(!back.matches(&event(KeyCode::Tab, KeyModifiers::NONE)));
+
+        let f12 = KeyChord::parse("alt+F12").unwrap();
+        let mut press = event(KeyCode::F(12), KeyModifiers::ALT);
+        assert!(f12.matches(&press));
+        press.kind = KeyEventKind::Release;
+        assert!(!f12.matches(&press));
+    }
+}
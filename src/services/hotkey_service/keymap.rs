@@ -0,0 +1,35 @@
+//! Errors and warnings from loading user keymap overrides.
+
+use thiserror::Error;
+
+use crate::services::hotkey_service::key_chord::ChordParseError;
+
+/// Why a keymap could not be loaded.
+///
+/// Returned by [`HotkeyRegistry::load_overrides`](crate::services::hotkey_service::HotkeyRegistry::load_overrides),
+/// which leaves the registry unchanged when it fails.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum KeymapError {
+    /// The keymap is not valid TOML.
+    #[error("invalid keymap TOML: {0}")]
+    Toml(String),
+    /// An action was bound to something other than a chord string.
+    #[error("expected a key chord string for \"{0}\"")]
+    NotAString(String),
+    /// An action's chord string could not be parsed.
+    #[error("invalid key chord for \"{id}\": {source}")]
+    InvalidChord {
+        /// The action id.
+        id: String,
+        /// What was wrong with the chord.
+        source: ChordParseError,
+    },
+}
+
+/// Something in a keymap that was skipped without failing the load.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum KeymapWarning {
+    /// No registered hotkey has this action id.
+    #[error("unknown action id \"{0}\"")]
+    UnknownAction(String),
+}
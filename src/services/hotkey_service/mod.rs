@@ -26,13 +26,19 @@
 //! }
 //! ```
 
+pub mod hotkey_conflict;
 pub mod hotkey_item;
 pub mod hotkey_registry;
 pub mod hotkey_scope;
+pub mod key_chord;
+pub mod keymap;
 pub mod traits;
 
+pub use hotkey_conflict::HotkeyConflict;
 pub use hotkey_item::Hotkey;
 pub use hotkey_registry::HotkeyRegistry;
 pub use hotkey_scope::HotkeyScope;
+pub use key_chord::{ChordParseError, KeyChord};
+pub use keymap::{KeymapError, KeymapWarning};
 pub use traits::HasHotkeys;
 pub use traits::HotkeyHandler;
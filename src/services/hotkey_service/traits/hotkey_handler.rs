@@ -1,4 +1,5 @@
 use crate::services::hotkey_service::Hotkey;
+use crate::services::hotkey_service::HotkeyRegistry;
 use crate::services::hotkey_service::HotkeyScope;
 use crossterm::event::KeyCode;
 
@@ -57,29 +58,28 @@ pub trait HotkeyHandler {
     ///
     /// `true` if this handler can handle the hotkey.
     fn can_handle(&self, hotkey: &Hotkey, key: &KeyCode, scope: &HotkeyScope) -> bool {
-        let hotkey_key = hotkey.key.to_lowercase();
-        let matches_key = match key {
-            KeyCode::Char(c) => hotkey_key == c.to_string().to_lowercase(),
-            KeyCode::Tab => hotkey_key == "tab",
-            KeyCode::Enter => hotkey_key == "enter",
-            KeyCode::Esc => hotkey_key == "escape" || hotkey_key == "esc",
-            KeyCode::Up => hotkey_key == "up",
-            KeyCode::Down => hotkey_key == "down",
-            KeyCode::Left => hotkey_key == "left",
-            KeyCode::Right => hotkey_key == "right",
-            KeyCode::Backspace => hotkey_key == "backspace",
-            _ => false,
-        };
-
-        if !matches_key {
-            return false;
-        }
+        hotkey.matches_key(key) && hotkey.scope.is_active_in(scope)
+    }
 
-        match &hotkey.scope {
-            HotkeyScope::Global => true,
-            HotkeyScope::Modal(m) => matches!(scope, HotkeyScope::Modal(s) if s == m),
-            HotkeyScope::Tab(t) => matches!(scope, HotkeyScope::Tab(s) if s == t),
-            HotkeyScope::Custom(c) => matches!(scope, HotkeyScope::Custom(s) if s == c),
+    /// Handle a key press if it resolves to a registered hotkey.
+    ///
+    /// When several hotkeys share the key, [`HotkeyRegistry::resolve`]
+    /// picks the one that applies, and this handler only runs if it can
+    /// handle that hotkey.
+    ///
+    /// # Arguments
+    ///
+    /// * `registry` - The registry the hotkeys were registered in
+    /// * `key` - The key code that was pressed
+    /// * `scope` - The current active scope
+    ///
+    /// # Returns
+    ///
+    /// `true` if the hotkey was handled, `false` otherwise.
+    fn dispatch(&mut self, registry: &HotkeyRegistry, key: &KeyCode, scope: &HotkeyScope) -> bool {
+        match registry.resolve(key, scope) {
+            Some(hotkey) if self.can_handle(hotkey, key, scope) => self.handle_hotkey(key, scope),
+            _ => false,
         }
     }
 }
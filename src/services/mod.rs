@@ -1,3 +1,9 @@
+#[cfg(feature = "clipboard")]
+pub mod clipboard;
+
+#[cfg(feature = "external-opener")]
+pub mod external_opener;
+
 #[cfg(feature = "file-watcher")]
 pub mod file_watcher;
 
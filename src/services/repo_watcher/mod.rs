@@ -52,6 +52,7 @@ impl Default for RepoWatchConfig {
             files: WatchConfig {
                 mode: WatchMode::Recursive,
                 debounce_ms: 200,
+                ..WatchConfig::default()
             },
             include_untracked: true,
         }
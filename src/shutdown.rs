@@ -0,0 +1,196 @@
+//! Shutdown hooks for work that must finish before the process exits.
+//!
+//! When the app quits, the runner stops delivering events and polls the
+//! hooks returned by [`CoordinatorApp::shutdown_hooks`](crate::CoordinatorApp::shutdown_hooks)
+//! until they all report [`ShutdownAction::Done`] or the configured
+//! [`shutdown_timeout`](crate::RunnerConfig::shutdown_timeout) elapses.
+
+use std::time::Duration;
+
+/// Result of polling a [`ShutdownHook`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShutdownAction {
+    /// The hook has finished and will not be called again.
+    Done,
+    /// The hook is still working and should be polled on the next tick.
+    Pending,
+}
+
+/// Work that needs to finish before the process exits, such as terminating
+/// child processes, joining threads or flushing writes.
+pub trait ShutdownHook {
+    /// Starts or continues shutting down.
+    ///
+    /// Called once per shutdown tick until it returns [`ShutdownAction::Done`].
+    /// Implementations should not block; return [`ShutdownAction::Pending`]
+    /// and check again on the next call instead.
+    fn on_shutdown(&mut self) -> ShutdownAction;
+
+    /// Name used when logging hooks that did not finish in time.
+    fn shutdown_name(&self) -> &str {
+        std::any::type_name::<Self>()
+    }
+}
+
+/// Outcome of the runner's shutdown phase.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShutdownReport {
+    /// Time spent polling hooks.
+    pub elapsed: Duration,
+    /// Names of hooks still pending when the timeout elapsed.
+    pub unfinished: Vec<String>,
+}
+
+impl ShutdownReport {
+    /// Returns true if every hook finished before the timeout.
+    pub fn is_complete(&self) -> bool {
+        self.unfinished.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::time::{Duration, Instant};
+
+    use super::{ShutdownAction, ShutdownHook};
+    use crate::coordinator::{CoordinatorAction, CoordinatorApp, CoordinatorEvent};
+    use crate::core::{Runner, RunnerAction, RunnerConfig, RunnerEvent};
+    use crate::error::LayoutResult;
+    use crate::events::{KeyboardEvent, ResizeEvent};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    type Log = Rc<RefCell<Vec<String>>>;
+
+    struct Hook {
+        name: &'static str,
+        /// Number of polls before reporting done; `None` never finishes.
+        polls_needed: Option<usize>,
+        polls: usize,
+        log: Log,
+    }
+
+    impl ShutdownHook for Hook {
+        fn on_shutdown(&mut self) -> ShutdownAction {
+            self.polls += 1;
+            self.log
+                .borrow_mut()
+                .push(format!("{}:{}", self.name, self.polls));
+            match self.polls_needed {
+                Some(needed) if self.polls >= needed => ShutdownAction::Done,
+                _ => ShutdownAction::Pending,
+            }
+        }
+
+        fn shutdown_name(&self) -> &str {
+            self.name
+        }
+    }
+
+    struct App {
+        hooks: Vec<Hook>,
+        log: Log,
+    }
+
+    impl App {
+        fn new(hooks: &[(&'static str, Option<usize>)]) -> Self {
+            let log = Log::default();
+            let hooks = hooks
+                .iter()
+                .map(|&(name, polls_needed)| Hook {
+                    name,
+                    polls_needed,
+                    polls: 0,
+                    log: log.clone(),
+                })
+                .collect();
+            Self { hooks, log }
+        }
+    }
+
+    impl CoordinatorApp for App {
+        fn on_event(&mut self, event: CoordinatorEvent) -> LayoutResult<CoordinatorAction> {
+            match event {
+                CoordinatorEvent::Keyboard(_) => {
+                    self.log.borrow_mut().push("quit".to_string());
+                    Ok(CoordinatorAction::Quit)
+                }
+                _ => Ok(CoordinatorAction::Continue),
+            }
+        }
+
+        fn on_draw(&mut self, _frame: &mut ratatui::Frame) {}
+
+        fn shutdown_hooks(&mut self) -> Vec<&mut dyn ShutdownHook> {
+            self.hooks
+                .iter_mut()
+                .map(|hook| hook as &mut dyn ShutdownHook)
+                .collect()
+        }
+    }
+
+    fn runner(app: App, timeout: Duration) -> (Runner<App>, Log) {
+        let log = app.log.clone();
+        let config = RunnerConfig {
+            tick_rate: Duration::from_millis(5),
+            shutdown_timeout: timeout,
+            ..RunnerConfig::default()
+        };
+        let mut runner = Runner::new(app).with_config(config);
+        runner
+            .handle_event(RunnerEvent::Resize(ResizeEvent::new(80, 24)))
+            .unwrap();
+        (runner, log)
+    }
+
+    fn quit_key() -> RunnerEvent {
+        RunnerEvent::Keyboard(KeyboardEvent::from_crossterm(KeyEvent::new(
+            KeyCode::Char('q'),
+            KeyModifiers::NONE,
+        )))
+    }
+
+    #[test]
+    fn hooks_run_in_order_after_the_last_event() {
+        let app = App::new(&[("pty", Some(2)), ("cache", Some(1))]);
+        let (mut runner, log) = runner(app, Duration::from_secs(2));
+
+        assert_eq!(runner.handle_event(quit_key()).unwrap(), RunnerAction::Quit);
+        let report = runner.shutdown(|_| {});
+
+        assert!(report.is_complete());
+        assert_eq!(*log.borrow(), ["quit", "pty:1", "cache:1", "pty:2"]);
+
+        // Events are no longer delivered once shutdown has started.
+        assert_eq!(runner.handle_event(quit_key()).unwrap(), RunnerAction::Quit);
+        assert_eq!(log.borrow().len(), 4);
+    }
+
+    #[test]
+    fn slow_hook_is_abandoned_after_the_timeout() {
+        let app = App::new(&[("fast", Some(1)), ("stuck", None)]);
+        let timeout = Duration::from_millis(60);
+        let (mut runner, log) = runner(app, timeout);
+
+        let started = Instant::now();
+        let mut pending_calls = 0;
+        let report = runner.shutdown(|_| pending_calls += 1);
+
+        assert!(started.elapsed() >= timeout);
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert_eq!(report.unfinished, ["stuck"]);
+        assert!(pending_calls > 0);
+        let log = log.borrow();
+        assert_eq!(
+            log.iter().filter(|entry| entry.starts_with("fast")).count(),
+            1
+        );
+        assert!(
+            log.iter()
+                .filter(|entry| entry.starts_with("stuck"))
+                .count()
+                > 1
+        );
+    }
+}
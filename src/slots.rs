@@ -0,0 +1,239 @@
+//! Named layout slots: header, footer, sidebar and content.
+
+use std::collections::HashSet;
+
+use ratatui::layout::{Constraint, Layout, Rect};
+
+use crate::types::Region;
+
+/// A named area of the app skeleton.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Slot {
+    /// Full-width strip at the top.
+    Header,
+    /// Full-width strip at the bottom.
+    Footer,
+    /// Column on the left, between header and footer.
+    Sidebar,
+    /// The rest of the screen.
+    Content,
+}
+
+impl Slot {
+    /// The region elements in this slot belong to, which decides their
+    /// focus order.
+    pub(crate) fn region(self) -> Region {
+        match self {
+            Slot::Header => Region::Top,
+            Slot::Footer => Region::Bottom,
+            Slot::Sidebar | Slot::Content => Region::Center,
+        }
+    }
+}
+
+/// Sizes of the slots.
+///
+/// Header and footer sizes are heights, sidebar and content sizes are
+/// widths. A `Length(0)` header or footer, or a `None` sidebar, leaves the
+/// slot out.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::layout::{Constraint, Rect};
+/// use ratkit::{Slot, SlotLayout, SlotSpec};
+///
+/// let mut slots = SlotLayout::new(SlotSpec {
+///     header: Constraint::Length(3),
+///     footer: Constraint::Length(1),
+///     sidebar: Some(Constraint::Percentage(25)),
+///     content: Constraint::Fill(1),
+/// });
+/// let areas = slots.split(Rect::new(0, 0, 80, 24));
+/// assert_eq!(areas.sidebar, Rect::new(0, 3, 20, 20));
+/// assert_eq!(areas.content, Rect::new(20, 3, 60, 20));
+///
+/// slots.set_visible(Slot::Sidebar, false);
+/// assert_eq!(slots.split(Rect::new(0, 0, 80, 24)).content, Rect::new(0, 3, 80, 20));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SlotSpec {
+    /// Height of the header.
+    pub header: Constraint,
+    /// Height of the footer.
+    pub footer: Constraint,
+    /// Width of the sidebar, if there is one.
+    pub sidebar: Option<Constraint>,
+    /// Width of the content beside the sidebar.
+    pub content: Constraint,
+}
+
+impl Default for SlotSpec {
+    /// Content only: no header, footer or sidebar.
+    fn default() -> Self {
+        Self {
+            header: Constraint::Length(0),
+            footer: Constraint::Length(0),
+            sidebar: None,
+            content: Constraint::Fill(1),
+        }
+    }
+}
+
+/// Rects computed for each slot. Hidden slots get an empty rect.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SlotAreas {
+    /// Header rect.
+    pub header: Rect,
+    /// Footer rect.
+    pub footer: Rect,
+    /// Sidebar rect.
+    pub sidebar: Rect,
+    /// Content rect.
+    pub content: Rect,
+}
+
+impl SlotAreas {
+    /// The rect of `slot`.
+    pub fn get(&self, slot: Slot) -> Rect {
+        match slot {
+            Slot::Header => self.header,
+            Slot::Footer => self.footer,
+            Slot::Sidebar => self.sidebar,
+            Slot::Content => self.content,
+        }
+    }
+
+    /// The band between header and footer, holding sidebar and content.
+    pub fn body(&self) -> Rect {
+        self.sidebar.union(self.content)
+    }
+}
+
+/// A [`SlotSpec`] together with which slots are hidden.
+///
+/// Hiding a slot gives its space to the content slot. The content slot is
+/// always shown. Apps can split their frame with one directly in
+/// `on_draw`, or define the slots on the [`LayoutCoordinator`] so
+/// registered elements are placed in them.
+///
+/// [`LayoutCoordinator`]: crate::LayoutCoordinator
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlotLayout {
+    spec: SlotSpec,
+    hidden: HashSet<Slot>,
+}
+
+impl SlotLayout {
+    /// Create a layout with every slot shown.
+    pub fn new(spec: SlotSpec) -> Self {
+        Self {
+            spec,
+            hidden: HashSet::new(),
+        }
+    }
+
+    /// The slot sizes.
+    pub fn spec(&self) -> &SlotSpec {
+        &self.spec
+    }
+
+    /// Replace the slot sizes, keeping hidden slots hidden.
+    pub fn set_spec(&mut self, spec: SlotSpec) {
+        self.spec = spec;
+    }
+
+    /// Whether `slot` is shown. A slot the spec leaves out still counts as
+    /// shown unless it was hidden.
+    pub fn is_visible(&self, slot: Slot) -> bool {
+        !self.hidden.contains(&slot)
+    }
+
+    /// Show or hide `slot`. Returns whether that changed anything.
+    ///
+    /// The content slot cannot be hidden.
+    pub fn set_visible(&mut self, slot: Slot, visible: bool) -> bool {
+        if slot == Slot::Content {
+            return false;
+        }
+        if visible {
+            self.hidden.remove(&slot)
+        } else {
+            self.hidden.insert(slot)
+        }
+    }
+
+    /// Show `slot` if hidden, or hide it. Returns whether it is now shown.
+    pub fn toggle(&mut self, slot: Slot) -> bool {
+        let visible = !self.is_visible(slot);
+        self.set_visible(slot, visible);
+        self.is_visible(slot)
+    }
+
+    /// Split `area` into slot rects.
+    pub fn split(&self, area: Rect) -> SlotAreas {
+        let shown = |slot: Slot, constraint: Constraint| {
+            if self.is_visible(slot) {
+                constraint
+            } else {
+                Constraint::Length(0)
+            }
+        };
+        let [header, body, footer] = Layout::vertical([
+            shown(Slot::Header, self.spec.header),
+            Constraint::Fill(1),
+            shown(Slot::Footer, self.spec.footer),
+        ])
+        .areas(area);
+
+        let (sidebar, content) = match self.spec.sidebar {
+            Some(sidebar) if self.is_visible(Slot::Sidebar) => {
+                let [sidebar, content] =
+                    Layout::horizontal([sidebar, self.spec.content]).areas(body);
+                (sidebar, content)
+            }
+            _ => (Rect::new(body.x, body.y, 0, body.height), body),
+        };
+
+        SlotAreas {
+            header,
+            footer,
+            sidebar,
+            content,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hidden_slots_give_space_to_content() {
+        let area = Rect::new(0, 0, 100, 30);
+        let mut slots = SlotLayout::new(SlotSpec {
+            header: Constraint::Length(3),
+            footer: Constraint::Length(1),
+            sidebar: Some(Constraint::Length(20)),
+            content: Constraint::Fill(1),
+        });
+
+        let areas = slots.split(area);
+        assert_eq!(areas.header, Rect::new(0, 0, 100, 3));
+        assert_eq!(areas.footer, Rect::new(0, 29, 100, 1));
+        assert_eq!(areas.sidebar, Rect::new(0, 3, 20, 26));
+        assert_eq!(areas.content, Rect::new(20, 3, 80, 26));
+        assert_eq!(areas.body(), Rect::new(0, 3, 100, 26));
+
+        assert!(!slots.toggle(Slot::Sidebar));
+        assert!(slots.set_visible(Slot::Header, false));
+        assert!(!slots.set_visible(Slot::Content, false));
+        let areas = slots.split(area);
+        assert!(areas.header.is_empty());
+        assert!(areas.sidebar.is_empty());
+        assert_eq!(areas.content, Rect::new(0, 0, 100, 29));
+
+        assert!(slots.toggle(Slot::Sidebar));
+        assert_eq!(slots.split(area).content, Rect::new(20, 0, 80, 29));
+    }
+}
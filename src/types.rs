@@ -5,6 +5,8 @@ use std::fmt;
 use std::time::{Duration, Instant};
 use uuid::Uuid;
 
+use crate::slots::{Slot, SlotAreas};
+
 /// Unique identifier for a UI element.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ElementId(Uuid);
@@ -74,6 +76,8 @@ pub struct ElementMetadata {
     pub fixed_height: Option<u16>,
     /// Optional capture state for mouse events.
     pub mouse_capture: Option<ElementId>,
+    /// Slot the element fills, when the coordinator has slots defined.
+    pub slot: Option<Slot>,
 }
 
 impl ElementMetadata {
@@ -87,9 +91,22 @@ impl ElementMetadata {
             rect: Rect::default(),
             fixed_height: None,
             mouse_capture: None,
+            slot: None,
         }
     }
 
+    /// Create metadata for an element that fills `slot`.
+    pub fn in_slot(id: ElementId, slot: Slot) -> Self {
+        Self::new(id, slot.region()).with_slot(slot)
+    }
+
+    /// Place the element in `slot`, moving it to the slot's region.
+    pub fn with_slot(mut self, slot: Slot) -> Self {
+        self.region = slot.region();
+        self.slot = Some(slot);
+        self
+    }
+
     pub fn with_visibility(mut self, visibility: Visibility) -> Self {
         self.visibility = visibility;
         self
@@ -134,6 +151,8 @@ pub struct LayoutState {
     pub top_height: u16,
     /// Total height allocated to bottom region.
     pub bottom_height: u16,
+    /// Calculated slot areas, when slots are defined.
+    pub slot_areas: SlotAreas,
 }
 
 impl LayoutState {
@@ -145,6 +164,7 @@ impl LayoutState {
             bottom_area: Rect::default(),
             top_height: 0,
             bottom_height: 0,
+            slot_areas: SlotAreas::default(),
         }
     }
 }
@@ -1,13 +1,27 @@
 //! AI Chat Widget for interactive chat interfaces.
 //!
 //! Provides a chat interface with:
-//! - Multi-line text input (Ctrl+J for newline)
+//! - Multi-line text input (Shift+Enter or Ctrl+J for newline) that grows
+//!   with its content, with Up/Down recall of sent messages
 //! - File attachments via @ prefix with fuzzy search
-//! - Commands via / prefix (e.g., /clear)
+//! - Slash commands typed at the start of the input (e.g., /clear), with a
+//!   fuzzy-filtered menu and argument completion
 //! - Message history display
 //! - Loading spinner for AI responses
-
-use crate::widgets::ai_chat::{InputState, Message, MessageRole, MessageStore};
+//! - Streamed AI responses, rendered as markdown with the
+//!   `markdown-preview` feature, kept in view as they grow unless the user
+//!   has scrolled up. Only streaming messages are re-rendered each frame
+//! - A message cursor, moved with Up from an empty input, and a menu of
+//!   actions on the focused message
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::widgets::ai_chat::command::fuzzy_score;
+use crate::widgets::ai_chat::{
+    ArgCompletion, InputState, Message, MessageId, MessageRole, MessageStore, SlashCommand,
+};
 use ratatui::style::Style;
 
 /// Result of handling a key event.
@@ -19,8 +33,52 @@ pub enum AIChatEvent {
     MessageSubmitted(String),
     /// File attached
     FileAttached(String),
-    /// Command executed
-    Command(String),
+    /// A slash command was picked from the command menu or submitted.
+    ///
+    /// Built-in commands have already run when this is returned.
+    Command {
+        /// Command name, without the slash
+        name: String,
+        /// Text after the command name, trimmed
+        args: String,
+    },
+    /// A streamed AI message was finished.
+    MessageCompleted {
+        /// The finished message
+        id: MessageId,
+    },
+    /// An action was picked from the menu of the focused message.
+    ///
+    /// [`MessageAction::Copy`] has already copied the message when the
+    /// `clipboard` feature is enabled; the app handles the others.
+    MessageAction {
+        /// The focused message
+        id: MessageId,
+        /// The action picked
+        action: MessageAction,
+    },
+}
+
+/// An action offered in the menu of the focused message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageAction {
+    /// Copy the message text
+    Copy,
+    /// Generate an AI message again
+    Retry,
+    /// Remove the message
+    Delete,
+}
+
+impl MessageAction {
+    /// Label shown in the actions menu.
+    pub fn label(self) -> &'static str {
+        match self {
+            MessageAction::Copy => "Copy",
+            MessageAction::Retry => "Retry",
+            MessageAction::Delete => "Delete",
+        }
+    }
 }
 
 /// AI Chat widget for interactive chat interfaces.
@@ -39,12 +97,41 @@ pub struct AIChat {
     input_style: Style,
     /// Prompt text for input
     input_prompt: String,
-    /// Available commands
-    commands: Vec<String>,
-    /// Selected command index in command mode
+    /// Available slash commands
+    commands: Vec<SlashCommand>,
+    /// Selected entry in the command menu
     selected_command_index: usize,
+    /// First message row shown, or `None` to follow the newest output
+    scroll_top: Option<usize>,
+    /// Rows of messages and the height they were shown in at the last
+    /// render
+    last_layout: Cell<(usize, usize)>,
+    /// Most rows the input grows to before scrolling
+    max_input_height: u16,
+    /// First input row shown at the last render
+    input_scroll: Cell<usize>,
+    /// Width streamed markdown is rendered at
+    #[cfg(feature = "markdown-preview")]
+    markdown_width: Option<usize>,
+    /// Rendered lines of finished messages
+    line_cache: RefCell<HashMap<MessageId, Vec<Line<'static>>>>,
+    /// Whether the streaming cursor blinks
+    blink_cursor: bool,
+    /// When the chat was created, the clock the cursor blinks by
+    created: Instant,
+    /// Message the message cursor is on
+    focused: Option<MessageId>,
+    /// Selected entry of the actions menu, while it is open
+    action_index: Option<usize>,
+    /// Key that opens the actions menu on the focused message
+    actions_key: crossterm::event::KeyCode,
+    /// Style patched onto the focused message
+    focused_message_style: Style,
 }
 
+/// How long the blinking streaming cursor stays shown, then hidden.
+const CURSOR_BLINK: Duration = Duration::from_millis(530);
+
 impl AIChat {
     /// Create a new AI chat widget.
     pub fn new() -> Self {
@@ -58,8 +145,24 @@ impl AIChat {
             ai_message_style: Style::default().fg(Color::White),
             input_style: Style::default().fg(Color::White),
             input_prompt: "You: ".to_string(),
-            commands: vec!["/clear".to_string()],
+            commands: vec![
+                SlashCommand::new("clear", "Clear the conversation"),
+                SlashCommand::new("export", "Copy the conversation as markdown"),
+            ],
             selected_command_index: 0,
+            scroll_top: None,
+            last_layout: Cell::new((0, 0)),
+            max_input_height: 8,
+            input_scroll: Cell::new(0),
+            #[cfg(feature = "markdown-preview")]
+            markdown_width: None,
+            line_cache: RefCell::new(HashMap::new()),
+            blink_cursor: false,
+            created: Instant::now(),
+            focused: None,
+            action_index: None,
+            actions_key: crossterm::event::KeyCode::Enter,
+            focused_message_style: Style::default().bg(Color::DarkGray),
         }
     }
 
@@ -69,26 +172,56 @@ impl AIChat {
         self
     }
 
-    /// Register a command.
-    pub fn register_command(&mut self, command: String) {
-        if !self.commands.contains(&command) {
-            self.commands.push(command);
+    /// Register a slash command, replacing any command with the same name.
+    ///
+    /// `/clear` and `/export` are registered by default.
+    pub fn register_command(&mut self, command: SlashCommand) {
+        match self.commands.iter_mut().find(|c| c.name == command.name) {
+            Some(existing) => *existing = command,
+            None => self.commands.push(command),
         }
     }
 
     /// Get available commands.
-    pub fn commands(&self) -> &[String] {
+    pub fn commands(&self) -> &[SlashCommand] {
         &self.commands
     }
 
-    /// Get filtered commands matching the current command input.
-    pub fn filtered_commands(&self) -> Vec<String> {
-        let command_lower = self.input.command().to_lowercase();
-        self.commands
+    /// Get the commands matching the command name being typed, best match
+    /// first.
+    ///
+    /// Empty outside command mode and once the name is followed by a space.
+    pub fn filtered_commands(&self) -> Vec<&SlashCommand> {
+        let typed = self.input.command();
+        if !self.input.is_command_mode() || typed.contains(' ') {
+            return Vec::new();
+        }
+
+        let mut scored: Vec<(i32, &SlashCommand)> = self
+            .commands
             .iter()
-            .filter(|c| c.to_lowercase().starts_with(&format!("/{}", command_lower)))
-            .cloned()
-            .collect()
+            .filter_map(|command| Some((fuzzy_score(typed, &command.name)?, command)))
+            .collect();
+        scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        scored.into_iter().map(|(_, command)| command).collect()
+    }
+
+    /// Get the completions for the argument being typed after a command
+    /// name, e.g. file paths for `/attach src/`.
+    pub fn argument_completions(&self) -> Vec<String> {
+        let Some((command, args)) = self.typed_command() else {
+            return Vec::new();
+        };
+        let partial = if args.ends_with(' ') {
+            ""
+        } else {
+            args.rsplit(' ').next().unwrap_or("")
+        };
+        match &command.completion {
+            ArgCompletion::None => Vec::new(),
+            ArgCompletion::Files => self.input.files_matching(partial),
+            ArgCompletion::Custom(complete) => complete(partial),
+        }
     }
 
     /// Get selected command index.
@@ -101,19 +234,184 @@ impl AIChat {
         self.selected_command_index = index;
     }
 
-    /// Handle a command string (e.g., "/clear").
+    /// Run a built-in command given as typed, e.g. `"/clear"`.
+    ///
+    /// `/clear` removes all messages. `/export` copies the conversation as
+    /// markdown to the clipboard when the `clipboard` feature is enabled;
+    /// the app can also save [`transcript`](Self::transcript) itself.
     ///
     /// Returns true if command was handled, false if unknown.
     pub fn handle_command(&mut self, command: &str) -> bool {
-        match command {
-            "/clear" => {
+        let name = command
+            .trim_start_matches('/')
+            .split_whitespace()
+            .next()
+            .unwrap_or("");
+        match name {
+            "clear" => {
                 self.messages.clear();
+                self.line_cache.get_mut().clear();
+                self.focused = None;
+                self.action_index = None;
+                true
+            }
+            "export" => {
+                #[cfg(feature = "clipboard")]
+                crate::services::clipboard::copy(
+                    &self.transcript(),
+                    crate::services::clipboard::ClipboardSource::Chat,
+                )
+                .ok();
                 true
             }
             _ => false,
         }
     }
 
+    /// The conversation as markdown, one section per message.
+    pub fn transcript(&self) -> String {
+        let mut transcript = String::new();
+        for message in self.messages.messages() {
+            let heading = match message.role {
+                MessageRole::User => "You",
+                MessageRole::Assistant => "AI",
+            };
+            if !transcript.is_empty() {
+                transcript.push('\n');
+            }
+            transcript.push_str(&format!("## {}\n\n", heading));
+            for file in &message.attachments {
+                transcript.push_str(&format!("Attached: @{}\n\n", file));
+            }
+            transcript.push_str(message.content.trim_end());
+            transcript.push('\n');
+        }
+        transcript
+    }
+
+    /// The registered command whose name was typed in full, followed by a
+    /// space, and the text after that space.
+    fn typed_command(&self) -> Option<(&SlashCommand, &str)> {
+        if !self.input.is_command_mode() {
+            return None;
+        }
+        let (name, args) = self.input.command().split_once(' ')?;
+        let command = self.commands.iter().find(|c| c.name == name)?;
+        Some((command, args))
+    }
+
+    /// Entries of the command menu: matching commands while the name is
+    /// typed, then argument completions.
+    fn command_menu(&self) -> Vec<(String, String)> {
+        if self.typed_command().is_some() {
+            return self
+                .argument_completions()
+                .into_iter()
+                .map(|completion| (completion, String::new()))
+                .collect();
+        }
+        self.filtered_commands()
+            .into_iter()
+            .map(|command| {
+                let label = match &command.args {
+                    Some(args) => format!("/{} {}", command.name, args),
+                    None => format!("/{}", command.name),
+                };
+                (label, command.description.clone())
+            })
+            .collect()
+    }
+
+    /// Handles keys the command menu uses. Returns `None` for keys that
+    /// should go to the input as usual.
+    fn handle_command_menu_key(&mut self, key: crossterm::event::KeyCode) -> Option<AIChatEvent> {
+        use crossterm::event::KeyCode;
+
+        let menu_len = self.command_menu().len();
+        match key {
+            KeyCode::Up if menu_len > 0 => {
+                self.selected_command_index =
+                    (self.selected_command_index + menu_len - 1) % menu_len;
+                Some(AIChatEvent::None)
+            }
+            KeyCode::Down if menu_len > 0 => {
+                self.selected_command_index = (self.selected_command_index + 1) % menu_len;
+                Some(AIChatEvent::None)
+            }
+            KeyCode::Tab => {
+                self.complete_selected();
+                Some(AIChatEvent::None)
+            }
+            KeyCode::Enter => {
+                if let Some((command, args)) = self.typed_command() {
+                    let (name, args) = (command.name.clone(), args.trim().to_string());
+                    if self.complete_selected() {
+                        return Some(AIChatEvent::None);
+                    }
+                    return Some(self.run_command(name, args));
+                }
+                let command = self
+                    .filtered_commands()
+                    .get(self.selected_command_index)
+                    .map(|command| (command.name.clone(), command.takes_args()))?;
+                match command {
+                    (_, true) => {
+                        self.complete_selected();
+                        Some(AIChatEvent::None)
+                    }
+                    (name, false) => Some(self.run_command(name, String::new())),
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Completes the selected menu entry into the input. Returns false if
+    /// nothing was selected or the input already held the completion.
+    fn complete_selected(&mut self) -> bool {
+        let text = self.input.text();
+        let completed = if let Some((command, args)) = self.typed_command() {
+            let Some(completion) = self
+                .argument_completions()
+                .get(self.selected_command_index)
+                .cloned()
+            else {
+                return false;
+            };
+            let kept = args.rfind(' ').map_or("", |space| &args[..=space]);
+            format!("/{} {}{}", command.name, kept, completion)
+        } else {
+            let Some(command) = self
+                .filtered_commands()
+                .get(self.selected_command_index)
+                .copied()
+            else {
+                return false;
+            };
+            if command.takes_args() {
+                format!("/{} ", command.name)
+            } else {
+                format!("/{}", command.name)
+            }
+        };
+
+        if completed == text {
+            return false;
+        }
+        self.input.set_text(&completed);
+        self.selected_command_index = 0;
+        true
+    }
+
+    /// Clears the input, runs the command if it is a built-in and returns
+    /// the event for it.
+    fn run_command(&mut self, name: String, args: String) -> AIChatEvent {
+        self.input.clear();
+        self.selected_command_index = 0;
+        self.handle_command(&format!("/{}", name));
+        AIChatEvent::Command { name, args }
+    }
+
     /// Set the loading state.
     pub fn set_loading(&mut self, loading: bool) {
         self.is_loading = loading;
@@ -124,6 +422,118 @@ impl AIChat {
         self.is_loading
     }
 
+    /// Start a streamed AI message, ending the loading state, and return
+    /// its id.
+    ///
+    /// Feed the reply in with [`append_to_message`](Self::append_to_message)
+    /// and end it with [`finish_message`](Self::finish_message). The message
+    /// shows a cursor while it streams; see
+    /// [`with_blinking_cursor`](Self::with_blinking_cursor).
+    pub fn begin_assistant_message(&mut self) -> MessageId {
+        let id = self.messages.begin_assistant_message();
+        #[cfg(feature = "markdown-preview")]
+        if let (Some(width), Some(message)) = (self.markdown_width, self.messages.get_mut(id)) {
+            message.set_markdown_width(width);
+        }
+        self.is_loading = false;
+        id
+    }
+
+    /// Append streamed text to message `id`.
+    ///
+    /// The view follows the new text unless the user has scrolled up.
+    /// Returns false if there is no such message or it is finished.
+    pub fn append_to_message(&mut self, id: MessageId, chunk: &str) -> bool {
+        self.messages.append_to(id, chunk)
+    }
+
+    /// Mark message `id` as complete.
+    ///
+    /// Returns [`AIChatEvent::MessageCompleted`], or [`AIChatEvent::None`]
+    /// if there is no such message or it was already finished.
+    pub fn finish_message(&mut self, id: MessageId) -> AIChatEvent {
+        if self.messages.finish(id) {
+            AIChatEvent::MessageCompleted { id }
+        } else {
+            AIChatEvent::None
+        }
+    }
+
+    /// Start a streamed AI response, ending the loading state.
+    ///
+    /// Feed the response in with [`append_response`](Self::append_response)
+    /// and end it with [`finish_response`](Self::finish_response).
+    pub fn start_response(&mut self) {
+        self.begin_assistant_message();
+    }
+
+    /// Append text to the streamed AI response, starting one if the last
+    /// message is not a streaming AI message.
+    pub fn append_response(&mut self, chunk: &str) {
+        let id = match self.messages.last() {
+            Some(m) if m.role == MessageRole::Assistant && m.is_streaming() => m.id(),
+            _ => self.begin_assistant_message(),
+        };
+        self.append_to_message(id, chunk);
+    }
+
+    /// Mark the streamed AI response as complete.
+    ///
+    /// Returns [`AIChatEvent::MessageCompleted`] if the last message was a
+    /// streaming AI message.
+    pub fn finish_response(&mut self) -> AIChatEvent {
+        match self.messages.last() {
+            Some(m) if m.role == MessageRole::Assistant => self.finish_message(m.id()),
+            _ => AIChatEvent::None,
+        }
+    }
+
+    /// Scroll the messages up by `rows`, so new output no longer moves the
+    /// view.
+    pub fn scroll_up(&mut self, rows: usize) {
+        let top = self.scroll_top.unwrap_or_else(|| self.max_scroll_top());
+        self.scroll_top = Some(top.saturating_sub(rows));
+    }
+
+    /// Scroll the messages down by `rows`. Reaching the bottom follows new
+    /// output again.
+    pub fn scroll_down(&mut self, rows: usize) {
+        if let Some(top) = self.scroll_top {
+            let top = top + rows;
+            self.scroll_top = (top < self.max_scroll_top()).then_some(top);
+        }
+    }
+
+    /// Scroll to the newest message and follow new output.
+    pub fn scroll_to_bottom(&mut self) {
+        self.scroll_top = None;
+    }
+
+    /// Whether the view follows new output, i.e. the user has not scrolled
+    /// up.
+    pub fn is_following(&self) -> bool {
+        self.scroll_top.is_none()
+    }
+
+    /// Largest first row that still fills the view, as of the last render.
+    fn max_scroll_top(&self) -> usize {
+        let (rows, height) = self.last_layout.get();
+        rows.saturating_sub(height)
+    }
+
+    /// Set the width streamed responses are rendered at, usually the width
+    /// of the chat area.
+    ///
+    /// Already rendered responses are re-laid out without parsing again.
+    #[cfg(feature = "markdown-preview")]
+    pub fn set_markdown_width(&mut self, width: usize) {
+        self.markdown_width = Some(width);
+        self.line_cache.get_mut().clear();
+        for message in self.messages.messages_mut() {
+            message.set_markdown_width(width);
+        }
+    }
+
     /// Set user message style.
     pub fn with_user_message_style(mut self, style: Style) -> Self {
         self.user_message_style = style;
@@ -148,31 +558,222 @@ impl AIChat {
         self
     }
 
-    /// Handle a key event.
+    /// Make the cursor after a streaming message blink (default off).
     ///
-    /// Returns an event indicating what happened.
+    /// The cursor only changes when the chat is drawn, so the app should
+    /// redraw on a timer of a few hundred milliseconds while a message
+    /// streams.
+    pub fn with_blinking_cursor(mut self, blink: bool) -> Self {
+        self.blink_cursor = blink;
+        self
+    }
+
+    /// Set the key that opens the actions menu on the focused message
+    /// (default Enter).
+    pub fn with_actions_key(mut self, key: crossterm::event::KeyCode) -> Self {
+        self.actions_key = key;
+        self
+    }
+
+    /// Set the style patched onto the lines of the focused message
+    /// (default a dark gray background).
+    pub fn with_focused_message_style(mut self, style: Style) -> Self {
+        self.focused_message_style = style;
+        self
+    }
+
+    /// Get the message the message cursor is on.
+    pub fn focused_message(&self) -> Option<MessageId> {
+        self.focused
+    }
+
+    /// Put the message cursor on message `id`, or give focus back to the
+    /// input with `None`. Closes the actions menu.
+    pub fn focus_message(&mut self, id: Option<MessageId>) {
+        self.focused = id;
+        self.action_index = None;
+    }
+
+    /// Whether the actions menu of the focused message is open.
+    pub fn is_actions_menu_open(&self) -> bool {
+        self.action_index.is_some()
+    }
+
+    /// Get the actions offered for message `id`: Copy, Retry for AI
+    /// messages, and Delete. Empty if there is no such message.
+    pub fn message_actions(&self, id: MessageId) -> Vec<MessageAction> {
+        match self.messages.get(id).map(|message| message.role) {
+            Some(MessageRole::User) => vec![MessageAction::Copy, MessageAction::Delete],
+            Some(MessageRole::Assistant) => vec![
+                MessageAction::Copy,
+                MessageAction::Retry,
+                MessageAction::Delete,
+            ],
+            None => Vec::new(),
+        }
+    }
+
+    /// Handles keys while a message is focused. Returns `None` for keys
+    /// that give focus back to the input and should go to it as usual.
+    fn handle_focus_key(&mut self, key: crossterm::event::KeyCode) -> Option<AIChatEvent> {
+        use crossterm::event::KeyCode;
+
+        let messages = self.messages.messages();
+        let Some(index) = self
+            .focused
+            .and_then(|id| messages.iter().position(|message| message.id() == id))
+        else {
+            self.focused = None;
+            return None;
+        };
+        match key {
+            key if key == self.actions_key => self.action_index = Some(0),
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.focused = Some(messages[index.saturating_sub(1)].id());
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.focused = messages.get(index + 1).map(Message::id);
+            }
+            KeyCode::Esc => self.focused = None,
+            _ => {
+                self.focused = None;
+                return None;
+            }
+        }
+        Some(AIChatEvent::None)
+    }
+
+    /// Handles keys while the actions menu is open.
+    fn handle_actions_key(&mut self, key: crossterm::event::KeyCode) -> AIChatEvent {
+        use crossterm::event::KeyCode;
+
+        let (Some(id), Some(selected)) = (self.focused, self.action_index) else {
+            self.action_index = None;
+            return AIChatEvent::None;
+        };
+        let actions = self.message_actions(id);
+        if actions.is_empty() {
+            self.action_index = None;
+            return AIChatEvent::None;
+        }
+        let len = actions.len();
+        match key {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.action_index = Some((selected + len - 1) % len);
+            }
+            KeyCode::Down | KeyCode::Char('j') => self.action_index = Some((selected + 1) % len),
+            KeyCode::Esc => self.action_index = None,
+            KeyCode::Enter => {
+                self.action_index = None;
+                let action = actions[selected.min(len - 1)];
+                #[cfg(feature = "clipboard")]
+                if action == MessageAction::Copy {
+                    if let Some(message) = self.messages.get(id) {
+                        crate::services::clipboard::copy(
+                            &message.content,
+                            crate::services::clipboard::ClipboardSource::Chat,
+                        )
+                        .ok();
+                    }
+                }
+                return AIChatEvent::MessageAction { id, action };
+            }
+            _ => {}
+        }
+        AIChatEvent::None
+    }
+
+    /// Set how many rows the input grows to before it scrolls (default
+    /// 8, at least 3).
+    pub fn with_max_input_height(mut self, height: u16) -> Self {
+        self.max_input_height = height;
+        self
+    }
+
+    /// Handle a key press without modifiers.
+    ///
+    /// See [`handle_key_event`](Self::handle_key_event).
     pub fn handle_key(&mut self, key: crossterm::event::KeyCode) -> AIChatEvent {
         use crossterm::event::{KeyEvent, KeyModifiers};
 
-        let key = KeyEvent::new(key, KeyModifiers::NONE);
+        self.handle_key_event(KeyEvent::new(key, KeyModifiers::NONE))
+    }
+
+    /// Handle a key event.
+    ///
+    /// PageUp and PageDown scroll the messages; sending a message scrolls
+    /// back to the newest. Up on an empty input focuses the newest message;
+    /// then Up/Down or k/j move between messages, Esc or Down past the
+    /// newest returns to the input, and the actions key (Enter by default)
+    /// opens the actions menu, which returns
+    /// [`AIChatEvent::MessageAction`] for the picked action. Other keys
+    /// return to the input and go to it. Enter sends the input and Shift+Enter starts a
+    /// new line; see [`InputState::handle_key`] for the other editing keys.
+    /// While the input starts with `/`, Up and Down move through the command
+    /// menu, Tab completes the selected entry and Enter runs the command,
+    /// or completes it first if it takes arguments. Esc closes the menu
+    /// and leaves the typed text as a plain message.
+    ///
+    /// Returns an event indicating what happened.
+    pub fn handle_key_event(&mut self, key: crossterm::event::KeyEvent) -> AIChatEvent {
+        use crossterm::event::{KeyCode, KeyEventKind, KeyModifiers};
 
-        if let Some(result) = self.input.handle_key(key) {
-            if result.starts_with('@') {
-                return AIChatEvent::FileAttached(result);
+        if key.kind == KeyEventKind::Release {
+            return AIChatEvent::None;
+        }
+        if self.action_index.is_some() {
+            return self.handle_actions_key(key.code);
+        }
+        if self.focused.is_some() {
+            if let Some(event) = self.handle_focus_key(key.code) {
+                return event;
             }
-            if result.starts_with('/') {
-                if self.handle_command(&result) {
-                    return AIChatEvent::Command(result);
-                }
-                return AIChatEvent::Command(result);
+        } else if key.code == KeyCode::Up
+            && key.modifiers.is_empty()
+            && self.input.text().is_empty()
+        {
+            if let Some(last) = self.messages.last() {
+                self.focused = Some(last.id());
+                return AIChatEvent::None;
             }
-            if !result.is_empty() {
-                self.messages.add(Message::user(result.clone()));
-                self.is_loading = true;
-                return AIChatEvent::MessageSubmitted(result);
+        }
+        let newline = key.code == KeyCode::Enter
+            && key
+                .modifiers
+                .intersects(KeyModifiers::SHIFT | KeyModifiers::ALT);
+        if self.input.is_command_mode() && !newline {
+            if let Some(event) = self.handle_command_menu_key(key.code) {
+                return event;
             }
         }
-        AIChatEvent::None
+        if matches!(key.code, KeyCode::Char(_) | KeyCode::Backspace) {
+            self.selected_command_index = 0;
+        }
+
+        let page = self.last_layout.get().1.saturating_sub(1).max(1);
+        match key.code {
+            KeyCode::PageUp => {
+                self.scroll_up(page);
+                return AIChatEvent::None;
+            }
+            KeyCode::PageDown => {
+                self.scroll_down(page);
+                return AIChatEvent::None;
+            }
+            _ => {}
+        }
+
+        let result = self.input.handle_key(key);
+        if let Some(text) = self.input.take_submitted() {
+            self.messages.add(Message::user(text.clone()));
+            self.is_loading = true;
+            self.scroll_to_bottom();
+            return AIChatEvent::MessageSubmitted(text);
+        }
+        match result {
+            Some(file) if file.starts_with('@') => AIChatEvent::FileAttached(file),
+            _ => AIChatEvent::None,
+        }
     }
 
     /// Get messages reference.
@@ -181,7 +782,11 @@ impl AIChat {
     }
 
     /// Get messages mutable reference.
+    ///
+    /// Finished messages are rendered again on the next frame, in case
+    /// they were edited.
     pub fn messages_mut(&mut self) -> &mut MessageStore {
+        self.line_cache.get_mut().clear();
         &mut self.messages
     }
 
@@ -200,15 +805,19 @@ use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style as TuiStyle},
     text::{Line, Span},
-    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph},
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem, Paragraph},
     Frame,
 };
+use unicode_width::UnicodeWidthStr;
 
 impl AIChat {
     pub fn render(&self, frame: &mut Frame, area: Rect) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Min(0), Constraint::Length(3)])
+            .constraints([
+                Constraint::Min(0),
+                Constraint::Length(self.input_height(area.width)),
+            ])
             .split(area);
 
         let messages_area = chunks[0];
@@ -217,7 +826,9 @@ impl AIChat {
         self.render_messages(frame, messages_area);
         self.render_input(frame, input_area);
 
-        if self.input.is_file_mode() {
+        if self.action_index.is_some() {
+            self.render_actions_popup(frame, input_area);
+        } else if self.input.is_file_mode() {
             self.render_file_popup(frame, input_area);
         } else if self.input.is_command_mode() {
             self.render_command_popup(frame, input_area);
@@ -233,87 +844,174 @@ impl AIChat {
         let inner = block.inner(area);
         frame.render_widget(block, area);
 
-        let mut items = Vec::new();
+        let mut lines: Vec<Line> = Vec::new();
+        let mut cache = self.line_cache.borrow_mut();
+        let cursor_shown = !self.blink_cursor
+            || (self.created.elapsed().as_millis() / CURSOR_BLINK.as_millis()) % 2 == 0;
+
+        let mut focused_rows = None;
 
         for msg in self.messages.messages() {
-            let prefix = match msg.role {
-                MessageRole::User => "You: ",
-                MessageRole::Assistant => "AI:  ",
-            };
+            let start = lines.len();
+            if msg.is_streaming() {
+                lines.extend(self.message_lines(msg));
+                if cursor_shown {
+                    if let Some(last) = lines.last_mut() {
+                        last.spans
+                            .push(Span::styled("▍", TuiStyle::default().fg(Color::Gray)));
+                    }
+                }
+            } else {
+                let cached = cache
+                    .entry(msg.id())
+                    .or_insert_with(|| self.message_lines(msg));
+                lines.extend(cached.iter().cloned());
+            }
+            if self.focused == Some(msg.id()) {
+                for line in &mut lines[start..] {
+                    line.style = line.style.patch(self.focused_message_style);
+                }
+                focused_rows = Some((start, lines.len()));
+            }
+        }
+        drop(cache);
 
-            let style = match msg.role {
-                MessageRole::User => self.user_message_style,
-                MessageRole::Assistant => self.ai_message_style,
-            };
+        if self.is_loading {
+            lines.push(Line::from(vec![
+                Span::styled("AI:  ", self.ai_message_style),
+                Span::styled("⠋ Thinking...", TuiStyle::default().fg(Color::Gray)),
+            ]));
+        }
 
-            let mut content = vec![Span::styled(prefix, style)];
-
-            if !msg.attachments.is_empty() {
-                let files_str = msg
-                    .attachments
-                    .iter()
-                    .map(|f| format!("@{}", f))
-                    .collect::<Vec<_>>()
-                    .join(", ");
-                content.push(Span::styled(
-                    format!("[{}] ", files_str),
-                    TuiStyle::default().fg(Color::Yellow),
-                ));
+        let height = inner.height as usize;
+        self.last_layout.set((lines.len(), height));
+        let max_top = lines.len().saturating_sub(height);
+        let mut top = self.scroll_top.map_or(max_top, |top| top.min(max_top));
+        // Keep the focused message in view, its first row if it is taller
+        if let Some((start, end)) = focused_rows {
+            if start < top {
+                top = start;
+            } else if end > top + height {
+                top = end.saturating_sub(height).min(start);
             }
+        }
 
-            content.push(Span::raw(&msg.content));
+        let paragraph = Paragraph::new(lines).scroll((top as u16, 0));
+        frame.render_widget(paragraph, inner);
+    }
 
-            let line = Line::from(content);
-            items.push(ListItem::new(line));
+    /// Lines of one message: the sender prefix and attachments, then the
+    /// body, as markdown if it was streamed.
+    fn message_lines(&self, msg: &Message) -> Vec<Line<'static>> {
+        let prefix = match msg.role {
+            MessageRole::User => "You: ",
+            MessageRole::Assistant => "AI:  ",
+        };
+
+        let style = match msg.role {
+            MessageRole::User => self.user_message_style,
+            MessageRole::Assistant => self.ai_message_style,
+        };
+
+        let mut content = vec![Span::styled(prefix, style)];
+
+        if !msg.attachments.is_empty() {
+            let files_str = msg
+                .attachments
+                .iter()
+                .map(|f| format!("@{}", f))
+                .collect::<Vec<_>>()
+                .join(", ");
+            content.push(Span::styled(
+                format!("[{}] ", files_str),
+                TuiStyle::default().fg(Color::Yellow),
+            ));
         }
 
-        if self.is_loading {
-            items.push(ListItem::new(Line::from(vec![
-                Span::styled("AI:  ", self.ai_message_style),
-                Span::styled("⠋ Thinking...", TuiStyle::default().fg(Color::Gray)),
-            ])));
+        #[allow(unused_mut)]
+        let mut body = vec![Line::from(msg.content.clone())];
+        #[cfg(feature = "markdown-preview")]
+        if let Some(markdown) = msg.markdown_lines() {
+            body = markdown;
+            if body.is_empty() {
+                body.push(Line::default());
+            }
         }
+        content.append(&mut body[0].spans);
+        body[0].spans = content;
+        body
+    }
 
-        let list = List::new(items)
-            .block(Block::default())
-            .style(TuiStyle::default());
+    /// Rows the input takes at `width` columns: one per wrapped row, at
+    /// least 3 and at most the maximum input height.
+    fn input_height(&self, width: u16) -> u16 {
+        let rows = if self.input.is_file_mode() {
+            1
+        } else {
+            self.input.wrapped_rows(self.input_text_width(width)).len()
+        };
+        let max = self.max_input_height.max(3);
+        (rows.min(max as usize) as u16).max(3)
+    }
 
-        frame.render_widget(list, inner);
+    /// Columns left for the input text after the prompt.
+    fn input_text_width(&self, width: u16) -> usize {
+        (width as usize)
+            .saturating_sub(self.input_prompt.width())
+            .max(1)
     }
 
     fn render_input(&self, frame: &mut Frame, area: Rect) {
-        let mut input_text = self.input.text().to_string();
+        let prompt = &self.input_prompt;
 
         if self.input.is_file_mode() {
             let filtered = self.input.filtered_files();
-            if let Some(file) = filtered.get(self.input.selected_file_index()) {
-                input_text = format!("@{}{}", self.input.file_query(), file);
-            } else {
-                input_text = format!("@{}", self.input.file_query());
-            }
-        } else if self.input.is_command_mode() {
-            let filtered = self.filtered_commands();
-            if let Some(cmd) = filtered.get(self.selected_command_index()) {
-                input_text = cmd.clone();
-            } else {
-                input_text = format!("/{}", self.input.command());
-            }
+            let input_text = match filtered.get(self.input.selected_file_index()) {
+                Some(file) => format!("@{}{}", self.input.file_query(), file),
+                None => format!("@{}", self.input.file_query()),
+            };
+            let paragraph =
+                Paragraph::new(format!("{}{}", prompt, input_text)).style(self.input_style);
+            frame.render_widget(paragraph, area);
+            return;
         }
 
-        let prompt = &self.input_prompt;
-        let cursor_pos = prompt.len() + self.input.cursor();
+        let width = self.input_text_width(area.width);
+        let text = self.input.text_input().display_value();
+        let rows = self.input.wrapped_rows(width);
+        let (cursor_row, cursor_col) = self.input.wrapped_cursor(width);
+
+        // Scroll just enough to keep the cursor's row in view
+        let height = (area.height as usize).max(1);
+        let mut scroll = self
+            .input_scroll
+            .get()
+            .min(rows.len().saturating_sub(height));
+        if cursor_row < scroll {
+            scroll = cursor_row;
+        } else if cursor_row >= scroll + height {
+            scroll = cursor_row + 1 - height;
+        }
+        self.input_scroll.set(scroll);
 
-        let paragraph = Paragraph::new(format!("{}{}", prompt, input_text))
-            .style(self.input_style)
-            .block(Block::default());
+        let indent = " ".repeat(prompt.width());
+        let lines: Vec<Line> = rows
+            .iter()
+            .enumerate()
+            .skip(scroll)
+            .take(height)
+            .map(|(index, row)| {
+                let lead = if index == 0 { prompt.as_str() } else { &indent };
+                Line::from(format!("{}{}", lead, &text[row.clone()]))
+            })
+            .collect();
 
-        frame.render_widget(paragraph, area);
+        frame.render_widget(Paragraph::new(lines).style(self.input_style), area);
 
-        if cursor_pos < input_text.len() + prompt.len() {
-            let cursor_x = area.x + cursor_pos as u16;
-            let cursor_y = area.y;
-            frame.set_cursor_position((cursor_x, cursor_y));
-        }
+        let cursor_x = area.x as usize + prompt.width() + cursor_col;
+        let cursor_x = cursor_x.min((area.right() as usize).saturating_sub(1));
+        let cursor_y = area.y as usize + cursor_row - scroll;
+        frame.set_cursor_position((cursor_x as u16, cursor_y as u16));
     }
 
     fn render_file_popup(&self, frame: &mut Frame, input_area: Rect) {
@@ -368,14 +1066,59 @@ impl AIChat {
         frame.render_widget(list, popup_area);
     }
 
+    fn render_actions_popup(&self, frame: &mut Frame, input_area: Rect) {
+        let Some(id) = self.focused else {
+            return;
+        };
+        let actions = self.message_actions(id);
+        if actions.is_empty() {
+            return;
+        }
+
+        let popup_height = actions.len() as u16 + 2;
+        let popup_area = Rect {
+            x: input_area.x,
+            y: input_area.y.saturating_sub(popup_height),
+            width: 12.min(input_area.width),
+            height: popup_height,
+        };
+
+        let selected = self.action_index.unwrap_or(0);
+        let items: Vec<ListItem> = actions
+            .iter()
+            .enumerate()
+            .map(|(i, action)| {
+                let style = if i == selected {
+                    TuiStyle::default()
+                        .bg(Color::Blue)
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    TuiStyle::default().fg(Color::White).bg(Color::Black)
+                };
+                ListItem::new(Line::styled(action.label(), style))
+            })
+            .collect();
+
+        let list = List::new(items).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .style(TuiStyle::default().bg(Color::Black)),
+        );
+
+        frame.render_widget(Clear, popup_area);
+        frame.render_widget(list, popup_area);
+    }
+
     fn render_command_popup(&self, frame: &mut Frame, input_area: Rect) {
-        let filtered = self.filtered_commands();
+        let menu = self.command_menu();
 
-        if filtered.is_empty() {
+        if menu.is_empty() {
             return;
         }
 
-        let max_height = 10.min(filtered.len() as u16);
+        let max_height = 10.min(menu.len() as u16);
         let popup_height = max_height + 2;
 
         let popup_y = if input_area.y.saturating_sub(popup_height) > 0 {
@@ -384,7 +1127,22 @@ impl AIChat {
             input_area.y.saturating_add(1)
         };
 
-        let popup_width = 40.min(input_area.width);
+        let label_width = menu
+            .iter()
+            .map(|(label, _)| label.chars().count())
+            .max()
+            .unwrap_or(0);
+        let content_width = menu
+            .iter()
+            .map(|(_, description)| match description.chars().count() {
+                0 => label_width,
+                len => label_width + 2 + len,
+            })
+            .max()
+            .unwrap_or(0);
+        let popup_width = (content_width as u16 + 2)
+            .clamp(20, 60)
+            .min(input_area.width);
         let popup_x = input_area.x;
 
         let popup_area = Rect {
@@ -394,10 +1152,10 @@ impl AIChat {
             height: popup_height,
         };
 
-        let items: Vec<ListItem> = filtered
-            .iter()
+        let items: Vec<ListItem> = menu
+            .into_iter()
             .enumerate()
-            .map(|(i, cmd)| {
+            .map(|(i, (label, description))| {
                 let style = if i == self.selected_command_index() {
                     TuiStyle::default()
                         .bg(Color::Blue)
@@ -406,7 +1164,14 @@ impl AIChat {
                 } else {
                     TuiStyle::default().fg(Color::White).bg(Color::Black)
                 };
-                ListItem::new(Span::styled(cmd.clone(), style))
+                let padding = label_width - label.chars().count() + 2;
+                ListItem::new(Line::from(vec![
+                    Span::styled(label, style),
+                    Span::styled(
+                        format!("{}{}", " ".repeat(padding), description),
+                        style.fg(Color::Gray).remove_modifier(Modifier::BOLD),
+                    ),
+                ]))
             })
             .collect();
 
@@ -417,6 +1182,392 @@ impl AIChat {
                 .style(TuiStyle::default().bg(Color::Black)),
         );
 
+        frame.render_widget(Clear, popup_area);
         frame.render_widget(list, popup_area);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyCode;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    fn type_text(chat: &mut AIChat, text: &str) {
+        for c in text.chars() {
+            assert_eq!(chat.handle_key(KeyCode::Char(c)), AIChatEvent::None);
+        }
+    }
+
+    fn command(name: &str, args: &str) -> AIChatEvent {
+        AIChatEvent::Command {
+            name: name.to_string(),
+            args: args.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_slash_at_start_opens_menu_and_runs_builtin() {
+        let mut chat = AIChat::new();
+        chat.messages_mut().add(Message::user("hello".to_string()));
+
+        type_text(&mut chat, "/cr");
+        assert!(chat.input().is_command_mode());
+        let names: Vec<&str> = chat
+            .filtered_commands()
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect();
+        assert_eq!(names, ["clear"]);
+
+        assert_eq!(chat.handle_key(KeyCode::Enter), command("clear", ""));
+        assert!(chat.messages().is_empty());
+        assert_eq!(chat.input().text(), "");
+
+        // A slash later in the text is plain typing.
+        type_text(&mut chat, "a/b");
+        assert!(!chat.input().is_command_mode());
+        assert_eq!(
+            chat.handle_key(KeyCode::Enter),
+            AIChatEvent::MessageSubmitted("a/b".to_string())
+        );
+    }
+
+    #[test]
+    fn test_input_edits_whole_graphemes() {
+        let mut chat = AIChat::new();
+        type_text(&mut chat, "cafe\u{301} \u{b4}e \u{1f469}\u{200d}\u{1f4bb}");
+        assert_eq!(
+            chat.input().text(),
+            "caf\u{e9} \u{e9} \u{1f469}\u{200d}\u{1f4bb}"
+        );
+
+        chat.handle_key(KeyCode::Backspace);
+        chat.handle_key(KeyCode::Left);
+        chat.handle_key(KeyCode::Left);
+        chat.handle_key(KeyCode::Backspace);
+        assert_eq!(
+            chat.handle_key(KeyCode::Enter),
+            AIChatEvent::MessageSubmitted("caf\u{e9}\u{e9} ".to_string())
+        );
+    }
+
+    #[test]
+    fn test_shift_enter_and_history_recall() {
+        use crossterm::event::{KeyEvent, KeyModifiers};
+
+        let mut chat = AIChat::new();
+        type_text(&mut chat, "one");
+        chat.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::SHIFT));
+        type_text(&mut chat, "two");
+        assert_eq!(chat.input().text(), "one\ntwo");
+        assert_eq!(chat.input().cursor_row(), 1);
+        assert_eq!(
+            chat.handle_key(KeyCode::Enter),
+            AIChatEvent::MessageSubmitted("one\ntwo".to_string())
+        );
+        assert_eq!(chat.input_mut().take_submitted(), None);
+        for _ in 0..2 {
+            type_text(&mut chat, "three");
+            chat.handle_key(KeyCode::Enter);
+        }
+        assert_eq!(chat.input().history().count(), 2);
+
+        type_text(&mut chat, "dr");
+        chat.handle_key(KeyCode::Up);
+        assert_eq!(chat.input().text(), "three");
+        chat.handle_key(KeyCode::Up);
+        assert_eq!(chat.input().text(), "one\ntwo");
+        chat.handle_key(KeyCode::Up);
+        assert_eq!(
+            (chat.input().cursor_row(), chat.input().cursor_col()),
+            (0, 3)
+        );
+        chat.handle_key(KeyCode::Up);
+        assert_eq!(chat.input().text(), "one\ntwo");
+        chat.handle_key(KeyCode::Down);
+        chat.handle_key(KeyCode::Down);
+        assert_eq!(chat.input().text(), "three");
+        chat.handle_key(KeyCode::Down);
+        assert_eq!(chat.input().text(), "dr");
+    }
+
+    #[test]
+    fn test_input_grows_with_content_then_scrolls() {
+        let mut chat = AIChat::new().with_max_input_height(4);
+        type_text(&mut chat, "abcdefghijklmnopqrstuvwxyz");
+        let rows = screen(&chat, 20, 12);
+        assert_eq!(rows[9], "You: abcdefghijklmno");
+        assert_eq!(rows[10], "     pqrstuvwxyz    ");
+
+        chat.input_mut().set_text("1\n2\n3\n4\n5\n6");
+        let rows = screen(&chat, 20, 12);
+        assert!(rows[7].starts_with('╰'), "{rows:?}");
+        assert_eq!(rows[8].trim(), "3");
+        assert_eq!(rows[11].trim(), "6");
+    }
+
+    #[test]
+    fn test_esc_keeps_typed_slash_as_plain_text() {
+        let mut chat = AIChat::new();
+        type_text(&mut chat, "/ex");
+        assert_eq!(chat.filtered_commands().len(), 1);
+
+        chat.handle_key(KeyCode::Esc);
+        assert!(!chat.input().is_command_mode());
+        assert!(chat.filtered_commands().is_empty());
+        type_text(&mut chat, "tra");
+        assert_eq!(
+            chat.handle_key(KeyCode::Enter),
+            AIChatEvent::MessageSubmitted("/extra".to_string())
+        );
+
+        // The menu opens again for the next command.
+        type_text(&mut chat, "/");
+        assert!(chat.input().is_command_mode());
+    }
+
+    #[test]
+    fn test_arguments_complete_with_files_and_custom_hooks() {
+        let mut chat = AIChat::new();
+        chat.input_mut()
+            .set_available_files(vec!["README.md".to_string(), "src/main.rs".to_string()]);
+        chat.register_command(
+            SlashCommand::new("attach", "Attach a file")
+                .args("<path>")
+                .complete_files(),
+        );
+        chat.register_command(
+            SlashCommand::new("/model", "Switch the model")
+                .args("<name>")
+                .complete_with(|partial| {
+                    ["gpt-x", "gpt-x-mini"]
+                        .iter()
+                        .filter(|model| model.starts_with(partial))
+                        .map(|model| model.to_string())
+                        .collect()
+                }),
+        );
+
+        type_text(&mut chat, "/att");
+        assert_eq!(chat.handle_key(KeyCode::Enter), AIChatEvent::None);
+        assert_eq!(chat.input().text(), "/attach ");
+        type_text(&mut chat, "MAI");
+        assert_eq!(chat.argument_completions(), ["src/main.rs"]);
+        chat.handle_key(KeyCode::Tab);
+        assert_eq!(chat.input().text(), "/attach src/main.rs");
+        assert_eq!(
+            chat.handle_key(KeyCode::Enter),
+            command("attach", "src/main.rs")
+        );
+
+        type_text(&mut chat, "/model gpt");
+        chat.handle_key(KeyCode::Down);
+        assert_eq!(chat.handle_key(KeyCode::Enter), AIChatEvent::None);
+        assert_eq!(chat.input().text(), "/model gpt-x-mini");
+        assert_eq!(
+            chat.handle_key(KeyCode::Enter),
+            command("model", "gpt-x-mini")
+        );
+    }
+
+    fn screen(chat: &AIChat, width: u16, height: u16) -> Vec<String> {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal
+            .draw(|frame| chat.render(frame, frame.area()))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| buffer[(x, y)].symbol().to_string())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_streamed_message_stays_in_view_until_scrolled_up() {
+        let mut chat = AIChat::new();
+        for i in 0..6 {
+            chat.messages_mut()
+                .add(Message::user(format!("question {i}")));
+        }
+        let id = chat.begin_assistant_message();
+        assert!(chat.append_to_message(id, "first"));
+        let rows = screen(&chat, 30, 8);
+        assert!(rows[3].contains("first▍"), "{rows:?}");
+
+        chat.scroll_up(2);
+        assert!(!chat.is_following());
+        chat.append_to_message(id, " token");
+        let rows = screen(&chat, 30, 8);
+        assert!(rows[1].contains("question 2"), "{rows:?}");
+
+        chat.scroll_down(10);
+        assert!(chat.is_following());
+        assert_eq!(
+            chat.finish_message(id),
+            AIChatEvent::MessageCompleted { id }
+        );
+        assert_eq!(chat.finish_message(id), AIChatEvent::None);
+        let rows = screen(&chat, 30, 8);
+        assert!(rows[3].contains("first token "), "{rows:?}");
+        assert!(!rows[3].contains('▍'), "{rows:?}");
+    }
+
+    #[test]
+    fn test_finished_messages_are_cached_until_edited() {
+        let mut chat = AIChat::new().with_blinking_cursor(true);
+        chat.messages_mut().add(Message::user("hi".to_string()));
+        let done = chat.begin_assistant_message();
+        chat.append_to_message(done, "old reply");
+        chat.finish_message(done);
+        let id = chat.begin_assistant_message();
+        chat.append_to_message(id, "new");
+        let rows = screen(&chat, 30, 8);
+        assert!(rows[2].contains("old reply"), "{rows:?}");
+        assert!(rows[3].contains("new▍"), "{rows:?}");
+        assert_eq!(chat.line_cache.borrow().len(), 2);
+
+        // The cursor is hidden in the off half of each blink.
+        chat.created = Instant::now() - CURSOR_BLINK;
+        chat.append_to_message(id, "er");
+        let rows = screen(&chat, 30, 8);
+        assert!(rows[3].contains("newer "), "{rows:?}");
+        assert!(!rows[3].contains('▍'), "{rows:?}");
+
+        chat.messages_mut().messages_mut()[0].content = "edited".to_string();
+        assert!(chat.line_cache.borrow().is_empty());
+        let rows = screen(&chat, 30, 8);
+        assert!(rows[1].contains("You: edited"), "{rows:?}");
+    }
+
+    #[test]
+    fn test_message_cursor_moves_without_touching_the_input() {
+        let mut chat = AIChat::new();
+        type_text(&mut chat, "first");
+        chat.handle_key(KeyCode::Enter);
+        let reply = chat.begin_assistant_message();
+        chat.append_to_message(reply, "answer");
+        chat.finish_message(reply);
+        let ids: Vec<MessageId> = chat.messages().messages().iter().map(|m| m.id()).collect();
+
+        // Up on an empty input focuses the newest message, not the history.
+        chat.handle_key(KeyCode::Up);
+        assert_eq!(chat.focused_message(), Some(reply));
+        assert_eq!(chat.input().text(), "");
+        chat.handle_key(KeyCode::Char('k'));
+        chat.handle_key(KeyCode::Up);
+        assert_eq!(chat.focused_message(), Some(ids[0]));
+        chat.handle_key(KeyCode::Char('j'));
+        assert_eq!(chat.focused_message(), Some(reply));
+        chat.handle_key(KeyCode::Down);
+        assert_eq!(chat.focused_message(), None);
+        assert_eq!(chat.input().text(), "");
+
+        // Typing leaves the messages and goes to the input.
+        chat.handle_key(KeyCode::Up);
+        type_text(&mut chat, "ok");
+        assert_eq!(chat.focused_message(), None);
+        assert_eq!(chat.input().text(), "ok");
+        chat.handle_key(KeyCode::Up);
+        assert_eq!(chat.focused_message(), None);
+        assert_eq!(chat.input().text(), "first");
+    }
+
+    #[test]
+    fn test_message_actions_depend_on_role_and_raise_events() {
+        let mut chat = AIChat::new();
+        chat.messages_mut()
+            .add(Message::user("question".to_string()));
+        chat.messages_mut()
+            .add(Message::assistant("answer".to_string()));
+        let ids: Vec<MessageId> = chat.messages().messages().iter().map(|m| m.id()).collect();
+        assert_eq!(
+            chat.message_actions(ids[0]),
+            [MessageAction::Copy, MessageAction::Delete]
+        );
+        assert_eq!(
+            chat.message_actions(ids[1]),
+            [
+                MessageAction::Copy,
+                MessageAction::Retry,
+                MessageAction::Delete
+            ]
+        );
+
+        chat.handle_key(KeyCode::Up);
+        chat.handle_key(KeyCode::Enter);
+        assert!(chat.is_actions_menu_open());
+        let rows = screen(&chat, 30, 12);
+        assert!(rows[6].starts_with("│Retry"), "{rows:?}");
+        chat.handle_key(KeyCode::Down);
+        assert_eq!(
+            chat.handle_key(KeyCode::Enter),
+            AIChatEvent::MessageAction {
+                id: ids[1],
+                action: MessageAction::Retry
+            }
+        );
+        assert!(!chat.is_actions_menu_open());
+
+        // Delete is left to the app; the cursor leaves a removed message.
+        chat.handle_key(KeyCode::Char('k'));
+        chat.handle_key(KeyCode::Enter);
+        chat.handle_key(KeyCode::Up);
+        assert_eq!(
+            chat.handle_key(KeyCode::Enter),
+            AIChatEvent::MessageAction {
+                id: ids[0],
+                action: MessageAction::Delete
+            }
+        );
+        chat.messages_mut().remove(ids[0]);
+        chat.handle_key(KeyCode::Char('x'));
+        assert_eq!(chat.focused_message(), None);
+        assert_eq!(chat.input().text(), "x");
+
+        let mut chat = AIChat::new().with_actions_key(KeyCode::Char('a'));
+        chat.messages_mut().add(Message::user("hi".to_string()));
+        chat.handle_key(KeyCode::Up);
+        chat.handle_key(KeyCode::Char('a'));
+        assert_eq!(
+            chat.handle_key(KeyCode::Enter),
+            AIChatEvent::MessageAction {
+                id: chat.messages().messages()[0].id(),
+                action: MessageAction::Copy
+            }
+        );
+    }
+
+    #[test]
+    fn test_command_menu_renders_above_input() {
+        let mut chat = AIChat::new();
+        type_text(&mut chat, "/");
+
+        let mut terminal = Terminal::new(TestBackend::new(50, 12)).unwrap();
+        terminal
+            .draw(|frame| chat.render(frame, frame.area()))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let rows: Vec<String> = (0..12)
+            .map(|y| {
+                (0..50)
+                    .map(|x| buffer[(x, y)].symbol().to_string())
+                    .collect()
+            })
+            .collect();
+
+        assert!(
+            rows[6].contains("/clear   Clear the conversation"),
+            "{rows:?}"
+        );
+        assert!(
+            rows[7].contains("/export  Copy the conversation"),
+            "{rows:?}"
+        );
+        assert!(rows[9].starts_with("You: /"), "{rows:?}");
+    }
+}
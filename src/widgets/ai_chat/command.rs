@@ -0,0 +1,156 @@
+//! Slash commands typed at the start of the chat input.
+
+use std::fmt;
+use std::sync::Arc;
+
+/// Suggests completions for a command argument, given the part of the
+/// argument typed so far.
+pub type CompletionFn = Arc<dyn Fn(&str) -> Vec<String> + Send + Sync>;
+
+/// How the argument of a [`SlashCommand`] is completed.
+#[derive(Clone, Default)]
+pub enum ArgCompletion {
+    /// No suggestions.
+    #[default]
+    None,
+    /// File paths, from the same list the `@` file picker searches.
+    Files,
+    /// Suggestions from a function supplied by the app.
+    Custom(CompletionFn),
+}
+
+impl fmt::Debug for ArgCompletion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => write!(f, "None"),
+            Self::Files => write!(f, "Files"),
+            Self::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+/// A command the user can run by typing `/name` in the chat input.
+///
+/// # Example
+///
+/// ```rust
+/// use ratkit::widgets::ai_chat::{AIChat, SlashCommand};
+///
+/// let mut chat = AIChat::new();
+/// chat.register_command(
+///     SlashCommand::new("model", "Switch the model")
+///         .args("<name>")
+///         .complete_with(|partial| {
+///             ["gpt-x", "gpt-x-mini"]
+///                 .iter()
+///                 .filter(|model| model.starts_with(partial))
+///                 .map(|model| model.to_string())
+///                 .collect()
+///         }),
+/// );
+/// chat.register_command(
+///     SlashCommand::new("attach", "Attach a file")
+///         .args("<path>")
+///         .complete_files(),
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct SlashCommand {
+    /// Name typed after the slash, e.g. `clear`.
+    pub name: String,
+    /// One-line description shown in the command menu.
+    pub description: String,
+    /// Hint for the arguments shown in the menu, e.g. `<path>`. Commands
+    /// without one run as soon as they are picked.
+    pub args: Option<String>,
+    /// How the argument being typed is completed.
+    pub completion: ArgCompletion,
+}
+
+impl SlashCommand {
+    /// Creates a command without arguments. A leading `/` on `name` is
+    /// ignored.
+    pub fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
+        let name = name.into();
+        Self {
+            name: name.strip_prefix('/').unwrap_or(&name).to_string(),
+            description: description.into(),
+            args: None,
+            completion: ArgCompletion::None,
+        }
+    }
+
+    /// Makes the command take arguments, described by `hint`.
+    pub fn args(mut self, hint: impl Into<String>) -> Self {
+        self.args = Some(hint.into());
+        self
+    }
+
+    /// Completes the argument with file paths, like the `@` file picker.
+    pub fn complete_files(mut self) -> Self {
+        self.completion = ArgCompletion::Files;
+        self
+    }
+
+    /// Completes the argument with suggestions from `complete`, which gets
+    /// the part of the argument typed so far.
+    pub fn complete_with(
+        mut self,
+        complete: impl Fn(&str) -> Vec<String> + Send + Sync + 'static,
+    ) -> Self {
+        self.completion = ArgCompletion::Custom(Arc::new(complete));
+        self
+    }
+
+    /// Whether the command takes arguments.
+    pub fn takes_args(&self) -> bool {
+        self.args.is_some()
+    }
+}
+
+/// Scores how well `query` fuzzy-matches `candidate`, ignoring case.
+///
+/// Every character of `query` must appear in `candidate` in order. Runs of
+/// consecutive characters and matches at the start of `candidate` or of a
+/// word in it score higher. Returns `None` if `query` does not match.
+pub(crate) fn fuzzy_score(query: &str, candidate: &str) -> Option<i32> {
+    let candidate: Vec<char> = candidate.chars().flat_map(char::to_lowercase).collect();
+    let mut score = 0;
+    let mut next = 0;
+    let mut previous: Option<usize> = None;
+
+    for q in query.chars().flat_map(char::to_lowercase) {
+        let found = next + candidate[next..].iter().position(|&c| c == q)?;
+        score += 1;
+        if previous.is_some_and(|previous| previous + 1 == found) {
+            score += 4;
+        }
+        if found == 0 || matches!(candidate[found - 1], '-' | '_' | ' ' | '/') {
+            score += 6;
+        }
+        previous = Some(found);
+        next = found + 1;
+    }
+
+    // Among equal matches, prefer the shorter candidate.
+    Some(score * 100 - candidate.len() as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_score_ranks_prefixes_and_runs_first() {
+        assert_eq!(fuzzy_score("xyz", "export"), None);
+        assert!(fuzzy_score("", "export").is_some());
+
+        let mut names = vec!["clear-cache", "model", "clear", "color"];
+        names.retain(|name| fuzzy_score("cl", name).is_some());
+        names.sort_by_key(|name| -fuzzy_score("cl", name).unwrap());
+        assert_eq!(names, ["clear", "clear-cache", "color"]);
+
+        assert!(fuzzy_score("EXP", "export").is_some());
+        assert!(fuzzy_score("cc", "clear-cache") > fuzzy_score("cc", "occur"));
+    }
+}
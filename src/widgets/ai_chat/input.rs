@@ -1,45 +1,59 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::VecDeque;
 use std::fs;
+use std::ops::Range;
 use std::path::Path;
 
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::primitives::text_input::TextInputState;
+
+/// Sent messages kept for recall with Up and Down by default.
+pub const DEFAULT_HISTORY_LIMIT: usize = 100;
+
 /// State for text input with multi-line support and special prefix parsing.
 #[derive(Debug, Clone)]
 pub struct InputState {
-    /// Current input text
-    text: String,
-    /// Cursor position in text
-    cursor: usize,
-    /// Lines of text (split by newlines)
-    lines: Vec<String>,
-    /// Current line being edited
-    current_line: usize,
+    /// Current input text and cursor
+    input: TextInputState,
     /// Whether @ prefix is active (file attachment mode)
     is_file_mode: bool,
     /// File search query
-    file_query: String,
+    file_query: TextInputState,
     /// Available files for fuzzy search
     available_files: Vec<String>,
     /// Selected file index in search results
     selected_file_index: usize,
-    /// Whether / prefix is active (command mode)
-    is_command_mode: bool,
-    /// Command being entered
-    command: String,
+    /// Whether Esc closed the command menu for the current `/` input
+    command_menu_dismissed: bool,
+    /// Sent messages, oldest first
+    history: VecDeque<String>,
+    /// Most sent messages kept in `history`
+    history_limit: usize,
+    /// Entry of `history` shown in the input while browsing it
+    history_index: Option<usize>,
+    /// Text typed before browsing the history, restored past its newest
+    /// entry
+    draft: String,
+    /// Message sent by the last Enter, until taken
+    submitted: Option<String>,
 }
 
 impl Default for InputState {
     fn default() -> Self {
         Self {
-            text: String::new(),
-            cursor: 0,
-            lines: vec![String::new()],
-            current_line: 0,
+            input: TextInputState::new(),
             is_file_mode: false,
-            file_query: String::new(),
+            file_query: TextInputState::new(),
             available_files: Vec::new(),
             selected_file_index: 0,
-            is_command_mode: false,
-            command: String::new(),
+            command_menu_dismissed: false,
+            history: VecDeque::new(),
+            history_limit: DEFAULT_HISTORY_LIMIT,
+            history_index: None,
+            draft: String::new(),
+            submitted: None,
         }
     }
 }
@@ -50,14 +64,100 @@ impl InputState {
         Self::default()
     }
 
-    /// Get current input text.
+    /// Get current input text, in Unicode normalization form C.
     pub fn text(&self) -> &str {
-        &self.text
+        self.input.value()
     }
 
-    /// Get cursor position.
+    /// Get cursor position, as a byte offset into [`text`](Self::text).
     pub fn cursor(&self) -> usize {
-        self.cursor
+        self.input.cursor()
+    }
+
+    /// Get the text editing state, e.g. to draw a dead key waiting to
+    /// compose with the next character.
+    pub fn text_input(&self) -> &TextInputState {
+        &self.input
+    }
+
+    /// Get the line the cursor is on (0-indexed).
+    pub fn cursor_row(&self) -> usize {
+        self.text()[..self.cursor()].matches('\n').count()
+    }
+
+    /// Get the display column of the cursor within its line.
+    pub fn cursor_col(&self) -> usize {
+        self.text()[self.line_start()..self.cursor()].width()
+    }
+
+    /// Get the number of lines in the input.
+    pub fn line_count(&self) -> usize {
+        self.text().matches('\n').count() + 1
+    }
+
+    /// Split the text as drawn, with a pending dead key shown at the
+    /// cursor, into rows of at most `width` columns.
+    ///
+    /// Lines break at newlines and wrap between graphemes. Returns the byte
+    /// range of each row in [`TextInputState::display_value`].
+    pub fn wrapped_rows(&self, width: usize) -> Vec<Range<usize>> {
+        wrap_rows(&self.input.display_value(), width)
+    }
+
+    /// Get the row and column of the cursor in
+    /// [`wrapped_rows`](Self::wrapped_rows) for `width`.
+    ///
+    /// At the end of a full row the column equals `width`.
+    pub fn wrapped_cursor(&self, width: usize) -> (usize, usize) {
+        let display = self.input.display_value();
+        let rows = wrap_rows(&display, width);
+        let cursor = self.cursor();
+        let row = rows
+            .iter()
+            .position(|row| row.start <= cursor && cursor < row.end)
+            .or_else(|| rows.iter().rposition(|row| row.end == cursor))
+            .unwrap_or(0);
+        let col = display[rows[row].start..cursor].width();
+        (row, col)
+    }
+
+    /// Take the message sent by the last Enter, if it was not taken yet.
+    ///
+    /// Enter with an empty input sends nothing.
+    pub fn take_submitted(&mut self) -> Option<String> {
+        self.submitted.take()
+    }
+
+    /// Get the sent messages Up and Down recall, oldest first, e.g. to
+    /// save them when the app exits.
+    pub fn history(&self) -> impl Iterator<Item = &str> {
+        self.history.iter().map(String::as_str)
+    }
+
+    /// Replace the sent messages Up and Down recall, oldest first, e.g.
+    /// with ones saved by an earlier run.
+    ///
+    /// Empty entries are skipped, and only the newest ones up to the
+    /// history limit are kept.
+    pub fn set_history<I>(&mut self, entries: I)
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.history = entries
+            .into_iter()
+            .map(Into::into)
+            .filter(|entry| !entry.is_empty())
+            .collect();
+        self.history_index = None;
+        self.trim_history();
+    }
+
+    /// Set how many sent messages are kept for recall, dropping the
+    /// oldest ones past `limit`.
+    pub fn set_history_limit(&mut self, limit: usize) {
+        self.history_limit = limit;
+        self.trim_history();
     }
 
     /// Check if in file attachment mode.
@@ -65,19 +165,27 @@ impl InputState {
         self.is_file_mode
     }
 
-    /// Check if in command mode.
+    /// Check if in command mode: the input starts with `/`, is a single
+    /// line, and the command menu was not dismissed with Esc.
     pub fn is_command_mode(&self) -> bool {
-        self.is_command_mode
+        let text = self.text();
+        text.starts_with('/') && !text.contains('\n') && !self.command_menu_dismissed
     }
 
     /// Get current file search query.
     pub fn file_query(&self) -> &str {
-        &self.file_query
+        self.file_query.value()
     }
 
     /// Get filtered files matching query.
     pub fn filtered_files(&self) -> Vec<String> {
-        let query_lower = self.file_query.to_lowercase();
+        self.files_matching(self.file_query.value())
+    }
+
+    /// Get the available files matching `query`, the way the `@` file
+    /// picker filters them.
+    pub fn files_matching(&self, query: &str) -> Vec<String> {
+        let query_lower = query.to_lowercase();
         self.available_files
             .iter()
             .filter(|f| f.to_lowercase().contains(&query_lower))
@@ -90,9 +198,20 @@ impl InputState {
         self.selected_file_index
     }
 
-    /// Get current command.
+    /// Get the text typed after the leading `/`, or an empty string
+    /// outside command mode.
     pub fn command(&self) -> &str {
-        &self.command
+        if self.is_command_mode() {
+            &self.text()[1..]
+        } else {
+            ""
+        }
+    }
+
+    /// Replace the input text, moving the cursor to the end.
+    pub fn set_text(&mut self, text: &str) {
+        self.input.set_value(text);
+        self.text_changed();
     }
 
     /// Set available files for fuzzy search.
@@ -138,6 +257,12 @@ impl InputState {
 
     /// Handle a key event.
     ///
+    /// Enter sends the input; Shift+Enter, Alt+Enter and Ctrl+J insert a
+    /// newline. Terminals only report Shift+Enter with the keyboard
+    /// enhancement flags enabled. Up and Down move between lines, and
+    /// recall sent messages from the first and last line. Home and End
+    /// move within the line.
+    ///
     /// Returns:
     /// - `Some(text)` if Enter was pressed (submit message or command)
     /// - `Some(file)` if a file was selected
@@ -145,14 +270,11 @@ impl InputState {
     pub fn handle_key(&mut self, key: KeyEvent) -> Option<String> {
         match key.code {
             KeyCode::Char('@') => {
-                if !self.is_file_mode && !self.is_command_mode {
+                if !self.is_file_mode && !self.is_command_mode() {
                     self.is_file_mode = true;
-                }
-                None
-            }
-            KeyCode::Char('/') => {
-                if !self.is_file_mode && !self.is_command_mode {
-                    self.is_command_mode = true;
+                } else if self.is_command_mode() {
+                    self.input.insert_char('@');
+                    self.text_changed();
                 }
                 None
             }
@@ -162,42 +284,37 @@ impl InputState {
                 if is_ctrl_j {
                     self.insert_newline();
                 } else if self.is_file_mode {
-                    self.file_query.push(c);
+                    self.file_query.handle_key(&key);
                     self.selected_file_index = 0;
-                } else if self.is_command_mode {
-                    self.command.push(c);
-                } else {
-                    self.insert_char(c);
+                } else if self.input.handle_key(&key) {
+                    self.text_changed();
                 }
                 None
             }
             KeyCode::Backspace => {
                 if self.is_file_mode {
                     if !self.file_query.is_empty() {
-                        self.file_query.pop();
+                        self.file_query.backspace();
                         if self.file_query.is_empty() {
                             self.is_file_mode = false;
                         }
                     }
-                } else if self.is_command_mode {
-                    self.command.pop();
-                    if self.command.is_empty() {
-                        self.is_command_mode = false;
-                    }
                 } else {
                     self.backspace();
                 }
                 None
             }
-            KeyCode::Left => {
-                if !self.is_file_mode && !self.is_command_mode && self.cursor > 0 {
-                    self.cursor -= 1;
-                }
+            KeyCode::Home if !self.is_file_mode => {
+                self.input.set_cursor(self.line_start());
                 None
             }
-            KeyCode::Right => {
-                if !self.is_file_mode && !self.is_command_mode && self.cursor < self.text.len() {
-                    self.cursor += 1;
+            KeyCode::End if !self.is_file_mode => {
+                self.input.set_cursor(self.line_end());
+                None
+            }
+            KeyCode::Left | KeyCode::Right | KeyCode::Delete => {
+                if !self.is_file_mode && self.input.handle_key(&key) {
+                    self.text_changed();
                 }
                 None
             }
@@ -211,6 +328,10 @@ impl InputState {
                             self.selected_file_index - 1
                         };
                     }
+                } else if self.cursor_row() > 0 {
+                    self.move_to_line(self.cursor_row() - 1);
+                } else {
+                    self.recall_previous();
                 }
                 None
             }
@@ -220,11 +341,22 @@ impl InputState {
                     if !filtered.is_empty() {
                         self.selected_file_index = (self.selected_file_index + 1) % filtered.len();
                     }
+                } else if self.cursor_row() + 1 < self.line_count() {
+                    self.move_to_line(self.cursor_row() + 1);
+                } else {
+                    self.recall_next();
                 }
                 None
             }
             KeyCode::Enter => {
-                if self.is_file_mode {
+                if !self.is_file_mode
+                    && key
+                        .modifiers
+                        .intersects(KeyModifiers::SHIFT | KeyModifiers::ALT)
+                {
+                    self.insert_newline();
+                    None
+                } else if self.is_file_mode {
                     let filtered = self.filtered_files();
                     if let Some(file) = filtered.get(self.selected_file_index) {
                         let file = file.clone();
@@ -235,14 +367,13 @@ impl InputState {
                     } else {
                         None
                     }
-                } else if self.is_command_mode {
-                    let command = self.command.clone();
-                    self.is_command_mode = false;
-                    self.command.clear();
-                    Some(format!("/{}", command))
                 } else {
-                    let text = self.text.clone();
+                    let text = self.text().to_string();
                     self.clear();
+                    if !text.is_empty() {
+                        self.push_history(&text);
+                        self.submitted = Some(text.clone());
+                    }
                     Some(text)
                 }
             }
@@ -252,9 +383,8 @@ impl InputState {
                     self.file_query.clear();
                     self.selected_file_index = 0;
                 }
-                if self.is_command_mode {
-                    self.is_command_mode = false;
-                    self.command.clear();
+                if self.is_command_mode() {
+                    self.command_menu_dismissed = true;
                 }
                 None
             }
@@ -262,55 +392,222 @@ impl InputState {
         }
     }
 
-    /// Insert a character at cursor position.
-    fn insert_char(&mut self, c: char) {
-        self.text.insert(self.cursor, c);
-        self.cursor += 1;
-        self.update_lines();
-    }
-
     /// Insert a newline.
     fn insert_newline(&mut self) {
-        self.text.insert(self.cursor, '\n');
-        self.cursor += 1;
-        self.update_lines();
+        self.input.insert_str("\n");
+        self.text_changed();
+    }
+
+    /// Insert text at the cursor, e.g. an entry pasted from the clipboard
+    /// history or a crossterm `Event::Paste`.
+    pub fn insert_str(&mut self, text: &str) {
+        if self.is_file_mode {
+            self.file_query.insert_str(text);
+            self.selected_file_index = 0;
+        } else {
+            self.input.insert_str(text);
+            self.text_changed();
+        }
     }
 
-    /// Delete character before cursor.
+    /// Delete the grapheme before the cursor.
     fn backspace(&mut self) {
-        if self.cursor > 0 {
-            self.text.remove(self.cursor - 1);
-            self.cursor -= 1;
-            self.update_lines();
+        if self.input.backspace() {
+            self.text_changed();
         }
     }
 
     /// Clear input.
     pub fn clear(&mut self) {
-        self.text.clear();
-        self.cursor = 0;
-        self.lines = vec![String::new()];
-        self.current_line = 0;
+        self.input.clear();
+        self.command_menu_dismissed = false;
+        self.history_index = None;
+    }
+
+    /// Reopen the command menu once the text no longer starts with `/`,
+    /// and stop browsing the history once a recalled message is edited.
+    fn text_changed(&mut self) {
+        if !self.text().starts_with('/') {
+            self.command_menu_dismissed = false;
+        }
+        if let Some(index) = self.history_index {
+            if self.history[index] != self.text() {
+                self.history_index = None;
+            }
+        }
+    }
+
+    /// Byte offset of the start of the cursor's line.
+    fn line_start(&self) -> usize {
+        self.text()[..self.cursor()]
+            .rfind('\n')
+            .map_or(0, |i| i + 1)
+    }
+
+    /// Byte offset of the end of the cursor's line.
+    fn line_end(&self) -> usize {
+        let cursor = self.cursor();
+        self.text()[cursor..]
+            .find('\n')
+            .map_or(self.text().len(), |i| cursor + i)
+    }
+
+    /// Move the cursor to line `row`, as close to its current display
+    /// column as the line allows.
+    fn move_to_line(&mut self, row: usize) {
+        let col = self.cursor_col();
+        let text = self.text();
+        let start = text
+            .match_indices('\n')
+            .nth(row.wrapping_sub(1))
+            .map_or(0, |(i, _)| i + 1);
+        let line = text[start..].split('\n').next().unwrap_or("");
+        let mut offset = start + line.len();
+        let mut width = 0;
+        for (i, grapheme) in line.grapheme_indices(true) {
+            width += grapheme.width();
+            if width > col {
+                offset = start + i;
+                break;
+            }
+        }
+        self.input.set_cursor(offset);
+    }
+
+    /// Show the sent message before the one shown, saving the typed text
+    /// when starting to browse.
+    fn recall_previous(&mut self) {
+        let index = match self.history_index {
+            None if self.history.is_empty() => return,
+            None => {
+                self.draft = self.text().to_string();
+                self.history.len() - 1
+            }
+            Some(0) => return,
+            Some(index) => index - 1,
+        };
+        self.history_index = Some(index);
+        self.input.set_value(&self.history[index]);
+    }
+
+    /// Show the sent message after the one shown, or the saved typed text
+    /// past the newest.
+    fn recall_next(&mut self) {
+        let Some(index) = self.history_index else {
+            return;
+        };
+        if index + 1 < self.history.len() {
+            self.history_index = Some(index + 1);
+            self.input.set_value(&self.history[index + 1]);
+        } else {
+            self.history_index = None;
+            let draft = std::mem::take(&mut self.draft);
+            self.input.set_value(&draft);
+        }
+    }
+
+    /// Remember a sent message, unless it repeats the last one.
+    fn push_history(&mut self, text: &str) {
+        if self.history.back().map(String::as_str) != Some(text) {
+            self.history.push_back(text.to_string());
+            self.trim_history();
+        }
     }
 
-    /// Update lines based on text.
-    fn update_lines(&mut self) {
-        self.lines = self.text.split('\n').map(|s| s.to_string()).collect();
-        if self.lines.is_empty() {
-            self.lines.push(String::new());
+    fn trim_history(&mut self) {
+        while self.history.len() > self.history_limit {
+            self.history.pop_front();
         }
     }
+}
 
-    /// Update cursor position from current line.
-    #[allow(dead_code)]
-    fn update_cursor_from_lines(&mut self) {
-        let mut pos = 0;
-        for (i, line) in self.lines.iter().enumerate() {
-            if i == self.current_line {
-                self.cursor = pos + line.len();
-                return;
+/// Byte ranges of the rows `text` takes at `width` columns, breaking at
+/// newlines and between graphemes.
+fn wrap_rows(text: &str, width: usize) -> Vec<Range<usize>> {
+    let width = width.max(1);
+    let mut rows = Vec::new();
+    let mut start = 0;
+    for line in text.split('\n') {
+        let mut row_start = start;
+        let mut row_width = 0;
+        for (i, grapheme) in line.grapheme_indices(true) {
+            let grapheme_width = grapheme.width();
+            if row_width + grapheme_width > width && row_width > 0 {
+                rows.push(row_start..start + i);
+                row_start = start + i;
+                row_width = 0;
             }
-            pos += line.len() + 1;
+            row_width += grapheme_width;
         }
+        rows.push(row_start..start + line.len());
+        start += line.len() + 1;
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(input: &mut InputState, code: KeyCode) {
+        input.handle_key(KeyEvent::new(code, KeyModifiers::NONE));
+    }
+
+    #[test]
+    fn test_wrapping_counts_columns_not_bytes() {
+        // Wide characters take two columns and never split across rows.
+        assert_eq!(wrap_rows("日本語", 4), [0..6, 6..9]);
+        assert_eq!(wrap_rows("ab\n\ncd", 1), [0..1, 1..2, 3..3, 4..5, 5..6]);
+        assert_eq!(wrap_rows("", 10), [Range { start: 0, end: 0 }]);
+
+        let mut input = InputState::new();
+        input.set_text("héllo wörld");
+        assert_eq!(input.wrapped_rows(5), [0..6, 6..12, 12..13]);
+        assert_eq!(input.wrapped_cursor(5), (2, 1));
+        press(&mut input, KeyCode::Left);
+        press(&mut input, KeyCode::Left);
+        assert_eq!(input.wrapped_cursor(5), (1, 4));
+    }
+
+    #[test]
+    fn test_cursor_moves_over_multibyte_lines() {
+        let mut input = InputState::new();
+        input.set_text("日本\nabcd");
+        press(&mut input, KeyCode::Left);
+        assert_eq!((input.cursor_row(), input.cursor_col()), (1, 3));
+
+        // Column 3 falls inside the second wide character.
+        press(&mut input, KeyCode::Up);
+        assert_eq!((input.cursor_row(), input.cursor_col()), (0, 2));
+        assert_eq!(input.cursor(), 3);
+        press(&mut input, KeyCode::End);
+        press(&mut input, KeyCode::Down);
+        assert_eq!((input.cursor_row(), input.cursor_col()), (1, 4));
+    }
+
+    #[test]
+    fn test_history_is_capped_and_recall_stops_at_its_ends() {
+        let mut input = InputState::new();
+        input.set_history_limit(2);
+        input.set_history(["one", "", "two", "three"]);
+        assert_eq!(input.history().collect::<Vec<_>>(), ["two", "three"]);
+
+        // Down before browsing does nothing; Up past the oldest stays.
+        press(&mut input, KeyCode::Down);
+        assert_eq!(input.text(), "");
+        for _ in 0..3 {
+            press(&mut input, KeyCode::Up);
+        }
+        assert_eq!(input.text(), "two");
+        press(&mut input, KeyCode::Down);
+        press(&mut input, KeyCode::Down);
+        assert_eq!(input.text(), "");
+
+        // Sending a repeat of the newest entry does not add it again.
+        input.set_text("three");
+        press(&mut input, KeyCode::Enter);
+        input.set_text("four");
+        press(&mut input, KeyCode::Enter);
+        assert_eq!(input.history().collect::<Vec<_>>(), ["three", "four"]);
     }
 }
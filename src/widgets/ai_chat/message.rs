@@ -1,3 +1,20 @@
+#[cfg(feature = "markdown-preview")]
+use std::cell::{Cell, Ref, RefCell};
+
+#[cfg(feature = "markdown-preview")]
+use ratatui::text::Line;
+
+#[cfg(feature = "markdown-preview")]
+use crate::widgets::markdown_preview::StreamingMarkdown;
+
+/// Width streamed markdown is rendered at until the chat sets one.
+#[cfg(feature = "markdown-preview")]
+const DEFAULT_MARKDOWN_WIDTH: usize = 80;
+
+/// Identifies a message in a [`MessageStore`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MessageId(u64);
+
 /// Role of a message sender.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MessageRole {
@@ -10,6 +27,8 @@ pub enum MessageRole {
 /// A single chat message.
 #[derive(Debug, Clone)]
 pub struct Message {
+    /// Id assigned when the message is added to a store
+    id: MessageId,
     /// Role of sender
     pub role: MessageRole,
     /// Message content
@@ -18,26 +37,137 @@ pub struct Message {
     pub attachments: Vec<String>,
     /// Timestamp
     pub timestamp: std::time::SystemTime,
+    /// Whether more of the message is still streaming in
+    streaming: bool,
+    /// Incrementally rendered markdown, for messages built with
+    /// [`Message::streaming`]
+    #[cfg(feature = "markdown-preview")]
+    markdown: Option<RefCell<StreamingMarkdown>>,
+    /// Bytes of `content` already fed to the markdown
+    #[cfg(feature = "markdown-preview")]
+    parsed: Cell<usize>,
 }
 
 impl Message {
     /// Create a new user message.
     pub fn user(content: String) -> Self {
         Self {
+            id: MessageId(0),
             role: MessageRole::User,
             content,
             attachments: Vec::new(),
             timestamp: std::time::SystemTime::now(),
+            streaming: false,
+            #[cfg(feature = "markdown-preview")]
+            markdown: None,
+            #[cfg(feature = "markdown-preview")]
+            parsed: Cell::new(0),
         }
     }
 
     /// Create a new AI message.
     pub fn assistant(content: String) -> Self {
         Self {
+            id: MessageId(0),
             role: MessageRole::Assistant,
             content,
             attachments: Vec::new(),
             timestamp: std::time::SystemTime::now(),
+            streaming: false,
+            #[cfg(feature = "markdown-preview")]
+            markdown: None,
+            #[cfg(feature = "markdown-preview")]
+            parsed: Cell::new(0),
+        }
+    }
+
+    /// Create an empty message from `role` to be filled in with
+    /// [`append`](Self::append) as it streams in.
+    ///
+    /// With the `markdown-preview` feature the message is rendered as
+    /// markdown, parsing only the unfinished tail when it is next read.
+    pub fn streaming(role: MessageRole) -> Self {
+        Self {
+            role,
+            streaming: true,
+            #[cfg(feature = "markdown-preview")]
+            markdown: Some(RefCell::new(StreamingMarkdown::new(DEFAULT_MARKDOWN_WIDTH))),
+            ..Self::assistant(String::new())
+        }
+    }
+
+    /// Create an empty AI message to be filled in as the reply streams in.
+    pub fn streaming_assistant() -> Self {
+        Self::streaming(MessageRole::Assistant)
+    }
+
+    /// Append streamed text to the message content.
+    ///
+    /// The markdown is parsed when it is next read, so any number of
+    /// chunks appended between two frames cost one parse. Returns false,
+    /// leaving the message unchanged, once it is finished.
+    pub fn append(&mut self, chunk: &str) -> bool {
+        if !self.streaming {
+            return false;
+        }
+        self.content.push_str(chunk);
+        true
+    }
+
+    /// Mark a streamed message as complete. It can no longer be appended
+    /// to.
+    pub fn finish(&mut self) {
+        self.streaming = false;
+        #[cfg(feature = "markdown-preview")]
+        if let Some(markdown) = self.markdown.as_ref() {
+            self.sync_markdown();
+            markdown.borrow_mut().finish();
+        }
+    }
+
+    /// Feeds the markdown the content appended since it was last read.
+    #[cfg(feature = "markdown-preview")]
+    fn sync_markdown(&self) {
+        let Some(markdown) = self.markdown.as_ref() else {
+            return;
+        };
+        if let Some(pending) = self.content.get(self.parsed.get()..) {
+            if !pending.is_empty() {
+                markdown.borrow_mut().push_str(pending);
+                self.parsed.set(self.content.len());
+            }
+        }
+    }
+
+    /// Get the id the store assigned to the message.
+    pub fn id(&self) -> MessageId {
+        self.id
+    }
+
+    /// Whether the message is still streaming in.
+    pub fn is_streaming(&self) -> bool {
+        self.streaming
+    }
+
+    /// Get the streamed markdown, if the message was streamed, parsing
+    /// any text appended since it was last read.
+    #[cfg(feature = "markdown-preview")]
+    pub fn markdown(&self) -> Option<Ref<'_, StreamingMarkdown>> {
+        self.sync_markdown();
+        self.markdown.as_ref().map(RefCell::borrow)
+    }
+
+    /// Get the rendered markdown lines, if the message was streamed.
+    #[cfg(feature = "markdown-preview")]
+    pub fn markdown_lines(&self) -> Option<Vec<Line<'static>>> {
+        self.markdown().map(|markdown| markdown.lines())
+    }
+
+    /// Set the width streamed markdown is rendered at.
+    #[cfg(feature = "markdown-preview")]
+    pub fn set_markdown_width(&mut self, width: usize) {
+        if let Some(markdown) = self.markdown.as_mut() {
+            markdown.get_mut().set_width(width);
         }
     }
 
@@ -53,6 +183,8 @@ impl Message {
 pub struct MessageStore {
     /// All messages in conversation
     messages: Vec<Message>,
+    /// Id of the last message added
+    last_id: u64,
 }
 
 impl MessageStore {
@@ -62,10 +194,77 @@ impl MessageStore {
     }
 
     /// Add a message to store.
-    pub fn add(&mut self, message: Message) {
+    pub fn add(&mut self, mut message: Message) {
+        self.last_id += 1;
+        message.id = MessageId(self.last_id);
         self.messages.push(message);
     }
 
+    /// Add an empty message from `role` to stream into and return its id.
+    ///
+    /// Feed it with [`append_to`](Self::append_to) and end it with
+    /// [`finish`](Self::finish).
+    pub fn begin_streaming(&mut self, role: MessageRole) -> MessageId {
+        self.add(Message::streaming(role));
+        MessageId(self.last_id)
+    }
+
+    /// Add an empty AI message to stream a reply into and return its id.
+    pub fn begin_assistant_message(&mut self) -> MessageId {
+        self.begin_streaming(MessageRole::Assistant)
+    }
+
+    /// Append streamed text to message `id`.
+    ///
+    /// Returns false if there is no such message or it is finished.
+    pub fn append_to(&mut self, id: MessageId, delta: &str) -> bool {
+        self.get_mut(id)
+            .is_some_and(|message| message.append(delta))
+    }
+
+    /// Same as [`append_to`](Self::append_to).
+    pub fn append_to_message(&mut self, id: MessageId, chunk: &str) -> bool {
+        self.append_to(id, chunk)
+    }
+
+    /// Mark message `id` as complete.
+    ///
+    /// Returns false if there is no such message or it was already
+    /// finished.
+    pub fn finish(&mut self, id: MessageId) -> bool {
+        match self.get_mut(id) {
+            Some(message) if message.is_streaming() => {
+                message.finish();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Same as [`finish`](Self::finish).
+    pub fn finish_message(&mut self, id: MessageId) -> bool {
+        self.finish(id)
+    }
+
+    /// Remove message `id` and return it.
+    pub fn remove(&mut self, id: MessageId) -> Option<Message> {
+        let index = self.messages.iter().position(|message| message.id == id)?;
+        Some(self.messages.remove(index))
+    }
+
+    /// Get message `id`.
+    pub fn get(&self, id: MessageId) -> Option<&Message> {
+        self.messages.iter().rev().find(|message| message.id == id)
+    }
+
+    /// Get message `id` mutably.
+    pub fn get_mut(&mut self, id: MessageId) -> Option<&mut Message> {
+        self.messages
+            .iter_mut()
+            .rev()
+            .find(|message| message.id == id)
+    }
+
     /// Get all messages.
     pub fn messages(&self) -> &[Message] {
         &self.messages
@@ -90,4 +289,95 @@ impl MessageStore {
     pub fn last(&self) -> Option<&Message> {
         self.messages.last()
     }
+
+    /// Get last message mutably.
+    pub fn last_mut(&mut self) -> Option<&mut Message> {
+        self.messages.last_mut()
+    }
+
+    /// Get all messages mutably.
+    pub fn messages_mut(&mut self) -> &mut [Message] {
+        &mut self.messages
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "markdown-preview")]
+    use crate::widgets::markdown_preview::render_markdown;
+
+    #[test]
+    fn test_store_streams_into_message_by_id() {
+        let mut store = MessageStore::new();
+        store.add(Message::user("hi".to_string()));
+        let first = store.begin_assistant_message();
+        let second = store.begin_assistant_message();
+        assert_ne!(first, second);
+        assert_ne!(store.messages()[0].id(), first);
+
+        assert!(store.append_to_message(first, "Hel"));
+        assert!(store.append_to_message(second, "Other"));
+        assert!(store.append_to_message(first, "lo"));
+        assert!(store.get(first).unwrap().is_streaming());
+
+        assert!(store.finish_message(first));
+        assert!(!store.finish_message(first));
+        assert!(!store.append_to_message(first, "!"));
+        assert_eq!(store.get(first).unwrap().content, "Hello");
+        assert!(!store.get(first).unwrap().is_streaming());
+        assert!(store.get(second).unwrap().is_streaming());
+    }
+
+    #[cfg(feature = "markdown-preview")]
+    #[test]
+    fn test_streamed_message_renders_markdown() {
+        let reply = "# Plan\n\n- one\n- two\n\n```rust\nfn main() {}\n```\n";
+        let mut message = Message::streaming_assistant();
+        for chunk in reply.as_bytes().chunks(5) {
+            message.append(std::str::from_utf8(chunk).unwrap());
+        }
+        message.finish();
+
+        assert_eq!(message.content, reply);
+        assert_eq!(
+            message.markdown_lines(),
+            Some(render_markdown(reply, Some(DEFAULT_MARKDOWN_WIDTH)).lines)
+        );
+        assert_eq!(Message::assistant(reply.to_string()).markdown_lines(), None);
+    }
+
+    #[test]
+    fn test_finished_messages_are_immutable() {
+        let mut store = MessageStore::new();
+        let id = store.begin_streaming(MessageRole::User);
+        assert_eq!(store.get(id).unwrap().role, MessageRole::User);
+        assert!(store.append_to(id, "draft"));
+        assert!(store.finish(id));
+
+        assert!(!store.append_to(id, " more"));
+        assert!(!store.get_mut(id).unwrap().append(" more"));
+        assert!(!Message::assistant("done".to_string()).append("!"));
+        assert_eq!(store.get(id).unwrap().content, "draft");
+    }
+
+    #[cfg(feature = "markdown-preview")]
+    #[test]
+    fn test_appends_between_reads_are_parsed_once() {
+        let mut message = Message::streaming_assistant();
+        for delta in ["Hel", "lo ", "**wor", "ld**"] {
+            assert!(message.append(delta));
+        }
+        {
+            let markdown = message.markdown().unwrap();
+            assert_eq!(markdown.source(), "Hello **world**");
+            assert_eq!(markdown.last_stats().bytes_parsed, 15);
+        }
+
+        message.append("\n");
+        message.finish();
+        let markdown = message.markdown().unwrap();
+        assert!(markdown.is_finished());
+        assert_eq!(markdown.source(), "Hello **world**\n");
+    }
 }
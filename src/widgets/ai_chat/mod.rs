@@ -1,10 +1,12 @@
 //! AI chat interface for ratatui
 
 pub mod ai_chat;
+mod command;
 mod components;
 mod input;
 mod message;
 
-pub use ai_chat::{AIChat, AIChatEvent};
+pub use ai_chat::{AIChat, AIChatEvent, MessageAction};
+pub use command::{ArgCompletion, CompletionFn, SlashCommand};
 pub use input::InputState;
-pub use message::{Message, MessageRole, MessageStore};
+pub use message::{Message, MessageId, MessageRole, MessageStore};
@@ -0,0 +1,502 @@
+use crossterm::event::{KeyEvent, MouseEvent};
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+use ratatui::Frame;
+
+use crate::primitives::menu_bar::{display_width, MenuBar, MenuEvent};
+use crate::primitives::statusline::{StackedItem, StatusLineStacked};
+use crate::widgets::chrome_bar::status_item::StatusItem;
+use crate::widgets::hotkey_footer::{HotkeyFooter, HotkeyItem};
+
+/// Rows of the multi-row layout: the bordered menu bar, the status line
+/// and the hotkey footer.
+const MULTI_ROW_HEIGHT: u16 = 5;
+
+/// Hotkeys kept when the bar collapses to one row.
+const COMPACT_HOTKEYS: usize = 2;
+
+const MENU_GLYPH: &str = "≡";
+
+/// Where a [`ChromeBar`] puts its rows and the content between them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChromeLayout {
+    /// True when everything shares a single row.
+    pub compact: bool,
+    /// The menu bar, or the whole single row when compact.
+    pub menu: Rect,
+    /// The status line; `None` when compact.
+    pub status: Option<Rect>,
+    /// The hotkey footer; `None` when compact.
+    pub hotkeys: Option<Rect>,
+    /// The area left for the application.
+    pub content: Rect,
+}
+
+/// Menu bar, status line and hotkey footer that collapse into a single
+/// row on short terminals.
+///
+/// Above [`compact_height`](Self::compact_height) rows, the menu bar sits at
+/// the top and the status line and hotkey footer at the bottom. At or below
+/// it, one row at the top shows the active menu and the mode, the two most
+/// relevant hotkeys for the current scope, and as many status items as fit
+/// by priority. Clicking the menu region still opens its dropdown.
+pub struct ChromeBar {
+    /// The menu; its items and dropdowns work in both layouts.
+    pub menu: MenuBar,
+    /// Always shown, e.g. the current mode or tab.
+    pub mode: StatusItem,
+    /// Status indicators, dropped by priority when the row is full.
+    pub status: Vec<StatusItem>,
+    /// Hotkeys shown in every scope.
+    pub hotkeys: Vec<HotkeyItem>,
+    /// Hotkeys shown only in their scope, ahead of the global ones.
+    pub scoped_hotkeys: Vec<(String, HotkeyItem)>,
+    /// The active scope, set with [`set_scope`](Self::set_scope).
+    pub scope: Option<String>,
+    /// Terminal heights up to this use the single-row layout.
+    pub compact_height: u16,
+    /// Base style of the status line and the single row.
+    pub status_style: Style,
+    /// Color of hotkey keys.
+    pub key_color: Color,
+    /// Color of hotkey descriptions.
+    pub description_color: Color,
+    /// Background of the hotkeys.
+    pub background_color: Color,
+}
+
+impl ChromeBar {
+    /// Creates a chrome bar around `menu`, with no mode, status or hotkeys.
+    pub fn new(menu: MenuBar) -> Self {
+        Self {
+            menu,
+            mode: StatusItem::new("", u8::MAX),
+            status: Vec::new(),
+            hotkeys: Vec::new(),
+            scoped_hotkeys: Vec::new(),
+            scope: None,
+            compact_height: 20,
+            status_style: Style::default().fg(Color::White).bg(Color::DarkGray),
+            key_color: Color::Cyan,
+            description_color: Color::DarkGray,
+            background_color: Color::Black,
+        }
+    }
+
+    /// Sets the indicator that is always shown, whatever its priority.
+    pub fn mode(mut self, mode: StatusItem) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Adds a status indicator.
+    pub fn status_item(mut self, item: StatusItem) -> Self {
+        self.status.push(item);
+        self
+    }
+
+    /// Sets the hotkeys shown in every scope.
+    pub fn hotkeys(mut self, items: Vec<HotkeyItem>) -> Self {
+        self.hotkeys = items;
+        self
+    }
+
+    /// Adds hotkeys that only apply while `scope` is active.
+    pub fn scoped_hotkeys(mut self, scope: impl Into<String>, items: Vec<HotkeyItem>) -> Self {
+        let scope = scope.into();
+        self.scoped_hotkeys
+            .extend(items.into_iter().map(|item| (scope.clone(), item)));
+        self
+    }
+
+    /// Sets the tallest terminal that gets the single-row layout.
+    pub fn compact_height(mut self, height: u16) -> Self {
+        self.compact_height = height;
+        self
+    }
+
+    /// Sets the base style of the status line and the single row.
+    pub fn status_style(mut self, style: Style) -> Self {
+        self.status_style = style;
+        self
+    }
+
+    /// Sets the hotkey colors, as [`HotkeyFooter::with_theme_colors`] does.
+    pub fn with_theme_colors(
+        mut self,
+        key_color: Color,
+        description_color: Color,
+        background_color: Color,
+    ) -> Self {
+        self.key_color = key_color;
+        self.description_color = description_color;
+        self.background_color = background_color;
+        self
+    }
+
+    /// Sets the scope whose hotkeys are shown first.
+    pub fn set_scope(&mut self, scope: Option<&str>) {
+        self.scope = scope.map(ToString::to_string);
+    }
+
+    /// Hotkeys for the current scope, then the global ones.
+    pub fn relevant_hotkeys(&self) -> Vec<&HotkeyItem> {
+        self.scoped_hotkeys
+            .iter()
+            .filter(|(scope, _)| self.scope.as_deref() == Some(scope.as_str()))
+            .map(|(_, item)| item)
+            .chain(self.hotkeys.iter())
+            .collect()
+    }
+
+    /// Splits `area` into the bar's rows and the content area.
+    pub fn layout(&self, area: Rect) -> ChromeLayout {
+        let compact = area.height <= self.compact_height || area.height <= MULTI_ROW_HEIGHT;
+        if compact {
+            let menu = Rect {
+                height: area.height.min(1),
+                ..area
+            };
+            return ChromeLayout {
+                compact,
+                menu,
+                status: None,
+                hotkeys: None,
+                content: Rect {
+                    y: area.y + menu.height,
+                    height: area.height - menu.height,
+                    ..area
+                },
+            };
+        }
+
+        let bottom = area.y + area.height;
+        ChromeLayout {
+            compact,
+            menu: Rect { height: 3, ..area },
+            status: Some(Rect {
+                y: bottom - 2,
+                height: 1,
+                ..area
+            }),
+            hotkeys: Some(Rect {
+                y: bottom - 1,
+                height: 1,
+                ..area
+            }),
+            content: Rect {
+                y: area.y + 3,
+                height: area.height - MULTI_ROW_HEIGHT,
+                ..area
+            },
+        }
+    }
+
+    /// Renders the bar around `area` and returns the content area.
+    ///
+    /// Call [`render_overlays`](Self::render_overlays) after drawing the
+    /// content so open dropdowns appear on top.
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) -> Rect {
+        let layout = self.layout(area);
+        if layout.compact {
+            self.render_compact(frame, layout.menu);
+        } else {
+            self.menu.render(frame, layout.menu);
+            if let Some(status) = layout.status {
+                self.render_status_line(frame, status);
+            }
+            if let Some(hotkeys) = layout.hotkeys {
+                let items = self.relevant_hotkeys().into_iter().cloned().collect();
+                HotkeyFooter::new(items)
+                    .with_theme_colors(
+                        self.key_color,
+                        self.description_color,
+                        self.background_color,
+                    )
+                    .render(frame, hotkeys);
+            }
+        }
+        layout.content
+    }
+
+    /// Renders open menu dropdowns over the rest of the frame.
+    pub fn render_overlays(&mut self, frame: &mut Frame) {
+        self.menu.render_dropdowns(frame);
+    }
+
+    /// Forwards a key press to the menu; see [`MenuBar::handle_key`].
+    pub fn handle_key(&mut self, key: KeyEvent) -> Option<MenuEvent> {
+        self.menu.handle_key(key)
+    }
+
+    /// Forwards a mouse event to the menu in either layout; see
+    /// [`MenuBar::handle_mouse_event`].
+    pub fn handle_mouse_event(&mut self, event: MouseEvent) -> Option<MenuEvent> {
+        self.menu.handle_mouse_event(event)
+    }
+
+    fn mode_text(&self) -> Option<String> {
+        (!self.mode.text.is_empty()).then(|| format!(" {} ", self.mode.text))
+    }
+
+    fn render_status_line(&self, frame: &mut Frame, area: Rect) {
+        let mut line = StatusLineStacked::new().style(self.status_style);
+        if let Some(mode) = self.mode_text() {
+            line = line.start_item(
+                StackedItem::new(Span::styled(mode, self.mode.style)).priority(u8::MAX),
+            );
+        }
+        // `end_item` stacks from the right edge inwards.
+        for item in self.status.iter().rev() {
+            line = line.end_item(
+                StackedItem::new(Span::styled(format!(" {} ", item.text), item.style))
+                    .priority(item.priority)
+                    .truncate(item.truncate)
+                    .min_width(item.min_width),
+            );
+        }
+        frame.render_widget(line, area);
+    }
+
+    /// Index of the top-level item the compact row shows.
+    fn active_item(&self) -> usize {
+        self.menu
+            .open
+            .first()
+            .copied()
+            .or_else(|| self.menu.selected())
+            .unwrap_or(0)
+    }
+
+    fn render_compact(&mut self, frame: &mut Frame, row: Rect) {
+        if row.height == 0 {
+            return;
+        }
+        let active = self.active_item();
+        let Some(item) = self.menu.items.get(active) else {
+            return;
+        };
+
+        let menu_text = format!(" {} {} ", MENU_GLYPH, item.display_label());
+        let menu_style = match (self.menu.is_open(), item.hovered) {
+            (true, _) => self.menu.highlight_style,
+            (false, true) => self.menu.selected_hover_style,
+            (false, false) => self.menu.selected_style,
+        };
+        let menu_width = (display_width(&menu_text) as u16).min(row.width);
+        let mut remaining = row.width as usize - menu_width as usize;
+
+        let mode = self.mode_text().map(|mode| {
+            remaining = remaining.saturating_sub(display_width(&mode));
+            Span::styled(mode, self.mode.style)
+        });
+
+        let mut hotkeys = Vec::new();
+        for hotkey in self.relevant_hotkeys().into_iter().take(COMPACT_HOTKEYS) {
+            let width = display_width(&hotkey.key) + display_width(&hotkey.description) + 3;
+            if width > remaining {
+                break;
+            }
+            remaining -= width;
+            hotkeys.push(hotkey);
+        }
+        let hotkeys_width: usize = hotkeys
+            .iter()
+            .map(|hotkey| display_width(&hotkey.key) + display_width(&hotkey.description) + 3)
+            .sum();
+
+        let mut by_priority: Vec<usize> = (0..self.status.len()).collect();
+        by_priority.sort_by_key(|&index| std::cmp::Reverse(self.status[index].priority));
+        let mut shown = vec![false; self.status.len()];
+        for index in by_priority {
+            let width = display_width(&self.status[index].text) + 2;
+            if width <= remaining {
+                remaining -= width;
+                shown[index] = true;
+            }
+        }
+
+        let mut spans = vec![Span::styled(menu_text, menu_style)];
+        spans.extend(mode);
+        spans.extend(
+            self.status
+                .iter()
+                .zip(&shown)
+                .filter(|(_, shown)| **shown)
+                .map(|(item, _)| Span::styled(format!(" {} ", item.text), item.style)),
+        );
+        frame.render_widget(
+            Paragraph::new(Line::from(spans)).style(self.status_style),
+            row,
+        );
+
+        if !hotkeys.is_empty() {
+            let mut spans = Vec::new();
+            for hotkey in hotkeys {
+                spans.push(Span::styled(
+                    format!(" {}", hotkey.key),
+                    Style::default()
+                        .fg(self.key_color)
+                        .add_modifier(Modifier::BOLD),
+                ));
+                spans.push(Span::styled(
+                    format!(" {} ", hotkey.description),
+                    Style::default().fg(self.description_color),
+                ));
+            }
+            let width = hotkeys_width as u16;
+            let area = Rect {
+                x: row.x + row.width - width,
+                width,
+                height: 1,
+                ..row
+            };
+            frame.render_widget(
+                Paragraph::new(Line::from(spans)).style(Style::default().bg(self.background_color)),
+                area,
+            );
+        }
+
+        // Only the active item is on screen; clicking it opens its dropdown
+        // below the row.
+        let menu_area = Rect {
+            width: menu_width,
+            height: 1,
+            ..row
+        };
+        self.menu.area = Some(Rect { height: 1, ..row });
+        for (index, item) in self.menu.items.iter_mut().enumerate() {
+            item.area = (index == active).then_some(menu_area);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+    use ratatui::backend::TestBackend;
+    use ratatui::layout::Rect;
+    use ratatui::Terminal;
+
+    use crate::primitives::menu_bar::{MenuBar, MenuEvent, MenuItem};
+    use crate::widgets::chrome_bar::{ChromeBar, StatusItem};
+    use crate::widgets::hotkey_footer::HotkeyItem;
+
+    fn chrome() -> ChromeBar {
+        let menu = MenuBar::new(vec![
+            MenuItem::new("File", 0)
+                .with_children(vec![MenuItem::new("Open", 10), MenuItem::new("Quit", 11)]),
+            MenuItem::new("View", 1).with_children(vec![MenuItem::new("Zoom", 20)]),
+        ])
+        .with_selected(0);
+        ChromeBar::new(menu)
+            .mode(StatusItem::new("NORMAL", 0))
+            .status_item(StatusItem::new("main", 5))
+            .status_item(StatusItem::new("utf-8", 1))
+            .status_item(StatusItem::new("Ln 12", 9))
+            .hotkeys(vec![
+                HotkeyItem::new("q", "quit"),
+                HotkeyItem::new("?", "help"),
+            ])
+            .scoped_hotkeys("tree", vec![HotkeyItem::new("o", "open")])
+    }
+
+    fn draw(chrome: &mut ChromeBar, width: u16, height: u16) -> (Terminal<TestBackend>, Rect) {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        let mut content = Rect::default();
+        terminal
+            .draw(|frame| {
+                content = chrome.render(frame, frame.area());
+                chrome.render_overlays(frame);
+            })
+            .unwrap();
+        (terminal, content)
+    }
+
+    fn row(terminal: &Terminal<TestBackend>, y: u16) -> String {
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.width)
+            .map(|x| buffer[(x, y)].symbol().to_string())
+            .collect()
+    }
+
+    fn click(column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    #[test]
+    fn switches_to_multi_row_above_the_threshold() {
+        let chrome = chrome();
+        let small = chrome.layout(Rect::new(0, 0, 80, 20));
+        assert!(small.compact);
+        assert_eq!(small.content, Rect::new(0, 1, 80, 19));
+
+        let tall = chrome.layout(Rect::new(0, 0, 80, 21));
+        assert!(!tall.compact);
+        assert_eq!(tall.menu, Rect::new(0, 0, 80, 3));
+        assert_eq!(tall.status, Some(Rect::new(0, 19, 80, 1)));
+        assert_eq!(tall.hotkeys, Some(Rect::new(0, 20, 80, 1)));
+        assert_eq!(tall.content, Rect::new(0, 3, 80, 16));
+    }
+
+    #[test]
+    fn compact_row_keeps_menu_mode_and_scoped_hotkeys() {
+        let mut chrome = chrome();
+        chrome.set_scope(Some("tree"));
+        let (terminal, _) = draw(&mut chrome, 80, 20);
+
+        let line = row(&terminal, 0);
+        assert!(line.starts_with(" ≡ File  NORMAL "), "{line:?}");
+        assert!(line.contains(" main ") && line.contains(" utf-8 "));
+        assert!(line.trim_end().ends_with("o open  q quit"), "{line:?}");
+        assert!(!line.contains("help"));
+    }
+
+    #[test]
+    fn status_items_drop_by_priority_when_narrow() {
+        let mut chrome = chrome();
+        let (terminal, _) = draw(&mut chrome, 46, 10);
+
+        let line = row(&terminal, 0);
+        assert!(line.contains("NORMAL"), "{line:?}");
+        assert!(line.contains("Ln 12") && line.contains("main"), "{line:?}");
+        assert!(!line.contains("utf-8"), "{line:?}");
+    }
+
+    #[test]
+    fn clicking_the_compact_menu_opens_a_dropdown() {
+        let mut chrome = chrome();
+        draw(&mut chrome, 80, 20);
+        assert!(matches!(
+            chrome.handle_mouse_event(click(2, 0)),
+            Some(MenuEvent::Opened { index: 0 })
+        ));
+
+        let (terminal, _) = draw(&mut chrome, 80, 20);
+        assert!(row(&terminal, 2).contains("Open"));
+        // Other top-level items are hidden and cannot be clicked.
+        assert!(chrome.menu.items[1].area.is_none());
+    }
+
+    #[test]
+    fn clicking_menu_items_works_in_the_multi_row_layout() {
+        let mut chrome = chrome();
+        let (terminal, content) = draw(&mut chrome, 80, 30);
+        assert_eq!(content.height, 25);
+        assert!(row(&terminal, 28).contains("main"));
+        assert!(row(&terminal, 29).contains("quit"));
+
+        let view = chrome.menu.items[1].area.unwrap();
+        assert!(matches!(
+            chrome.handle_mouse_event(click(view.x, view.y)),
+            Some(MenuEvent::Opened { index: 1 })
+        ));
+    }
+}
@@ -0,0 +1,29 @@
+//! Chrome bar widget for ratatui.
+//!
+//! Composes a [`MenuBar`](crate::primitives::menu_bar::MenuBar), status
+//! items and hotkeys into the usual top and bottom bars, collapsing them
+//! into a single row on short terminals:
+//! - The active menu and the mode are always shown
+//! - Hotkeys collapse to the two most relevant for the current scope
+//! - Status items drop by priority when space runs out
+//! - Menu dropdowns open from either layout
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratkit::primitives::menu_bar::{MenuBar, MenuItem};
+//! use ratkit::widgets::chrome_bar::{ChromeBar, StatusItem};
+//! use ratkit::widgets::hotkey_footer::HotkeyItem;
+//!
+//! let chrome = ChromeBar::new(MenuBar::new(vec![MenuItem::new("File", 0)]))
+//!     .mode(StatusItem::new("NORMAL", 0))
+//!     .status_item(StatusItem::new("main", 5))
+//!     .hotkeys(vec![HotkeyItem::new("q", "quit")])
+//!     .compact_height(20);
+//! ```
+
+mod bar;
+mod status_item;
+
+pub use bar::{ChromeBar, ChromeLayout};
+pub use status_item::StatusItem;
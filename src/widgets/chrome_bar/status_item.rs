@@ -0,0 +1,47 @@
+use ratatui::style::Style;
+
+/// A status indicator shown by a [`ChromeBar`](super::ChromeBar).
+///
+/// When there is not enough room, indicators with the lowest `priority` are
+/// dropped first. On the multi-row status line a `truncate` indicator is
+/// shortened with `…`, down to `min_width` columns, before it is dropped.
+#[derive(Clone, Debug)]
+pub struct StatusItem {
+    /// Text shown, padded with a space on each side.
+    pub text: String,
+    /// Style of the indicator.
+    pub style: Style,
+    /// Higher priorities are kept longer.
+    pub priority: u8,
+    /// Narrowest width, in columns, the text may be truncated to.
+    pub min_width: u16,
+    /// Whether the text may be truncated instead of dropped.
+    pub truncate: bool,
+}
+
+impl StatusItem {
+    /// Creates an unstyled indicator.
+    pub fn new(text: impl Into<String>, priority: u8) -> Self {
+        Self {
+            text: text.into(),
+            style: Style::default(),
+            priority,
+            min_width: 0,
+            truncate: false,
+        }
+    }
+
+    /// Sets the style of the indicator.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Lets the text be truncated to as few as `min_width` columns before
+    /// the indicator is dropped.
+    pub fn truncate(mut self, min_width: u16) -> Self {
+        self.truncate = true;
+        self.min_width = min_width;
+        self
+    }
+}
@@ -0,0 +1,31 @@
+//! Clipboard history overlay.
+//!
+//! A centered popup listing recent entries from the clipboard ring
+//! ([`crate::services::clipboard`]) with their source icon and a one-line
+//! preview.
+//!
+//! # Features
+//!
+//! - Keyboard navigation (j/k/Up/Down)
+//! - Enter copies the highlighted entry again and returns it for pasting
+//! - Esc to cancel
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use ratkit::widgets::clipboard_history::{ClipboardHistory, ClipboardHistoryEvent};
+//! use crossterm::event::KeyCode;
+//!
+//! let mut history = ClipboardHistory::new();
+//! history.show();
+//!
+//! if let Some(ClipboardHistoryEvent::Selected(entry)) = history.handle_key(&KeyCode::Enter) {
+//!     // Paste into the widget that opened the history, e.g.
+//!     // chat_input.insert_str(&entry.text);
+//!     let _ = entry;
+//! }
+//! ```
+
+mod widget;
+
+pub use widget::{ClipboardHistory, ClipboardHistoryEvent};
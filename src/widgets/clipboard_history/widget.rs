@@ -0,0 +1,195 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::services::clipboard::{self, ClipboardEntry};
+
+const MAX_VISIBLE_ENTRIES: usize = 15;
+const POPUP_WIDTH: u16 = 60;
+
+/// Events emitted by [`ClipboardHistory`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClipboardHistoryEvent {
+    /// An entry was copied to the system clipboard again; paste it where
+    /// the history was opened from.
+    Selected(ClipboardEntry),
+    /// The history was closed without choosing an entry.
+    Cancelled,
+}
+
+/// Popup listing the clipboard ring, newest first.
+#[derive(Debug, Clone)]
+pub struct ClipboardHistory {
+    visible: bool,
+    index: usize,
+    width: u16,
+    title: String,
+}
+
+impl Default for ClipboardHistory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ClipboardHistory {
+    /// Creates a hidden clipboard history popup.
+    pub fn new() -> Self {
+        Self {
+            visible: false,
+            index: 0,
+            width: POPUP_WIDTH,
+            title: "Clipboard History".to_string(),
+        }
+    }
+
+    /// Sets the popup width.
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the popup title.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Shows the popup with the newest entry highlighted.
+    pub fn show(&mut self) {
+        self.visible = true;
+        self.index = 0;
+    }
+
+    /// Hides the popup.
+    pub fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    /// Returns true if the popup is shown.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Index of the highlighted entry, where 0 is the newest.
+    pub fn selected_index(&self) -> usize {
+        self.index
+    }
+
+    /// Handles a key press while the popup is shown.
+    pub fn handle_key(&mut self, key: &crossterm::event::KeyCode) -> Option<ClipboardHistoryEvent> {
+        if !self.visible {
+            return None;
+        }
+
+        use crossterm::event::KeyCode;
+
+        let len = clipboard::with_ring(|ring| ring.len());
+        match key {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.hide();
+                Some(ClipboardHistoryEvent::Cancelled)
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                if len > 0 {
+                    self.index = (self.index + 1) % len;
+                }
+                None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                if len > 0 {
+                    self.index = self.index.checked_sub(1).unwrap_or(len - 1);
+                }
+                None
+            }
+            KeyCode::Enter => {
+                let entry = clipboard::recopy(self.index)?;
+                self.hide();
+                Some(ClipboardHistoryEvent::Selected(entry))
+            }
+            _ => None,
+        }
+    }
+
+    /// Renders the popup centered in `area`.
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        let entries: Vec<ClipboardEntry> =
+            clipboard::with_ring(|ring| ring.entries().cloned().collect());
+        self.index = self.index.min(entries.len().saturating_sub(1));
+
+        let visible_count = entries.len().clamp(1, MAX_VISIBLE_ENTRIES);
+        let popup_height = (visible_count + 4) as u16;
+        let popup_area = Rect {
+            x: area.x + area.width.saturating_sub(self.width) / 2,
+            y: area.y + area.height.saturating_sub(popup_height) / 2,
+            width: self.width.min(area.width),
+            height: popup_height.min(area.height),
+        };
+
+        frame.render_widget(Clear, popup_area);
+
+        let scroll_offset = crate::primitives::scroll::calculate_scroll_offset(
+            self.index,
+            visible_count,
+            entries.len(),
+        );
+        // Borders, prefix, icon and the space after it.
+        let preview_width = (popup_area.width as usize).saturating_sub(8);
+        let muted = Style::default().fg(Color::DarkGray);
+
+        let mut lines: Vec<Line> = Vec::new();
+        if entries.is_empty() {
+            lines.push(Line::from(Span::styled("   Nothing copied yet", muted)));
+        }
+        for (idx, entry) in entries
+            .iter()
+            .enumerate()
+            .skip(scroll_offset)
+            .take(visible_count)
+        {
+            let is_selected = idx == self.index;
+            let style = if is_selected {
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            lines.push(Line::from(vec![
+                Span::styled(if is_selected { " > " } else { "   " }, style),
+                Span::styled(format!("{} ", entry.source.icon()), muted),
+                Span::styled(entry.preview(preview_width), style),
+            ]));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled(" [", muted),
+            Span::styled("j/k", Style::default().fg(Color::Yellow)),
+            Span::styled("] scroll  [", muted),
+            Span::styled("Enter", Style::default().fg(Color::Green)),
+            Span::styled("] copy  [", muted),
+            Span::styled("Esc", Style::default().fg(Color::Red)),
+            Span::styled("] cancel", muted),
+        ]));
+
+        let popup = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .title(Span::styled(
+                    format!(" {} ({}) ", self.title, entries.len()),
+                    Style::default().add_modifier(Modifier::BOLD),
+                )),
+        );
+        frame.render_widget(popup, popup_area);
+    }
+}
@@ -2,10 +2,10 @@
 
 use std::collections::HashMap;
 
-use super::super::super::diff_file_tree::DiffFileEntry;
-use super::super::super::diff_file_tree::DiffFileTree;
-use super::super::super::diff_file_tree::FileStatus;
-use super::super::super::primitives::tree_view::TreeNode;
+use crate::primitives::tree_view::TreeNode;
+use crate::widgets::code_diff::code_diff::extensions::file_tree::DiffFileEntry;
+use crate::widgets::code_diff::code_diff::extensions::file_tree::DiffFileTree;
+use crate::widgets::code_diff::code_diff::extensions::file_tree::FileStatus;
 
 impl DiffFileTree {
     /// Creates a `DiffFileTree` from a list of (path, status) pairs.
@@ -24,7 +24,7 @@ impl DiffFileTree {
     /// # Example
     ///
     /// ```rust
-    /// use ratatui_toolkit::widgets::code_diff::diff_file_tree::{DiffFileTree, FileStatus};
+    /// use ratkit::widgets::code_diff::{DiffFileTree, FileStatus};
     ///
     /// let files = vec![
     ///     ("src/lib.rs", FileStatus::Modified),
@@ -157,7 +157,7 @@ fn build_directory_node(dir_name: &str, files: &[(String, FileStatus)]) -> TreeN
     }
 
     // Add direct files
-    direct_files.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
+    direct_files.sort_by_key(|(path, _)| path.to_lowercase());
     for (path, status) in direct_files {
         let name = path.split('/').next_back().unwrap_or(&path);
         let entry = DiffFileEntry::file(name, &path, status);
@@ -224,7 +224,7 @@ fn build_subdirectory_node(
     }
 
     // Add direct files
-    direct_files.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
+    direct_files.sort_by_key(|(path, _)| path.to_lowercase());
     for (path, status) in direct_files {
         let name = path.split('/').next_back().unwrap_or(&path);
         let entry = DiffFileEntry::file(name, &path, status);
@@ -3,4 +3,5 @@
 mod from_paths;
 mod new;
 mod with_focus;
+mod with_staging;
 mod with_theme;
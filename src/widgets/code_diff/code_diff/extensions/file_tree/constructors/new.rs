@@ -1,8 +1,8 @@
 //! Constructor for creating an empty DiffFileTree.
 
-use super::super::super::diff_file_tree::DiffFileTree;
-use super::super::super::primitives::tree_view::TreeViewState;
-use crate::widgets::code_diff::services::theme::AppTheme;
+use crate::primitives::tree_view::TreeViewState;
+use crate::widgets::code_diff::code_diff::extensions::file_tree::DiffFileTree;
+use crate::widgets::markdown_preview::services::theme::AppTheme;
 
 impl DiffFileTree {
     /// Creates a new empty `DiffFileTree`.
@@ -14,7 +14,7 @@ impl DiffFileTree {
     /// # Example
     ///
     /// ```rust
-    /// use ratatui_toolkit::widgets::code_diff::diff_file_tree::DiffFileTree;
+    /// use ratkit::widgets::code_diff::DiffFileTree;
     ///
     /// let tree = DiffFileTree::new();
     /// assert!(tree.nodes.is_empty());
@@ -27,6 +27,7 @@ impl DiffFileTree {
             selected_index: 0,
             focused: false,
             theme: AppTheme::default(),
+            show_staged: false,
         }
     }
 }
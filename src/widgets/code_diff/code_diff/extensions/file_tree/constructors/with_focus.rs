@@ -1,6 +1,6 @@
 //! Builder method for setting focus state.
 
-use super::super::super::diff_file_tree::DiffFileTree;
+use crate::widgets::code_diff::code_diff::extensions::file_tree::DiffFileTree;
 
 impl DiffFileTree {
     /// Sets the focus state and returns self for chaining.
@@ -14,7 +14,7 @@ impl DiffFileTree {
     /// # Example
     ///
     /// ```rust
-    /// use ratatui_toolkit::widgets::code_diff::diff_file_tree::{DiffFileTree, FileStatus};
+    /// use ratkit::widgets::code_diff::{DiffFileTree, FileStatus};
     ///
     /// let tree = DiffFileTree::from_paths(&[("src/lib.rs", FileStatus::Modified)])
     ///     .with_focus(true);
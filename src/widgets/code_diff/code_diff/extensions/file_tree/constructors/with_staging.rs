@@ -0,0 +1,26 @@
+//! Builder method for showing staged boxes.
+
+use crate::widgets::code_diff::code_diff::extensions::file_tree::DiffFileTree;
+
+impl DiffFileTree {
+    /// Shows a staged box before each entry and lets `Space` or a click
+    /// on the box stage and unstage files.
+    ///
+    /// # Arguments
+    ///
+    /// * `show` - Whether staged boxes are shown
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratkit::widgets::code_diff::{DiffFileTree, FileStatus};
+    ///
+    /// let tree = DiffFileTree::from_paths(&[("src/lib.rs", FileStatus::Modified)])
+    ///     .with_staging(true);
+    /// ```
+    #[must_use]
+    pub fn with_staging(mut self, show: bool) -> Self {
+        self.show_staged = show;
+        self
+    }
+}
@@ -1,7 +1,7 @@
 //! Builder method for setting the theme.
 
-use super::super::super::diff_file_tree::DiffFileTree;
-use crate::widgets::code_diff::services::theme::AppTheme;
+use crate::widgets::code_diff::code_diff::extensions::file_tree::DiffFileTree;
+use crate::widgets::markdown_preview::services::theme::AppTheme;
 
 impl DiffFileTree {
     /// Sets the application theme for styling.
@@ -20,7 +20,7 @@ impl DiffFileTree {
     /// # Example
     ///
     /// ```rust
-    /// use ratatui_toolkit::widgets::code_diff::diff_file_tree::DiffFileTree;
+    /// use ratkit::widgets::code_diff::DiffFileTree;
     /// use ratatui_toolkit::services::theme::AppTheme;
     ///
     /// let theme = AppTheme::default();
@@ -1,9 +1,9 @@
 //! Method for adding a file to an existing tree.
 
-use super::super::super::diff_file_tree::DiffFileEntry;
-use super::super::super::diff_file_tree::DiffFileTree;
-use super::super::super::diff_file_tree::FileStatus;
-use super::super::super::primitives::tree_view::TreeNode;
+use crate::primitives::tree_view::TreeNode;
+use crate::widgets::code_diff::code_diff::extensions::file_tree::DiffFileEntry;
+use crate::widgets::code_diff::code_diff::extensions::file_tree::DiffFileTree;
+use crate::widgets::code_diff::code_diff::extensions::file_tree::FileStatus;
 
 impl DiffFileTree {
     /// Adds a file to the tree, creating intermediate directories as needed.
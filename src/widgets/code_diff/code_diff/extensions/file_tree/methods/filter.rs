@@ -1,6 +1,6 @@
 //! Filter-related methods for DiffFileTree.
 
-use super::super::super::diff_file_tree::DiffFileTree;
+use crate::widgets::code_diff::code_diff::extensions::file_tree::DiffFileTree;
 
 impl DiffFileTree {
     /// Enters filter mode, initializing an empty filter.
@@ -11,7 +11,7 @@ impl DiffFileTree {
     /// # Example
     ///
     /// ```rust
-    /// use ratatui_toolkit::widgets::code_diff::diff_file_tree::DiffFileTree;
+    /// use ratkit::widgets::code_diff::DiffFileTree;
     ///
     /// let mut tree = DiffFileTree::new();
     /// tree.enter_filter_mode();
@@ -1,6 +1,6 @@
 //! Focus-related methods for DiffFileTree.
 
-use super::super::super::diff_file_tree::DiffFileTree;
+use crate::widgets::code_diff::code_diff::extensions::file_tree::DiffFileTree;
 
 impl DiffFileTree {
     /// Sets focus on this widget.
@@ -2,8 +2,8 @@
 
 use crossterm::event::KeyCode;
 
-use super::super::super::diff_file_tree::DiffFileTree;
-use super::super::super::primitives::tree_view::TreeNavigator;
+use crate::primitives::tree_view::TreeNavigator;
+use crate::widgets::code_diff::code_diff::extensions::file_tree::DiffFileTree;
 
 impl DiffFileTree {
     /// Handles a key press while in filter mode.
@@ -30,7 +30,7 @@ impl DiffFileTree {
     ///
     /// ```rust
     /// use crossterm::event::KeyCode;
-    /// use ratatui_toolkit::widgets::code_diff::diff_file_tree::DiffFileTree;
+    /// use ratkit::widgets::code_diff::DiffFileTree;
     ///
     /// let mut tree = DiffFileTree::new();
     /// tree.enter_filter_mode();
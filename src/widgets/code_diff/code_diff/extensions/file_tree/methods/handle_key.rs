@@ -0,0 +1,43 @@
+//! Method to handle keyboard input.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::primitives::tree_view::{matches_filter, TreeNavigator};
+use crate::widgets::code_diff::code_diff::extensions::file_tree::{
+    DiffFileEntry, DiffFileTree, DiffFileTreeEvent,
+};
+
+impl DiffFileTree {
+    /// Handles a key press.
+    ///
+    /// In filter mode keys go to [`handle_filter_key`](Self::handle_filter_key).
+    /// Otherwise `Space` toggles the staged state of the selection when
+    /// staged boxes are shown, and other keys navigate with the tree view
+    /// bindings (`j`/`k`, `h`/`l`, `Enter`, `g`/`G`).
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key code that was pressed
+    ///
+    /// # Returns
+    ///
+    /// One [`DiffFileTreeEvent::StageToggled`] per file whose staged state
+    /// changed; empty for navigation.
+    pub fn handle_key(&mut self, key: KeyCode) -> Vec<DiffFileTreeEvent> {
+        if self.is_filter_mode() {
+            self.handle_filter_key(key);
+            return Vec::new();
+        }
+        if key == KeyCode::Char(' ') && self.show_staged {
+            return self.toggle_staged_selected();
+        }
+        let navigator = TreeNavigator::new();
+        navigator.handle_key_filtered(
+            KeyEvent::new(key, KeyModifiers::NONE),
+            &self.nodes,
+            &mut self.state,
+            |entry: &DiffFileEntry, filter: &Option<String>| matches_filter(&entry.name, filter),
+        );
+        Vec::new()
+    }
+}
@@ -0,0 +1,72 @@
+//! Method to handle mouse input.
+
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::Rect;
+
+use crate::primitives::tree_view::get_visible_paths;
+use crate::widgets::code_diff::code_diff::extensions::file_tree::{
+    DiffFileTree, DiffFileTreeEvent,
+};
+
+/// Columns before the staged box: the expand icon or folder icon and the
+/// status glyph, each followed by a space.
+const BOX_OFFSET: u16 = 4;
+/// Width of the staged box, e.g. `[x]`.
+const BOX_WIDTH: u16 = 3;
+
+impl DiffFileTree {
+    /// Handles a mouse event over the tree rendered in `area`.
+    ///
+    /// A left click selects the row under the pointer; on the staged box it
+    /// also toggles the row's staged state.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The mouse event
+    /// * `area` - The area the tree was last rendered in
+    ///
+    /// # Returns
+    ///
+    /// One [`DiffFileTreeEvent::StageToggled`] per file whose staged state
+    /// changed.
+    pub fn handle_mouse(&mut self, event: MouseEvent, area: Rect) -> Vec<DiffFileTreeEvent> {
+        if event.kind != MouseEventKind::Down(MouseButton::Left) {
+            return Vec::new();
+        }
+        let has_filter = self.state.filter.as_ref().is_some_and(|f| !f.is_empty());
+        let height = if (self.state.filter_mode || has_filter) && area.height > 1 {
+            area.height - 1
+        } else {
+            area.height
+        };
+        if event.column < area.x
+            || event.column >= area.x.saturating_add(area.width)
+            || event.row < area.y
+            || event.row >= area.y.saturating_add(height)
+        {
+            return Vec::new();
+        }
+
+        // Scroll the way rendering does before mapping the row
+        let paths = get_visible_paths(&self.nodes, &self.state);
+        let mut state = self.state.clone();
+        if let Some(selected) = &self.state.selected_path {
+            if let Some(index) = paths.iter().position(|path| path == selected) {
+                state.scroll_index_into_view(index, height as usize);
+            }
+        }
+        let Some(path) = paths.get(state.offset + (event.row - area.y) as usize) else {
+            return Vec::new();
+        };
+        let path = path.clone();
+        self.state.select(path.clone());
+
+        let indent = 2 * (path.len() as u16 - 1);
+        let box_start = area.x + indent + BOX_OFFSET;
+        if self.show_staged && (box_start..box_start + BOX_WIDTH).contains(&event.column) {
+            self.toggle_staged_at(&path)
+        } else {
+            Vec::new()
+        }
+    }
+}
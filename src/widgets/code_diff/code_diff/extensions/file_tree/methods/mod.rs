@@ -4,5 +4,8 @@ mod add_file;
 mod filter;
 mod focus;
 mod handle_filter_key;
+mod handle_key;
+mod handle_mouse;
 mod navigation;
 mod selected_path;
+mod staging;
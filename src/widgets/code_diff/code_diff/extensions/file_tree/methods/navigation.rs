@@ -2,10 +2,8 @@
 //!
 //! These methods delegate to TreeNavigator for centralized keyboard handling.
 
-use super::super::super::diff_file_tree::{DiffFileEntry, DiffFileTree};
-use super::super::super::primitives::tree_view::{
-    get_visible_paths_filtered, matches_filter, TreeNavigator,
-};
+use crate::primitives::tree_view::{get_visible_paths_filtered, matches_filter, TreeNavigator};
+use crate::widgets::code_diff::code_diff::extensions::file_tree::{DiffFileEntry, DiffFileTree};
 
 impl DiffFileTree {
     /// Returns the filter matcher function for DiffFileEntry nodes.
@@ -137,7 +135,7 @@ impl DiffFileTree {
         path: &[usize],
     ) -> Option<
         &crate::primitives::tree_view::TreeNode<
-            crate::widgets::code_diff::diff_file_tree::DiffFileEntry,
+            crate::widgets::code_diff::code_diff::extensions::file_tree::DiffFileEntry,
         >,
     > {
         if path.is_empty() {
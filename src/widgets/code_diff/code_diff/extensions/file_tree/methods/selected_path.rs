@@ -1,6 +1,6 @@
 //! Method for getting the path of the selected item.
 
-use super::super::super::diff_file_tree::DiffFileTree;
+use crate::widgets::code_diff::code_diff::extensions::file_tree::DiffFileTree;
 
 impl DiffFileTree {
     /// Returns the full path of the currently selected item.
@@ -0,0 +1,250 @@
+//! Staging and unstaging files.
+
+use std::collections::HashMap;
+
+use crate::primitives::tree_view::TreeNode;
+use crate::widgets::code_diff::code_diff::extensions::file_tree::{
+    DiffFileEntry, DiffFileTree, DiffFileTreeEvent, StageState,
+};
+
+/// Computes the staged state of `node` from the files at or below it.
+fn stage_state_of(node: &TreeNode<DiffFileEntry>) -> StageState {
+    fn count(node: &TreeNode<DiffFileEntry>, staged: &mut usize, total: &mut usize) {
+        if !node.data.is_dir {
+            *total += 1;
+            if node.data.staged {
+                *staged += 1;
+            }
+        }
+        for child in &node.children {
+            count(child, staged, total);
+        }
+    }
+
+    let (mut staged, mut total) = (0, 0);
+    count(node, &mut staged, &mut total);
+    if staged == 0 {
+        StageState::Unstaged
+    } else if staged == total {
+        StageState::Staged
+    } else {
+        StageState::Partial
+    }
+}
+
+/// Sets every file at or below `node` to `staged`, recording an event for
+/// each one that changed.
+fn set_staged_below(
+    node: &mut TreeNode<DiffFileEntry>,
+    staged: bool,
+    events: &mut Vec<DiffFileTreeEvent>,
+) {
+    if !node.data.is_dir && node.data.staged != staged {
+        node.data.staged = staged;
+        events.push(DiffFileTreeEvent::StageToggled {
+            path: node.data.full_path.clone(),
+            staged,
+        });
+    }
+    for child in &mut node.children {
+        set_staged_below(child, staged, events);
+    }
+}
+
+impl DiffFileTree {
+    /// Returns the staged state of the node at `path`, as child indices
+    /// from the root.
+    ///
+    /// # Returns
+    ///
+    /// The state, or `None` if there is no node at `path`.
+    #[must_use]
+    pub fn stage_state(&self, path: &[usize]) -> Option<StageState> {
+        self.get_node_at_path(path).map(stage_state_of)
+    }
+
+    /// Staged state of every node, keyed by its path.
+    pub(crate) fn stage_states(&self) -> HashMap<Vec<usize>, StageState> {
+        fn collect(
+            nodes: &[TreeNode<DiffFileEntry>],
+            prefix: &[usize],
+            states: &mut HashMap<Vec<usize>, StageState>,
+        ) {
+            for (index, node) in nodes.iter().enumerate() {
+                let mut path = prefix.to_vec();
+                path.push(index);
+                collect(&node.children, &path, states);
+                states.insert(path, stage_state_of(node));
+            }
+        }
+
+        let mut states = HashMap::new();
+        collect(&self.nodes, &[], &mut states);
+        states
+    }
+
+    /// Marks the file at `full_path` staged or not without raising an
+    /// event, e.g. to mirror `git status`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a file with that path is in the tree.
+    pub fn set_staged(&mut self, full_path: &str, staged: bool) -> bool {
+        fn find<'a>(
+            nodes: &'a mut [TreeNode<DiffFileEntry>],
+            full_path: &str,
+        ) -> Option<&'a mut TreeNode<DiffFileEntry>> {
+            for node in nodes {
+                if !node.data.is_dir && node.data.full_path == full_path {
+                    return Some(node);
+                }
+                if let Some(found) = find(&mut node.children, full_path) {
+                    return Some(found);
+                }
+            }
+            None
+        }
+
+        match find(&mut self.nodes, full_path) {
+            Some(node) => {
+                node.data.staged = staged;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Toggles the staged state of the selected file or directory.
+    ///
+    /// A directory that is fully staged unstages every file below it;
+    /// otherwise it stages them all.
+    ///
+    /// # Returns
+    ///
+    /// One [`DiffFileTreeEvent::StageToggled`] per file whose staged state
+    /// changed, in tree order.
+    pub fn toggle_staged_selected(&mut self) -> Vec<DiffFileTreeEvent> {
+        match self.state.selected_path.clone() {
+            Some(path) => self.toggle_staged_at(&path),
+            None => Vec::new(),
+        }
+    }
+
+    /// Toggles the staged state of the node at `path`; see
+    /// [`toggle_staged_selected`](Self::toggle_staged_selected).
+    pub(crate) fn toggle_staged_at(&mut self, path: &[usize]) -> Vec<DiffFileTreeEvent> {
+        let Some(state) = self.stage_state(path) else {
+            return Vec::new();
+        };
+        let mut nodes = &mut self.nodes;
+        let (last, parents) = match path.split_last() {
+            Some(split) => split,
+            None => return Vec::new(),
+        };
+        for &index in parents {
+            nodes = &mut nodes[index].children;
+        }
+        let mut events = Vec::new();
+        set_staged_below(&mut nodes[*last], state != StageState::Staged, &mut events);
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+    use ratatui::layout::Rect;
+
+    use crate::widgets::code_diff::code_diff::extensions::file_tree::{
+        DiffFileTree, DiffFileTreeEvent, FileStatus, StageState,
+    };
+
+    /// `src/` holding `util/` and `lib.rs`, then `README.md`.
+    fn tree() -> DiffFileTree {
+        DiffFileTree::from_paths(&[
+            ("src/lib.rs", FileStatus::Modified),
+            ("src/util/a.rs", FileStatus::Added),
+            ("src/util/b.rs", FileStatus::Deleted),
+            ("README.md", FileStatus::Modified),
+        ])
+        .with_staging(true)
+    }
+
+    fn toggled(path: &str, staged: bool) -> DiffFileTreeEvent {
+        DiffFileTreeEvent::StageToggled {
+            path: path.to_string(),
+            staged,
+        }
+    }
+
+    #[test]
+    fn directories_are_staged_when_all_files_below_are() {
+        let mut tree = tree();
+        assert_eq!(tree.stage_state(&[0]), Some(StageState::Unstaged));
+
+        assert!(tree.set_staged("src/util/a.rs", true));
+        assert!(!tree.set_staged("src/missing.rs", true));
+        assert_eq!(tree.stage_state(&[0]), Some(StageState::Partial));
+        assert_eq!(tree.stage_state(&[0, 0]), Some(StageState::Partial));
+        assert_eq!(tree.stage_state(&[0, 0, 0]), Some(StageState::Staged));
+        assert_eq!(tree.stage_state(&[1]), Some(StageState::Unstaged));
+
+        tree.set_staged("src/util/b.rs", true);
+        assert_eq!(tree.stage_state(&[0, 0]), Some(StageState::Staged));
+        assert_eq!(tree.stage_state(&[0]), Some(StageState::Partial));
+        tree.set_staged("src/lib.rs", true);
+        assert_eq!(tree.stage_state(&[0]), Some(StageState::Staged));
+        assert_eq!(tree.stage_state(&[5]), None);
+    }
+
+    #[test]
+    fn toggling_a_directory_stages_every_file_below_it() {
+        let mut tree = tree();
+        tree.set_staged("src/util/a.rs", true);
+
+        // A partly staged directory stages the rest, one event per file.
+        tree.state.select(vec![0]);
+        assert_eq!(
+            tree.handle_key(KeyCode::Char(' ')),
+            [toggled("src/util/b.rs", true), toggled("src/lib.rs", true)]
+        );
+        assert_eq!(tree.stage_state(&[0]), Some(StageState::Staged));
+
+        // A fully staged one unstages everything.
+        assert_eq!(
+            tree.handle_key(KeyCode::Char(' ')),
+            [
+                toggled("src/util/a.rs", false),
+                toggled("src/util/b.rs", false),
+                toggled("src/lib.rs", false)
+            ]
+        );
+
+        // Without staged boxes the key does nothing.
+        let mut plain = tree.clone().with_staging(false);
+        assert!(plain.handle_key(KeyCode::Char(' ')).is_empty());
+    }
+
+    #[test]
+    fn clicking_the_box_toggles_and_clicking_the_name_selects() {
+        let mut tree = tree();
+        let area = Rect::new(0, 0, 40, 10);
+        let click = |column, row| MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        };
+
+        // Rows: src/, util/ (collapsed), lib.rs, README.md. lib.rs is at
+        // depth 1, so its box spans columns 6..9.
+        assert_eq!(tree.handle_mouse(click(12, 2), area), []);
+        assert_eq!(tree.state.selected_path, Some(vec![0, 1]));
+        assert_eq!(
+            tree.handle_mouse(click(7, 2), area),
+            [toggled("src/lib.rs", true)]
+        );
+        assert_eq!(tree.stage_state(&[0]), Some(StageState::Partial));
+        assert_eq!(tree.handle_mouse(click(7, 9), area), []);
+    }
+}
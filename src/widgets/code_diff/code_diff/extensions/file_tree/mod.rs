@@ -12,6 +12,8 @@
 //! - **Collapsible directories**: Expand/collapse with keyboard
 //! - **Color coding**: Green (added), yellow (modified), red (deleted), blue (renamed)
 //! - **Keyboard navigation**: Uses TreeView's navigation
+//! - **Staging**: Optional `[x]` boxes, tri-state on directories, that
+//!   raise [`DiffFileTreeEvent::StageToggled`] for the app to run git with
 //!
 //! # Structure
 //!
@@ -22,7 +24,7 @@
 //! # Example
 //!
 //! ```rust
-//! use ratatui_toolkit::widgets::code_diff::diff_file_tree::{DiffFileTree, FileStatus};
+//! use ratkit::widgets::code_diff::{DiffFileTree, FileStatus};
 //!
 //! let files = vec![
 //!     ("src/lib.rs", FileStatus::Modified),
@@ -53,8 +55,8 @@ pub mod traits;
 
 pub use helpers::file_icon;
 
-use super::super::super::primitives::tree_view::{TreeNode, TreeViewState};
-use crate::widgets::code_diff::services::theme::AppTheme;
+use crate::primitives::tree_view::{TreeNode, TreeViewState};
+use crate::widgets::markdown_preview::services::theme::AppTheme;
 use ratatui::style::Color;
 
 /// The modification status of a file in a diff.
@@ -98,6 +100,46 @@ impl FileStatus {
     }
 }
 
+/// How much of a file or directory is staged.
+///
+/// A file is either staged or not; a directory is staged when all files
+/// below it are, partially staged when some are.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StageState {
+    /// Every file is staged.
+    Staged,
+    /// Some files are staged.
+    Partial,
+    /// No file is staged.
+    Unstaged,
+}
+
+impl StageState {
+    /// Returns the checkbox shown for this state.
+    #[must_use]
+    pub fn marker(&self) -> &'static str {
+        match self {
+            Self::Staged => "[x]",
+            Self::Partial => "[-]",
+            Self::Unstaged => "[ ]",
+        }
+    }
+}
+
+/// Something the app should act on, returned by the key and mouse
+/// handlers of [`DiffFileTree`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffFileTreeEvent {
+    /// A file was staged or unstaged, e.g. run `git add` or
+    /// `git restore --staged` on `path`.
+    StageToggled {
+        /// Full path of the file.
+        path: String,
+        /// Whether the file is now staged.
+        staged: bool,
+    },
+}
+
 /// A single file or directory entry in a diff tree.
 ///
 /// This is the data type stored in each [`TreeNode`](crate::primitives::tree_view::TreeNode).
@@ -111,6 +153,9 @@ pub struct DiffFileEntry {
     pub is_dir: bool,
     /// The modification status (None for directories).
     pub status: Option<FileStatus>,
+    /// Whether the file is staged; directories take their state from the
+    /// files below them.
+    pub staged: bool,
 }
 
 impl DiffFileEntry {
@@ -122,6 +167,7 @@ impl DiffFileEntry {
             full_path: full_path.to_string(),
             is_dir: false,
             status: Some(status),
+            staged: false,
         }
     }
 
@@ -133,6 +179,7 @@ impl DiffFileEntry {
             full_path: full_path.to_string(),
             is_dir: true,
             status: None,
+            staged: false,
         }
     }
 }
@@ -156,4 +203,6 @@ pub struct DiffFileTree {
     pub focused: bool,
     /// Application theme for styling.
     pub theme: AppTheme,
+    /// Whether staged boxes are shown and can be toggled.
+    pub show_staged: bool,
 }
@@ -3,10 +3,12 @@
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 
-use super::super::super::diff_file_tree::helpers::file_icon;
-use super::super::super::diff_file_tree::{DiffFileEntry, FileStatus};
-use super::super::super::primitives::tree_view::NodeState;
-use crate::widgets::code_diff::services::theme::AppTheme;
+use crate::primitives::tree_view::NodeState;
+use crate::widgets::code_diff::code_diff::extensions::file_tree::helpers::file_icon;
+use crate::widgets::code_diff::code_diff::extensions::file_tree::{
+    DiffFileEntry, FileStatus, StageState,
+};
+use crate::widgets::markdown_preview::services::theme::AppTheme;
 
 /// Gets the color for a file status from the theme.
 fn status_color(status: FileStatus, theme: &AppTheme) -> Color {
@@ -25,6 +27,7 @@ fn status_color(status: FileStatus, theme: &AppTheme) -> Color {
 /// * `entry` - The diff file entry to render
 /// * `node_state` - The current state of the node (selected, expanded, etc.)
 /// * `focused` - Whether the tree widget has focus
+/// * `stage` - The entry's staged state, or `None` when staged boxes are hidden
 /// * `theme` - Application theme for styling
 ///
 /// # Returns
@@ -34,6 +37,7 @@ pub fn render_entry<'a>(
     entry: &DiffFileEntry,
     node_state: &NodeState,
     focused: bool,
+    stage: Option<StageState>,
     theme: &AppTheme,
 ) -> Line<'a> {
     let mut spans = Vec::new();
@@ -50,7 +54,24 @@ pub fn render_entry<'a>(
             format!("{} ", marker),
             Style::default().fg(marker_color),
         ));
+    } else if stage.is_some() {
+        // Keep directory boxes in the same column as file boxes
+        spans.push(Span::raw("  "));
+    }
 
+    if let Some(stage) = stage {
+        let color = match stage {
+            StageState::Staged => theme.success,
+            StageState::Partial => theme.warning,
+            StageState::Unstaged => theme.text_muted,
+        };
+        spans.push(Span::styled(
+            format!("{} ", stage.marker()),
+            Style::default().fg(color),
+        ));
+    }
+
+    if !entry.is_dir {
         // File type icon based on extension
         let icon = file_icon(&entry.name);
         spans.push(Span::styled(
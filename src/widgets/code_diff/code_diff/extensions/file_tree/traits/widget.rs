@@ -2,15 +2,17 @@
 //!
 //! Uses TreeViewRef to avoid cloning nodes on every render.
 
+use std::collections::HashMap;
+
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Widget;
 
-use super::super::super::diff_file_tree::{DiffFileEntry, DiffFileTree};
-use super::super::super::primitives::tree_view::{matches_filter, TreeViewRef};
-use crate::widgets::code_diff::services::theme::AppTheme;
+use crate::primitives::tree_view::{matches_filter, TreeViewRef};
+use crate::widgets::code_diff::code_diff::extensions::file_tree::{DiffFileEntry, DiffFileTree};
+use crate::widgets::markdown_preview::services::theme::AppTheme;
 
 use super::render_entry::render_entry;
 
@@ -93,12 +95,18 @@ impl Widget for DiffFileTree {
         let highlight_bg = theme.background_element;
         let icon_style = Style::default().fg(theme.info);
         let theme_for_render = theme.clone();
+        let stage_states = if self.show_staged {
+            self.stage_states()
+        } else {
+            HashMap::new()
+        };
 
         let tree_view = TreeViewRef::new(&self.nodes)
             .icons("\u{F07B}", "\u{F07C}") // Nerd font folder icons (closed, open)
             .icon_style(icon_style)
             .render_fn(move |entry, node_state| {
-                render_entry(entry, node_state, focused, &theme_for_render)
+                let stage = stage_states.get(&node_state.path).copied();
+                render_entry(entry, node_state, focused, stage, &theme_for_render)
             })
             .filter_fn(entry_matches_filter)
             .highlight_style(Style::default().bg(highlight_bg));
@@ -142,12 +150,18 @@ impl Widget for &DiffFileTree {
         let highlight_bg = theme.background_element;
         let icon_style = Style::default().fg(theme.info);
         let theme_for_render = theme.clone();
+        let stage_states = if self.show_staged {
+            self.stage_states()
+        } else {
+            HashMap::new()
+        };
 
         let tree_view = TreeViewRef::new(&self.nodes)
             .icons("\u{F07B}", "\u{F07C}") // Nerd font folder icons (closed, open)
             .icon_style(icon_style)
             .render_fn(move |entry, node_state| {
-                render_entry(entry, node_state, focused, &theme_for_render)
+                let stage = stage_states.get(&node_state.path).copied();
+                render_entry(entry, node_state, focused, stage, &theme_for_render)
             })
             .filter_fn(entry_matches_filter)
             .highlight_style(Style::default().bg(highlight_bg));
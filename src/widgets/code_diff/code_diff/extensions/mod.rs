@@ -1 +1,3 @@
 //! Optional extensions for the code diff widget.
+
+pub mod file_tree;
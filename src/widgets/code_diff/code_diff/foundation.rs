@@ -4,6 +4,7 @@ use ratatui::style::Color;
 
 pub mod diff_config {
     use super::*;
+    use crossterm::event::KeyCode;
 
     #[derive(Debug, Clone, Default)]
     pub struct DiffConfig {
@@ -19,6 +20,26 @@ pub mod diff_config {
         pub line_number_fg: Color,
         pub gutter_width: usize,
         pub context_lines: usize,
+        /// Background of lines a patch preview adds to the file.
+        pub applied_bg: Color,
+        /// Color of the badge marking hunks that do not apply.
+        pub error_fg: Color,
+        /// Whether the words that changed between a removed line and the
+        /// added line replacing it are highlighted.
+        pub intraline: bool,
+        /// Background of the changed words on an added line.
+        pub added_word_bg: Color,
+        /// Background of the changed words on a removed line.
+        pub removed_word_bg: Color,
+        /// Whether the diff view is unified or side by side.
+        pub style: enums::DiffStyle,
+        /// Key sequences that ask to open the file at the cursor line.
+        pub open_keys: Vec<Vec<KeyCode>>,
+        /// Unchanged runs are folded behind a marker row when more than
+        /// this many of their lines would be hidden. `None` never folds.
+        pub fold_threshold: Option<usize>,
+        /// Lines a folded run reveals per expand key press or click.
+        pub fold_expand_step: usize,
     }
 
     impl DiffConfig {
@@ -36,6 +57,18 @@ pub mod diff_config {
                 line_number_fg: Color::DarkGray,
                 gutter_width: 4,
                 context_lines: 3,
+                applied_bg: Color::Rgb(28, 48, 34),
+                error_fg: Color::LightRed,
+                intraline: true,
+                added_word_bg: Color::Rgb(80, 250, 123),
+                removed_word_bg: Color::Rgb(255, 85, 85),
+                style: enums::DiffStyle::Unified,
+                open_keys: vec![
+                    vec![KeyCode::Enter],
+                    vec![KeyCode::Char('g'), KeyCode::Char('d')],
+                ],
+                fold_threshold: Some(10),
+                fold_expand_step: 20,
             }
         }
 
@@ -43,6 +76,44 @@ pub mod diff_config {
             self.show_line_numbers = show;
             self
         }
+
+        /// Highlights the changed words within paired removed and added
+        /// lines. On by default.
+        pub fn intraline(mut self, enabled: bool) -> Self {
+            self.intraline = enabled;
+            self
+        }
+
+        /// Lays the diff view out unified or side by side. Unified by
+        /// default.
+        pub fn diff_style(mut self, style: enums::DiffStyle) -> Self {
+            self.style = style;
+            self
+        }
+
+        /// Sets the key sequences that ask to open the file at the cursor
+        /// line. `Enter` and `g` `d` by default; an empty list turns
+        /// opening off.
+        pub fn open_keys(mut self, keys: Vec<Vec<KeyCode>>) -> Self {
+            self.open_keys = keys;
+            self
+        }
+
+        /// Folds unchanged runs down to
+        /// [`context_lines`](Self::context_lines) lines next to each change
+        /// when more than `threshold` lines would be hidden. 10 by default;
+        /// `None` shows every line.
+        pub fn fold_threshold(mut self, threshold: Option<usize>) -> Self {
+            self.fold_threshold = threshold;
+            self
+        }
+
+        /// Sets how many hidden lines one press of the expand key or a
+        /// click on a fold marker reveals. 20 by default.
+        pub fn fold_expand_step(mut self, step: usize) -> Self {
+            self.fold_expand_step = step;
+            self
+        }
     }
 }
 
@@ -102,12 +173,20 @@ pub mod diff_line {
             }
         }
 
+        /// Parses one line of a unified diff hunk body, without its `+`,
+        /// `-` or ` ` prefix.
+        ///
+        /// Line numbers are left at 0 for the caller to fill in. The
+        /// `\ No newline at end of file` marker is not a line and yields
+        /// `None`.
         pub fn from_diff_line(line: &str) -> Option<Self> {
+            let body = line.get(1..).unwrap_or("");
             match line.chars().next() {
-                Some('+') if !line.starts_with("+++") => Some(Self::added(line, 0)),
-                Some('-') if !line.starts_with("---") => Some(Self::removed(line, 0)),
+                Some('+') if !line.starts_with("+++") => Some(Self::added(body, 0)),
+                Some('-') if !line.starts_with("---") => Some(Self::removed(body, 0)),
                 Some('@') => Some(Self::hunk_header(line)),
-                Some(' ') | Some('\t') | Some('\\') => Some(Self::context(line, 0, 0)),
+                Some(' ') => Some(Self::context(body, 0, 0)),
+                Some('\t') => Some(Self::context(line, 0, 0)),
                 _ => None,
             }
         }
@@ -214,13 +293,47 @@ pub mod diff_hunk {
 pub mod enums {
     use super::*;
 
+    /// What the main pane of a [`CodeDiff`](crate::widgets::code_diff::CodeDiff) shows.
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
-    pub enum DiffStyle {
+    pub enum DiffViewMode {
+        /// The hunks themselves.
         #[default]
+        Diff,
+        /// The base content with the selected hunks applied.
+        Preview,
+    }
+
+    /// How the diff view lays out old and new lines.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub enum DiffStyle {
+        /// Old lines on the left and new lines on the right, paired row
+        /// by row.
         SideBySide,
+        /// One column of lines, removed lines before the added lines that
+        /// replace them.
+        #[default]
         Unified,
     }
 
+    /// Which way to move between hunks or files.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Direction {
+        /// Towards the end of the diff.
+        Next,
+        /// Towards the start of the diff.
+        Previous,
+    }
+
+    /// Something a [`CodeDiff`](crate::widgets::code_diff::CodeDiff) asks
+    /// its host to do, returned by
+    /// [`handle_key`](crate::widgets::code_diff::CodeDiff::handle_key).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum CodeDiffEvent {
+        /// Show the next or previous file; the host owns the list of
+        /// files, such as a file tree next to the diff.
+        FileNavigationRequested(Direction),
+    }
+
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
     pub enum DiffLineKind {
         Context,
@@ -231,6 +344,577 @@ pub mod enums {
     }
 }
 
+/// Errors from applying hunks to base content.
+pub mod apply_error {
+    use thiserror::Error;
+
+    /// Why a hunk could not be applied to the base content.
+    #[derive(Debug, Clone, PartialEq, Eq, Error)]
+    pub enum ApplyError {
+        /// No base content was set, so there is nothing to apply hunks to.
+        #[error("no base content to apply hunks to")]
+        MissingBase,
+
+        /// A context or removed line does not match the base content.
+        #[error(
+            "hunk {hunk} does not apply: expected {expected:?} at line {line}, found {found:?}"
+        )]
+        ContextMismatch {
+            /// Index of the hunk in [`CodeDiff::hunks`](crate::widgets::code_diff::CodeDiff::hunks).
+            hunk: usize,
+            /// 1-based line of the base content that was checked.
+            line: usize,
+            /// The line the hunk expected.
+            expected: String,
+            /// The line actually there, or `None` past the end of the content.
+            found: Option<String>,
+        },
+
+        /// The hunk touches lines an earlier selected hunk already changes.
+        #[error("hunk {hunk} overlaps hunk {other}")]
+        Overlap {
+            /// Index of the rejected hunk.
+            hunk: usize,
+            /// Index of the earlier hunk it overlaps.
+            other: usize,
+        },
+    }
+
+    impl ApplyError {
+        /// Index of the hunk that failed, if the error is about one hunk.
+        pub fn hunk(&self) -> Option<usize> {
+            match self {
+                Self::MissingBase => None,
+                Self::ContextMismatch { hunk, .. } | Self::Overlap { hunk, .. } => Some(*hunk),
+            }
+        }
+    }
+}
+
+/// Word-level differences between a removed line and the added line that
+/// replaces it.
+pub mod intraline {
+    use std::collections::HashMap;
+    use std::ops::Range;
+
+    use super::diff_line::DiffLine;
+
+    /// Lines with more tokens than this are not compared word by word.
+    const MAX_TOKENS: usize = 400;
+
+    /// Changed byte ranges of the old line and of the new line.
+    pub type ChangedRanges = (Vec<Range<usize>>, Vec<Range<usize>>);
+
+    /// Splits `line` into words, runs of whitespace and single punctuation
+    /// characters, as byte ranges.
+    ///
+    /// A word is a run of alphanumerics and underscores, so an identifier
+    /// that was renamed is one changed token.
+    pub fn tokenize(line: &str) -> Vec<Range<usize>> {
+        #[derive(PartialEq)]
+        enum Class {
+            Word,
+            Space,
+            Punct,
+        }
+        let class = |c: char| {
+            if c.is_alphanumeric() || c == '_' {
+                Class::Word
+            } else if c.is_whitespace() {
+                Class::Space
+            } else {
+                Class::Punct
+            }
+        };
+
+        let mut tokens: Vec<Range<usize>> = Vec::new();
+        let mut previous = None;
+        for (start, c) in line.char_indices() {
+            let current = class(c);
+            let end = start + c.len_utf8();
+            match tokens.last_mut() {
+                Some(token) if previous.as_ref() == Some(&current) && current != Class::Punct => {
+                    token.end = end;
+                }
+                _ => tokens.push(start..end),
+            }
+            previous = Some(current);
+        }
+        tokens
+    }
+
+    /// The byte ranges of `old` and of `new` that differ between them.
+    ///
+    /// Tokens are matched by longest common subsequence. Changed ranges
+    /// separated only by whitespace are merged. Returns `None` when the
+    /// lines share no words, where highlighting would mark everything.
+    pub fn changed_ranges(old: &str, new: &str) -> Option<ChangedRanges> {
+        let old_tokens = tokenize(old);
+        let new_tokens = tokenize(new);
+        if old_tokens.len() > MAX_TOKENS || new_tokens.len() > MAX_TOKENS {
+            return None;
+        }
+        let old_words: Vec<&str> = old_tokens.iter().map(|t| &old[t.clone()]).collect();
+        let new_words: Vec<&str> = new_tokens.iter().map(|t| &new[t.clone()]).collect();
+
+        // lcs[i][j]: length of the common subsequence of old[i..], new[j..].
+        let (n, m) = (old_words.len(), new_words.len());
+        let mut lcs = vec![vec![0u16; m + 1]; n + 1];
+        for i in (0..n).rev() {
+            for j in (0..m).rev() {
+                lcs[i][j] = if old_words[i] == new_words[j] {
+                    lcs[i + 1][j + 1] + 1
+                } else {
+                    lcs[i + 1][j].max(lcs[i][j + 1])
+                };
+            }
+        }
+
+        let mut old_kept = vec![false; n];
+        let mut new_kept = vec![false; m];
+        let mut shares_words = false;
+        let (mut i, mut j) = (0, 0);
+        while i < n && j < m {
+            if old_words[i] == new_words[j] {
+                old_kept[i] = true;
+                new_kept[j] = true;
+                shares_words |= !old_words[i].trim().is_empty();
+                i += 1;
+                j += 1;
+            } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        if !shares_words {
+            return None;
+        }
+
+        Some((
+            merge_changes(old, &old_tokens, &old_kept),
+            merge_changes(new, &new_tokens, &new_kept),
+        ))
+    }
+
+    /// Joins the tokens not kept into ranges, bridging whitespace between
+    /// two changes.
+    fn merge_changes(line: &str, tokens: &[Range<usize>], kept: &[bool]) -> Vec<Range<usize>> {
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+        for (index, token) in tokens.iter().enumerate() {
+            if kept[index] {
+                continue;
+            }
+            match ranges.last_mut() {
+                Some(last) if line[last.end..token.start].trim().is_empty() => {
+                    last.end = token.end;
+                }
+                _ => ranges.push(token.clone()),
+            }
+        }
+        ranges
+    }
+
+    /// The changed ranges of each paired line in a hunk, by line index.
+    ///
+    /// Within a run of removed lines followed by a run of added lines, the
+    /// first removed line is paired with the first added line, and so on.
+    /// Lines left over in the longer run are not paired.
+    pub fn hunk_ranges(lines: &[DiffLine]) -> HashMap<usize, Vec<Range<usize>>> {
+        let mut ranges = HashMap::new();
+        let mut index = 0;
+        while index < lines.len() {
+            let removed_start = index;
+            while index < lines.len() && lines[index].is_removed() {
+                index += 1;
+            }
+            let added_start = index;
+            while index < lines.len() && lines[index].is_added() {
+                index += 1;
+            }
+            if index == removed_start {
+                index += 1;
+                continue;
+            }
+
+            for (old, new) in (removed_start..added_start).zip(added_start..index) {
+                if let Some((old_ranges, new_ranges)) =
+                    changed_ranges(&lines[old].content, &lines[new].content)
+                {
+                    ranges.insert(old, old_ranges);
+                    ranges.insert(new, new_ranges);
+                }
+            }
+        }
+        ranges
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_changed_ranges_between_similar_lines() {
+            let old = "let total = compute_sum(items, 0);";
+            let new = "let total = compute_total(items, 10);";
+            let (old_ranges, new_ranges) = changed_ranges(old, new).unwrap();
+
+            let changed = |line: &str, ranges: &[Range<usize>]| -> Vec<String> {
+                ranges.iter().map(|r| line[r.clone()].to_string()).collect()
+            };
+            assert_eq!(changed(old, &old_ranges), ["compute_sum", "0"]);
+            assert_eq!(changed(new, &new_ranges), ["compute_total", "10"]);
+            assert_eq!(old_ranges, [12..23, 31..32]);
+            assert_eq!(new_ranges, [12..25, 33..35]);
+
+            assert_eq!(
+                changed_ranges("fn a(x: u8)", "fn a(x: u8)"),
+                Some((vec![], vec![]))
+            );
+            assert_eq!(changed_ranges("foo bar", "baz qux"), None);
+        }
+
+        #[test]
+        fn test_merges_changes_across_whitespace_and_pairs_lines() {
+            let new = "call(one two)";
+            let (_, ranges) = changed_ranges("call(a)", new).unwrap();
+            let changed: Vec<&str> = ranges.iter().map(|r| &new[r.clone()]).collect();
+            assert_eq!(changed, ["one two"]);
+
+            let lines = vec![
+                DiffLine::context("fn main() {", 1, 1),
+                DiffLine::removed("    let x = 1;", 2),
+                DiffLine::removed("    let y = 2;", 3),
+                DiffLine::added("    let x = 5;", 2),
+                DiffLine::context("}", 4, 3),
+            ];
+            let ranges = hunk_ranges(&lines);
+            assert_eq!(ranges.len(), 2);
+            for (line, changed) in [(1, "1"), (3, "5")] {
+                let found: Vec<&str> = ranges[&line]
+                    .iter()
+                    .map(|r| &lines[line].content[r.clone()])
+                    .collect();
+                assert_eq!(found, [changed]);
+            }
+        }
+    }
+}
+
+/// Errors from parsing unified diff text.
+pub mod diff_error {
+    use thiserror::Error;
+
+    /// Result of parsing unified diff text.
+    pub type DiffResult<T> = Result<T, DiffError>;
+
+    /// Why unified diff text could not be parsed.
+    #[derive(Debug, Clone, PartialEq, Eq, Error)]
+    pub enum DiffError {
+        /// The text is not a well-formed unified diff.
+        #[error("invalid diff at line {line}: {reason}")]
+        Parse {
+            /// 1-based line of the diff text where parsing failed.
+            line: usize,
+            /// What was wrong with it.
+            reason: String,
+        },
+    }
+}
+
+/// Parsing unified diff text, as printed by `git diff` or `diff -u`.
+pub mod unified {
+    use super::diff_error::{DiffError, DiffResult};
+    use super::diff_hunk::DiffHunk;
+    use super::diff_line::DiffLine;
+
+    /// The hunks for one file of a unified diff.
+    #[derive(Debug, Clone, Default)]
+    pub(crate) struct FileDiff {
+        /// Path from the file headers, without git's `a/` or `b/` prefix.
+        pub path: Option<String>,
+        pub hunks: Vec<DiffHunk>,
+    }
+
+    /// Parses unified diff text into hunks, with line numbers filled in.
+    ///
+    /// File headers (`diff --git`, `---`, `+++` and git's extended
+    /// headers) are skipped, so the hunks of every file in the diff are
+    /// returned in order. Text before the first file is ignored, and so is
+    /// the `\ No newline at end of file` marker.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DiffError::Parse`] for a malformed `@@` header, a hunk
+    /// with fewer or more lines than its header counts, or a line in a
+    /// hunk that is not a context, added or removed line.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratkit::widgets::code_diff::parse_unified_diff;
+    ///
+    /// let hunks = parse_unified_diff("@@ -3,2 +3,2 @@\n context\n-old\n+new\n").unwrap();
+    /// assert_eq!(hunks[0].lines[2].new_line_num, Some(4));
+    /// ```
+    pub fn parse_unified_diff(diff: &str) -> DiffResult<Vec<DiffHunk>> {
+        Ok(parse_files(diff)?
+            .into_iter()
+            .flat_map(|file| file.hunks)
+            .collect())
+    }
+
+    /// Parses unified diff text into the hunks of each file.
+    pub(crate) fn parse_files(diff: &str) -> DiffResult<Vec<FileDiff>> {
+        let mut files: Vec<FileDiff> = Vec::new();
+        let mut old_path = None;
+        // Lines the open hunk still needs on the old and new side.
+        let mut remaining = (0, 0);
+        let mut old_line = 0;
+        let mut new_line = 0;
+        let mut last_line = 0;
+
+        for (index, line) in diff.lines().enumerate() {
+            last_line = index + 1;
+            let error = |reason: &str| DiffError::Parse {
+                line: index + 1,
+                reason: reason.to_string(),
+            };
+
+            if remaining != (0, 0) {
+                let hunk = files
+                    .last_mut()
+                    .and_then(|file| file.hunks.last_mut())
+                    .expect("an open hunk");
+                let body = line.get(1..).unwrap_or("");
+                match line.chars().next() {
+                    // Some tools strip the space from empty context lines.
+                    Some(' ') | None => {
+                        if remaining.0 == 0 || remaining.1 == 0 {
+                            return Err(error("more context lines than the hunk header counts"));
+                        }
+                        hunk.add_line(DiffLine::context(body, old_line, new_line));
+                        old_line += 1;
+                        new_line += 1;
+                        remaining = (remaining.0 - 1, remaining.1 - 1);
+                    }
+                    Some('-') => {
+                        if remaining.0 == 0 {
+                            return Err(error("more removed lines than the hunk header counts"));
+                        }
+                        hunk.add_line(DiffLine::removed(body, old_line));
+                        old_line += 1;
+                        remaining.0 -= 1;
+                    }
+                    Some('+') => {
+                        if remaining.1 == 0 {
+                            return Err(error("more added lines than the hunk header counts"));
+                        }
+                        hunk.add_line(DiffLine::added(body, new_line));
+                        new_line += 1;
+                        remaining.1 -= 1;
+                    }
+                    Some('\\') => {}
+                    Some(_) => {
+                        return Err(error(&format!(
+                            "hunk ends early, {} old and {} new lines short",
+                            remaining.0, remaining.1
+                        )))
+                    }
+                }
+                continue;
+            }
+
+            let in_hunks = files.last().is_some_and(|file| !file.hunks.is_empty());
+            if line.starts_with("@@") {
+                let hunk = parse_hunk_header(line).ok_or_else(|| error("malformed hunk header"))?;
+                old_line = hunk.old_start;
+                new_line = hunk.new_start;
+                remaining = (hunk.old_count, hunk.new_count);
+                if files.is_empty() {
+                    files.push(FileDiff::default());
+                }
+                files.last_mut().expect("a file").hunks.push(hunk);
+            } else if let Some(rest) = line.strip_prefix("diff ") {
+                files.push(FileDiff {
+                    path: rest.rsplit_once(" b/").map(|(_, path)| path.to_string()),
+                    hunks: Vec::new(),
+                });
+                old_path = None;
+            } else if let Some(rest) = line.strip_prefix("--- ") {
+                // Without `diff` lines, a new `---` after hunks starts the
+                // next file.
+                if in_hunks {
+                    files.push(FileDiff::default());
+                }
+                old_path = header_path(rest);
+            } else if let Some(rest) = line.strip_prefix("+++ ") {
+                if files.is_empty() {
+                    files.push(FileDiff::default());
+                }
+                let file = files.last_mut().expect("a file");
+                file.path = header_path(rest).or_else(|| old_path.take());
+            } else if in_hunks && matches!(line.chars().next(), Some(' ' | '+' | '-')) {
+                return Err(error("line outside of any hunk"));
+            }
+        }
+
+        if remaining != (0, 0) {
+            return Err(DiffError::Parse {
+                line: last_line,
+                reason: format!(
+                    "diff ends inside a hunk, {} old and {} new lines short",
+                    remaining.0, remaining.1
+                ),
+            });
+        }
+        Ok(files)
+    }
+
+    /// Parses `@@ -start[,count] +start[,count] @@ [section]`, where a
+    /// missing count is 1.
+    fn parse_hunk_header(line: &str) -> Option<DiffHunk> {
+        let (ranges, _) = line.strip_prefix("@@ -")?.split_once(" @@")?;
+        let (old, new) = ranges.split_once(" +")?;
+        let range = |range: &str| -> Option<(usize, usize)> {
+            match range.split_once(',') {
+                Some((start, count)) => Some((start.parse().ok()?, count.parse().ok()?)),
+                None => Some((range.parse().ok()?, 1)),
+            }
+        };
+        let (old_start, old_count) = range(old)?;
+        let (new_start, new_count) = range(new)?;
+        let mut hunk = DiffHunk::new(old_start, old_count, new_start, new_count);
+        hunk.header = line.to_string();
+        Some(hunk)
+    }
+
+    /// The path in a `---` or `+++` header, or `None` for `/dev/null`.
+    fn header_path(rest: &str) -> Option<String> {
+        // `diff -u` follows the path with a tab and a timestamp.
+        let path = rest.split('\t').next().unwrap_or(rest).trim_end();
+        if path == "/dev/null" {
+            return None;
+        }
+        let path = path
+            .strip_prefix("a/")
+            .or_else(|| path.strip_prefix("b/"))
+            .unwrap_or(path);
+        Some(path.to_string())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn numbers(hunk: &DiffHunk) -> Vec<(Option<usize>, Option<usize>)> {
+            hunk.lines
+                .iter()
+                .map(|line| (line.old_line_num, line.new_line_num))
+                .collect()
+        }
+
+        #[test]
+        fn test_parses_multi_hunk_git_diff() {
+            let diff = "\
+diff --git a/src/lib.rs b/src/lib.rs
+index 3b18e51..a9c2f04 100644
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -1,3 +1,3 @@
+ use std::io;
+-fn old() {}
++fn new() {}
+ 
+@@ -10,2 +10,3 @@ impl Thing {
+     a();
++    b();
+     c();
+diff --git a/README.md b/README.md
+--- a/README.md
++++ b/README.md
+@@ -5 +5 @@
+-Old title
++New title
+";
+            let files = parse_files(diff).unwrap();
+            assert_eq!(files.len(), 2);
+            assert_eq!(files[0].path.as_deref(), Some("src/lib.rs"));
+            assert_eq!(files[1].path.as_deref(), Some("README.md"));
+
+            let hunks = parse_unified_diff(diff).unwrap();
+            assert_eq!(hunks.len(), 3);
+            assert_eq!(
+                numbers(&hunks[0]),
+                [
+                    (Some(1), Some(1)),
+                    (Some(2), None),
+                    (None, Some(2)),
+                    (Some(3), Some(3)),
+                ]
+            );
+            assert_eq!(hunks[0].lines[3].content, "");
+            assert_eq!(hunks[1].header, "@@ -10,2 +10,3 @@ impl Thing {");
+            assert_eq!(
+                numbers(&hunks[1]),
+                [(Some(10), Some(10)), (None, Some(11)), (Some(11), Some(12))]
+            );
+            assert_eq!((hunks[2].old_count, hunks[2].new_count), (1, 1));
+            assert_eq!(numbers(&hunks[2]), [(Some(5), None), (None, Some(5))]);
+        }
+
+        #[test]
+        fn test_no_newline_markers_and_new_files() {
+            let diff = "\
+--- a/notes.txt\t2024-01-01 10:00:00
++++ b/notes.txt\t2024-01-02 10:00:00
+@@ -1,2 +1,2 @@
+ first
+-last
+\\ No newline at end of file
++last!
+\\ No newline at end of file
+--- /dev/null
++++ b/added.txt
+@@ -0,0 +1,2 @@
++one
++two
+";
+            let files = parse_files(diff).unwrap();
+            assert_eq!(files.len(), 2);
+            assert_eq!(files[0].path.as_deref(), Some("notes.txt"));
+            assert_eq!(files[0].hunks[0].lines.len(), 3);
+            assert_eq!(files[0].hunks[0].lines[2].content, "last!");
+            assert_eq!(files[1].path.as_deref(), Some("added.txt"));
+            assert_eq!(
+                numbers(&files[1].hunks[0]),
+                [(None, Some(1)), (None, Some(2))]
+            );
+        }
+
+        #[test]
+        fn test_malformed_diffs_are_errors() {
+            let parse_error = |diff: &str| match parse_unified_diff(diff) {
+                Err(DiffError::Parse { line, .. }) => line,
+                Ok(hunks) => panic!("parsed {hunks:?}"),
+            };
+            assert_eq!(parse_error("@@ -1,x +1 @@\n-a\n"), 1);
+            assert_eq!(parse_error("@@ -1,2 +1,2 @@\n a\n-b\n"), 3);
+            assert_eq!(parse_error("@@ -1 +1 @@\n-a\n-b\n+c\n"), 3);
+            assert_eq!(parse_error("@@ -1,2 +1,2 @@\n a\n?b\n b\n"), 3);
+            assert_eq!(parse_error("@@ -1 +1 @@\n-a\n+b\n c\n"), 4);
+
+            assert!(parse_unified_diff("").unwrap().is_empty());
+            assert_eq!(
+                parse_unified_diff("@@ -1 +1,2 @@\n x\n y\n")
+                    .unwrap_err()
+                    .to_string(),
+                "invalid diff at line 3: more context lines than the hunk header counts"
+            );
+        }
+    }
+}
+
 pub mod helpers {
     use super::*;
 
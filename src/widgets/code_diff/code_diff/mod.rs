@@ -3,12 +3,21 @@
 //! This module provides a VS Code-style diff viewer widget for ratatui,
 //! supporting side-by-side display of code changes with syntax highlighting.
 
+#[cfg(feature = "diff-file-tree")]
+pub mod extensions;
 pub mod foundation;
 pub mod widget;
 
+#[cfg(feature = "diff-file-tree")]
+pub use extensions::file_tree::{
+    DiffFileEntry, DiffFileTree, DiffFileTreeEvent, FileStatus, StageState,
+};
+pub use foundation::apply_error::ApplyError;
 pub use foundation::diff_config::DiffConfig;
+pub use foundation::diff_error::{DiffError, DiffResult};
 pub use foundation::diff_hunk::DiffHunk;
 pub use foundation::diff_line::DiffLine;
-pub use foundation::enums::{DiffLineKind, DiffStyle};
+pub use foundation::enums::{CodeDiffEvent, DiffLineKind, DiffStyle, DiffViewMode, Direction};
 pub use foundation::helpers::get_git_diff;
+pub use foundation::unified::parse_unified_diff;
 pub use widget::CodeDiff;
@@ -0,0 +1,112 @@
+//! Copying a reference to the cursor line, its hunk or its text.
+
+use crate::services::clipboard::{ClipboardSource, CopyPayload, CopyProvider};
+use crate::widgets::code_diff::code_diff::foundation::diff_line::DiffLineKind;
+use crate::widgets::code_diff::code_diff::foundation::enums::DiffViewMode;
+use crate::widgets::code_diff::code_diff::widget::CodeDiff;
+
+impl CodeDiff {
+    /// The hunk under the cursor as a patch `git apply` accepts, with file
+    /// headers when [`file_path`](Self::file_path) is set.
+    pub fn hunk_patch_at_cursor(&self) -> Option<String> {
+        if self.view_mode != DiffViewMode::Diff {
+            return None;
+        }
+        let &(index, _) = self.view_rows().get(self.cursor)?;
+        let hunk = &self.hunks[index];
+
+        let mut patch = String::new();
+        if let Some(path) = &self.file_path {
+            patch.push_str(&format!("--- a/{path}\n+++ b/{path}\n"));
+        }
+        patch.push_str(&hunk.header);
+        patch.push('\n');
+        for line in &hunk.lines {
+            let prefix = match line.kind {
+                DiffLineKind::Context => ' ',
+                DiffLineKind::Added => '+',
+                DiffLineKind::Removed => '-',
+                DiffLineKind::HunkHeader => continue,
+            };
+            patch.push(prefix);
+            patch.push_str(&line.content);
+            patch.push('\n');
+        }
+        Some(patch)
+    }
+
+    /// Text of the line under the cursor, without its diff prefix. `None`
+    /// on a hunk header or fold marker.
+    pub fn line_text_at_cursor(&self) -> Option<String> {
+        match self.view_mode {
+            DiffViewMode::Preview => self
+                .preview()?
+                .lines
+                .get(self.cursor)
+                .map(|line| line.text.clone()),
+            DiffViewMode::Diff => {
+                let &(hunk, line) = self.view_rows().get(self.cursor)?;
+                let line = line?;
+                if self.fold_marker(hunk, line).is_some() {
+                    return None;
+                }
+                Some(self.hunks[hunk].lines[line].content.clone())
+            }
+        }
+    }
+}
+
+/// Offers `path:line` of the cursor line, the hunk under the cursor as a
+/// patch and the cursor line's text.
+impl CopyProvider for CodeDiff {
+    fn copy_payloads(&self) -> Vec<CopyPayload> {
+        let mut payloads = Vec::new();
+        if let Some(target) = self.open_target() {
+            payloads.push(CopyPayload::new(
+                "Path:line",
+                format!("{}:{}", target.path.display(), target.line),
+            ));
+        }
+        if let Some(patch) = self.hunk_patch_at_cursor() {
+            payloads.push(CopyPayload::new("Hunk patch", patch));
+        }
+        if let Some(text) = self.line_text_at_cursor() {
+            payloads.push(CopyPayload::new("Line text", text));
+        }
+        payloads
+    }
+
+    fn copy_source(&self) -> ClipboardSource {
+        ClipboardSource::Diff
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::services::clipboard::CopyProvider;
+    use crate::widgets::code_diff::CodeDiff;
+
+    #[test]
+    fn cursor_line_offers_a_reference_its_hunk_and_its_text() {
+        let mut diff = CodeDiff::from_unified_diff(
+            "--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -10,3 +10,3 @@\n keep\n-old\n+new\n tail\n",
+        )
+        .unwrap();
+
+        // Rows: header, keep, -old, +new, tail
+        diff.cursor = 3;
+        let payloads = diff.copy_payloads();
+        let labels: Vec<&str> = payloads.iter().map(|p| p.label.as_str()).collect();
+        assert_eq!(labels, ["Path:line", "Hunk patch", "Line text"]);
+        assert_eq!(payloads[0].text, "src/lib.rs:11");
+        assert_eq!(
+            payloads[1].text,
+            "--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -10,3 +10,3 @@\n keep\n-old\n+new\n tail\n"
+        );
+        assert_eq!(payloads[2].text, "new");
+
+        // A hunk header has no line text.
+        diff.cursor = 0;
+        assert_eq!(diff.copy_payloads().len(), 2);
+    }
+}
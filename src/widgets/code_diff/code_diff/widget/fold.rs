@@ -0,0 +1,265 @@
+//! Folding long runs of unchanged lines behind marker rows.
+
+use std::ops::Range;
+
+use crate::widgets::code_diff::code_diff::foundation::enums::DiffViewMode;
+use crate::widgets::code_diff::code_diff::widget::CodeDiff;
+
+impl CodeDiff {
+    /// Runs of unchanged lines of hunk `index` that fold, as line ranges.
+    ///
+    /// A run keeps [`context_lines`](crate::widgets::code_diff::DiffConfig::context_lines)
+    /// lines next to each change and folds the rest when that is more
+    /// than [`fold_threshold`](crate::widgets::code_diff::DiffConfig::fold_threshold)
+    /// lines.
+    pub(crate) fn fold_regions(&self, index: usize) -> Vec<Range<usize>> {
+        let Some(threshold) = self.config.fold_threshold else {
+            return Vec::new();
+        };
+        let Some(hunk) = self.hunks.get(index) else {
+            return Vec::new();
+        };
+        let lines = &hunk.lines;
+        let margin = self.config.context_lines;
+
+        let mut regions = Vec::new();
+        let mut line = 0;
+        while line < lines.len() {
+            if !lines[line].is_context() {
+                line += 1;
+                continue;
+            }
+            let run_start = line;
+            while line < lines.len() && lines[line].is_context() {
+                line += 1;
+            }
+            let start = if run_start == 0 {
+                run_start
+            } else {
+                run_start + margin
+            };
+            let end = if line == lines.len() {
+                line
+            } else {
+                line.saturating_sub(margin)
+            };
+            if end > start && end - start > threshold {
+                regions.push(start..end);
+            }
+        }
+        regions
+    }
+
+    /// Lines of `region` of hunk `index` still hidden behind its marker.
+    fn hidden_lines(&self, index: usize, region: &Range<usize>) -> Option<Range<usize>> {
+        let (top, bottom) = self
+            .fold_reveals
+            .get(&(index, region.start))
+            .copied()
+            .unwrap_or_default();
+        let start = region.start.saturating_add(top);
+        let end = region.end.saturating_sub(bottom);
+        (start < end).then_some(start..end)
+    }
+
+    /// The hidden lines of hunk `index` that `line` is one of.
+    pub(crate) fn fold_at(&self, index: usize, line: usize) -> Option<Range<usize>> {
+        self.fold_regions(index)
+            .iter()
+            .filter_map(|region| self.hidden_lines(index, region))
+            .find(|hidden| hidden.contains(&line))
+    }
+
+    /// Whether `line` of hunk `index` is hidden and not the row its
+    /// marker is shown on.
+    pub(crate) fn is_folded_away(&self, index: usize, line: usize) -> bool {
+        self.fold_at(index, line)
+            .is_some_and(|hidden| hidden.start != line)
+    }
+
+    /// Number of lines behind the marker shown on `line` of hunk `index`,
+    /// or `None` if the row is not a marker.
+    pub(crate) fn fold_marker(&self, index: usize, line: usize) -> Option<usize> {
+        self.fold_at(index, line)
+            .filter(|hidden| hidden.start == line)
+            .map(|hidden| hidden.len())
+    }
+
+    /// Number of lines behind the fold marker under the cursor.
+    pub fn fold_marker_at_cursor(&self) -> Option<usize> {
+        if self.view_mode != DiffViewMode::Diff {
+            return None;
+        }
+        let &(index, line) = self.view_rows().get(self.cursor)?;
+        self.fold_marker(index, line?)
+    }
+
+    /// Reveals up to [`fold_expand_step`](crate::widgets::code_diff::DiffConfig::fold_expand_step)
+    /// lines of the fold under the cursor, or all of them if `all` is set.
+    ///
+    /// Lines are revealed next to the change before the fold, or next to
+    /// the change after it for a fold at the top of a hunk. Returns `false`
+    /// if the cursor is not on a fold marker.
+    pub fn expand_fold_at_cursor(&mut self, all: bool) -> bool {
+        if self.fold_marker_at_cursor().is_none() {
+            return false;
+        }
+        let Some(&(index, Some(line))) = self.view_rows().get(self.cursor) else {
+            return false;
+        };
+        let Some(region) = self
+            .fold_regions(index)
+            .into_iter()
+            .find(|region| region.contains(&line))
+        else {
+            return false;
+        };
+
+        let step = if all {
+            region.len()
+        } else {
+            self.config.fold_expand_step.max(1)
+        };
+        let reveal = self.fold_reveals.entry((index, region.start)).or_default();
+        if region.start == 0 {
+            reveal.1 = reveal.1.saturating_add(step);
+        } else {
+            reveal.0 = reveal.0.saturating_add(step);
+        }
+        // Keep the cursor on the marker so the next press continues
+        if let Some(hidden) = self.hidden_lines(index, &region) {
+            let marker = (index, Some(hidden.start));
+            if let Some(row) = self.view_rows().iter().position(|&row| row == marker) {
+                self.cursor = row;
+            }
+        }
+        true
+    }
+
+    /// Folds every run again.
+    pub fn collapse_folds(&mut self) {
+        let row = self.unified_row(self.cursor);
+        self.fold_reveals.clear();
+        if self.view_mode == DiffViewMode::Diff {
+            self.cursor = self.view_row(row);
+            self.scroll_offset = self.scroll_offset.min(self.cursor);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+    use ratatui::layout::Rect;
+
+    use crate::widgets::code_diff::{CodeDiff, DiffConfig, DiffHunk, DiffLine, DiffStyle};
+
+    /// A hunk with 30 unchanged lines, a change, then 30 more.
+    fn long_diff(config: DiffConfig) -> CodeDiff {
+        let mut hunk = DiffHunk::new(1, 61, 1, 61);
+        for n in 1..=30 {
+            hunk.add_line(DiffLine::context(&format!("line {n}"), n, n));
+        }
+        hunk.add_line(DiffLine::removed("old", 31));
+        hunk.add_line(DiffLine::added("new", 31));
+        for n in 32..=61 {
+            hunk.add_line(DiffLine::context(&format!("line {n}"), n, n));
+        }
+        let mut diff = CodeDiff::new().with_config(config);
+        diff.add_hunk(hunk);
+        diff
+    }
+
+    /// New line numbers of the rows, with `None` for the header and markers.
+    fn new_numbers(diff: &CodeDiff) -> Vec<Option<usize>> {
+        diff.view_rows()
+            .into_iter()
+            .map(|(index, line)| {
+                let line = line?;
+                if diff.fold_marker(index, line).is_some() {
+                    return None;
+                }
+                diff.hunks[index].lines[line].new_line_num
+            })
+            .collect()
+    }
+
+    #[test]
+    fn runs_fold_past_the_threshold_and_keep_context_next_to_changes() {
+        let diff = long_diff(DiffConfig::new());
+        // 27 lines fold above the change and 27 below it.
+        assert_eq!(diff.fold_regions(0), [0..27, 35..62]);
+        // Header, marker, 3 context, -old, +new, 3 context, marker.
+        assert_eq!(diff.row_count(), 11);
+
+        let exact = long_diff(DiffConfig::new().fold_threshold(Some(27)));
+        assert!(exact.fold_regions(0).is_empty());
+        let off = long_diff(DiffConfig::new().fold_threshold(None));
+        assert_eq!(off.row_count(), 63);
+    }
+
+    #[test]
+    fn expanding_reveals_steps_and_keeps_line_numbers_continuous() {
+        let mut diff = long_diff(DiffConfig::new());
+
+        // The marker below the change reveals downwards from it, and the
+        // cursor follows it.
+        diff.cursor = 10;
+        assert_eq!(diff.fold_marker_at_cursor(), Some(27));
+        assert!(diff.handle_key_event(KeyCode::Char('o')));
+        assert_eq!(diff.row_count(), 31);
+        assert_eq!(diff.cursor, 30);
+        assert_eq!(diff.fold_marker_at_cursor(), Some(7));
+        assert!(diff.handle_key_event(KeyCode::Char('o')));
+        assert_eq!(diff.fold_marker_at_cursor(), None);
+        assert_eq!(diff.row_count(), 37);
+
+        // The marker at the top of the hunk reveals upwards from the change.
+        diff.cursor = 1;
+        assert!(diff.handle_key_event(KeyCode::Char('o')));
+        assert_eq!(diff.fold_marker_at_cursor(), Some(7));
+        assert_eq!(diff.cursor, 1);
+        let numbers = new_numbers(&diff);
+        assert_eq!(numbers[..3], [None, None, Some(8)]);
+        let shown: Vec<usize> = numbers[2..].iter().flatten().copied().collect();
+        assert_eq!(shown, (8..=61).collect::<Vec<_>>());
+
+        assert!(diff.handle_key_event(KeyCode::Char('O')));
+        assert_eq!(diff.row_count(), 63);
+        let numbers: Vec<usize> = new_numbers(&diff).into_iter().flatten().collect();
+        assert_eq!(numbers, (1..=61).collect::<Vec<_>>());
+
+        // A line that is not a marker does not expand.
+        assert!(!diff.expand_fold_at_cursor(false));
+
+        diff.cursor = 40;
+        diff.collapse_folds();
+        assert_eq!(diff.row_count(), 11);
+        assert_eq!(diff.fold_marker_at_cursor(), Some(27));
+
+        // Side by side shows the same markers.
+        diff.set_diff_style(DiffStyle::SideBySide);
+        assert_eq!(diff.row_count(), 10);
+        assert_eq!(diff.fold_marker_at_cursor(), Some(27));
+    }
+
+    #[test]
+    fn clicking_a_marker_expands_it() {
+        let mut diff = long_diff(DiffConfig::new());
+        let area = Rect::new(0, 0, 80, 20);
+        let click = |row| MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: 5,
+            row,
+            modifiers: KeyModifiers::NONE,
+        };
+
+        // The title is above row 0 of the diff
+        assert!(!diff.handle_mouse(click(0), area));
+        assert!(diff.handle_mouse(click(2), area));
+        assert_eq!(diff.cursor, 1);
+        assert_eq!(diff.fold_marker_at_cursor(), Some(7));
+        assert_eq!(diff.row_count(), 31);
+        assert!(!diff.handle_mouse(click(40), area));
+    }
+}
@@ -3,14 +3,27 @@
 //! The main widget that renders diff hunks in a side-by-side or unified view,
 //! similar to VS Code's diff viewer.
 
-use std::collections::HashMap;
+#[cfg(feature = "clipboard")]
+mod copy;
+mod fold;
+mod navigate;
+mod open;
+mod preview;
+mod render;
+
+use std::collections::{HashMap, HashSet};
 
 use super::foundation::diff_config::DiffConfig;
+use super::foundation::diff_error::DiffResult;
 use super::foundation::diff_hunk::DiffHunk;
-use super::foundation::diff_line::DiffLine;
-use ratatui::buffer::Buffer;
+use super::foundation::enums::{CodeDiffEvent, DiffStyle, DiffViewMode, Direction};
+use super::foundation::unified::parse_files;
+use crate::services::external_opener::OpenTarget;
+use crossterm::event::{KeyCode, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::layout::Rect;
-use ratatui::widgets::Widget;
+
+/// Lines of a hunk shown on the left and right of a side-by-side row.
+pub(crate) type SplitRow = (Option<usize>, Option<usize>);
 
 #[derive(Debug, Clone, Default)]
 pub struct CodeDiff {
@@ -19,6 +32,30 @@ pub struct CodeDiff {
     pub config: DiffConfig,
     pub scroll_offset: usize,
     pub file_diffs: HashMap<String, Vec<DiffHunk>>,
+    /// Content the hunks apply to, shown with them applied in the preview.
+    pub base_content: Option<String>,
+    /// Whether the main pane shows the diff or the patched file.
+    pub view_mode: DiffViewMode,
+    /// Cursor row in the current view.
+    pub cursor: usize,
+    pub(crate) deselected_hunks: HashSet<usize>,
+    /// Keys typed so far of a multi-key open binding.
+    pub(crate) pending_keys: Vec<KeyCode>,
+    /// Target of the last open key press, until taken.
+    pub(crate) open_request: Option<OpenTarget>,
+    /// Lines revealed at the top and bottom of each folded run, keyed by
+    /// hunk and the run's first line.
+    pub(crate) fold_reveals: HashMap<(usize, usize), (usize, usize)>,
+    /// Request for the host raised by the last key press.
+    pub(crate) event: Option<CodeDiffEvent>,
+}
+
+/// What a multi-key binding does.
+#[derive(Debug, Clone, Copy)]
+enum Sequence {
+    Open,
+    Hunk(Direction),
+    File(Direction),
 }
 
 impl CodeDiff {
@@ -29,6 +66,14 @@ impl CodeDiff {
             config: DiffConfig::new(),
             scroll_offset: 0,
             file_diffs: HashMap::new(),
+            base_content: None,
+            view_mode: DiffViewMode::Diff,
+            cursor: 0,
+            deselected_hunks: HashSet::new(),
+            pending_keys: Vec::new(),
+            open_request: None,
+            fold_reveals: HashMap::new(),
+            event: None,
         }
     }
 
@@ -54,43 +99,328 @@ impl CodeDiff {
         &mut self.hunks
     }
 
-    pub fn from_unified_diff(diff: &str) -> Self {
+    /// Creates a diff from unified diff text, such as `git diff` output.
+    ///
+    /// The first file's hunks are shown and its path, if the text has file
+    /// headers, becomes [`file_path`](Self::file_path). Every file with a
+    /// path is also kept in [`file_diffs`](Self::file_diffs).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DiffError::Parse`](super::foundation::diff_error::DiffError::Parse) if the text is not a well-formed
+    /// unified diff; see [`parse_unified_diff`](super::foundation::unified::parse_unified_diff).
+    pub fn from_unified_diff(diff: &str) -> DiffResult<Self> {
+        let files = parse_files(diff)?;
         let mut result = Self::new();
-        result.parse_unified_diff(diff);
-        result
+        for file in &files {
+            if let Some(path) = &file.path {
+                result.file_diffs.insert(path.clone(), file.hunks.clone());
+            }
+        }
+        if let Some(first) = files.into_iter().next() {
+            result.file_path = first.path;
+            result.hunks = first.hunks;
+        }
+        Ok(result)
     }
 
-    fn parse_unified_diff(&mut self, diff: &str) {
-        let mut current_hunk: Option<DiffHunk> = None;
+    /// Rows of the diff view as `(hunk, line)` pairs, where a `None` line
+    /// is the hunk header.
+    pub(crate) fn diff_rows(&self) -> Vec<(usize, Option<usize>)> {
+        self.hunks
+            .iter()
+            .enumerate()
+            .flat_map(|(index, hunk)| {
+                std::iter::once((index, None))
+                    .chain((0..hunk.lines.len()).map(move |line| (index, Some(line))))
+            })
+            .collect()
+    }
 
-        for line in diff.lines() {
-            if line.starts_with("@@") {
-                if let Some(hunk) = current_hunk {
-                    self.hunks.push(hunk);
+    /// Rows of the side-by-side diff view as `(hunk, sides)` pairs, where
+    /// `sides` holds the lines shown on the left and right and `None` is
+    /// the hunk header.
+    ///
+    /// Context lines fill both sides. A run of removed lines is paired row
+    /// by row with the run of added lines after it, leaving the other side
+    /// empty where one run is longer.
+    pub(crate) fn split_rows(&self) -> Vec<(usize, Option<SplitRow>)> {
+        let mut rows = Vec::new();
+        for (index, hunk) in self.hunks.iter().enumerate() {
+            rows.push((index, None));
+            let lines = &hunk.lines;
+            let mut line = 0;
+            while line < lines.len() {
+                if lines[line].is_context() {
+                    rows.push((index, Some((Some(line), Some(line)))));
+                    line += 1;
+                    continue;
+                }
+                let removed_start = line;
+                while line < lines.len() && lines[line].is_removed() {
+                    line += 1;
+                }
+                let added_start = line;
+                while line < lines.len() && lines[line].is_added() {
+                    line += 1;
                 }
-                current_hunk = Some(DiffHunk::from_header(line));
-            } else if let Some(ref mut hunk) = current_hunk {
-                if let Some(parsed_line) = DiffLine::from_diff_line(line) {
-                    hunk.add_line(parsed_line);
+                if line == removed_start {
+                    line += 1;
+                    continue;
+                }
+                let removed = added_start - removed_start;
+                let added = line - added_start;
+                for offset in 0..removed.max(added) {
+                    rows.push((
+                        index,
+                        Some((
+                            (offset < removed).then_some(removed_start + offset),
+                            (offset < added).then_some(added_start + offset),
+                        )),
+                    ));
                 }
             }
         }
+        rows
+    }
+
+    /// Side-by-side rows left once folded runs are collapsed into their
+    /// marker rows.
+    pub(crate) fn shown_split_rows(&self) -> Vec<(usize, Option<SplitRow>)> {
+        self.split_rows()
+            .into_iter()
+            .filter(|&(hunk, sides)| {
+                !sides
+                    .and_then(|(left, right)| left.or(right))
+                    .is_some_and(|line| self.is_folded_away(hunk, line))
+            })
+            .collect()
+    }
+
+    /// Rows of the diff view in its current style, as `(hunk, line)`
+    /// pairs, with folded runs collapsed into their marker rows. A
+    /// side-by-side row is identified by its left line, or its right line
+    /// when the left is empty.
+    pub(crate) fn view_rows(&self) -> Vec<(usize, Option<usize>)> {
+        match self.config.style {
+            DiffStyle::Unified => self
+                .diff_rows()
+                .into_iter()
+                .filter(|&(hunk, line)| !line.is_some_and(|line| self.is_folded_away(hunk, line)))
+                .collect(),
+            DiffStyle::SideBySide => self
+                .shown_split_rows()
+                .into_iter()
+                .map(|(hunk, sides)| (hunk, sides.and_then(|(left, right)| left.or(right))))
+                .collect(),
+        }
+    }
+
+    /// Unified diff row of diff view row `row`.
+    pub(crate) fn unified_row(&self, row: usize) -> usize {
+        let Some(target) = self.view_rows().get(row).copied() else {
+            return 0;
+        };
+        self.diff_rows()
+            .iter()
+            .position(|&row| row == target)
+            .unwrap_or(0)
+    }
 
-        if let Some(hunk) = current_hunk {
-            self.hunks.push(hunk);
+    /// Diff view row showing unified diff row `row`, or the marker row of
+    /// the fold hiding it.
+    pub(crate) fn view_row(&self, row: usize) -> usize {
+        let Some((hunk, line)) = self.diff_rows().get(row).copied() else {
+            return 0;
+        };
+        let line = line.map(|line| self.fold_at(hunk, line).map_or(line, |hidden| hidden.start));
+        match self.config.style {
+            DiffStyle::Unified => self
+                .view_rows()
+                .iter()
+                .position(|&row| row == (hunk, line))
+                .unwrap_or(0),
+            DiffStyle::SideBySide => self
+                .shown_split_rows()
+                .iter()
+                .position(|&(row_hunk, sides)| {
+                    row_hunk == hunk
+                        && match (sides, line) {
+                            (Some((left, right)), Some(_)) => left == line || right == line,
+                            (None, None) => true,
+                            _ => false,
+                        }
+                })
+                .unwrap_or(0),
         }
     }
-}
 
-impl Widget for CodeDiff {
-    fn render(self, area: Rect, buf: &mut ratatui::buffer::Buffer) {
-        let content = if let Some(path) = &self.file_path {
-            format!("Diff: {}", path)
+    /// Switches the diff view between unified and side by side, keeping
+    /// the cursor on the same line.
+    pub fn set_diff_style(&mut self, style: DiffStyle) {
+        if self.config.style == style {
+            return;
+        }
+        if self.view_mode == DiffViewMode::Diff {
+            let row = self.unified_row(self.cursor);
+            self.config.style = style;
+            self.cursor = self.view_row(row);
+            self.scroll_offset = self.scroll_offset.min(self.cursor);
         } else {
-            "Diff: (no file)".to_string()
-        };
+            self.config.style = style;
+        }
+    }
 
-        let widget = ratatui::widgets::Paragraph::new(content);
-        widget.render(area, buf);
+    /// Number of rows in the current view.
+    pub fn row_count(&self) -> usize {
+        match self.view_mode {
+            DiffViewMode::Diff => self.view_rows().len(),
+            DiffViewMode::Preview => self.preview().map_or(0, |preview| preview.lines.len()),
+        }
+    }
+
+    /// Handles a key press.
+    ///
+    /// # Key Bindings
+    ///
+    /// - `j` / `Down` - Move the cursor down
+    /// - `k` / `Up` - Move the cursor up
+    /// - `Space` - Include or exclude the hunk under the cursor from the preview
+    /// - `p` - Toggle between the diff and preview views
+    /// - `s` - Toggle between the unified and side-by-side layouts
+    /// - `o` - Reveal more lines of the fold marker under the cursor; see
+    ///   [`DiffConfig::fold_expand_step`]
+    /// - `O` - Reveal all lines of the fold marker under the cursor
+    /// - `z` - Fold every unchanged run again
+    /// - `]` `h` / `[` `h` - Jump to the next or previous hunk header
+    /// - `]` `f` / `[` `f` - Ask for the next or previous file; see
+    ///   [`handle_key`](Self::handle_key)
+    /// - `Enter` / `g` `d` - Ask to open the file at the cursor line; see
+    ///   [`take_open_request`](Self::take_open_request) and
+    ///   [`DiffConfig::open_keys`]
+    ///
+    /// # Returns
+    ///
+    /// `true` if the key was handled, `false` otherwise.
+    pub fn handle_key_event(&mut self, key: KeyCode) -> bool {
+        self.event = None;
+        if let Some(handled) = self.handle_sequence_key(key) {
+            return handled;
+        }
+        match key {
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.cursor = (self.cursor + 1).min(self.row_count().saturating_sub(1));
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.cursor = self.cursor.saturating_sub(1);
+            }
+            KeyCode::Char(' ') => self.toggle_hunk_at_cursor(),
+            KeyCode::Char('p') => self.toggle_preview(),
+            KeyCode::Char('s') => self.set_diff_style(match self.config.style {
+                DiffStyle::Unified => DiffStyle::SideBySide,
+                DiffStyle::SideBySide => DiffStyle::Unified,
+            }),
+            KeyCode::Char('o') => return self.expand_fold_at_cursor(false),
+            KeyCode::Char('O') => return self.expand_fold_at_cursor(true),
+            KeyCode::Char('z') => self.collapse_folds(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Handles a key press like [`handle_key_event`](Self::handle_key_event),
+    /// returning what the host should do about it, such as switching files
+    /// on `]` `f`.
+    pub fn handle_key(&mut self, key: KeyCode) -> Option<CodeDiffEvent> {
+        self.handle_key_event(key);
+        self.event.take()
+    }
+
+    /// Multi-key bindings: the open keys, then hunk and file jumps.
+    fn sequences(&self) -> Vec<(Vec<KeyCode>, Sequence)> {
+        let mut sequences: Vec<_> = self
+            .config
+            .open_keys
+            .iter()
+            .map(|keys| (keys.clone(), Sequence::Open))
+            .collect();
+        for (prefix, direction) in [(']', Direction::Next), ('[', Direction::Previous)] {
+            let prefix = KeyCode::Char(prefix);
+            sequences.push((vec![prefix, KeyCode::Char('h')], Sequence::Hunk(direction)));
+            sequences.push((vec![prefix, KeyCode::Char('f')], Sequence::File(direction)));
+        }
+        sequences
+    }
+
+    /// Matches `key` against the multi-key bindings, with the keys typed
+    /// before it. Returns `None` if it is not part of one.
+    fn handle_sequence_key(&mut self, key: KeyCode) -> Option<bool> {
+        self.pending_keys.push(key);
+        let sequences = self.sequences();
+        loop {
+            if let Some(&(_, sequence)) = sequences
+                .iter()
+                .find(|(keys, _)| *keys == self.pending_keys)
+            {
+                self.pending_keys.clear();
+                match sequence {
+                    Sequence::Open => self.open_request = self.open_target(),
+                    Sequence::Hunk(direction) => {
+                        self.jump_to_hunk(direction);
+                    }
+                    Sequence::File(direction) => {
+                        self.event = Some(CodeDiffEvent::FileNavigationRequested(direction));
+                    }
+                }
+                return Some(true);
+            }
+            if sequences
+                .iter()
+                .any(|(keys, _)| keys.starts_with(&self.pending_keys))
+            {
+                return Some(true);
+            }
+            if self.pending_keys.len() == 1 {
+                self.pending_keys.clear();
+                return None;
+            }
+            // The sequence broke off; the key may start another one
+            self.pending_keys = vec![key];
+        }
+    }
+
+    /// Handles a mouse event over the widget rendered in `area`.
+    ///
+    /// A left click moves the cursor to the clicked row and expands it if
+    /// it is a fold marker; the wheel moves the cursor.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the event was handled, `false` otherwise.
+    pub fn handle_mouse(&mut self, event: MouseEvent, area: Rect) -> bool {
+        match event.kind {
+            MouseEventKind::ScrollDown => self.handle_key_event(KeyCode::Down),
+            MouseEventKind::ScrollUp => self.handle_key_event(KeyCode::Up),
+            MouseEventKind::Down(MouseButton::Left) => {
+                // The first row of the area is the title
+                let body_top = area.y.saturating_add(1);
+                if event.column < area.x
+                    || event.column >= area.x.saturating_add(area.width)
+                    || event.row < body_top
+                    || event.row >= area.y.saturating_add(area.height)
+                {
+                    return false;
+                }
+                let height = area.height.saturating_sub(1) as usize;
+                let row = self.visible_offset(height) + (event.row - body_top) as usize;
+                if row >= self.row_count() {
+                    return false;
+                }
+                self.cursor = row;
+                self.expand_fold_at_cursor(false);
+                true
+            }
+            _ => false,
+        }
     }
 }
@@ -0,0 +1,117 @@
+//! Jumping between hunks.
+
+use crate::widgets::code_diff::code_diff::foundation::enums::{DiffViewMode, Direction};
+use crate::widgets::code_diff::code_diff::widget::CodeDiff;
+
+impl CodeDiff {
+    /// Index into [`hunks`](Self::hunks) of the hunk under the cursor, or
+    /// `None` in the preview. For a "hunk 3/7" status line show it plus
+    /// one and the number of hunks.
+    pub fn current_hunk(&self) -> Option<usize> {
+        if self.view_mode != DiffViewMode::Diff {
+            return None;
+        }
+        self.view_rows().get(self.cursor).map(|&(hunk, _)| hunk)
+    }
+
+    /// Moves the cursor to the header of the next hunk, or of the hunk
+    /// above the cursor, and scrolls it to the top of the view.
+    ///
+    /// Returns `false` in the preview or when there is no hunk that way.
+    pub fn jump_to_hunk(&mut self, direction: Direction) -> bool {
+        if self.view_mode != DiffViewMode::Diff {
+            return false;
+        }
+        let mut headers = self
+            .view_rows()
+            .into_iter()
+            .enumerate()
+            .filter(|(_, (_, line))| line.is_none())
+            .map(|(row, _)| row);
+        let target = match direction {
+            Direction::Next => headers.find(|&row| row > self.cursor),
+            Direction::Previous => headers.rev().find(|&row| row < self.cursor),
+        };
+        let Some(row) = target else {
+            return false;
+        };
+        self.cursor = row;
+        self.scroll_offset = row;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::KeyCode;
+
+    use crate::widgets::code_diff::{
+        CodeDiff, CodeDiffEvent, DiffConfig, DiffHunk, DiffLine, Direction,
+    };
+
+    /// Three hunks of 30 unchanged lines around one change each.
+    fn three_hunks() -> CodeDiff {
+        let mut diff = CodeDiff::new().with_config(DiffConfig::new());
+        for start in [1, 101, 201] {
+            let mut hunk = DiffHunk::new(start, 61, start, 61);
+            for n in 0..30 {
+                hunk.add_line(DiffLine::context("same", start + n, start + n));
+            }
+            hunk.add_line(DiffLine::removed("old", start + 30));
+            hunk.add_line(DiffLine::added("new", start + 30));
+            for n in 31..61 {
+                hunk.add_line(DiffLine::context("same", start + n, start + n));
+            }
+            diff.add_hunk(hunk);
+        }
+        diff
+    }
+
+    #[test]
+    fn hunk_jumps_land_on_headers_past_folded_context() {
+        let mut diff = three_hunks();
+        // Each folded hunk is 11 rows: header, marker, 3 context, the
+        // change, 3 context and a marker.
+        assert_eq!(diff.current_hunk(), Some(0));
+        assert!(diff.handle_key_event(KeyCode::Char(']')));
+        assert!(diff.handle_key_event(KeyCode::Char('h')));
+        assert_eq!((diff.cursor, diff.scroll_offset), (11, 11));
+        assert_eq!(diff.current_hunk(), Some(1));
+        assert!(diff.jump_to_hunk(Direction::Next));
+        assert_eq!(diff.cursor, 22);
+        assert!(!diff.jump_to_hunk(Direction::Next));
+
+        // Back from inside a hunk goes to its own header first.
+        diff.cursor = 25;
+        assert!(diff.jump_to_hunk(Direction::Previous));
+        assert_eq!(diff.cursor, 22);
+        assert!(diff.jump_to_hunk(Direction::Previous));
+        assert_eq!((diff.cursor, diff.current_hunk()), (11, Some(1)));
+
+        // Expanding a fold moves the headers after it down.
+        diff.cursor = 10;
+        assert!(diff.expand_fold_at_cursor(true));
+        diff.cursor = 0;
+        assert!(diff.jump_to_hunk(Direction::Next));
+        assert_eq!(diff.cursor, 37);
+        assert_eq!(diff.current_hunk(), Some(1));
+    }
+
+    #[test]
+    fn file_jumps_ask_the_host() {
+        let mut diff = three_hunks();
+        assert_eq!(diff.handle_key(KeyCode::Char(']')), None);
+        assert_eq!(
+            diff.handle_key(KeyCode::Char('f')),
+            Some(CodeDiffEvent::FileNavigationRequested(Direction::Next))
+        );
+        diff.handle_key(KeyCode::Char('['));
+        assert_eq!(
+            diff.handle_key(KeyCode::Char('f')),
+            Some(CodeDiffEvent::FileNavigationRequested(Direction::Previous))
+        );
+        // Other keys raise nothing.
+        assert_eq!(diff.handle_key(KeyCode::Char('j')), None);
+        assert_eq!(diff.cursor, 1);
+    }
+}
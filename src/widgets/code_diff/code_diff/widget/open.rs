@@ -0,0 +1,105 @@
+//! Asking to open the file at the cursor line.
+
+use crate::services::external_opener::OpenTarget;
+use crate::widgets::code_diff::code_diff::foundation::enums::{DiffStyle, DiffViewMode};
+use crate::widgets::code_diff::code_diff::widget::CodeDiff;
+
+impl CodeDiff {
+    /// The line of the new file under the cursor, or `None` without a
+    /// [`file_path`](Self::file_path).
+    ///
+    /// A removed line opens where it was removed from, a hunk header at
+    /// the hunk's first line and a side-by-side row at its right side.
+    pub fn open_target(&self) -> Option<OpenTarget> {
+        let path = self.file_path.as_ref()?;
+        let line = match self.view_mode {
+            DiffViewMode::Preview => self.cursor + 1,
+            DiffViewMode::Diff => self.cursor_new_line()?,
+        };
+        Some(OpenTarget::new(path, line.max(1)))
+    }
+
+    /// Takes the target of the last open key press, if it was not taken
+    /// yet. Pass it to an
+    /// [`ExternalOpener`](crate::services::external_opener::ExternalOpener).
+    pub fn take_open_request(&mut self) -> Option<OpenTarget> {
+        self.open_request.take()
+    }
+
+    /// Line of the new file of the diff row under the cursor.
+    fn cursor_new_line(&self) -> Option<usize> {
+        let (hunk_index, line) = match self.config.style {
+            DiffStyle::Unified => *self.view_rows().get(self.cursor)?,
+            DiffStyle::SideBySide => {
+                let (hunk, sides) = *self.shown_split_rows().get(self.cursor)?;
+                (hunk, sides.and_then(|(left, right)| right.or(left)))
+            }
+        };
+        let hunk = &self.hunks[hunk_index];
+        let Some(line) = line else {
+            return Some(hunk.new_start);
+        };
+        let before = hunk.lines[..line]
+            .iter()
+            .filter(|line| !line.is_removed() && !line.is_hunk_header())
+            .count();
+        Some(
+            hunk.lines[line]
+                .new_line_num
+                .unwrap_or(hunk.new_start + before),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::KeyCode;
+
+    use crate::services::external_opener::OpenTarget;
+    use crate::widgets::code_diff::{CodeDiff, DiffConfig, DiffStyle};
+
+    #[test]
+    fn open_keys_request_the_new_line_under_the_cursor() {
+        let mut diff = CodeDiff::from_unified_diff(
+            "--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -10,3 +10,3 @@\n keep\n-old\n+new\n tail\n",
+        )
+        .unwrap();
+        assert_eq!(diff.file_path.as_deref(), Some("src/lib.rs"));
+
+        // Rows: header, keep, -old, +new, tail
+        diff.cursor = 2;
+        assert!(diff.handle_key_event(KeyCode::Enter));
+        assert_eq!(
+            diff.take_open_request(),
+            Some(OpenTarget::new("src/lib.rs", 11))
+        );
+        assert_eq!(diff.take_open_request(), None);
+
+        diff.cursor = 4;
+        assert!(diff.handle_key_event(KeyCode::Char('g')));
+        assert_eq!(diff.take_open_request(), None);
+        assert!(diff.handle_key_event(KeyCode::Char('d')));
+        assert_eq!(
+            diff.take_open_request(),
+            Some(OpenTarget::new("src/lib.rs", 12))
+        );
+
+        // A broken-off sequence falls through to the other bindings.
+        diff.handle_key_event(KeyCode::Char('g'));
+        assert!(diff.handle_key_event(KeyCode::Char('k')));
+        assert_eq!(diff.cursor, 3);
+        assert_eq!(diff.take_open_request(), None);
+
+        diff.config = DiffConfig::new()
+            .diff_style(DiffStyle::SideBySide)
+            .open_keys(vec![vec![KeyCode::Char('o')]]);
+        assert!(!diff.handle_key_event(KeyCode::Enter));
+        // Side-by-side rows: header, keep, -old|+new, tail
+        diff.cursor = 2;
+        diff.handle_key_event(KeyCode::Char('o'));
+        assert_eq!(
+            diff.take_open_request(),
+            Some(OpenTarget::new("src/lib.rs", 11))
+        );
+    }
+}
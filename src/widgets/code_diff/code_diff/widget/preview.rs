@@ -0,0 +1,479 @@
+//! Patch application preview.
+//!
+//! Applies the selected hunks to the base content and keeps track of where
+//! every diff row ends up in the result, so the cursor can move between
+//! the diff and preview views without losing its place.
+
+use std::collections::HashMap;
+
+use crate::widgets::code_diff::code_diff::foundation::apply_error::ApplyError;
+use crate::widgets::code_diff::code_diff::foundation::diff_hunk::DiffHunk;
+use crate::widgets::code_diff::code_diff::foundation::enums::DiffViewMode;
+use crate::widgets::code_diff::code_diff::widget::CodeDiff;
+
+/// One line of the previewed file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PreviewLine {
+    pub text: String,
+    /// True if a hunk added this line.
+    pub applied: bool,
+}
+
+/// The base content with the selected hunks applied.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PatchPreview {
+    pub lines: Vec<PreviewLine>,
+    /// Selected hunks left out of the preview, by hunk index.
+    pub failures: HashMap<usize, ApplyError>,
+    /// Preview row of every diff row (see [`CodeDiff::diff_rows`]).
+    pub row_map: Vec<usize>,
+    trailing_newline: bool,
+}
+
+impl PatchPreview {
+    /// Joins the preview back into file content.
+    pub fn content(&self) -> String {
+        let mut content = self
+            .lines
+            .iter()
+            .map(|line| line.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+        if self.trailing_newline && !self.lines.is_empty() {
+            content.push('\n');
+        }
+        content
+    }
+
+    /// The first failure in hunk order.
+    pub fn first_failure(&self) -> Option<&ApplyError> {
+        self.failures
+            .iter()
+            .min_by_key(|(hunk, _)| **hunk)
+            .map(|(_, error)| error)
+    }
+}
+
+/// 0-based line of the base content where `hunk` starts replacing lines.
+///
+/// A hunk that only adds lines has a start line naming the line it follows.
+fn hunk_start(hunk: &DiffHunk, old_len: usize) -> usize {
+    if old_len == 0 {
+        hunk.old_start
+    } else {
+        hunk.old_start.saturating_sub(1)
+    }
+}
+
+/// Old side of `hunk`: its context and removed lines.
+fn old_side(hunk: &DiffHunk) -> Vec<&str> {
+    hunk.lines
+        .iter()
+        .filter(|line| line.is_context() || line.is_removed())
+        .map(|line| line.content.as_str())
+        .collect()
+}
+
+/// Checks that `hunk` applies at its stated position in `base`.
+fn validate(index: usize, hunk: &DiffHunk, base: &[&str]) -> Result<(usize, usize), ApplyError> {
+    let old = old_side(hunk);
+    let start = hunk_start(hunk, old.len());
+    for (offset, expected) in old.iter().enumerate() {
+        let found = base.get(start + offset).copied();
+        if found != Some(*expected) {
+            return Err(ApplyError::ContextMismatch {
+                hunk: index,
+                line: start + offset + 1,
+                expected: expected.to_string(),
+                found: found.map(str::to_string),
+            });
+        }
+    }
+    if start > base.len() {
+        return Err(ApplyError::ContextMismatch {
+            hunk: index,
+            line: start,
+            expected: String::new(),
+            found: None,
+        });
+    }
+    Ok((start, start + old.len()))
+}
+
+/// Applies the hunks in `selected` to `base`.
+///
+/// Hunks that do not match the base content, or that overlap a hunk
+/// earlier in the file, are recorded in [`PatchPreview::failures`] and left
+/// out. Hunks not in `selected` are left out silently.
+pub(crate) fn apply_hunks(
+    base: &str,
+    hunks: &[DiffHunk],
+    selected: impl Fn(usize) -> bool,
+) -> PatchPreview {
+    let base_lines: Vec<&str> = base.lines().collect();
+    let mut preview = PatchPreview {
+        trailing_newline: base.is_empty() || base.ends_with('\n'),
+        ..PatchPreview::default()
+    };
+
+    let mut candidates: Vec<(usize, usize, usize)> = Vec::new();
+    for (index, hunk) in hunks
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| selected(*index))
+    {
+        match validate(index, hunk, &base_lines) {
+            Ok((start, end)) => candidates.push((index, start, end)),
+            Err(error) => {
+                preview.failures.insert(index, error);
+            }
+        }
+    }
+    candidates.sort_by_key(|&(index, start, _)| (start, index));
+
+    let mut accepted: Vec<(usize, usize, usize)> = Vec::new();
+    for (index, start, end) in candidates {
+        match accepted.last() {
+            Some(&(other, _, previous_end)) if start < previous_end => {
+                preview
+                    .failures
+                    .insert(index, ApplyError::Overlap { hunk: index, other });
+            }
+            _ => accepted.push((index, start, end)),
+        }
+    }
+
+    // Preview row of each base line, plus one for the end of the file.
+    let mut old_rows = vec![0; base_lines.len() + 1];
+    let mut line_rows: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut next_old = 0;
+    for &(index, start, end) in &accepted {
+        for (line, text) in base_lines.iter().enumerate().take(start).skip(next_old) {
+            old_rows[line] = preview.lines.len();
+            preview.lines.push(PreviewLine {
+                text: text.to_string(),
+                applied: false,
+            });
+        }
+
+        let mut old = start;
+        for (position, line) in hunks[index].lines.iter().enumerate() {
+            line_rows.insert((index, position), preview.lines.len());
+            if line.is_added() {
+                preview.lines.push(PreviewLine {
+                    text: line.content.clone(),
+                    applied: true,
+                });
+            } else if line.is_context() {
+                old_rows[old] = preview.lines.len();
+                preview.lines.push(PreviewLine {
+                    text: line.content.clone(),
+                    applied: false,
+                });
+                old += 1;
+            } else if line.is_removed() {
+                old_rows[old] = preview.lines.len();
+                old += 1;
+            }
+        }
+        next_old = end;
+    }
+    for (line, text) in base_lines.iter().enumerate().skip(next_old) {
+        old_rows[line] = preview.lines.len();
+        preview.lines.push(PreviewLine {
+            text: text.to_string(),
+            applied: false,
+        });
+    }
+    old_rows[base_lines.len()] = preview.lines.len();
+
+    // Diff rows of hunks left out fall back to where their old lines went.
+    let last_row = preview.lines.len().saturating_sub(1);
+    for (index, hunk) in hunks.iter().enumerate() {
+        let start = hunk_start(hunk, old_side(hunk).len());
+        let old_row = |old: usize| old_rows[old.min(base_lines.len())].min(last_row);
+        preview.row_map.push(
+            line_rows
+                .get(&(index, 0))
+                .copied()
+                .unwrap_or_else(|| old_row(start))
+                .min(last_row),
+        );
+        let mut old = start;
+        for (position, line) in hunk.lines.iter().enumerate() {
+            let row = line_rows
+                .get(&(index, position))
+                .copied()
+                .unwrap_or_else(|| old_row(old));
+            preview.row_map.push(row.min(last_row));
+            if line.is_context() || line.is_removed() {
+                old += 1;
+            }
+        }
+    }
+
+    preview
+}
+
+impl CodeDiff {
+    /// Sets the content the hunks apply to, enabling the preview view.
+    pub fn with_base_content(mut self, content: &str) -> Self {
+        self.set_base_content(content);
+        self
+    }
+
+    /// Sets the content the hunks apply to, enabling the preview view.
+    pub fn set_base_content(&mut self, content: &str) {
+        self.base_content = Some(content.to_string());
+    }
+
+    /// Returns true if hunk `index` is included in the preview.
+    ///
+    /// Every hunk is selected until deselected.
+    pub fn is_hunk_selected(&self, index: usize) -> bool {
+        !self.deselected_hunks.contains(&index)
+    }
+
+    /// Includes or excludes hunk `index` from the preview.
+    pub fn set_hunk_selected(&mut self, index: usize, selected: bool) {
+        if selected {
+            self.deselected_hunks.remove(&index);
+        } else {
+            self.deselected_hunks.insert(index);
+        }
+    }
+
+    /// Toggles whether the hunk under the cursor is included in the
+    /// preview.
+    pub fn toggle_hunk_at_cursor(&mut self) {
+        if self.view_mode != DiffViewMode::Diff {
+            return;
+        }
+        if let Some(&(hunk, _)) = self.view_rows().get(self.cursor) {
+            self.set_hunk_selected(hunk, !self.is_hunk_selected(hunk));
+        }
+    }
+
+    /// Applies the selected hunks to the base content.
+    ///
+    /// Returns `None` if no base content is set.
+    pub(crate) fn preview(&self) -> Option<PatchPreview> {
+        let base = self.base_content.as_deref()?;
+        Some(apply_hunks(base, &self.hunks, |index| {
+            self.is_hunk_selected(index)
+        }))
+    }
+
+    /// Computes the base content with the selected hunks applied, for
+    /// writing back to the file once the user confirms.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApplyError::MissingBase`] if no base content is set, or
+    /// the error for the first selected hunk that does not apply cleanly.
+    /// Failed hunks are never silently dropped from the result.
+    pub fn preview_content(&self) -> Result<String, ApplyError> {
+        let preview = self.preview().ok_or(ApplyError::MissingBase)?;
+        match preview.first_failure() {
+            Some(error) => Err(error.clone()),
+            None => Ok(preview.content()),
+        }
+    }
+
+    /// Selected hunks that do not apply cleanly, by hunk index.
+    pub fn apply_errors(&self) -> HashMap<usize, ApplyError> {
+        self.preview()
+            .map(|preview| preview.failures)
+            .unwrap_or_default()
+    }
+
+    /// Switches between the diff and preview views, keeping the cursor on
+    /// the same logical line.
+    ///
+    /// Does nothing in the diff view if no base content is set.
+    pub fn toggle_preview(&mut self) {
+        let Some(preview) = self.preview() else {
+            return;
+        };
+        match self.view_mode {
+            DiffViewMode::Diff => {
+                let row = self.unified_row(self.cursor);
+                self.cursor = preview.row_map.get(row).copied().unwrap_or(0);
+                self.view_mode = DiffViewMode::Preview;
+            }
+            DiffViewMode::Preview => {
+                let target = self.cursor;
+                let rows = self.diff_rows();
+                let row = preview
+                    .row_map
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|&(row, &preview_row)| {
+                        // Prefer lines that are in the preview over removed
+                        // lines and hunk headers mapping to the same row.
+                        let rank = match rows
+                            .get(row)
+                            .and_then(|&(hunk, line)| self.hunks[hunk].lines.get(line?))
+                        {
+                            Some(line) if line.is_removed() => 1,
+                            Some(_) => 0,
+                            None => 2,
+                        };
+                        (preview_row.abs_diff(target), rank)
+                    })
+                    .map_or(0, |(row, _)| row);
+                self.cursor = self.view_row(row);
+                self.view_mode = DiffViewMode::Diff;
+            }
+        }
+        self.scroll_offset = self.scroll_offset.min(self.cursor);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::KeyCode;
+
+    use crate::widgets::code_diff::{ApplyError, CodeDiff, DiffStyle, DiffViewMode};
+
+    const BASE: &str = "one\ntwo\nthree\nfour\nfive\nsix\nseven\n";
+
+    #[test]
+    fn applies_selected_hunks() {
+        let diff = CodeDiff::from_unified_diff(
+            "@@ -1,2 +1,2 @@\n-one\n+ONE\n two\n@@ -6,2 +6,3 @@\n six\n+six and a half\n seven\n",
+        )
+        .unwrap()
+        .with_base_content(BASE);
+
+        assert_eq!(
+            diff.preview_content().unwrap(),
+            "ONE\ntwo\nthree\nfour\nfive\nsix\nsix and a half\nseven\n"
+        );
+    }
+
+    #[test]
+    fn deselected_hunks_are_left_out() {
+        let mut diff = CodeDiff::from_unified_diff(
+            "@@ -1,2 +1,2 @@\n-one\n+ONE\n two\n@@ -6,2 +6,3 @@\n six\n+six and a half\n seven\n",
+        )
+        .unwrap()
+        .with_base_content(BASE);
+        diff.set_hunk_selected(0, false);
+
+        assert_eq!(
+            diff.preview_content().unwrap(),
+            "one\ntwo\nthree\nfour\nfive\nsix\nsix and a half\nseven\n"
+        );
+    }
+
+    #[test]
+    fn conflicting_hunk_is_flagged_and_excluded() {
+        let mut diff = CodeDiff::from_unified_diff(
+            "@@ -2,2 +2,2 @@\n two\n-three\n+THREE\n@@ -4,1 +4,1 @@\n-FOUR\n+4\n",
+        )
+        .unwrap()
+        .with_base_content(BASE);
+
+        let errors = diff.apply_errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[&1],
+            ApplyError::ContextMismatch {
+                hunk: 1,
+                line: 4,
+                expected: "FOUR".to_string(),
+                found: Some("four".to_string()),
+            }
+        );
+        assert_eq!(diff.preview_content(), Err(errors[&1].clone()));
+
+        let preview = diff.preview().unwrap();
+        assert_eq!(preview.lines[2].text, "THREE");
+        assert!(preview.lines[2].applied);
+        assert_eq!(preview.lines[3].text, "four");
+
+        diff.set_hunk_selected(1, false);
+        assert!(diff.preview_content().unwrap().contains("THREE\nfour\n"));
+    }
+
+    #[test]
+    fn overlapping_hunk_is_rejected() {
+        let diff = CodeDiff::from_unified_diff(
+            "@@ -2,2 +2,2 @@\n two\n-three\n+THREE\n@@ -3,2 +3,2 @@\n-three\n+3\n four\n",
+        )
+        .unwrap()
+        .with_base_content(BASE);
+
+        assert_eq!(
+            diff.preview_content(),
+            Err(ApplyError::Overlap { hunk: 1, other: 0 })
+        );
+        assert_eq!(diff.preview().unwrap().lines[2].text, "THREE");
+    }
+
+    #[test]
+    fn missing_base_is_an_error() {
+        let diff = CodeDiff::from_unified_diff("@@ -1,1 +1,1 @@\n-a\n+b\n").unwrap();
+        assert_eq!(diff.preview_content(), Err(ApplyError::MissingBase));
+    }
+
+    #[test]
+    fn toggling_preview_keeps_the_cursor_on_the_same_line() {
+        let mut diff = CodeDiff::from_unified_diff(
+            "@@ -1,2 +1,2 @@\n-one\n+ONE\n two\n@@ -6,2 +6,3 @@\n six\n+six and a half\n seven\n",
+        )
+        .unwrap()
+        .with_base_content(BASE);
+
+        // Rows: header, -one, +ONE, two, header, six, +six and a half, seven
+        diff.cursor = 6;
+        diff.toggle_preview();
+        assert_eq!(diff.view_mode, DiffViewMode::Preview);
+        assert_eq!(diff.cursor, 6);
+
+        diff.toggle_preview();
+        assert_eq!(diff.view_mode, DiffViewMode::Diff);
+        assert_eq!(diff.cursor, 6);
+
+        // A removed line maps to the line that replaced it, and back to the
+        // added line rather than the removed one.
+        diff.cursor = 1;
+        diff.toggle_preview();
+        assert_eq!(diff.cursor, 0);
+        diff.toggle_preview();
+        assert_eq!(diff.cursor, 2);
+
+        // Lines outside any hunk map to the nearest diff row.
+        diff.toggle_preview();
+        diff.cursor = 3;
+        diff.toggle_preview();
+        assert_eq!(diff.cursor, 3);
+    }
+
+    #[test]
+    fn side_by_side_rows_keep_the_cursor_on_the_same_line() {
+        let mut diff = CodeDiff::from_unified_diff(
+            "@@ -1,2 +1,2 @@\n-one\n+ONE\n two\n@@ -6,2 +6,3 @@\n six\n+six and a half\n seven\n",
+        )
+        .unwrap()
+        .with_base_content(BASE);
+
+        // Unified row 2 is +ONE, which sits beside -one in side-by-side row 1.
+        diff.cursor = 2;
+        diff.set_diff_style(DiffStyle::SideBySide);
+        assert_eq!(diff.cursor, 1);
+        // Rows: header, -one|+ONE, two, header, six, |+six and a half, seven
+        assert_eq!(diff.row_count(), 7);
+
+        diff.cursor = 5;
+        diff.toggle_preview();
+        assert_eq!(diff.cursor, 6);
+        diff.toggle_preview();
+        assert_eq!(diff.cursor, 5);
+
+        diff.handle_key_event(KeyCode::Char('s'));
+        assert_eq!(diff.config.style, DiffStyle::Unified);
+        assert_eq!(diff.cursor, 6);
+    }
+}
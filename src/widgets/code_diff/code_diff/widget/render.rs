@@ -0,0 +1,397 @@
+//! Rendering for the diff and preview views.
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Paragraph, Widget};
+use unicode_width::UnicodeWidthChar;
+
+use super::preview::PatchPreview;
+use super::CodeDiff;
+use crate::widgets::code_diff::code_diff::foundation::apply_error::ApplyError;
+use crate::widgets::code_diff::code_diff::foundation::diff_line::DiffLine;
+use crate::widgets::code_diff::code_diff::foundation::enums::{DiffStyle, DiffViewMode};
+use crate::widgets::code_diff::code_diff::foundation::intraline;
+
+impl CodeDiff {
+    fn gutter(&self, number: Option<usize>) -> Span<'static> {
+        let width = self.config.gutter_width;
+        let text = match number {
+            Some(number) => format!("{:>width$} ", number),
+            None => format!("{:>width$} ", ""),
+        };
+        Span::styled(text, Style::default().fg(self.config.line_number_fg))
+    }
+
+    /// The hunk header row, marked when the hunk is skipped or does not
+    /// apply.
+    fn hunk_header_line(&self, index: usize, preview: Option<&PatchPreview>) -> Line<'static> {
+        let config = &self.config;
+        let mut spans = vec![Span::styled(
+            self.hunks[index].header.clone(),
+            Style::default()
+                .fg(config.hunk_header_fg)
+                .bg(config.hunk_header_bg),
+        )];
+        if !self.is_hunk_selected(index) {
+            spans.push(Span::styled(
+                " (skipped)",
+                Style::default().fg(config.line_number_fg),
+            ));
+        }
+        if let Some(error) = preview.and_then(|p| p.failures.get(&index)) {
+            let reason = match error {
+                ApplyError::Overlap { other, .. } => {
+                    format!(" ✗ overlaps hunk {} ", other + 1)
+                }
+                _ => " ✗ does not apply ".to_string(),
+            };
+            spans.push(Span::raw(" "));
+            spans.push(Span::styled(
+                reason,
+                Style::default()
+                    .fg(config.error_fg)
+                    .add_modifier(Modifier::BOLD | Modifier::REVERSED),
+            ));
+        }
+        Line::from(spans)
+    }
+
+    /// The row standing in for `hidden` folded lines.
+    fn fold_marker_line(&self, hidden: usize) -> Line<'static> {
+        let config = &self.config;
+        let mut spans = Vec::new();
+        if config.show_line_numbers {
+            spans.push(self.gutter(None));
+            spans.push(self.gutter(None));
+        }
+        spans.push(Span::styled(
+            format!("⋯ {} unchanged lines (press o to expand)", hidden),
+            Style::default()
+                .fg(config.line_number_fg)
+                .bg(config.context_bg),
+        ));
+        Line::from(spans)
+    }
+
+    /// First row shown when the body is `height` rows tall, keeping the
+    /// cursor in view.
+    pub(crate) fn visible_offset(&self, height: usize) -> usize {
+        let mut offset = self.scroll_offset.min(self.cursor);
+        if height > 0 && self.cursor >= offset + height {
+            offset = self.cursor + 1 - height;
+        }
+        offset
+    }
+
+    /// Changed word ranges of the paired lines of every hunk, or nothing
+    /// when intraline highlighting is off.
+    fn word_ranges(&self) -> Vec<HashMap<usize, Vec<Range<usize>>>> {
+        if self.config.intraline {
+            self.hunks
+                .iter()
+                .map(|hunk| intraline::hunk_ranges(&hunk.lines))
+                .collect()
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn line_style(&self, line: &DiffLine) -> Style {
+        let config = &self.config;
+        if line.is_added() {
+            Style::default().fg(config.added_fg).bg(config.added_bg)
+        } else if line.is_removed() {
+            Style::default().fg(config.removed_fg).bg(config.removed_bg)
+        } else {
+            Style::default().fg(config.context_fg).bg(config.context_bg)
+        }
+    }
+
+    /// The prefix and content of `line`, with its changed words
+    /// highlighted when `ranges` are given.
+    fn line_spans(
+        &self,
+        line: &DiffLine,
+        ranges: Option<&Vec<Range<usize>>>,
+    ) -> Vec<Span<'static>> {
+        let style = self.line_style(line);
+        match ranges {
+            Some(ranges) => {
+                let mut spans = vec![Span::styled(line.prefix(), style)];
+                spans.extend(self.word_spans(line, ranges, style));
+                spans
+            }
+            None => vec![Span::styled(
+                format!("{}{}", line.prefix(), line.content),
+                style,
+            )],
+        }
+    }
+
+    fn diff_lines(&self, preview: Option<&PatchPreview>) -> Vec<Line<'static>> {
+        let word_ranges = self.word_ranges();
+        self.view_rows()
+            .into_iter()
+            .map(|(index, row)| {
+                let hunk = &self.hunks[index];
+                let Some(line) = row.and_then(|line| hunk.lines.get(line)) else {
+                    return self.hunk_header_line(index, preview);
+                };
+                if let Some(hidden) = row.and_then(|row| self.fold_marker(index, row)) {
+                    return self.fold_marker_line(hidden);
+                }
+
+                let mut spans = Vec::new();
+                if self.config.show_line_numbers {
+                    spans.push(self.gutter(line.old_line_num));
+                    spans.push(self.gutter(line.new_line_num));
+                }
+                let ranges = row.and_then(|row| word_ranges.get(index)?.get(&row));
+                spans.extend(self.line_spans(line, ranges));
+                Line::from(spans)
+            })
+            .collect()
+    }
+
+    /// Side-by-side rows: old lines on the left of a divider and new lines
+    /// on the right, each side `width / 2` columns wide.
+    fn split_lines(&self, preview: Option<&PatchPreview>, width: u16) -> Vec<Line<'static>> {
+        let word_ranges = self.word_ranges();
+        let side_width = (width as usize).saturating_sub(1) / 2;
+        let divider = Span::styled("│", Style::default().fg(self.config.line_number_fg));
+
+        self.shown_split_rows()
+            .into_iter()
+            .map(|(index, sides)| {
+                let Some((left, right)) = sides else {
+                    return self.hunk_header_line(index, preview);
+                };
+                if let Some(hidden) = left.and_then(|left| self.fold_marker(index, left)) {
+                    return self.fold_marker_line(hidden);
+                }
+                let hunk = &self.hunks[index];
+                let side = |line: Option<usize>, old: bool| -> Vec<Span<'static>> {
+                    let Some(diff_line) = line.and_then(|line| hunk.lines.get(line)) else {
+                        return fit_spans(Vec::new(), side_width);
+                    };
+                    let mut spans = Vec::new();
+                    if self.config.show_line_numbers {
+                        spans.push(self.gutter(if old {
+                            diff_line.old_line_num
+                        } else {
+                            diff_line.new_line_num
+                        }));
+                    }
+                    let ranges = line.and_then(|line| word_ranges.get(index)?.get(&line));
+                    spans.extend(self.line_spans(diff_line, ranges));
+                    fit_spans(spans, side_width)
+                };
+
+                let mut spans = side(left, true);
+                spans.push(divider.clone());
+                spans.extend(side(right, false));
+                Line::from(spans)
+            })
+            .collect()
+    }
+
+    /// Splits a paired line into spans that dim the unchanged text and
+    /// highlight the changed `ranges`.
+    fn word_spans(
+        &self,
+        line: &DiffLine,
+        ranges: &[Range<usize>],
+        style: Style,
+    ) -> Vec<Span<'static>> {
+        let word_bg = if line.is_added() {
+            self.config.added_word_bg
+        } else {
+            self.config.removed_word_bg
+        };
+        let unchanged = style.add_modifier(Modifier::DIM);
+        let changed = style.bg(word_bg).add_modifier(Modifier::BOLD);
+
+        let mut spans = Vec::new();
+        let mut pos = 0;
+        for range in ranges {
+            if range.start > pos {
+                spans.push(Span::styled(
+                    line.content[pos..range.start].to_string(),
+                    unchanged,
+                ));
+            }
+            spans.push(Span::styled(
+                line.content[range.clone()].to_string(),
+                changed,
+            ));
+            pos = range.end;
+        }
+        if pos < line.content.len() {
+            spans.push(Span::styled(line.content[pos..].to_string(), unchanged));
+        }
+        spans
+    }
+
+    fn preview_lines(&self, preview: &PatchPreview) -> Vec<Line<'static>> {
+        preview
+            .lines
+            .iter()
+            .enumerate()
+            .map(|(row, line)| {
+                let style = if line.applied {
+                    Style::default()
+                        .fg(self.config.context_fg)
+                        .bg(self.config.applied_bg)
+                } else {
+                    Style::default()
+                        .fg(self.config.context_fg)
+                        .bg(self.config.context_bg)
+                };
+                let mut spans = Vec::new();
+                if self.config.show_line_numbers {
+                    spans.push(self.gutter(Some(row + 1)));
+                }
+                spans.push(Span::styled(line.text.clone(), style));
+                Line::from(spans)
+            })
+            .collect()
+    }
+}
+
+/// Cuts `spans` to `width` columns, or pads them with blanks to it.
+fn fit_spans(spans: Vec<Span<'static>>, width: usize) -> Vec<Span<'static>> {
+    let mut fitted = Vec::new();
+    let mut used = 0;
+    for span in spans {
+        let mut text = String::new();
+        for c in span.content.chars() {
+            let char_width = UnicodeWidthChar::width(c).unwrap_or(0);
+            if used + char_width > width {
+                break;
+            }
+            text.push(c);
+            used += char_width;
+        }
+        let complete = text.len() == span.content.len();
+        fitted.push(Span::styled(text, span.style));
+        if !complete {
+            break;
+        }
+    }
+    if used < width {
+        fitted.push(Span::raw(" ".repeat(width - used)));
+    }
+    fitted
+}
+
+impl Widget for CodeDiff {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.height == 0 {
+            return;
+        }
+
+        let path = self.file_path.as_deref().unwrap_or("(no file)");
+        let preview = self.preview();
+        let (title, mut lines) = match (self.view_mode, &preview) {
+            (DiffViewMode::Preview, Some(preview)) => ("Preview: ", self.preview_lines(preview)),
+            (DiffViewMode::Preview, None) => {
+                ("Preview: ", vec![Line::from("No base content to preview")])
+            }
+            (DiffViewMode::Diff, _) => {
+                let lines = match self.config.style {
+                    DiffStyle::Unified => self.diff_lines(preview.as_ref()),
+                    DiffStyle::SideBySide => self.split_lines(preview.as_ref(), area.width),
+                };
+                ("Diff: ", lines)
+            }
+        };
+
+        // An underlined path marks that the cursor line can be opened
+        let path_style = if self.open_target().is_some() && !self.config.open_keys.is_empty() {
+            Style::default().add_modifier(Modifier::UNDERLINED)
+        } else {
+            Style::default()
+        };
+        let mut header = vec![Span::raw(title), Span::styled(path.to_string(), path_style)];
+        let failed = preview.as_ref().map_or(0, |p| p.failures.len());
+        if failed > 0 {
+            header.push(Span::styled(
+                format!("  ✗ {} hunk(s) do not apply", failed),
+                Style::default().fg(self.config.error_fg),
+            ));
+        }
+        Paragraph::new(Line::from(header)).render(Rect { height: 1, ..area }, buf);
+
+        let body = Rect {
+            y: area.y + 1,
+            height: area.height - 1,
+            ..area
+        };
+        let height = body.height as usize;
+        let offset = self.visible_offset(height);
+        if let Some(line) = lines.get_mut(self.cursor) {
+            *line = std::mem::take(line).patch_style(Modifier::REVERSED);
+        }
+        let visible: Vec<Line> = lines.into_iter().skip(offset).take(height).collect();
+        Paragraph::new(visible).render(body, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Color;
+
+    use super::*;
+    use crate::widgets::code_diff::code_diff::foundation::diff_config::DiffConfig;
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        (0..buf.area.height)
+            .map(|y| {
+                (0..buf.area.width)
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn side_by_side_pairs_old_and_new_lines() {
+        let diff = CodeDiff::from_unified_diff(
+            "@@ -1,4 +1,3 @@\n keep\n-old one\n-old two\n+new one\n tail\n",
+        )
+        .unwrap()
+        .with_config(DiffConfig::new().diff_style(DiffStyle::SideBySide));
+
+        let area = Rect::new(0, 0, 33, 7);
+        let mut buf = Buffer::empty(area);
+        diff.clone().render(area, &mut buf);
+        assert_eq!(
+            rows(&buf),
+            [
+                "Diff: (no file)                  ",
+                "@@ -1,4 +1,3 @@                  ",
+                "   1  keep      │   1  keep      ",
+                "   2 -old one   │   2 +new one   ",
+                "   3 -old two   │                ",
+                "   4  tail      │   3  tail      ",
+                "                                 ",
+            ]
+        );
+        // Both sides of the removed-only row keep the line background.
+        assert_eq!(buf[(6, 4)].bg, DiffConfig::new().removed_bg);
+        assert_eq!(buf[(20, 4)].bg, Color::Reset);
+        // Without a path there is nothing to open.
+        assert!(!buf[(6, 0)].modifier.contains(Modifier::UNDERLINED));
+
+        let diff = diff.with_file_path("src/lib.rs");
+        let mut buf = Buffer::empty(area);
+        diff.render(area, &mut buf);
+        assert!(buf[(6, 0)].modifier.contains(Modifier::UNDERLINED));
+        assert!(!buf[(5, 0)].modifier.contains(Modifier::UNDERLINED));
+    }
+}
@@ -2,4 +2,10 @@
 
 pub mod code_diff;
 
-pub use code_diff::{CodeDiff, DiffConfig, DiffHunk, DiffLine, DiffLineKind, DiffStyle};
+#[cfg(feature = "diff-file-tree")]
+pub use code_diff::{DiffFileEntry, DiffFileTree, DiffFileTreeEvent, FileStatus, StageState};
+
+pub use code_diff::{
+    parse_unified_diff, ApplyError, CodeDiff, CodeDiffEvent, DiffConfig, DiffError, DiffHunk,
+    DiffLine, DiffLineKind, DiffResult, DiffStyle, DiffViewMode, Direction,
+};
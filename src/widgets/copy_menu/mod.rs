@@ -0,0 +1,48 @@
+//! Copy menu overlay.
+//!
+//! A centered popup listing the texts a widget offers to copy through
+//! [`CopyProvider`](crate::services::clipboard::CopyProvider), such as a
+//! file's relative path, absolute path and name. The chosen text is copied
+//! through the clipboard service ([`crate::services::clipboard`]).
+//!
+//! # Features
+//!
+//! - One copy key for every widget: [`CopyMenu::open`] copies the kind of
+//!   text last chosen for that widget type instantly and only shows the
+//!   menu until a kind was chosen
+//! - [`CopyMenu::show`] always shows the menu, to pick another kind
+//! - Keyboard navigation (j/k/Up/Down), Enter or 1-9 to copy, Esc to cancel
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use ratkit::services::clipboard::{CopyPayload, CopyProvider};
+//! use ratkit::widgets::copy_menu::{CopyMenu, CopyMenuEvent};
+//!
+//! struct Task {
+//!     id: u32,
+//!     title: String,
+//! }
+//!
+//! impl CopyProvider for Task {
+//!     fn copy_payloads(&self) -> Vec<CopyPayload> {
+//!         vec![
+//!             CopyPayload::new("Reference", format!("#{}", self.id)),
+//!             CopyPayload::new("Title", self.title.clone()),
+//!         ]
+//!     }
+//! }
+//!
+//! let task = Task { id: 42, title: "Fix the build".into() };
+//! let mut menu = CopyMenu::new();
+//! // On the copy key:
+//! if let Some(CopyMenuEvent::Copied(payload)) = menu.open(&task) {
+//!     println!("Copied {}", payload.label);
+//! }
+//! // While `menu.is_visible()`, route keys to `menu.handle_key(..)` and
+//! // draw it on top with `menu.render(frame, frame.area())`.
+//! ```
+
+mod widget;
+
+pub use widget::{CopyMenu, CopyMenuEvent};
@@ -0,0 +1,353 @@
+use std::collections::HashMap;
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::services::clipboard::{
+    self, ClipboardEntry, ClipboardSource, CopyPayload, CopyProvider,
+};
+
+const POPUP_WIDTH: u16 = 60;
+
+/// Events emitted by [`CopyMenu`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CopyMenuEvent {
+    /// A payload was copied to the clipboard.
+    Copied(CopyPayload),
+    /// The menu was closed without copying.
+    Cancelled,
+}
+
+/// Popup listing what can be copied from a [`CopyProvider`].
+///
+/// The label of the last copied payload is remembered per provider type,
+/// so [`open`](Self::open) copies it again without showing the menu.
+#[derive(Debug, Clone)]
+pub struct CopyMenu {
+    visible: bool,
+    index: usize,
+    payloads: Vec<CopyPayload>,
+    source: ClipboardSource,
+    kind: &'static str,
+    last_used: HashMap<&'static str, String>,
+    width: u16,
+    title: String,
+}
+
+impl Default for CopyMenu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CopyMenu {
+    /// Creates a hidden copy menu.
+    pub fn new() -> Self {
+        Self {
+            visible: false,
+            index: 0,
+            payloads: Vec::new(),
+            source: ClipboardSource::Dialog,
+            kind: "",
+            last_used: HashMap::new(),
+            width: POPUP_WIDTH,
+            title: "Copy".to_string(),
+        }
+    }
+
+    /// Sets the popup width.
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the popup title.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Copies the payload last used for this type of provider if it offers
+    /// one with that label, otherwise shows the menu.
+    ///
+    /// Returns the event for an instant copy, or `None` when the menu was
+    /// shown or the provider has nothing to copy.
+    pub fn open<P: CopyProvider + ?Sized>(&mut self, provider: &P) -> Option<CopyMenuEvent> {
+        let kind = std::any::type_name::<P>();
+        let payloads = provider.copy_payloads();
+        let remembered = self
+            .last_used
+            .get(kind)
+            .and_then(|label| payloads.iter().find(|payload| &payload.label == label));
+        if let Some(payload) = remembered.cloned() {
+            self.kind = kind;
+            self.source = provider.copy_source();
+            return Some(self.copy(payload));
+        }
+        self.show(provider);
+        None
+    }
+
+    /// Shows the menu with the provider's payloads, highlighting the one
+    /// last used for this type of provider. Does nothing if the provider
+    /// has nothing to copy.
+    pub fn show<P: CopyProvider + ?Sized>(&mut self, provider: &P) {
+        let kind = std::any::type_name::<P>();
+        let payloads = provider.copy_payloads();
+        if payloads.is_empty() {
+            return;
+        }
+        self.index = self
+            .last_used
+            .get(kind)
+            .and_then(|label| payloads.iter().position(|payload| &payload.label == label))
+            .unwrap_or(0);
+        self.payloads = payloads;
+        self.source = provider.copy_source();
+        self.kind = kind;
+        self.visible = true;
+    }
+
+    /// Hides the menu.
+    pub fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    /// Returns true if the menu is shown.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Index of the highlighted payload.
+    pub fn selected_index(&self) -> usize {
+        self.index
+    }
+
+    /// Label of the payload last copied from providers of type `P`.
+    pub fn last_used<P: CopyProvider + ?Sized>(&self) -> Option<&str> {
+        self.last_used
+            .get(std::any::type_name::<P>())
+            .map(String::as_str)
+    }
+
+    /// Forgets the remembered payloads, so every provider shows the menu
+    /// on its next [`open`](Self::open).
+    pub fn forget(&mut self) {
+        self.last_used.clear();
+    }
+
+    /// Handles a key press while the menu is shown.
+    ///
+    /// `1`-`9` copy a payload by its position in the list.
+    pub fn handle_key(&mut self, key: &crossterm::event::KeyCode) -> Option<CopyMenuEvent> {
+        if !self.visible {
+            return None;
+        }
+
+        use crossterm::event::KeyCode;
+
+        let len = self.payloads.len();
+        match key {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.hide();
+                Some(CopyMenuEvent::Cancelled)
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                if len > 0 {
+                    self.index = (self.index + 1) % len;
+                }
+                None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                if len > 0 {
+                    self.index = self.index.checked_sub(1).unwrap_or(len - 1);
+                }
+                None
+            }
+            KeyCode::Char(c @ '1'..='9') => {
+                let index = c.to_digit(10)? as usize - 1;
+                let payload = self.payloads.get(index)?.clone();
+                self.hide();
+                Some(self.copy(payload))
+            }
+            KeyCode::Enter => {
+                let payload = self.payloads.get(self.index)?.clone();
+                self.hide();
+                Some(self.copy(payload))
+            }
+            _ => None,
+        }
+    }
+
+    /// Copies `payload`, remembering its label for the current provider
+    /// type. A failure to set the system clipboard is logged; the payload
+    /// is still in the clipboard ring.
+    fn copy(&mut self, payload: CopyPayload) -> CopyMenuEvent {
+        if let Err(e) = clipboard::copy(&payload.text, self.source.clone()) {
+            tracing::warn!("failed to set system clipboard: {e}");
+        }
+        self.last_used.insert(self.kind, payload.label.clone());
+        CopyMenuEvent::Copied(payload)
+    }
+
+    /// Renders the menu centered in `area`.
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        let popup_height = (self.payloads.len() + 4) as u16;
+        let popup_area = Rect {
+            x: area.x + area.width.saturating_sub(self.width) / 2,
+            y: area.y + area.height.saturating_sub(popup_height) / 2,
+            width: self.width.min(area.width),
+            height: popup_height.min(area.height),
+        };
+
+        frame.render_widget(Clear, popup_area);
+
+        let label_width = self
+            .payloads
+            .iter()
+            .map(|payload| payload.label.chars().count())
+            .max()
+            .unwrap_or(0);
+        // Borders, prefix, number, label and the gap after it.
+        let preview_width = (popup_area.width as usize).saturating_sub(label_width + 9);
+        let muted = Style::default().fg(Color::DarkGray);
+        let remembered = self.last_used.get(self.kind);
+
+        let mut lines: Vec<Line> = Vec::new();
+        for (idx, payload) in self.payloads.iter().enumerate() {
+            let is_selected = idx == self.index;
+            let style = if is_selected {
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            let marker = if remembered == Some(&payload.label) {
+                "*"
+            } else {
+                " "
+            };
+            let preview = ClipboardEntry::new(payload.text.as_str(), self.source.clone())
+                .preview(preview_width);
+            lines.push(Line::from(vec![
+                Span::styled(if is_selected { " > " } else { "   " }, style),
+                Span::styled(format!("{}{}", idx + 1, marker), muted),
+                Span::styled(
+                    format!("{:<width$}  ", payload.label, width = label_width),
+                    style,
+                ),
+                Span::styled(preview, muted),
+            ]));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled(" [", muted),
+            Span::styled("j/k", Style::default().fg(Color::Yellow)),
+            Span::styled("] move  [", muted),
+            Span::styled("Enter/1-9", Style::default().fg(Color::Green)),
+            Span::styled("] copy  [", muted),
+            Span::styled("Esc", Style::default().fg(Color::Red)),
+            Span::styled("] cancel", muted),
+        ]));
+
+        let popup = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .title(Span::styled(
+                    format!(" {} ", self.title),
+                    Style::default().add_modifier(Modifier::BOLD),
+                )),
+        );
+        frame.render_widget(popup, popup_area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::KeyCode;
+
+    use super::*;
+
+    struct Tree;
+
+    impl CopyProvider for Tree {
+        fn copy_payloads(&self) -> Vec<CopyPayload> {
+            vec![
+                CopyPayload::new("Relative path", "src/lib.rs"),
+                CopyPayload::new("File name", "lib.rs"),
+            ]
+        }
+    }
+
+    struct Empty;
+
+    impl CopyProvider for Empty {
+        fn copy_payloads(&self) -> Vec<CopyPayload> {
+            Vec::new()
+        }
+    }
+
+    #[test]
+    fn repeat_opens_copy_the_last_used_kind_per_provider_type() {
+        let mut menu = CopyMenu::new();
+        assert_eq!(menu.open(&Empty), None);
+        assert!(!menu.is_visible());
+
+        assert_eq!(menu.open(&Tree), None);
+        assert!(menu.is_visible());
+        menu.handle_key(&KeyCode::Down);
+        assert_eq!(
+            menu.handle_key(&KeyCode::Enter),
+            Some(CopyMenuEvent::Copied(CopyPayload::new(
+                "File name",
+                "lib.rs"
+            )))
+        );
+        assert!(!menu.is_visible());
+        assert_eq!(menu.last_used::<Tree>(), Some("File name"));
+        assert!(clipboard::with_ring(|ring| ring
+            .entries()
+            .any(|entry| entry.text == "lib.rs")));
+
+        // The second press copies without the menu.
+        assert_eq!(
+            menu.open(&Tree),
+            Some(CopyMenuEvent::Copied(CopyPayload::new(
+                "File name",
+                "lib.rs"
+            )))
+        );
+        assert!(!menu.is_visible());
+
+        // Showing the menu highlights the remembered kind.
+        menu.show(&Tree);
+        assert_eq!(menu.selected_index(), 1);
+        assert_eq!(
+            menu.handle_key(&KeyCode::Char('1')),
+            Some(CopyMenuEvent::Copied(CopyPayload::new(
+                "Relative path",
+                "src/lib.rs"
+            )))
+        );
+        assert_eq!(menu.last_used::<Tree>(), Some("Relative path"));
+
+        menu.forget();
+        assert_eq!(menu.open(&Tree), None);
+        assert_eq!(
+            menu.handle_key(&KeyCode::Esc),
+            Some(CopyMenuEvent::Cancelled)
+        );
+    }
+}
@@ -3,6 +3,14 @@ use ratatui::style::{Color, Modifier, Style};
 #[derive(Debug, Clone, Copy)]
 pub struct FileSystemTreeConfig {
     pub show_hidden: bool,
+    /// Skip entries matched by `.gitignore` files.
+    pub respect_gitignore: bool,
+    /// Read size and modification time for each entry while scanning.
+    pub collect_metadata: bool,
+    /// Show a right-aligned size column. Needs `collect_metadata`.
+    pub show_size: bool,
+    /// Expand symlinks to directories like the directories themselves.
+    pub follow_symlinks: bool,
     pub use_dark_theme: bool,
     pub dir_style: Style,
     pub file_style: Style,
@@ -13,6 +21,10 @@ impl Default for FileSystemTreeConfig {
     fn default() -> Self {
         Self {
             show_hidden: false,
+            respect_gitignore: false,
+            collect_metadata: false,
+            show_size: false,
+            follow_symlinks: false,
             use_dark_theme: true,
             dir_style: Style::default().fg(Color::Blue),
             file_style: Style::default().fg(Color::White),
@@ -27,6 +39,41 @@ impl FileSystemTreeConfig {
         self
     }
 
+    /// Skips entries matched by `.gitignore` files while scanning directories
+    /// inside a git repository.
+    pub fn respect_gitignore(mut self, respect: bool) -> Self {
+        self.respect_gitignore = respect;
+        self
+    }
+
+    /// Reads each entry's size and modification time while scanning
+    /// directories.
+    ///
+    /// Off by default to avoid a `stat` call per entry.
+    pub fn collect_metadata(mut self, collect: bool) -> Self {
+        self.collect_metadata = collect;
+        self
+    }
+
+    /// Shows file sizes in a right-aligned column.
+    ///
+    /// Sizes are only known when [`collect_metadata`](Self::collect_metadata)
+    /// is enabled.
+    pub fn show_size(mut self, show: bool) -> Self {
+        self.show_size = show;
+        self
+    }
+
+    /// Lets symlinks to directories be expanded.
+    ///
+    /// Off by default, when every symlink is shown as a leaf. When on, a
+    /// link back to a directory it is already inside is shown as a cycle
+    /// and not expanded, so link loops cannot recurse forever.
+    pub fn follow_symlinks(mut self, follow: bool) -> Self {
+        self.follow_symlinks = follow;
+        self
+    }
+
     pub fn use_dark_theme(mut self, dark: bool) -> Self {
         self.use_dark_theme = dark;
         self
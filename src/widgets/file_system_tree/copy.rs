@@ -0,0 +1,73 @@
+//! Copying the selected entry's paths.
+
+use crate::services::clipboard::{ClipboardSource, CopyPayload, CopyProvider};
+use crate::widgets::file_system_tree::state::FileSystemTreeState;
+use crate::widgets::file_system_tree::widget::FileSystemTree;
+
+/// Offers the selected entry's path relative to the tree root, its
+/// absolute path and its file name.
+impl CopyProvider for (&FileSystemTree<'_>, &FileSystemTreeState) {
+    fn copy_payloads(&self) -> Vec<CopyPayload> {
+        let (tree, state) = *self;
+        let Some(entry) = tree.get_selected_entry(state) else {
+            return Vec::new();
+        };
+
+        let relative = entry
+            .path
+            .strip_prefix(&tree.root_path)
+            .unwrap_or(&entry.path);
+        let absolute = if entry.path.is_absolute() {
+            entry.path.clone()
+        } else {
+            std::env::current_dir()
+                .map(|dir| dir.join(&entry.path))
+                .unwrap_or_else(|_| entry.path.clone())
+        };
+
+        vec![
+            CopyPayload::new("Relative path", relative.display().to_string()),
+            CopyPayload::new("Absolute path", absolute.display().to_string()),
+            CopyPayload::new("File name", entry.name.clone()),
+        ]
+    }
+
+    fn copy_source(&self) -> ClipboardSource {
+        ClipboardSource::Other("file tree".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::tempdir;
+
+    use crate::services::clipboard::CopyProvider;
+    use crate::widgets::file_system_tree::{FileSystemTree, FileSystemTreeState};
+
+    #[test]
+    fn selected_entry_offers_relative_and_absolute_paths() {
+        let dir = tempdir().unwrap();
+        fs::create_dir(dir.path().join("src")).unwrap();
+        fs::write(dir.path().join("src/lib.rs"), "").unwrap();
+
+        let mut tree = FileSystemTree::new(dir.path().to_path_buf()).unwrap();
+        let mut state = FileSystemTreeState::new();
+        assert!((&tree, &state).copy_payloads().is_empty());
+
+        tree.expand_directory(&[0, 0]).unwrap();
+        state.select(vec![0, 0, 0]);
+        let payloads = (&tree, &state).copy_payloads();
+        let texts: Vec<&str> = payloads.iter().map(|p| p.text.as_str()).collect();
+        let absolute = dir.path().join("src").join("lib.rs");
+        assert_eq!(
+            texts,
+            [
+                format!("src{}lib.rs", std::path::MAIN_SEPARATOR).as_str(),
+                absolute.display().to_string().as_str(),
+                "lib.rs",
+            ]
+        );
+    }
+}
@@ -1,4 +1,6 @@
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
 #[derive(Debug, Clone)]
 pub struct FileSystemEntry {
@@ -6,6 +8,18 @@ pub struct FileSystemEntry {
     pub path: PathBuf,
     pub is_dir: bool,
     pub is_hidden: bool,
+    /// Size in bytes, for files whose metadata was collected.
+    pub size: Option<u64>,
+    /// Last modification time, if metadata was collected.
+    pub modified: Option<SystemTime>,
+    /// Where the entry points, as stored in the link, if it is a symlink.
+    pub symlink_target: Option<PathBuf>,
+    /// The canonical path the symlink resolves to. `None` for entries that
+    /// are not symlinks and for broken links.
+    pub resolved_target: Option<PathBuf>,
+    /// Whether the entry is a followed symlink to a directory that is
+    /// already open above it. Such links are not expanded.
+    pub is_cycle: bool,
 }
 
 impl FileSystemEntry {
@@ -19,12 +33,81 @@ impl FileSystemEntry {
 
         let is_dir = path.is_dir();
         let is_hidden = name.starts_with('.');
+        let is_symlink = path.is_symlink();
+        let (symlink_target, resolved_target) = if is_symlink {
+            (fs::read_link(&path).ok(), fs::canonicalize(&path).ok())
+        } else {
+            (None, None)
+        };
 
         Ok(Self {
             name,
             path,
             is_dir,
             is_hidden,
+            size: None,
+            modified: None,
+            symlink_target,
+            resolved_target,
+            is_cycle: false,
+        })
+    }
+
+    /// Whether the entry is a symlink.
+    pub fn is_symlink(&self) -> bool {
+        self.symlink_target.is_some()
+    }
+
+    /// Whether the entry is a symlink whose target does not exist, or that
+    /// loops without reaching a file.
+    pub fn is_broken_link(&self) -> bool {
+        self.is_symlink() && self.resolved_target.is_none()
+    }
+
+    /// The path to open for this entry: the resolved target for symlinks,
+    /// otherwise [`path`](Self::path). Broken links fall back to the link
+    /// itself.
+    pub fn target_path(&self) -> &Path {
+        self.resolved_target.as_deref().unwrap_or(&self.path)
+    }
+
+    /// Fills in [`size`](Self::size) and [`modified`](Self::modified) from
+    /// the file system, following symlinks.
+    ///
+    /// Fields the file system cannot provide, such as for dangling or looping
+    /// symlinks and unreadable entries, are left as `None`.
+    pub fn load_metadata(&mut self) {
+        let Ok(metadata) = fs::metadata(&self.path) else {
+            self.size = None;
+            self.modified = None;
+            return;
+        };
+        self.size = metadata.is_file().then_some(metadata.len());
+        self.modified = metadata.modified().ok();
+    }
+
+    /// Formats [`size`](Self::size) the way `ls -lh` does, e.g. `812`,
+    /// `4.1K` or `12M`.
+    pub fn display_size(&self) -> Option<String> {
+        const UNITS: &[&str] = &["K", "M", "G", "T", "P"];
+        let size = self.size?;
+        if size < 1024 {
+            return Some(size.to_string());
+        }
+
+        let mut value = size as f64;
+        let mut unit = "";
+        for next in UNITS {
+            if value < 1024.0 {
+                break;
+            }
+            value /= 1024.0;
+            unit = next;
+        }
+        Some(if value < 10.0 {
+            format!("{:.1}{}", value, unit)
+        } else {
+            format!("{:.0}{}", value, unit)
         })
     }
 }
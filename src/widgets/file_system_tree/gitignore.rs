@@ -0,0 +1,362 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A single pattern line from a `.gitignore` file.
+#[derive(Debug, Clone)]
+struct Rule {
+    pattern: Vec<char>,
+    negated: bool,
+    dir_only: bool,
+    anchored: bool,
+}
+
+impl Rule {
+    fn parse(line: &str) -> Option<Self> {
+        let line = line.trim_end_matches(['\r', '\n']);
+        let line = trim_unescaped_trailing_spaces(line);
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+
+        let (negated, line) = match line.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None if line.starts_with("\\#") || line.starts_with("\\!") => (false, &line[1..]),
+            None => (false, line),
+        };
+        let (dir_only, line) = match line.strip_suffix('/') {
+            Some(rest) => (true, rest),
+            None => (false, line),
+        };
+        // A slash anywhere but the end anchors the pattern to the directory
+        // holding the `.gitignore`; otherwise it matches at any depth.
+        let anchored = line.contains('/');
+        let line = line.strip_prefix('/').unwrap_or(line);
+        if line.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            pattern: line.chars().collect(),
+            negated,
+            dir_only,
+            anchored,
+        })
+    }
+
+    fn matches(&self, relative: &str, is_dir: bool) -> bool {
+        if self.dir_only && !is_dir {
+            return false;
+        }
+
+        let text: Vec<char> = if self.anchored {
+            relative.chars().collect()
+        } else {
+            relative
+                .rsplit('/')
+                .next()
+                .unwrap_or(relative)
+                .chars()
+                .collect()
+        };
+        glob_match(&self.pattern, &text)
+    }
+}
+
+fn trim_unescaped_trailing_spaces(line: &str) -> &str {
+    let trimmed = line.trim_end_matches(' ');
+    if trimmed.ends_with('\\') && trimmed.len() < line.len() {
+        &line[..trimmed.len() + 1]
+    } else {
+        trimmed
+    }
+}
+
+/// Matches `text` against a gitignore glob.
+///
+/// `*` and `?` never match `/`; `**` matches across directories, and `**/`
+/// also matches zero directories.
+fn glob_match(pattern: &[char], text: &[char]) -> bool {
+    match pattern.first() {
+        None => text.is_empty(),
+        Some('*') if pattern.get(1) == Some(&'*') => {
+            let rest = &pattern[2..];
+            if rest.first() == Some(&'/') && glob_match(&rest[1..], text) {
+                return true;
+            }
+            (0..=text.len()).any(|skip| glob_match(rest, &text[skip..]))
+        }
+        Some('*') => {
+            let rest = &pattern[1..];
+            for skip in 0..=text.len() {
+                if glob_match(rest, &text[skip..]) {
+                    return true;
+                }
+                if text.get(skip) == Some(&'/') {
+                    break;
+                }
+            }
+            false
+        }
+        Some('?') => {
+            matches!(text.first(), Some(c) if *c != '/') && glob_match(&pattern[1..], &text[1..])
+        }
+        Some('[') => match (text.first(), match_class(&pattern[1..], text.first())) {
+            (Some(c), Some((true, len))) if *c != '/' => {
+                glob_match(&pattern[len + 1..], &text[1..])
+            }
+            (_, Some(_)) => false,
+            // An unterminated class is a literal `[`.
+            (_, None) => text.first() == Some(&'[') && glob_match(&pattern[1..], &text[1..]),
+        },
+        Some('\\') if pattern.len() > 1 => {
+            text.first() == Some(&pattern[1]) && glob_match(&pattern[2..], &text[1..])
+        }
+        Some(c) => text.first() == Some(c) && glob_match(&pattern[1..], &text[1..]),
+    }
+}
+
+/// Matches a `[...]` class body; returns whether `c` matched and the length
+/// of the body including the closing `]`, or `None` if it is unterminated.
+fn match_class(body: &[char], c: Option<&char>) -> Option<(bool, usize)> {
+    let (negated, start) = match body.first() {
+        Some('!' | '^') => (true, 1),
+        _ => (false, 0),
+    };
+
+    let mut matched = false;
+    let mut i = start;
+    while i < body.len() {
+        let lo = body[i];
+        if lo == ']' && i > start {
+            return Some((matched != negated, i + 1));
+        }
+        if body.get(i + 1) == Some(&'-') && body.get(i + 2).is_some_and(|hi| *hi != ']') {
+            let hi = body[i + 2];
+            matched |= c.is_some_and(|c| (lo..=hi).contains(c));
+            i += 3;
+        } else {
+            matched |= c == Some(&lo);
+            i += 1;
+        }
+    }
+    None
+}
+
+/// The rules of one `.gitignore` file and the directory it applies to.
+#[derive(Debug, Clone)]
+struct IgnoreFile {
+    base: PathBuf,
+    rules: Vec<Rule>,
+}
+
+/// The `.gitignore` rules that apply inside one directory.
+///
+/// Collected from the directory and its ancestors up to the repository root
+/// (the first ancestor containing `.git`). Deeper files take precedence and
+/// the last matching rule in a file wins, as in git. Outside a repository no
+/// rules apply, also as in git.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Gitignore {
+    files: Vec<IgnoreFile>,
+}
+
+impl Gitignore {
+    pub(crate) fn for_directory(dir: &Path) -> Self {
+        let mut dirs = Vec::new();
+        let mut in_repository = false;
+        for ancestor in dir.ancestors() {
+            dirs.push(ancestor);
+            if ancestor.join(".git").exists() {
+                in_repository = true;
+                break;
+            }
+        }
+        if !in_repository {
+            return Self::default();
+        }
+
+        let files = dirs
+            .into_iter()
+            .rev()
+            .filter_map(|base| {
+                let contents = fs::read_to_string(base.join(".gitignore")).ok()?;
+                Some(Self::parse(base, &contents))
+            })
+            .collect();
+        Self { files }
+    }
+
+    fn parse(base: &Path, contents: &str) -> IgnoreFile {
+        IgnoreFile {
+            base: base.to_path_buf(),
+            rules: contents.lines().filter_map(Rule::parse).collect(),
+        }
+    }
+
+    pub(crate) fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        if is_dir && path.file_name().is_some_and(|name| name == ".git") {
+            return true;
+        }
+
+        for file in self.files.iter().rev() {
+            let Ok(relative) = path.strip_prefix(&file.base) else {
+                continue;
+            };
+            let relative = relative.to_string_lossy().replace('\\', "/");
+            if let Some(rule) = file
+                .rules
+                .iter()
+                .rev()
+                .find(|rule| rule.matches(&relative, is_dir))
+            {
+                return !rule.negated;
+            }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use tempfile::tempdir;
+
+    use super::Gitignore;
+    use crate::widgets::file_system_tree::test_support::names;
+    use crate::widgets::file_system_tree::{
+        FileSystemTree, FileSystemTreeConfig, FileSystemTreeState,
+    };
+
+    fn ignored(rules: &str, path: &str, is_dir: bool) -> bool {
+        let gitignore = Gitignore {
+            files: vec![Gitignore::parse(Path::new("/repo"), rules)],
+        };
+        gitignore.is_ignored(&Path::new("/repo").join(path), is_dir)
+    }
+
+    #[test]
+    fn matches_globs_directories_and_anchors() {
+        assert!(ignored("*.log", "debug.log", false));
+        assert!(ignored("*.log", "nested/deep/debug.log", false));
+        assert!(!ignored("*.log", "debug.log.txt", false));
+        assert!(ignored("target/", "target", true));
+        assert!(!ignored("target/", "target", false));
+        assert!(ignored("/build", "build", true));
+        assert!(!ignored("/build", "src/build", true));
+        assert!(ignored("doc/*.txt", "doc/notes.txt", false));
+        assert!(!ignored("doc/*.txt", "doc/sub/notes.txt", false));
+        assert!(ignored("**/cache", "a/b/cache", true));
+        assert!(ignored("a/**/z", "a/z", false));
+        assert!(ignored("a/**/z", "a/b/c/z", false));
+        assert!(ignored("file[0-9].rs", "file7.rs", false));
+        assert!(!ignored("file[!0-9].rs", "file7.rs", false));
+        assert!(ignored("\\#notes", "#notes", false));
+        assert!(!ignored("# comment\n\n", "# comment", false));
+    }
+
+    #[test]
+    fn later_negation_reincludes() {
+        let rules = "*.log\n!keep.log\n";
+        assert!(ignored(rules, "debug.log", false));
+        assert!(!ignored(rules, "keep.log", false));
+        assert!(ignored("!keep.log\n*.log\n", "keep.log", false));
+    }
+
+    #[test]
+    fn always_ignores_git_directory() {
+        assert!(ignored("", ".git", true));
+    }
+
+    #[test]
+    fn scanning_skips_ignored_entries_with_nested_gitignore() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir(root.join(".git")).unwrap();
+        fs::write(root.join(".gitignore"), "target/\n*.log\n!keep.log\n").unwrap();
+        fs::create_dir(root.join("target")).unwrap();
+        fs::create_dir(root.join("src")).unwrap();
+        fs::write(root.join("debug.log"), "").unwrap();
+        fs::write(root.join("keep.log"), "").unwrap();
+        fs::write(root.join("main.rs"), "").unwrap();
+        fs::write(root.join("src/.gitignore"), "generated.rs\n!trace.log\n").unwrap();
+        fs::write(root.join("src/generated.rs"), "").unwrap();
+        fs::write(root.join("src/lib.rs"), "").unwrap();
+        fs::write(root.join("src/trace.log"), "").unwrap();
+        fs::write(root.join("src/other.log"), "").unwrap();
+
+        let config = FileSystemTreeConfig::default().respect_gitignore(true);
+        let mut tree = FileSystemTree::with_config(root.to_path_buf(), config).unwrap();
+        assert_eq!(names(&tree, &[0]), ["src", "keep.log", "main.rs"]);
+
+        tree.expand_directory(&[0, 0]).unwrap();
+        assert_eq!(names(&tree, &[0, 0]), ["lib.rs", "trace.log"]);
+    }
+
+    #[test]
+    fn applies_no_rules_outside_a_repository() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        fs::write(root.join(".gitignore"), "*.log\n").unwrap();
+        fs::create_dir(root.join("src")).unwrap();
+
+        let gitignore = Gitignore::for_directory(&root.join("src"));
+        assert!(!gitignore.is_ignored(&root.join("src/debug.log"), false));
+
+        fs::create_dir(root.join(".git")).unwrap();
+        let gitignore = Gitignore::for_directory(&root.join("src"));
+        assert!(gitignore.is_ignored(&root.join("src/debug.log"), false));
+    }
+
+    #[test]
+    fn toggling_rescans_expanded_directories() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir(root.join(".git")).unwrap();
+        fs::write(root.join(".gitignore"), "build/\n*.tmp\n").unwrap();
+        fs::create_dir(root.join("build")).unwrap();
+        fs::create_dir(root.join("src")).unwrap();
+        fs::write(root.join("src/a.tmp"), "").unwrap();
+        fs::write(root.join("src/main.rs"), "").unwrap();
+
+        let mut tree = FileSystemTree::new(root.to_path_buf()).unwrap();
+        let mut state = FileSystemTreeState::new();
+        state.expand(vec![0]);
+        tree.expand_directory(&[0, 1]).unwrap();
+        state.expand(vec![0, 1]);
+        state.select(vec![0, 1, 1]);
+        assert_eq!(names(&tree, &[0]), ["build", "src"]);
+
+        assert!(tree.toggle_gitignore(&mut state).unwrap());
+        assert_eq!(names(&tree, &[0]), ["src"]);
+        assert_eq!(names(&tree, &[0, 0]), ["main.rs"]);
+        assert!(state.is_expanded(&[0, 0]));
+        assert_eq!(state.selected_path, Some(vec![0, 0, 0]));
+
+        assert!(!tree.toggle_gitignore(&mut state).unwrap());
+        assert_eq!(names(&tree, &[0]), ["build", "src"]);
+        assert_eq!(names(&tree, &[0, 1]), ["a.tmp", "main.rs"]);
+        assert!(state.is_expanded(&[0, 1]));
+        assert_eq!(state.selected_path, Some(vec![0, 1, 1]));
+    }
+
+    #[test]
+    fn selection_moves_to_parent_when_entry_becomes_ignored() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir(root.join(".git")).unwrap();
+        fs::write(root.join(".gitignore"), "*.tmp\n").unwrap();
+        fs::create_dir(root.join("src")).unwrap();
+        fs::write(root.join("src/a.tmp"), "").unwrap();
+
+        let mut tree = FileSystemTree::new(root.to_path_buf()).unwrap();
+        let mut state = FileSystemTreeState::new();
+        tree.expand_directory(&[0, 0]).unwrap();
+        state.expand(vec![0, 0]);
+        state.select(vec![0, 0, 0]);
+
+        tree.set_respect_gitignore(&mut state, true).unwrap();
+        assert_eq!(state.selected_path, Some(vec![0, 0]));
+    }
+}
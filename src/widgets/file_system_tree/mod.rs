@@ -2,10 +2,17 @@
 //!
 //! A tree view widget for browsing file system directories with:
 //! - Directory and file display with icons
-//! - Expand/collapse directories
+//! - Expand/collapse directories, read from disk on first expansion
 //! - Selection navigation
 //! - Filter mode for searching
 //! - Hidden file filtering
+//! - Optional `.gitignore`-aware filtering
+//! - Optional size and modification time metadata, with a size column
+//! - Symlink targets, with optional cycle-safe following of linked directories
+//! - Create, rename and delete operations
+//! - Refreshing directories from file watcher events
+//! - Copying the selected entry's paths through `CopyProvider` (`clipboard`
+//!   feature)
 //!
 //! # Example
 //!
@@ -18,9 +25,16 @@
 //! ```
 
 mod config;
+#[cfg(feature = "clipboard")]
+mod copy;
 mod entry;
+mod gitignore;
+mod operations;
 mod state;
+#[cfg(test)]
+mod test_support;
 mod tree_node;
+mod watch;
 mod widget;
 
 pub use config::FileSystemTreeConfig;
@@ -0,0 +1,483 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::widgets::file_system_tree::state::FileSystemTreeState;
+use crate::widgets::file_system_tree::tree_node::FileSystemTreeNode;
+use crate::widgets::file_system_tree::widget::FileSystemTree;
+
+fn invalid_input(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message.to_string())
+}
+
+fn validate_name(name: &str) -> io::Result<()> {
+    if name.is_empty() || name == "." || name == ".." {
+        return Err(invalid_input("invalid file name"));
+    }
+    if name.contains('/') || name.contains(std::path::MAIN_SEPARATOR) {
+        return Err(invalid_input("file name must not contain a path separator"));
+    }
+    Ok(())
+}
+
+/// Rewrites index paths in `state` for the children of `parent`.
+///
+/// `map` receives a child's old index and returns its new index, or `None`
+/// when the child was removed (its expanded descendants are dropped).
+fn remap_child_paths(
+    state: &mut FileSystemTreeState,
+    parent: &[usize],
+    map: impl Fn(usize) -> Option<usize>,
+) {
+    let depth = parent.len();
+    let remap = |path: &Vec<usize>| -> Option<Vec<usize>> {
+        if path.len() <= depth || !path.starts_with(parent) {
+            return Some(path.clone());
+        }
+        let mut path = path.clone();
+        path[depth] = map(path[depth])?;
+        Some(path)
+    };
+
+    state.expanded = state.expanded.iter().filter_map(remap).collect();
+    state.selected_path = state.selected_path.as_ref().and_then(remap);
+}
+
+/// Points a node and its loaded descendants at a new location on disk.
+fn relocate(node: &mut FileSystemTreeNode, new_path: PathBuf) {
+    for child in &mut node.children {
+        let child_path = new_path.join(&child.data.name);
+        relocate(child, child_path);
+    }
+    node.data.path = new_path;
+}
+
+impl<'a> FileSystemTree<'a> {
+    pub(crate) fn node_mut(&mut self, path: &[usize]) -> Option<&mut FileSystemTreeNode> {
+        let (first, rest) = path.split_first()?;
+        let mut node = self.nodes.get_mut(*first)?;
+        for &idx in rest {
+            node = node.children.get_mut(idx)?;
+        }
+        Some(node)
+    }
+
+    fn directory_path(&self, path: &[usize]) -> io::Result<PathBuf> {
+        match self.get_entry_at_path(path) {
+            Some(entry) if entry.is_dir => Ok(entry.path.clone()),
+            Some(_) => Err(invalid_input("parent is not a directory")),
+            None => Err(io::Error::new(io::ErrorKind::NotFound, "no such tree node")),
+        }
+    }
+
+    /// Inserts a freshly created entry under `parent` and selects it.
+    ///
+    /// Returns `None` when the entry is hidden by the current config.
+    fn insert_entry(
+        &mut self,
+        state: &mut FileSystemTreeState,
+        parent: &[usize],
+        node: FileSystemTreeNode,
+    ) -> io::Result<Option<Vec<usize>>> {
+        let config = self.config;
+        let parent_node = self
+            .node_mut(parent)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no such tree node"))?;
+
+        let index = if !parent_node.loaded {
+            // The directory was never loaded; read it now so expanding it
+            // later does not show only the new entry.
+            parent_node.children = Self::load_directory(&parent_node.data.path, &config)?;
+            parent_node.loaded = true;
+            parent_node
+                .children
+                .iter()
+                .position(|child| child.data.path == node.data.path)
+        } else if Self::is_excluded(
+            &node.data,
+            &config,
+            Self::gitignore_for(&parent_node.data.path, &config).as_ref(),
+        ) {
+            None
+        } else {
+            let index = parent_node
+                .children
+                .partition_point(|child| Self::compare_nodes(child, &node).is_lt());
+            parent_node.children.insert(index, node);
+            remap_child_paths(state, parent, |i| Some(if i >= index { i + 1 } else { i }));
+            Some(index)
+        };
+
+        let Some(index) = index else {
+            return Ok(None);
+        };
+
+        let mut new_path = parent.to_vec();
+        new_path.push(index);
+        state.expand(parent.to_vec());
+        state.select(new_path.clone());
+        Ok(Some(new_path))
+    }
+
+    /// Creates an empty file named `name` in the directory at `parent`.
+    ///
+    /// Returns the tree path of the new file, or `None` if it is hidden.
+    pub fn create_file(
+        &mut self,
+        state: &mut FileSystemTreeState,
+        parent: &[usize],
+        name: &str,
+    ) -> io::Result<Option<Vec<usize>>> {
+        validate_name(name)?;
+        let path = self.directory_path(parent)?.join(name);
+        fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+
+        let node = FileSystemTreeNode::new(Self::read_entry(path, &self.config)?);
+        self.insert_entry(state, parent, node)
+    }
+
+    /// Creates a directory named `name` in the directory at `parent`.
+    ///
+    /// Returns the tree path of the new directory, or `None` if it is hidden.
+    pub fn create_dir(
+        &mut self,
+        state: &mut FileSystemTreeState,
+        parent: &[usize],
+        name: &str,
+    ) -> io::Result<Option<Vec<usize>>> {
+        validate_name(name)?;
+        let path = self.directory_path(parent)?.join(name);
+        fs::create_dir(&path)?;
+
+        let node = FileSystemTreeNode {
+            data: Self::read_entry(path, &self.config)?,
+            children: Vec::new(),
+            expandable: true,
+            loaded: true,
+        };
+        self.insert_entry(state, parent, node)
+    }
+
+    /// Renames the entry at `path` to `new_name` within the same directory.
+    ///
+    /// Loaded children and expansion state move with a renamed directory.
+    /// Returns the new tree path, or `None` if the new name is hidden or
+    /// ignored by the current config.
+    pub fn rename(
+        &mut self,
+        state: &mut FileSystemTreeState,
+        path: &[usize],
+        new_name: &str,
+    ) -> io::Result<Option<Vec<usize>>> {
+        validate_name(new_name)?;
+        let Some((&old_index, parent)) = path.split_last().filter(|(_, p)| !p.is_empty()) else {
+            return Err(invalid_input("cannot rename the root"));
+        };
+        let old_path = self
+            .get_entry_at_path(path)
+            .map(|entry| entry.path.clone())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no such tree node"))?;
+        let new_path = old_path.with_file_name(new_name);
+        if new_path != old_path && fs::symlink_metadata(&new_path).is_ok() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} already exists", new_path.display()),
+            ));
+        }
+        fs::rename(&old_path, &new_path)?;
+
+        let config = self.config;
+        let Some(parent_node) = self.node_mut(parent) else {
+            return Ok(None);
+        };
+        let mut node = parent_node.children.remove(old_index);
+        node.data.name = new_name.to_string();
+        node.data.is_hidden = new_name.starts_with('.');
+        relocate(&mut node, new_path);
+
+        if Self::is_excluded(
+            &node.data,
+            &config,
+            Self::gitignore_for(&parent_node.data.path, &config).as_ref(),
+        ) {
+            remap_child_paths(state, parent, |i| match i {
+                i if i == old_index => None,
+                i if i > old_index => Some(i - 1),
+                i => Some(i),
+            });
+            self.select_after_removal(state, parent, old_index);
+            return Ok(None);
+        }
+
+        let new_index = parent_node
+            .children
+            .partition_point(|child| Self::compare_nodes(child, &node).is_lt());
+        parent_node.children.insert(new_index, node);
+        remap_child_paths(state, parent, |i| {
+            Some(if i == old_index {
+                new_index
+            } else if old_index < new_index && i > old_index && i <= new_index {
+                i - 1
+            } else if new_index < old_index && i >= new_index && i < old_index {
+                i + 1
+            } else {
+                i
+            })
+        });
+
+        let mut renamed = parent.to_vec();
+        renamed.push(new_index);
+        state.select(renamed.clone());
+        Ok(Some(renamed))
+    }
+
+    /// Deletes the entry at `path`, recursively for directories.
+    ///
+    /// Selects the next sibling afterwards, falling back to the previous
+    /// sibling and then the parent.
+    pub fn delete(&mut self, state: &mut FileSystemTreeState, path: &[usize]) -> io::Result<()> {
+        let Some((&index, parent)) = path.split_last().filter(|(_, p)| !p.is_empty()) else {
+            return Err(invalid_input("cannot delete the root"));
+        };
+        let entry = self
+            .get_entry_at_path(path)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no such tree node"))?;
+        if entry.is_dir {
+            fs::remove_dir_all(&entry.path)?;
+        } else {
+            fs::remove_file(&entry.path)?;
+        }
+
+        if let Some(parent_node) = self.node_mut(parent) {
+            parent_node.children.remove(index);
+        }
+        remap_child_paths(state, parent, |i| match i {
+            i if i == index => None,
+            i if i > index => Some(i - 1),
+            i => Some(i),
+        });
+        self.select_after_removal(state, parent, index);
+        Ok(())
+    }
+
+    fn select_after_removal(
+        &self,
+        state: &mut FileSystemTreeState,
+        parent: &[usize],
+        index: usize,
+    ) {
+        let sibling_count = self
+            .get_children(parent)
+            .map_or(0, |children| children.len());
+
+        let mut selected = parent.to_vec();
+        if index < sibling_count {
+            selected.push(index);
+        } else if sibling_count > 0 {
+            selected.push(sibling_count - 1);
+        }
+        state.select(selected);
+    }
+
+    fn get_children(&self, path: &[usize]) -> Option<&[FileSystemTreeNode]> {
+        let (first, rest) = path.split_first()?;
+        let mut node = self.nodes.get(*first)?;
+        for &idx in rest {
+            node = node.children.get(idx)?;
+        }
+        Some(&node.children)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::tempdir;
+
+    use crate::widgets::file_system_tree::test_support::names;
+    use crate::widgets::file_system_tree::{
+        FileSystemTree, FileSystemTreeConfig, FileSystemTreeState,
+    };
+
+    #[test]
+    fn create_file_inserts_sorted_and_selects() {
+        let dir = tempdir().unwrap();
+        fs::create_dir(dir.path().join("src")).unwrap();
+        fs::write(dir.path().join("a.txt"), "").unwrap();
+        fs::write(dir.path().join("c.txt"), "").unwrap();
+        let mut tree = FileSystemTree::new(dir.path().to_path_buf()).unwrap();
+        let mut state = FileSystemTreeState::new();
+        state.expand(vec![0]);
+        state.expand(vec![0, 0]);
+        state.select(vec![0, 2]);
+
+        let path = tree.create_file(&mut state, &[0], "B.txt").unwrap();
+
+        assert_eq!(path, Some(vec![0, 2]));
+        assert!(dir.path().join("B.txt").is_file());
+        assert_eq!(names(&tree, &[0]), ["src", "a.txt", "B.txt", "c.txt"]);
+        assert_eq!(state.selected_path, Some(vec![0, 2]));
+        assert!(state.is_expanded(&[0, 0]));
+    }
+
+    #[test]
+    fn create_dir_sorts_before_files() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("a.txt"), "").unwrap();
+        let mut tree = FileSystemTree::new(dir.path().to_path_buf()).unwrap();
+        let mut state = FileSystemTreeState::new();
+
+        let path = tree.create_dir(&mut state, &[0], "zeta").unwrap();
+
+        assert_eq!(path, Some(vec![0, 0]));
+        assert!(dir.path().join("zeta").is_dir());
+        assert_eq!(names(&tree, &[0]), ["zeta", "a.txt"]);
+        assert!(state.is_expanded(&[0]));
+    }
+
+    #[test]
+    fn create_respects_hidden_filtering() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("a.txt"), "").unwrap();
+        let mut tree = FileSystemTree::new(dir.path().to_path_buf()).unwrap();
+        let mut state = FileSystemTreeState::new();
+        state.select(vec![0, 0]);
+
+        let path = tree.create_file(&mut state, &[0], ".env").unwrap();
+
+        assert_eq!(path, None);
+        assert!(dir.path().join(".env").is_file());
+        assert_eq!(names(&tree, &[0]), ["a.txt"]);
+        assert_eq!(state.selected_path, Some(vec![0, 0]));
+
+        let config = FileSystemTreeConfig::default().show_hidden(true);
+        let mut tree = FileSystemTree::with_config(dir.path().to_path_buf(), config).unwrap();
+        let path = tree.create_file(&mut state, &[0], ".gitignore").unwrap();
+        assert_eq!(path, Some(vec![0, 1]));
+        assert_eq!(names(&tree, &[0]), [".env", ".gitignore", "a.txt"]);
+    }
+
+    #[test]
+    fn create_in_unloaded_directory_loads_siblings() {
+        let dir = tempdir().unwrap();
+        fs::create_dir(dir.path().join("sub")).unwrap();
+        fs::write(dir.path().join("sub/existing.txt"), "").unwrap();
+        let mut tree = FileSystemTree::new(dir.path().to_path_buf()).unwrap();
+        let mut state = FileSystemTreeState::new();
+
+        let path = tree.create_file(&mut state, &[0, 0], "new.txt").unwrap();
+
+        assert_eq!(path, Some(vec![0, 0, 1]));
+        assert_eq!(names(&tree, &[0, 0]), ["existing.txt", "new.txt"]);
+    }
+
+    #[test]
+    fn create_rejects_invalid_names_and_existing_entries() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("a.txt"), "").unwrap();
+        let mut tree = FileSystemTree::new(dir.path().to_path_buf()).unwrap();
+        let mut state = FileSystemTreeState::new();
+
+        let err = tree.create_file(&mut state, &[0], "x/y").unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+        let err = tree.create_file(&mut state, &[0], "a.txt").unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::AlreadyExists);
+        let err = tree.create_dir(&mut state, &[0, 0], "sub").unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn rename_expanded_directory_moves_children_and_expansion() {
+        let dir = tempdir().unwrap();
+        fs::create_dir(dir.path().join("alpha")).unwrap();
+        fs::create_dir(dir.path().join("beta")).unwrap();
+        fs::create_dir(dir.path().join("beta/inner")).unwrap();
+        fs::write(dir.path().join("beta/inner/file.rs"), "").unwrap();
+        let mut tree = FileSystemTree::new(dir.path().to_path_buf()).unwrap();
+        let mut state = FileSystemTreeState::new();
+        state.expand(vec![0]);
+        tree.expand_directory(&[0, 1]).unwrap();
+        state.expand(vec![0, 1]);
+        tree.expand_directory(&[0, 1, 0]).unwrap();
+        state.expand(vec![0, 1, 0]);
+        state.expand(vec![0, 0]);
+
+        let path = tree.rename(&mut state, &[0, 1], "aardvark").unwrap();
+
+        assert_eq!(path, Some(vec![0, 0]));
+        assert_eq!(names(&tree, &[0]), ["aardvark", "alpha"]);
+        assert!(state.is_expanded(&[0, 0]));
+        assert!(state.is_expanded(&[0, 0, 0]));
+        assert!(state.is_expanded(&[0, 1]));
+        assert_eq!(state.selected_path, Some(vec![0, 0]));
+
+        let file = tree.get_entry_at_path(&[0, 0, 0, 0]).unwrap();
+        assert_eq!(file.path, dir.path().join("aardvark/inner/file.rs"));
+        assert!(file.path.is_file());
+        assert!(!dir.path().join("beta").exists());
+    }
+
+    #[test]
+    fn rename_refuses_to_overwrite() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("a.txt"), "a").unwrap();
+        fs::write(dir.path().join("b.txt"), "b").unwrap();
+        let mut tree = FileSystemTree::new(dir.path().to_path_buf()).unwrap();
+        let mut state = FileSystemTreeState::new();
+
+        let err = tree.rename(&mut state, &[0, 0], "b.txt").unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::AlreadyExists);
+        assert_eq!(fs::read_to_string(dir.path().join("b.txt")).unwrap(), "b");
+    }
+
+    #[test]
+    fn rename_to_ignored_name_drops_out_of_tree() {
+        let dir = tempdir().unwrap();
+        fs::create_dir(dir.path().join(".git")).unwrap();
+        fs::write(dir.path().join(".gitignore"), "*.log\n").unwrap();
+        fs::write(dir.path().join("a.txt"), "").unwrap();
+        fs::write(dir.path().join("b.txt"), "").unwrap();
+        let config = FileSystemTreeConfig::default().respect_gitignore(true);
+        let mut tree = FileSystemTree::with_config(dir.path().to_path_buf(), config).unwrap();
+        let mut state = FileSystemTreeState::new();
+        state.select(vec![0, 0]);
+
+        let path = tree.rename(&mut state, &[0, 0], "a.log").unwrap();
+
+        assert_eq!(path, None);
+        assert!(dir.path().join("a.log").is_file());
+        assert_eq!(names(&tree, &[0]), ["b.txt"]);
+        assert_eq!(state.selected_path, Some(vec![0, 0]));
+    }
+
+    #[test]
+    fn delete_selects_next_sibling_then_previous() {
+        let dir = tempdir().unwrap();
+        fs::create_dir(dir.path().join("dir")).unwrap();
+        fs::write(dir.path().join("dir/nested.txt"), "").unwrap();
+        fs::write(dir.path().join("a.txt"), "").unwrap();
+        fs::write(dir.path().join("b.txt"), "").unwrap();
+        let mut tree = FileSystemTree::new(dir.path().to_path_buf()).unwrap();
+        let mut state = FileSystemTreeState::new();
+        state.expand(vec![0]);
+        tree.expand_directory(&[0, 0]).unwrap();
+        state.expand(vec![0, 0]);
+
+        tree.delete(&mut state, &[0, 0]).unwrap();
+        assert!(!dir.path().join("dir").exists());
+        assert_eq!(names(&tree, &[0]), ["a.txt", "b.txt"]);
+        assert!(!state.is_expanded(&[0, 0]));
+        assert_eq!(state.selected_path, Some(vec![0, 0]));
+
+        tree.delete(&mut state, &[0, 1]).unwrap();
+        assert_eq!(state.selected_path, Some(vec![0, 0]));
+
+        tree.delete(&mut state, &[0, 0]).unwrap();
+        assert_eq!(state.selected_path, Some(vec![0]));
+        assert!(tree.delete(&mut state, &[0]).is_err());
+    }
+}
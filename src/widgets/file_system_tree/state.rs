@@ -9,6 +9,8 @@ pub struct FileSystemTreeState {
     pub offset: usize,
     pub filter: Option<String>,
     pub filter_mode: bool,
+    /// Directories queued by [`refresh_node`](Self::refresh_node).
+    pub(crate) pending_refresh: HashSet<Vec<usize>>,
 }
 
 impl FileSystemTreeState {
@@ -57,6 +59,16 @@ impl FileSystemTreeState {
         }
     }
 
+    /// Queues the directory at `path` to be re-read from disk.
+    ///
+    /// A loaded directory otherwise keeps its children across collapse and
+    /// expand. The tree applies queued refreshes in
+    /// [`FileSystemTree::apply_refreshes`](crate::widgets::file_system_tree::FileSystemTree::apply_refreshes),
+    /// which also runs before the selection is expanded.
+    pub fn refresh_node(&mut self, path: Vec<usize>) {
+        self.pending_refresh.insert(path);
+    }
+
     pub fn collapse_all(&mut self) {
         self.expanded.clear();
     }
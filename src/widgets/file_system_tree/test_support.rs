@@ -0,0 +1,15 @@
+//! Helpers shared by the file system tree tests.
+
+use crate::widgets::file_system_tree::FileSystemTree;
+
+/// Names of the loaded children of the node at `path`, in display order.
+pub(crate) fn names(tree: &FileSystemTree, path: &[usize]) -> Vec<String> {
+    let mut node = &tree.nodes[path[0]];
+    for &idx in &path[1..] {
+        node = &node.children[idx];
+    }
+    node.children
+        .iter()
+        .map(|child| child.data.name.clone())
+        .collect()
+}
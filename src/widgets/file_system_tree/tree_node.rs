@@ -5,6 +5,9 @@ pub struct FileSystemTreeNode {
     pub data: FileSystemEntry,
     pub children: Vec<FileSystemTreeNode>,
     pub expandable: bool,
+    /// Whether `children` has been read from disk. Directories are read
+    /// when first expanded and keep their children until refreshed.
+    pub loaded: bool,
 }
 
 impl FileSystemTreeNode {
@@ -13,6 +16,7 @@ impl FileSystemTreeNode {
             data,
             children: Vec::new(),
             expandable: false,
+            loaded: false,
         }
     }
 
@@ -22,6 +26,7 @@ impl FileSystemTreeNode {
             data,
             children,
             expandable,
+            loaded: true,
         }
     }
 
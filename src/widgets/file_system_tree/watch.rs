@@ -0,0 +1,362 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "file-watcher")]
+use crate::services::file_watcher::FileWatcher;
+use crate::widgets::file_system_tree::config::FileSystemTreeConfig;
+use crate::widgets::file_system_tree::state::FileSystemTreeState;
+use crate::widgets::file_system_tree::tree_node::FileSystemTreeNode;
+use crate::widgets::file_system_tree::widget::FileSystemTree;
+
+/// Changed paths held back until a burst of events settles.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PendingFsEvents {
+    paths: HashSet<PathBuf>,
+    last_event: Option<Instant>,
+}
+
+impl PendingFsEvents {
+    fn push(&mut self, paths: &[PathBuf], now: Instant) {
+        if paths.is_empty() {
+            return;
+        }
+        self.paths.extend(paths.iter().cloned());
+        self.last_event = Some(now);
+    }
+
+    /// Takes the queued paths once no event has arrived for `quiet`.
+    fn take_settled(&mut self, now: Instant, quiet: Duration) -> Option<Vec<PathBuf>> {
+        let last_event = self.last_event?;
+        if now.saturating_duration_since(last_event) < quiet {
+            return None;
+        }
+        self.last_event = None;
+        Some(self.paths.drain().collect())
+    }
+}
+
+/// Re-reads the listing of `node`, keeping the loaded subtrees of child
+/// directories that still exist.
+fn rescan_children(node: &mut FileSystemTreeNode, config: &FileSystemTreeConfig) -> io::Result<()> {
+    let fresh = FileSystemTree::load_directory(&node.data.path, config)?;
+    let mut previous: HashMap<PathBuf, FileSystemTreeNode> = mem::take(&mut node.children)
+        .into_iter()
+        .filter(|child| child.data.is_dir)
+        .map(|child| (child.data.path.clone(), child))
+        .collect();
+
+    node.children = fresh;
+    node.loaded = true;
+    for child in &mut node.children {
+        let is_dir = child.data.is_dir;
+        if let Some(old) = previous.remove(&child.data.path).filter(|_| is_dir) {
+            child.children = old.children;
+            child.loaded = old.loaded;
+        }
+    }
+    Ok(())
+}
+
+impl<'a> FileSystemTree<'a> {
+    /// Finds the loaded node for `path`, which may be given relative to the
+    /// root as the tree was created or in canonical form.
+    fn index_path_of(&self, path: &Path) -> Option<Vec<usize>> {
+        self.nodes.iter().enumerate().find_map(|(root_idx, root)| {
+            let relative = path.strip_prefix(&root.data.path).ok().or_else(|| {
+                let canonical = fs::canonicalize(&root.data.path).ok()?;
+                path.strip_prefix(canonical).ok()
+            })?;
+
+            let mut index_path = vec![root_idx];
+            let mut node = root;
+            for component in relative.components() {
+                let idx = node
+                    .children
+                    .iter()
+                    .position(|child| child.data.path.file_name() == Some(component.as_os_str()))?;
+                index_path.push(idx);
+                node = &node.children[idx];
+            }
+            Some(index_path)
+        })
+    }
+
+    /// Refreshes the directories affected by a batch of changed paths, such
+    /// as those reported by a file watcher.
+    ///
+    /// Each changed path refreshes the listing of its parent directory, once
+    /// per batch however many events name it. The root and expanded
+    /// directories are re-read, keeping the loaded subtrees of child
+    /// directories that still exist. A collapsed directory only drops its
+    /// cached children so it is re-read when next expanded, and paths under
+    /// directories that were never loaded are ignored. Expansion and
+    /// selection follow their entries by path.
+    ///
+    /// Returns the directories that were re-read.
+    pub fn apply_fs_events(
+        &mut self,
+        state: &mut FileSystemTreeState,
+        paths: &[PathBuf],
+    ) -> io::Result<Vec<PathBuf>> {
+        let dirs: Vec<PathBuf> = paths
+            .iter()
+            .filter_map(|path| path.parent())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .map(Path::to_path_buf)
+            .collect();
+        self.refresh_directories(state, dirs)
+    }
+
+    /// Re-reads the directories queued with
+    /// [`FileSystemTreeState::refresh_node`].
+    ///
+    /// The root and expanded directories are re-read now; a collapsed
+    /// directory drops its cached children and is re-read when next
+    /// expanded. Returns the directories that were re-read.
+    pub fn apply_refreshes(&mut self, state: &mut FileSystemTreeState) -> io::Result<Vec<PathBuf>> {
+        let dirs: Vec<PathBuf> = mem::take(&mut state.pending_refresh)
+            .iter()
+            .filter_map(|path| self.get_entry_at_path(path))
+            .filter(|entry| entry.is_dir)
+            .map(|entry| entry.path.clone())
+            .collect();
+        self.refresh_directories(state, dirs)
+    }
+
+    /// Refreshes the listings of `dirs`, as described on
+    /// [`apply_fs_events`](Self::apply_fs_events).
+    fn refresh_directories(
+        &mut self,
+        state: &mut FileSystemTreeState,
+        mut dirs: Vec<PathBuf>,
+    ) -> io::Result<Vec<PathBuf>> {
+        if dirs.is_empty() {
+            return Ok(Vec::new());
+        }
+        // Parents first, so a deleted directory is gone before its own
+        // events are looked at.
+        dirs.sort_by_key(|dir| dir.components().count());
+
+        let (expanded, selected) = self.state_paths(state);
+        let config = self.config;
+        let mut rescanned = Vec::new();
+        let mut changed = false;
+
+        for dir in dirs {
+            let Some(index_path) = self.index_path_of(&dir) else {
+                continue;
+            };
+            let is_root = index_path.len() == 1;
+            let Some(node) = self.node_mut(&index_path) else {
+                continue;
+            };
+            if !node.data.is_dir {
+                continue;
+            }
+
+            if is_root || expanded.contains(&node.data.path) {
+                match rescan_children(node, &config) {
+                    Ok(()) => rescanned.push(node.data.path.clone()),
+                    // Removed along with its parent; that event covers it.
+                    Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+                    Err(err) => return Err(err),
+                }
+                changed = true;
+            } else if node.loaded {
+                node.children.clear();
+                node.loaded = false;
+                changed = true;
+            }
+        }
+
+        if changed {
+            self.restore_state_paths(state, &expanded, selected);
+        }
+        Ok(rescanned)
+    }
+
+    /// Queues changed paths to be applied by
+    /// [`flush_fs_events`](Self::flush_fs_events) once the burst settles.
+    pub fn queue_fs_events(&mut self, paths: &[PathBuf]) {
+        self.pending_fs_events.push(paths, Instant::now());
+    }
+
+    /// Applies the queued paths with [`apply_fs_events`](Self::apply_fs_events)
+    /// once no new path has been queued for `quiet`.
+    ///
+    /// Returns the directories that were re-read, or an empty list while
+    /// events are still arriving.
+    pub fn flush_fs_events(
+        &mut self,
+        state: &mut FileSystemTreeState,
+        quiet: Duration,
+    ) -> io::Result<Vec<PathBuf>> {
+        self.flush_fs_events_at(state, Instant::now(), quiet)
+    }
+
+    fn flush_fs_events_at(
+        &mut self,
+        state: &mut FileSystemTreeState,
+        now: Instant,
+        quiet: Duration,
+    ) -> io::Result<Vec<PathBuf>> {
+        match self.pending_fs_events.take_settled(now, quiet) {
+            Some(paths) => self.apply_fs_events(state, &paths),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Pulls changes from `watcher` and refreshes the affected directories,
+    /// debounced by the watcher's configured interval.
+    ///
+    /// Call this on every tick with a watcher from
+    /// [`FileWatcher::for_directory`] watching the tree root. Returns the
+    /// directories that were re-read.
+    #[cfg(feature = "file-watcher")]
+    pub fn sync_with_watcher(
+        &mut self,
+        state: &mut FileSystemTreeState,
+        watcher: &mut FileWatcher,
+    ) -> io::Result<Vec<PathBuf>> {
+        if watcher.check_for_changes() {
+            self.queue_fs_events(&watcher.get_changed_paths());
+        }
+        self.flush_fs_events(state, Duration::from_millis(watcher.config.debounce_ms))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+    use std::time::{Duration, Instant};
+
+    use tempfile::tempdir;
+
+    use crate::widgets::file_system_tree::test_support::names;
+    use crate::widgets::file_system_tree::{FileSystemTree, FileSystemTreeState};
+
+    /// Tags a node so a test can tell whether it survived a rescan; nodes
+    /// read from disk are never tagged.
+    fn mark(tree: &mut FileSystemTree, path: &[usize]) {
+        tree.node_mut(path).unwrap().data.name.push('*');
+    }
+
+    fn is_marked(tree: &FileSystemTree, path: &[usize]) -> bool {
+        tree.get_entry_at_path(path).unwrap().name.ends_with('*')
+    }
+
+    fn fixture(root: &Path) -> (FileSystemTree<'static>, FileSystemTreeState) {
+        for dir in ["a", "b", "c", "d/deep"] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        fs::write(root.join("a/x.txt"), "").unwrap();
+        fs::write(root.join("b/y.txt"), "").unwrap();
+        fs::write(root.join("c/z.txt"), "").unwrap();
+
+        let mut tree = FileSystemTree::new(root.to_path_buf()).unwrap();
+        let mut state = FileSystemTreeState::new();
+        state.expand(vec![0]);
+        for idx in 0..3 {
+            tree.expand_directory(&[0, idx]).unwrap();
+        }
+        // a and b are expanded; c was loaded, then collapsed; d was never opened.
+        state.expand(vec![0, 0]);
+        state.expand(vec![0, 1]);
+        (tree, state)
+    }
+
+    #[test]
+    fn rescans_only_directories_with_changes() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        let (mut tree, mut state) = fixture(root);
+        mark(&mut tree, &[0, 0, 0]);
+        mark(&mut tree, &[0, 1, 0]);
+        state.select(vec![0, 1, 0]);
+
+        fs::write(root.join("a/new.txt"), "").unwrap();
+        fs::write(root.join("c/new.txt"), "").unwrap();
+        fs::write(root.join("d/deep/new.txt"), "").unwrap();
+        let events = [
+            root.join("a/new.txt"),
+            root.join("a/new.txt"),
+            root.join("a/x.txt"),
+            root.join("c/new.txt"),
+            root.join("d/deep/new.txt"),
+        ];
+
+        let rescanned = tree.apply_fs_events(&mut state, &events).unwrap();
+        assert_eq!(rescanned, [root.join("a")]);
+        assert_eq!(names(&tree, &[0, 0]), ["new.txt", "x.txt"]);
+        assert!(!is_marked(&tree, &[0, 0, 1]));
+        assert!(is_marked(&tree, &[0, 1, 0]));
+        assert!(names(&tree, &[0, 2]).is_empty());
+        assert!(names(&tree, &[0, 3]).is_empty());
+        assert!(state.is_expanded(&[0, 0]) && state.is_expanded(&[0, 1]));
+        assert_eq!(state.selected_path, Some(vec![0, 1, 0]));
+
+        // The collapsed directory reads its new listing when expanded again.
+        tree.expand_directory(&[0, 2]).unwrap();
+        assert_eq!(names(&tree, &[0, 2]), ["new.txt", "z.txt"]);
+    }
+
+    #[test]
+    fn root_rescan_keeps_loaded_subtrees_and_drops_deleted_ones() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        let (mut tree, mut state) = fixture(root);
+        mark(&mut tree, &[0, 1, 0]);
+        state.select(vec![0, 0, 0]);
+
+        fs::remove_dir_all(root.join("a")).unwrap();
+        fs::write(root.join("top.txt"), "").unwrap();
+        let events = [root.join("a/x.txt"), root.join("a"), root.join("top.txt")];
+
+        let rescanned = tree.apply_fs_events(&mut state, &events).unwrap();
+        assert_eq!(rescanned, [root.to_path_buf()]);
+        assert_eq!(names(&tree, &[0]), ["b", "c", "d", "top.txt"]);
+        assert!(is_marked(&tree, &[0, 0, 0]));
+        assert!(state.is_expanded(&[0, 0]));
+        assert!(!state.is_expanded(&[0, 1]));
+        assert_eq!(state.selected_path, Some(vec![0]));
+    }
+
+    #[test]
+    fn burst_of_events_is_applied_once_settled() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        let (mut tree, mut state) = fixture(root);
+        let quiet = Duration::from_millis(100);
+        let start = Instant::now();
+
+        fs::write(root.join("a/one.txt"), "").unwrap();
+        tree.pending_fs_events
+            .push(&[root.join("a/one.txt")], start);
+        fs::write(root.join("a/two.txt"), "").unwrap();
+        tree.pending_fs_events
+            .push(&[root.join("a/two.txt")], start + Duration::from_millis(60));
+
+        let early = start + Duration::from_millis(120);
+        assert!(tree
+            .flush_fs_events_at(&mut state, early, quiet)
+            .unwrap()
+            .is_empty());
+        assert_eq!(names(&tree, &[0, 0]), ["x.txt"]);
+
+        let settled = start + Duration::from_millis(160);
+        let rescanned = tree.flush_fs_events_at(&mut state, settled, quiet).unwrap();
+        assert_eq!(rescanned, [root.join("a")]);
+        assert_eq!(names(&tree, &[0, 0]), ["one.txt", "two.txt", "x.txt"]);
+
+        assert!(tree
+            .flush_fs_events_at(&mut state, settled, quiet)
+            .unwrap()
+            .is_empty());
+    }
+}
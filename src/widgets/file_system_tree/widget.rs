@@ -1,5 +1,6 @@
+use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
@@ -9,8 +10,10 @@ use ratatui::widgets::{Block, Widget};
 
 use crate::widgets::file_system_tree::config::FileSystemTreeConfig;
 use crate::widgets::file_system_tree::entry::FileSystemEntry;
+use crate::widgets::file_system_tree::gitignore::Gitignore;
 use crate::widgets::file_system_tree::state::FileSystemTreeState;
 use crate::widgets::file_system_tree::tree_node::FileSystemTreeNode;
+use crate::widgets::file_system_tree::watch::PendingFsEvents;
 use devicons::{icon_for_file, Theme as DevIconTheme};
 
 fn parse_hex_color(color: &str) -> Option<Color> {
@@ -25,6 +28,9 @@ fn parse_hex_color(color: &str) -> Option<Color> {
     Some(Color::Rgb(r, g, b))
 }
 
+const SYMLINK_FILE_ICON: char = '\u{f481}';
+const SYMLINK_DIR_ICON: char = '\u{f482}';
+
 fn yazi_dir_icon_color() -> Color {
     parse_hex_color("#03a9f4").unwrap_or(Color::Blue)
 }
@@ -35,18 +41,20 @@ pub struct FileSystemTree<'a> {
     pub nodes: Vec<FileSystemTreeNode>,
     pub config: FileSystemTreeConfig,
     pub block: Option<Block<'a>>,
+    pub(crate) pending_fs_events: PendingFsEvents,
 }
 
 impl<'a> FileSystemTree<'a> {
     pub fn new(root_path: std::path::PathBuf) -> std::io::Result<Self> {
         let config = FileSystemTreeConfig::default();
-        let root_entry = FileSystemEntry::new(root_path.clone())?;
+        let root_entry = Self::read_entry(root_path.clone(), &config)?;
         let root_children = if root_entry.is_dir {
             Self::load_directory(&root_path, &config)?
         } else {
             Vec::new()
         };
         let nodes = vec![FileSystemTreeNode {
+            loaded: root_entry.is_dir,
             data: root_entry,
             children: root_children,
             expandable: root_path.is_dir(),
@@ -57,6 +65,7 @@ impl<'a> FileSystemTree<'a> {
             nodes,
             config,
             block: None,
+            pending_fs_events: PendingFsEvents::default(),
         })
     }
 
@@ -64,13 +73,14 @@ impl<'a> FileSystemTree<'a> {
         root_path: std::path::PathBuf,
         config: FileSystemTreeConfig,
     ) -> std::io::Result<Self> {
-        let root_entry = FileSystemEntry::new(root_path.clone())?;
+        let root_entry = Self::read_entry(root_path.clone(), &config)?;
         let root_children = if root_entry.is_dir {
             Self::load_directory(&root_path, &config)?
         } else {
             Vec::new()
         };
         let nodes = vec![FileSystemTreeNode {
+            loaded: root_entry.is_dir,
             data: root_entry,
             children: root_children,
             expandable: root_path.is_dir(),
@@ -81,32 +91,54 @@ impl<'a> FileSystemTree<'a> {
             nodes,
             config,
             block: None,
+            pending_fs_events: PendingFsEvents::default(),
         })
     }
 
-    fn load_directory(
+    pub(crate) fn load_directory(
         path: &Path,
         config: &FileSystemTreeConfig,
     ) -> std::io::Result<Vec<FileSystemTreeNode>> {
         let mut entries = Vec::new();
+        let gitignore = Self::gitignore_for(path, config);
+        let mut open_dirs: Option<HashSet<PathBuf>> = None;
 
         let read_dir = fs::read_dir(path)?;
 
         for entry in read_dir {
             let entry = entry?;
-            let path = entry.path();
-
-            let fs_entry = FileSystemEntry::new(path.clone())?;
+            let mut fs_entry = FileSystemEntry::new(entry.path())?;
 
-            if fs_entry.is_hidden && !config.show_hidden {
+            // Filters see the link itself, not what it points to.
+            if Self::is_excluded(&fs_entry, config, gitignore.as_ref()) {
                 continue;
             }
+            Self::apply_symlink_policy(&mut fs_entry, config);
+            if fs_entry.is_dir && fs_entry.is_symlink() {
+                let open_dirs = open_dirs.get_or_insert_with(|| {
+                    path.ancestors()
+                        .filter_map(|dir| fs::canonicalize(dir).ok())
+                        .collect()
+                });
+                if fs_entry
+                    .resolved_target
+                    .as_ref()
+                    .is_some_and(|target| open_dirs.contains(target))
+                {
+                    fs_entry.is_cycle = true;
+                    fs_entry.is_dir = false;
+                }
+            }
+            if config.collect_metadata {
+                fs_entry.load_metadata();
+            }
 
             let node = if fs_entry.is_dir {
                 FileSystemTreeNode {
                     data: fs_entry,
                     children: Vec::new(),
                     expandable: true,
+                    loaded: false,
                 }
             } else {
                 FileSystemTreeNode::new(fs_entry)
@@ -115,13 +147,57 @@ impl<'a> FileSystemTree<'a> {
             entries.push(node);
         }
 
-        entries.sort_by(|a, b| match (a.data.is_dir, b.data.is_dir) {
+        entries.sort_by(Self::compare_nodes);
+
+        Ok(entries)
+    }
+
+    /// Reads the entry at `path`, with metadata if the config collects it.
+    pub(crate) fn read_entry(
+        path: PathBuf,
+        config: &FileSystemTreeConfig,
+    ) -> std::io::Result<FileSystemEntry> {
+        let mut entry = FileSystemEntry::new(path)?;
+        Self::apply_symlink_policy(&mut entry, config);
+        if config.collect_metadata {
+            entry.load_metadata();
+        }
+        Ok(entry)
+    }
+
+    /// Makes symlinks leaves unless the config follows them.
+    fn apply_symlink_policy(entry: &mut FileSystemEntry, config: &FileSystemTreeConfig) {
+        if entry.is_symlink() && !config.follow_symlinks {
+            entry.is_dir = false;
+        }
+    }
+
+    pub(crate) fn gitignore_for(dir: &Path, config: &FileSystemTreeConfig) -> Option<Gitignore> {
+        config
+            .respect_gitignore
+            .then(|| Gitignore::for_directory(dir))
+    }
+
+    pub(crate) fn is_excluded(
+        entry: &FileSystemEntry,
+        config: &FileSystemTreeConfig,
+        gitignore: Option<&Gitignore>,
+    ) -> bool {
+        // Like git, treat a symlink as a file whatever it points to.
+        let is_dir = entry.is_dir && !entry.is_symlink();
+        (entry.is_hidden && !config.show_hidden)
+            || gitignore.is_some_and(|gitignore| gitignore.is_ignored(&entry.path, is_dir))
+    }
+
+    pub(crate) fn compare_nodes(
+        a: &FileSystemTreeNode,
+        b: &FileSystemTreeNode,
+    ) -> std::cmp::Ordering {
+        match (a.data.is_dir, b.data.is_dir) {
             (true, false) => std::cmp::Ordering::Less,
             (false, true) => std::cmp::Ordering::Greater,
             _ => a.data.name.to_lowercase().cmp(&b.data.name.to_lowercase()),
-        });
-
-        Ok(entries)
+        }
     }
 
     pub fn expand_directory(&mut self, path: &[usize]) -> std::io::Result<()> {
@@ -136,8 +212,9 @@ impl<'a> FileSystemTree<'a> {
 
             if path.len() == 1 {
                 if let Some(node) = nodes.get_mut(path[0]) {
-                    if node.data.is_dir && node.children.is_empty() {
+                    if node.data.is_dir && !node.loaded {
                         node.children = FileSystemTree::load_directory(&node.data.path, config)?;
+                        node.loaded = true;
                     }
                 }
                 return Ok(());
@@ -153,6 +230,115 @@ impl<'a> FileSystemTree<'a> {
         find_and_expand(&mut self.nodes, path, &self.config)
     }
 
+    /// Re-reads every loaded directory from disk with the current config.
+    ///
+    /// Expanded directories are reloaded recursively and keep their expansion;
+    /// the selection follows its entry, or moves to the nearest ancestor that
+    /// is still listed.
+    pub fn reload(&mut self, state: &mut FileSystemTreeState) -> std::io::Result<()> {
+        fn reload_children(
+            node: &mut FileSystemTreeNode,
+            expanded: &HashSet<PathBuf>,
+            config: &FileSystemTreeConfig,
+        ) -> std::io::Result<()> {
+            node.children = FileSystemTree::load_directory(&node.data.path, config)?;
+            node.loaded = true;
+            for child in &mut node.children {
+                if child.data.is_dir && expanded.contains(&child.data.path) {
+                    reload_children(child, expanded, config)?;
+                }
+            }
+            Ok(())
+        }
+
+        let (expanded, selected) = self.state_paths(state);
+
+        let config = self.config;
+        for root in &mut self.nodes {
+            if root.data.is_dir {
+                reload_children(root, &expanded, &config)?;
+            }
+        }
+
+        self.restore_state_paths(state, &expanded, selected);
+        Ok(())
+    }
+
+    /// Returns the filesystem paths of the expanded and selected nodes, so
+    /// they can be restored after the tree changes shape.
+    pub(crate) fn state_paths(
+        &self,
+        state: &FileSystemTreeState,
+    ) -> (HashSet<PathBuf>, Option<PathBuf>) {
+        let expanded = state
+            .expanded
+            .iter()
+            .filter_map(|path| self.get_entry_at_path(path))
+            .map(|entry| entry.path.clone())
+            .collect();
+        let selected = self
+            .get_selected_entry(state)
+            .map(|entry| entry.path.clone());
+        (expanded, selected)
+    }
+
+    /// Maps paths saved by [`state_paths`](Self::state_paths) back onto tree
+    /// indices. The selection falls back to its nearest listed ancestor.
+    pub(crate) fn restore_state_paths(
+        &self,
+        state: &mut FileSystemTreeState,
+        expanded: &HashSet<PathBuf>,
+        selected: Option<PathBuf>,
+    ) {
+        let mut index_paths = HashMap::new();
+        let mut current = Vec::new();
+        Self::collect_index_paths(&self.nodes, &mut current, &mut index_paths);
+
+        state.expanded = expanded
+            .iter()
+            .filter_map(|path| index_paths.get(path).cloned())
+            .collect();
+        state.selected_path = selected.and_then(|selected| {
+            selected
+                .ancestors()
+                .find_map(|path| index_paths.get(path).cloned())
+        });
+    }
+
+    fn collect_index_paths(
+        nodes: &[FileSystemTreeNode],
+        current: &mut Vec<usize>,
+        index_paths: &mut HashMap<PathBuf, Vec<usize>>,
+    ) {
+        for (idx, node) in nodes.iter().enumerate() {
+            current.push(idx);
+            index_paths.insert(node.data.path.clone(), current.clone());
+            Self::collect_index_paths(&node.children, current, index_paths);
+            current.pop();
+        }
+    }
+
+    /// Turns `.gitignore` filtering on or off and rescans loaded directories.
+    pub fn set_respect_gitignore(
+        &mut self,
+        state: &mut FileSystemTreeState,
+        respect: bool,
+    ) -> std::io::Result<()> {
+        if self.config.respect_gitignore == respect {
+            return Ok(());
+        }
+        self.config.respect_gitignore = respect;
+        self.reload(state)
+    }
+
+    /// Flips `.gitignore` filtering, e.g. from a hotkey, and returns the new
+    /// setting.
+    pub fn toggle_gitignore(&mut self, state: &mut FileSystemTreeState) -> std::io::Result<bool> {
+        let respect = !self.config.respect_gitignore;
+        self.set_respect_gitignore(state, respect)?;
+        Ok(respect)
+    }
+
     pub fn get_entry_at_path(&self, path: &[usize]) -> Option<&FileSystemEntry> {
         fn find_entry<'a>(
             nodes: &'a [FileSystemTreeNode],
@@ -240,6 +426,7 @@ impl<'a> FileSystemTree<'a> {
     }
 
     pub fn toggle_selected(&mut self, state: &mut FileSystemTreeState) -> std::io::Result<()> {
+        self.apply_refreshes(state)?;
         if let Some(path) = state.selected_path.clone() {
             if let Some(entry) = self.get_entry_at_path(&path) {
                 if entry.is_dir {
@@ -254,6 +441,7 @@ impl<'a> FileSystemTree<'a> {
     }
 
     pub fn expand_selected(&mut self, state: &mut FileSystemTreeState) -> std::io::Result<bool> {
+        self.apply_refreshes(state)?;
         let Some(path) = state.selected_path.clone() else {
             return Ok(false);
         };
@@ -429,7 +617,14 @@ impl<'a> ratatui::widgets::StatefulWidget for FileSystemTree<'a> {
             if let Some(entry) = self.get_entry_at_path(path) {
                 let is_selected = state.selected_path.as_ref() == Some(path);
 
-                let (icon_glyph, icon_color) = if entry.is_dir {
+                let (icon_glyph, icon_color) = if entry.is_symlink() {
+                    let glyph = if entry.is_dir || entry.is_cycle {
+                        SYMLINK_DIR_ICON
+                    } else {
+                        SYMLINK_FILE_ICON
+                    };
+                    (glyph, Color::Cyan)
+                } else if entry.is_dir {
                     if state.is_expanded(path) {
                         ('\u{f115}', yazi_dir_icon_color())
                     } else {
@@ -449,6 +644,8 @@ impl<'a> ratatui::widgets::StatefulWidget for FileSystemTree<'a> {
 
                 let style = if is_selected {
                     config.selected_style
+                } else if entry.is_broken_link() {
+                    Style::default().fg(Color::Red)
                 } else if entry.is_dir {
                     config.dir_style
                 } else {
@@ -510,7 +707,7 @@ impl<'a> ratatui::widgets::StatefulWidget for FileSystemTree<'a> {
                     (tree_area.x, tree_area.width)
                 };
 
-                let line = if is_selected {
+                let mut line = if is_selected {
                     Line::from(vec![
                         Span::styled(indent, selected_text_style),
                         Span::styled(format!("{} ", icon_glyph), selected_text_style),
@@ -523,8 +720,43 @@ impl<'a> ratatui::widgets::StatefulWidget for FileSystemTree<'a> {
                         Span::styled(entry.name.clone(), style),
                     ])
                 };
+                if let Some(target) = &entry.symlink_target {
+                    let dim_style = if is_selected {
+                        selected_text_style
+                    } else if entry.is_broken_link() {
+                        Style::default().fg(Color::Red).add_modifier(Modifier::DIM)
+                    } else {
+                        Style::default().fg(Color::DarkGray)
+                    };
+                    line.spans.push(Span::styled(
+                        format!(" \u{2192} {}", target.display()),
+                        dim_style,
+                    ));
+                    if entry.is_cycle {
+                        line.spans.push(Span::styled(" \u{21a9} cycle", dim_style));
+                    }
+                }
+
+                let size = config
+                    .show_size
+                    .then(|| entry.display_size())
+                    .flatten()
+                    .filter(|size| size.len() + 1 < line_width as usize);
+                let name_width = match &size {
+                    Some(size) => line_width - size.len() as u16 - 1,
+                    None => line_width,
+                };
+                buf.set_line(line_x, y, &line, name_width);
 
-                buf.set_line(line_x, y, &line, line_width);
+                if let Some(size) = size {
+                    let size_style = if is_selected {
+                        selected_text_style
+                    } else {
+                        Style::default().fg(Color::DarkGray)
+                    };
+                    let x = line_x + line_width - size.len() as u16;
+                    buf.set_string(x, y, size, size_style);
+                }
             }
         }
 
@@ -561,3 +793,211 @@ impl<'a> ratatui::widgets::StatefulWidget for FileSystemTree<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::StatefulWidget;
+    use tempfile::tempdir;
+
+    use crate::widgets::file_system_tree::test_support::names;
+    use crate::widgets::file_system_tree::{
+        FileSystemTree, FileSystemTreeConfig, FileSystemTreeState,
+    };
+
+    #[test]
+    fn directories_are_read_on_first_expansion_and_cached() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("src/nested")).unwrap();
+        fs::create_dir(root.join("empty")).unwrap();
+        fs::write(root.join("src/lib.rs"), "").unwrap();
+
+        let mut tree = FileSystemTree::new(root.to_path_buf()).unwrap();
+        let mut state = FileSystemTreeState::new();
+        assert!(tree.nodes[0].loaded);
+        assert_eq!(names(&tree, &[0]), ["empty", "src"]);
+        let src = &tree.nodes[0].children[1];
+        assert!(!src.loaded && src.children.is_empty());
+
+        state.select(vec![0, 1]);
+        assert!(tree.expand_selected(&mut state).unwrap());
+        assert!(tree.nodes[0].children[1].loaded);
+        assert_eq!(names(&tree, &[0, 1]), ["nested", "lib.rs"]);
+        assert!(!tree.nodes[0].children[1].children[0].loaded);
+
+        // Collapsing and expanding again reuses the cached listing.
+        fs::write(root.join("src/main.rs"), "").unwrap();
+        tree.toggle_selected(&mut state).unwrap();
+        tree.toggle_selected(&mut state).unwrap();
+        assert!(state.is_expanded(&[0, 1]));
+        assert_eq!(names(&tree, &[0, 1]), ["nested", "lib.rs"]);
+
+        // An empty directory is only read once.
+        tree.expand_directory(&[0, 0]).unwrap();
+        assert!(tree.nodes[0].children[0].loaded);
+        fs::write(root.join("empty/late.txt"), "").unwrap();
+        tree.expand_directory(&[0, 0]).unwrap();
+        assert!(names(&tree, &[0, 0]).is_empty());
+    }
+
+    #[test]
+    fn refresh_node_rereads_a_single_directory() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("a")).unwrap();
+        fs::create_dir_all(root.join("b")).unwrap();
+        let mut tree = FileSystemTree::new(root.to_path_buf()).unwrap();
+        let mut state = FileSystemTreeState::new();
+        tree.expand_directory(&[0, 0]).unwrap();
+        tree.expand_directory(&[0, 1]).unwrap();
+        state.expand(vec![0, 0]);
+
+        fs::write(root.join("a/new.txt"), "").unwrap();
+        fs::write(root.join("b/new.txt"), "").unwrap();
+        state.refresh_node(vec![0, 0]);
+        let rescanned = tree.apply_refreshes(&mut state).unwrap();
+
+        assert_eq!(rescanned, [root.join("a")]);
+        assert_eq!(names(&tree, &[0, 0]), ["new.txt"]);
+        assert!(names(&tree, &[0, 1]).is_empty());
+        assert!(tree.nodes[0].children[1].loaded);
+
+        // A collapsed directory is re-read when it is next expanded.
+        state.refresh_node(vec![0, 1]);
+        state.select(vec![0, 1]);
+        assert!(tree.expand_selected(&mut state).unwrap());
+        assert_eq!(names(&tree, &[0, 1]), ["new.txt"]);
+    }
+
+    #[test]
+    fn metadata_is_collected_only_when_enabled() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir(root.join("docs")).unwrap();
+        fs::write(root.join("big.bin"), vec![0u8; 1536]).unwrap();
+        fs::write(root.join("small.txt"), "hello").unwrap();
+
+        let tree = FileSystemTree::new(root.to_path_buf()).unwrap();
+        let entry = &tree.nodes[0].children[1].data;
+        assert_eq!((entry.size, entry.modified), (None, None));
+
+        let config = FileSystemTreeConfig::default()
+            .collect_metadata(true)
+            .show_size(true);
+        let tree = FileSystemTree::with_config(root.to_path_buf(), config).unwrap();
+        let children = &tree.nodes[0].children;
+        assert_eq!(children[0].data.size, None);
+        assert!(children[0].data.modified.is_some());
+        assert_eq!(children[1].data.size, Some(1536));
+        assert_eq!(children[1].data.display_size().as_deref(), Some("1.5K"));
+        assert_eq!(children[2].data.display_size().as_deref(), Some("5"));
+
+        let area = Rect::new(0, 0, 30, 4);
+        let mut buf = Buffer::empty(area);
+        let mut state = FileSystemTreeState::new();
+        tree.render(area, &mut buf, &mut state);
+        let row: String = (0..area.width)
+            .map(|x| buf[(x, 2)].symbol().to_string())
+            .collect();
+        assert!(row.contains("big.bin"));
+        assert!(row.trim_end().ends_with("1.5K"));
+        assert_eq!(row.chars().nth(28), Some('K'));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn unreadable_metadata_is_left_empty() {
+        use std::os::unix::fs::symlink;
+
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        symlink(root.join("loop_b"), root.join("loop_a")).unwrap();
+        symlink(root.join("loop_a"), root.join("loop_b")).unwrap();
+        symlink(root.join("missing"), root.join("dangling")).unwrap();
+
+        let config = FileSystemTreeConfig::default().collect_metadata(true);
+        let tree = FileSystemTree::with_config(root.to_path_buf(), config).unwrap();
+        let children = &tree.nodes[0].children;
+        assert_eq!(children.len(), 3);
+        for child in children {
+            assert_eq!((child.data.size, child.data.modified), (None, None));
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlinks_show_targets_and_cycles_are_not_followed() {
+        use std::os::unix::fs::symlink;
+
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir(root.join("real")).unwrap();
+        fs::write(root.join("real/file.txt"), "").unwrap();
+        fs::write(root.join("app.log"), "").unwrap();
+        fs::create_dir(root.join(".git")).unwrap();
+        fs::write(root.join(".gitignore"), "*.log\n").unwrap();
+        symlink("..", root.join("real/up")).unwrap();
+        symlink("real", root.join("link")).unwrap();
+        symlink("missing", root.join("broken")).unwrap();
+        symlink("real/file.txt", root.join(".hidden")).unwrap();
+        symlink("app.log", root.join("alias")).unwrap();
+        symlink("real/file.txt", root.join("old.log")).unwrap();
+
+        // Filters look at the link name: `alias` points at an ignored file
+        // but stays, `old.log` points at a kept file but is ignored.
+        let config = FileSystemTreeConfig::default().respect_gitignore(true);
+        let tree = FileSystemTree::with_config(root.to_path_buf(), config).unwrap();
+        assert_eq!(names(&tree, &[0]), ["real", "alias", "broken", "link"]);
+        let link = &tree.nodes[0].children[3].data;
+        assert!(!link.is_dir);
+        assert!(tree.nodes[0].children[2].data.is_broken_link());
+
+        let config = FileSystemTreeConfig::default().follow_symlinks(true);
+        let mut tree = FileSystemTree::with_config(root.to_path_buf(), config).unwrap();
+        assert_eq!(
+            names(&tree, &[0]),
+            ["link", "real", "alias", "app.log", "broken", "old.log"]
+        );
+        let link = &tree.nodes[0].children[0].data;
+        assert!(link.is_dir);
+        assert_eq!(link.path, root.join("link"));
+        assert_eq!(link.symlink_target.as_deref(), Some(Path::new("real")));
+        assert_eq!(
+            link.target_path(),
+            fs::canonicalize(root.join("real")).unwrap()
+        );
+
+        let mut state = FileSystemTreeState::new();
+        state.select(vec![0, 0]);
+        assert!(tree.expand_selected(&mut state).unwrap());
+        assert_eq!(names(&tree, &[0, 0]), ["file.txt", "up"]);
+        let up = &tree.nodes[0].children[0].children[1].data;
+        assert!(up.is_cycle && !up.is_dir);
+
+        state.select(vec![0, 0, 1]);
+        assert!(!tree.expand_selected(&mut state).unwrap());
+
+        state.expand(vec![0]);
+        let area = Rect::new(0, 0, 40, 10);
+        let mut buf = Buffer::empty(area);
+        tree.render(area, &mut buf, &mut state);
+        let rows: Vec<String> = (0..area.height)
+            .map(|y| {
+                (0..area.width)
+                    .map(|x| buf[(x, y)].symbol().to_string())
+                    .collect()
+            })
+            .collect();
+        assert!(rows[1].contains("link \u{2192} real"), "{rows:?}");
+        assert!(
+            rows[3].contains("up \u{2192} .. \u{21a9} cycle"),
+            "{rows:?}"
+        );
+        assert!(rows[7].contains("broken \u{2192} missing"), "{rows:?}");
+    }
+}
@@ -4,15 +4,40 @@ use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Paragraph, Widget};
 use ratatui::Frame;
+use unicode_width::UnicodeWidthStr;
 
 use crate::widgets::hotkey_footer::hotkey::HotkeyItem;
 
+/// Width of the space before the first hint on each row.
+const LEADING_WIDTH: usize = 1;
+/// Width of the gap between hints.
+const SEPARATOR_WIDTH: usize = 2;
+/// Placeholder replaced with the number of collapsed hints.
+const COUNT_PLACEHOLDER: &str = "{count}";
+
 #[derive(Clone, Debug)]
 pub struct HotkeyFooter {
     pub items: Vec<HotkeyItem>,
     pub key_color: Color,
     pub description_color: Color,
     pub background_color: Color,
+    /// Text shown in place of hints that do not fit, with `{count}`
+    /// replaced by how many were collapsed.
+    pub overflow_indicator: String,
+    /// Most rows hints wrap onto before collapsing.
+    pub max_rows: u16,
+    /// Item indices from most to least important; unlisted items rank
+    /// below every listed one.
+    pub priority: Vec<usize>,
+}
+
+/// Which hints go on which row.
+#[derive(Debug, PartialEq, Eq)]
+struct FooterLayout {
+    /// Indices into the footer's items, per row.
+    rows: Vec<Vec<usize>>,
+    /// Number of hints collapsed into the overflow indicator.
+    hidden: usize,
 }
 
 impl HotkeyFooter {
@@ -22,9 +47,37 @@ impl HotkeyFooter {
             key_color: Color::Cyan,
             description_color: Color::DarkGray,
             background_color: Color::Black,
+            overflow_indicator: "+{count} more (?)".to_string(),
+            max_rows: 1,
+            priority: Vec::new(),
         }
     }
 
+    /// Builds a footer from the hotkeys of `registry` active in `scope`,
+    /// leaving out those that are not
+    /// [`footer_visible`](crate::services::hotkey_service::Hotkey::footer_visible).
+    ///
+    /// Items follow registration order and are ranked by the hotkeys'
+    /// priority, so the most important stay visible when hints collapse.
+    #[cfg(feature = "hotkey-service")]
+    pub fn from_registry(
+        registry: &crate::services::hotkey_service::HotkeyRegistry,
+        scope: &crate::services::hotkey_service::HotkeyScope,
+    ) -> Self {
+        let hotkeys: Vec<_> = registry
+            .get_by_scope(scope)
+            .into_iter()
+            .filter(|hotkey| hotkey.footer_visible)
+            .collect();
+        let items = hotkeys
+            .iter()
+            .map(|hotkey| HotkeyItem::new(hotkey.key.clone(), hotkey.description.clone()))
+            .collect();
+        let mut order: Vec<usize> = (0..hotkeys.len()).collect();
+        order.sort_by_key(|&index| std::cmp::Reverse(hotkeys[index].priority));
+        Self::new(items).priority(order)
+    }
+
     pub fn with_theme_colors(
         mut self,
         key_color: Color,
@@ -52,49 +105,293 @@ impl HotkeyFooter {
         self
     }
 
-    fn build_line(&self) -> Line<'static> {
-        let mut spans = Vec::new();
+    /// Sets the text shown in place of hints that do not fit.
+    ///
+    /// `{count}` is replaced by the number of collapsed hints, so the
+    /// default `+{count} more (?)` reads `+4 more (?)`.
+    pub fn overflow_indicator(mut self, indicator: impl Into<String>) -> Self {
+        self.overflow_indicator = indicator.into();
+        self
+    }
+
+    /// Lets hints wrap onto up to `rows` rows, as far as the area's height
+    /// allows, before collapsing the rest.
+    pub fn max_rows(mut self, rows: u16) -> Self {
+        self.max_rows = rows.max(1);
+        self
+    }
+
+    /// Ranks items by importance, as indices into the items from most to
+    /// least important. When hints collapse, unpinned items go first,
+    /// lowest ranked first; unlisted items rank lowest and go trailing
+    /// first.
+    pub fn priority(mut self, order: impl IntoIterator<Item = usize>) -> Self {
+        self.priority = order.into_iter().collect();
+        self
+    }
+
+    /// Position of item `index` in [`priority`](Self::priority), or
+    /// `usize::MAX` if it is not listed.
+    fn rank(&self, index: usize) -> usize {
+        self.priority
+            .iter()
+            .position(|&ranked| ranked == index)
+            .unwrap_or(usize::MAX)
+    }
+
+    fn indicator_text(&self, hidden: usize) -> String {
+        self.overflow_indicator
+            .replace(COUNT_PLACEHOLDER, &hidden.to_string())
+    }
+
+    fn item_width(item: &HotkeyItem) -> usize {
+        item.key.width() + 1 + item.description.width()
+    }
 
-        for (i, item) in self.items.iter().enumerate() {
-            if i == 0 {
-                spans.push(Span::raw(" "));
+    /// Width of a row holding `row`, followed by the indicator if `hidden`
+    /// is non-zero.
+    fn row_width(&self, row: &[usize], hidden: usize) -> usize {
+        let mut widths: Vec<usize> = row
+            .iter()
+            .map(|&index| Self::item_width(&self.items[index]))
+            .collect();
+        if hidden > 0 {
+            widths.push(self.indicator_text(hidden).width());
+        }
+        if widths.is_empty() {
+            return 0;
+        }
+        LEADING_WIDTH + widths.iter().sum::<usize>() + SEPARATOR_WIDTH * (widths.len() - 1)
+    }
+
+    /// Wraps hints onto up to `rows` rows of `width` columns, collapsing
+    /// those that do not fit on the last row.
+    ///
+    /// Unpinned hints are collapsed first, lowest [`priority`](Self::priority)
+    /// and then trailing first; pinned hints are only collapsed once no
+    /// unpinned hint is left on the row.
+    fn layout(&self, width: usize, rows: usize) -> FooterLayout {
+        let mut layout = FooterLayout {
+            rows: Vec::new(),
+            hidden: 0,
+        };
+        let mut next = 0;
+        while layout.rows.len() + 1 < rows && next < self.items.len() {
+            let mut row = Vec::new();
+            while next < self.items.len() {
+                row.push(next);
+                if row.len() > 1 && self.row_width(&row, 0) > width {
+                    row.pop();
+                    break;
+                }
+                next += 1;
             }
+            layout.rows.push(row);
+        }
 
-            spans.push(Span::styled(
-                item.key.clone(),
-                Style::default()
-                    .fg(self.key_color)
-                    .add_modifier(Modifier::BOLD),
-            ));
-
-            spans.push(Span::styled(
-                format!(" {}  ", item.description),
-                Style::default().fg(self.description_color),
-            ));
+        let mut last: Vec<usize> = (next..self.items.len()).collect();
+        while !last.is_empty() && self.row_width(&last, layout.hidden) > width {
+            let collapse = last
+                .iter()
+                .enumerate()
+                .max_by_key(|&(position, &index)| {
+                    (!self.items[index].pinned, self.rank(index), position)
+                })
+                .map_or(0, |(position, _)| position);
+            last.remove(collapse);
+            layout.hidden += 1;
+        }
+        if !last.is_empty() || layout.hidden > 0 {
+            layout.rows.push(last);
         }
+        layout
+    }
+
+    fn build_lines(&self, area: Rect) -> Vec<Line<'static>> {
+        let rows = self.max_rows.min(area.height).max(1);
+        let layout = self.layout(area.width as usize, rows as usize);
+        let row_count = layout.rows.len();
 
-        Line::from(spans)
+        layout
+            .rows
+            .into_iter()
+            .enumerate()
+            .map(|(row_index, row)| {
+                let mut spans = vec![Span::raw(" ")];
+                for index in row {
+                    let item = &self.items[index];
+                    spans.push(Span::styled(
+                        item.key.clone(),
+                        Style::default()
+                            .fg(self.key_color)
+                            .add_modifier(Modifier::BOLD),
+                    ));
+                    spans.push(Span::styled(
+                        format!(" {}  ", item.description),
+                        Style::default().fg(self.description_color),
+                    ));
+                }
+                if row_index + 1 == row_count && layout.hidden > 0 {
+                    spans.push(Span::styled(
+                        self.indicator_text(layout.hidden),
+                        Style::default()
+                            .fg(self.description_color)
+                            .add_modifier(Modifier::DIM),
+                    ));
+                }
+                Line::from(spans)
+            })
+            .collect()
     }
 
     pub fn render(&self, frame: &mut Frame, area: Rect) {
-        let line = self.build_line();
-        let widget = Paragraph::new(line).style(Style::default().bg(self.background_color));
-        frame.render_widget(widget, area);
+        frame.render_widget(self, area);
     }
 }
 
 impl Widget for HotkeyFooter {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let line = self.build_line();
-        let widget = Paragraph::new(line).style(Style::default().bg(self.background_color));
-        widget.render(area, buf);
+        Widget::render(&self, area, buf);
     }
 }
 
 impl Widget for &HotkeyFooter {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let line = self.build_line();
-        let widget = Paragraph::new(line).style(Style::default().bg(self.background_color));
+        let lines = self.build_lines(area);
+        let widget = Paragraph::new(lines).style(Style::default().bg(self.background_color));
         widget.render(area, buf);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items() -> Vec<HotkeyItem> {
+        vec![
+            HotkeyItem::new("q", "quit"),
+            HotkeyItem::new("?", "help"),
+            HotkeyItem::new("s", "save"),
+            HotkeyItem::new("o", "open"),
+            HotkeyItem::new("f", "find"),
+            HotkeyItem::new("r", "reload"),
+        ]
+    }
+
+    fn render_rows(footer: &HotkeyFooter, width: u16, height: u16) -> Vec<String> {
+        let area = Rect::new(0, 0, width, height);
+        let mut buf = Buffer::empty(area);
+        Widget::render(footer, area, &mut buf);
+        (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_all_hints_fit_on_a_wide_footer() {
+        let footer = HotkeyFooter::new(items());
+        assert_eq!(
+            render_rows(&footer, 60, 1),
+            [" q quit  ? help  s save  o open  f find  r reload"]
+        );
+        assert_eq!(
+            render_rows(&footer, 49, 1),
+            [" q quit  ? help  s save  o open  f find  r reload"]
+        );
+    }
+
+    #[test]
+    fn test_trailing_hints_collapse_into_indicator() {
+        let footer = HotkeyFooter::new(items());
+        assert_eq!(
+            render_rows(&footer, 48, 1),
+            [" q quit  ? help  s save  o open  +2 more (?)"]
+        );
+        assert_eq!(
+            render_rows(&footer, 30, 1),
+            [" q quit  ? help  +4 more (?)"]
+        );
+        assert_eq!(render_rows(&footer, 14, 1), [" +6 more (?)"]);
+
+        let footer = HotkeyFooter::new(items()).overflow_indicator("…{count}");
+        assert_eq!(render_rows(&footer, 20, 1), [" q quit  ? help  …4"]);
+    }
+
+    #[test]
+    fn test_pinned_hints_are_kept() {
+        let mut items = items();
+        items[5] = HotkeyItem::new("r", "reload").pinned(true);
+        let footer = HotkeyFooter::new(items);
+        assert_eq!(
+            render_rows(&footer, 30, 1),
+            [" q quit  r reload  +4 more (?)"]
+        );
+        assert_eq!(render_rows(&footer, 29, 1), [" r reload  +5 more (?)"]);
+    }
+
+    #[test]
+    fn test_priority_decides_which_hints_collapse() {
+        // Most important first: reload, find, quit
+        let footer = HotkeyFooter::new(items()).priority([5, 4, 0]);
+        let shown = " q quit  f find  r reload";
+        let hidden = items().len() - 3;
+        assert_eq!(
+            render_rows(&footer, 38, 1),
+            [format!("{shown}  +{hidden} more (?)")]
+        );
+
+        // Pinned items still outrank the priority list.
+        let mut pinned = items();
+        pinned[1] = HotkeyItem::new("?", "help").pinned(true);
+        let footer = HotkeyFooter::new(pinned).priority([5, 4, 0]);
+        assert_eq!(
+            render_rows(&footer, 38, 1),
+            [" ? help  f find  r reload  +3 more (?)"]
+        );
+    }
+
+    #[cfg(feature = "hotkey-service")]
+    #[test]
+    fn test_from_registry_lists_visible_hotkeys_of_the_scope() {
+        use crate::services::hotkey_service::{Hotkey, HotkeyRegistry, HotkeyScope};
+
+        let mut registry = HotkeyRegistry::new();
+        registry.register(Hotkey::new("q", "quit").scope(HotkeyScope::Global));
+        registry.register(
+            Hotkey::new("j", "down")
+                .scope(HotkeyScope::Tab("Files"))
+                .priority(5),
+        );
+        registry.register(Hotkey::new("x", "debug").footer_visible(false));
+        registry.register(Hotkey::new("g", "graph").scope(HotkeyScope::Tab("Log")));
+
+        let footer = HotkeyFooter::from_registry(&registry, &HotkeyScope::Tab("Files"));
+        let keys: Vec<&str> = footer.items.iter().map(|item| item.key.as_str()).collect();
+        assert_eq!(keys, ["q", "j"]);
+        assert_eq!(footer.priority, [1, 0]);
+    }
+
+    #[test]
+    fn test_two_rows_wrap_before_collapsing() {
+        let footer = HotkeyFooter::new(items()).max_rows(2);
+        assert_eq!(
+            render_rows(&footer, 30, 2),
+            [" q quit  ? help  s save", " o open  f find  r reload"]
+        );
+        assert_eq!(
+            render_rows(&footer, 20, 2),
+            [" q quit  ? help", " s save  +3 more (?)"]
+        );
+        // Without the vertical space, the hints collapse onto one row.
+        assert_eq!(
+            render_rows(&footer, 30, 1),
+            [" q quit  ? help  +4 more (?)"]
+        );
+    }
+}
@@ -2,6 +2,9 @@
 pub struct HotkeyItem {
     pub key: String,
     pub description: String,
+    /// Whether the footer keeps this item visible in preference to
+    /// unpinned items when space runs out.
+    pub pinned: bool,
 }
 
 impl HotkeyItem {
@@ -9,6 +12,14 @@ impl HotkeyItem {
         Self {
             key: key.into(),
             description: description.into(),
+            pinned: false,
         }
     }
+
+    /// Keeps this item visible in preference to unpinned items when the
+    /// footer is too narrow for every hint.
+    pub fn pinned(mut self, pinned: bool) -> Self {
+        self.pinned = pinned;
+        self
+    }
 }
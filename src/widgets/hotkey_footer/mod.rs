@@ -2,6 +2,9 @@
 //!
 //! A styled hotkey footer bar component (aerospace-tui style)
 //! Renders a single line with alternating hotkey/description pairs.
+//! Hints that do not fit collapse into a "+N more" indicator, keeping
+//! pinned hints and then those ranked by `priority` visible, or wrap onto
+//! more rows with `max_rows`.
 //!
 //! # Example
 //!
@@ -10,7 +13,7 @@
 //! use ratkit_hotkey_footer::{HotkeyFooter, HotkeyItem};
 //!
 //! let footer = HotkeyFooter::new(vec![
-//!     HotkeyItem::new("q", "quit"),
+//!     HotkeyItem::new("q", "quit").pinned(true),
 //!     HotkeyItem::new("?", "help"),
 //! ])
 //! .key_color(Color::Cyan)
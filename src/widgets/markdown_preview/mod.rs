@@ -10,17 +10,20 @@ pub mod widgets;
 pub use widgets::markdown_widget::extensions::{
     get_effective_theme_variant, handle_click, handle_mouse_event,
     handle_mouse_event_with_double_click, load_theme_from_json, palettes, should_render_line,
-    ColorMapping, ColorPalette, CustomScrollbar, MarkdownStyle, MarkdownTheme, ScrollbarConfig,
-    SyntaxHighlighter, SyntaxThemeVariant, ThemeVariant, Toc, TocConfig,
+    ColorMapping, ColorPalette, CustomScrollbar, DecorationRange, DecorationSpan, MarkdownStyle,
+    MarkdownTheme, RelativeTimeDecorator, ScrollbarConfig, SyntaxHighlighter, SyntaxThemeVariant,
+    TextDecorator, ThemeVariant, ThousandsSeparatorDecorator, Toc, TocConfig,
 };
 pub use widgets::markdown_widget::{
     render_element, render_element_with_options, render_markdown, render_markdown_to_elements,
     render_markdown_with_style, CacheState, CheckboxState, CodeBlockBorderKind, CodeBlockColors,
     CodeBlockTheme, CollapseState, ColumnAlignment, DisplaySettings, DoubleClickState, ElementKind,
-    ExpandableEntry, ExpandableState, GitStats, GitStatsState, MarkdownDoubleClickEvent,
-    MarkdownElement, MarkdownEvent, MarkdownSource, MarkdownState, MarkdownWidget,
-    MarkdownWidgetMode, ParsedCache, RenderCache, RenderOptions, ScrollState, SelectionPos,
-    SelectionState, SourceState, TableBorderKind, TextSegment, TocEntry, TocState, VimState,
-    BLOCKQUOTE_MARKER, BULLET_MARKERS, CHECKBOX_CHECKED, CHECKBOX_TODO, CHECKBOX_UNCHECKED,
-    HEADING_ICONS, HORIZONTAL_RULE_CHAR, INLINE_CODE_BG, INLINE_CODE_FG_FALLBACK,
+    ExpandableEntry, ExpandableState, FenceRenderFn, GitStats, GitStatsState,
+    MarkdownDoubleClickEvent, MarkdownElement, MarkdownEvent, MarkdownSource, MarkdownState,
+    MarkdownWidget, MarkdownWidgetMode, ParsedCache, RenderCache, RenderOptions, ScrollState,
+    SearchMatch, SearchState, SelectionPos, SelectionState, SourceState, StreamStats,
+    StreamingMarkdown, TableBorderKind, TextSegment, TocEntry, TocFocusAction, TocPosition,
+    TocState, VimState, BLOCKQUOTE_MARKER, BULLET_MARKERS, CHECKBOX_CHECKED, CHECKBOX_TODO,
+    CHECKBOX_UNCHECKED, HEADING_ICONS, HORIZONTAL_RULE_CHAR, INLINE_CODE_BG,
+    INLINE_CODE_FG_FALLBACK,
 };
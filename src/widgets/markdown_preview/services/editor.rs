@@ -0,0 +1,89 @@
+//! Opening markdown in the user's editor.
+//!
+//! The editor runs in the terminal the app is drawing in, so these helpers
+//! hand it over with [`crate::suspend_terminal`] and must be called while
+//! [`crate::run`] is running.
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process::{Command, ExitStatus};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Builds the command that opens `path` at `line` (1-indexed).
+///
+/// Uses `$VISUAL`, then `$EDITOR`, then `vi`. The variable may include
+/// arguments, e.g. `code --wait`. The line is passed as `+<line>`, which
+/// most terminal editors understand.
+pub fn editor_command(path: &Path, line: usize) -> Command {
+    let editor = env::var("VISUAL")
+        .ok()
+        .filter(|editor| !editor.trim().is_empty())
+        .or_else(|| env::var("EDITOR").ok())
+        .filter(|editor| !editor.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string());
+    let mut words = editor.split_whitespace();
+    let mut command = Command::new(words.next().unwrap_or("vi"));
+    command
+        .args(words)
+        .arg(format!("+{}", line.max(1)))
+        .arg(path);
+    command
+}
+
+/// Opens `path` at `line` (1-indexed) in the user's editor and waits for
+/// it to exit.
+///
+/// # Errors
+///
+/// Returns an error if the terminal could not be handed over or the
+/// editor could not be started.
+pub fn open_in_editor(path: &Path, line: usize) -> io::Result<ExitStatus> {
+    crate::suspend_terminal(|| editor_command(path, line).status())?
+}
+
+/// Opens `text` in the user's editor through a temporary file and returns
+/// the saved text.
+///
+/// Returns `None` if the editor exited unsuccessfully.
+///
+/// # Errors
+///
+/// Returns an error if the temporary file could not be written or read,
+/// or the editor could not be started.
+pub fn edit_text_in_editor(text: &str) -> io::Result<Option<String>> {
+    static NEXT_FILE: AtomicUsize = AtomicUsize::new(0);
+    let path = env::temp_dir().join(format!(
+        "ratkit-edit-{}-{}.md",
+        std::process::id(),
+        NEXT_FILE.fetch_add(1, Ordering::Relaxed)
+    ));
+    fs::write(&path, text)?;
+    let result = open_in_editor(&path, 1).and_then(|status| {
+        if status.success() {
+            fs::read_to_string(&path).map(Some)
+        } else {
+            Ok(None)
+        }
+    });
+    let _ = fs::remove_file(&path);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_editor_command_passes_line_then_path() {
+        let command = editor_command(Path::new("notes.md"), 12);
+        let args: Vec<_> = command.get_args().collect();
+        assert_eq!(
+            args.len(),
+            args.iter().position(|arg| *arg == "+12").unwrap() + 2
+        );
+        assert_eq!(args.last().copied(), Some("notes.md".as_ref()));
+        assert!(!command.get_program().is_empty());
+    }
+}
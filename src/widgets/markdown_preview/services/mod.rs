@@ -1,3 +1,4 @@
+pub mod editor;
 pub mod file_watcher;
 pub mod git_watcher;
 pub mod theme;
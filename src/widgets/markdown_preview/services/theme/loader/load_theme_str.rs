@@ -205,6 +205,7 @@ fn build_markdown_colors(
         image: resolve("markdownImage", default.image),
         image_text: resolve("markdownImageText", default.image_text),
         code_block: resolve("markdownCodeBlock", default.code_block),
+        language_overrides: default.language_overrides,
     }
 }
 
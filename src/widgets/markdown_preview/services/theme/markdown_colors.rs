@@ -22,8 +22,12 @@
 /// let colors = MarkdownColors::default();
 /// // Use colors.heading for heading text color
 /// ```
+use std::collections::HashMap;
+
 use ratatui::style::Color;
 
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::CodeBlockColors;
+
 /// Colors for rendering markdown content.
 ///
 /// This struct contains all the colors needed for the [`MarkdownWidget`](crate::MarkdownWidget)
@@ -40,6 +44,7 @@ use ratatui::style::Color;
 /// - **Structure**: `block_quote`, `horizontal_rule`
 /// - **Lists**: `list_item`, `list_enumeration`
 /// - **Images**: `image`, `image_text`
+/// - **Code block overrides**: `language_overrides`
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct MarkdownColors {
     /// Color for regular paragraph text.
@@ -111,6 +116,13 @@ pub struct MarkdownColors {
     ///
     /// Used for fenced code blocks.
     pub code_block: Color,
+
+    /// Code block colors for specific fence languages.
+    ///
+    /// Keys are matched case-insensitively against the fence language (the
+    /// first token of the info string). Languages without an entry use the
+    /// widget's [`CodeBlockTheme`](crate::widgets::markdown_preview::CodeBlockTheme).
+    pub language_overrides: HashMap<String, CodeBlockColors>,
 }
 
 /// New constructor for [`MarkdownColors`].
@@ -194,7 +206,48 @@ impl MarkdownColors {
             image,
             image_text,
             code_block,
+            language_overrides: HashMap::new(),
+        }
+    }
+}
+
+impl MarkdownColors {
+    /// Sets the code block colors used for fences of `language`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use ratatui::style::Color;
+    /// use ratatui_toolkit::services::theme::MarkdownColors;
+    /// use ratatui_toolkit::{CodeBlockColors, CodeBlockTheme};
+    ///
+    /// let console = CodeBlockColors {
+    ///     background: Color::Black,
+    ///     ..CodeBlockTheme::default().colors()
+    /// };
+    /// let colors = MarkdownColors::default().with_language_override("console", console);
+    /// ```
+    pub fn with_language_override(
+        mut self,
+        language: impl Into<String>,
+        colors: CodeBlockColors,
+    ) -> Self {
+        self.language_overrides
+            .insert(language.into().to_lowercase(), colors);
+        self
+    }
+
+    /// Returns the override for `language`, ignoring ASCII case.
+    pub fn language_override(&self, language: &str) -> Option<&CodeBlockColors> {
+        if language.is_empty() {
+            return None;
         }
+        self.language_overrides.get(language).or_else(|| {
+            self.language_overrides
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(language))
+                .map(|(_, colors)| colors)
+        })
     }
 }
 
@@ -226,6 +279,7 @@ impl Default for MarkdownColors {
             image: Color::Rgb(100, 200, 100),            // Match link color (green)
             image_text: Color::Rgb(100, 200, 100),       // Match link color (green)
             code_block: Color::Rgb(191, 189, 182),       // Match text color
+            language_overrides: HashMap::new(),
         }
     }
 }
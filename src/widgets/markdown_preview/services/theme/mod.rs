@@ -9,6 +9,8 @@
 //!
 //! - [`AppTheme`] - The main theme struct with all widget colors
 //! - [`ThemeVariant`] - Dark/light mode selection
+//! - [`VariantSource`] - Fixed, OS-following or scheduled variant selection
+//! - [`VariantWatcher`] - Keeps the variant in step with its source
 //! - [`DiffColors`] - Colors for CodeDiff widget
 //! - [`MarkdownColors`] - Colors for MarkdownWidget
 //! - [`SyntaxColors`] - Colors for syntax highlighting
@@ -93,9 +95,12 @@ pub mod app_theme;
 pub mod diff_colors;
 pub mod loader;
 pub mod markdown_colors;
+pub mod os_appearance;
 pub mod persistence;
 pub mod syntax_colors;
 pub mod theme_variant;
+pub mod variant_source;
+pub mod variant_watcher;
 
 // Re-export main types at module level
 pub use app_theme::AppTheme;
@@ -103,3 +108,5 @@ pub use diff_colors::DiffColors;
 pub use markdown_colors::MarkdownColors;
 pub use syntax_colors::SyntaxColors;
 pub use theme_variant::ThemeVariant;
+pub use variant_source::{TimeOfDay, VariantSource};
+pub use variant_watcher::{VariantChangeFn, VariantWatcher};
@@ -0,0 +1,211 @@
+//! Detection of the operating system's dark/light preference.
+//!
+//! Each platform is queried through its own command line tool, so nothing
+//! here links against platform libraries:
+//!
+//! - macOS: `defaults read -g AppleInterfaceStyle`
+//! - Linux and BSDs: the XDG desktop portal through `gdbus`, then GNOME's
+//!   `gsettings`
+//! - Windows: the `AppsUseLightTheme` registry value through `reg`
+//!
+//! These tools can take a moment to answer, so call
+//! [`detect_os_variant`] off the UI thread.
+//!
+//! Every function returns `None` when the preference or time cannot be
+//! determined, including when the tool is missing.
+
+use std::process::{Command, Stdio};
+
+use chrono::Timelike;
+
+use crate::widgets::markdown_preview::services::theme::{ThemeVariant, TimeOfDay};
+
+/// Detects the operating system's dark/light preference.
+pub fn detect_os_variant() -> Option<ThemeVariant> {
+    if cfg!(target_os = "macos") {
+        let (success, stdout) = run("defaults", &["read", "-g", "AppleInterfaceStyle"])?;
+        Some(parse_apple_interface_style(success, &stdout))
+    } else if cfg!(windows) {
+        let (_, stdout) = run(
+            "reg",
+            &[
+                "query",
+                r"HKCU\Software\Microsoft\Windows\CurrentVersion\Themes\Personalize",
+                "/v",
+                "AppsUseLightTheme",
+            ],
+        )?;
+        parse_apps_use_light_theme(&stdout)
+    } else {
+        detect_portal_variant().or_else(detect_gsettings_variant)
+    }
+}
+
+/// Reads the current local time.
+pub fn local_time_of_day() -> Option<TimeOfDay> {
+    let now = chrono::Local::now();
+    Some(TimeOfDay::new(now.hour() as u8, now.minute() as u8))
+}
+
+fn detect_portal_variant() -> Option<ThemeVariant> {
+    let (success, stdout) = run(
+        "gdbus",
+        &[
+            "call",
+            "--session",
+            "--timeout",
+            "1",
+            "--dest",
+            "org.freedesktop.portal.Desktop",
+            "--object-path",
+            "/org/freedesktop/portal/desktop",
+            "--method",
+            "org.freedesktop.portal.Settings.Read",
+            "org.freedesktop.appearance",
+            "color-scheme",
+        ],
+    )?;
+    success
+        .then(|| parse_portal_color_scheme(&stdout))
+        .flatten()
+}
+
+fn detect_gsettings_variant() -> Option<ThemeVariant> {
+    let (success, stdout) = run(
+        "gsettings",
+        &["get", "org.gnome.desktop.interface", "color-scheme"],
+    )?;
+    if let Some(variant) = success
+        .then(|| parse_gsettings_color_scheme(&stdout))
+        .flatten()
+    {
+        return Some(variant);
+    }
+    let (success, stdout) = run(
+        "gsettings",
+        &["get", "org.gnome.desktop.interface", "gtk-theme"],
+    )?;
+    success.then(|| parse_gtk_theme(&stdout))
+}
+
+/// Runs `program`, returning whether it succeeded and its standard output,
+/// or `None` if it could not be started.
+fn run(program: &str, args: &[&str]) -> Option<(bool, String)> {
+    let output = Command::new(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    Some((
+        output.status.success(),
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+    ))
+}
+
+/// `defaults` prints `Dark` in dark mode and fails because the key is unset
+/// in light mode.
+fn parse_apple_interface_style(success: bool, stdout: &str) -> ThemeVariant {
+    if success && stdout.trim().eq_ignore_ascii_case("dark") {
+        ThemeVariant::Dark
+    } else {
+        ThemeVariant::Light
+    }
+}
+
+/// `reg` prints a line like `AppsUseLightTheme    REG_DWORD    0x0`.
+fn parse_apps_use_light_theme(stdout: &str) -> Option<ThemeVariant> {
+    let line = stdout
+        .lines()
+        .find(|line| line.contains("AppsUseLightTheme"))?;
+    match line.split_whitespace().last()? {
+        "0x0" => Some(ThemeVariant::Dark),
+        "0x1" => Some(ThemeVariant::Light),
+        _ => None,
+    }
+}
+
+/// The portal replies with `(<<uint32 1>>,)`: 1 prefers dark, 2 prefers
+/// light and 0 has no preference.
+fn parse_portal_color_scheme(stdout: &str) -> Option<ThemeVariant> {
+    let digits: String = stdout
+        .rsplit("uint32")
+        .next()?
+        .chars()
+        .skip_while(|c| c.is_whitespace())
+        .take_while(char::is_ascii_digit)
+        .collect();
+    match digits.as_str() {
+        "1" => Some(ThemeVariant::Dark),
+        "2" => Some(ThemeVariant::Light),
+        _ => None,
+    }
+}
+
+/// `gsettings` prints a quoted value; `'default'` has no preference.
+fn parse_gsettings_color_scheme(stdout: &str) -> Option<ThemeVariant> {
+    match stdout.trim().trim_matches('\'') {
+        "prefer-dark" => Some(ThemeVariant::Dark),
+        "prefer-light" => Some(ThemeVariant::Light),
+        _ => None,
+    }
+}
+
+/// Older desktops only signal dark mode through the GTK theme name, such
+/// as `'Adwaita-dark'`.
+fn parse_gtk_theme(stdout: &str) -> ThemeVariant {
+    if stdout.to_lowercase().contains("dark") {
+        ThemeVariant::Dark
+    } else {
+        ThemeVariant::Light
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_apple_interface_style() {
+        assert_eq!(
+            parse_apple_interface_style(true, "Dark\n"),
+            ThemeVariant::Dark
+        );
+        assert_eq!(parse_apple_interface_style(false, ""), ThemeVariant::Light);
+    }
+
+    #[test]
+    fn test_parse_apps_use_light_theme() {
+        let output = "\r\nHKEY_CURRENT_USER\\Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize\r\n    AppsUseLightTheme    REG_DWORD    0x0\r\n";
+        assert_eq!(parse_apps_use_light_theme(output), Some(ThemeVariant::Dark));
+        assert_eq!(
+            parse_apps_use_light_theme(&output.replace("0x0", "0x1")),
+            Some(ThemeVariant::Light)
+        );
+        assert_eq!(parse_apps_use_light_theme("ERROR"), None);
+    }
+
+    #[test]
+    fn test_parse_portal_color_scheme() {
+        assert_eq!(
+            parse_portal_color_scheme("(<<uint32 1>>,)\n"),
+            Some(ThemeVariant::Dark)
+        );
+        assert_eq!(
+            parse_portal_color_scheme("(<uint32 2>,)\n"),
+            Some(ThemeVariant::Light)
+        );
+        assert_eq!(parse_portal_color_scheme("(<<uint32 0>>,)\n"), None);
+    }
+
+    #[test]
+    fn test_parse_gsettings() {
+        assert_eq!(
+            parse_gsettings_color_scheme("'prefer-dark'\n"),
+            Some(ThemeVariant::Dark)
+        );
+        assert_eq!(parse_gsettings_color_scheme("'default'\n"), None);
+        assert_eq!(parse_gtk_theme("'Adwaita-dark'\n"), ThemeVariant::Dark);
+        assert_eq!(parse_gtk_theme("'Adwaita'\n"), ThemeVariant::Light);
+    }
+}
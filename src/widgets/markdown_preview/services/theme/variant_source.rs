@@ -0,0 +1,168 @@
+//! Where the active [`ThemeVariant`] comes from.
+//!
+//! A [`VariantSource`] either pins the variant, follows the operating
+//! system's dark/light preference, or switches on a daily schedule. Use a
+//! [`VariantWatcher`](crate::widgets::markdown_preview::services::theme::VariantWatcher)
+//! to keep the variant up to date while the app runs.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use ratatui_toolkit::services::theme::{TimeOfDay, VariantSource};
+//!
+//! // Dark from 19:00 until 07:00 the next morning.
+//! let source = VariantSource::Schedule {
+//!     dark_start: TimeOfDay::new(19, 0),
+//!     dark_end: TimeOfDay::new(7, 0),
+//! };
+//! ```
+
+use crate::widgets::markdown_preview::services::theme::ThemeVariant;
+
+/// A local wall-clock time, to the minute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimeOfDay {
+    /// Hour, `0..24`.
+    pub hour: u8,
+    /// Minute, `0..60`.
+    pub minute: u8,
+}
+
+impl TimeOfDay {
+    /// Creates a time of day, clamping out-of-range values to 23:59.
+    pub fn new(hour: u8, minute: u8) -> Self {
+        Self {
+            hour: hour.min(23),
+            minute: minute.min(59),
+        }
+    }
+
+    /// Parses `HH:MM`, such as `"07:30"`.
+    pub fn parse(text: &str) -> Option<Self> {
+        let (hour, minute) = text.trim().split_once(':')?;
+        let hour: u8 = hour.parse().ok()?;
+        let minute: u8 = minute.parse().ok()?;
+        (hour < 24 && minute < 60).then_some(Self { hour, minute })
+    }
+
+    fn minutes(self) -> u16 {
+        self.hour as u16 * 60 + self.minute as u16
+    }
+}
+
+/// How the theme variant is chosen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VariantSource {
+    /// Always use this variant.
+    Fixed(ThemeVariant),
+
+    /// Follow the operating system's dark/light preference.
+    FollowOs,
+
+    /// Use the dark variant from `dark_start` until `dark_end`, local time,
+    /// and the light variant otherwise.
+    ///
+    /// The dark period may wrap past midnight.
+    Schedule {
+        /// When the dark variant starts.
+        dark_start: TimeOfDay,
+        /// When the dark variant ends.
+        dark_end: TimeOfDay,
+    },
+}
+
+impl Default for VariantSource {
+    fn default() -> Self {
+        Self::Fixed(ThemeVariant::default())
+    }
+}
+
+impl VariantSource {
+    /// Resolves the variant from the detected OS preference and local time.
+    ///
+    /// Returns `fallback` when the source needs something that could not be
+    /// detected.
+    pub fn resolve(
+        &self,
+        fallback: ThemeVariant,
+        os_variant: Option<ThemeVariant>,
+        now: Option<TimeOfDay>,
+    ) -> ThemeVariant {
+        match *self {
+            Self::Fixed(variant) => variant,
+            Self::FollowOs => os_variant.unwrap_or(fallback),
+            Self::Schedule {
+                dark_start,
+                dark_end,
+            } => match now {
+                Some(now) if is_within(now, dark_start, dark_end) => ThemeVariant::Dark,
+                Some(_) => ThemeVariant::Light,
+                None => fallback,
+            },
+        }
+    }
+}
+
+/// Whether `now` falls in `[start, end)`, wrapping past midnight when
+/// `end` is earlier than `start`.
+fn is_within(now: TimeOfDay, start: TimeOfDay, end: TimeOfDay) -> bool {
+    let (now, start, end) = (now.minutes(), start.minutes(), end.minutes());
+    if start <= end {
+        start <= now && now < end
+    } else {
+        now >= start || now < end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(start: (u8, u8), end: (u8, u8)) -> VariantSource {
+        VariantSource::Schedule {
+            dark_start: TimeOfDay::new(start.0, start.1),
+            dark_end: TimeOfDay::new(end.0, end.1),
+        }
+    }
+
+    fn at(hour: u8, minute: u8) -> Option<TimeOfDay> {
+        Some(TimeOfDay::new(hour, minute))
+    }
+
+    #[test]
+    fn test_schedule_wraps_past_midnight() {
+        let source = schedule((19, 0), (7, 0));
+        let light = ThemeVariant::Light;
+        assert_eq!(source.resolve(light, None, at(18, 59)), ThemeVariant::Light);
+        assert_eq!(source.resolve(light, None, at(19, 0)), ThemeVariant::Dark);
+        assert_eq!(source.resolve(light, None, at(2, 30)), ThemeVariant::Dark);
+        assert_eq!(source.resolve(light, None, at(7, 0)), ThemeVariant::Light);
+    }
+
+    #[test]
+    fn test_schedule_within_one_day() {
+        let source = schedule((12, 0), (13, 30));
+        let dark = ThemeVariant::Dark;
+        assert_eq!(source.resolve(dark, None, at(11, 0)), ThemeVariant::Light);
+        assert_eq!(source.resolve(dark, None, at(13, 29)), ThemeVariant::Dark);
+        assert_eq!(source.resolve(dark, None, at(13, 30)), ThemeVariant::Light);
+    }
+
+    #[test]
+    fn test_undetected_inputs_use_fallback() {
+        let light = ThemeVariant::Light;
+        assert_eq!(VariantSource::FollowOs.resolve(light, None, None), light);
+        assert_eq!(
+            VariantSource::FollowOs.resolve(light, Some(ThemeVariant::Dark), None),
+            ThemeVariant::Dark
+        );
+        assert_eq!(schedule((19, 0), (7, 0)).resolve(light, None, None), light);
+    }
+
+    #[test]
+    fn test_parse_time_of_day() {
+        assert_eq!(TimeOfDay::parse("07:05\n"), Some(TimeOfDay::new(7, 5)));
+        assert_eq!(TimeOfDay::parse("24:00"), None);
+        assert_eq!(TimeOfDay::parse("noon"), None);
+    }
+}
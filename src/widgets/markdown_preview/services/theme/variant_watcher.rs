@@ -0,0 +1,312 @@
+//! Keeps the theme variant in step with its [`VariantSource`].
+//!
+//! # Example
+//!
+//! ```rust,ignore,no_run
+//! use ratatui_toolkit::services::theme::{VariantSource, VariantWatcher};
+//!
+//! let mut watcher = VariantWatcher::new(VariantSource::FollowOs);
+//!
+//! // On every tick of the event loop:
+//! if let Some(variant) = watcher.poll() {
+//!     // Reload the theme with `variant` and redraw.
+//! }
+//! ```
+
+use std::fmt;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::widgets::markdown_preview::services::theme::os_appearance::{
+    detect_os_variant, local_time_of_day,
+};
+use crate::widgets::markdown_preview::services::theme::{ThemeVariant, TimeOfDay, VariantSource};
+
+/// How often [`VariantWatcher::poll`] re-checks by default.
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Callback run when the resolved variant changes.
+pub type VariantChangeFn = Box<dyn FnMut(ThemeVariant) + Send>;
+
+/// Resolves a [`VariantSource`] and reports when the variant changes.
+///
+/// The variant is resolved when the watcher is created, then again on
+/// [`poll`](Self::poll) once the check interval has passed, or on
+/// [`refresh`](Self::refresh). Changes are returned from those methods and
+/// passed to the [`on_change`](Self::on_change) callback, so an app can
+/// either react in its tick handler or from the callback.
+///
+/// Asking the OS for its preference runs a command line tool, so while
+/// following the OS a background thread does that every interval and
+/// [`poll`](Self::poll) only picks up its results. Creating the watcher,
+/// [`refresh`](Self::refresh) and [`set_source`](Self::set_source) detect
+/// on the calling thread.
+///
+/// When the OS preference or local time cannot be detected, the watcher
+/// silently keeps its current variant, as if the source were
+/// [`VariantSource::Fixed`].
+pub struct VariantWatcher {
+    source: VariantSource,
+    variant: ThemeVariant,
+    interval: Duration,
+    last_check: Instant,
+    on_change: Option<VariantChangeFn>,
+    detect: fn() -> Option<ThemeVariant>,
+    clock: fn() -> Option<TimeOfDay>,
+    detector: Option<Detector>,
+}
+
+/// A background thread re-detecting the OS preference every interval.
+///
+/// The thread stops once this is dropped.
+struct Detector {
+    results: Receiver<Option<ThemeVariant>>,
+    _stop: Sender<()>,
+}
+
+impl Detector {
+    fn spawn(detect: fn() -> Option<ThemeVariant>, interval: Duration) -> Self {
+        let (result_tx, results) = mpsc::channel();
+        let (stop, stop_rx) = mpsc::channel::<()>();
+        thread::spawn(move || {
+            while let Err(RecvTimeoutError::Timeout) = stop_rx.recv_timeout(interval) {
+                if result_tx.send(detect()).is_err() {
+                    break;
+                }
+            }
+        });
+        Self {
+            results,
+            _stop: stop,
+        }
+    }
+
+    /// The latest detection finished since the last call, if any.
+    fn latest(&self) -> Option<Option<ThemeVariant>> {
+        self.results.try_iter().last()
+    }
+}
+
+impl VariantWatcher {
+    /// Creates a watcher and resolves `source` immediately, falling back to
+    /// the dark variant.
+    pub fn new(source: VariantSource) -> Self {
+        Self::with_fallback(source, ThemeVariant::default())
+    }
+
+    /// Creates a watcher that starts from `fallback` when `source` cannot
+    /// be resolved.
+    pub fn with_fallback(source: VariantSource, fallback: ThemeVariant) -> Self {
+        let mut watcher = Self {
+            source,
+            variant: fallback,
+            interval: DEFAULT_INTERVAL,
+            last_check: Instant::now(),
+            on_change: None,
+            detect: detect_os_variant,
+            clock: local_time_of_day,
+            detector: None,
+        };
+        watcher.variant = watcher.resolve();
+        watcher.restart_detector();
+        watcher
+    }
+
+    /// Sets how often [`poll`](Self::poll) re-checks the source.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self.restart_detector();
+        self
+    }
+
+    /// Sets a callback run whenever the variant changes.
+    pub fn on_change(mut self, callback: impl FnMut(ThemeVariant) + Send + 'static) -> Self {
+        self.on_change = Some(Box::new(callback));
+        self
+    }
+
+    /// The current variant.
+    pub fn variant(&self) -> ThemeVariant {
+        self.variant
+    }
+
+    /// The source the variant is resolved from.
+    pub fn source(&self) -> VariantSource {
+        self.source
+    }
+
+    /// Switches to `source` and resolves it immediately.
+    ///
+    /// Returns the new variant if it changed.
+    pub fn set_source(&mut self, source: VariantSource) -> Option<ThemeVariant> {
+        self.source = source;
+        let changed = self.refresh();
+        self.restart_detector();
+        changed
+    }
+
+    /// Re-checks the source now, regardless of the interval.
+    ///
+    /// Returns the new variant if it changed.
+    pub fn refresh(&mut self) -> Option<ThemeVariant> {
+        self.last_check = Instant::now();
+        let variant = self.resolve();
+        self.apply(variant)
+    }
+
+    /// Re-checks the source if the interval has passed since the last check.
+    ///
+    /// Call this from the app's tick handler. Returns the new variant if it
+    /// changed. A [`VariantSource::Fixed`] source is never re-checked, and
+    /// an OS-following one only takes the latest result of the background
+    /// detection, so this never blocks.
+    pub fn poll(&mut self) -> Option<ThemeVariant> {
+        match self.source {
+            VariantSource::Fixed(_) => None,
+            VariantSource::FollowOs => {
+                let os_variant = self.detector.as_ref()?.latest()?;
+                self.last_check = Instant::now();
+                let variant = self.source.resolve(self.variant, os_variant, None);
+                self.apply(variant)
+            }
+            VariantSource::Schedule { .. } => {
+                if self.last_check.elapsed() < self.interval {
+                    return None;
+                }
+                self.refresh()
+            }
+        }
+    }
+
+    /// Switches to `variant`, running the callback if it changed.
+    fn apply(&mut self, variant: ThemeVariant) -> Option<ThemeVariant> {
+        if variant == self.variant {
+            return None;
+        }
+        self.variant = variant;
+        if let Some(callback) = self.on_change.as_mut() {
+            callback(variant);
+        }
+        Some(variant)
+    }
+
+    /// Runs background detection while following the OS, and stops it
+    /// otherwise.
+    fn restart_detector(&mut self) {
+        self.detector = matches!(self.source, VariantSource::FollowOs)
+            .then(|| Detector::spawn(self.detect, self.interval));
+    }
+
+    fn resolve(&self) -> ThemeVariant {
+        let os_variant = match self.source {
+            VariantSource::FollowOs => (self.detect)(),
+            _ => None,
+        };
+        let now = match self.source {
+            VariantSource::Schedule { .. } => (self.clock)(),
+            _ => None,
+        };
+        self.source.resolve(self.variant, os_variant, now)
+    }
+}
+
+impl fmt::Debug for VariantWatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VariantWatcher")
+            .field("source", &self.source)
+            .field("variant", &self.variant)
+            .field("interval", &self.interval)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    fn os_dark() -> Option<ThemeVariant> {
+        Some(ThemeVariant::Dark)
+    }
+
+    fn os_light() -> Option<ThemeVariant> {
+        Some(ThemeVariant::Light)
+    }
+
+    fn os_unknown() -> Option<ThemeVariant> {
+        None
+    }
+
+    fn watcher(detect: fn() -> Option<ThemeVariant>) -> VariantWatcher {
+        let mut watcher = VariantWatcher::with_fallback(
+            VariantSource::Fixed(ThemeVariant::Light),
+            ThemeVariant::Light,
+        )
+        .interval(Duration::from_millis(1));
+        watcher.detect = detect;
+        watcher
+    }
+
+    #[test]
+    fn test_follow_os_reports_changes() {
+        static OS_LIGHT: AtomicBool = AtomicBool::new(false);
+        fn os_switching() -> Option<ThemeVariant> {
+            Some(if OS_LIGHT.load(Ordering::SeqCst) {
+                ThemeVariant::Light
+            } else {
+                ThemeVariant::Dark
+            })
+        }
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut watcher = watcher(os_switching).on_change({
+            let seen = Arc::clone(&seen);
+            move |variant| seen.lock().unwrap().push(variant)
+        });
+
+        assert_eq!(
+            watcher.set_source(VariantSource::FollowOs),
+            Some(ThemeVariant::Dark)
+        );
+        assert_eq!(watcher.poll(), None);
+
+        OS_LIGHT.store(true, Ordering::SeqCst);
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let mut changed = None;
+        while changed.is_none() && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(1));
+            changed = watcher.poll();
+        }
+        assert_eq!(changed, Some(ThemeVariant::Light));
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![ThemeVariant::Dark, ThemeVariant::Light]
+        );
+    }
+
+    #[test]
+    fn test_failed_detection_keeps_current_variant() {
+        let mut watcher = watcher(os_unknown);
+        assert_eq!(watcher.set_source(VariantSource::FollowOs), None);
+        assert_eq!(watcher.variant(), ThemeVariant::Light);
+
+        watcher.clock = || None;
+        let schedule = VariantSource::Schedule {
+            dark_start: TimeOfDay::new(0, 0),
+            dark_end: TimeOfDay::new(23, 59),
+        };
+        assert_eq!(watcher.set_source(schedule), None);
+        assert_eq!(watcher.variant(), ThemeVariant::Light);
+    }
+
+    #[test]
+    fn test_poll_waits_for_interval() {
+        let mut watcher = watcher(os_light).interval(Duration::from_secs(3600));
+        watcher.set_source(VariantSource::FollowOs);
+        watcher.detect = os_dark;
+        assert_eq!(watcher.poll(), None);
+        assert_eq!(watcher.refresh(), Some(ThemeVariant::Dark));
+    }
+}
@@ -0,0 +1,704 @@
+//! Render-time decorations for prose text.
+//!
+//! A [`TextDecorator`] looks at the plain text of paragraphs, list items and
+//! blockquotes and returns [`DecorationSpan`]s that restyle, replace or
+//! append to parts of it. Code blocks and inline code are never decorated.
+//!
+//! Decorations only change what is drawn: selecting and copying decorated
+//! text yields the original markdown source text.
+//!
+//! # Built-in Decorators
+//!
+//! - [`RelativeTimeDecorator`]: appends a dim relative time to ISO-8601 /
+//!   RFC-3339 timestamps, e.g. `2024-11-02T10:31Z (3 weeks ago)`
+//! - [`ThousandsSeparatorDecorator`]: groups the digits of standalone
+//!   integers above a threshold, e.g. `1234567` → `1,234,567`
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use ratatui::style::{Color, Modifier, Style};
+//! use ratkit::widgets::markdown_preview::widgets::markdown_widget::extensions::decorations::{
+//!     DecorationSpan, RelativeTimeDecorator, TextDecorator,
+//! };
+//!
+//! struct Tickets;
+//!
+//! impl TextDecorator for Tickets {
+//!     fn name(&self) -> &str {
+//!         "tickets"
+//!     }
+//!
+//!     fn decorate(&self, text: &str) -> Vec<DecorationSpan> {
+//!         text.match_indices("PROJ-")
+//!             .map(|(start, _)| {
+//!                 let end = text[start + 5..]
+//!                     .find(|c: char| !c.is_ascii_digit())
+//!                     .map_or(text.len(), |len| start + 5 + len);
+//!                 DecorationSpan::styled(
+//!                     start..end,
+//!                     Style::default().fg(Color::Cyan).add_modifier(Modifier::UNDERLINED),
+//!                 )
+//!             })
+//!             .collect()
+//!     }
+//! }
+//!
+//! let widget = MarkdownWidget::from_state(&state)
+//!     .with_decorator(RelativeTimeDecorator::new())
+//!     .with_decorator(Tickets);
+//! ```
+
+use std::ops::Range;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::TextSegment;
+
+/// Finds parts of prose text to decorate.
+pub trait TextDecorator {
+    /// Name used to toggle the decorator in
+    /// [`DisplaySettings`](crate::widgets::markdown_preview::widgets::markdown_widget::state::DisplaySettings).
+    fn name(&self) -> &str;
+
+    /// Returns the decorations for `text`.
+    ///
+    /// `text` is a run of unformatted prose; spans index into it by byte.
+    /// Spans that overlap an earlier span, or that do not fall on character
+    /// boundaries, are ignored.
+    fn decorate(&self, text: &str) -> Vec<DecorationSpan>;
+}
+
+/// A decoration of part of a prose text run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecorationSpan {
+    /// Byte range of the decorated text.
+    pub range: Range<usize>,
+    /// Style patched over the decorated text.
+    pub style: Style,
+    /// Text drawn in place of the decorated text, if any.
+    pub replacement: Option<String>,
+    /// Text and style drawn after the decorated text, if any.
+    pub suffix: Option<(String, Style)>,
+}
+
+impl DecorationSpan {
+    /// Restyles `range` without changing its text.
+    pub fn styled(range: Range<usize>, style: Style) -> Self {
+        Self {
+            range,
+            style,
+            replacement: None,
+            suffix: None,
+        }
+    }
+
+    /// Draws `replacement` in place of `range`.
+    pub fn replace(range: Range<usize>, replacement: impl Into<String>) -> Self {
+        Self {
+            range,
+            style: Style::default(),
+            replacement: Some(replacement.into()),
+            suffix: None,
+        }
+    }
+
+    /// Draws `text` after `range`.
+    pub fn append(range: Range<usize>, text: impl Into<String>, style: Style) -> Self {
+        Self {
+            range,
+            style: Style::default(),
+            replacement: None,
+            suffix: Some((text.into(), style)),
+        }
+    }
+}
+
+/// Where decorated text ended up in the rendered lines.
+///
+/// Selecting any part of the range copies `original` once in place of the
+/// drawn characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecorationRange {
+    /// Rendered line index.
+    pub row: usize,
+    /// First character column of the drawn text.
+    pub start: usize,
+    /// Character column one past the drawn text.
+    pub end: usize,
+    /// Source text the drawn text stands for (empty for appended text).
+    pub original: String,
+}
+
+/// Applies `decorators` to the plain segments of `segments`.
+///
+/// Returns `None` if nothing was decorated.
+pub(crate) fn decorate_segments(
+    segments: &[TextSegment],
+    decorators: &[&dyn TextDecorator],
+) -> Option<Vec<TextSegment>> {
+    let mut decorated = false;
+    let mut result = Vec::with_capacity(segments.len());
+
+    for segment in segments {
+        let TextSegment::Plain(text) = segment else {
+            result.push(segment.clone());
+            continue;
+        };
+
+        let mut spans: Vec<DecorationSpan> = decorators
+            .iter()
+            .flat_map(|decorator| decorator.decorate(text))
+            .filter(|span| {
+                span.range.start <= span.range.end
+                    && span.range.end <= text.len()
+                    && text.is_char_boundary(span.range.start)
+                    && text.is_char_boundary(span.range.end)
+            })
+            .collect();
+        if spans.is_empty() {
+            result.push(segment.clone());
+            continue;
+        }
+        spans.sort_by_key(|span| span.range.start);
+
+        let mut cursor = 0;
+        for span in spans {
+            if span.range.start < cursor {
+                continue;
+            }
+            decorated = true;
+            if span.range.start > cursor {
+                result.push(TextSegment::Plain(
+                    text[cursor..span.range.start].to_string(),
+                ));
+            }
+
+            let source = &text[span.range.clone()];
+            if span.replacement.is_none() && span.style == Style::default() {
+                if !source.is_empty() {
+                    result.push(TextSegment::Plain(source.to_string()));
+                }
+            } else {
+                result.push(TextSegment::Decorated {
+                    original: span
+                        .replacement
+                        .as_ref()
+                        .filter(|replacement| replacement.as_str() != source)
+                        .map(|_| source.to_string()),
+                    text: span.replacement.unwrap_or_else(|| source.to_string()),
+                    style: span.style,
+                });
+            }
+            if let Some((suffix, style)) = span.suffix {
+                result.push(TextSegment::Decorated {
+                    text: suffix,
+                    style,
+                    original: Some(String::new()),
+                });
+            }
+            cursor = span.range.end;
+        }
+        if cursor < text.len() {
+            result.push(TextSegment::Plain(text[cursor..].to_string()));
+        }
+    }
+
+    decorated.then_some(result)
+}
+
+/// Locates the decorated segments of one element in its rendered lines.
+///
+/// Wrapping only changes whitespace, so each segment's words appear in the
+/// rendered text in order. Words are matched one after another from a
+/// cursor; a segment whose words cannot all be found is left out.
+pub(crate) fn locate_decorations(
+    lines: &[Line<'static>],
+    first_row: usize,
+    segments: &[TextSegment],
+) -> Vec<DecorationRange> {
+    let rows: Vec<Vec<char>> = lines
+        .iter()
+        .map(|line| {
+            line.spans
+                .iter()
+                .flat_map(|span| span.content.chars())
+                .collect()
+        })
+        .collect();
+
+    let mut ranges = Vec::new();
+    let mut cursor = (0, 0);
+
+    for segment in segments {
+        let (text, original) = match segment {
+            TextSegment::Decorated { text, original, .. } => (text.as_str(), original.as_ref()),
+            TextSegment::Plain(text)
+            | TextSegment::Bold(text)
+            | TextSegment::Italic(text)
+            | TextSegment::BoldItalic(text)
+            | TextSegment::InlineCode(text)
+            | TextSegment::Strikethrough(text)
+            | TextSegment::Html(text)
+            | TextSegment::Link { text, .. } => (text.as_str(), None),
+            TextSegment::Checkbox(_) => continue,
+        };
+
+        let mut found: Vec<(usize, usize, usize)> = Vec::new();
+        for word in text.split_whitespace() {
+            let word: Vec<char> = word.chars().collect();
+            let Some((row, col)) = find_word(&rows, cursor, &word) else {
+                found.clear();
+                break;
+            };
+            cursor = (row, col + word.len());
+            found.push((row, col, col + word.len()));
+        }
+
+        let Some(original) = original else {
+            continue;
+        };
+        let Some(&(first_row_idx, first_col, _)) = found.first() else {
+            continue;
+        };
+
+        // Appended text usually starts with a space; copying drops it too
+        let leading = text.chars().take_while(|c| c.is_whitespace()).count();
+        let mut range = DecorationRange {
+            row: first_row + first_row_idx,
+            start: first_col.saturating_sub(leading),
+            end: first_col,
+            original: original.clone(),
+        };
+        for (row, start, end) in found {
+            if first_row + row != range.row {
+                ranges.push(std::mem::replace(
+                    &mut range,
+                    DecorationRange {
+                        row: first_row + row,
+                        start,
+                        end,
+                        original: String::new(),
+                    },
+                ));
+            }
+            range.end = end;
+        }
+        ranges.push(range);
+    }
+
+    ranges
+}
+
+pub(crate) fn find_word(
+    rows: &[Vec<char>],
+    from: (usize, usize),
+    word: &[char],
+) -> Option<(usize, usize)> {
+    if word.is_empty() {
+        return Some(from);
+    }
+    for (row_idx, row) in rows.iter().enumerate().skip(from.0) {
+        let start = if row_idx == from.0 { from.1 } else { 0 };
+        if row.len() < word.len() || start > row.len() - word.len() {
+            continue;
+        }
+        if let Some(col) =
+            (start..=row.len() - word.len()).find(|&col| &row[col..col + word.len()] == word)
+        {
+            return Some((row_idx, col));
+        }
+    }
+    None
+}
+
+/// Appends a dim relative time after ISO-8601 / RFC-3339 timestamps.
+///
+/// Recognizes `YYYY-MM-DD`, optionally followed by `T` or a space and
+/// `HH:MM[:SS[.fff]]`, and an optional `Z` or `±HH[:]MM` offset. Timestamps
+/// without an offset are read as UTC.
+///
+/// Relative times are computed when the document is rendered and kept with
+/// the cached lines, so they only advance when the render cache is rebuilt.
+#[derive(Debug, Clone, Default)]
+pub struct RelativeTimeDecorator {
+    now: Option<SystemTime>,
+    style: Option<Style>,
+}
+
+impl RelativeTimeDecorator {
+    /// Decorator name used for toggling.
+    pub const NAME: &'static str = "relative-time";
+
+    /// Creates a decorator relative to the current time.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Measures relative times from `now` instead of the current time.
+    pub fn with_now(mut self, now: SystemTime) -> Self {
+        self.now = Some(now);
+        self
+    }
+
+    /// Sets the style of the appended relative time (dim by default).
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+}
+
+impl TextDecorator for RelativeTimeDecorator {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn decorate(&self, text: &str) -> Vec<DecorationSpan> {
+        let now = self.now.unwrap_or_else(SystemTime::now);
+        let style = self.style.unwrap_or_else(|| {
+            Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::DIM)
+        });
+
+        let bytes = text.as_bytes();
+        let mut spans = Vec::new();
+        let mut pos = 0;
+        while pos < bytes.len() {
+            if pos > 0 && bytes[pos - 1].is_ascii_alphanumeric() {
+                pos += 1;
+                continue;
+            }
+            match parse_timestamp(&bytes[pos..]) {
+                Some((len, time)) => {
+                    spans.push(DecorationSpan::append(
+                        pos..pos + len,
+                        format!(" ({})", relative_time(time, now)),
+                        style,
+                    ));
+                    pos += len;
+                }
+                None => pos += 1,
+            }
+        }
+        spans
+    }
+}
+
+/// Parses a timestamp at the start of `bytes`, returning its length and
+/// the time it names.
+fn parse_timestamp(bytes: &[u8]) -> Option<(usize, SystemTime)> {
+    let number = |at: usize, len: usize| -> Option<i64> {
+        let digits = bytes.get(at..at + len)?;
+        digits
+            .iter()
+            .all(u8::is_ascii_digit)
+            .then(|| digits.iter().fold(0, |n, d| n * 10 + i64::from(d - b'0')))
+    };
+    let byte = |at: usize| bytes.get(at).copied();
+
+    let year = number(0, 4)?;
+    (byte(4) == Some(b'-')).then_some(())?;
+    let month = number(5, 2)?;
+    (byte(7) == Some(b'-')).then_some(())?;
+    let day = number(8, 2)?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    let mut len = 10;
+    let mut seconds = 0;
+    if matches!(byte(10), Some(b'T' | b't' | b' ')) && byte(13) == Some(b':') {
+        if let (Some(hour), Some(minute)) = (number(11, 2), number(14, 2)) {
+            if hour > 23 || minute > 59 {
+                return None;
+            }
+            len = 16;
+            seconds = hour * 3600 + minute * 60;
+            if byte(16) == Some(b':') {
+                if let Some(second) = number(17, 2).filter(|&s| s <= 60) {
+                    len = 19;
+                    seconds += second;
+                    if byte(19) == Some(b'.') && byte(20).is_some_and(|b| b.is_ascii_digit()) {
+                        len = 20;
+                        while byte(len).is_some_and(|b| b.is_ascii_digit()) {
+                            len += 1;
+                        }
+                    }
+                }
+            }
+
+            match byte(len) {
+                Some(b'Z' | b'z') => len += 1,
+                Some(sign @ (b'+' | b'-')) => {
+                    let hours = number(len + 1, 2);
+                    let (minutes, offset_len) = if byte(len + 3) == Some(b':') {
+                        (number(len + 4, 2), 6)
+                    } else {
+                        (number(len + 3, 2), 5)
+                    };
+                    if let (Some(hours), Some(minutes)) = (hours, minutes) {
+                        let offset = hours * 3600 + minutes * 60;
+                        seconds -= if sign == b'+' { offset } else { -offset };
+                        len += offset_len;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if byte(len).is_some_and(|b| b.is_ascii_alphanumeric()) {
+        return None;
+    }
+
+    let secs = days_from_civil(year, month, day) * 86_400 + seconds;
+    let time = if secs >= 0 {
+        UNIX_EPOCH + Duration::from_secs(secs as u64)
+    } else {
+        UNIX_EPOCH - Duration::from_secs(secs.unsigned_abs())
+    };
+    Some((len, time))
+}
+
+/// Days from 1970-01-01 to the given proleptic Gregorian date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Formats the distance from `now` to `time`, e.g. "3 weeks ago".
+fn relative_time(time: SystemTime, now: SystemTime) -> String {
+    let (secs, future) = match now.duration_since(time) {
+        Ok(elapsed) => (elapsed.as_secs(), false),
+        Err(err) => (err.duration().as_secs(), true),
+    };
+
+    let (count, unit) = match secs {
+        0..=59 => return "just now".to_string(),
+        60..=3_599 => (secs / 60, "minute"),
+        3_600..=86_399 => (secs / 3_600, "hour"),
+        86_400..=604_799 => (secs / 86_400, "day"),
+        604_800..=2_591_999 => (secs / 604_800, "week"),
+        2_592_000..=31_535_999 => (secs / 2_592_000, "month"),
+        _ => (secs / 31_536_000, "year"),
+    };
+    let plural = if count == 1 { "" } else { "s" };
+
+    if future {
+        format!("in {} {}{}", count, unit, plural)
+    } else {
+        format!("{} {}{} ago", count, unit, plural)
+    }
+}
+
+/// Groups the digits of standalone integers above a threshold.
+///
+/// Numbers that are part of a word, a decimal, an already grouped number
+/// or a `#123` reference are left alone, as are numbers with leading zeros.
+#[derive(Debug, Clone)]
+pub struct ThousandsSeparatorDecorator {
+    threshold: u64,
+    separator: char,
+}
+
+impl Default for ThousandsSeparatorDecorator {
+    fn default() -> Self {
+        Self {
+            threshold: 9_999,
+            separator: ',',
+        }
+    }
+}
+
+impl ThousandsSeparatorDecorator {
+    /// Decorator name used for toggling.
+    pub const NAME: &'static str = "thousands-separator";
+
+    /// Creates a decorator that groups numbers above 9,999 with commas.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only groups numbers greater than `threshold`.
+    pub fn with_threshold(mut self, threshold: u64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Sets the group separator, e.g. `'.'` or a thin space for other locales.
+    pub fn with_separator(mut self, separator: char) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    fn format(&self, digits: &str) -> String {
+        let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i) % 3 == 0 {
+                grouped.push(self.separator);
+            }
+            grouped.push(digit);
+        }
+        grouped
+    }
+}
+
+impl TextDecorator for ThousandsSeparatorDecorator {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn decorate(&self, text: &str) -> Vec<DecorationSpan> {
+        let bytes = text.as_bytes();
+        let mut spans = Vec::new();
+        let mut pos = 0;
+        while pos < bytes.len() {
+            if !bytes[pos].is_ascii_digit() {
+                pos += 1;
+                continue;
+            }
+            let start = pos;
+            while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+                pos += 1;
+            }
+
+            let before = start.checked_sub(1).map(|at| bytes[at]);
+            let after = bytes.get(pos).copied();
+            let after_next = bytes.get(pos + 1).copied();
+            let joined_before = before.is_some_and(|b| {
+                b.is_ascii_alphanumeric() || matches!(b, b'_' | b'.' | b',' | b'#')
+            });
+            let joined_after = after.is_some_and(|b| b.is_ascii_alphanumeric() || b == b'_')
+                || (matches!(after, Some(b'.' | b','))
+                    && after_next.is_some_and(|b| b.is_ascii_digit()));
+            let digits = &text[start..pos];
+            if joined_before || joined_after || (digits.len() > 1 && digits.starts_with('0')) {
+                continue;
+            }
+
+            let above = digits.parse::<u64>().map_or(true, |n| n > self.threshold);
+            if above {
+                spans.push(DecorationSpan::replace(start..pos, self.format(digits)));
+            }
+        }
+        spans
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use super::{
+        decorate_segments, parse_timestamp, RelativeTimeDecorator, TextDecorator,
+        ThousandsSeparatorDecorator,
+    };
+    use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::TextSegment;
+
+    fn at(secs: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn parses_iso_and_rfc3339_timestamps() {
+        // 2024-11-02T10:31:00Z
+        let expected = at(1_730_543_460);
+        for text in [
+            "2024-11-02T10:31Z",
+            "2024-11-02 10:31:00",
+            "2024-11-02T10:31:00.250Z",
+            "2024-11-02T12:31+02:00",
+            "2024-11-02T05:31-0500",
+        ] {
+            let (len, time) = parse_timestamp(text.as_bytes()).unwrap();
+            assert_eq!(len, text.len(), "{}", text);
+            assert_eq!(
+                time.duration_since(expected).unwrap_or_default().as_secs(),
+                0,
+                "{}",
+                text
+            );
+        }
+
+        assert_eq!(parse_timestamp(b"2024-11-02").unwrap().0, 10);
+        assert!(parse_timestamp(b"2024-13-02").is_none());
+        assert!(parse_timestamp(b"2024-11-02x").is_none());
+    }
+
+    #[test]
+    fn appends_relative_time_after_timestamps() {
+        let decorator = RelativeTimeDecorator::new().with_now(at(1_730_543_460 + 21 * 86_400));
+        let text = "Deployed 2024-11-02T10:31Z, next 2024-12-01.";
+        let spans = decorator.decorate(text);
+
+        let suffixes: Vec<_> = spans
+            .iter()
+            .map(|span| {
+                (
+                    &text[span.range.clone()],
+                    span.suffix.as_ref().unwrap().0.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            suffixes,
+            [
+                ("2024-11-02T10:31Z", " (3 weeks ago)"),
+                ("2024-12-01", " (in 1 week)")
+            ]
+        );
+        assert!(decorator.decorate("build v2024-11-02").is_empty());
+    }
+
+    #[test]
+    fn groups_standalone_integers_above_threshold() {
+        let decorator = ThousandsSeparatorDecorator::new();
+        let text = "1234567 rows, 9999 ok, 12345.6 avg, #123456, id42000, 007000, -250000";
+        let replaced: Vec<_> = decorator
+            .decorate(text)
+            .into_iter()
+            .map(|span| (&text[span.range], span.replacement.unwrap()))
+            .collect();
+        assert_eq!(
+            replaced,
+            [
+                ("1234567", "1,234,567".to_string()),
+                ("250000", "250,000".to_string())
+            ]
+        );
+
+        let dotted = ThousandsSeparatorDecorator::new()
+            .with_threshold(999)
+            .with_separator('.');
+        assert_eq!(
+            dotted.decorate("1000")[0].replacement.as_deref(),
+            Some("1.000")
+        );
+    }
+
+    #[test]
+    fn decorates_only_plain_segments() {
+        let decorator = ThousandsSeparatorDecorator::new();
+        let segments = vec![
+            TextSegment::Plain("total 10000 and ".to_string()),
+            TextSegment::InlineCode("20000".to_string()),
+        ];
+
+        let decorated = decorate_segments(&segments, &[&decorator]).unwrap();
+        assert!(matches!(
+            &decorated[1],
+            TextSegment::Decorated { text, original: Some(original), .. }
+                if text == "10,000" && original == "10000"
+        ));
+        assert!(matches!(&decorated[3], TextSegment::InlineCode(code) if code == "20000"));
+        assert!(decorate_segments(&segments[1..], &[&decorator]).is_none());
+    }
+}
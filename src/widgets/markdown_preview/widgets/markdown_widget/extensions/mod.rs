@@ -6,16 +6,22 @@
 //!
 //! # Available Extensions
 //!
+//! - `decorations`: Render-time decorations for prose text
 //! - `scrollbar`: Custom scrollbar with accurate scroll tracking
 //! - `selection`: Mouse event handling for selection and navigation
 //! - `theme`: Color themes and syntax highlighting
 //! - `toc`: Table of Contents navigation widget
 
+pub mod decorations;
 pub mod scrollbar;
 pub mod selection;
 pub mod theme;
 pub mod toc;
 
+pub use decorations::{
+    DecorationRange, DecorationSpan, RelativeTimeDecorator, TextDecorator,
+    ThousandsSeparatorDecorator,
+};
 pub use scrollbar::{CustomScrollbar, ScrollbarConfig};
 pub use selection::{
     handle_click, handle_mouse_event, handle_mouse_event_with_double_click, should_render_line,
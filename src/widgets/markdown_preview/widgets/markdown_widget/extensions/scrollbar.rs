@@ -196,6 +196,8 @@ mod tests {
             current_line: 1,
             filter: None,
             filter_mode: false,
+            source_anchor: None,
+            top_anchor: None,
         };
         let offset = click_to_offset(0, area, &scroll);
         assert_eq!(offset, 0);
@@ -211,6 +213,8 @@ mod tests {
             current_line: 1,
             filter: None,
             filter_mode: false,
+            source_anchor: None,
+            top_anchor: None,
         };
         let offset = click_to_offset(19, area, &scroll);
         // Should be close to max_scroll (90)
@@ -227,6 +231,8 @@ mod tests {
             current_line: 1,
             filter: None,
             filter_mode: false,
+            source_anchor: None,
+            top_anchor: None,
         };
         let offset = click_to_offset(10, area, &scroll);
         // Should be roughly half of max_scroll (45)
@@ -393,6 +399,8 @@ mod thumb_bounds_tests {
             current_line: 1,
             filter: None,
             filter_mode: false,
+            source_anchor: None,
+            top_anchor: None,
         };
         let (y, height) = thumb_bounds(&scroll, 20, 1);
         assert_eq!(y, 0);
@@ -408,6 +416,8 @@ mod thumb_bounds_tests {
             current_line: 1,
             filter: None,
             filter_mode: false,
+            source_anchor: None,
+            top_anchor: None,
         };
         let (y, _height) = thumb_bounds(&scroll, 20, 1);
         assert_eq!(y, 0);
@@ -422,6 +432,8 @@ mod thumb_bounds_tests {
             current_line: 1,
             filter: None,
             filter_mode: false,
+            source_anchor: None,
+            top_anchor: None,
         };
         let (y, height) = thumb_bounds(&scroll, 20, 1);
         // Thumb should be at bottom: y + height = track_height
@@ -437,6 +449,8 @@ mod thumb_bounds_tests {
             current_line: 1,
             filter: None,
             filter_mode: false,
+            source_anchor: None,
+            top_anchor: None,
         };
         let (_y, height) = thumb_bounds(&scroll, 20, 3);
         assert!(height >= 3); // Should respect min_thumb_height
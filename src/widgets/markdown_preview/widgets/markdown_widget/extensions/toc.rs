@@ -11,6 +11,10 @@
 //! - Current heading highlight (blue in expanded, bright in compact)
 //! - Hover highlight for items
 //! - Click-to-scroll navigation
+//! - Keyboard focus mode with a selection marker, and collapsible heading
+//!   subtrees marked `▸` (collapsed) or `▾` (expanded)
+//! - Rail mode: one heading-level dot per row, for a TOC resized too narrow
+//!   to show text
 //!
 //! # Mouse Capture Requirement
 //!
@@ -69,6 +73,8 @@ pub struct Toc<'a> {
     pub(crate) config: TocConfig,
     /// Whether the TOC is in expanded mode.
     pub(crate) expanded: bool,
+    /// Whether the TOC is drawn as an icon-only rail.
+    pub(crate) rail: bool,
 }
 
 /// Hovered entry configuration for Toc widget.
@@ -122,6 +128,7 @@ impl<'a> Toc<'a> {
             toc_state,
             config: TocConfig::default(),
             expanded: false,
+            rail: false,
         }
     }
 
@@ -139,6 +146,21 @@ impl<'a> Toc<'a> {
         self
     }
 
+    /// Draw the TOC as an icon-only rail: one dot per heading, sized by
+    /// level. Takes precedence over the compact and expanded modes.
+    ///
+    /// # Arguments
+    ///
+    /// * `rail` - True for rail mode.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn rail(mut self, rail: bool) -> Self {
+        self.rail = rail;
+        self
+    }
+
     /// Set the TOC visual style mode.
     ///
     /// # Arguments
@@ -216,6 +238,7 @@ impl TocConfig {
     /// - Text style (using markdown text color)
     /// - Active style (using theme primary color)
     /// - Hover style (using theme accent with background_element)
+    /// - Focus style (using theme warning color, bold)
     /// - Background style (using theme background_panel)
     /// - Line style (using theme border color)
     /// - Active line style (using theme text color)
@@ -244,6 +267,9 @@ impl TocConfig {
         self.text_style = Style::default().fg(theme.text_muted);
         self.active_style = Style::default().fg(theme.primary);
         self.hover_style = Style::default().fg(theme.text).bg(theme.background_element);
+        self.focus_style = Style::default()
+            .fg(theme.warning)
+            .add_modifier(ratatui::style::Modifier::BOLD);
         self.background_style = Style::default().bg(theme.background_panel);
         self.line_style = Style::default().fg(theme.border);
         self.active_line_style = Style::default().fg(theme.text);
@@ -282,6 +308,8 @@ pub struct TocConfig {
     pub active_style: Style,
     /// Style for hovered heading.
     pub hover_style: Style,
+    /// Style for the heading selected in keyboard focus mode.
+    pub focus_style: Style,
     /// Style for the accent bar in clerk mode (left border indicator).
     pub accent_style: Style,
     /// Style for the active accent bar in clerk mode.
@@ -303,6 +331,12 @@ pub struct TocConfig {
     /// Spacing between lines in compact mode (in 1/8 cell units).
     /// 1 = tightest (8 lines per row), 8 = one line per row.
     pub line_spacing: u8,
+    /// Narrowest the TOC can be dragged to.
+    pub min_width: u16,
+    /// Widest the TOC can be dragged to.
+    pub max_width: u16,
+    /// A TOC dragged narrower than this is drawn as an icon-only rail.
+    pub rail_threshold: u16,
 }
 
 /// Line width calculation for TOC entries.
@@ -452,16 +486,25 @@ impl<'a> Toc<'a> {
             return None;
         }
 
-        if self.expanded {
-            // Calculate content area based on actual entries rather than passed area height
-            let content_area = get_expanded_content_area(area, &self.config, entries.len());
-            find_entry_at_position_expanded(
-                x,
-                y,
-                content_area,
-                entries,
-                self.toc_state.scroll_offset,
-            )
+        if self.rail {
+            let visible = self.toc_state.visible_entries();
+            let row = y.checked_sub(area.y)?;
+            if row >= area.height {
+                return None;
+            }
+            visible
+                .get(self.toc_state.scroll_offset + row as usize)
+                .copied()
+        } else if self.expanded {
+            // Rows only show the entries outside collapsed subtrees
+            let visible = self.toc_state.visible_entries();
+            let content_area = get_expanded_content_area(area, &self.config, visible.len());
+            if x < content_area.x || x >= content_area.x + content_area.width || y < content_area.y
+            {
+                return None;
+            }
+            let row = self.toc_state.scroll_offset + (y - content_area.y) as usize;
+            visible.get(row).copied()
         } else {
             find_entry_at_position_compact(y, area, &self.config, entries)
         }
@@ -807,8 +850,11 @@ fn get_line_offset(depth: u8) -> u16 {
 impl<'a> Toc<'a> {
     /// Render the TOC in expanded mode (full heading text).
     ///
-    /// Shows heading text with indentation based on level.
-    /// Active heading is shown in blue, hovered has background highlight.
+    /// Shows heading text with indentation based on level, leaving out
+    /// the entries inside collapsed subtrees. Active heading is shown in
+    /// blue, hovered has background highlight, and the entry selected in
+    /// focus mode uses the focus style. Headings with nested entries get a
+    /// collapse marker before their text.
     pub(crate) fn render_expanded(&self, area: Rect, buf: &mut Buffer) {
         let entries = &self.toc_state.entries;
         if entries.is_empty() || area.height == 0 {
@@ -819,13 +865,29 @@ impl<'a> Toc<'a> {
         let padding_right: u16 = 1;
         let available_width = area.width.saturating_sub(padding_left + padding_right) as usize;
 
+        let visible = self.toc_state.visible_entries();
         let visible_count = area.height as usize;
-        let start_idx = self.toc_state.scroll_offset;
+        let mut start_row = self.toc_state.scroll_offset;
 
         let hovered_index = self.toc_state.hovered_entry;
         let active_index: Option<usize> = None;
+        let selected_index = if self.toc_state.focused {
+            self.toc_state.selected_entry()
+        } else {
+            None
+        };
 
-        for (display_idx, entry_idx) in (start_idx..entries.len()).take(visible_count).enumerate() {
+        // Keep the selection on screen in focus mode
+        if let Some(selected_row) =
+            selected_index.and_then(|selected| visible.iter().position(|&idx| idx == selected))
+        {
+            if selected_row >= start_row + visible_count {
+                start_row = selected_row + 1 - visible_count;
+            }
+        }
+
+        for (display_idx, row) in (start_row..visible.len()).take(visible_count).enumerate() {
+            let entry_idx = visible[row];
             let entry = &entries[entry_idx];
             let y = area.y + display_idx as u16;
 
@@ -839,13 +901,16 @@ impl<'a> Toc<'a> {
             let available_for_text = available_width.saturating_sub(indent as usize);
             let display_text = truncate_text(&entry.text, available_for_text);
 
-            let (text_style, fill_bg) = if Some(entry_idx) == hovered_index {
+            let (mut text_style, fill_bg) = if Some(entry_idx) == hovered_index {
                 (self.config.hover_style, true)
             } else if Some(entry_idx) == active_index {
                 (self.config.active_style, false)
             } else {
                 (self.config.text_style, false)
             };
+            if Some(entry_idx) == selected_index {
+                text_style = text_style.patch(self.config.focus_style);
+            }
 
             let is_active_or_hovered =
                 Some(entry_idx) == hovered_index || Some(entry_idx) == active_index;
@@ -858,16 +923,16 @@ impl<'a> Toc<'a> {
             };
 
             if self.config.style == TocStyle::Clerk {
+                let prev_depth = (row > start_row).then(|| entries[visible[row - 1]].level);
+                let next_depth = visible.get(row + 1).map(|&idx| entries[idx].level);
                 self.render_clerk_lines(
                     area,
                     buf,
                     y,
                     entry.level,
                     line_offset,
-                    entry_idx,
-                    entries.len(),
-                    start_idx,
-                    hovered_index,
+                    prev_depth,
+                    next_depth,
                     accent_style,
                 );
             }
@@ -880,6 +945,17 @@ impl<'a> Toc<'a> {
                 }
             }
 
+            if self.toc_state.has_children(entry_idx) {
+                let marker = if self.toc_state.is_collapsed(entry_idx) {
+                    '▸'
+                } else {
+                    '▾'
+                };
+                if let Some(cell) = buf.cell_mut((area.x + indent - 1, y)) {
+                    cell.set_char(marker).set_style(accent_style);
+                }
+            }
+
             let x = area.x + indent;
             let full_text = display_text.to_string();
 
@@ -897,6 +973,9 @@ impl<'a> Toc<'a> {
         }
     }
 
+    /// Draw the clerk-style accent line for one row, given the levels of
+    /// the rows drawn above and below it.
+    #[allow(clippy::too_many_arguments)]
     fn render_clerk_lines(
         &self,
         area: Rect,
@@ -904,27 +983,13 @@ impl<'a> Toc<'a> {
         y: u16,
         depth: u8,
         line_offset: u16,
-        entry_idx: usize,
-        total_entries: usize,
-        start_idx: usize,
-        _hovered_index: Option<usize>,
+        prev_entry_depth: Option<u8>,
+        next_entry_depth: Option<u8>,
         accent_style: ratatui::style::Style,
     ) {
         let line_x = area.x + line_offset;
         let border_x = area.x + line_offset;
-        let next_entry_depth = if entry_idx + 1 < total_entries {
-            Some(self.toc_state.entries[entry_idx + 1].level)
-        } else {
-            None
-        };
-        let prev_entry_idx = if entry_idx > start_idx {
-            Some(entry_idx - 1)
-        } else {
-            None
-        };
-        let prev_entry_depth = prev_entry_idx
-            .map(|idx| self.toc_state.entries[idx].level)
-            .unwrap_or(depth);
+        let prev_entry_depth = prev_entry_depth.unwrap_or(depth);
 
         let upper_offset = get_line_offset(prev_entry_depth);
         let lower_offset = next_entry_depth.map(get_line_offset).unwrap_or(line_offset);
@@ -966,6 +1031,50 @@ impl<'a> Toc<'a> {
     }
 }
 
+/// Rail mode rendering for TOC (one dot per heading).
+impl<'a> Toc<'a> {
+    /// Render the TOC as an icon-only rail.
+    ///
+    /// Each row shows one entry outside collapsed subtrees as a dot whose
+    /// size follows the heading level, centered in the rail. The hovered
+    /// entry uses the active line style and the entry selected in focus
+    /// mode uses the focus style.
+    pub(crate) fn render_rail(&self, area: Rect, buf: &mut Buffer) {
+        fill_background(buf, area, self.panel_background_style());
+
+        let entries = &self.toc_state.entries;
+        let selected_index = if self.toc_state.focused {
+            self.toc_state.selected_entry()
+        } else {
+            None
+        };
+        let x = area.x + area.width.saturating_sub(1) / 2;
+
+        let visible = self.toc_state.visible_entries();
+        let rows = visible
+            .iter()
+            .skip(self.toc_state.scroll_offset)
+            .take(area.height as usize);
+        for (row, &entry_idx) in rows.enumerate() {
+            let dot = match entries[entry_idx].level {
+                1 => '●',
+                2 => '•',
+                _ => '·',
+            };
+            let style = if Some(entry_idx) == selected_index {
+                self.config.focus_style
+            } else if Some(entry_idx) == self.toc_state.hovered_entry {
+                self.config.active_line_style
+            } else {
+                self.config.line_style
+            };
+            if let Some(cell) = buf.cell_mut((x, area.y + row as u16)) {
+                cell.set_char(dot).set_style(style);
+            }
+        }
+    }
+}
+
 /// Truncate text to fit within a given width, adding ellipsis if needed.
 fn truncate_text(text: &str, max_width: usize) -> String {
     if text.width() <= max_width {
@@ -1129,6 +1238,9 @@ impl Default for TocConfig {
             text_style: Style::default().fg(Color::Rgb(160, 160, 160)),
             active_style: Style::default().fg(Color::Rgb(97, 175, 239)), // Blue
             hover_style: Style::default().fg(Color::White).bg(Color::Rgb(60, 60, 70)),
+            focus_style: Style::default()
+                .fg(Color::Rgb(230, 180, 80))
+                .add_modifier(ratatui::style::Modifier::BOLD),
             accent_style: Style::default()
                 .fg(Color::Rgb(160, 160, 160))
                 .add_modifier(ratatui::style::Modifier::DIM),
@@ -1141,6 +1253,9 @@ impl Default for TocConfig {
             title_style: Style::default().fg(Color::Rgb(138, 99, 210)),  // Purple
             title: "TOC".to_string(),
             line_spacing: 2, // 2 dots per entry (tight spacing)
+            min_width: 3,
+            max_width: 60,
+            rail_threshold: 8,
         }
     }
 }
@@ -1153,7 +1268,9 @@ impl<'a> Widget for Toc<'a> {
             return;
         }
 
-        if self.expanded {
+        if self.rail {
+            self.render_rail(area, buf);
+        } else if self.expanded {
             // Expanded mode (hovered): show border + text entries
             for y in area.y..area.y + area.height {
                 for x in area.x..area.x + area.width {
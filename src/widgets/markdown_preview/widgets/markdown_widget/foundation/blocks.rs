@@ -0,0 +1,347 @@
+//! Top-level blocks of a document, for re-parsing only what changed.
+//!
+//! A document is split at the same stable boundaries
+//! [`StreamingMarkdown`](super::streaming::StreamingMarkdown) uses: text
+//! before a boundary never changes how the text after it parses, so each
+//! block can be parsed on its own and the elements of untouched blocks
+//! reused when the document is reloaded.
+//!
+//! Blocks are identified by the headings above them and their position
+//! under the nearest one, so a block keeps its [`BlockId`] when text is
+//! added or removed in another section.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+
+use super::elements::{ElementKind, MarkdownElement};
+use super::helpers::hash_content;
+use super::parser::parse_elements;
+use super::streaming::BoundaryScanner;
+use crate::widgets::markdown_preview::widgets::markdown_widget::extensions::theme::SyntaxHighlighter;
+
+/// Identity of a top-level block that survives edits elsewhere in the
+/// document: a hash of the heading path above the block and the block's
+/// index under its nearest heading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BlockId(u64);
+
+/// A top-level block of markdown source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceBlock {
+    /// Stable identity of the block.
+    pub id: BlockId,
+    /// Hash of the block's text.
+    pub hash: u64,
+    /// Byte range of the block in the document.
+    pub bytes: Range<usize>,
+    /// Source line the block starts on (1-indexed).
+    pub first_line: usize,
+    /// Number of source lines in the block.
+    pub line_count: usize,
+}
+
+impl SourceBlock {
+    /// Source lines (1-indexed, end exclusive) the block covers.
+    pub fn lines(&self) -> Range<usize> {
+        self.first_line..self.first_line + self.line_count
+    }
+}
+
+/// Splits `content` into top-level blocks.
+///
+/// A document with a link reference or footnote definition is one block,
+/// since a definition changes how text anywhere else parses.
+pub fn split_blocks(content: &str) -> Vec<SourceBlock> {
+    let mut scanner = BoundaryScanner::default();
+    let mut starts = vec![0];
+    let mut offset = 0;
+    for line in content.split_inclusive('\n') {
+        if scanner.scan_line(line) {
+            starts.push(offset);
+        }
+        offset += line.len();
+    }
+    if !scanner.incremental {
+        starts.truncate(1);
+    }
+
+    let mut blocks = Vec::with_capacity(starts.len());
+    let mut path: Vec<(usize, String)> = Vec::new();
+    let mut index = 0;
+    let mut seen = HashSet::new();
+    let mut first_line = 1;
+    for (i, &start) in starts.iter().enumerate() {
+        let end = starts.get(i + 1).copied().unwrap_or(content.len());
+        let text = &content[start..end];
+        let headings = atx_headings(text);
+
+        let leading = headings.first().filter(|heading| heading.0 == 0);
+        if let Some((_, level, heading)) = leading {
+            push_heading(&mut path, *level, heading);
+            index = 0;
+        }
+        let mut id = path_id(&path, index);
+        while !seen.insert(id) {
+            id = BlockId(id.0.wrapping_add(1));
+        }
+        index += 1;
+        for (_, level, heading) in headings.iter().skip(usize::from(leading.is_some())) {
+            push_heading(&mut path, *level, heading);
+            index = 0;
+        }
+
+        let line_count = text.lines().count();
+        blocks.push(SourceBlock {
+            id,
+            hash: hash_content(text),
+            bytes: start..end,
+            first_line,
+            line_count,
+        });
+        first_line += line_count;
+    }
+    blocks
+}
+
+/// Which blocks of a document changed between two versions of it.
+#[derive(Debug, Clone, Default)]
+pub struct BlockDiff {
+    old: Vec<SourceBlock>,
+    new: Vec<SourceBlock>,
+}
+
+impl BlockDiff {
+    /// Splits both versions into blocks and matches them up.
+    pub fn new(old: &str, new: &str) -> Self {
+        Self {
+            old: split_blocks(old),
+            new: split_blocks(new),
+        }
+    }
+
+    /// Blocks of the old version.
+    pub fn old_blocks(&self) -> &[SourceBlock] {
+        &self.old
+    }
+
+    /// Blocks of the new version.
+    pub fn new_blocks(&self) -> &[SourceBlock] {
+        &self.new
+    }
+
+    /// Whether the new version has a block with `block`'s identity and
+    /// text.
+    pub fn is_unchanged(&self, block: &SourceBlock) -> bool {
+        self.new
+            .iter()
+            .any(|new| new.id == block.id && new.hash == block.hash)
+    }
+
+    /// Blocks of the new version that are new or whose text changed.
+    pub fn changed(&self) -> impl Iterator<Item = &SourceBlock> + '_ {
+        let old: HashSet<(BlockId, u64)> = self
+            .old
+            .iter()
+            .map(|block| (block.id, block.hash))
+            .collect();
+        self.new
+            .iter()
+            .filter(move |block| !old.contains(&(block.id, block.hash)))
+    }
+
+    /// Where source line `line` (1-indexed) of the old version is in the
+    /// new one.
+    ///
+    /// A line keeps its offset into its block when the block is still
+    /// there, even if the block's text changed; if the block is gone the
+    /// line maps to `None`.
+    pub fn map_line(&self, line: usize) -> Option<usize> {
+        let old = self
+            .old
+            .iter()
+            .find(|block| block.lines().contains(&line))?;
+        let new = self.new.iter().find(|block| block.id == old.id)?;
+        let offset = line - old.first_line;
+        Some(new.first_line + offset.min(new.line_count.saturating_sub(1)))
+    }
+}
+
+/// A block and the elements it parsed to on its own.
+///
+/// The elements' source lines count from the block's first line and their
+/// section IDs from 1, as if the block were a document.
+#[derive(Debug, Clone)]
+pub struct ParsedBlock {
+    /// The block.
+    pub source: SourceBlock,
+    /// Elements of the block parsed on its own.
+    pub elements: Vec<MarkdownElement>,
+    /// Range of the block's elements in the assembled document.
+    pub range: Range<usize>,
+}
+
+/// Parses `content` block by block, reusing the elements of blocks in
+/// `previous` with the same identity and text.
+///
+/// Returns the blocks and the elements of the whole document, which equal
+/// those of a full parse.
+pub(crate) fn parse_blocks(
+    content: &str,
+    previous: &[ParsedBlock],
+    highlighter: &SyntaxHighlighter,
+) -> (Vec<ParsedBlock>, Vec<MarkdownElement>) {
+    let mut reusable: HashMap<(BlockId, u64), &ParsedBlock> = previous
+        .iter()
+        .map(|block| ((block.source.id, block.source.hash), block))
+        .collect();
+
+    let mut blocks: Vec<ParsedBlock> = split_blocks(content)
+        .into_iter()
+        .map(|source| {
+            let elements = match reusable.remove(&(source.id, source.hash)) {
+                Some(block) => block.elements.clone(),
+                None => parse_elements(&content[source.bytes.clone()], true, false, highlighter),
+            };
+            ParsedBlock {
+                source,
+                elements,
+                range: 0..0,
+            }
+        })
+        .collect();
+
+    let elements = assemble(&mut blocks);
+    (blocks, elements)
+}
+
+/// Joins the elements of `blocks` into a document, moving their source
+/// lines to the block's place and numbering sections across blocks.
+fn assemble(blocks: &mut [ParsedBlock]) -> Vec<MarkdownElement> {
+    let mut elements = Vec::new();
+    let mut section_offset = 0;
+    let mut current_section = None;
+    for block in blocks.iter_mut() {
+        let start = elements.len();
+        let mut last_local = 0;
+        for element in &block.elements {
+            let mut element = element.clone();
+            element.source_line += block.source.first_line - 1;
+            if let ElementKind::Heading { section_id, .. } = &mut element.kind {
+                last_local = last_local.max(*section_id);
+                *section_id += section_offset;
+                current_section = Some(*section_id);
+            } else {
+                // Text before the block's first heading belongs to the
+                // section of the previous block
+                element.section_id = match element.section_id {
+                    Some(0) => Some(0),
+                    Some(local) => Some(local + section_offset),
+                    None => current_section,
+                };
+            }
+            elements.push(element);
+        }
+        section_offset += last_local;
+        block.range = start..elements.len();
+    }
+
+    while matches!(elements.last(), Some(element) if matches!(element.kind, ElementKind::Empty)) {
+        elements.pop();
+    }
+    if elements.is_empty() {
+        elements.push(MarkdownElement {
+            kind: ElementKind::Empty,
+            section_id: None,
+            source_line: 1,
+        });
+    }
+    if let Some(last) = blocks.last_mut() {
+        last.range.end = last.range.end.min(elements.len());
+    }
+    elements
+}
+
+/// ATX headings in `text` outside fences, as (line index, level, text).
+fn atx_headings(text: &str) -> Vec<(usize, usize, String)> {
+    let mut headings = Vec::new();
+    let mut fence: Option<char> = None;
+    for (index, line) in text.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if let Some(marker) = fence {
+            if trimmed.chars().take_while(|&c| c == marker).count() >= 3 {
+                fence = None;
+            }
+            continue;
+        }
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            fence = trimmed.chars().next();
+            continue;
+        }
+        let level = trimmed.chars().take_while(|&c| c == '#').count();
+        let rest = &trimmed[level..];
+        if (1..=6).contains(&level) && (rest.is_empty() || rest.starts_with(' ')) {
+            headings.push((
+                index,
+                level,
+                rest.trim().trim_end_matches('#').trim().to_string(),
+            ));
+        }
+    }
+    headings
+}
+
+fn push_heading(path: &mut Vec<(usize, String)>, level: usize, text: &str) {
+    while path.last().is_some_and(|(last, _)| *last >= level) {
+        path.pop();
+    }
+    path.push((level, text.to_string()));
+}
+
+fn path_id(path: &[(usize, String)], index: usize) -> BlockId {
+    let mut hasher = DefaultHasher::new();
+    path.hash(&mut hasher);
+    index.hash(&mut hasher);
+    BlockId(hasher.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::render;
+    use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::parser::render_markdown_to_elements;
+
+    const DOC: &str = "---\ntitle: x\n---\n\nIntro.\n\n# One\n\nFirst.\n\n- a\n\n- b\n\n## Sub\n\n```rust\nfn main() {\n\n}\n```\n\n# Two\n\nSecond.\n";
+
+    #[test]
+    fn test_block_parse_matches_full_parse() {
+        let (blocks, elements) = parse_blocks(DOC, &[], &SyntaxHighlighter::new());
+        assert_eq!(blocks.len(), 8);
+
+        let full = render_markdown_to_elements(DOC, true);
+        assert_eq!(elements.len(), full.len());
+        for (block, full) in elements.iter().zip(&full) {
+            assert_eq!(block.source_line, full.source_line);
+            assert_eq!(block.section_id, full.section_id);
+            assert_eq!(render(block, 40), render(full, 40));
+        }
+    }
+
+    #[test]
+    fn test_diff_keeps_ids_of_blocks_in_other_sections() {
+        let edited = DOC.replace("Intro.\n", "Intro,\nnow longer.\n\nMore.\n");
+        let diff = BlockDiff::new(DOC, &edited);
+        let changed: Vec<usize> = diff.changed().map(|block| block.first_line).collect();
+        assert_eq!(changed, [5, 8]);
+
+        let second = diff
+            .old_blocks()
+            .iter()
+            .find(|b| b.first_line == 25)
+            .unwrap();
+        assert!(diff.is_unchanged(second));
+        assert_eq!(diff.map_line(25), Some(28));
+        assert_eq!(diff.map_line(5), Some(5));
+    }
+}
@@ -1,7 +1,7 @@
 //! Render code block header, content, and border.
 
 use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::constants::{
-    get_language_icon, CodeBlockTheme,
+    get_language_icon, CodeBlockColors,
 };
 use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::enums::CodeBlockBorderKind;
 use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::blockquote::{
@@ -15,10 +15,9 @@ pub fn render_header(
     _element: &MarkdownElement,
     language: &str,
     width: usize,
-    theme: CodeBlockTheme,
+    colors: CodeBlockColors,
     blockquote_depth: usize,
 ) -> Line<'static> {
-    let colors = theme.colors();
     let icon = get_language_icon(language);
     let lang_display = if language.is_empty() {
         "text"
@@ -57,16 +56,69 @@ pub fn render_header(
     Line::from(spans)
 }
 
+/// Label of the copy action in a collapsible code block header.
+pub const FENCE_COPY_LABEL: &str = " \u{29c9} copy ";
+
+/// Header of a collapsible code block: a fold marker, the language, the
+/// line count and a [`FENCE_COPY_LABEL`] near the right edge.
+pub fn render_fold_header(
+    language: &str,
+    line_count: usize,
+    collapsed: bool,
+    width: usize,
+    colors: CodeBlockColors,
+    blockquote_depth: usize,
+) -> Line<'static> {
+    let icon = get_language_icon(language);
+    let marker = if collapsed { "\u{25b8} " } else { "\u{25be} " };
+    let header_text = format!(" {} ", language);
+    let count_text = if line_count == 1 {
+        "\u{b7} 1 line ".to_string()
+    } else {
+        format!("\u{b7} {} lines ", line_count)
+    };
+
+    let bq_width = blockquote_prefix_width(blockquote_depth);
+    let effective_width = width.saturating_sub(bq_width);
+    let used = [marker, icon, &header_text, &count_text, FENCE_COPY_LABEL]
+        .iter()
+        .map(|text| text.chars().count())
+        .sum::<usize>();
+    let remaining = effective_width.saturating_sub(used + 5); // 5 for the corners and their dashes
+
+    let border_style = Style::default().fg(colors.border);
+    let header_style = Style::default().fg(colors.header_text).bg(colors.header_bg);
+    let icon_style = Style::default()
+        .fg(colors.icon)
+        .bg(colors.header_bg)
+        .add_modifier(Modifier::BOLD);
+    let count_style = Style::default().fg(colors.line_number);
+    let copy_style = Style::default().fg(colors.icon);
+
+    let mut spans = create_blockquote_prefix(blockquote_depth);
+    spans.extend(vec![
+        Span::styled("\u{256d}\u{2500} ", border_style),
+        Span::styled(marker, border_style),
+        Span::styled(icon.to_string(), icon_style),
+        Span::styled(header_text, header_style),
+        Span::styled(count_text, count_style),
+        Span::styled("\u{2500}".repeat(remaining), border_style),
+        Span::styled(FENCE_COPY_LABEL, copy_style),
+        Span::styled("\u{2500}\u{256e}", border_style),
+    ]);
+
+    Line::from(spans)
+}
+
 pub fn render_content(
     _element: &MarkdownElement,
     content: &str,
     highlighted: Option<&ratatui::text::Text<'static>>,
     width: usize,
     line_number: Option<usize>,
-    theme: CodeBlockTheme,
+    colors: CodeBlockColors,
     blockquote_depth: usize,
 ) -> Line<'static> {
-    let colors = theme.colors();
     let border_style = Style::default().fg(colors.border);
     let line_num_style = Style::default()
         .fg(colors.line_number)
@@ -150,11 +202,9 @@ pub fn render_border(
     _element: &MarkdownElement,
     kind: &CodeBlockBorderKind,
     width: usize,
-    theme: CodeBlockTheme,
+    colors: CodeBlockColors,
     blockquote_depth: usize,
 ) -> Line<'static> {
-    let colors = theme.colors();
-
     // Account for blockquote prefix in width
     let bq_width = blockquote_prefix_width(blockquote_depth);
     let effective_width = width.saturating_sub(bq_width);
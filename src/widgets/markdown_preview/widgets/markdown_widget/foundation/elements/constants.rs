@@ -28,7 +28,7 @@ pub enum CodeBlockTheme {
 }
 
 /// Colors for a code block theme
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CodeBlockColors {
     /// Border color
     pub border: Color,
@@ -38,6 +38,8 @@ pub enum ColumnAlignment {
 }
 
 /// Kind of markdown element for markdown rendering.
+use ratatui::style::Style;
+
 use super::MarkdownElement;
 
 /// Represents the kind of markdown element.
@@ -72,12 +74,16 @@ pub enum ElementKind {
         highlighted: Option<ratatui::text::Text<'static>>,
         /// Line number (1-indexed)
         line_number: usize,
+        /// Fence language of the enclosing code block (empty if none)
+        language: String,
         /// Blockquote nesting depth (0 = not in blockquote)
         blockquote_depth: usize,
     },
     /// Code block border (top, middle, bottom).
     CodeBlockBorder {
         kind: CodeBlockBorderKind,
+        /// Fence language of the enclosing code block (empty if none)
+        language: String,
         /// Blockquote nesting depth (0 = not in blockquote)
         blockquote_depth: usize,
     },
@@ -98,9 +104,17 @@ pub enum ElementKind {
     },
     /// Table row.
     TableRow {
+        /// Cell text, one per column.
         cells: Vec<String>,
+        /// Styled text of each cell, so bold, code and links keep their
+        /// look inside the table.
+        cell_segments: Vec<Vec<TextSegment>>,
+        /// Whether this is the header row.
         is_header: bool,
+        /// Alignment of each column, from the header separator row.
         alignments: Vec<ColumnAlignment>,
+        /// Natural width of each column: its widest cell.
+        widths: Vec<usize>,
     },
     /// Table border.
     TableBorder(TableBorderKind),
@@ -199,4 +213,15 @@ pub enum TextSegment {
     Html(String),
     /// Checkbox for task lists.
     Checkbox(CheckboxState),
+    /// Text produced by a [`TextDecorator`](crate::widgets::markdown_preview::widgets::markdown_widget::extensions::decorations::TextDecorator)
+    /// at render time; never produced by the parser.
+    Decorated {
+        /// Text as displayed.
+        text: String,
+        /// Style patched over the surrounding text style.
+        style: Style,
+        /// Source text the displayed text stands for, if it differs (empty
+        /// for inserted text). Copying uses this instead of `text`.
+        original: Option<String>,
+    },
 }
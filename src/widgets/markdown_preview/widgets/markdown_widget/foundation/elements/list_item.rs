@@ -174,6 +174,7 @@ fn segment_text_and_style(
             };
             (format!("{} ", icon), Style::default().fg(color))
         }
+        TextSegment::Decorated { text, style, .. } => (text.clone(), *style),
     }
 }
 
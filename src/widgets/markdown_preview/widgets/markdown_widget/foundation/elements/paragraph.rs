@@ -129,6 +129,7 @@ fn render_line_with_segments(
                     .add_modifier(Modifier::CROSSED_OUT),
             ),
             TextSegment::Html(t) => (t.clone(), Style::default()),
+            TextSegment::Decorated { text, style, .. } => (text.clone(), *style),
             TextSegment::Checkbox(state) => {
                 let (icon, color) = match state {
                     CheckboxState::Unchecked => (CHECKBOX_UNCHECKED, Color::Rgb(150, 150, 150)),
@@ -196,6 +197,7 @@ fn segments_to_plain_text(segments: &[TextSegment]) -> String {
             }
             TextSegment::Strikethrough(text) => text.clone(),
             TextSegment::Html(content) => content.clone(),
+            TextSegment::Decorated { text, .. } => text.clone(),
             TextSegment::Checkbox(_) => String::new(), // Checkbox handled separately
         })
         .collect::<Vec<_>>()
@@ -2,7 +2,9 @@
 
 use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::blockquote;
 use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::code_block;
-use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::constants::CodeBlockTheme;
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::constants::{
+    CodeBlockColors, CodeBlockTheme,
+};
 use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::enums::ElementKind;
 use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::expandable;
 use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::frontmatter;
@@ -27,6 +29,17 @@ pub struct RenderOptions<'a> {
     pub show_heading_collapse: bool,
 }
 
+impl RenderOptions<'_> {
+    /// Code block colors for `language`: the app theme's per-language
+    /// override if one exists, otherwise the code block theme.
+    pub fn code_block_colors(&self, language: &str) -> CodeBlockColors {
+        self.app_theme
+            .and_then(|theme| theme.markdown.language_override(language))
+            .copied()
+            .unwrap_or_else(|| self.theme.colors())
+    }
+}
+
 /// Render a markdown element to ratatui Line with given width.
 pub fn render(element: &MarkdownElement, width: usize) -> Vec<Line<'static>> {
     render_with_options(element, width, RenderOptions::default())
@@ -69,7 +82,7 @@ pub fn render_with_options(
                 element,
                 language,
                 width,
-                options.theme,
+                options.code_block_colors(language),
                 *blockquote_depth,
             )]
         }
@@ -77,6 +90,7 @@ pub fn render_with_options(
             content,
             highlighted,
             line_number,
+            language,
             blockquote_depth,
         } => {
             vec![code_block::render_content(
@@ -89,19 +103,20 @@ pub fn render_with_options(
                 } else {
                     None
                 },
-                options.theme,
+                options.code_block_colors(language),
                 *blockquote_depth,
             )]
         }
         ElementKind::CodeBlockBorder {
             kind,
+            language,
             blockquote_depth,
         } => {
             vec![code_block::render_border(
                 element,
                 kind,
                 width,
-                options.theme,
+                options.code_block_colors(language),
                 *blockquote_depth,
             )]
         }
@@ -126,12 +141,22 @@ pub fn render_with_options(
             blockquote::render(element, content, *depth, width, options.app_theme)
         }
         ElementKind::TableRow {
-            cells, is_header, ..
-        } => {
-            vec![table::render_table_row(element, cells, *is_header)]
-        }
+            cell_segments,
+            is_header,
+            alignments,
+            widths,
+            ..
+        } => table::render_table_row(
+            element,
+            cell_segments,
+            *is_header,
+            alignments,
+            widths,
+            width,
+            options.app_theme,
+        ),
         ElementKind::TableBorder(kind) => {
-            vec![table::render_table_border(element, kind)]
+            vec![table::render_table_border(element, kind, width)]
         }
         ElementKind::HorizontalRule => {
             vec![horizontal_rule::render(element, width, options.app_theme)]
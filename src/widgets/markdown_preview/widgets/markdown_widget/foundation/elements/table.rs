@@ -1,36 +1,80 @@
-use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::enums::TableBorderKind;
-/// Render table border.
+use crate::widgets::markdown_preview::services::theme::AppTheme;
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::enums::{
+    ColumnAlignment, TableBorderKind, TextSegment,
+};
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::text::{
+    inline_code_style, render_text_segment,
+};
 use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::MarkdownElement;
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::parser::{
+    terminal_char_width, terminal_display_width,
+};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 
-pub fn render_table_border(_element: &MarkdownElement, kind: &TableBorderKind) -> Line<'static> {
-    let border_style = Style::default().fg(Color::DarkGray);
+/// Widest a column grows; longer cells are truncated with an ellipsis.
+pub const MAX_COLUMN_WIDTH: usize = 40;
 
-    let content = match kind {
-        TableBorderKind::Top(widths) => {
-            let segments: Vec<String> = widths.iter().map(|w| "\u{2500}".repeat(*w + 2)).collect();
-            format!("\u{250c}{}\u{2510}", segments.join("\u{252c}"))
-        }
-        TableBorderKind::HeaderSeparator(widths) => {
-            let segments: Vec<String> = widths.iter().map(|w| "\u{2500}".repeat(*w + 2)).collect();
-            format!("\u{251c}{}\u{2524}", segments.join("\u{253c}"))
-        }
-        TableBorderKind::Bottom(widths) => {
-            let segments: Vec<String> = widths.iter().map(|w| "\u{2500}".repeat(*w + 2)).collect();
-            format!("\u{2514}{}\u{2518}", segments.join("\u{2534}"))
-        }
+/// Marks the right edge of a table clipped to the available width.
+const CLIP_INDICATOR: &str = "\u{25b8}";
+
+/// Display width of a cell's styled text, as [`render_table_row`] draws it.
+pub fn cell_width(segments: &[TextSegment]) -> usize {
+    cell_spans(segments, Style::default(), None)
+        .iter()
+        .map(|span| terminal_display_width(&span.content))
+        .sum()
+}
+
+/// Column widths as drawn: each column's widest cell, capped at
+/// [`MAX_COLUMN_WIDTH`].
+pub fn column_widths(natural: &[usize]) -> Vec<usize> {
+    natural
+        .iter()
+        .map(|&w| w.clamp(1, MAX_COLUMN_WIDTH))
+        .collect()
+}
+
+/// Render a table border line, clipped to `width` like the rows.
+pub fn render_table_border(
+    _element: &MarkdownElement,
+    kind: &TableBorderKind,
+    width: usize,
+) -> Line<'static> {
+    let border_style = Style::default().fg(Color::DarkGray);
+    let (left, join, right, widths) = match kind {
+        TableBorderKind::Top(widths) => ("\u{250c}", "\u{252c}", "\u{2510}", widths),
+        TableBorderKind::HeaderSeparator(widths) => ("\u{251c}", "\u{253c}", "\u{2524}", widths),
+        TableBorderKind::Bottom(widths) => ("\u{2514}", "\u{2534}", "\u{2518}", widths),
     };
 
-    Line::from(Span::styled(content, border_style))
+    let segments: Vec<String> = column_widths(widths)
+        .iter()
+        .map(|w| "\u{2500}".repeat(*w + 2))
+        .collect();
+    let content = format!("{}{}{}", left, segments.join(join), right);
+
+    Line::from(clip_spans(
+        vec![Span::styled(content, border_style)],
+        width,
+        border_style,
+    ))
 }
 
 /// Render table row.
+///
+/// Cells keep their inline styling and are aligned within their column,
+/// truncated with an ellipsis past [`MAX_COLUMN_WIDTH`]. A row wider than
+/// `width` is clipped, with a marker in the last column.
 pub fn render_table_row(
     _element: &MarkdownElement,
-    cells: &[String],
+    cells: &[Vec<TextSegment>],
     is_header: bool,
-) -> Line<'static> {
+    alignments: &[ColumnAlignment],
+    widths: &[usize],
+    width: usize,
+    app_theme: Option<&AppTheme>,
+) -> Vec<Line<'static>> {
     let style = if is_header {
         Style::default()
             .fg(Color::Cyan)
@@ -38,20 +82,226 @@ pub fn render_table_row(
     } else {
         Style::default().fg(Color::White)
     };
+    let border_style = Style::default().fg(Color::DarkGray);
+
+    let mut spans = vec![Span::styled("\u{2502}", border_style)];
+    for (column, column_width) in column_widths(widths).into_iter().enumerate() {
+        let segments = cells.get(column).map(Vec::as_slice).unwrap_or(&[]);
+        let alignment = alignments
+            .get(column)
+            .copied()
+            .unwrap_or(ColumnAlignment::None);
+        let (cell, cell_width) =
+            truncate_spans(cell_spans(segments, style, app_theme), column_width);
+
+        let padding = column_width - cell_width;
+        let left_pad = match alignment {
+            ColumnAlignment::Right => padding,
+            ColumnAlignment::Center => padding / 2,
+            ColumnAlignment::Left | ColumnAlignment::None => 0,
+        };
+        spans.push(Span::styled(" ".repeat(left_pad + 1), style));
+        spans.extend(cell);
+        spans.push(Span::styled(" ".repeat(padding - left_pad + 1), style));
+        spans.push(Span::styled("\u{2502}", border_style));
+    }
+
+    vec![Line::from(clip_spans(spans, width, border_style))]
+}
+
+/// Styled spans for the text of one cell.
+fn cell_spans(
+    segments: &[TextSegment],
+    style: Style,
+    app_theme: Option<&AppTheme>,
+) -> Vec<Span<'static>> {
+    segments
+        .iter()
+        .map(|segment| match segment {
+            TextSegment::InlineCode(code) => {
+                Span::styled(format!(" {} ", code), inline_code_style(style, app_theme))
+            }
+            segment => render_text_segment(segment, style),
+        })
+        .collect()
+}
+
+/// Cuts `spans` down to `width` columns, ending in an ellipsis when text
+/// was cut. Returns the spans and the width they take.
+fn truncate_spans(spans: Vec<Span<'static>>, width: usize) -> (Vec<Span<'static>>, usize) {
+    let total: usize = spans
+        .iter()
+        .map(|span| terminal_display_width(&span.content))
+        .sum();
+    if total <= width {
+        return (spans, total);
+    }
+    let ellipsis_style = spans.last().map(|span| span.style).unwrap_or_default();
+    let mut truncated = take_width(spans, width.saturating_sub(1));
+    truncated.push(Span::styled("\u{2026}", ellipsis_style));
+    let used = truncated
+        .iter()
+        .map(|span| terminal_display_width(&span.content))
+        .sum();
+    (truncated, used)
+}
 
-    let mut spans = Vec::new();
-    spans.push(Span::styled(
-        "\u{2502}",
-        Style::default().fg(Color::DarkGray),
+/// Clips a table line to `width` columns, putting a marker in the last
+/// column if anything was cut off.
+fn clip_spans(spans: Vec<Span<'static>>, width: usize, marker_style: Style) -> Vec<Span<'static>> {
+    let total: usize = spans
+        .iter()
+        .map(|span| terminal_display_width(&span.content))
+        .sum();
+    if total <= width || width == 0 {
+        return spans;
+    }
+    let mut clipped = take_width(spans, width - 1);
+    let used: usize = clipped
+        .iter()
+        .map(|span| terminal_display_width(&span.content))
+        .sum();
+    clipped.push(Span::styled(
+        format!("{}{}", " ".repeat(width - 1 - used), CLIP_INDICATOR),
+        marker_style,
     ));
+    clipped
+}
+
+/// The leading characters of `spans` that fit in `width` columns.
+fn take_width(spans: Vec<Span<'static>>, width: usize) -> Vec<Span<'static>> {
+    let mut taken = Vec::new();
+    let mut used = 0;
+    for span in spans {
+        let mut text = String::new();
+        for c in span.content.chars() {
+            let char_width = terminal_char_width(c);
+            if used + char_width > width {
+                break;
+            }
+            text.push(c);
+            used += char_width;
+        }
+        let complete = text.len() == span.content.len();
+        if !text.is_empty() {
+            taken.push(Span::styled(text, span.style));
+        }
+        if !complete {
+            break;
+        }
+    }
+    taken
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::Widget;
+
+    use super::*;
+    use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::INLINE_CODE_BG;
+    use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::functions::render_markdown;
+    use crate::widgets::markdown_preview::widgets::markdown_widget::state::MarkdownState;
+    use crate::widgets::markdown_preview::widgets::markdown_widget::widget::MarkdownWidget;
 
-    for cell in cells {
-        spans.push(Span::styled(format!(" {} ", cell), style));
-        spans.push(Span::styled(
-            "\u{2502}",
-            Style::default().fg(Color::DarkGray),
-        ));
+    fn render_rows(markdown: &str, width: usize) -> Vec<String> {
+        render_markdown(markdown, Some(width))
+            .lines
+            .iter()
+            .map(|line| line.to_string())
+            .collect()
     }
 
-    Line::from(spans)
+    #[test]
+    fn test_alignment_markers() {
+        let markdown = "| Left | Center | Right | Plain |\n|:-----|:------:|------:|-------|\n| a | b | c | d |\n| long text | wide cell | 12345 | xy |\n";
+        assert_eq!(
+            render_rows(markdown, 80),
+            [
+                "┌───────────┬───────────┬───────┬───────┐",
+                "│ Left      │  Center   │ Right │ Plain │",
+                "├───────────┼───────────┼───────┼───────┤",
+                "│ a         │     b     │     c │ d     │",
+                "│ long text │ wide cell │ 12345 │ xy    │",
+                "└───────────┴───────────┴───────┴───────┘",
+            ]
+        );
+    }
+
+    /// Renders `markdown` through the widget and reads back the rows of
+    /// the buffer.
+    fn render_buffer(markdown: &str, width: u16) -> Buffer {
+        let mut state = MarkdownState::new();
+        state.source.set_source_string(markdown);
+        let area = Rect::new(0, 0, width, 8);
+        let mut buf = Buffer::empty(area);
+        MarkdownWidget::from_state(&state)
+            .with_has_pane(false)
+            .show_statusline(false)
+            .show_scrollbar(false)
+            .render(area, &mut buf);
+        buf
+    }
+
+    fn buffer_rows(buf: &Buffer) -> Vec<String> {
+        (0..buf.area.height)
+            .map(|y| {
+                (0..buf.area.width)
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_mixed_alignment_keeps_inline_styles() {
+        let markdown = "| Name | Kind | Size |\n|:---|:---:|---:|\n| **ratkit** | `lib` | 12 |\n| demo | bin | 3 |\n";
+        let buf = render_buffer(markdown, 40);
+        assert_eq!(
+            buffer_rows(&buf)[..6],
+            [
+                "┌────────┬───────┬──────┐",
+                "│ Name   │ Kind  │ Size │",
+                "├────────┼───────┼──────┤",
+                "│ ratkit │  lib  │   12 │",
+                "│ demo   │  bin  │    3 │",
+                "└────────┴───────┴──────┘",
+            ]
+        );
+        assert!(buf[(2, 3)].modifier.contains(Modifier::BOLD));
+        assert_eq!(buf[(12, 3)].bg, INLINE_CODE_BG);
+        assert!(!buf[(2, 4)].modifier.contains(Modifier::BOLD));
+    }
+
+    #[test]
+    fn test_wide_table_is_clipped_with_marker() {
+        let markdown = format!(
+            "| Name | Description |\n|---|---|\n| grid | {} |\n",
+            "a very long description ".repeat(3)
+        );
+        let rows = buffer_rows(&render_buffer(&markdown, 30));
+        assert_eq!(
+            rows[..5],
+            [
+                "┌──────┬─────────────────────▸",
+                "│ Name │ Description         ▸",
+                "├──────┼─────────────────────▸",
+                "│ grid │ a very long descript▸",
+                "└──────┴─────────────────────▸",
+            ]
+        );
+        assert!(rows.iter().all(|row| row.chars().count() <= 30));
+    }
+
+    #[test]
+    fn test_long_cells_are_truncated() {
+        let markdown = format!("| Key | Value |\n|---|---|\n| k | {} |\n", "x".repeat(60));
+        let rows = render_rows(&markdown, 120);
+        assert_eq!(rows[3].chars().count(), 4 + 4 + MAX_COLUMN_WIDTH + 2);
+        assert!(rows[3].ends_with("x… │"), "{:?}", rows[3]);
+        assert_eq!(column_widths(&[0, 5, 100]), [1, 5, MAX_COLUMN_WIDTH]);
+    }
 }
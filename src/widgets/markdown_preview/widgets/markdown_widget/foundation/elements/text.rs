@@ -98,6 +98,9 @@ pub fn render_text_segment(segment: &TextSegment, base_style: Style) -> Span<'st
             };
             Span::styled(icon.to_string(), base_style.fg(color))
         }
+        TextSegment::Decorated { text, style, .. } => {
+            Span::styled(text.clone(), base_style.patch(*style))
+        }
     }
 }
 
@@ -127,6 +130,7 @@ pub fn segments_to_plain_text(segments: &[TextSegment]) -> String {
             TextSegment::Strikethrough(text) => text.clone(),
             TextSegment::Html(text) => text.clone(),
             TextSegment::Checkbox(_) => String::new(), // Checkbox icon handled separately
+            TextSegment::Decorated { text, .. } => text.clone(),
         })
         .collect::<Vec<_>>()
         .join("")
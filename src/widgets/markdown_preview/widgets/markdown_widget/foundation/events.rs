@@ -2,6 +2,9 @@
 
 //! Event returned when a line is double-clicked in the markdown widget.
 
+use std::ops::Range;
+use std::path::PathBuf;
+
 /// Event returned when a line is double-clicked in the markdown widget.
 #[derive(Debug, Clone)]
 pub struct MarkdownDoubleClickEvent {
@@ -40,6 +43,15 @@ pub enum MarkdownEvent {
         collapsed: bool,
     },
 
+    /// A fenced block in a language that cannot be highlighted was
+    /// collapsed or expanded.
+    FenceToggled {
+        /// The fence language.
+        language: String,
+        /// Whether the block is now collapsed.
+        collapsed: bool,
+    },
+
     /// A double-click occurred on a line.
     DoubleClick {
         /// Source line number (1-indexed).
@@ -76,6 +88,19 @@ pub enum MarkdownEvent {
         hovered: bool,
     },
 
+    /// Keyboard focus moved into or out of the TOC, or the TOC selection
+    /// or a collapsed subtree changed.
+    TocFocusChanged {
+        /// Whether the TOC has keyboard focus.
+        focused: bool,
+    },
+
+    /// The TOC divider was dragged, or double-clicked to reset the width.
+    TocResized {
+        /// The new TOC width, `None` for the default width.
+        width: Option<u16>,
+    },
+
     /// Filter mode changed (entered, text changed, or exited with Esc).
     FilterModeChanged {
         /// Whether filter mode is active.
@@ -89,4 +114,62 @@ pub enum MarkdownEvent {
         /// The line that was focused when filter mode was exited.
         line: usize,
     },
+
+    /// A link was clicked.
+    ///
+    /// The widget does not open links itself; the application decides what
+    /// to do with `url`.
+    LinkClicked {
+        /// The link destination.
+        url: String,
+        /// The link text.
+        text: String,
+    },
+
+    /// An internal `#anchor` link was clicked and the view scrolled to the
+    /// matching heading.
+    AnchorNavigated {
+        /// The anchor, without the leading `#`.
+        anchor: String,
+        /// Source line of the heading (1-indexed).
+        line: usize,
+    },
+
+    /// The current search match changed and the view scrolled to it.
+    SearchMoved {
+        /// Index of the current match (0-indexed).
+        index: usize,
+        /// Number of matches.
+        total: usize,
+    },
+
+    /// Search input opened, its query changed, or it closed.
+    ///
+    /// Closing with Enter keeps the query and its highlights; closing with
+    /// Esc clears them, leaving `query` empty.
+    SearchModeChanged {
+        /// Whether the query is being typed.
+        active: bool,
+        /// The current query.
+        query: String,
+    },
+
+    /// The user asked to edit the section under the cursor.
+    ///
+    /// For a file source, open `path` at `line` in an editor; the widget
+    /// picks up the saved file through
+    /// [`reload_source_if_changed`](crate::widgets::markdown_preview::MarkdownWidget::reload_source_if_changed).
+    /// Otherwise edit `text` and hand the result to
+    /// [`apply_section_edit`](crate::widgets::markdown_preview::MarkdownWidget::apply_section_edit)
+    /// with `lines`.
+    EditRequested {
+        /// The source file, if the content was loaded from one.
+        path: Option<PathBuf>,
+        /// Source line under the cursor (1-indexed).
+        line: usize,
+        /// Source lines of the section (0-indexed, end exclusive).
+        lines: Range<usize>,
+        /// The section's raw markdown, when there is no source file.
+        text: Option<String>,
+    },
 }
@@ -20,6 +20,9 @@ fn segments_to_text(segments: &[TextSegment]) -> String {
             TextSegment::Strikethrough(t) => t.clone(),
             TextSegment::Html(t) => t.clone(),
             TextSegment::Checkbox(_) => String::new(),
+            TextSegment::Decorated { text, original, .. } => {
+                original.clone().unwrap_or_else(|| text.clone())
+            }
         })
         .collect::<Vec<_>>()
         .join("")
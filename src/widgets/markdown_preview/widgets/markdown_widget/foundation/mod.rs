@@ -2,14 +2,20 @@
 //!
 //! Contains core elements, types, events, helpers, and rendering functions.
 
+pub mod blocks;
 pub mod elements;
 pub mod events;
 pub mod functions;
 pub mod helpers;
 pub mod parser;
 pub mod source;
+pub mod stats;
+pub mod streaming;
 pub mod types;
 
+pub use blocks::{BlockDiff, BlockId, SourceBlock};
 pub use events::{MarkdownDoubleClickEvent, MarkdownEvent};
 pub use functions::{render_markdown, render_markdown_with_style};
-pub use types::{GitStats, SelectionPos};
+pub use stats::{DocumentStats, SelectionStats};
+pub use streaming::{StreamStats, StreamingMarkdown};
+pub use types::{FenceBlock, GitStats, LinkRange, SelectionPos};
@@ -113,32 +113,65 @@ pub fn parse_frontmatter(content: &str) -> (Option<Vec<(String, String)>>, &str,
 use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
 
 use crate::widgets::markdown_preview::widgets::markdown_widget::extensions::theme::SyntaxHighlighter;
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::table;
 
 /// Calculate the display width of a string for terminal rendering.
 /// This uses unicode_width but treats emoji as width 1 since many terminals
 /// render emoji at width 1 instead of the Unicode-standard width 2.
-fn terminal_display_width(s: &str) -> usize {
-    s.chars()
-        .map(|c| {
-            // Check if character is likely an emoji (simplified check)
-            // Emoji are typically in these ranges or have variation selectors
-            let cp = c as u32;
-            if (0x1F300..=0x1F9FF).contains(&cp)  // Miscellaneous Symbols and Pictographs, Emoticons, etc.
-                || (0x2600..=0x26FF).contains(&cp)  // Miscellaneous Symbols
-                || (0x2700..=0x27BF).contains(&cp)  // Dingbats
-                || (0x1F600..=0x1F64F).contains(&cp) // Emoticons
-                || (0x1F680..=0x1F6FF).contains(&cp) // Transport and Map Symbols
-                || (0x2300..=0x23FF).contains(&cp)
-            // Miscellaneous Technical
-            {
-                // Treat emoji as width 1 for terminal compatibility
-                1
-            } else {
-                // Use standard unicode width for other characters
-                unicode_width::UnicodeWidthChar::width(c).unwrap_or(0)
-            }
-        })
-        .sum()
+pub(crate) fn terminal_display_width(s: &str) -> usize {
+    s.chars().map(terminal_char_width).sum()
+}
+
+/// Display width of one character, as counted by [`terminal_display_width`].
+pub(crate) fn terminal_char_width(c: char) -> usize {
+    // Check if character is likely an emoji (simplified check)
+    // Emoji are typically in these ranges or have variation selectors
+    let cp = c as u32;
+    if (0x1F300..=0x1F9FF).contains(&cp)  // Miscellaneous Symbols and Pictographs, Emoticons, etc.
+        || (0x2600..=0x26FF).contains(&cp)  // Miscellaneous Symbols
+        || (0x2700..=0x27BF).contains(&cp)  // Dingbats
+        || (0x1F600..=0x1F64F).contains(&cp) // Emoticons
+        || (0x1F680..=0x1F6FF).contains(&cp) // Transport and Map Symbols
+        || (0x2300..=0x23FF).contains(&cp)
+    // Miscellaneous Technical
+    {
+        // Treat emoji as width 1 for terminal compatibility
+        1
+    } else {
+        // Use standard unicode width for other characters
+        unicode_width::UnicodeWidthChar::width(c).unwrap_or(0)
+    }
+}
+
+/// Add text to the last cell of the table row being parsed, both as plain
+/// text and as a styled segment.
+fn push_cell_segment(
+    cells: &mut Vec<String>,
+    segments: &mut Vec<Vec<TextSegment>>,
+    text: &str,
+    segment: TextSegment,
+) {
+    if cells.is_empty() {
+        cells.push(String::new());
+        segments.push(Vec::new());
+    }
+    if let Some(cell) = cells.last_mut() {
+        cell.push_str(text);
+    }
+    if let Some(cell) = segments.last_mut() {
+        cell.push(segment);
+    }
+}
+
+/// Extract the language from a fence info string.
+///
+/// The language is the first token; attributes after a comma or whitespace
+/// (as in ```` ```rust,no_run ```` or ```` ```python title="x" ````) are ignored.
+pub fn fence_language(info: &str) -> &str {
+    info.trim()
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .next()
+        .unwrap_or("")
 }
 
 /// Render markdown content to markdown elements.
@@ -154,6 +187,28 @@ fn terminal_display_width(s: &str) -> usize {
 pub fn render_markdown_to_elements(
     content: &str,
     frontmatter_collapsed: bool,
+) -> Vec<MarkdownElement> {
+    parse_elements(
+        content,
+        frontmatter_collapsed,
+        true,
+        &SyntaxHighlighter::new(),
+    )
+}
+
+/// Parse markdown content to elements, optionally keeping the spacing
+/// lines after the last block.
+///
+/// Untrimmed output of text that ends on a block boundary can be followed
+/// by the elements of the text after it, which is how
+/// [`StreamingMarkdown`](super::streaming::StreamingMarkdown) reuses the
+/// elements of finished blocks. Code blocks are highlighted with
+/// `highlighter`, which is costly to create.
+pub(crate) fn parse_elements(
+    content: &str,
+    frontmatter_collapsed: bool,
+    trim_trailing: bool,
+    highlighter: &SyntaxHighlighter,
 ) -> Vec<MarkdownElement> {
     let mut lines = Vec::new();
 
@@ -223,9 +278,10 @@ pub fn render_markdown_to_elements(
     let mut in_table = false;
     let mut table_header_done = false;
     let mut current_row_cells: Vec<String> = Vec::new();
+    let mut current_row_segments: Vec<Vec<TextSegment>> = Vec::new();
     let mut table_col_widths: Vec<usize> = Vec::new();
     let mut table_alignments: Vec<ColumnAlignment> = Vec::new();
-    let mut pending_table_rows: Vec<(Vec<String>, bool)> = Vec::new(); // (cells, is_header)
+    let mut pending_table_rows: Vec<(Vec<String>, Vec<Vec<TextSegment>>, bool)> = Vec::new(); // (cells, segments, is_header)
 
     // Text formatting state
     let mut in_bold = false;
@@ -237,7 +293,9 @@ pub fn render_markdown_to_elements(
 
     // Build byte offset to line number mapping for remaining content
     let mut byte_to_line: Vec<usize> = Vec::with_capacity(remaining_content.len());
-    let mut line_num = current_source_line;
+    // The remaining content starts on the line the frontmatter ends on
+    let consumed = content.len() - remaining_content.len();
+    let mut line_num = content[..consumed].matches('\n').count() + 1;
     for ch in remaining_content.chars() {
         for _ in 0..ch.len_utf8() {
             byte_to_line.push(line_num);
@@ -256,7 +314,6 @@ pub fn render_markdown_to_elements(
 
     let options = Options::all();
     let parser = Parser::new_ext(remaining_content, options).into_offset_iter();
-    let highlighter = SyntaxHighlighter::new();
 
     // Track the last event's source line for use in flush_paragraph
     let mut last_event_source_line = current_source_line;
@@ -290,7 +347,7 @@ pub fn render_markdown_to_elements(
                     in_code_block = true;
                     code_block_started = false;
                     code_block_lang = match kind {
-                        CodeBlockKind::Fenced(lang) => lang.to_string(),
+                        CodeBlockKind::Fenced(info) => fence_language(&info).to_string(),
                         CodeBlockKind::Indented => String::new(),
                     };
                 }
@@ -382,13 +439,16 @@ pub fn render_markdown_to_elements(
                 }
                 Tag::TableHead => {
                     current_row_cells.clear();
+                    current_row_segments.clear();
                 }
                 Tag::TableRow => {
                     current_row_cells.clear();
+                    current_row_segments.clear();
                 }
                 Tag::TableCell => {
                     // Start a new cell - push empty string to accumulate text into
                     current_row_cells.push(String::new());
+                    current_row_segments.push(Vec::new());
                 }
                 _ => {}
             },
@@ -450,6 +510,7 @@ pub fn render_markdown_to_elements(
                     lines.push(MarkdownElement {
                         kind: ElementKind::CodeBlockBorder {
                             kind: CodeBlockBorderKind::Bottom,
+                            language: code_block_lang.clone(),
                             blockquote_depth,
                         },
                         section_id: current_section_id,
@@ -533,49 +594,21 @@ pub fn render_markdown_to_elements(
                             source_line: event_source_line,
                         });
 
-                        for (cells, is_header) in pending_table_rows.drain(..) {
-                            // Pad cells to column widths with proper alignment
-                            let padded_cells: Vec<String> = cells
-                                .iter()
-                                .enumerate()
-                                .map(|(j, cell)| {
-                                    let width = table_col_widths
-                                        .get(j)
-                                        .copied()
-                                        .unwrap_or_else(|| terminal_display_width(cell));
-                                    let alignment = table_alignments
-                                        .get(j)
-                                        .copied()
-                                        .unwrap_or(ColumnAlignment::None);
-                                    // Pad based on terminal display width (emoji-aware)
-                                    let cell_width = terminal_display_width(cell);
-                                    let padding = width.saturating_sub(cell_width);
-                                    match alignment {
-                                        ColumnAlignment::Right => {
-                                            format!("{}{}", " ".repeat(padding), cell)
-                                        }
-                                        ColumnAlignment::Center => {
-                                            let left_pad = padding / 2;
-                                            let right_pad = padding - left_pad;
-                                            format!(
-                                                "{}{}{}",
-                                                " ".repeat(left_pad),
-                                                cell,
-                                                " ".repeat(right_pad)
-                                            )
-                                        }
-                                        ColumnAlignment::Left | ColumnAlignment::None => {
-                                            format!("{}{}", cell, " ".repeat(padding))
-                                        }
-                                    }
-                                })
-                                .collect();
+                        for (mut cells, mut cell_segments, is_header) in
+                            pending_table_rows.drain(..)
+                        {
+                            // Cells are padded to their column when rendered,
+                            // once the available width is known
+                            cells.resize(table_col_widths.len(), String::new());
+                            cell_segments.resize(table_col_widths.len(), Vec::new());
 
                             lines.push(MarkdownElement {
                                 kind: ElementKind::TableRow {
-                                    cells: padded_cells,
+                                    cells,
+                                    cell_segments,
                                     is_header,
                                     alignments: table_alignments.clone(),
+                                    widths: table_col_widths.clone(),
                                 },
                                 section_id: current_section_id,
                                 source_line: event_source_line,
@@ -612,23 +645,26 @@ pub fn render_markdown_to_elements(
                 }
                 TagEnd::TableHead => {
                     // Finalize header row
-                    for (i, cell) in current_row_cells.iter().enumerate() {
-                        let cell_width = terminal_display_width(cell);
+                    for (i, segments) in current_row_segments.iter().enumerate() {
+                        let cell_width = table::cell_width(segments);
                         if i >= table_col_widths.len() {
                             table_col_widths.push(cell_width);
                         } else {
                             table_col_widths[i] = table_col_widths[i].max(cell_width);
                         }
                     }
-                    pending_table_rows.push((current_row_cells.clone(), true)); // Header
-                    current_row_cells.clear();
+                    pending_table_rows.push((
+                        std::mem::take(&mut current_row_cells),
+                        std::mem::take(&mut current_row_segments),
+                        true,
+                    )); // Header
                     table_header_done = true;
                 }
                 TagEnd::TableRow => {
                     // Finalize body row
                     if table_header_done {
-                        for (i, cell) in current_row_cells.iter().enumerate() {
-                            let cell_width = terminal_display_width(cell);
+                        for (i, segments) in current_row_segments.iter().enumerate() {
+                            let cell_width = table::cell_width(segments);
                             if i >= table_col_widths.len() {
                                 table_col_widths.push(cell_width);
                             } else {
@@ -636,9 +672,14 @@ pub fn render_markdown_to_elements(
                             }
                         }
 
-                        pending_table_rows.push((current_row_cells.clone(), false));
+                        pending_table_rows.push((
+                            std::mem::take(&mut current_row_cells),
+                            std::mem::take(&mut current_row_segments),
+                            false,
+                        ));
                     }
                     current_row_cells.clear();
+                    current_row_segments.clear();
                 }
                 TagEnd::TableCell => {
                     // Cell content already added via Text events
@@ -669,19 +710,13 @@ pub fn render_markdown_to_elements(
                                 content: line.to_string(),
                                 highlighted,
                                 line_number: i + 1,
+                                language: code_block_lang.clone(),
                                 blockquote_depth,
                             },
                             section_id: current_section_id,
                             source_line: event_source_line + i,
                         });
                     }
-                } else if in_table {
-                    // Accumulate text for current cell
-                    if let Some(last_cell) = current_row_cells.last_mut() {
-                        last_cell.push_str(&text);
-                    } else {
-                        current_row_cells.push(text.to_string());
-                    }
                 } else {
                     let segment = if in_link {
                         // Detect autolink: text matches URL (with or without protocol)
@@ -711,18 +746,26 @@ pub fn render_markdown_to_elements(
                     } else {
                         TextSegment::Plain(text.to_string())
                     };
-                    current_segments.push(segment);
+                    if in_table {
+                        push_cell_segment(
+                            &mut current_row_cells,
+                            &mut current_row_segments,
+                            &text,
+                            segment,
+                        );
+                    } else {
+                        current_segments.push(segment);
+                    }
                 }
             }
             Event::Code(code) => {
                 if in_table {
-                    // Add inline code to cell - just use the raw content
-                    // The code content is displayed as-is without wrapping backticks
-                    if let Some(last_cell) = current_row_cells.last_mut() {
-                        last_cell.push_str(&code);
-                    } else {
-                        current_row_cells.push(code.to_string());
-                    }
+                    push_cell_segment(
+                        &mut current_row_cells,
+                        &mut current_row_segments,
+                        &code,
+                        TextSegment::InlineCode(code.to_string()),
+                    );
                 } else {
                     current_segments.push(TextSegment::InlineCode(code.to_string()));
                 }
@@ -791,6 +834,10 @@ pub fn render_markdown_to_elements(
         last_event_source_line,
     );
 
+    if !trim_trailing {
+        return lines;
+    }
+
     // Remove trailing empty lines
     while matches!(lines.last(), Some(l) if matches!(l.kind, ElementKind::Empty)) {
         lines.pop();
@@ -806,3 +853,32 @@ pub fn render_markdown_to_elements(
 
     lines
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{fence_language, render_markdown_to_elements};
+    use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::ElementKind;
+
+    #[test]
+    fn fence_language_is_first_token() {
+        assert_eq!(fence_language("rust"), "rust");
+        assert_eq!(fence_language("rust,no_run"), "rust");
+        assert_eq!(fence_language(" python title=\"a.py\""), "python");
+        assert_eq!(fence_language(""), "");
+    }
+
+    #[test]
+    fn code_block_elements_carry_parsed_language() {
+        let elements = render_markdown_to_elements("```rust,ignore\nfn main() {}\n```\n", true);
+        let languages: Vec<&str> = elements
+            .iter()
+            .filter_map(|element| match &element.kind {
+                ElementKind::CodeBlockHeader { language, .. }
+                | ElementKind::CodeBlockContent { language, .. }
+                | ElementKind::CodeBlockBorder { language, .. } => Some(language.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(languages, ["rust", "rust", "rust"]);
+    }
+}
@@ -0,0 +1,176 @@
+//! Word counts and reading time for a document or a selection.
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::{
+    ElementKind, MarkdownElement, TextSegment,
+};
+
+/// Reading speed used for [`DocumentStats::reading_time_minutes`].
+pub const WORDS_PER_MINUTE: usize = 200;
+
+/// Statistics for a whole document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DocumentStats {
+    /// Words in prose, tables and code blocks.
+    pub words: usize,
+    /// Characters in prose, tables and code blocks, spaces included.
+    pub characters: usize,
+    /// Number of headings.
+    pub headings: usize,
+    /// Number of fenced code blocks.
+    pub code_blocks: usize,
+    /// Minutes to read the document at [`WORDS_PER_MINUTE`], rounded up.
+    /// Code blocks only count when asked for.
+    pub reading_time_minutes: usize,
+}
+
+impl DocumentStats {
+    /// Counts the words, characters, headings and code blocks in `elements`.
+    ///
+    /// Words follow Unicode word boundaries, so punctuation is not counted
+    /// and `don't` is one word. Frontmatter is left out.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - The parsed document.
+    /// * `include_code` - Whether code blocks count towards the reading time.
+    pub fn from_elements(elements: &[MarkdownElement], include_code: bool) -> Self {
+        let mut stats = Self::default();
+        let mut code_words = 0;
+
+        for element in elements {
+            match &element.kind {
+                ElementKind::Heading { text, .. } => {
+                    stats.headings += 1;
+                    stats.add_text(&segments_text(text));
+                }
+                ElementKind::Paragraph(segments)
+                | ElementKind::ListItem {
+                    content: segments, ..
+                }
+                | ElementKind::Blockquote {
+                    content: segments, ..
+                } => {
+                    stats.add_text(&segments_text(segments));
+                }
+                ElementKind::TableRow { cells, .. } => {
+                    for cell in cells {
+                        stats.add_text(cell);
+                    }
+                }
+                ElementKind::CodeBlockHeader { .. } => stats.code_blocks += 1,
+                ElementKind::CodeBlockContent { content, .. } => {
+                    code_words += stats.add_text(content);
+                }
+                _ => {}
+            }
+        }
+
+        let read_words = if include_code {
+            stats.words
+        } else {
+            stats.words - code_words
+        };
+        stats.reading_time_minutes = (read_words + WORDS_PER_MINUTE - 1) / WORDS_PER_MINUTE;
+        stats
+    }
+
+    /// Adds `text` to the counts, returning its word count.
+    fn add_text(&mut self, text: &str) -> usize {
+        let words = text.unicode_words().count();
+        self.words += words;
+        self.characters += text.chars().count();
+        words
+    }
+}
+
+/// Statistics for the selected text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SelectionStats {
+    /// Words, by Unicode word boundaries.
+    pub words: usize,
+    /// Characters, line breaks excluded.
+    pub chars: usize,
+    /// Lines the selection touches.
+    pub lines: usize,
+}
+
+impl SelectionStats {
+    /// Counts the words, characters and lines in `text`.
+    pub fn from_text(text: &str) -> Self {
+        Self {
+            words: text.unicode_words().count(),
+            chars: text.chars().filter(|&c| c != '\n' && c != '\r').count(),
+            lines: text.lines().count(),
+        }
+    }
+}
+
+fn segments_text(segments: &[TextSegment]) -> String {
+    segments
+        .iter()
+        .map(|segment| match segment {
+            TextSegment::Plain(text)
+            | TextSegment::Bold(text)
+            | TextSegment::Italic(text)
+            | TextSegment::BoldItalic(text)
+            | TextSegment::InlineCode(text)
+            | TextSegment::Strikethrough(text)
+            | TextSegment::Html(text)
+            | TextSegment::Link { text, .. } => text.as_str(),
+            TextSegment::Decorated { text, original, .. } => original.as_deref().unwrap_or(text),
+            TextSegment::Checkbox(_) => "",
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::parser::render_markdown_to_elements;
+
+    #[test]
+    fn test_document_stats() {
+        let markdown = "---\ntitle: Notes\n---\n# Café guide\n\nDon't skip the crème brûlée, it's 3.50!\n\n```rust\nlet total = price * count;\n```\n\n- 東京 tower\n";
+        let elements = render_markdown_to_elements(markdown, true);
+
+        let stats = DocumentStats::from_elements(&elements, false);
+        // "Café guide" 2, the sentence 7, the list item 3 (each ideograph is
+        // a word), the code 4
+        assert_eq!(stats.words, 16);
+        assert_eq!(stats.headings, 1);
+        assert_eq!(stats.code_blocks, 1);
+        assert_eq!(stats.reading_time_minutes, 1);
+    }
+
+    #[test]
+    fn test_reading_time_can_exclude_code() {
+        let prose = "word ".repeat(250);
+        let code = "token ".repeat(300);
+        let markdown = format!("{prose}\n\n```\n{code}\n```\n");
+        let elements = render_markdown_to_elements(&markdown, true);
+
+        assert_eq!(
+            DocumentStats::from_elements(&elements, false).reading_time_minutes,
+            2
+        );
+        assert_eq!(
+            DocumentStats::from_elements(&elements, true).reading_time_minutes,
+            3
+        );
+    }
+
+    #[test]
+    fn test_selection_stats() {
+        let stats = SelectionStats::from_text("Hello, wörld!\nit's 東京");
+        assert_eq!(
+            stats,
+            SelectionStats {
+                words: 5,
+                chars: 20,
+                lines: 2
+            }
+        );
+    }
+}
@@ -0,0 +1,490 @@
+//! Incremental rendering for markdown that arrives in pieces.
+//!
+//! A streamed chat reply grows by a few tokens at a time. Parsing the whole
+//! reply on every append makes the work per append grow with the reply, so
+//! [`StreamingMarkdown`] splits the text at stable block boundaries: text
+//! before the last boundary is parsed and rendered once, and only the
+//! unfinished tail is parsed again on each append.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use ratatui_toolkit::markdown_widget::foundation::streaming::StreamingMarkdown;
+//!
+//! let mut markdown = StreamingMarkdown::new(80);
+//! markdown.push_str("# Title\n\nSome ");
+//! markdown.push_str("text\n\n```rust\nfn main() {");
+//! // The unclosed fence is shown as code until more text arrives.
+//! let lines = markdown.lines();
+//! ```
+
+use std::fmt;
+use std::sync::Arc;
+
+use ratatui::text::Line;
+
+use super::elements::{render, ElementKind, MarkdownElement};
+use super::parser::parse_elements;
+use crate::widgets::markdown_preview::widgets::markdown_widget::extensions::theme::SyntaxHighlighter;
+
+/// Parser work done by the most recent [`StreamingMarkdown::push_str`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StreamStats {
+    /// Blocks handed to the parser: one for each block finished by the
+    /// append plus one for the unfinished tail.
+    ///
+    /// A block here is the text between two stable boundaries, which may
+    /// hold several markdown blocks when no blank line separates them.
+    pub blocks_parsed: usize,
+    /// Bytes of source handed to the parser.
+    pub bytes_parsed: usize,
+}
+
+/// Markdown source that is rendered incrementally as it grows.
+///
+/// Stable boundaries are the start of a line at column 0 that follows a
+/// blank line, outside fenced code, raw HTML and frontmatter, and that
+/// cannot continue a list or block quote. Text before such a boundary can
+/// never be changed by text after it, so its rendered lines are kept.
+///
+/// The tail after the last boundary is parsed on every append. An unclosed
+/// code fence in the tail is rendered as code running to the end of the
+/// text, exactly as a full parse would, until its closing fence arrives.
+///
+/// Link reference and footnote definitions change how earlier text is
+/// parsed, so once one appears every append parses the whole text. Either
+/// way, [`lines`](Self::lines) always equals
+/// [`render_markdown`](super::functions::render_markdown) of the full
+/// text at the same width.
+#[derive(Clone)]
+pub struct StreamingMarkdown {
+    source: String,
+    /// Shared by every parse, since loading the syntax definitions takes
+    /// far longer than parsing a few blocks.
+    highlighter: Arc<SyntaxHighlighter>,
+    width: usize,
+    /// Byte offset of the first line not yet scanned for boundaries.
+    scanned: usize,
+    scanner: BoundaryScanner,
+    /// Byte offset where the unfinished tail starts.
+    stable_end: usize,
+    stable_elements: Vec<MarkdownElement>,
+    stable_lines: Vec<Line<'static>>,
+    /// Number of spacing lines at the end of `stable_lines`.
+    stable_trailing_empty: usize,
+    tail_elements: Vec<MarkdownElement>,
+    tail_lines: Vec<Line<'static>>,
+    finished: bool,
+    last_stats: StreamStats,
+}
+
+impl StreamingMarkdown {
+    /// Creates empty streaming markdown rendered at `width` columns.
+    pub fn new(width: usize) -> Self {
+        Self {
+            source: String::new(),
+            highlighter: Arc::new(SyntaxHighlighter::new()),
+            width,
+            scanned: 0,
+            scanner: BoundaryScanner::default(),
+            stable_end: 0,
+            stable_elements: Vec::new(),
+            stable_lines: Vec::new(),
+            stable_trailing_empty: 0,
+            tail_elements: Vec::new(),
+            tail_lines: Vec::new(),
+            finished: false,
+            last_stats: StreamStats::default(),
+        }
+    }
+
+    /// Appends `chunk` and re-renders the text after the last stable
+    /// boundary.
+    pub fn push_str(&mut self, chunk: &str) {
+        self.source.push_str(chunk);
+
+        let mut boundaries = Vec::new();
+        while let Some(offset) = self.source[self.scanned..].find('\n') {
+            let start = self.scanned;
+            let end = start + offset + 1;
+            if self.scanner.scan_line(&self.source[start..end]) {
+                boundaries.push(start);
+            }
+            self.scanned = end;
+        }
+
+        let mut stats = StreamStats::default();
+        if !self.scanner.incremental {
+            self.stable_end = 0;
+            self.stable_elements.clear();
+            self.stable_lines.clear();
+            self.stable_trailing_empty = 0;
+        } else {
+            for boundary in boundaries {
+                if boundary > self.stable_end {
+                    self.stabilize(boundary, &mut stats);
+                }
+            }
+        }
+        self.parse_tail(&mut stats);
+        self.last_stats = stats;
+    }
+
+    /// Marks the text as complete.
+    ///
+    /// The rendered lines already cover the whole text, including any
+    /// unclosed code fence, so this only records that no more text is
+    /// expected.
+    pub fn finish(&mut self) {
+        self.finished = true;
+    }
+
+    /// Returns true once [`finish`](Self::finish) has been called.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// The text pushed so far.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// The width lines are rendered at.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Re-renders every line at `width` without parsing again.
+    pub fn set_width(&mut self, width: usize) {
+        if width == self.width {
+            return;
+        }
+        self.width = width;
+        self.stable_lines = render_all(&self.stable_elements, width);
+        self.tail_lines = render_all(&self.tail_elements, width);
+    }
+
+    /// Parser work done by the most recent append.
+    pub fn last_stats(&self) -> StreamStats {
+        self.last_stats
+    }
+
+    /// The rendered lines for the text so far.
+    pub fn lines(&self) -> Vec<Line<'static>> {
+        let mut lines = if self.tail_lines.is_empty() {
+            let end = self.stable_lines.len() - self.stable_trailing_empty;
+            self.stable_lines[..end].to_vec()
+        } else {
+            let mut lines = self.stable_lines.clone();
+            lines.extend(self.tail_lines.iter().cloned());
+            lines
+        };
+        if lines.is_empty() {
+            lines = render(&MarkdownElement::default(), self.width);
+        }
+        lines
+    }
+
+    /// Parses the text up to `boundary` and keeps its lines.
+    fn stabilize(&mut self, boundary: usize, stats: &mut StreamStats) {
+        let text = &self.source[self.stable_end..boundary];
+        let elements = parse_elements(text, true, false, &self.highlighter);
+        stats.blocks_parsed += 1;
+        stats.bytes_parsed += text.len();
+
+        let trailing = elements
+            .iter()
+            .rev()
+            .take_while(|element| matches!(element.kind, ElementKind::Empty))
+            .count();
+        if trailing == elements.len() {
+            self.stable_trailing_empty += trailing;
+        } else {
+            self.stable_trailing_empty = trailing;
+        }
+        self.stable_lines.extend(render_all(&elements, self.width));
+        self.stable_elements.extend(elements);
+        self.stable_end = boundary;
+    }
+
+    fn parse_tail(&mut self, stats: &mut StreamStats) {
+        let text = &self.source[self.stable_end..];
+        let mut elements = if text.trim().is_empty() {
+            Vec::new()
+        } else {
+            stats.blocks_parsed += 1;
+            stats.bytes_parsed += text.len();
+            parse_elements(text, true, false, &self.highlighter)
+        };
+        while matches!(elements.last(), Some(element) if matches!(element.kind, ElementKind::Empty))
+        {
+            elements.pop();
+        }
+        self.tail_lines = render_all(&elements, self.width);
+        self.tail_elements = elements;
+    }
+}
+
+impl fmt::Debug for StreamingMarkdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StreamingMarkdown")
+            .field("source", &self.source)
+            .field("width", &self.width)
+            .field("stable_end", &self.stable_end)
+            .field("finished", &self.finished)
+            .field("last_stats", &self.last_stats)
+            .finish_non_exhaustive()
+    }
+}
+
+fn render_all(elements: &[MarkdownElement], width: usize) -> Vec<Line<'static>> {
+    elements
+        .iter()
+        .flat_map(|element| render(element, width))
+        .collect()
+}
+
+/// Tracks the constructs a stable boundary may not fall inside.
+#[derive(Debug, Clone)]
+pub(crate) struct BoundaryScanner {
+    /// Whether a non-blank line has been seen.
+    seen_content: bool,
+    /// Whether the previous line was blank.
+    after_blank: bool,
+    /// Whether the text opened with `---` and the frontmatter is unclosed.
+    in_frontmatter: bool,
+    /// The open fence's character and length.
+    fence: Option<(char, usize)>,
+    /// What ends the open raw HTML block.
+    html_end: Option<&'static str>,
+    /// False once a link reference or footnote definition is seen.
+    pub(crate) incremental: bool,
+}
+
+impl Default for BoundaryScanner {
+    fn default() -> Self {
+        Self {
+            seen_content: false,
+            after_blank: false,
+            in_frontmatter: false,
+            fence: None,
+            html_end: None,
+            incremental: true,
+        }
+    }
+}
+
+impl BoundaryScanner {
+    /// Scans one complete line, returning true if a stable boundary is at
+    /// its start.
+    pub(crate) fn scan_line(&mut self, line: &str) -> bool {
+        let content = line.trim_end_matches(['\n', '\r']);
+        let blank = content.trim().is_empty();
+
+        let boundary = self.seen_content
+            && self.after_blank
+            && !self.in_frontmatter
+            && self.fence.is_none()
+            && self.html_end.is_none()
+            && starts_stable_block(content);
+
+        if self.in_frontmatter {
+            // The frontmatter parser closes at the next line starting `---`.
+            self.in_frontmatter = !content.starts_with("---");
+        } else if let Some((fence_char, fence_len)) = self.fence {
+            if closes_fence(content, fence_char, fence_len) {
+                self.fence = None;
+            }
+        } else if let Some(end) = self.html_end {
+            if content.to_ascii_lowercase().contains(end) {
+                self.html_end = None;
+            }
+        } else if !blank {
+            if !self.seen_content && content.trim_start().starts_with("---") {
+                self.in_frontmatter = true;
+            } else if let Some(fence) = opens_fence(content) {
+                self.fence = Some(fence);
+            } else if let Some(end) = opens_raw_html(content) {
+                let rest = content.trim_start().to_ascii_lowercase();
+                if !rest[1..].contains(end) {
+                    self.html_end = Some(end);
+                }
+            } else if is_definition(content) {
+                self.incremental = false;
+            }
+        }
+
+        self.seen_content |= !blank;
+        self.after_blank = blank && self.fence.is_none();
+        boundary
+    }
+}
+
+/// Whether a line can start a block that never continues the one before a
+/// blank line.
+fn starts_stable_block(line: &str) -> bool {
+    let Some(first) = line.chars().next() else {
+        return false;
+    };
+    if first.is_whitespace() || first == '>' || first == '<' || line.starts_with("---") {
+        return false;
+    }
+    if matches!(first, '-' | '+' | '*') {
+        return !matches!(line[1..].chars().next(), None | Some(' ' | '\t'));
+    }
+    let digits = line.bytes().take_while(u8::is_ascii_digit).count();
+    !(digits > 0 && matches!(line.as_bytes().get(digits), Some(b'.' | b')')))
+}
+
+/// Strips up to three spaces of indentation, or returns `None` for a line
+/// indented further.
+fn block_indent(line: &str) -> Option<&str> {
+    let rest = line.trim_start_matches(' ');
+    (line.len() - rest.len() <= 3).then_some(rest)
+}
+
+fn opens_fence(line: &str) -> Option<(char, usize)> {
+    let rest = block_indent(line)?;
+    let fence_char = rest.chars().next().filter(|c| matches!(c, '`' | '~'))?;
+    let len = rest.chars().take_while(|&c| c == fence_char).count();
+    if len < 3 || (fence_char == '`' && rest[len..].contains('`')) {
+        return None;
+    }
+    Some((fence_char, len))
+}
+
+fn closes_fence(line: &str, fence_char: char, fence_len: usize) -> bool {
+    let Some(rest) = block_indent(line) else {
+        return false;
+    };
+    let len = rest.chars().take_while(|&c| c == fence_char).count();
+    len >= fence_len && rest[len..].trim().is_empty()
+}
+
+/// Returns what ends a raw HTML block opened by `line`, for the kinds of
+/// HTML block that may contain blank lines.
+fn opens_raw_html(line: &str) -> Option<&'static str> {
+    let rest = block_indent(line)?.to_ascii_lowercase();
+    if rest.starts_with("<!--") {
+        return Some("-->");
+    }
+    if rest.starts_with("<![cdata[") {
+        return Some("]]>");
+    }
+    if rest.starts_with("<?") {
+        return Some("?>");
+    }
+    if rest.starts_with("<!") {
+        return Some(">");
+    }
+    for (tag, end) in [
+        ("<pre", "</pre>"),
+        ("<script", "</script>"),
+        ("<style", "</style>"),
+        ("<textarea", "</textarea>"),
+    ] {
+        if rest.starts_with(tag)
+            && matches!(
+                rest[tag.len()..].chars().next(),
+                None | Some(' ' | '\t' | '>')
+            )
+        {
+            return Some(end);
+        }
+    }
+    None
+}
+
+/// Whether `line` looks like a link reference or footnote definition.
+fn is_definition(line: &str) -> bool {
+    block_indent(line).is_some_and(|rest| rest.starts_with('[') && rest.contains("]:"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::functions::render_markdown;
+
+    const WIDTH: usize = 80;
+
+    fn full_parse(text: &str) -> Vec<Line<'static>> {
+        render_markdown(text, Some(WIDTH)).lines
+    }
+
+    fn stream(text: &str, chunk_len: usize) -> StreamingMarkdown {
+        let mut markdown = StreamingMarkdown::new(WIDTH);
+        let mut start = 0;
+        while start < text.len() {
+            let mut end = (start + chunk_len).min(text.len());
+            while !text.is_char_boundary(end) {
+                end += 1;
+            }
+            markdown.push_str(&text[start..end]);
+            start = end;
+        }
+        markdown
+    }
+
+    /// About 10k lines mixing the block kinds a chat reply uses.
+    fn long_reply() -> String {
+        let mut text = String::from("# Reply\n\n");
+        for i in 0..700 {
+            text.push_str(&format!("## Step {i}\n\nSome *text* with `code` and a [link](https://example.com/{i}).\nA second line of the same paragraph.\n\n"));
+            text.push_str("- first item\n- second item\n  continued\n\n");
+            text.push_str("```rust\nfn main() {\n\n    println!(\"hi\");\n}\n```\n\n");
+            text.push_str("> quoted\n\n| a | b |\n|---|---|\n| 1 | 2 |\n\n");
+        }
+        text
+    }
+
+    #[test]
+    fn test_long_stream_parses_only_the_tail() {
+        let text = long_reply();
+        assert!(text.lines().count() >= 10_000);
+
+        let mut markdown = StreamingMarkdown::new(WIDTH);
+        let mut start = 0;
+        let mut appends = 0;
+        while start < text.len() {
+            let end = (start + 37).min(text.len());
+            markdown.push_str(&text[start..end]);
+            start = end;
+            appends += 1;
+
+            let stats = markdown.last_stats();
+            assert!(stats.blocks_parsed <= 3, "{stats:?} at byte {end}");
+            assert!(stats.bytes_parsed <= 400, "{stats:?} at byte {end}");
+            if appends % 1000 == 0 {
+                assert_eq!(markdown.lines(), full_parse(&text[..end]));
+            }
+        }
+        markdown.finish();
+        assert_eq!(markdown.lines(), full_parse(&text));
+    }
+
+    #[test]
+    fn test_unclosed_fence_is_code_until_closed() {
+        let mut markdown = StreamingMarkdown::new(WIDTH);
+        markdown.push_str("Intro\n\n```rust\nfn main() {\n\nlet x = 1;\n");
+        let open = markdown.lines();
+        assert_eq!(open, full_parse(markdown.source()));
+        assert!(open
+            .iter()
+            .any(|line| line.to_string().contains("let x = 1;")));
+
+        markdown.push_str("}\n```\n\nAfter the code.");
+        markdown.finish();
+        assert_eq!(markdown.lines(), full_parse(markdown.source()));
+    }
+
+    #[test]
+    fn test_stream_matches_full_parse_for_tricky_blocks() {
+        let text = "---\ntitle: Notes\n---\n\nIntro\n\n- a\n\n- b\n\n1. one\n\n2. two\n\n<!--\n\nhidden\n\n-->\n\n> quote\n\nlazy\n\n    indented\n\n    code\n\n***\n\nSee [docs].\n\n[docs]: https://example.com\n\nDone\n";
+        for chunk_len in [1, 3, 7, 64] {
+            let markdown = stream(text, chunk_len);
+            assert_eq!(markdown.lines(), full_parse(text), "chunks of {chunk_len}");
+        }
+        assert_eq!(stream("", 1).lines(), full_parse(""));
+        assert_eq!(stream("\n\n", 1).lines(), full_parse("\n\n"));
+    }
+}
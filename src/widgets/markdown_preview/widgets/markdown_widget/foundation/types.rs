@@ -30,3 +30,41 @@ impl SelectionPos {
         Self { x, y }
     }
 }
+
+/// A clickable link in the rendered lines.
+///
+/// A link that wraps is recorded once per rendered line it covers, each
+/// with the link's full `url` and `text`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkRange {
+    /// Rendered line index.
+    pub row: usize,
+    /// First character column of the link, its icon included.
+    pub start: usize,
+    /// Character column one past the link text on this line.
+    pub end: usize,
+    /// Link destination.
+    pub url: String,
+    /// Link text.
+    pub text: String,
+}
+
+/// A fence rendered as a collapsible block because its language cannot be
+/// highlighted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FenceBlock {
+    /// Collapse ID: source line of the opening fence (1-indexed).
+    pub id: usize,
+    /// Rendered line index of the block's header.
+    pub row: usize,
+    /// Number of rendered lines, header and bottom border included.
+    pub line_count: usize,
+    /// Character columns of the copy glyph in the header.
+    pub copy_columns: (usize, usize),
+    /// Fence language as written in the info string.
+    pub language: String,
+    /// Raw fence content, one source line per line.
+    pub content: String,
+    /// Whether the block is collapsed to its header.
+    pub collapsed: bool,
+}
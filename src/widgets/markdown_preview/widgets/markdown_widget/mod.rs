@@ -130,15 +130,22 @@ pub use foundation::source::MarkdownSource;
 pub use foundation::events::{MarkdownDoubleClickEvent, MarkdownEvent};
 
 // Types
-pub use foundation::types::{GitStats, SelectionPos};
+pub use foundation::types::{FenceBlock, GitStats, LinkRange, SelectionPos};
 
 // Functions
 pub use foundation::functions::{render_markdown, render_markdown_with_style};
 
+// Streaming
+pub use foundation::streaming::{StreamStats, StreamingMarkdown};
+
+// Statistics
+pub use foundation::stats::{DocumentStats, SelectionStats, WORDS_PER_MINUTE};
+
 // ============================================================================
 // Widget
 // ============================================================================
 
+pub use widget::FenceRenderFn;
 pub use widget::MarkdownWidget;
 pub use widget::MarkdownWidgetMode;
 
@@ -148,8 +155,8 @@ pub use widget::MarkdownWidgetMode;
 
 pub use state::{
     CacheState, CollapseState, DisplaySettings, DoubleClickState, ExpandableEntry, ExpandableState,
-    GitStatsState, MarkdownState, ParsedCache, RenderCache, ScrollState, SelectionState,
-    SourceState, TocEntry, TocState, VimState,
+    GitStatsState, MarkdownState, ParsedCache, RenderCache, ScrollState, SearchMatch, SearchState,
+    SelectionState, SourceState, TocEntry, TocFocusAction, TocPosition, TocState, VimState,
 };
 
 // ============================================================================
@@ -177,6 +184,12 @@ pub use extensions::theme::{
     ThemeVariant,
 };
 
+// Decorations
+pub use extensions::decorations::{
+    DecorationRange, DecorationSpan, RelativeTimeDecorator, TextDecorator,
+    ThousandsSeparatorDecorator,
+};
+
 // Selection handlers
 pub use extensions::selection::{
     handle_click, handle_mouse_event, handle_mouse_event_with_double_click, should_render_line,
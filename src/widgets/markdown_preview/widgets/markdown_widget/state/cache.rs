@@ -2,9 +2,22 @@
 //!
 //! Manages parsed and rendered markdown caches for efficient rendering.
 
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::widgets::markdown_preview::widgets::markdown_widget::extensions::decorations::DecorationRange;
+use crate::widgets::markdown_preview::widgets::markdown_widget::extensions::theme::SyntaxHighlighter;
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::blocks::{
+    parse_blocks, BlockDiff, BlockId, ParsedBlock,
+};
 use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::{
     CodeBlockTheme, MarkdownElement,
 };
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::helpers::hash_content;
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::stats::DocumentStats;
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::types::{
+    FenceBlock, LinkRange,
+};
 use ratatui::text::Line;
 
 /// Cache state for markdown rendering.
@@ -18,6 +31,8 @@ pub struct CacheState {
     pub(crate) parsed: Option<ParsedCache>,
     /// Cache for rendered lines (depends on width).
     pub(crate) render: Option<RenderCache>,
+    /// Statistics for the parsed document.
+    pub(crate) stats: Option<DocumentStats>,
 }
 
 impl CacheState {
@@ -26,6 +41,7 @@ impl CacheState {
         Self {
             parsed: None,
             render: None,
+            stats: None,
         }
     }
 
@@ -40,6 +56,35 @@ impl CacheState {
     pub fn invalidate(&mut self) {
         self.parsed = None;
         self.render = None;
+        self.stats = None;
+    }
+
+    /// Invalidate only the top-level blocks `diff` reports as changed.
+    ///
+    /// Re-parses the changed blocks of `content` and keeps the parsed
+    /// elements and rendered lines of the others, so the next render lays
+    /// out only what changed. Use this instead of
+    /// [`invalidate`](Self::invalidate) when the content was reloaded.
+    pub fn invalidate_blocks(&mut self, content: &str, diff: &BlockDiff) {
+        let previous = self
+            .parsed
+            .take()
+            .map(|parsed| parsed.blocks)
+            .unwrap_or_default();
+        let (blocks, elements) = parse_blocks(content, &previous, &SyntaxHighlighter::new());
+        self.parsed = Some(ParsedCache {
+            content_hash: hash_content(content),
+            elements,
+            blocks,
+        });
+        self.stats = None;
+
+        if let Some(render) = &mut self.render {
+            let changed: HashSet<BlockId> = diff.changed().map(|block| block.id).collect();
+            render
+                .rendered_elements
+                .retain(|(id, _), _| !changed.contains(id));
+        }
     }
 
     /// Invalidate only the render cache.
@@ -86,6 +131,9 @@ pub struct ParsedCache {
     pub content_hash: u64,
     /// Parsed markdown elements.
     pub elements: Vec<MarkdownElement>,
+    /// Top-level blocks the elements were parsed from, for reuse when the
+    /// content changes. Empty if the content was parsed as a whole.
+    pub blocks: Vec<ParsedBlock>,
 }
 
 impl ParsedCache {
@@ -94,10 +142,37 @@ impl ParsedCache {
         Self {
             content_hash,
             elements,
+            blocks: Vec::new(),
+        }
+    }
+
+    /// The block of each element, with the block's content hash and the
+    /// element's index in it.
+    pub(crate) fn element_keys(&self) -> Vec<Option<(BlockId, u64, usize)>> {
+        let mut keys = vec![None; self.elements.len()];
+        for block in &self.blocks {
+            for idx in block.range.clone() {
+                if let Some(key) = keys.get_mut(idx) {
+                    *key = Some((block.source.id, block.source.hash, idx - block.range.start));
+                }
+            }
         }
+        keys
     }
 }
 
+/// Lines rendered for one element, kept across reloads while its block is
+/// unchanged.
+#[derive(Debug, Clone)]
+pub struct RenderedElement {
+    /// Content hash of the block the element belongs to.
+    pub block_hash: u64,
+    /// Rendered lines.
+    pub lines: Vec<Line<'static>>,
+    /// Decorated text, with rows counted from the element's first line.
+    pub decorations: Vec<DecorationRange>,
+}
+
 /// Cache for rendered markdown lines (depends on width).
 #[derive(Debug, Clone)]
 pub struct RenderCache {
@@ -113,10 +188,29 @@ pub struct RenderCache {
     pub app_theme_hash: u64,
     /// Whether heading collapse indicators were shown.
     pub show_heading_collapse: bool,
+    /// Hash of the per-language code block overrides and registered fence
+    /// renderer languages.
+    pub fence_hash: u64,
+    /// Hash of the enabled text decorators.
+    pub decoration_hash: u64,
     /// Cached rendered lines.
     pub lines: Vec<Line<'static>>,
     /// Line boundaries: (start_visual_idx, visual_line_count) for each logical line.
     pub line_boundaries: Vec<(usize, usize)>,
+    /// Source line (1-indexed) of each entry in `line_boundaries`, or 0
+    /// where it is not known.
+    pub source_lines: Vec<usize>,
+    /// Fences rendered by a custom renderer: (start_visual_idx, visual_line_count, raw content).
+    pub raw_blocks: Vec<(usize, usize, String)>,
+    /// Fences rendered as collapsible blocks, for toggling and copying.
+    pub fences: Vec<FenceBlock>,
+    /// Decorated text in the cached lines, mapped back to its source text.
+    pub decorations: Vec<DecorationRange>,
+    /// Links in the cached lines, for click hit-testing.
+    pub links: Vec<LinkRange>,
+    /// Lines of each element by block and index in the block, reused by
+    /// the next render for blocks that did not change.
+    pub rendered_elements: HashMap<(BlockId, usize), Arc<RenderedElement>>,
 }
 
 impl RenderCache {
@@ -129,8 +223,16 @@ impl RenderCache {
         theme: CodeBlockTheme,
         app_theme_hash: u64,
         show_heading_collapse: bool,
+        fence_hash: u64,
+        decoration_hash: u64,
         lines: Vec<Line<'static>>,
         line_boundaries: Vec<(usize, usize)>,
+        source_lines: Vec<usize>,
+        raw_blocks: Vec<(usize, usize, String)>,
+        fences: Vec<FenceBlock>,
+        decorations: Vec<DecorationRange>,
+        links: Vec<LinkRange>,
+        rendered_elements: HashMap<(BlockId, usize), Arc<RenderedElement>>,
     ) -> Self {
         Self {
             content_hash,
@@ -139,8 +241,38 @@ impl RenderCache {
             theme,
             app_theme_hash,
             show_heading_collapse,
+            fence_hash,
+            decoration_hash,
             lines,
             line_boundaries,
+            source_lines,
+            raw_blocks,
+            fences,
+            decorations,
+            links,
+            rendered_elements,
         }
     }
+
+    /// Source line (1-indexed) rendered on `row` (0-indexed).
+    pub(crate) fn source_line_at_row(&self, row: usize) -> Option<usize> {
+        self.line_boundaries
+            .iter()
+            .zip(&self.source_lines)
+            .filter(|(_, &line)| line > 0)
+            .take_while(|((start, _), _)| *start <= row)
+            .last()
+            .map(|(_, &line)| line)
+    }
+
+    /// First rendered row (0-indexed) of source line `line` (1-indexed), or
+    /// of the nearest line above it that was rendered.
+    pub(crate) fn row_for_source_line(&self, line: usize) -> Option<usize> {
+        self.line_boundaries
+            .iter()
+            .zip(&self.source_lines)
+            .filter(|((_, count), &source)| *count > 0 && source > 0 && source <= line)
+            .max_by_key(|(_, &source)| source)
+            .map(|((start, _), _)| *start)
+    }
 }
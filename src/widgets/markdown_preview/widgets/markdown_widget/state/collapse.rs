@@ -13,6 +13,9 @@ pub struct CollapseState {
     sections: HashMap<usize, bool>,
     /// Section hierarchy: section_id -> (level, parent_section_id).
     hierarchy: HashMap<usize, (u8, Option<usize>)>,
+    /// Collapsible block state: block_id -> is_collapsed. Blocks the user
+    /// never toggled are missing and use their default.
+    blocks: HashMap<usize, bool>,
 }
 
 /// Constructor for CollapseState.
@@ -23,6 +26,7 @@ impl CollapseState {
         Self {
             sections: HashMap::new(),
             hierarchy: HashMap::new(),
+            blocks: HashMap::new(),
         }
     }
 }
@@ -187,6 +191,72 @@ impl CollapseState {
     }
 }
 
+/// Block collapse methods for CollapseState.
+impl CollapseState {
+    /// Get the collapse state of a block, such as a fenced code block.
+    ///
+    /// Blocks are tracked apart from sections, so a block and a section may
+    /// share an ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_id` - The ID of the block.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the block was never collapsed or expanded.
+    pub fn block_collapsed(&self, block_id: usize) -> Option<bool> {
+        self.blocks.get(&block_id).copied()
+    }
+
+    /// Set the collapse state of a block.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_id` - The ID of the block.
+    /// * `collapsed` - Whether the block should be collapsed.
+    pub fn set_block_collapsed(&mut self, block_id: usize, collapsed: bool) {
+        self.blocks.insert(block_id, collapsed);
+    }
+}
+
+/// Remap methods for CollapseState.
+impl CollapseState {
+    /// Move section state to new section IDs after the content changed.
+    ///
+    /// Sections `remap` maps to `None` are forgotten. Section 0, the text
+    /// before the first heading, keeps its ID. The hierarchy is cleared and
+    /// registered again on the next render.
+    ///
+    /// # Arguments
+    ///
+    /// * `remap` - Maps an old section ID to its new one.
+    pub fn remap_sections(&mut self, remap: impl Fn(usize) -> Option<usize>) {
+        self.sections = std::mem::take(&mut self.sections)
+            .into_iter()
+            .filter_map(|(id, collapsed)| {
+                let id = if id == 0 { Some(0) } else { remap(id) };
+                id.map(|id| (id, collapsed))
+            })
+            .collect();
+        self.hierarchy.clear();
+    }
+
+    /// Move block state to new block IDs after the content changed.
+    ///
+    /// Blocks `remap` maps to `None` are forgotten.
+    ///
+    /// # Arguments
+    ///
+    /// * `remap` - Maps an old block ID to its new one.
+    pub fn remap_blocks(&mut self, remap: impl Fn(usize) -> Option<usize>) {
+        self.blocks = std::mem::take(&mut self.blocks)
+            .into_iter()
+            .filter_map(|(id, collapsed)| remap(id).map(|id| (id, collapsed)))
+            .collect();
+    }
+}
+
 /// Default trait implementation for CollapseState.
 
 impl Default for CollapseState {
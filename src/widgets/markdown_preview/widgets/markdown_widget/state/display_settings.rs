@@ -2,8 +2,23 @@
 //!
 //! Manages display-related configuration like line numbers and themes.
 
+use std::collections::HashSet;
+
 use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::CodeBlockTheme;
 
+/// Default for [`DisplaySettings::fence_collapse_threshold`].
+pub(crate) const DEFAULT_FENCE_COLLAPSE_LINES: usize = 20;
+
+/// Which side of the document the TOC is drawn on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TocPosition {
+    /// Along the left edge.
+    Left,
+    /// Along the right edge, clear of the scrollbar.
+    #[default]
+    Right,
+}
+
 /// Display settings for markdown rendering.
 ///
 /// Controls visual options like line numbers, themes, and collapse indicators.
@@ -19,6 +34,22 @@ pub struct DisplaySettings {
     pub show_heading_collapse: bool,
     /// Scroll multiplier (lines per scroll tick).
     pub scroll_multiplier: usize,
+    /// Names of registered text decorators that are turned off.
+    pub disabled_decorations: HashSet<String>,
+    /// Whether code blocks count towards the reading time estimate.
+    pub reading_time_includes_code: bool,
+    /// Widest the content is wrapped to, however wide the area is.
+    pub max_content_width: Option<u16>,
+    /// Whether content narrower than the area is centered in it.
+    pub center_content: bool,
+    /// Whether links are written as OSC 8 hyperlinks, which terminals that
+    /// support them make clickable on their own.
+    pub hyperlinks: bool,
+    /// Fences in a language that cannot be highlighted start collapsed
+    /// when they have more lines than this. `None` never collapses them.
+    pub fence_collapse_threshold: Option<usize>,
+    /// Which side of the document the TOC is drawn on.
+    pub toc_position: TocPosition,
 }
 
 impl DisplaySettings {
@@ -30,6 +61,13 @@ impl DisplaySettings {
             code_block_theme: CodeBlockTheme::default(),
             show_heading_collapse: false,
             scroll_multiplier: 3,
+            disabled_decorations: HashSet::new(),
+            reading_time_includes_code: false,
+            max_content_width: None,
+            center_content: false,
+            hyperlinks: false,
+            fence_collapse_threshold: Some(DEFAULT_FENCE_COLLAPSE_LINES),
+            toc_position: TocPosition::default(),
         }
     }
 
@@ -110,6 +148,160 @@ impl DisplaySettings {
         }
     }
 
+    /// Enable or disable a registered text decorator by name.
+    ///
+    /// Decorators are enabled by default once registered.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The decorator's [`TextDecorator::name`](crate::widgets::markdown_preview::widgets::markdown_widget::extensions::decorations::TextDecorator::name).
+    /// * `enabled` - Whether the decorator should run.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the value changed (caller should invalidate cache).
+    pub fn set_decoration_enabled(&mut self, name: &str, enabled: bool) -> bool {
+        if enabled {
+            self.disabled_decorations.remove(name)
+        } else {
+            self.disabled_decorations.insert(name.to_string())
+        }
+    }
+
+    /// Count code blocks towards the reading time estimate.
+    ///
+    /// # Arguments
+    ///
+    /// * `include` - Whether code blocks are read too.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the value changed (caller should recompute statistics).
+    pub fn set_reading_time_includes_code(&mut self, include: bool) -> bool {
+        if self.reading_time_includes_code != include {
+            self.reading_time_includes_code = include;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Check whether the text decorator called `name` is enabled.
+    pub fn is_decoration_enabled(&self, name: &str) -> bool {
+        !self.disabled_decorations.contains(name)
+    }
+
+    /// Cap the width content is wrapped to.
+    ///
+    /// Prose, code blocks and tables all wrap to the smaller of the area
+    /// width and `width`.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Maximum content width in columns, or `None` for no cap.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the value changed (caller should invalidate cache).
+    pub fn set_max_content_width(&mut self, width: Option<u16>) -> bool {
+        if self.max_content_width != width {
+            self.max_content_width = width;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Center content narrower than the area, leaving equal gutters.
+    ///
+    /// # Arguments
+    ///
+    /// * `center` - Whether to center the content.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the value changed.
+    pub fn set_center_content(&mut self, center: bool) -> bool {
+        if self.center_content != center {
+            self.center_content = center;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Write links as OSC 8 hyperlinks.
+    ///
+    /// Terminals that understand OSC 8 open the link on click themselves;
+    /// others ignore the escape sequences. Off by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to emit hyperlinks.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the value changed.
+    pub fn set_hyperlinks(&mut self, enabled: bool) -> bool {
+        if self.hyperlinks != enabled {
+            self.hyperlinks = enabled;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Set how many lines a fence in a language that cannot be highlighted
+    /// may have before it starts collapsed.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - Line count, or `None` to never collapse by default.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the value changed (caller should invalidate cache).
+    pub fn set_fence_collapse_threshold(&mut self, threshold: Option<usize>) -> bool {
+        if self.fence_collapse_threshold != threshold {
+            self.fence_collapse_threshold = threshold;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Move the TOC to the other side of the document.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The side to draw the TOC on.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the value changed.
+    pub fn set_toc_position(&mut self, position: TocPosition) -> bool {
+        if self.toc_position != position {
+            self.toc_position = position;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Width to wrap content to and its offset from the left edge, given
+    /// `available` columns.
+    pub fn content_layout(&self, available: usize) -> (usize, usize) {
+        let width = self
+            .max_content_width
+            .map_or(available, |max| available.min(max as usize));
+        let offset = if self.center_content {
+            (available - width) / 2
+        } else {
+            0
+        };
+        (width, offset)
+    }
+
     /// Enable or disable line numbers in code blocks.
     ///
     /// # Arguments
@@ -134,3 +326,66 @@ impl Default for DisplaySettings {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::Widget;
+
+    use crate::widgets::markdown_preview::widgets::markdown_widget::state::MarkdownState;
+    use crate::widgets::markdown_preview::widgets::markdown_widget::widget::MarkdownWidget;
+
+    fn render(state: &MarkdownState) -> Buffer {
+        let area = Rect::new(0, 0, 80, 12);
+        let mut buf = Buffer::empty(area);
+        MarkdownWidget::from_state(state)
+            .with_has_pane(false)
+            .show_statusline(false)
+            .show_scrollbar(false)
+            .render(area, &mut buf);
+        buf
+    }
+
+    /// Columns of `row` that hold something other than a space.
+    fn used_columns(buf: &Buffer, row: u16) -> Vec<u16> {
+        (0..buf.area.width)
+            .filter(|&x| buf[(x, row)].symbol() != " ")
+            .collect()
+    }
+
+    #[test]
+    fn test_content_wraps_to_max_width() {
+        let mut state = MarkdownState::new();
+        state.source.set_source_string(format!(
+            "{}\n\n```\n{}\n```\n\n| a | b |\n|---|---|\n| {} | x |\n",
+            "word ".repeat(30),
+            "x".repeat(60),
+            "cell ".repeat(15),
+        ));
+        state.display.set_max_content_width(Some(40));
+
+        let buf = render(&state);
+        for row in 0..buf.area.height {
+            assert!(used_columns(&buf, row).iter().all(|&x| x < 40), "row {row}");
+        }
+        assert!((0..buf.area.height).any(|row| used_columns(&buf, row).contains(&39)));
+
+        state.display.set_center_content(true);
+        let buf = render(&state);
+        let columns = used_columns(&buf, 0);
+        assert_eq!(columns.first(), Some(&20));
+        assert!(columns.iter().all(|&x| x < 60));
+    }
+
+    #[test]
+    fn test_content_layout() {
+        let mut display = super::DisplaySettings::new();
+        assert_eq!(display.content_layout(80), (80, 0));
+        display.set_max_content_width(Some(100));
+        assert_eq!(display.content_layout(80), (80, 0));
+        display.set_max_content_width(Some(40));
+        display.set_center_content(true);
+        assert_eq!(display.content_layout(81), (40, 20));
+    }
+}
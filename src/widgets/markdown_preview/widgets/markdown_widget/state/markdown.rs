@@ -3,10 +3,13 @@
 //! `MarkdownState` bundles all component states into a single struct,
 //! simplifying widget construction and state management.
 
+use std::collections::HashSet;
+
 use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::types::GitStats;
+use crate::widgets::markdown_preview::widgets::markdown_widget::state::reload::apply_block_diff;
 use crate::widgets::markdown_preview::widgets::markdown_widget::state::{
     CacheState, CollapseState, DisplaySettings, DoubleClickState, ExpandableState, GitStatsState,
-    ScrollState, SelectionState, SourceState, VimState,
+    ScrollState, SearchState, SelectionState, SourceState, VimState,
 };
 
 /// Unified state for the markdown widget.
@@ -49,12 +52,27 @@ pub struct MarkdownState {
     pub selection: SelectionState,
     /// Double-click detection state.
     pub double_click: DoubleClickState,
+    /// In-document search state.
+    pub search: SearchState,
     /// Whether the TOC is currently hovered.
     pub toc_hovered: bool,
     /// Index of the hovered TOC entry.
     pub toc_hovered_entry: Option<usize>,
     /// Scroll offset for the TOC list.
     pub toc_scroll_offset: usize,
+    /// Whether keyboard focus is in the TOC.
+    pub toc_focused: bool,
+    /// Index of the TOC entry selected in focus mode.
+    pub toc_selected_entry: usize,
+    /// Collapsed TOC subtrees, keyed by heading text so they survive
+    /// reloads.
+    pub toc_collapsed: HashSet<String>,
+    /// Width the TOC divider was dragged to, `None` for the default width.
+    pub toc_width: Option<u16>,
+    /// Whether the TOC divider is being dragged.
+    pub toc_resizing: bool,
+    /// Double-click detection on the TOC divider.
+    pub toc_divider_clicks: DoubleClickState,
     /// Whether selection mode is active.
     pub selection_active: bool,
     /// Git statistics for the file (cached from git_stats state).
@@ -102,10 +120,22 @@ impl MarkdownState {
 
     /// Reload file content if the watcher detected changes.
     ///
-    /// Returns `true` when content changed and caches were invalidated.
+    /// Only the blocks that changed are parsed and rendered again. The view
+    /// stays on the same heading and collapsed sections stay collapsed.
+    ///
+    /// Returns `true` when content changed and caches were updated.
     pub fn reload_source_if_changed(&mut self) -> std::io::Result<bool> {
         if self.source.reload_if_changed()? {
-            self.cache.invalidate();
+            match self.source.take_block_diff() {
+                Some(diff) => apply_block_diff(
+                    self.source.content().unwrap_or(""),
+                    &diff,
+                    &mut self.cache,
+                    &mut self.collapse,
+                    &mut self.scroll,
+                ),
+                None => self.cache.invalidate(),
+            }
             self.rendered_lines.clear();
             Ok(true)
         } else {
@@ -13,6 +13,7 @@
 //! - `TocState` - Table of Contents state
 //! - `SelectionState` - Text selection state
 //! - `DoubleClickState` - Double-click detection state
+//! - `SearchState` - In-document search state
 
 // Focused state modules
 pub mod cache;
@@ -22,22 +23,25 @@ pub mod double_click;
 pub mod expandable;
 pub mod git_stats;
 pub mod markdown;
+pub(crate) mod reload;
 pub mod scroll;
+pub mod search;
 pub mod selection;
 pub mod source;
 pub mod toc;
 pub mod vim;
 
 // State exports
-pub use cache::{CacheState, ParsedCache, RenderCache};
+pub use cache::{CacheState, ParsedCache, RenderCache, RenderedElement};
 pub use collapse::CollapseState;
-pub use display_settings::DisplaySettings;
+pub use display_settings::{DisplaySettings, TocPosition};
 pub use double_click::DoubleClickState;
 pub use expandable::{ExpandableEntry, ExpandableState};
 pub use git_stats::GitStatsState;
 pub use markdown::MarkdownState;
 pub use scroll::ScrollState;
+pub use search::{SearchMatch, SearchState};
 pub use selection::SelectionState;
 pub use source::SourceState;
-pub use toc::{TocEntry, TocState};
+pub use toc::{TocEntry, TocFocusAction, TocState};
 pub use vim::VimState;
@@ -0,0 +1,78 @@
+//! Carrying view state over a reload of the content.
+
+use std::collections::HashMap;
+
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::blocks::BlockDiff;
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::ElementKind;
+use crate::widgets::markdown_preview::widgets::markdown_widget::state::{
+    CacheState, CollapseState, ScrollState,
+};
+
+/// Updates the caches for `content` after the blocks in `diff` changed,
+/// keeping the view where it was.
+///
+/// Untouched blocks keep their parsed elements and rendered lines. Section
+/// and fence collapse state follows the headings and fences it belongs to,
+/// the heading at the top of the viewport stays there and the cursor stays
+/// on its source line, wherever these moved to.
+pub(crate) fn apply_block_diff(
+    content: &str,
+    diff: &BlockDiff,
+    cache: &mut CacheState,
+    collapse: &mut CollapseState,
+    scroll: &mut ScrollState,
+) {
+    let old_headings = heading_lines(cache);
+    let render = cache.render.as_ref();
+    let cursor = scroll.source_anchor.or_else(|| {
+        render.and_then(|render| render.source_line_at_row(scroll.current_line.saturating_sub(1)))
+    });
+    let top = render.and_then(|render| {
+        let line = render.source_line_at_row(scroll.scroll_offset)?;
+        let heading = old_headings
+            .values()
+            .copied()
+            .filter(|&heading| heading <= line)
+            .max()
+            .unwrap_or(line);
+        let row = render.row_for_source_line(heading)?;
+        Some((heading, scroll.scroll_offset.saturating_sub(row)))
+    });
+
+    let had_parse = cache.parsed.is_some();
+    cache.invalidate_blocks(content, diff);
+
+    if had_parse {
+        let new_sections: HashMap<usize, usize> = heading_lines(cache)
+            .into_iter()
+            .map(|(section_id, line)| (line, section_id))
+            .collect();
+        collapse.remap_sections(|section_id| {
+            let line = diff.map_line(*old_headings.get(&section_id)?)?;
+            new_sections.get(&line).copied()
+        });
+    }
+    collapse.remap_blocks(|line| diff.map_line(line));
+
+    if let Some((line, rows)) = top {
+        if let Some(line) = diff.map_line(line) {
+            scroll.anchor_top_to_source_line(line, rows);
+        }
+    }
+    if let Some(line) = cursor {
+        scroll.anchor_to_source_line(diff.map_line(line).unwrap_or(line));
+    }
+}
+
+/// Source line of each heading in the parsed cache, by section ID.
+fn heading_lines(cache: &CacheState) -> HashMap<usize, usize> {
+    cache
+        .parsed
+        .iter()
+        .flat_map(|parsed| &parsed.elements)
+        .filter_map(|element| match element.kind {
+            ElementKind::Heading { section_id, .. } => Some((section_id, element.source_line)),
+            _ => None,
+        })
+        .collect()
+}
@@ -20,6 +20,12 @@ pub struct ScrollState {
     pub filter: Option<String>,
     /// Whether filter mode is currently active.
     pub filter_mode: bool,
+    /// Source line (1-indexed) to move `current_line` to on the next
+    /// render, once the rendered rows are known.
+    pub source_anchor: Option<usize>,
+    /// Source line (1-indexed) to keep at the top of the viewport on the
+    /// next render, and how many of its rows are scrolled past.
+    pub top_anchor: Option<(usize, usize)>,
 }
 
 /// Constructor for ScrollState.
@@ -34,6 +40,8 @@ impl ScrollState {
             current_line: 1,
             filter: None,
             filter_mode: false,
+            source_anchor: None,
+            top_anchor: None,
         }
     }
 }
@@ -129,6 +137,8 @@ mod filter_tests {
             current_line: 5,
             filter: None,
             filter_mode: false,
+            source_anchor: None,
+            top_anchor: None,
         };
         state.filter_line_down(String::new());
         assert_eq!(state.current_line, 6);
@@ -143,6 +153,8 @@ mod filter_tests {
             current_line: 5,
             filter: None,
             filter_mode: false,
+            source_anchor: None,
+            top_anchor: None,
         };
         state.filter_line_up(String::new());
         assert_eq!(state.current_line, 4);
@@ -157,6 +169,8 @@ mod filter_tests {
             current_line: 100,
             filter: None,
             filter_mode: false,
+            source_anchor: None,
+            top_anchor: None,
         };
         state.filter_line_down(String::new());
         assert_eq!(state.current_line, 100);
@@ -171,6 +185,8 @@ mod filter_tests {
             current_line: 1,
             filter: None,
             filter_mode: false,
+            source_anchor: None,
+            top_anchor: None,
         };
         state.filter_line_up(String::new());
         assert_eq!(state.current_line, 1);
@@ -192,6 +208,8 @@ mod filter_empty_tests {
             current_line: 5,
             filter: None,
             filter_mode: false,
+            source_anchor: None,
+            top_anchor: None,
         };
         state.filter_line_down(String::new());
         assert_eq!(state.current_line, 6);
@@ -206,6 +224,8 @@ mod filter_empty_tests {
             current_line: 5,
             filter: None,
             filter_mode: false,
+            source_anchor: None,
+            top_anchor: None,
         };
         state.filter_line_up(String::new());
         assert_eq!(state.current_line, 4);
@@ -220,6 +240,8 @@ mod filter_empty_tests {
             current_line: 100,
             filter: None,
             filter_mode: false,
+            source_anchor: None,
+            top_anchor: None,
         };
         state.filter_line_down(String::new());
         assert_eq!(state.current_line, 100);
@@ -234,6 +256,8 @@ mod filter_empty_tests {
             current_line: 1,
             filter: None,
             filter_mode: false,
+            source_anchor: None,
+            top_anchor: None,
         };
         state.filter_line_up(String::new());
         assert_eq!(state.current_line, 1);
@@ -370,6 +394,35 @@ impl ScrollState {
         self.current_line = line.clamp(1, self.total_lines.max(1));
         self.adjust_scroll_for_current_line();
     }
+
+    /// Move `current_line` to the rendered row of source line `line`
+    /// (1-indexed) on the next render.
+    ///
+    /// Useful after the content changes, when the rows it will render to
+    /// are not known yet.
+    pub fn anchor_to_source_line(&mut self, line: usize) {
+        self.source_anchor = Some(line);
+    }
+
+    /// Take the source line set by [`anchor_to_source_line`](Self::anchor_to_source_line).
+    pub(crate) fn take_source_anchor(&mut self) -> Option<usize> {
+        self.source_anchor.take()
+    }
+
+    /// Scroll so that source line `line` (1-indexed) starts `rows` rows
+    /// above the top of the viewport on the next render.
+    ///
+    /// Keeps the same text in view after the content above it grew or
+    /// shrank.
+    pub fn anchor_top_to_source_line(&mut self, line: usize, rows: usize) {
+        self.top_anchor = Some((line, rows));
+    }
+
+    /// Take the anchor set by
+    /// [`anchor_top_to_source_line`](Self::anchor_top_to_source_line).
+    pub(crate) fn take_top_anchor(&mut self) -> Option<(usize, usize)> {
+        self.top_anchor.take()
+    }
 }
 
 /// Update total lines method for ScrollState.
@@ -0,0 +1,235 @@
+//! Search state for finding text in the rendered document.
+
+use ratatui::text::Line;
+
+/// Where a search match was found in the rendered lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchMatch {
+    /// Rendered line the match is on (0-indexed).
+    pub row: usize,
+    /// Column of the first matched character.
+    pub start: usize,
+    /// Column just past the last matched character.
+    pub end: usize,
+}
+
+/// Search state for markdown widget.
+///
+/// Matches are found in the rendered lines rather than the source, so
+/// they follow wrapping and skip the contents of collapsed sections.
+/// A match never spans two rendered lines.
+#[derive(Debug, Clone, Default)]
+pub struct SearchState {
+    /// The text being searched for. Empty means no search.
+    query: String,
+    /// Whether matching respects case.
+    case_sensitive: bool,
+    /// Whether a query with an uppercase letter respects case.
+    smart_case: bool,
+    /// Whether the query is being typed.
+    editing: bool,
+    /// Matches in document order.
+    matches: Vec<SearchMatch>,
+    /// Index into `matches` of the current match.
+    current: Option<usize>,
+}
+
+impl SearchState {
+    /// Create a new search state with no query.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The text being searched for.
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Whether a search is in progress.
+    pub fn is_active(&self) -> bool {
+        !self.query.is_empty()
+    }
+
+    /// Whether matching respects case. Searches ignore case by default.
+    pub fn case_sensitive(&self) -> bool {
+        self.case_sensitive
+    }
+
+    /// Whether a query containing an uppercase letter respects case even
+    /// when the search otherwise ignores it. Off by default.
+    pub fn smart_case(&self) -> bool {
+        self.smart_case
+    }
+
+    /// Whether the query is being typed.
+    pub fn is_editing(&self) -> bool {
+        self.editing
+    }
+
+    /// All matches, in document order.
+    pub fn matches(&self) -> &[SearchMatch] {
+        &self.matches
+    }
+
+    /// Index of the current match (0-indexed).
+    pub fn current_index(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// The current match.
+    pub fn current_match(&self) -> Option<SearchMatch> {
+        self.current
+            .and_then(|index| self.matches.get(index).copied())
+    }
+
+    /// Set the query and search `lines` for it.
+    ///
+    /// The first match becomes current.
+    pub(crate) fn set_query(&mut self, query: &str, lines: &[Line<'_>]) {
+        self.query = query.to_string();
+        self.current = None;
+        self.find_matches(lines);
+    }
+
+    /// Set whether matching respects case and search `lines` again.
+    pub(crate) fn set_case_sensitive(&mut self, case_sensitive: bool, lines: &[Line<'_>]) {
+        self.case_sensitive = case_sensitive;
+        self.current = None;
+        self.find_matches(lines);
+    }
+
+    /// Set whether a query with an uppercase letter respects case, and
+    /// search `lines` again.
+    pub(crate) fn set_smart_case(&mut self, smart_case: bool, lines: &[Line<'_>]) {
+        self.smart_case = smart_case;
+        self.current = None;
+        self.find_matches(lines);
+    }
+
+    /// Start or stop typing the query.
+    pub(crate) fn set_editing(&mut self, editing: bool) {
+        self.editing = editing;
+    }
+
+    /// Clear the query and matches.
+    pub fn clear(&mut self) {
+        self.editing = false;
+        self.query.clear();
+        self.matches.clear();
+        self.current = None;
+    }
+
+    /// Search `lines` for the query again, e.g. after they were re-rendered.
+    ///
+    /// The current match keeps its index where there are still enough
+    /// matches; otherwise the last match becomes current.
+    pub(crate) fn find_matches(&mut self, lines: &[Line<'_>]) {
+        self.matches.clear();
+        if self.query.is_empty() {
+            self.current = None;
+            return;
+        }
+
+        let case_sensitive =
+            self.case_sensitive || (self.smart_case && self.query.chars().any(char::is_uppercase));
+        let fold = |c: char| {
+            if case_sensitive {
+                c
+            } else {
+                c.to_lowercase().next().unwrap_or(c)
+            }
+        };
+        let query: Vec<char> = self.query.chars().map(fold).collect();
+        for (row, line) in lines.iter().enumerate() {
+            let text: Vec<char> = line
+                .spans
+                .iter()
+                .flat_map(|span| span.content.chars())
+                .map(fold)
+                .collect();
+            let mut col = 0;
+            while col + query.len() <= text.len() {
+                if text[col..col + query.len()] == query[..] {
+                    self.matches.push(SearchMatch {
+                        row,
+                        start: col,
+                        end: col + query.len(),
+                    });
+                    col += query.len();
+                } else {
+                    col += 1;
+                }
+            }
+        }
+
+        self.current = match (self.current, self.matches.len()) {
+            (_, 0) => None,
+            (Some(index), len) => Some(index.min(len - 1)),
+            (None, _) => Some(0),
+        };
+    }
+
+    /// Make the next match current, wrapping to the first.
+    pub(crate) fn next_match(&mut self) -> Option<SearchMatch> {
+        let len = self.matches.len();
+        if len == 0 {
+            return None;
+        }
+        self.current = Some(self.current.map_or(0, |index| (index + 1) % len));
+        self.current_match()
+    }
+
+    /// Make the previous match current, wrapping to the last.
+    pub(crate) fn prev_match(&mut self) -> Option<SearchMatch> {
+        let len = self.matches.len();
+        if len == 0 {
+            return None;
+        }
+        self.current = Some(
+            self.current
+                .map_or(len - 1, |index| (index + len - 1) % len),
+        );
+        self.current_match()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_ignore_case_by_default_and_cycle() {
+        let lines = vec![
+            Line::from("Alpha beta ALPHA"),
+            Line::from("no match"),
+            Line::from("alphabet"),
+        ];
+        let mut search = SearchState::new();
+        search.set_query("alpha", &lines);
+
+        let positions: Vec<(usize, usize)> =
+            search.matches().iter().map(|m| (m.row, m.start)).collect();
+        assert_eq!(positions, [(0, 0), (0, 11), (2, 0)]);
+        assert_eq!(search.current_index(), Some(0));
+
+        assert_eq!(search.next_match().map(|m| m.start), Some(11));
+        search.next_match();
+        assert_eq!(search.next_match().map(|m| m.row), Some(0));
+        assert_eq!(search.prev_match().map(|m| m.row), Some(2));
+
+        search.set_case_sensitive(true, &lines);
+        assert_eq!(search.matches().len(), 1);
+        assert_eq!(search.current_index(), Some(0));
+
+        search.set_case_sensitive(false, &lines);
+        search.set_smart_case(true, &lines);
+        assert_eq!(search.matches().len(), 3);
+        search.set_query("ALPHA", &lines);
+        assert_eq!(search.matches().len(), 1);
+        assert_eq!(search.current_match().map(|m| m.start), Some(11));
+
+        search.clear();
+        assert!(!search.is_active());
+        assert_eq!(search.next_match(), None);
+    }
+}
@@ -1,5 +1,9 @@
 //! Selection state for markdown widget text selection and copy.
 
+use crate::widgets::markdown_preview::widgets::markdown_widget::extensions::decorations::DecorationRange;
+use std::cell::Cell;
+
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::stats::SelectionStats;
 use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::types::SelectionPos;
 
 /// Selection state for markdown widget.
@@ -17,8 +21,17 @@ pub struct SelectionState {
     pub frozen_lines: Option<Vec<ratatui::text::Line<'static>>>,
     /// Width when lines were frozen.
     pub frozen_width: usize,
+    /// Custom-rendered fences in the frozen lines:
+    /// (start_row, row_count, raw content). Selecting any of their rows
+    /// copies the raw fence content instead of the rendered text.
+    pub frozen_raw_blocks: Vec<(usize, usize, String)>,
+    /// Decorated text in the frozen lines. Selecting any of it copies the
+    /// source text it stands for instead of the drawn text.
+    pub frozen_decorations: Vec<DecorationRange>,
     /// Last copied text (for showing toast notification).
     pub last_copied_text: Option<String>,
+    /// Statistics for the last selection bounds they were counted for.
+    stats: Cell<Option<(SelectionPos, SelectionPos, SelectionStats)>>,
 }
 
 /// Constructor for SelectionState.
@@ -48,6 +61,7 @@ impl SelectionState {
         self.cursor = Some(SelectionPos::new(x, y));
         self.frozen_lines = Some(lines);
         self.frozen_width = width;
+        self.stats.set(None);
     }
 
     /// Check if selection mode is active.
@@ -66,6 +80,9 @@ impl SelectionState {
         self.cursor = None;
         self.frozen_lines = None;
         self.frozen_width = 0;
+        self.frozen_raw_blocks.clear();
+        self.frozen_decorations.clear();
+        self.stats.set(None);
     }
 }
 
@@ -83,6 +100,8 @@ impl SelectionState {
 
         Some(extract_text_from_lines(
             lines,
+            &self.frozen_raw_blocks,
+            &self.frozen_decorations,
             start.x as usize,
             start.y as usize,
             end.x as usize,
@@ -94,6 +113,8 @@ impl SelectionState {
 /// Extract text from rendered lines within the selection bounds.
 fn extract_text_from_lines(
     lines: &[Line<'static>],
+    raw_blocks: &[(usize, usize, String)],
+    decorations: &[DecorationRange],
     start_x: usize,
     start_y: usize,
     end_x: usize,
@@ -106,6 +127,22 @@ fn extract_text_from_lines(
             continue;
         }
 
+        // Rows of a custom-rendered fence yield its raw content once
+        if let Some((block_start, block_len, raw)) =
+            raw_blocks.iter().find(|(block_start, block_len, _)| {
+                (*block_start..block_start + block_len).contains(&row_idx)
+            })
+        {
+            let block_end = block_start + block_len - 1;
+            if row_idx == (*block_start).max(start_y) {
+                result.push_str(raw);
+            }
+            if row_idx == block_end.min(end_y) && row_idx < end_y {
+                result.push('\n');
+            }
+            continue;
+        }
+
         // Get the full text content of this line
         let line_text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
 
@@ -123,7 +160,7 @@ fn extract_text_from_lines(
         let actual_end = col_end.min(chars.len());
 
         if actual_start < actual_end {
-            let selected: String = chars[actual_start..actual_end].iter().collect();
+            let selected = select_columns(&chars, actual_start, actual_end, row_idx, decorations);
             result.push_str(selected.trim_end());
         }
 
@@ -136,6 +173,35 @@ fn extract_text_from_lines(
     result
 }
 
+/// Collect the characters in `start..end` of a row, replacing decorated
+/// text with the source text it stands for.
+fn select_columns(
+    chars: &[char],
+    start: usize,
+    end: usize,
+    row_idx: usize,
+    decorations: &[DecorationRange],
+) -> String {
+    let mut selected = String::new();
+    let mut col = start;
+    while col < end {
+        match decorations
+            .iter()
+            .find(|range| range.row == row_idx && (range.start..range.end).contains(&col))
+        {
+            Some(range) => {
+                selected.push_str(&range.original);
+                col = range.end;
+            }
+            None => {
+                selected.push(chars[col]);
+                col += 1;
+            }
+        }
+    }
+    selected
+}
+
 /// Get the current selection bounds.
 
 impl SelectionState {
@@ -218,6 +284,29 @@ pub fn is_pos_in_selection(x: i32, y: i32, start: &SelectionPos, end: &Selection
     }
 }
 
+impl SelectionState {
+    /// Word, character and line counts for the selected text.
+    ///
+    /// The counts are kept until the selection bounds change, so this is
+    /// cheap to call every frame.
+    ///
+    /// # Returns
+    ///
+    /// The statistics, or `None` if no selection.
+    pub fn stats(&self) -> Option<SelectionStats> {
+        let (start, end) = self.get_selection()?;
+        if let Some((cached_start, cached_end, stats)) = self.stats.get() {
+            if cached_start == start && cached_end == end {
+                return Some(stats);
+            }
+        }
+
+        let stats = SelectionStats::from_text(&self.get_selected_text()?);
+        self.stats.set(Some((start, end, stats)));
+        Some(stats)
+    }
+}
+
 /// Update cursor position during selection.
 
 impl SelectionState {
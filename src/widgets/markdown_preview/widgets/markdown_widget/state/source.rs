@@ -5,6 +5,7 @@
 use std::path::PathBuf;
 
 use crate::services::file_watcher::FileWatcher;
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::blocks::BlockDiff;
 use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::source::MarkdownSource;
 
 /// Source state for markdown content management.
@@ -20,6 +21,8 @@ pub struct SourceState {
     watcher: Option<FileWatcher>,
     /// Cached watched path (for re-initializing watchers).
     watch_path: Option<PathBuf>,
+    /// Blocks that changed in the last reload, until taken.
+    block_diff: Option<BlockDiff>,
 }
 
 impl Clone for SourceState {
@@ -29,6 +32,7 @@ impl Clone for SourceState {
             line_count: self.line_count,
             watcher: None,
             watch_path: self.watch_path.clone(),
+            block_diff: self.block_diff.clone(),
         }
     }
 }
@@ -43,6 +47,7 @@ impl SourceState {
             line_count: 0,
             watcher: None,
             watch_path: None,
+            block_diff: None,
         }
     }
 }
@@ -111,13 +116,15 @@ impl SourceState {
     /// Reload the source content from disk (for file-based sources).
     ///
     /// This re-reads the file. The caller should check the return value
-    /// and invalidate caches if content changed.
+    /// and update caches if content changed, using
+    /// [`take_block_diff`](Self::take_block_diff) to find out which blocks
+    /// changed.
     ///
     /// For string-based sources, this is a no-op.
     ///
     /// # Returns
     ///
-    /// * `Ok(true)` - Content changed, caller should invalidate caches.
+    /// * `Ok(true)` - Content changed, caller should update caches.
     /// * `Ok(false)` - Content unchanged or source is string-based.
     ///
     /// # Errors
@@ -125,15 +132,24 @@ impl SourceState {
     /// Returns an error if the file cannot be read.
     pub fn reload_source(&mut self) -> std::io::Result<bool> {
         if let Some(ref mut source) = self.source {
+            let old = source.content().to_string();
             let changed = source.reload()?;
             if changed {
                 self.line_count = source.content().lines().count();
+                self.block_diff = Some(BlockDiff::new(&old, source.content()));
             }
             Ok(changed)
         } else {
             Ok(false)
         }
     }
+
+    /// Take the blocks that changed in the last reload.
+    ///
+    /// Returns `None` if the content was not reloaded since the last call.
+    pub fn take_block_diff(&mut self) -> Option<BlockDiff> {
+        self.block_diff.take()
+    }
 }
 
 /// Set source file method for SourceState.
@@ -159,6 +175,7 @@ impl SourceState {
         self.line_count = source.content().lines().count();
         self.source = Some(source);
         self.watch_path = Some(path.as_ref().to_path_buf());
+        self.block_diff = None;
 
         let mut watcher = FileWatcher::for_file()
             .map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err))?;
@@ -186,6 +203,7 @@ impl SourceState {
         self.source = Some(MarkdownSource::from_string(content_str));
         self.watcher = None;
         self.watch_path = None;
+        self.block_diff = None;
     }
 }
 
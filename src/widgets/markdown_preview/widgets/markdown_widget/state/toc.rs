@@ -1,10 +1,16 @@
 //! Table of Contents state for markdown widget.
 //!
-//! Single source of truth for TOC state including scroll offset, hover state, and entries.
+//! Single source of truth for TOC state including scroll offset, hover state,
+//! keyboard focus, collapsed subtrees, the resized width and entries.
+
+use std::collections::HashSet;
+
+use crossterm::event::KeyCode;
 
 /// State for the Table of Contents sidebar.
 ///
-/// Manages scroll position, hover state, and TOC entries.
+/// Manages scroll position, hover state, keyboard focus, collapsed subtrees,
+/// the width the TOC was dragged to and TOC entries.
 #[derive(Debug, Clone, Default)]
 pub struct TocState {
     /// Current scroll offset within the TOC.
@@ -15,6 +21,31 @@ pub struct TocState {
     pub hovered: bool,
     /// List of TOC entries extracted from the document.
     pub entries: Vec<TocEntry>,
+    /// Whether keyboard focus is in the TOC.
+    pub focused: bool,
+    /// Index of the entry selected in focus mode.
+    pub selected_entry: usize,
+    /// Keys of the headings whose subtrees are collapsed, see
+    /// [`entry_key`](Self::entry_key).
+    pub collapsed: HashSet<String>,
+    /// Width the TOC divider was dragged to, `None` for the default width.
+    /// Kept apart from the entries so it outlives content reloads.
+    pub width: Option<u16>,
+    /// Whether the TOC divider is being dragged.
+    pub resizing: bool,
+}
+
+/// What a key press in TOC focus mode asks of the document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TocFocusAction {
+    /// The key is not a TOC key.
+    Ignored,
+    /// The selection or a subtree changed.
+    Handled,
+    /// Scroll the document to this source line (1-indexed).
+    Jump(usize),
+    /// Focus went back to the document.
+    Exit,
 }
 
 /// Constructor for TocState.
@@ -223,3 +254,255 @@ impl TocState {
         self.scroll_offset = self.entries.len().saturating_sub(1);
     }
 }
+
+/// Collapsible subtree methods for TocState.
+impl TocState {
+    /// The key a heading's collapse state is stored under: its text with
+    /// the text of the headings it is nested in, so it survives edits
+    /// elsewhere in the document.
+    pub fn entry_key(&self, index: usize) -> Option<String> {
+        self.entry_keys().into_iter().nth(index)
+    }
+
+    /// Whether the entry has nested headings after it.
+    pub fn has_children(&self, index: usize) -> bool {
+        match (self.entries.get(index), self.entries.get(index + 1)) {
+            (Some(entry), Some(next)) => next.level > entry.level,
+            _ => false,
+        }
+    }
+
+    /// Whether the entry's nested headings are hidden.
+    pub fn is_collapsed(&self, index: usize) -> bool {
+        self.has_children(index)
+            && self
+                .entry_key(index)
+                .is_some_and(|key| self.collapsed.contains(&key))
+    }
+
+    /// Collapses or expands the entry's nested headings. Returns whether
+    /// anything changed.
+    pub fn set_collapsed(&mut self, index: usize, collapsed: bool) -> bool {
+        if !self.has_children(index) {
+            return false;
+        }
+        let Some(key) = self.entry_key(index) else {
+            return false;
+        };
+        if collapsed {
+            self.collapsed.insert(key)
+        } else {
+            self.collapsed.remove(&key)
+        }
+    }
+
+    /// Indices of the entries not hidden inside a collapsed heading, in
+    /// order.
+    pub fn visible_entries(&self) -> Vec<usize> {
+        let keys = self.entry_keys();
+        let mut visible = Vec::new();
+        let mut hidden_below: Option<u8> = None;
+        for (index, entry) in self.entries.iter().enumerate() {
+            if hidden_below.is_some_and(|level| entry.level > level) {
+                continue;
+            }
+            hidden_below = None;
+            visible.push(index);
+            if self.has_children(index) && self.collapsed.contains(&keys[index]) {
+                hidden_below = Some(entry.level);
+            }
+        }
+        visible
+    }
+
+    /// Keys of all entries, see [`entry_key`](Self::entry_key).
+    fn entry_keys(&self) -> Vec<String> {
+        let mut path: Vec<(u8, &str)> = Vec::new();
+        self.entries
+            .iter()
+            .map(|entry| {
+                while path.last().is_some_and(|(level, _)| *level >= entry.level) {
+                    path.pop();
+                }
+                path.push((entry.level, &entry.text));
+                path.iter()
+                    .map(|(_, text)| *text)
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .collect()
+    }
+
+    /// The closest entry before `index` with a lower level.
+    fn parent_of(&self, index: usize) -> Option<usize> {
+        let level = self.entries.get(index)?.level;
+        self.entries[..index]
+            .iter()
+            .rposition(|entry| entry.level < level)
+    }
+}
+
+/// Keyboard focus methods for TocState.
+impl TocState {
+    /// Check if keyboard focus is in the TOC.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Moves keyboard focus into the TOC, selecting the hovered entry if
+    /// there is one.
+    pub fn focus(&mut self) {
+        self.focused = true;
+        if let Some(hovered) = self.hovered_entry {
+            self.selected_entry = hovered;
+        }
+        self.reveal_selection();
+    }
+
+    /// Index of the selected entry, if there are entries.
+    pub fn selected_entry(&self) -> Option<usize> {
+        (!self.entries.is_empty()).then(|| self.selected_entry.min(self.entries.len() - 1))
+    }
+
+    /// Handles a key while the TOC has focus: `j`/`k` move between
+    /// entries, `h` collapses a subtree or moves to its parent, `l`
+    /// expands it, Enter jumps to the entry and Esc leaves focus mode.
+    pub fn handle_focus_key(&mut self, key: KeyCode) -> TocFocusAction {
+        if !self.focused {
+            return TocFocusAction::Ignored;
+        }
+        let Some(selected) = self.selected_entry() else {
+            return match key {
+                KeyCode::Esc => {
+                    self.focused = false;
+                    TocFocusAction::Exit
+                }
+                _ => TocFocusAction::Ignored,
+            };
+        };
+
+        match key {
+            KeyCode::Char('j') | KeyCode::Down => self.move_selection(1),
+            KeyCode::Char('k') | KeyCode::Up => self.move_selection(-1),
+            KeyCode::Char('h') | KeyCode::Left => {
+                if self.has_children(selected) && !self.is_collapsed(selected) {
+                    self.set_collapsed(selected, true);
+                } else if let Some(parent) = self.parent_of(selected) {
+                    self.selected_entry = parent;
+                }
+                self.reveal_selection();
+            }
+            KeyCode::Char('l') | KeyCode::Right => {
+                self.set_collapsed(selected, false);
+            }
+            KeyCode::Enter => return TocFocusAction::Jump(self.entries[selected].line_number),
+            KeyCode::Esc => {
+                self.focused = false;
+                return TocFocusAction::Exit;
+            }
+            _ => return TocFocusAction::Ignored,
+        }
+        TocFocusAction::Handled
+    }
+
+    /// Moves the selection `delta` visible entries, clamping at the ends.
+    fn move_selection(&mut self, delta: isize) {
+        self.reveal_selection();
+        let visible = self.visible_entries();
+        let row = visible
+            .iter()
+            .position(|&index| index == self.selected_entry)
+            .unwrap_or(0);
+        let row = row.saturating_add_signed(delta).min(visible.len() - 1);
+        self.selected_entry = visible[row];
+        self.scroll_offset = self.scroll_offset.min(row);
+    }
+
+    /// Moves a selection hidden in a collapsed subtree to the visible
+    /// heading that contains it.
+    fn reveal_selection(&mut self) {
+        let Some(mut selected) = self.selected_entry() else {
+            return;
+        };
+        let visible = self.visible_entries();
+        while !visible.contains(&selected) {
+            match self.parent_of(selected) {
+                Some(parent) => selected = parent,
+                None => break,
+            }
+        }
+        self.selected_entry = selected;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOC: &str = "# Guide\n## Setup\n### Install\n### Configure\n## Usage\n### Install\n";
+
+    #[test]
+    fn test_collapse_is_keyed_on_heading_text() {
+        let mut state = TocState::from_content(DOC);
+        assert_eq!(state.entry_key(5).as_deref(), Some("Guide\nUsage\nInstall"));
+        assert!(state.set_collapsed(1, true));
+        assert!(
+            !state.set_collapsed(2, true),
+            "leaf headings do not collapse"
+        );
+        assert_eq!(state.visible_entries(), [0, 1, 4, 5]);
+
+        // A reload that moves the section keeps it collapsed.
+        state.update_from_content(
+            "# Guide\nIntro\n## Usage\n### Install\n## Setup\n### Install\n### Configure\n",
+        );
+        assert!(state.is_collapsed(3));
+        assert_eq!(state.visible_entries(), [0, 1, 2, 3]);
+
+        // Renaming the heading drops its collapse state.
+        state.update_from_content("# Guide\n## Set up\n### Install\n");
+        assert_eq!(state.visible_entries(), [0, 1, 2]);
+    }
+
+    #[test]
+    fn test_focus_keys_move_collapse_and_jump() {
+        let mut state = TocState::from_content(DOC);
+        assert_eq!(
+            state.handle_focus_key(KeyCode::Char('j')),
+            TocFocusAction::Ignored
+        );
+
+        state.hovered_entry = Some(2);
+        state.focus();
+        assert_eq!(state.selected_entry(), Some(2));
+
+        // h on a leaf moves to its parent, then collapses it.
+        assert_eq!(
+            state.handle_focus_key(KeyCode::Char('h')),
+            TocFocusAction::Handled
+        );
+        assert_eq!(state.selected_entry(), Some(1));
+        state.handle_focus_key(KeyCode::Char('h'));
+        assert!(state.is_collapsed(1));
+
+        state.handle_focus_key(KeyCode::Char('j'));
+        assert_eq!(
+            state.selected_entry(),
+            Some(4),
+            "skips the collapsed subtree"
+        );
+        state.handle_focus_key(KeyCode::Char('k'));
+        state.handle_focus_key(KeyCode::Char('l'));
+        assert!(!state.is_collapsed(1));
+        state.handle_focus_key(KeyCode::Char('j'));
+        assert_eq!(state.selected_entry(), Some(2));
+
+        assert_eq!(
+            state.handle_focus_key(KeyCode::Enter),
+            TocFocusAction::Jump(3)
+        );
+        assert!(state.is_focused());
+        assert_eq!(state.handle_focus_key(KeyCode::Esc), TocFocusAction::Exit);
+        assert!(!state.is_focused());
+    }
+}
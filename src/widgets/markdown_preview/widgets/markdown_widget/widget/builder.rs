@@ -5,12 +5,13 @@ use crate::widgets::markdown_preview::widgets::markdown_widget::extensions::toc:
 use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::types::GitStats;
 use crate::widgets::markdown_preview::widgets::markdown_widget::state::{
     CacheState, CollapseState, DisplaySettings, DoubleClickState, ExpandableState, GitStatsState,
-    MarkdownState, ScrollState, SelectionState, SourceState, TocState, VimState,
+    MarkdownState, ScrollState, SearchState, SelectionState, SourceState, TocState, VimState,
 };
 use crate::widgets::markdown_preview::widgets::markdown_widget::widget::{
     MarkdownWidget, MarkdownWidgetMode, FRONTMATTER_SECTION_ID,
 };
 use ratatui::layout::Rect;
+use std::collections::{HashMap, HashSet};
 
 impl<'a> MarkdownWidget<'a> {
     pub fn from_state(state: &'a MarkdownState) -> Self {
@@ -24,6 +25,10 @@ impl<'a> MarkdownWidget<'a> {
 
         let mode = if state.filter_mode {
             MarkdownWidgetMode::Filter
+        } else if state.search.is_editing() {
+            MarkdownWidgetMode::Search
+        } else if state.toc_focused {
+            MarkdownWidgetMode::Toc
         } else {
             MarkdownWidgetMode::Normal
         };
@@ -40,10 +45,12 @@ impl<'a> MarkdownWidget<'a> {
             vim: state.vim.clone(),
             selection: state.selection.clone(),
             double_click: state.double_click.clone(),
+            search: state.search.clone(),
             toc_state: None,
             is_resizing: false,
             mode,
             show_statusline: true,
+            show_document_stats: false,
             show_scrollbar: false,
             scrollbar_config: ScrollbarConfig::default(),
             selection_active: state.selection_active,
@@ -53,6 +60,12 @@ impl<'a> MarkdownWidget<'a> {
             toc_hovered: state.toc_hovered,
             toc_hovered_entry: state.toc_hovered_entry,
             toc_scroll_offset: state.toc_scroll_offset,
+            toc_focused: state.toc_focused,
+            toc_selected_entry: state.toc_selected_entry,
+            toc_collapsed: state.toc_collapsed.clone(),
+            toc_width: state.toc_width,
+            toc_resizing: state.toc_resizing,
+            toc_divider_clicks: state.toc_divider_clicks.clone(),
             rendered_lines,
             app_theme: None,
             last_double_click: None,
@@ -63,6 +76,8 @@ impl<'a> MarkdownWidget<'a> {
             pane: None,
             pane_title: None,
             pane_color: None,
+            fence_renderers: HashMap::new(),
+            decorators: Vec::new(),
             inner_area: None,
         }
     }
@@ -93,10 +108,12 @@ impl<'a> MarkdownWidget<'a> {
             vim,
             selection,
             double_click,
+            search: SearchState::default(),
             toc_state: None,
             is_resizing: false,
             mode: MarkdownWidgetMode::Normal,
             show_statusline: true,
+            show_document_stats: false,
             show_scrollbar: false,
             scrollbar_config: ScrollbarConfig::default(),
             selection_active: false,
@@ -106,6 +123,12 @@ impl<'a> MarkdownWidget<'a> {
             toc_hovered: false,
             toc_hovered_entry: None,
             toc_scroll_offset: 0,
+            toc_focused: false,
+            toc_selected_entry: 0,
+            toc_collapsed: HashSet::new(),
+            toc_width: None,
+            toc_resizing: false,
+            toc_divider_clicks: DoubleClickState::default(),
             rendered_lines: Vec::new(),
             app_theme: None,
             last_double_click: None,
@@ -116,6 +139,8 @@ impl<'a> MarkdownWidget<'a> {
             pane: None,
             pane_title: None,
             pane_color: None,
+            fence_renderers: HashMap::new(),
+            decorators: Vec::new(),
             inner_area: None,
         }
     }
@@ -179,6 +204,9 @@ impl<'a> MarkdownWidget<'a> {
     }
 
     pub fn with_toc_state(mut self, toc_state: TocState) -> Self {
+        if toc_state.width.is_some() {
+            self.toc_width = toc_state.width;
+        }
         self.toc_state = Some(toc_state);
         self
     }
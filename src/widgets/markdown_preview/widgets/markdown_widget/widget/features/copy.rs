@@ -0,0 +1,99 @@
+//! Copying the selection, a link to the current section or the code block
+//! under the cursor.
+
+use crate::services::clipboard::{ClipboardSource, CopyPayload, CopyProvider};
+use crate::widgets::markdown_preview::widgets::markdown_widget::widget::features::fence::fence_at;
+use crate::widgets::markdown_preview::widgets::markdown_widget::widget::MarkdownWidget;
+
+impl<'a> MarkdownWidget<'a> {
+    /// Link to the heading at or above the cursor: the source path, if
+    /// any, followed by the heading's `#anchor`.
+    pub fn heading_link_at_cursor(&self) -> Option<String> {
+        let line = self.current_source_line()?;
+        let (anchor, _) = self
+            .heading_anchors()
+            .into_iter()
+            .take_while(|(_, heading_line)| *heading_line <= line)
+            .last()?;
+        Some(match self.source.source_path() {
+            Some(path) => format!("{}#{}", path.display(), anchor),
+            None => format!("#{}", anchor),
+        })
+    }
+
+    /// Raw content of the fenced code block under the cursor.
+    pub fn code_block_at_cursor(&self) -> Option<String> {
+        let line = self.current_source_line()?;
+        let elements = self.parse_elements();
+        (0..elements.len()).find_map(|idx| {
+            let fence = fence_at(&elements, idx)?;
+            // Fence elements do not all carry the line they were written on;
+            // the span of their lines, with the closing fence below it,
+            // covers the block.
+            let lines = elements[idx..fence.end].iter().map(|e| e.source_line);
+            let first = lines.clone().min()?;
+            let last = lines.max()? + 1;
+            (first..=last).contains(&line).then_some(fence.content)
+        })
+    }
+}
+
+/// Offers the selected text, a link to the current section and the code
+/// block under the cursor.
+impl CopyProvider for MarkdownWidget<'_> {
+    fn copy_payloads(&self) -> Vec<CopyPayload> {
+        let mut payloads = Vec::new();
+        if let Some(text) = self
+            .selection
+            .get_selected_text()
+            .filter(|text| !text.is_empty())
+        {
+            payloads.push(CopyPayload::new("Selection", text));
+        }
+        if let Some(link) = self.heading_link_at_cursor() {
+            payloads.push(CopyPayload::new("Heading link", link));
+        }
+        if let Some(code) = self.code_block_at_cursor() {
+            payloads.push(CopyPayload::new("Code block", code));
+        }
+        payloads
+    }
+
+    fn copy_source(&self) -> ClipboardSource {
+        ClipboardSource::Markdown
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::services::clipboard::CopyProvider;
+    use crate::widgets::markdown_preview::widgets::markdown_widget::state::MarkdownState;
+    use crate::widgets::markdown_preview::widgets::markdown_widget::widget::MarkdownWidget;
+
+    #[test]
+    fn cursor_offers_its_section_link_and_code_block() {
+        let mut state = MarkdownState::new();
+        state.source.set_source_string(
+            "# Guide\n\n## Setup\n\n```sh\ncargo build\ncargo test\n```\n\n## Setup\n\nAgain.\n",
+        );
+        let mut widget = MarkdownWidget::from_state(&state);
+        assert!(widget.copy_payloads().is_empty());
+
+        widget.scroll_to_source_line(6);
+        let payloads = widget.copy_payloads();
+        let labels: Vec<&str> = payloads.iter().map(|p| p.label.as_str()).collect();
+        assert_eq!(labels, ["Heading link", "Code block"]);
+        assert_eq!(payloads[0].text, "#setup");
+        assert_eq!(payloads[1].text, "cargo build\ncargo test");
+
+        // Repeated headings link to their own anchor.
+        widget.scroll_to_source_line(12);
+        assert_eq!(
+            widget.copy_payloads(),
+            [crate::services::clipboard::CopyPayload::new(
+                "Heading link",
+                "#setup-1"
+            )]
+        );
+    }
+}
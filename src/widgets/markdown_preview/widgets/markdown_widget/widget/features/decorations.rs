@@ -0,0 +1,206 @@
+//! Registered text decorators and their render-time application.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use ratatui::text::Line;
+
+use crate::widgets::markdown_preview::widgets::markdown_widget::extensions::decorations::{
+    decorate_segments, locate_decorations, DecorationRange, TextDecorator,
+};
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::{
+    render_with_options, ElementKind, MarkdownElement, RenderOptions, TextSegment,
+};
+use crate::widgets::markdown_preview::widgets::markdown_widget::widget::MarkdownWidget;
+
+impl<'a> MarkdownWidget<'a> {
+    /// Registers `decorator` to run over paragraphs, list items and
+    /// blockquotes at render time.
+    ///
+    /// A decorator with the same [`name`](TextDecorator::name) replaces the
+    /// one registered before it. Decorators run in registration order; where
+    /// their spans overlap, the span that starts first wins.
+    pub fn register_decorator(&mut self, decorator: impl TextDecorator + 'a) {
+        let name = decorator.name().to_string();
+        self.decorators.retain(|existing| existing.name() != name);
+        self.decorators.push(Box::new(decorator));
+        self.cache.render = None;
+    }
+
+    /// Builder form of [`register_decorator`](Self::register_decorator).
+    pub fn with_decorator(mut self, decorator: impl TextDecorator + 'a) -> Self {
+        self.register_decorator(decorator);
+        self
+    }
+
+    /// Enables or disables the registered decorator called `name`.
+    pub fn set_decoration_enabled(&mut self, name: &str, enabled: bool) {
+        if self.display.set_decoration_enabled(name, enabled) {
+            self.cache.render = None;
+        }
+    }
+
+    fn enabled_decorators(&self) -> Vec<&dyn TextDecorator> {
+        self.decorators
+            .iter()
+            .map(|decorator| decorator.as_ref() as &dyn TextDecorator)
+            .filter(|decorator| self.display.is_decoration_enabled(decorator.name()))
+            .collect()
+    }
+
+    /// Renders `element` with the enabled decorators applied, along with
+    /// where its decorated text ended up (rows offset by `first_row`).
+    ///
+    /// Returns `None` if the element is not prose or nothing was decorated.
+    pub(crate) fn render_decorated(
+        &self,
+        element: &MarkdownElement,
+        width: usize,
+        options: RenderOptions<'_>,
+        first_row: usize,
+    ) -> Option<(Vec<Line<'static>>, Vec<DecorationRange>)> {
+        let decorators = self.enabled_decorators();
+        if decorators.is_empty() {
+            return None;
+        }
+
+        let mut decorated = element.clone();
+        let segments = match &mut decorated.kind {
+            ElementKind::Paragraph(segments)
+            | ElementKind::ListItem {
+                content: segments, ..
+            }
+            | ElementKind::Blockquote {
+                content: segments, ..
+            } => segments,
+            _ => return None,
+        };
+        *segments = decorate_segments(segments, &decorators)?;
+        let segments: Vec<TextSegment> = segments.clone();
+
+        let lines = render_with_options(&decorated, width, options);
+        let ranges = locate_decorations(&lines, first_row, &segments);
+        Some((lines, ranges))
+    }
+
+    /// Hash of the enabled decorators, in the order they run.
+    pub(crate) fn decoration_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        for decorator in self.enabled_decorators() {
+            decorator.name().hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    /// Decorated text from the last render, for selection copy.
+    pub(crate) fn rendered_decorations(&self) -> Vec<DecorationRange> {
+        self.cache
+            .render
+            .as_ref()
+            .map(|cache| cache.decorations.clone())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::Widget;
+
+    use crate::widgets::markdown_preview::widgets::markdown_widget::extensions::decorations::{
+        RelativeTimeDecorator, ThousandsSeparatorDecorator,
+    };
+    use crate::widgets::markdown_preview::widgets::markdown_widget::state::MarkdownState;
+    use crate::widgets::markdown_preview::widgets::markdown_widget::widget::MarkdownWidget;
+
+    const CONTENT: &str = "Shipped 2024-11-02T10:31Z with 1500000 rows.\n\n```\n1500000\n```\n";
+
+    fn render(widget: &mut MarkdownWidget<'_>, width: u16) -> Vec<String> {
+        let area = Rect::new(0, 0, width, 20);
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+        widget
+            .rendered_lines
+            .iter()
+            .map(|line| line.to_string())
+            .collect()
+    }
+
+    fn widget(state: &MarkdownState) -> MarkdownWidget<'_> {
+        // 2024-11-23T10:31Z, three weeks after the timestamp
+        let now = UNIX_EPOCH + Duration::from_secs(1_730_543_460 + 21 * 86_400);
+        MarkdownWidget::from_state(state)
+            .with_has_pane(false)
+            .with_decorator(RelativeTimeDecorator::new().with_now(now))
+            .with_decorator(ThousandsSeparatorDecorator::new())
+    }
+
+    fn copy_all(widget: &mut MarkdownWidget<'_>, rows: usize) -> Option<String> {
+        widget
+            .selection
+            .enter(0, 0, widget.rendered_lines.clone(), 40);
+        widget.selection.frozen_decorations = widget.rendered_decorations();
+        widget.selection.update_cursor(200, rows as i32 - 1);
+        widget.selection.get_selected_text()
+    }
+
+    #[test]
+    fn decorates_prose_but_copies_source() {
+        let mut state = MarkdownState::new();
+        state.source.set_source_string(CONTENT);
+        let mut widget = widget(&state);
+
+        let lines = render(&mut widget, 80);
+        assert_eq!(
+            lines[0],
+            "Shipped 2024-11-02T10:31Z (3 weeks ago) with 1,500,000 rows."
+        );
+        assert!(lines.iter().any(|line| line.contains("1500000")));
+
+        assert_eq!(
+            copy_all(&mut widget, 1).as_deref(),
+            Some("Shipped 2024-11-02T10:31Z with 1500000 rows.")
+        );
+    }
+
+    #[test]
+    fn copies_source_when_decorations_wrap() {
+        let mut state = MarkdownState::new();
+        state
+            .source
+            .set_source_string("Shipped 2024-11-02T10:31Z with 1500000 rows.");
+        let mut widget = widget(&state);
+
+        let lines = render(&mut widget, 30);
+        assert!(lines.len() > 1);
+        assert_eq!(
+            copy_all(&mut widget, lines.len())
+                .map(|text| text.split_whitespace().collect::<Vec<_>>().join(" ")),
+            Some("Shipped 2024-11-02T10:31Z with 1500000 rows.".to_string())
+        );
+    }
+
+    #[test]
+    fn disabled_decorators_are_skipped_and_invalidate_the_cache() {
+        let mut state = MarkdownState::new();
+        state.source.set_source_string(CONTENT);
+        let mut widget = widget(&state);
+        render(&mut widget, 80);
+        let before = widget.cache.render.as_ref().unwrap().decoration_hash;
+
+        widget.set_decoration_enabled(RelativeTimeDecorator::NAME, false);
+        let lines = render(&mut widget, 80);
+
+        assert_ne!(
+            widget.cache.render.as_ref().unwrap().decoration_hash,
+            before
+        );
+        assert_eq!(lines[0], "Shipped 2024-11-02T10:31Z with 1,500,000 rows.");
+        assert!(!widget
+            .display
+            .is_decoration_enabled(RelativeTimeDecorator::NAME));
+    }
+}
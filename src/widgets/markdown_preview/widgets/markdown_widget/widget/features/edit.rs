@@ -0,0 +1,234 @@
+//! Editing the section under the cursor outside the widget.
+
+use std::ops::Range;
+
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::events::MarkdownEvent;
+use crate::widgets::markdown_preview::widgets::markdown_widget::widget::MarkdownWidget;
+
+impl<'a> MarkdownWidget<'a> {
+    /// Asks the application to edit the section under the cursor.
+    ///
+    /// The section runs from the nearest heading at or above the cursor to
+    /// the next heading of the same or a higher level. For a string source
+    /// the event carries the section's raw text.
+    pub fn request_edit(&self) -> MarkdownEvent {
+        let line = self.current_source_line().unwrap_or(1);
+        let lines = section_lines(&self.content, line - 1);
+        let path = self.source.source_path().map(|path| path.to_path_buf());
+        let text = if path.is_some() {
+            None
+        } else {
+            let mut text = self
+                .content
+                .lines()
+                .skip(lines.start)
+                .take(lines.len())
+                .collect::<Vec<_>>()
+                .join("\n");
+            text.push('\n');
+            Some(text)
+        };
+        MarkdownEvent::EditRequested {
+            path,
+            line,
+            lines,
+            text,
+        }
+    }
+
+    /// Replaces source lines `lines` (0-indexed, end exclusive) with
+    /// `new_text`, e.g. the edited text of an
+    /// [`EditRequested`](MarkdownEvent::EditRequested) event.
+    ///
+    /// The cursor stays on the line it was on, moving with it if lines were
+    /// added or removed above, even when the edit changes how the document
+    /// wraps. For a file source only the displayed content changes; edit
+    /// the file instead and call
+    /// [`reload_source_if_changed`](Self::reload_source_if_changed).
+    pub fn apply_section_edit(&mut self, lines: Range<usize>, new_text: &str) {
+        let old_lines: Vec<&str> = self.content.lines().collect();
+        let end = lines.end.min(old_lines.len());
+        let start = lines.start.min(end);
+        let new_lines: Vec<&str> = new_text.lines().collect();
+
+        let mut content = old_lines[..start].to_vec();
+        content.extend(&new_lines);
+        content.extend(&old_lines[end..]);
+        let mut content = content.join("\n");
+        if self.content.ends_with('\n') {
+            content.push('\n');
+        }
+
+        if let Some(line) = self.current_source_line() {
+            let index = line - 1;
+            let index = if index < start {
+                index
+            } else if index < end {
+                start + (index - start).min(new_lines.len().saturating_sub(1))
+            } else {
+                index + new_lines.len() - (end - start)
+            };
+            self.scroll.anchor_to_source_line(index + 1);
+        }
+
+        if !self.source.is_file_source() {
+            self.source.set_source_string(content.clone());
+        }
+        self.content = content;
+        self.cache.invalidate();
+    }
+
+    /// Moves the cursor to source line `line` (1-indexed) on the next
+    /// render.
+    pub fn scroll_to_source_line(&mut self, line: usize) {
+        self.scroll.anchor_to_source_line(line);
+    }
+
+    /// Source line (1-indexed) of the cursor, or `None` before the first
+    /// render.
+    pub(crate) fn current_source_line(&self) -> Option<usize> {
+        if let Some(line) = self.scroll.source_anchor {
+            return Some(line);
+        }
+        self.source_line_at_row(self.scroll.current_line.saturating_sub(1))
+    }
+
+    /// Source line (1-indexed) rendered on `row` (0-indexed).
+    pub(crate) fn source_line_at_row(&self, row: usize) -> Option<usize> {
+        self.cache.render.as_ref()?.source_line_at_row(row)
+    }
+
+    /// First rendered row (0-indexed) of source line `line` (1-indexed), or
+    /// of the nearest line above it that was rendered.
+    pub(crate) fn row_for_source_line(&self, line: usize) -> Option<usize> {
+        self.cache.render.as_ref()?.row_for_source_line(line)
+    }
+}
+
+/// Source lines (0-indexed, end exclusive) of the section containing line
+/// `line` (0-indexed) of `content`.
+///
+/// A section starts at an ATX heading and runs to the next heading of the
+/// same or a higher level. Lines before the first heading form their own
+/// section. Headings inside fences and frontmatter are ignored.
+pub(crate) fn section_lines(content: &str, line: usize) -> Range<usize> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut headings: Vec<(usize, usize)> = Vec::new();
+    let mut fence: Option<&str> = None;
+    let mut in_frontmatter = lines.first().is_some_and(|first| first.trim_end() == "---");
+
+    for (index, text) in lines.iter().enumerate() {
+        let trimmed = text.trim_start();
+        if in_frontmatter {
+            if index > 0 && matches!(trimmed.trim_end(), "---" | "...") {
+                in_frontmatter = false;
+            }
+            continue;
+        }
+        if let Some(marker) = fence {
+            if trimmed.starts_with(marker) {
+                fence = None;
+            }
+            continue;
+        }
+        if trimmed.starts_with("```") {
+            fence = Some("```");
+            continue;
+        }
+        if trimmed.starts_with("~~~") {
+            fence = Some("~~~");
+            continue;
+        }
+        let level = trimmed.chars().take_while(|&c| c == '#').count();
+        let rest = &trimmed[level..];
+        if (1..=6).contains(&level) && (rest.is_empty() || rest.starts_with(' ')) {
+            headings.push((index, level));
+        }
+    }
+
+    let line = line.min(lines.len().saturating_sub(1));
+    match headings.iter().rposition(|&(index, _)| index <= line) {
+        Some(position) => {
+            let (start, level) = headings[position];
+            let end = headings[position + 1..]
+                .iter()
+                .find(|&&(_, next_level)| next_level <= level)
+                .map_or(lines.len(), |&(index, _)| index);
+            start..end
+        }
+        None => 0..headings.first().map_or(lines.len(), |&(index, _)| index),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::Widget;
+
+    use super::*;
+    use crate::widgets::markdown_preview::widgets::markdown_widget::state::MarkdownState;
+
+    const DOC: &str =
+        "# Title\n\nIntro.\n\n## One\n\nFirst.\n\n```\n# not a heading\n```\n\n## Two\n\nSecond.\n";
+
+    #[test]
+    fn test_section_lines() {
+        assert_eq!(section_lines(DOC, 2), 0..15);
+        assert_eq!(section_lines(DOC, 6), 4..12);
+        assert_eq!(section_lines(DOC, 9), 4..12);
+        assert_eq!(section_lines(DOC, 14), 12..15);
+        assert_eq!(section_lines("---\n# x: 1\n---\nText\n# Head\n", 1), 0..4);
+        assert_eq!(section_lines("", 3), 0..0);
+    }
+
+    #[test]
+    fn test_edit_round_trip_keeps_cursor_on_line() {
+        let mut state = MarkdownState::new();
+        state.source.set_source_string(DOC);
+        let mut widget = MarkdownWidget::from_state(&state)
+            .with_has_pane(false)
+            .show_statusline(false)
+            .show_scrollbar(false);
+        let area = Rect::new(0, 0, 40, 30);
+        let mut buf = Buffer::empty(area);
+        (&mut widget).render(area, &mut buf);
+
+        widget.scroll_to_source_line(15);
+        (&mut widget).render(area, &mut buf);
+        assert_eq!(widget.current_source_line(), Some(15));
+
+        widget.scroll_to_source_line(7);
+        (&mut widget).render(area, &mut buf);
+        let event = widget.handle_key(KeyEvent::new(KeyCode::Char('e'), KeyModifiers::NONE));
+        let MarkdownEvent::EditRequested {
+            path,
+            line,
+            lines,
+            text,
+        } = event
+        else {
+            panic!("expected EditRequested, got {event:?}");
+        };
+        assert_eq!(path, None);
+        assert_eq!(line, 7);
+        assert_eq!(lines, 4..12);
+        assert!(text.unwrap().starts_with("## One\n\nFirst.\n"));
+
+        widget.scroll_to_source_line(15);
+        (&mut widget).render(area, &mut buf);
+        widget.apply_section_edit(
+            lines,
+            "## One\n\nFirst,\nwith\nmore\nlines.\n\n```\n# not a heading\n```\n\n",
+        );
+        (&mut widget).render(area, &mut buf);
+        assert_eq!(widget.current_source_line(), Some(18));
+        assert!(widget.to_plain_text().contains("more"));
+        assert_eq!(
+            widget.source.content().map(str::len),
+            Some(widget.content.len())
+        );
+        assert!(widget.content.ends_with("Second.\n"));
+    }
+}
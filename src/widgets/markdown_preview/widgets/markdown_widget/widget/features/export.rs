@@ -0,0 +1,263 @@
+//! Exporting the rendered document as plain or ANSI-colored text.
+
+use std::fmt::Write;
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+
+use crate::widgets::markdown_preview::widgets::markdown_widget::extensions::theme::SyntaxHighlighter;
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::blocks::parse_blocks;
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::{
+    render_with_options, RenderOptions,
+};
+use crate::widgets::markdown_preview::widgets::markdown_widget::widget::MarkdownWidget;
+
+impl<'a> MarkdownWidget<'a> {
+    /// The rendered document as plain text, one rendered line per line.
+    ///
+    /// The text is wrapped the way it was last rendered and leaves out the
+    /// contents of collapsed sections. Trailing whitespace is trimmed from
+    /// each line. Empty until the widget has been rendered.
+    pub fn to_plain_text(&self) -> String {
+        lines_to_plain(&self.rendered_lines)
+    }
+
+    /// The whole document rendered at `width` columns as plain text, e.g.
+    /// to write to a file.
+    ///
+    /// Like [`to_ansi_string`](Self::to_ansi_string) without the escape
+    /// sequences: tables, list indentation and wrapped code blocks keep
+    /// their layout. Trailing whitespace is trimmed from each line.
+    pub fn to_plain_string(&self, width: u16) -> String {
+        lines_to_plain(&self.export_lines(width))
+    }
+
+    /// The whole document rendered at `width` columns with its styles
+    /// written as SGR escape sequences, e.g. to pipe to a pager.
+    ///
+    /// Collapsed sections and fences are expanded, and the TOC, scrollbar,
+    /// statusline and line number gutter are left out; the widget does not
+    /// need to have been rendered. Styles are reset after every styled
+    /// span, so none carry over a line break.
+    pub fn to_ansi_string(&self, width: u16) -> String {
+        let mut out = String::new();
+        for line in self.export_lines(width) {
+            for span in &line.spans {
+                let style = line.style.patch(span.style);
+                let sgr = sgr_codes(style);
+                if sgr.is_empty() {
+                    out.push_str(&span.content);
+                } else {
+                    let _ = write!(out, "\x1b[{sgr}m{}\x1b[0m", span.content);
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Every element of the document rendered at `width` columns with the
+    /// widget's display settings, ignoring collapse state.
+    fn export_lines(&self, width: u16) -> Vec<Line<'static>> {
+        let previous = self
+            .cache
+            .parsed
+            .as_ref()
+            .map_or(&[][..], |parsed| parsed.blocks.as_slice());
+        let (_, elements) = parse_blocks(&self.content, previous, &SyntaxHighlighter::new());
+        let width = usize::from(width).max(1);
+        let options = RenderOptions {
+            show_line_numbers: self.display.show_line_numbers,
+            theme: self.display.code_block_theme,
+            app_theme: self.app_theme.as_ref(),
+            show_heading_collapse: false,
+        };
+
+        let mut lines = Vec::new();
+        let mut skip_until = 0;
+        for (idx, element) in elements.iter().enumerate() {
+            if idx < skip_until {
+                continue;
+            }
+            if let Some(fence) = self.custom_fence_at(&elements, idx) {
+                skip_until = fence.end;
+                lines.extend(self.render_custom_fence(&fence));
+                continue;
+            }
+            match self.render_decorated(element, width, options, 0) {
+                Some((rendered, _)) => lines.extend(rendered),
+                None => lines.extend(render_with_options(element, width, options)),
+            }
+        }
+        lines
+    }
+}
+
+/// `lines` as text without styles, trailing whitespace trimmed.
+fn lines_to_plain(lines: &[Line<'_>]) -> String {
+    let mut text = String::new();
+    for line in lines {
+        let content: String = line
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect();
+        text.push_str(content.trim_end());
+        text.push('\n');
+    }
+    text
+}
+
+/// The SGR parameters that select `style`, joined with `;`.
+fn sgr_codes(style: Style) -> String {
+    let mut codes: Vec<String> = [
+        (Modifier::BOLD, "1"),
+        (Modifier::DIM, "2"),
+        (Modifier::ITALIC, "3"),
+        (Modifier::UNDERLINED, "4"),
+        (Modifier::SLOW_BLINK, "5"),
+        (Modifier::RAPID_BLINK, "6"),
+        (Modifier::REVERSED, "7"),
+        (Modifier::HIDDEN, "8"),
+        (Modifier::CROSSED_OUT, "9"),
+    ]
+    .into_iter()
+    .filter(|(modifier, _)| style.add_modifier.contains(*modifier))
+    .map(|(_, code)| code.to_string())
+    .collect();
+
+    if let Some(fg) = style.fg {
+        codes.push(color_code(fg, false));
+    }
+    if let Some(bg) = style.bg {
+        codes.push(color_code(bg, true));
+    }
+    codes.join(";")
+}
+
+/// The SGR parameter for `color` as a foreground or background.
+fn color_code(color: Color, background: bool) -> String {
+    let base = if background { 40 } else { 30 };
+    match color {
+        Color::Reset => (base + 9).to_string(),
+        Color::Black => base.to_string(),
+        Color::Red => (base + 1).to_string(),
+        Color::Green => (base + 2).to_string(),
+        Color::Yellow => (base + 3).to_string(),
+        Color::Blue => (base + 4).to_string(),
+        Color::Magenta => (base + 5).to_string(),
+        Color::Cyan => (base + 6).to_string(),
+        Color::Gray => (base + 7).to_string(),
+        Color::DarkGray => (base + 60).to_string(),
+        Color::LightRed => (base + 61).to_string(),
+        Color::LightGreen => (base + 62).to_string(),
+        Color::LightYellow => (base + 63).to_string(),
+        Color::LightBlue => (base + 64).to_string(),
+        Color::LightMagenta => (base + 65).to_string(),
+        Color::LightCyan => (base + 66).to_string(),
+        Color::White => (base + 67).to_string(),
+        Color::Indexed(index) => format!("{};5;{index}", base + 8),
+        Color::Rgb(r, g, b) => format!("{};2;{r};{g};{b}", base + 8),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::Widget;
+
+    use super::*;
+    use crate::widgets::markdown_preview::widgets::markdown_widget::state::MarkdownState;
+
+    #[test]
+    fn test_export_follows_wrapping_and_collapse() {
+        let mut state = MarkdownState::new();
+        state.source.set_source_string(
+            "# Title\n\nA **bold** paragraph that wraps onto a second line.\n\n## Hidden\n\nSecret text.\n",
+        );
+        let area = Rect::new(0, 0, 30, 20);
+        {
+            let mut widget = MarkdownWidget::from_state(&state)
+                .with_has_pane(false)
+                .show_statusline(false)
+                .show_scrollbar(false);
+            assert!(widget.to_plain_text().is_empty());
+
+            let mut buf = Buffer::empty(area);
+            (&mut widget).render(area, &mut buf);
+
+            let text = widget.to_plain_text();
+            assert!(text.contains("Secret text."), "{text}");
+            assert!(text.lines().all(|line| line.chars().count() <= 30));
+            assert!(!text.contains("**"));
+            assert!(!text.contains('\x1b'));
+
+            let ansi = widget.to_ansi_string(30);
+            assert_eq!(ansi.lines().count(), text.lines().count());
+            assert!(ansi.contains("\x1b[1"), "{ansi:?}");
+            for line in ansi.lines() {
+                let last_reset = line.rfind("\x1b[0m").map_or(0, |at| at + 4);
+                assert!(!line[last_reset..].contains('\x1b'), "{line:?}");
+            }
+        }
+
+        state.collapse.collapse_section(2);
+        let mut widget = MarkdownWidget::from_state(&state)
+            .with_has_pane(false)
+            .show_statusline(false)
+            .show_scrollbar(false);
+        let mut buf = Buffer::empty(area);
+        (&mut widget).render(area, &mut buf);
+        let collapsed = widget.to_plain_text();
+        assert!(collapsed.contains("Hidden"), "{collapsed}");
+        assert!(!collapsed.contains("Secret text."), "{collapsed}");
+    }
+
+    #[test]
+    fn test_export_renders_whole_document_at_width() {
+        let mut state = MarkdownState::new();
+        state.source.set_source_string(
+            "# Guide\n\n## Code\n\n```rust\nfn main() { println!(\"hi\"); }\n```\n\n## List\n\n- first item\n  - nested item\n- second\n",
+        );
+        state.collapse.collapse_section(2);
+        let widget = MarkdownWidget::from_state(&state)
+            .with_has_pane(false)
+            .show_toc(true);
+
+        let ansi = widget.to_ansi_string(40);
+        assert!(ansi.contains("\x1b[1;38;2;"), "{ansi:?}");
+        assert!(ansi.contains("\x1b[33m● \x1b[0mfirst item"), "{ansi:?}");
+        // Highlighted tokens get their own colors.
+        let sgr_before = |token: &str| {
+            let end = ansi.find(&format!("m{token}\x1b[0m")).unwrap();
+            &ansi[ansi[..end].rfind("\x1b[").unwrap()..end]
+        };
+        assert!(sgr_before("fn").contains(";48;2;"));
+        assert_ne!(sgr_before("fn"), sgr_before("main"));
+
+        // Collapsed sections are expanded and the TOC is left out.
+        let plain = widget.to_plain_string(40);
+        assert!(!plain.contains('\x1b'));
+        assert!(plain.contains("fn main() { println!(\"hi\"); }"), "{plain}");
+        assert!(plain.contains("\n  ○ nested item\n"), "{plain}");
+        assert_eq!(plain.matches("List").count(), 1, "{plain}");
+        assert!(plain.lines().all(|line| line.chars().count() <= 40));
+        assert_eq!(plain.lines().count(), ansi.lines().count());
+    }
+
+    #[test]
+    fn test_sgr_codes() {
+        assert_eq!(sgr_codes(Style::new()), "");
+        assert_eq!(
+            sgr_codes(
+                Style::new()
+                    .fg(Color::Rgb(1, 2, 3))
+                    .bg(Color::Indexed(8))
+                    .add_modifier(Modifier::BOLD | Modifier::ITALIC)
+            ),
+            "1;3;38;2;1;2;3;48;5;8"
+        );
+        assert_eq!(sgr_codes(Style::new().fg(Color::LightRed)), "91");
+    }
+}
@@ -0,0 +1,260 @@
+//! Custom fence renderers and per-language code block overrides.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use ratatui::text::Line;
+
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::{
+    CodeBlockBorderKind, ElementKind, MarkdownElement,
+};
+use crate::widgets::markdown_preview::widgets::markdown_widget::widget::MarkdownWidget;
+
+/// Renders the raw content of a fenced code block into lines.
+pub type FenceRenderFn<'a> = Box<dyn Fn(&str) -> Vec<Line<'static>> + 'a>;
+
+/// A fenced code block gathered from its elements.
+pub(crate) struct Fence {
+    /// Source line of the opening fence (1-indexed).
+    pub(crate) source_line: usize,
+    /// Index one past the fence's bottom border element.
+    pub(crate) end: usize,
+    /// Fence language as written in the info string.
+    pub(crate) language: String,
+    /// Raw fence content, one source line per line.
+    pub(crate) content: String,
+    /// Number of content lines.
+    pub(crate) line_count: usize,
+    /// Whether any content line was syntax highlighted.
+    pub(crate) highlighted: bool,
+}
+
+/// Returns the fence starting at `elements[idx]`, if that element is a code
+/// block header.
+pub(crate) fn fence_at(elements: &[MarkdownElement], idx: usize) -> Option<Fence> {
+    let header = elements.get(idx)?;
+    let ElementKind::CodeBlockHeader { language, .. } = &header.kind else {
+        return None;
+    };
+
+    let mut lines = Vec::new();
+    let mut highlighted = false;
+    let mut end = elements.len();
+    for (offset, element) in elements[idx + 1..].iter().enumerate() {
+        match &element.kind {
+            ElementKind::CodeBlockContent {
+                content,
+                highlighted: line_highlighted,
+                ..
+            } => {
+                lines.push(content.as_str());
+                highlighted |= line_highlighted.is_some();
+            }
+            ElementKind::CodeBlockBorder {
+                kind: CodeBlockBorderKind::Bottom,
+                ..
+            } => {
+                end = idx + 1 + offset + 1;
+                break;
+            }
+            _ => {
+                end = idx + 1 + offset;
+                break;
+            }
+        }
+    }
+
+    Some(Fence {
+        source_line: header.source_line,
+        end,
+        language: language.clone(),
+        line_count: lines.len(),
+        content: lines.join("\n"),
+        highlighted,
+    })
+}
+
+impl<'a> MarkdownWidget<'a> {
+    /// Registers `renderer` to render fences of `language` in place of the
+    /// built-in code block.
+    ///
+    /// The language is matched case-insensitively against the first token of
+    /// the fence info string. The renderer receives the raw fence content;
+    /// its lines take part in scrolling and layout like any other block, and
+    /// selecting them copies the raw content.
+    pub fn register_fence_renderer(
+        &mut self,
+        language: impl Into<String>,
+        renderer: FenceRenderFn<'a>,
+    ) {
+        self.fence_renderers
+            .insert(language.into().to_lowercase(), renderer);
+        self.cache.render = None;
+    }
+
+    /// Builder form of [`register_fence_renderer`](Self::register_fence_renderer).
+    pub fn with_fence_renderer(
+        mut self,
+        language: impl Into<String>,
+        renderer: FenceRenderFn<'a>,
+    ) -> Self {
+        self.register_fence_renderer(language, renderer);
+        self
+    }
+
+    /// Returns the custom fence starting at `elements[idx]`, if that element
+    /// is a code block header whose language has a registered renderer.
+    pub(crate) fn custom_fence_at(
+        &self,
+        elements: &[MarkdownElement],
+        idx: usize,
+    ) -> Option<Fence> {
+        fence_at(elements, idx).filter(|fence| {
+            self.fence_renderers
+                .contains_key(&fence.language.to_lowercase())
+        })
+    }
+
+    /// Renders a custom fence with its registered renderer.
+    pub(crate) fn render_custom_fence(&self, fence: &Fence) -> Vec<Line<'static>> {
+        self.fence_renderers
+            .get(&fence.language.to_lowercase())
+            .map(|renderer| renderer(&fence.content))
+            .unwrap_or_default()
+    }
+
+    /// Hash of everything that changes how fences render: the app theme's
+    /// per-language overrides and the languages with a custom renderer.
+    pub(crate) fn fence_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+
+        if let Some(theme) = &self.app_theme {
+            let mut overrides: Vec<_> = theme.markdown.language_overrides.iter().collect();
+            overrides.sort_by(|a, b| a.0.cmp(b.0));
+            for (language, colors) in overrides {
+                language.hash(&mut hasher);
+                format!("{:?}", colors).hash(&mut hasher);
+            }
+        }
+
+        let mut languages: Vec<_> = self.fence_renderers.keys().collect();
+        languages.sort();
+        languages.hash(&mut hasher);
+
+        hasher.finish()
+    }
+
+    /// Custom-rendered fences from the last render, for selection copy.
+    pub(crate) fn rendered_raw_blocks(&self) -> Vec<(usize, usize, String)> {
+        self.cache
+            .render
+            .as_ref()
+            .map(|cache| cache.raw_blocks.clone())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::style::Color;
+    use ratatui::text::Line;
+    use ratatui::widgets::Widget;
+
+    use crate::widgets::markdown_preview::services::theme::AppTheme;
+    use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::{
+        CodeBlockColors, CodeBlockTheme,
+    };
+    use crate::widgets::markdown_preview::widgets::markdown_widget::state::MarkdownState;
+    use crate::widgets::markdown_preview::widgets::markdown_widget::widget::MarkdownWidget;
+
+    const CONTENT: &str =
+        "Intro\n\n```Mermaid,theme=dark\ngraph TD\nA-->B\n```\n\n```rust\nfn main() {}\n```\n";
+
+    fn render(widget: &mut MarkdownWidget<'_>) -> Vec<String> {
+        let area = Rect::new(0, 0, 40, 20);
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+        widget
+            .rendered_lines
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn registered_renderer_replaces_fence() {
+        let mut state = MarkdownState::new();
+        state.source.set_source_string(CONTENT);
+        let mut widget = MarkdownWidget::from_state(&state)
+            .with_has_pane(false)
+            .with_fence_renderer(
+                "mermaid",
+                Box::new(|raw: &str| {
+                    vec![Line::from(format!(
+                        "diagram: {} lines",
+                        raw.lines().count()
+                    ))]
+                }),
+            );
+
+        let lines = render(&mut widget);
+        let diagram = lines
+            .iter()
+            .position(|line| line == "diagram: 2 lines")
+            .unwrap();
+        assert!(!lines.iter().any(|line| line.contains("A-->B")));
+        assert!(lines[diagram + 1..]
+            .iter()
+            .any(|line| line.contains("fn main")));
+
+        let raw_blocks = widget.rendered_raw_blocks();
+        assert_eq!(raw_blocks, [(diagram, 1, "graph TD\nA-->B".to_string())]);
+
+        widget
+            .selection
+            .enter(0, diagram as i32, widget.rendered_lines.clone(), 40);
+        widget.selection.frozen_raw_blocks = raw_blocks;
+        widget.selection.update_cursor(3, diagram as i32);
+        assert_eq!(
+            widget.selection.get_selected_text().as_deref(),
+            Some("graph TD\nA-->B")
+        );
+    }
+
+    #[test]
+    fn language_override_applies_and_invalidates_cache() {
+        let mut state = MarkdownState::new();
+        state.source.set_source_string(CONTENT);
+        let mut widget = MarkdownWidget::from_state(&state).with_has_pane(false);
+        render(&mut widget);
+        let before = widget.cache.render.as_ref().unwrap().fence_hash;
+
+        let overridden = CodeBlockColors {
+            background: Color::Rgb(1, 2, 3),
+            ..CodeBlockTheme::default().colors()
+        };
+        let mut theme = AppTheme::default();
+        theme.markdown = theme.markdown.with_language_override("RUST", overridden);
+        assert_eq!(theme.markdown.language_override("Rust"), Some(&overridden));
+        widget = widget.with_theme(&theme);
+        render(&mut widget);
+
+        assert_ne!(widget.cache.render.as_ref().unwrap().fence_hash, before);
+        let rust_line = widget
+            .rendered_lines
+            .iter()
+            .find(|line| line.to_string().contains("fn main"))
+            .unwrap();
+        assert!(rust_line
+            .spans
+            .iter()
+            .any(|span| span.style.bg == Some(Color::Rgb(1, 2, 3))));
+    }
+}
@@ -102,6 +102,9 @@ fn text_segment_to_string(segment: &TextSegment) -> String {
         | TextSegment::Html(s) => s.clone(),
         TextSegment::Link { text, .. } => text.clone(),
         TextSegment::Checkbox(_) => String::new(),
+        TextSegment::Decorated { text, original, .. } => {
+            original.clone().unwrap_or_else(|| text.clone())
+        }
     }
 }
 
@@ -0,0 +1,328 @@
+//! Collapsible blocks for fences in languages that cannot be highlighted.
+
+use ratatui::text::Line;
+use unicode_width::UnicodeWidthStr;
+
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::code_block::{
+    render_fold_header, FENCE_COPY_LABEL,
+};
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::{
+    render_with_options, ElementKind, MarkdownElement, RenderOptions,
+};
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::events::MarkdownEvent;
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::types::FenceBlock;
+use crate::widgets::markdown_preview::widgets::markdown_widget::widget::features::fence::{
+    fence_at, Fence,
+};
+use crate::widgets::markdown_preview::widgets::markdown_widget::widget::MarkdownWidget;
+
+impl<'a> MarkdownWidget<'a> {
+    /// Builder form of
+    /// [`set_fence_collapse_threshold`](Self::set_fence_collapse_threshold).
+    pub fn with_fence_collapse_threshold(mut self, threshold: Option<usize>) -> Self {
+        self.set_fence_collapse_threshold(threshold);
+        self
+    }
+
+    /// Sets how many lines a fence may have before it starts collapsed.
+    ///
+    /// Applies to fences whose language cannot be highlighted and has no
+    /// registered renderer, such as `mermaid` or `plantuml`. These render
+    /// as a block with the language and line count in its header; Enter or
+    /// a click on the header collapses or expands it, and `y` or a click on
+    /// the copy glyph copies its raw content. `None` keeps them expanded
+    /// until toggled. Defaults to 20 lines.
+    pub fn set_fence_collapse_threshold(&mut self, threshold: Option<usize>) {
+        if self.display.set_fence_collapse_threshold(threshold) {
+            self.cache.render = None;
+        }
+    }
+
+    /// Collapses or expands the fence under the cursor.
+    pub fn toggle_focused_fence(&mut self) -> MarkdownEvent {
+        match self.focused_fence() {
+            Some(block) => self.toggle_fence(&block),
+            None => MarkdownEvent::None,
+        }
+    }
+
+    /// Copies the raw content of the fence under the cursor.
+    pub fn copy_focused_fence(&mut self) -> MarkdownEvent {
+        self.focused_fence()
+            .and_then(|block| self.copy_text_to_clipboard(block.content, false))
+            .unwrap_or(MarkdownEvent::None)
+    }
+
+    /// Collapsible fences from the last render.
+    pub fn fence_blocks(&self) -> &[FenceBlock] {
+        self.cache
+            .render
+            .as_ref()
+            .map_or(&[], |cache| cache.fences.as_slice())
+    }
+
+    /// Returns the fence starting at `elements[idx]` if it renders as a
+    /// collapsible block: it names a language, no line of it could be
+    /// highlighted and no renderer is registered for it.
+    pub(crate) fn fallback_fence_at(
+        &self,
+        elements: &[MarkdownElement],
+        idx: usize,
+    ) -> Option<Fence> {
+        fence_at(elements, idx).filter(|fence| {
+            !fence.language.is_empty()
+                && !fence.highlighted
+                && !self
+                    .fence_renderers
+                    .contains_key(&fence.language.to_lowercase())
+        })
+    }
+
+    /// Whether `fence` is collapsed: as last toggled, or by its size.
+    pub(crate) fn is_fence_collapsed(&self, fence: &Fence) -> bool {
+        self.collapse
+            .block_collapsed(fence.source_line)
+            .unwrap_or_else(|| {
+                self.display
+                    .fence_collapse_threshold
+                    .is_some_and(|threshold| fence.line_count > threshold)
+            })
+    }
+
+    /// Renders the elements of `fence` as a collapsible block, returning
+    /// the source line and lines of each rendered element.
+    pub(crate) fn render_fallback_fence(
+        &self,
+        elements: &[MarkdownElement],
+        fence: &Fence,
+        collapsed: bool,
+        width: usize,
+        options: RenderOptions<'_>,
+    ) -> Vec<(usize, Vec<Line<'static>>)> {
+        let Some((header, rest)) = elements.split_first() else {
+            return Vec::new();
+        };
+        let ElementKind::CodeBlockHeader {
+            blockquote_depth, ..
+        } = &header.kind
+        else {
+            return Vec::new();
+        };
+
+        let mut rendered = vec![(
+            header.source_line,
+            vec![render_fold_header(
+                &fence.language,
+                fence.line_count,
+                collapsed,
+                width,
+                options.code_block_colors(&fence.language),
+                *blockquote_depth,
+            )],
+        )];
+        for element in rest {
+            if collapsed && matches!(element.kind, ElementKind::CodeBlockContent { .. }) {
+                continue;
+            }
+            rendered.push((
+                element.source_line,
+                render_with_options(element, width, options),
+            ));
+        }
+        rendered
+    }
+
+    /// Toggles the fence whose header is on rendered line `row`, or copies
+    /// it if `column` is on its copy glyph.
+    pub(crate) fn handle_fence_click(
+        &mut self,
+        column: usize,
+        row: usize,
+    ) -> Option<MarkdownEvent> {
+        let gutter = if self.display.show_document_line_numbers {
+            6
+        } else {
+            0
+        };
+        let column = column.checked_sub(gutter)?;
+        let block = self
+            .fence_blocks()
+            .iter()
+            .find(|block| block.row == row)?
+            .clone();
+        self.scroll.set_current_line(row + 1);
+
+        let (start, end) = block.copy_columns;
+        if (start..end).contains(&column) {
+            return Some(
+                self.copy_text_to_clipboard(block.content, false)
+                    .unwrap_or(MarkdownEvent::None),
+            );
+        }
+        Some(self.toggle_fence(&block))
+    }
+
+    /// The collapsible fence the cursor is on.
+    fn focused_fence(&self) -> Option<FenceBlock> {
+        let row = self.scroll.current_line.saturating_sub(1);
+        self.fence_blocks()
+            .iter()
+            .find(|block| (block.row..block.row + block.line_count).contains(&row))
+            .cloned()
+    }
+
+    fn toggle_fence(&mut self, block: &FenceBlock) -> MarkdownEvent {
+        let collapsed = !block.collapsed;
+        self.collapse.set_block_collapsed(block.id, collapsed);
+        self.cache.render = None;
+        self.scroll.set_current_line(block.row + 1);
+        MarkdownEvent::FenceToggled {
+            language: block.language.clone(),
+            collapsed,
+        }
+    }
+}
+
+/// Character columns of the copy glyph in a collapsible fence header.
+pub(crate) fn copy_label_columns(header: &Line<'_>) -> (usize, usize) {
+    let mut start = 0;
+    for span in &header.spans {
+        if span.content == FENCE_COPY_LABEL {
+            return (start, start + span.content.width());
+        }
+        start += span.content.width();
+    }
+    (0, 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::Widget;
+
+    use super::*;
+    use crate::services::clipboard;
+    use crate::widgets::markdown_preview::widgets::markdown_widget::state::MarkdownState;
+
+    fn state_for(content: &str) -> MarkdownState {
+        let mut state = MarkdownState::new();
+        state.source.set_source_string(content);
+        state
+    }
+
+    fn widget_for(state: &MarkdownState) -> MarkdownWidget<'_> {
+        MarkdownWidget::from_state(state)
+            .with_has_pane(false)
+            .show_statusline(false)
+            .show_scrollbar(false)
+    }
+
+    fn render(widget: &mut MarkdownWidget<'_>) {
+        let area = Rect::new(0, 0, 60, 40);
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+    }
+
+    fn mermaid(lines: usize) -> String {
+        let body: Vec<String> = (0..lines).map(|i| format!("A{i}-->B{i}")).collect();
+        format!("```mermaid\n{}\n```\n", body.join("\n"))
+    }
+
+    #[test]
+    fn test_long_fences_start_collapsed() {
+        let content = format!(
+            "{}\n{}\n```rust\nfn main() {{}}\n```\n",
+            mermaid(3),
+            mermaid(25)
+        );
+        let state = state_for(&content);
+        let mut widget = widget_for(&state);
+        render(&mut widget);
+
+        let blocks = widget.fence_blocks().to_vec();
+        assert_eq!(blocks.len(), 2);
+        assert!(!blocks[0].collapsed);
+        assert_eq!(blocks[0].line_count, 5);
+        assert!(blocks[1].collapsed);
+        assert_eq!(blocks[1].line_count, 2);
+        assert!(widget.rendered_lines[blocks[1].row]
+            .to_string()
+            .contains("mermaid \u{b7} 25 lines"));
+        assert!(!widget.to_plain_text().contains("A24-->B24"));
+
+        widget.set_fence_collapse_threshold(Some(2));
+        render(&mut widget);
+        assert!(widget.fence_blocks().iter().all(|block| block.collapsed));
+
+        widget.set_fence_collapse_threshold(None);
+        render(&mut widget);
+        assert!(widget.fence_blocks().iter().all(|block| !block.collapsed));
+    }
+
+    #[test]
+    fn test_toggle_focused_fence_from_keyboard_and_click() {
+        let state = state_for(&format!("Intro\n\n{}", mermaid(25)));
+        let mut widget = widget_for(&state);
+        render(&mut widget);
+        let block = widget.fence_blocks()[0].clone();
+        assert!(block.collapsed);
+
+        widget.scroll.set_current_line(block.row + 1);
+        let event = widget.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert!(matches!(
+            event,
+            MarkdownEvent::FenceToggled { ref language, collapsed: false } if language == "mermaid"
+        ));
+        render(&mut widget);
+        assert_eq!(widget.fence_blocks()[0].line_count, 27);
+        assert!(widget.to_plain_text().contains("A24-->B24"));
+
+        let event = widget.handle_fence_click(0, block.row).unwrap();
+        assert!(matches!(
+            event,
+            MarkdownEvent::FenceToggled {
+                collapsed: true,
+                ..
+            }
+        ));
+        render(&mut widget);
+        assert!(widget.fence_blocks()[0].collapsed);
+        assert!(widget.handle_fence_click(0, block.row + 1).is_none());
+    }
+
+    #[test]
+    fn test_copy_pushes_raw_fence_content() {
+        let state = state_for(&format!("Intro\n\n{}", mermaid(2)));
+        let mut widget = widget_for(&state);
+        render(&mut widget);
+        let block = widget.fence_blocks()[0].clone();
+        assert_eq!(block.content, "A0-->B0\nA1-->B1");
+
+        let header = widget.rendered_lines[block.row].to_string();
+        let (start, end) = block.copy_columns;
+        let label: String = header.chars().skip(start).take(end - start).collect();
+        assert_eq!(label, FENCE_COPY_LABEL);
+
+        // Without a system clipboard the copy still lands in the ring, but
+        // no event fires.
+        let event = widget.handle_fence_click(start + 1, block.row).unwrap();
+        match event {
+            MarkdownEvent::Copied { text } => assert_eq!(text, block.content),
+            MarkdownEvent::None => {}
+            other => panic!("expected Copied, got {other:?}"),
+        }
+        assert!(clipboard::with_ring(|ring| ring
+            .entries()
+            .any(|entry| entry.text == block.content)));
+        assert!(!widget.fence_blocks()[0].collapsed);
+
+        widget.scroll.set_current_line(block.row + 2);
+        let event = widget.handle_key(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE));
+        assert!(matches!(
+            event,
+            MarkdownEvent::Copied { .. } | MarkdownEvent::None
+        ));
+    }
+}
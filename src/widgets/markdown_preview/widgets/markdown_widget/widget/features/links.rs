@@ -0,0 +1,460 @@
+//! Clickable links: where they were rendered and what a click on one does.
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::text::Line;
+use unicode_width::UnicodeWidthChar;
+
+use crate::widgets::markdown_preview::widgets::markdown_widget::extensions::decorations::find_word;
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::constants::get_link_icon;
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::{
+    ElementKind, MarkdownElement, TextSegment,
+};
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::events::MarkdownEvent;
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::types::LinkRange;
+use crate::widgets::markdown_preview::widgets::markdown_widget::widget::MarkdownWidget;
+
+impl<'a> MarkdownWidget<'a> {
+    /// Handles a left click at `column` of rendered line `row`.
+    ///
+    /// `#anchor` links scroll to the matching heading; other links, and
+    /// anchors with no matching heading, are handed to the application.
+    /// Returns `None` if there is no link under the click.
+    pub(crate) fn handle_link_click(&mut self, column: usize, row: usize) -> Option<MarkdownEvent> {
+        let gutter = if self.display.show_document_line_numbers {
+            6
+        } else {
+            0
+        };
+        let column = column.checked_sub(gutter)?;
+        let link = self
+            .cache
+            .render
+            .as_ref()?
+            .links
+            .iter()
+            .find(|link| link.row == row && (link.start..link.end).contains(&column))?
+            .clone();
+
+        if let Some(anchor) = link.url.strip_prefix('#') {
+            if let Some(line) = self.scroll_to_anchor(anchor) {
+                return Some(MarkdownEvent::AnchorNavigated {
+                    anchor: anchor.to_string(),
+                    line,
+                });
+            }
+        }
+
+        Some(MarkdownEvent::LinkClicked {
+            url: link.url,
+            text: link.text,
+        })
+    }
+}
+
+/// The anchor GitHub generates for a heading: lowercased, spaces turned
+/// into dashes, and punctuation other than `-` and `_` dropped.
+pub(crate) fn heading_slug(text: &str) -> String {
+    text.trim()
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            '-' | '_' => Some(c),
+            c if c.is_alphanumeric() => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Locates the links of one rendered element (rows offset by `first_row`).
+pub(crate) fn element_links(
+    element: &MarkdownElement,
+    lines: &[Line<'static>],
+    first_row: usize,
+) -> Vec<LinkRange> {
+    match &element.kind {
+        ElementKind::Paragraph(segments)
+        | ElementKind::Heading { text: segments, .. }
+        | ElementKind::ListItem {
+            content: segments, ..
+        }
+        | ElementKind::Blockquote {
+            content: segments, ..
+        } => locate_links(lines, first_row, segments),
+        _ => Vec::new(),
+    }
+}
+
+/// Locates the links among `segments` in their rendered lines.
+///
+/// Words are matched in order from a cursor, the same way decorated text
+/// is located, so wrapping and renderer prefixes such as list markers don't
+/// throw the columns off. A link split into several segments by nested
+/// emphasis is one link; a link whose words cannot all be found is left out.
+pub(crate) fn locate_links(
+    lines: &[Line<'static>],
+    first_row: usize,
+    segments: &[TextSegment],
+) -> Vec<LinkRange> {
+    if !segments
+        .iter()
+        .any(|segment| matches!(segment, TextSegment::Link { .. }))
+    {
+        return Vec::new();
+    }
+
+    let rows: Vec<Vec<char>> = lines
+        .iter()
+        .map(|line| {
+            line.spans
+                .iter()
+                .flat_map(|span| span.content.chars())
+                .collect()
+        })
+        .collect();
+
+    let mut ranges = Vec::new();
+    let mut cursor = (0, 0);
+    let mut idx = 0;
+
+    while idx < segments.len() {
+        let TextSegment::Link { url, .. } = &segments[idx] else {
+            let text = match &segments[idx] {
+                TextSegment::Plain(text)
+                | TextSegment::Bold(text)
+                | TextSegment::Italic(text)
+                | TextSegment::BoldItalic(text)
+                | TextSegment::InlineCode(text)
+                | TextSegment::Strikethrough(text)
+                | TextSegment::Html(text)
+                | TextSegment::Decorated { text, .. } => text.as_str(),
+                _ => "",
+            };
+            for word in text.split_whitespace() {
+                let word: Vec<char> = word.chars().collect();
+                if let Some((row, col)) = find_word(&rows, cursor, &word) {
+                    cursor = (row, col + word.len());
+                }
+            }
+            idx += 1;
+            continue;
+        };
+
+        // The segments of one link share its url; each new link starts
+        // with the segment that carries the icon.
+        let mut text = String::new();
+        let mut show_icon = false;
+        while let Some(TextSegment::Link {
+            text: part,
+            url: part_url,
+            show_icon: part_icon,
+            ..
+        }) = segments.get(idx)
+        {
+            if part_url != url || (*part_icon && !text.is_empty()) {
+                break;
+            }
+            show_icon |= *part_icon;
+            text.push_str(part);
+            idx += 1;
+        }
+
+        let mut found: Vec<(usize, usize, usize)> = Vec::new();
+        for word in text.split_whitespace() {
+            let word: Vec<char> = word.chars().collect();
+            let Some((row, col)) = find_word(&rows, cursor, &word) else {
+                found.clear();
+                break;
+            };
+            cursor = (row, col + word.len());
+            found.push((row, col, col + word.len()));
+        }
+
+        let Some(&(first, first_col, _)) = found.first() else {
+            continue;
+        };
+        let icon: Vec<char> = if show_icon {
+            get_link_icon(url).chars().collect()
+        } else {
+            Vec::new()
+        };
+        let start = first_col
+            .checked_sub(icon.len())
+            .filter(|&start| !icon.is_empty() && rows[first][start..first_col] == icon[..])
+            .unwrap_or(first_col);
+
+        let mut range = LinkRange {
+            row: first_row + first,
+            start,
+            end: first_col,
+            url: url.clone(),
+            text: text.clone(),
+        };
+        for (row, start, end) in found {
+            if first_row + row != range.row {
+                let next = LinkRange {
+                    row: first_row + row,
+                    start,
+                    end,
+                    url: url.clone(),
+                    text: text.clone(),
+                };
+                ranges.push(std::mem::replace(&mut range, next));
+            }
+            range.end = end;
+        }
+        ranges.push(range);
+    }
+
+    ranges
+}
+
+/// Turns the visible links into OSC 8 hyperlinks.
+///
+/// `lines` are the lines drawn in `area`, showing rendered lines from
+/// `first_row` on, each after `column_offset` columns of gutter. The part
+/// of a link on each row is written, wrapped in the escape sequence, to its
+/// first cell; the rest of its cells are skipped so the terminal keeps the
+/// text it printed there.
+pub(crate) fn write_hyperlinks(
+    buf: &mut Buffer,
+    area: Rect,
+    lines: &[Line<'_>],
+    links: &[LinkRange],
+    first_row: usize,
+    column_offset: usize,
+) {
+    for link in links {
+        let Some(line) = link
+            .row
+            .checked_sub(first_row)
+            .and_then(|index| lines.get(index))
+        else {
+            continue;
+        };
+        let y = area.y + (link.row - first_row) as u16;
+        let columns = link.start + column_offset..link.end + column_offset;
+
+        let mut x = area.x;
+        let mut first_x = None;
+        let mut text = String::new();
+        for (column, ch) in line
+            .spans
+            .iter()
+            .flat_map(|span| span.content.chars())
+            .enumerate()
+        {
+            if column >= columns.end {
+                break;
+            }
+            let width = UnicodeWidthChar::width(ch).unwrap_or(0) as u16;
+            if x + width > area.right() {
+                break;
+            }
+            if columns.contains(&column) {
+                first_x.get_or_insert(x);
+                text.push(ch);
+            }
+            x += width;
+        }
+
+        let Some(first_x) = first_x else {
+            continue;
+        };
+        // A control character in the url would end the sequence early.
+        let url: String = link.url.chars().filter(|c| !c.is_control()).collect();
+        buf[(first_x, y)].set_symbol(&format!("\x1b]8;;{url}\x1b\\{text}\x1b]8;;\x1b\\"));
+        for skipped in first_x + 1..x {
+            buf[(skipped, y)].set_skip(true);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::Widget;
+
+    use super::*;
+    use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::functions::render_markdown;
+    use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::parser::render_markdown_to_elements;
+    use crate::widgets::markdown_preview::widgets::markdown_widget::state::MarkdownState;
+
+    fn click(widget: &mut MarkdownWidget<'_>, column: u16, row: u16) -> MarkdownEvent {
+        let area = Rect::new(0, 0, 40, 10);
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+        widget.handle_mouse(
+            MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                row,
+                modifiers: KeyModifiers::NONE,
+            },
+            area,
+        )
+    }
+
+    #[test]
+    fn test_links_are_located_across_wrapped_lines() {
+        let markdown = "See the [project readme file](https://example.com/readme) for more.";
+        let elements = render_markdown_to_elements(markdown, true);
+        let lines = render_markdown(markdown, Some(20)).lines;
+        let ElementKind::Paragraph(segments) = &elements[0].kind else {
+            panic!("expected a paragraph");
+        };
+
+        let links = locate_links(&lines, 3, segments);
+        let rows: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+        assert_eq!(links.len(), 2);
+        for link in &links {
+            assert_eq!(link.url, "https://example.com/readme");
+            assert_eq!(link.text, "project readme file");
+        }
+
+        let first: String = rows[links[0].row - 3]
+            .chars()
+            .skip(links[0].start)
+            .take(links[0].end - links[0].start)
+            .collect();
+        assert!(first.ends_with("project"), "{first:?} in {rows:?}");
+        assert_eq!(links[1].row, links[0].row + 1);
+    }
+
+    #[test]
+    fn test_clicking_a_link_emits_its_url() {
+        let mut state = MarkdownState::new();
+        state
+            .source
+            .set_source_string("- plain [docs](https://example.com/docs) here");
+        let mut widget = MarkdownWidget::from_state(&state).with_has_pane(false);
+
+        let event = click(&mut widget, 0, 0);
+        assert!(!matches!(event, MarkdownEvent::LinkClicked { .. }));
+
+        let column = widget_column(&mut widget, "docs");
+        match click(&mut widget, column + 1, 0) {
+            MarkdownEvent::LinkClicked { url, text } => {
+                assert_eq!(url, "https://example.com/docs");
+                assert_eq!(text, "docs");
+            }
+            other => panic!("expected LinkClicked, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_anchor_links_scroll_to_their_heading() {
+        let mut content = String::from("Jump to [the end](#deep-section).\n\n");
+        for i in 0..40 {
+            content.push_str(&format!("Filler paragraph {i}.\n\n"));
+        }
+        content.push_str("## Deep Section\n\nDone.\n");
+
+        let mut state = MarkdownState::new();
+        state.source.set_source_string(&content);
+        let mut widget = MarkdownWidget::from_state(&state).with_has_pane(false);
+
+        let column = widget_column(&mut widget, "the end");
+        match click(&mut widget, column, 0) {
+            MarkdownEvent::AnchorNavigated { anchor, line } => {
+                assert_eq!(anchor, "deep-section");
+                assert_eq!(line, 83);
+            }
+            other => panic!("expected AnchorNavigated, got {other:?}"),
+        }
+        assert!(widget.scroll.scroll_offset > 0);
+    }
+
+    #[test]
+    fn test_clicks_anywhere_on_a_wrapped_reference_link() {
+        let mut state = MarkdownState::new();
+        state.source.set_source_string(
+            "- Read the [project readme file for all the details][readme] today.\n\n\
+             [readme]: https://example.com/readme\n",
+        );
+        let mut widget = MarkdownWidget::from_state(&state).with_has_pane(false);
+        click(&mut widget, 0, 9);
+
+        let links = widget.cache.render.as_ref().unwrap().links.clone();
+        assert_eq!(links.len(), 2, "{:?}", widget.rendered_lines);
+        assert_eq!(links[1].row, links[0].row + 1);
+        assert_eq!(links[1].start, 2, "continuation lines keep the list indent");
+        let covered: Vec<String> = links
+            .iter()
+            .map(|link| {
+                widget.rendered_lines[link.row]
+                    .to_string()
+                    .chars()
+                    .skip(link.start)
+                    .take(link.end - link.start)
+                    .collect()
+            })
+            .collect();
+        assert!(
+            covered[0].ends_with("project readme file for all"),
+            "{covered:?}"
+        );
+        assert_eq!(covered[1], "the details");
+
+        for link in &links {
+            let middle = (link.start + link.end) / 2;
+            for column in [link.start, middle, link.end - 1] {
+                match click(&mut widget, column as u16, link.row as u16) {
+                    MarkdownEvent::LinkClicked { url, text } => {
+                        assert_eq!(url, "https://example.com/readme");
+                        assert_eq!(text, "project readme file for all the details");
+                    }
+                    other => panic!("expected LinkClicked at {column}, got {other:?}"),
+                }
+            }
+            let after = click(&mut widget, link.end as u16 + 1, link.row as u16);
+            assert!(!matches!(after, MarkdownEvent::LinkClicked { .. }));
+        }
+    }
+
+    #[test]
+    fn test_hyperlinks_are_written_when_enabled() {
+        let hyperlinks = |enabled: bool| {
+            let mut state = MarkdownState::new();
+            state
+                .source
+                .set_source_string("See [docs](https://example.com/docs) here");
+            state.display.set_hyperlinks(enabled);
+            let widget = MarkdownWidget::from_state(&state)
+                .with_has_pane(false)
+                .show_statusline(false);
+            let area = Rect::new(0, 0, 40, 4);
+            let mut buf = Buffer::empty(area);
+            widget.render(area, &mut buf);
+            (0..area.width)
+                .filter(|&x| buf[(x, 0)].symbol().starts_with('\x1b'))
+                .map(|x| (x, buf[(x, 0)].symbol().to_string(), buf[(x + 1, 0)].skip))
+                .collect::<Vec<_>>()
+        };
+
+        assert!(hyperlinks(false).is_empty());
+        let written = hyperlinks(true);
+        assert_eq!(written.len(), 1);
+        let (_, symbol, next_skipped) = &written[0];
+        assert!(symbol.starts_with("\x1b]8;;https://example.com/docs\x1b\\"));
+        assert!(symbol.ends_with("docs\x1b]8;;\x1b\\"), "{symbol:?}");
+        assert!(next_skipped);
+    }
+
+    fn widget_column(widget: &mut MarkdownWidget<'_>, needle: &str) -> u16 {
+        let area = Rect::new(0, 0, 40, 10);
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+        let line = widget.rendered_lines[0].to_string();
+        line[..line.find(needle).unwrap()].chars().count() as u16
+    }
+
+    #[test]
+    fn test_heading_slug() {
+        assert_eq!(heading_slug("Deep Section"), "deep-section");
+        assert_eq!(heading_slug("What's `new` in v2.0?"), "whats-new-in-v20");
+    }
+}
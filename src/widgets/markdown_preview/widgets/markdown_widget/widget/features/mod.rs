@@ -1,3 +1,13 @@
+pub mod copy;
+pub mod decorations;
+pub mod edit;
+pub mod export;
+pub mod fence;
 pub mod filter;
+pub mod fold;
+pub mod links;
+pub mod reload;
+pub mod search;
 pub mod selection;
+pub mod stats;
 pub mod toc;
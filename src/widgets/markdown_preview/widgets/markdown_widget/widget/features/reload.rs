@@ -0,0 +1,156 @@
+//! Reloading a file source that changed on disk.
+
+use std::io;
+
+use crate::widgets::markdown_preview::widgets::markdown_widget::state::reload::apply_block_diff;
+use crate::widgets::markdown_preview::widgets::markdown_widget::widget::MarkdownWidget;
+
+impl<'a> MarkdownWidget<'a> {
+    /// Reloads a file source if the file changed on disk.
+    ///
+    /// Call it on every tick, or after an editor opened from an
+    /// [`EditRequested`](crate::widgets::markdown_preview::widgets::markdown_widget::foundation::events::MarkdownEvent::EditRequested)
+    /// event exits. Only the blocks that changed are parsed and rendered
+    /// again. The heading at the top of the view stays there, the cursor
+    /// stays on the same source line and collapsed sections stay collapsed.
+    /// Returns whether the content changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read.
+    pub fn reload_source_if_changed(&mut self) -> io::Result<bool> {
+        if !self.source.reload_if_changed()? {
+            return Ok(false);
+        }
+        self.apply_reload();
+        Ok(true)
+    }
+
+    /// Takes over the content the source was reloaded with.
+    fn apply_reload(&mut self) {
+        if let Some(content) = self.source.content() {
+            self.content = content.to_string();
+        }
+        match self.source.take_block_diff() {
+            Some(diff) => apply_block_diff(
+                &self.content,
+                &diff,
+                &mut self.cache,
+                &mut self.collapse,
+                &mut self.scroll,
+            ),
+            None => self.cache.invalidate(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+    use std::sync::Arc;
+
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::Widget;
+
+    use super::*;
+    use crate::widgets::markdown_preview::widgets::markdown_widget::state::{
+        MarkdownState, RenderedElement,
+    };
+
+    fn state_for(path: &Path) -> MarkdownState {
+        let mut state = MarkdownState::new();
+        state.source.set_source_file(path).unwrap();
+        state
+    }
+
+    fn render(widget: &mut MarkdownWidget<'_>, height: u16) {
+        let area = Rect::new(0, 0, 40, height);
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+    }
+
+    fn reload(widget: &mut MarkdownWidget<'_>, path: &Path, content: &str) {
+        fs::write(path, content).unwrap();
+        assert!(widget.source.reload_source().unwrap());
+        widget.apply_reload();
+    }
+
+    /// The cached lines of the element rendered with `text`.
+    fn rendered(widget: &MarkdownWidget<'_>, text: &str) -> Arc<RenderedElement> {
+        let cache = widget.cache.render.as_ref().unwrap();
+        cache
+            .rendered_elements
+            .values()
+            .find(|element| {
+                element
+                    .lines
+                    .iter()
+                    .any(|line| line.to_string().contains(text))
+            })
+            .cloned()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_reload_keeps_lines_of_untouched_blocks() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("doc.md");
+        fs::write(&path, "# One\n\nFirst.\n\n# Two\n\nSecond.\n").unwrap();
+        let state = state_for(&path);
+        let mut widget = MarkdownWidget::from_state(&state)
+            .with_has_pane(false)
+            .show_statusline(false)
+            .show_scrollbar(false);
+        render(&mut widget, 20);
+        let first = rendered(&widget, "First.");
+        let second = rendered(&widget, "Second.");
+
+        reload(
+            &mut widget,
+            &path,
+            "# One\n\nFirst,\nnow edited.\n\n# Two\n\nSecond.\n",
+        );
+        render(&mut widget, 20);
+        assert!(Arc::ptr_eq(&second, &rendered(&widget, "Second.")));
+        assert!(!Arc::ptr_eq(&first, &rendered(&widget, "First,")));
+        assert!(widget.to_plain_text().contains("now edited."));
+    }
+
+    #[test]
+    fn test_reload_keeps_heading_at_top_of_view() {
+        let intro: String = (0..20).map(|i| format!("Intro {i}.\n\n")).collect();
+        let tail: String = (0..20).map(|i| format!("Tail {i}.\n\n")).collect();
+        let content = format!("{intro}# Target\n\n{tail}## Later\n\nEnd.\n");
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("doc.md");
+        fs::write(&path, &content).unwrap();
+        let state = state_for(&path);
+        let mut widget = MarkdownWidget::from_state(&state)
+            .with_has_pane(false)
+            .show_statusline(false)
+            .show_scrollbar(false);
+        render(&mut widget, 10);
+
+        let target = content.lines().position(|line| line == "# Target").unwrap() + 1;
+        let row = widget.row_for_source_line(target).unwrap();
+        widget.scroll.scroll_offset = row + 1;
+        widget.scroll.current_line = row + 6;
+        render(&mut widget, 10);
+
+        reload(
+            &mut widget,
+            &path,
+            &format!("Added.\n\nAnd more.\n\n{content}"),
+        );
+        render(&mut widget, 10);
+        let row = widget.row_for_source_line(target + 4).unwrap();
+        assert_eq!(widget.scroll.scroll_offset, row + 1);
+
+        reload(&mut widget, &path, &content.replacen("Intro 0.\n\n", "", 1));
+        render(&mut widget, 10);
+        let row = widget.row_for_source_line(target - 2).unwrap();
+        assert_eq!(widget.scroll.scroll_offset, row + 1);
+    }
+}
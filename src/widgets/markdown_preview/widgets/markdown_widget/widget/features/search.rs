@@ -0,0 +1,402 @@
+//! Searching the rendered document and highlighting the matches.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::events::MarkdownEvent;
+use crate::widgets::markdown_preview::widgets::markdown_widget::state::{SearchMatch, SearchState};
+use crate::widgets::markdown_preview::widgets::markdown_widget::widget::{
+    MarkdownWidget, MarkdownWidgetMode,
+};
+
+const SEARCH_MATCH_BG: Color = Color::Rgb(92, 78, 40);
+const SEARCH_CURRENT_FG: Color = Color::Black;
+const SEARCH_CURRENT_BG: Color = Color::Rgb(229, 192, 123);
+
+impl<'a> MarkdownWidget<'a> {
+    /// Highlights every match of `query` in the rendered document.
+    ///
+    /// The first match becomes current, but the view does not move until
+    /// [`next_match`](Self::next_match) or [`prev_match`](Self::prev_match)
+    /// is called. An empty query clears the search.
+    pub fn set_search_query(&mut self, query: &str) {
+        self.search.set_query(query, &self.rendered_lines);
+    }
+
+    /// Makes the search respect case. Searches ignore case by default.
+    pub fn set_search_case_sensitive(&mut self, case_sensitive: bool) {
+        if self.search.case_sensitive() != case_sensitive {
+            self.search
+                .set_case_sensitive(case_sensitive, &self.rendered_lines);
+        }
+    }
+
+    /// Makes a query with an uppercase letter respect case, like vim's
+    /// `smartcase`. Off by default.
+    pub fn set_search_smart_case(&mut self, smart_case: bool) {
+        if self.search.smart_case() != smart_case {
+            self.search.set_smart_case(smart_case, &self.rendered_lines);
+        }
+    }
+
+    /// Clears the search query and its highlights.
+    pub fn clear_search(&mut self) {
+        self.search.clear();
+        if self.mode == MarkdownWidgetMode::Search {
+            self.mode = MarkdownWidgetMode::Normal;
+        }
+    }
+
+    /// Opens the search input with an empty query, as `/` does.
+    ///
+    /// Keys go to the query until Enter or Esc; see
+    /// [`handle_key_event`](Self::handle_key_event).
+    pub fn start_search(&mut self) -> MarkdownEvent {
+        self.search.clear();
+        self.search.set_editing(true);
+        self.mode = MarkdownWidgetMode::Search;
+        MarkdownEvent::SearchModeChanged {
+            active: true,
+            query: String::new(),
+        }
+    }
+
+    /// Handles a key while the search query is being typed.
+    ///
+    /// Typing updates the matches and scrolls to the current one as it
+    /// goes. Enter keeps the query so `n`/`N` can step through matches;
+    /// Esc clears it.
+    pub(crate) fn handle_search_key(&mut self, key: KeyEvent) -> MarkdownEvent {
+        let mut query = self.search.query().to_string();
+        match key.code {
+            KeyCode::Esc => {
+                self.clear_search();
+                return MarkdownEvent::SearchModeChanged {
+                    active: false,
+                    query: String::new(),
+                };
+            }
+            KeyCode::Enter => {
+                self.search.set_editing(false);
+                self.mode = MarkdownWidgetMode::Normal;
+                return MarkdownEvent::SearchModeChanged {
+                    active: false,
+                    query,
+                };
+            }
+            KeyCode::Backspace => {
+                query.pop();
+            }
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                query.push(c);
+            }
+            _ => return MarkdownEvent::None,
+        }
+
+        self.set_search_query(&query);
+        let current = self.search.current_match();
+        self.move_to_search_match(current);
+        MarkdownEvent::SearchModeChanged {
+            active: true,
+            query,
+        }
+    }
+
+    /// The search query, matches and current match.
+    pub fn search(&self) -> &SearchState {
+        &self.search
+    }
+
+    /// The current match (1-indexed) and the number of matches, e.g. for a
+    /// `3/12` counter. `None` when there is no search or nothing matched.
+    pub fn search_position(&self) -> Option<(usize, usize)> {
+        let index = self.search.current_index()?;
+        Some((index + 1, self.search.matches().len()))
+    }
+
+    /// Moves to the next match, wrapping to the first, and scrolls it into
+    /// view.
+    ///
+    /// Returns [`MarkdownEvent::SearchMoved`], or [`MarkdownEvent::None`]
+    /// if nothing matched.
+    pub fn next_match(&mut self) -> MarkdownEvent {
+        let found = self.search.next_match();
+        self.move_to_search_match(found)
+    }
+
+    /// Moves to the previous match, wrapping to the last, and scrolls it
+    /// into view.
+    ///
+    /// Returns [`MarkdownEvent::SearchMoved`], or [`MarkdownEvent::None`]
+    /// if nothing matched.
+    pub fn prev_match(&mut self) -> MarkdownEvent {
+        let found = self.search.prev_match();
+        self.move_to_search_match(found)
+    }
+
+    fn move_to_search_match(&mut self, found: Option<SearchMatch>) -> MarkdownEvent {
+        let (Some(found), Some(index)) = (found, self.search.current_index()) else {
+            return MarkdownEvent::None;
+        };
+        self.scroll.current_line = found.row + 1;
+        self.scroll.adjust_scroll_for_current_line();
+        MarkdownEvent::SearchMoved {
+            index,
+            total: self.search.matches().len(),
+        }
+    }
+}
+
+/// Highlights the search matches on `lines`, which show rendered lines
+/// from `first_row` on, each after `column_offset` columns of gutter.
+pub(crate) fn apply_search_highlighting<'l>(
+    lines: Vec<Line<'l>>,
+    search: &SearchState,
+    first_row: usize,
+    column_offset: usize,
+) -> Vec<Line<'l>> {
+    let matches = search.matches();
+    if matches.is_empty() {
+        return lines;
+    }
+    let current = search.current_index();
+
+    lines
+        .into_iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let row = first_row + i;
+            let from = matches.partition_point(|m| m.row < row);
+            let to = matches.partition_point(|m| m.row <= row);
+            if from == to {
+                return line;
+            }
+            let ranges: Vec<(usize, usize, Style)> = (from..to)
+                .map(|index| {
+                    let m = matches[index];
+                    let style = if Some(index) == current {
+                        Style::new().fg(SEARCH_CURRENT_FG).bg(SEARCH_CURRENT_BG)
+                    } else {
+                        Style::new().bg(SEARCH_MATCH_BG)
+                    };
+                    (m.start + column_offset, m.end + column_offset, style)
+                })
+                .collect();
+            highlight_ranges(line, &ranges)
+        })
+        .collect()
+}
+
+/// Splits the spans of `line` at the edges of `ranges` (sorted character
+/// columns) and patches each range's style onto the characters inside it.
+fn highlight_ranges<'l>(line: Line<'l>, ranges: &[(usize, usize, Style)]) -> Line<'l> {
+    let mut spans: Vec<Span<'l>> = Vec::with_capacity(line.spans.len() + ranges.len() * 2);
+    let mut pos = 0;
+
+    for span in line.spans {
+        let len = span.content.chars().count();
+        let span_end = pos + len;
+        if !ranges
+            .iter()
+            .any(|&(start, end, _)| start < span_end && end > pos)
+        {
+            spans.push(span);
+            pos = span_end;
+            continue;
+        }
+
+        let chars: Vec<char> = span.content.chars().collect();
+        let mut cut = pos;
+        for &(start, end, style) in ranges {
+            let (start, end) = (start.max(cut), end.min(span_end));
+            if start >= end {
+                continue;
+            }
+            if start > cut {
+                let before: String = chars[cut - pos..start - pos].iter().collect();
+                spans.push(Span::styled(before, span.style));
+            }
+            let matched: String = chars[start - pos..end - pos].iter().collect();
+            spans.push(Span::styled(matched, span.style.patch(style)));
+            cut = end;
+        }
+        if cut < span_end {
+            let after: String = chars[cut - pos..].iter().collect();
+            spans.push(Span::styled(after, span.style));
+        }
+        pos = span_end;
+    }
+
+    Line {
+        spans,
+        style: line.style,
+        alignment: line.alignment,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::Widget;
+
+    use super::*;
+    use crate::widgets::markdown_preview::widgets::markdown_widget::state::MarkdownState;
+
+    fn render(widget: &mut MarkdownWidget<'_>, area: Rect) -> Buffer {
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+        buf
+    }
+
+    fn highlighted(buf: &Buffer, bg: Color) -> Vec<(u16, String)> {
+        let area = buf.area;
+        (area.top()..area.bottom())
+            .filter_map(|y| {
+                let text: String = (area.left()..area.right())
+                    .filter(|&x| buf[(x, y)].bg == bg)
+                    .map(|x| buf[(x, y)].symbol().to_string())
+                    .collect();
+                (!text.is_empty()).then_some((y, text))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_search_cycles_and_scrolls_to_matches() {
+        let mut content = String::from("# Needle in a haystack\n\n");
+        for i in 0..30 {
+            content.push_str(&format!("Filler paragraph {i}.\n\n"));
+        }
+        content.push_str("The last needle.\n");
+
+        let mut state = MarkdownState::new();
+        state.source.set_source_string(&content);
+        let mut widget = MarkdownWidget::from_state(&state)
+            .with_has_pane(false)
+            .show_statusline(false);
+        let area = Rect::new(0, 0, 40, 10);
+        render(&mut widget, area);
+
+        widget.set_search_query("NEEDLE");
+        assert_eq!(widget.search_position(), Some((1, 2)));
+
+        let buf = render(&mut widget, area);
+        assert_eq!(
+            highlighted(&buf, SEARCH_CURRENT_BG),
+            [(0, "Needle".to_string())]
+        );
+
+        assert!(matches!(
+            widget.next_match(),
+            MarkdownEvent::SearchMoved { index: 1, total: 2 }
+        ));
+        let buf = render(&mut widget, area);
+        assert!(widget.scroll.scroll_offset > 0);
+        let current = highlighted(&buf, SEARCH_CURRENT_BG);
+        assert_eq!(current.len(), 1);
+        assert_eq!(current[0].1, "needle");
+        assert_eq!(
+            usize::from(current[0].0) + widget.scroll.scroll_offset + 1,
+            widget.scroll.current_line
+        );
+
+        assert!(matches!(
+            widget.next_match(),
+            MarkdownEvent::SearchMoved { index: 0, total: 2 }
+        ));
+        assert_eq!(widget.scroll.scroll_offset, 0);
+        assert!(matches!(
+            widget.prev_match(),
+            MarkdownEvent::SearchMoved { index: 1, total: 2 }
+        ));
+
+        widget.set_search_case_sensitive(true);
+        assert_eq!(widget.search_position(), None);
+        widget.set_search_query("needle");
+        assert_eq!(widget.search_position(), Some((1, 1)));
+
+        widget.clear_search();
+        assert!(matches!(widget.next_match(), MarkdownEvent::None));
+    }
+
+    #[test]
+    fn test_slash_search_from_keys() {
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+        let mut content = String::from("# Top\n\n");
+        for i in 0..30 {
+            content.push_str(&format!("Filler paragraph {i}.\n\n"));
+        }
+        content.push_str("A Needle near the end, and a needle after it.\n");
+
+        let mut state = MarkdownState::new();
+        state.source.set_source_string(&content);
+        let mut widget = MarkdownWidget::from_state(&state)
+            .with_has_pane(false)
+            .show_statusline(false);
+        widget.set_search_smart_case(true);
+        let area = Rect::new(0, 0, 40, 10);
+        render(&mut widget, area);
+
+        assert!(matches!(
+            widget.handle_key_event(key(KeyCode::Char('/'))),
+            MarkdownEvent::SearchModeChanged { active: true, .. }
+        ));
+        assert_eq!(widget.mode, MarkdownWidgetMode::Search);
+        for c in "Needle".chars() {
+            widget.handle_key_event(key(KeyCode::Char(c)));
+        }
+        // Smart case: the uppercase N only matches the first needle.
+        assert_eq!(widget.search_position(), Some((1, 1)));
+        assert!(widget.scroll.scroll_offset > 0);
+
+        for _ in 0..6 {
+            widget.handle_key_event(key(KeyCode::Backspace));
+        }
+        for c in "needle".chars() {
+            widget.handle_key_event(key(KeyCode::Char(c)));
+        }
+        assert_eq!(widget.search_position(), Some((1, 2)));
+
+        assert!(matches!(
+            widget.handle_key_event(key(KeyCode::Enter)),
+            MarkdownEvent::SearchModeChanged { active: false, ref query } if query == "needle"
+        ));
+        assert_eq!(widget.mode, MarkdownWidgetMode::Normal);
+        assert!(matches!(
+            widget.handle_key_event(key(KeyCode::Char('n'))),
+            MarkdownEvent::SearchMoved { index: 1, total: 2 }
+        ));
+        assert!(matches!(
+            widget.handle_key_event(key(KeyCode::Char('N'))),
+            MarkdownEvent::SearchMoved { index: 0, total: 2 }
+        ));
+
+        widget.handle_key_event(key(KeyCode::Esc));
+        assert!(!widget.search().is_active());
+        assert!(matches!(
+            widget.handle_key_event(key(KeyCode::Char('n'))),
+            MarkdownEvent::None
+        ));
+    }
+
+    #[test]
+    fn test_matches_follow_wrapping_and_line_numbers() {
+        let mut state = MarkdownState::new();
+        state
+            .source
+            .set_source_string("Some words that wrap onto the next line before the target word.");
+        state.display.set_show_document_line_numbers(true);
+        let mut widget = MarkdownWidget::from_state(&state)
+            .with_has_pane(false)
+            .show_statusline(false);
+        widget.set_search_query("target");
+        let buf = render(&mut widget, Rect::new(0, 0, 30, 6));
+
+        let current = highlighted(&buf, SEARCH_CURRENT_BG);
+        assert_eq!(current.len(), 1);
+        assert_eq!(current[0].1, "target");
+        assert!(current[0].0 > 0);
+    }
+}
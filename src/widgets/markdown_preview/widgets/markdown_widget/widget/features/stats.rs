@@ -0,0 +1,105 @@
+//! Document and selection statistics for the status line.
+
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::stats::{
+    DocumentStats, SelectionStats,
+};
+use crate::widgets::markdown_preview::widgets::markdown_widget::widget::MarkdownWidget;
+
+impl<'a> MarkdownWidget<'a> {
+    /// Shows the word count and reading time in the status line, or the
+    /// selection's counts while text is selected.
+    pub fn show_document_stats(mut self, show: bool) -> Self {
+        self.show_document_stats = show;
+        self
+    }
+
+    /// Counts code blocks towards the reading time estimate.
+    ///
+    /// Code blocks are left out by default.
+    pub fn with_reading_time_includes_code(mut self, include: bool) -> Self {
+        self.set_reading_time_includes_code(include);
+        self
+    }
+
+    /// Counts code blocks towards the reading time estimate.
+    pub fn set_reading_time_includes_code(&mut self, include: bool) {
+        if self.display.set_reading_time_includes_code(include) {
+            self.cache.stats = self
+                .cache
+                .parsed
+                .as_ref()
+                .map(|parsed| DocumentStats::from_elements(&parsed.elements, include));
+        }
+    }
+
+    /// Statistics for the document.
+    ///
+    /// Counted once each time the content is parsed, so this is cheap to
+    /// call every frame. Returns `None` until the widget has been rendered.
+    pub fn stats(&self) -> Option<DocumentStats> {
+        self.cache.stats
+    }
+
+    /// Statistics for the selected text, or `None` if nothing is selected.
+    pub fn selection_stats(&self) -> Option<SelectionStats> {
+        self.selection.stats()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::Widget;
+
+    use crate::widgets::markdown_preview::widgets::markdown_widget::state::MarkdownState;
+    use crate::widgets::markdown_preview::widgets::markdown_widget::widget::MarkdownWidget;
+
+    fn render(widget: &mut MarkdownWidget<'_>) -> String {
+        let area = Rect::new(0, 0, 60, 8);
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+        (0..area.width)
+            .map(|x| buf[(x, area.bottom() - 1)].symbol().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_stats_follow_content_and_selection() {
+        let mut state = MarkdownState::new();
+        state.source.set_source_string(format!(
+            "{}\n\n```\nlet x = 1;\n```\n",
+            "word ".repeat(1400)
+        ));
+        let mut widget = MarkdownWidget::from_state(&state)
+            .with_has_pane(false)
+            .show_document_stats(true);
+        assert_eq!(widget.stats(), None);
+
+        let statusline = render(&mut widget);
+        let stats = widget.stats().unwrap();
+        assert_eq!(stats.words, 1403);
+        assert_eq!(stats.code_blocks, 1);
+        assert_eq!(stats.reading_time_minutes, 7);
+        assert!(
+            statusline.contains("1,403 words · 7 min read"),
+            "{statusline}"
+        );
+
+        widget.set_reading_time_includes_code(true);
+        assert_eq!(widget.stats().unwrap().reading_time_minutes, 8);
+
+        widget
+            .selection
+            .enter(0, 0, widget.rendered_lines.clone(), 60);
+        widget.selection.update_cursor(9, 0);
+        let selection = widget.selection_stats().unwrap();
+        assert_eq!(selection.words, 2);
+        assert_eq!(selection.chars, 9);
+        assert_eq!(selection.lines, 1);
+        assert!(render(&mut widget).contains("2 words · 9 chars selected"));
+
+        widget.selection.exit();
+        assert_eq!(widget.selection_stats(), None);
+    }
+}
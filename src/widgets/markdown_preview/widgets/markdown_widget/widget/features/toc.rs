@@ -1,9 +1,48 @@
+use std::collections::HashMap;
+
 use crate::widgets::markdown_preview::widgets::markdown_widget::extensions::toc::{Toc, TocConfig};
-use crate::widgets::markdown_preview::widgets::markdown_widget::state::TocState;
-use crate::widgets::markdown_preview::widgets::markdown_widget::widget::MarkdownWidget;
-use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::events::MarkdownEvent;
+use crate::widgets::markdown_preview::widgets::markdown_widget::state::{
+    TocFocusAction, TocPosition, TocState,
+};
+use crate::widgets::markdown_preview::widgets::markdown_widget::widget::features::links::heading_slug;
+use crate::widgets::markdown_preview::widgets::markdown_widget::widget::{
+    MarkdownWidget, MarkdownWidgetMode,
+};
+use crossterm::event::{KeyCode, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::layout::Rect;
 
+/// Columns between the TOC and the edge of the document it sits on.
+const TOC_EDGE_PADDING: u16 = 2;
+/// Rows between the top of the document and the TOC.
+const TOC_TOP_PADDING: u16 = 1;
+
+/// Where the TOC is drawn, shared by rendering and mouse hit-testing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TocLayout {
+    /// The TOC panel.
+    pub(crate) area: Rect,
+    /// Column of the edge facing the document, dragged to resize the TOC.
+    pub(crate) divider_x: u16,
+    /// Whether the TOC is drawn as an icon-only rail.
+    pub(crate) rail: bool,
+}
+
+impl TocLayout {
+    /// Whether `(column, row)` is on the divider.
+    pub(crate) fn is_on_divider(&self, column: u16, row: u16) -> bool {
+        column == self.divider_x && row >= self.area.y && row < self.area.bottom()
+    }
+
+    /// Whether `(column, row)` is inside the TOC panel.
+    pub(crate) fn contains(&self, column: u16, row: u16) -> bool {
+        column >= self.area.x
+            && column < self.area.right()
+            && row >= self.area.y
+            && row < self.area.bottom()
+    }
+}
+
 impl<'a> MarkdownWidget<'a> {
     pub fn show_toc(mut self, show: bool) -> Self {
         self.show_toc = show;
@@ -15,6 +54,7 @@ impl<'a> MarkdownWidget<'a> {
         if !self.show_toc {
             self.toc_hovered = false;
             self.toc_hovered_entry = None;
+            self.blur_toc();
         }
         self.show_toc
     }
@@ -39,7 +79,31 @@ impl<'a> MarkdownWidget<'a> {
         self
     }
 
+    /// Draws the TOC on `position`'s side of the document.
+    pub fn toc_position(mut self, position: TocPosition) -> Self {
+        self.display.toc_position = position;
+        self
+    }
+
+    /// Sets the width the TOC divider was dragged to, `None` for the
+    /// default width.
+    pub fn toc_width(mut self, width: Option<u16>) -> Self {
+        self.toc_width = width;
+        self
+    }
+
+    /// Width the TOC divider was dragged to, `None` for the default width.
+    pub fn get_toc_width(&self) -> Option<u16> {
+        self.toc_width
+    }
+
     pub fn calculate_toc_area(&self, total_area: Rect) -> Option<Rect> {
+        self.toc_layout(total_area).map(|layout| layout.area)
+    }
+
+    /// Where the TOC goes in `total_area`, or `None` while it is hidden or
+    /// does not fit.
+    pub(crate) fn toc_layout(&self, total_area: Rect) -> Option<TocLayout> {
         if !self.show_toc {
             return None;
         }
@@ -53,19 +117,33 @@ impl<'a> MarkdownWidget<'a> {
             total_area
         };
 
-        let padding_right: u16 = 2;
-        let padding_top: u16 = 1;
-
-        let toc_width = if self.toc_hovered {
-            Toc::required_expanded_width(&self.content, self.toc_config.show_border)
-                .min(main_area.width.saturating_sub(padding_right + 4))
+        let max_width = main_area.width.saturating_sub(TOC_EDGE_PADDING + 4);
+        let chosen_width = self.clamped_toc_width(self.toc_width);
+        let rail = self.toc_rail();
+
+        let toc_width = if rail {
+            chosen_width.unwrap_or(self.toc_config.min_width)
+        } else if self.toc_expanded() {
+            chosen_width
+                .unwrap_or_else(|| {
+                    Toc::required_expanded_width(&self.content, self.toc_config.show_border)
+                })
+                .min(max_width)
         } else {
-            self.toc_config.compact_width
+            chosen_width.map_or(self.toc_config.compact_width, |width| {
+                width.min(self.toc_config.compact_width)
+            })
         };
 
-        let toc_height = if self.toc_hovered {
-            Toc::required_height(&self.content, self.toc_config.show_border)
-                .min(main_area.height.saturating_sub(1))
+        let toc_height = if rail {
+            let auto_state = self.auto_toc_state();
+            let rows = self.resolved_toc_state(&auto_state).visible_entries().len() as u16;
+            rows.min(main_area.height.saturating_sub(1))
+        } else if self.toc_expanded() {
+            let border_height = if self.toc_config.show_border { 2 } else { 0 };
+            let auto_state = self.auto_toc_state();
+            let rows = self.resolved_toc_state(&auto_state).visible_entries().len() as u16;
+            (rows + border_height).min(main_area.height.saturating_sub(1))
         } else {
             Toc::required_compact_height(
                 &self.content,
@@ -75,18 +153,193 @@ impl<'a> MarkdownWidget<'a> {
             .min(main_area.height.saturating_sub(1))
         };
 
-        if main_area.width <= toc_width + padding_right + 2 {
+        if main_area.width <= toc_width + TOC_EDGE_PADDING + 2 {
             return None;
         }
 
-        Some(Rect {
-            x: main_area.x + main_area.width.saturating_sub(toc_width + padding_right),
-            y: main_area.y + padding_top,
+        let x = match self.display.toc_position {
+            TocPosition::Left => main_area.x + TOC_EDGE_PADDING,
+            TocPosition::Right => {
+                main_area.x + main_area.width.saturating_sub(toc_width + TOC_EDGE_PADDING)
+            }
+        };
+        let area = Rect {
+            x,
+            y: main_area.y + TOC_TOP_PADDING,
             width: toc_width,
             height: toc_height,
+        };
+        let divider_x = match self.display.toc_position {
+            TocPosition::Left => area.right().saturating_sub(1),
+            TocPosition::Right => area.x,
+        };
+        Some(TocLayout {
+            area,
+            divider_x,
+            rail,
         })
     }
 
+    /// `width` within the configured drag limits.
+    fn clamped_toc_width(&self, width: Option<u16>) -> Option<u16> {
+        let min = self.toc_config.min_width.max(1);
+        width.map(|width| width.clamp(min, self.toc_config.max_width.max(min)))
+    }
+
+    /// Whether the TOC was dragged narrow enough to become a rail.
+    pub(crate) fn toc_rail(&self) -> bool {
+        self.clamped_toc_width(self.toc_width)
+            .is_some_and(|width| width < self.toc_config.rail_threshold)
+    }
+
+    /// The TOC widget for `state` in the current display mode.
+    pub(crate) fn toc_widget<'s>(&self, state: &'s TocState) -> Toc<'s> {
+        Toc::new(state)
+            .expanded(self.toc_expanded())
+            .rail(self.toc_rail())
+            .config(self.toc_config.clone())
+    }
+
+    /// Starts dragging the TOC divider when `event` presses it, or resets
+    /// the width on a double-click. Returns `None` for other events.
+    pub(crate) fn handle_toc_divider_press(
+        &mut self,
+        event: &MouseEvent,
+        layout: TocLayout,
+    ) -> Option<MarkdownEvent> {
+        if !matches!(event.kind, MouseEventKind::Down(MouseButton::Left))
+            || !layout.is_on_divider(event.column, event.row)
+        {
+            return None;
+        }
+        let (is_double, _) = self
+            .toc_divider_clicks
+            .process_click(event.column, event.row, 0);
+        if is_double {
+            self.toc_resizing = false;
+            self.toc_width = None;
+            return Some(MarkdownEvent::TocResized { width: None });
+        }
+        self.toc_resizing = true;
+        Some(MarkdownEvent::None)
+    }
+
+    /// Resizes the TOC while its divider is dragged and ends the drag when
+    /// the button is released. Returns `None` when no drag is in progress.
+    pub(crate) fn handle_toc_resize(
+        &mut self,
+        event: &MouseEvent,
+        total_area: Rect,
+    ) -> Option<MarkdownEvent> {
+        if !self.toc_resizing {
+            return None;
+        }
+        match event.kind {
+            MouseEventKind::Drag(MouseButton::Left) => {
+                let Some(layout) = self.toc_layout(total_area) else {
+                    self.toc_resizing = false;
+                    return Some(MarkdownEvent::None);
+                };
+                let width = match self.display.toc_position {
+                    TocPosition::Left => (event.column + 1).saturating_sub(layout.area.x),
+                    TocPosition::Right => layout.area.right().saturating_sub(event.column),
+                };
+                let max_width = total_area.width.saturating_sub(TOC_EDGE_PADDING + 4);
+                let width = self.clamped_toc_width(Some(width.min(max_width)));
+                if width == self.toc_width {
+                    return Some(MarkdownEvent::None);
+                }
+                self.toc_width = width;
+                Some(MarkdownEvent::TocResized { width })
+            }
+            MouseEventKind::Up(_) => {
+                self.toc_resizing = false;
+                Some(MarkdownEvent::None)
+            }
+            _ => None,
+        }
+    }
+
+    /// Moves keyboard focus into the TOC, showing it expanded. Does nothing
+    /// while the TOC is hidden.
+    pub fn focus_toc(&mut self) -> MarkdownEvent {
+        if !self.show_toc {
+            return MarkdownEvent::None;
+        }
+        let mut state = self.auto_toc_state();
+        state.focus();
+        self.apply_toc_focus(&state);
+        self.mode = MarkdownWidgetMode::Toc;
+        MarkdownEvent::TocFocusChanged { focused: true }
+    }
+
+    /// Whether keyboard focus is in the TOC.
+    pub fn is_toc_focused(&self) -> bool {
+        self.toc_focused
+    }
+
+    /// Handles a key while the TOC has focus, see
+    /// [`TocState::handle_focus_key`]. Enter scrolls the document to the
+    /// selected heading.
+    pub(crate) fn handle_toc_focus_key(&mut self, key: KeyCode) -> MarkdownEvent {
+        let auto_state = self.auto_toc_state();
+        let mut state = self.resolved_toc_state(&auto_state).clone();
+        state.focused = true;
+        state.selected_entry = self.toc_selected_entry;
+        state.collapsed.clone_from(&self.toc_collapsed);
+        let action = state.handle_focus_key(key);
+        self.apply_toc_focus(&state);
+
+        match action {
+            TocFocusAction::Ignored => MarkdownEvent::None,
+            TocFocusAction::Handled => MarkdownEvent::TocFocusChanged { focused: true },
+            TocFocusAction::Jump(line) => {
+                self.scroll_to_heading_line(line);
+                MarkdownEvent::FocusedLine {
+                    line: self.scroll.current_line,
+                }
+            }
+            TocFocusAction::Exit => {
+                self.blur_toc();
+                MarkdownEvent::TocFocusChanged { focused: false }
+            }
+        }
+    }
+
+    fn blur_toc(&mut self) {
+        self.toc_focused = false;
+        if self.mode == MarkdownWidgetMode::Toc {
+            self.mode = MarkdownWidgetMode::Normal;
+        }
+    }
+
+    fn apply_toc_focus(&mut self, state: &TocState) {
+        self.toc_focused = state.focused;
+        self.toc_selected_entry = state.selected_entry;
+        self.toc_scroll_offset = state.scroll_offset;
+        self.toc_collapsed.clone_from(&state.collapsed);
+    }
+
+    /// Whether the TOC shows heading text rather than compact lines.
+    pub(crate) fn toc_expanded(&self) -> bool {
+        self.toc_hovered || self.toc_focused
+    }
+
+    /// TOC state built from the content and the widget's hover, focus and
+    /// collapse fields.
+    pub(crate) fn auto_toc_state(&self) -> TocState {
+        let mut state = TocState::from_content(&self.content);
+        state.scroll_offset = self.toc_scroll_offset;
+        state.hovered = self.toc_hovered;
+        state.hovered_entry = self.toc_hovered_entry;
+        state.focused = self.toc_focused;
+        state.selected_entry = self.toc_selected_entry;
+        state.collapsed.clone_from(&self.toc_collapsed);
+        state.width = self.toc_width;
+        state.resizing = self.toc_resizing;
+        state
+    }
+
     pub(crate) fn resolved_toc_state<'s>(&'s self, auto_state: &'s TocState) -> &'s TocState {
         if let Some(provided) = &self.toc_state {
             if provided.entries.is_empty() {
@@ -124,24 +377,13 @@ impl<'a> MarkdownWidget<'a> {
             return false;
         }
 
-        let mut auto_state = TocState::from_content(&self.content);
-        auto_state.scroll_offset = self.toc_scroll_offset;
-        auto_state.hovered = self.toc_hovered;
-        auto_state.hovered_entry = self.toc_hovered_entry;
+        let auto_state = self.auto_toc_state();
         let toc_state = self.resolved_toc_state(&auto_state);
-        let toc = Toc::new(toc_state)
-            .expanded(self.toc_hovered)
-            .config(self.toc_config.clone());
+        let toc = self.toc_widget(toc_state);
 
         if let Some(entry_idx) = toc.entry_at_position(event.column, event.row, toc_area) {
             if let Some(target_line) = toc.click_to_line(entry_idx) {
-                let new_offset = target_line.saturating_sub(2);
-                let max_offset = self
-                    .scroll
-                    .total_lines
-                    .saturating_sub(self.scroll.viewport_height);
-                self.scroll.scroll_offset = new_offset.min(max_offset);
-                self.scroll.current_line = target_line.saturating_add(1);
+                self.scroll_to_heading_line(target_line);
                 self.toc_hovered_entry = Some(entry_idx);
                 return true;
             }
@@ -177,13 +419,12 @@ impl<'a> MarkdownWidget<'a> {
 
         if is_potentially_over_toc {
             let hovered_entry = {
-                let mut auto_state = TocState::from_content(&self.content);
-                auto_state.scroll_offset = self.toc_scroll_offset;
+                let mut auto_state = self.auto_toc_state();
                 auto_state.hovered = true;
-                auto_state.hovered_entry = self.toc_hovered_entry;
                 let toc_state = self.resolved_toc_state(&auto_state);
                 let toc = Toc::new(toc_state)
                     .expanded(true)
+                    .rail(self.toc_rail())
                     .config(self.toc_config.clone());
                 toc.entry_at_position(event.column, event.row, toc_area)
             };
@@ -221,13 +462,12 @@ impl<'a> MarkdownWidget<'a> {
     }
 
     pub fn update_toc_hovered_entry(&mut self, x: u16, y: u16, toc_area: Rect) {
-        let mut auto_state = TocState::from_content(&self.content);
-        auto_state.scroll_offset = self.toc_scroll_offset;
+        let mut auto_state = self.auto_toc_state();
         auto_state.hovered = true;
-        auto_state.hovered_entry = self.toc_hovered_entry;
         let toc_state = self.resolved_toc_state(&auto_state);
         let toc = Toc::new(toc_state)
             .expanded(true)
+            .rail(self.toc_rail())
             .config(self.toc_config.clone());
 
         self.toc_hovered_entry = toc.entry_at_position(x, y, toc_area);
@@ -235,13 +475,12 @@ impl<'a> MarkdownWidget<'a> {
 
     pub(crate) fn handle_toc_hover_internal(&mut self, event: &MouseEvent, toc_area: Rect) {
         let hovered_entry = {
-            let mut auto_state = TocState::from_content(&self.content);
-            auto_state.scroll_offset = self.toc_scroll_offset;
+            let mut auto_state = self.auto_toc_state();
             auto_state.hovered = true;
-            auto_state.hovered_entry = self.toc_hovered_entry;
             let toc_state = self.resolved_toc_state(&auto_state);
             let toc = Toc::new(toc_state)
                 .expanded(true)
+                .rail(self.toc_rail())
                 .config(self.toc_config.clone());
             toc.entry_at_position(event.column, event.row, toc_area)
         };
@@ -249,7 +488,232 @@ impl<'a> MarkdownWidget<'a> {
         self.toc_hovered_entry = hovered_entry;
     }
 
+    /// Scrolls to the heading whose GitHub-style anchor is `anchor`,
+    /// returning its source line.
+    ///
+    /// Repeated headings are told apart with `-1`, `-2`, ... suffixes.
+    pub(crate) fn scroll_to_anchor(&mut self, anchor: &str) -> Option<usize> {
+        let anchor = anchor.to_lowercase();
+        let target_line = self
+            .heading_anchors()
+            .into_iter()
+            .find_map(|(slug, line)| (slug == anchor).then_some(line))?;
+
+        self.scroll_to_heading_line(target_line);
+        Some(target_line)
+    }
+
+    /// GitHub-style anchor and source line of every heading, in document
+    /// order. Repeated headings get `-1`, `-2`, ... suffixes.
+    pub(crate) fn heading_anchors(&self) -> Vec<(String, usize)> {
+        let auto_state = TocState::from_content(&self.content);
+        let toc_state = self.resolved_toc_state(&auto_state);
+
+        let mut seen: HashMap<String, usize> = HashMap::new();
+        toc_state
+            .entries
+            .iter()
+            .map(|entry| {
+                let slug = heading_slug(&entry.text);
+                let count = seen.entry(slug.clone()).or_insert(0);
+                let slug = if *count == 0 {
+                    slug
+                } else {
+                    format!("{}-{}", slug, count)
+                };
+                *count += 1;
+                (slug, entry.line_number)
+            })
+            .collect()
+    }
+
+    fn scroll_to_heading_line(&mut self, target_line: usize) {
+        let new_offset = target_line.saturating_sub(2);
+        let max_offset = self
+            .scroll
+            .total_lines
+            .saturating_sub(self.scroll.viewport_height);
+        self.scroll.scroll_offset = new_offset.min(max_offset);
+        self.scroll.current_line = target_line.saturating_add(1);
+    }
+
     pub(crate) fn handle_toc_click_internal(&mut self, event: &MouseEvent, toc_area: Rect) -> bool {
         self.handle_toc_click_in_area(event, toc_area)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyEvent, KeyModifiers};
+    use ratatui::buffer::Buffer;
+    use ratatui::style::Modifier;
+    use ratatui::widgets::Widget;
+
+    use super::*;
+    use crate::widgets::markdown_preview::widgets::markdown_widget::state::MarkdownState;
+
+    fn press(widget: &mut MarkdownWidget<'_>, c: char) -> MarkdownEvent {
+        widget.handle_key_event(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+    }
+
+    fn render(state: &MarkdownState) -> (MarkdownWidget<'_>, Buffer) {
+        let area = Rect::new(0, 0, 60, 20);
+        let mut widget = MarkdownWidget::from_state(state)
+            .with_has_pane(false)
+            .show_statusline(false)
+            .show_toc(true);
+        let mut buf = Buffer::empty(area);
+        (&mut widget).render(area, &mut buf);
+        (widget, buf)
+    }
+
+    fn screen(buf: &Buffer) -> String {
+        let area = buf.area;
+        (area.y..area.bottom())
+            .map(|y| {
+                (area.x..area.right())
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_toc_focus_mode_collapses_and_jumps() {
+        let mut state = MarkdownState::new();
+        state
+            .source
+            .set_source_string("# Guide\n\n## Setup\n\n### Install\n\n## Usage\n\ntext\n");
+
+        let (mut widget, _) = render(&state);
+        assert!(matches!(
+            press(&mut widget, 't'),
+            MarkdownEvent::TocFocusChanged { focused: true }
+        ));
+        assert_eq!(widget.mode, MarkdownWidgetMode::Toc);
+        press(&mut widget, 'j');
+        press(&mut widget, 'h');
+        let sync = widget.get_state_sync();
+        drop(widget);
+        sync.apply_to(&mut state);
+        assert!(state.toc_focused);
+
+        // The collapsed subtree and the selection survive a re-render.
+        let (mut widget, buf) = render(&state);
+        let text = screen(&buf);
+        assert!(text.contains("▸Setup"), "{text}");
+        assert_eq!(text.matches("Install").count(), 1, "{text}");
+        let (x, y) = (0..60)
+            .flat_map(|y| (0..60).map(move |x| (x, y)))
+            .find(|&(x, y)| buf[(x, y)].symbol() == "▸")
+            .unwrap();
+        assert!(buf[(x + 1, y)].modifier.contains(Modifier::BOLD));
+        assert!(!buf[(x + 1, y + 1)].modifier.contains(Modifier::BOLD));
+
+        assert!(matches!(
+            widget.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)),
+            MarkdownEvent::FocusedLine { line: 4 }
+        ));
+        assert!(matches!(
+            widget.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)),
+            MarkdownEvent::TocFocusChanged { focused: false }
+        ));
+        assert_eq!(widget.mode, MarkdownWidgetMode::Normal);
+        let sync = widget.get_state_sync();
+        drop(widget);
+        sync.apply_to(&mut state);
+
+        // Reloading with the heading unchanged keeps it collapsed.
+        state
+            .source
+            .set_source_string("# Guide\n\nIntro.\n\n## Setup\n\n### Install\n\n### Upgrade\n");
+        let (widget, _) = render(&state);
+        assert!(!widget.is_toc_focused());
+        assert_eq!(widget.auto_toc_state().visible_entries(), [0, 1]);
+    }
+
+    fn mouse(widget: &mut MarkdownWidget<'_>, kind: MouseEventKind, column: u16) -> MarkdownEvent {
+        let event = MouseEvent {
+            kind,
+            column,
+            row: 2,
+            modifiers: KeyModifiers::NONE,
+        };
+        widget.handle_mouse(event, Rect::new(0, 0, 60, 20))
+    }
+
+    #[test]
+    fn test_toc_divider_resizes_into_rail_on_either_side() {
+        let mut state = MarkdownState::new();
+        state
+            .source
+            .set_source_string("# Guide\n\n## Setup\n\n### Install\n\n## Usage\n\ntext\n");
+        state.display.set_toc_position(TocPosition::Left);
+        let down = MouseEventKind::Down(MouseButton::Left);
+        let drag = MouseEventKind::Drag(MouseButton::Left);
+
+        let (mut widget, _) = render(&state);
+        mouse(&mut widget, MouseEventKind::Moved, 5);
+        let layout = widget.toc_layout(Rect::new(0, 0, 60, 20)).unwrap();
+        assert_eq!((layout.area.x, layout.area.width), (2, 16));
+        assert_eq!(layout.divider_x, 17);
+
+        assert!(matches!(mouse(&mut widget, down, 17), MarkdownEvent::None));
+        // Dragging past the document edge keeps the minimum width.
+        assert!(matches!(
+            mouse(&mut widget, drag, 0),
+            MarkdownEvent::TocResized { width: Some(3) }
+        ));
+        assert!(matches!(
+            mouse(&mut widget, drag, 7),
+            MarkdownEvent::TocResized { width: Some(6) }
+        ));
+        mouse(&mut widget, MouseEventKind::Up(MouseButton::Left), 7);
+        assert!(!widget.toc_resizing);
+        let sync = widget.get_state_sync();
+        drop(widget);
+        sync.apply_to(&mut state);
+        assert_eq!(state.toc_width, Some(6));
+
+        // The width survives a cache invalidation and narrow TOCs are rails.
+        state.cache.invalidate();
+        let (mut widget, buf) = render(&state);
+        let rail: String = (1..5).map(|y| buf[(4, y)].symbol()).collect();
+        assert_eq!(rail, "●•·•");
+        assert!(matches!(
+            widget.handle_mouse(
+                MouseEvent {
+                    kind: down,
+                    column: 4,
+                    row: 3,
+                    modifiers: KeyModifiers::NONE,
+                },
+                Rect::new(0, 0, 60, 20)
+            ),
+            MarkdownEvent::Scrolled { .. }
+        ));
+        assert_eq!(widget.get_toc_hovered_entry(), Some(2));
+
+        // A double-click on the divider goes back to the default width.
+        mouse(&mut widget, down, 7);
+        assert!(matches!(
+            mouse(&mut widget, down, 7),
+            MarkdownEvent::TocResized { width: None }
+        ));
+        assert!(!widget.toc_rail());
+
+        // On the right the divider is the left edge.
+        let mut widget = widget.toc_position(TocPosition::Right).toc_width(Some(20));
+        mouse(&mut widget, MouseEventKind::Moved, 45);
+        let layout = widget.toc_layout(Rect::new(0, 0, 60, 20)).unwrap();
+        assert_eq!((layout.area.x, layout.divider_x), (38, 38));
+        mouse(&mut widget, down, 38);
+        assert!(matches!(
+            mouse(&mut widget, drag, 30),
+            MarkdownEvent::TocResized { width: Some(28) }
+        ));
+        let layout = widget.toc_layout(Rect::new(0, 0, 60, 20)).unwrap();
+        assert_eq!((layout.area.x, layout.area.right()), (30, 58));
+    }
+}
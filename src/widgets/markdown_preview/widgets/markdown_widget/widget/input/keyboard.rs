@@ -12,6 +12,14 @@ impl<'a> MarkdownWidget<'a> {
             return self.handle_filter_key(key);
         }
 
+        if self.search.is_editing() {
+            return self.handle_search_key(key);
+        }
+
+        if self.toc_focused {
+            return self.handle_toc_focus_key(key.code);
+        }
+
         if key.code == KeyCode::Esc && self.selection.is_active() {
             self.selection.exit();
             self.selection_active = false;
@@ -20,6 +28,14 @@ impl<'a> MarkdownWidget<'a> {
             return MarkdownEvent::SelectionEnded;
         }
 
+        if key.code == KeyCode::Esc && self.search.is_active() {
+            self.clear_search();
+            return MarkdownEvent::SearchModeChanged {
+                active: false,
+                query: String::new(),
+            };
+        }
+
         if key.code == KeyCode::Char('y') && self.selection.has_selection() {
             if let Some(text) = self.selection.get_selected_text() {
                 if let Some(event) = self.copy_text_to_clipboard(text, false) {
@@ -61,7 +77,12 @@ impl<'a> MarkdownWidget<'a> {
         self.vim.clear_pending_g();
 
         match key.code {
-            KeyCode::Char('/') => {
+            KeyCode::Char('/') => self.start_search(),
+            KeyCode::Char('e') => self.request_edit(),
+            KeyCode::Char('t') => self.focus_toc(),
+            KeyCode::Char('y') => self.copy_focused_fence(),
+            KeyCode::Enter => self.toggle_focused_fence(),
+            KeyCode::Char('f') => {
                 self.filter_mode = true;
                 self.filter = Some(String::new());
                 self.mode = MarkdownWidgetMode::Filter;
@@ -110,6 +131,8 @@ impl<'a> MarkdownWidget<'a> {
                     line: self.scroll.current_line,
                 }
             }
+            KeyCode::Char('n') if self.search.is_active() => self.next_match(),
+            KeyCode::Char('N') if self.search.is_active() => self.prev_match(),
             _ => MarkdownEvent::None,
         }
     }
@@ -20,6 +20,11 @@ impl<'a> MarkdownWidget<'a> {
         event: &MouseEvent,
         area: Rect,
     ) -> MarkdownEvent {
+        // A divider drag keeps going outside the area until released
+        if let Some(event) = self.handle_toc_resize(event, area) {
+            return event;
+        }
+
         if !is_in_area(event.column, event.row, area) {
             if self.selection.is_active() {
                 self.selection.exit();
@@ -31,20 +36,22 @@ impl<'a> MarkdownWidget<'a> {
 
         let border_offset = if self.bordered { 1 } else { 0 };
         let relative_y = event.row.saturating_sub(area.y + border_offset) as usize;
-        let relative_x = event.column.saturating_sub(area.x) as usize;
-        let width = area.width as usize;
+        let (width, content_offset) = self.content_layout(area.width);
+        let column = event.column.saturating_sub(area.x) as usize;
+        let in_margin = column < content_offset;
+        let relative_x = column.saturating_sub(content_offset);
 
         let document_y = (relative_y + self.scroll.scroll_offset) as i32;
         let document_x = relative_x as i32;
 
         if self.show_toc {
-            if let Some(toc_area) = self.calculate_toc_area(area) {
-                let is_over_toc = event.column >= toc_area.x
-                    && event.column < toc_area.x + toc_area.width
-                    && event.row >= toc_area.y
-                    && event.row < toc_area.y + toc_area.height;
+            if let Some(layout) = self.toc_layout(area) {
+                if let Some(event) = self.handle_toc_divider_press(event, layout) {
+                    return event;
+                }
 
-                if is_over_toc {
+                let toc_area = layout.area;
+                if layout.contains(event.column, event.row) {
                     match event.kind {
                         MouseEventKind::Moved => {
                             let prev_hovered = self.toc_hovered;
@@ -141,6 +148,18 @@ impl<'a> MarkdownWidget<'a> {
                     self.selection_active = false;
                 }
 
+                let link_column = relative_x.saturating_sub(border_offset as usize);
+                if !in_margin {
+                    if let Some(event) = self.handle_link_click(link_column, document_y as usize) {
+                        self.double_click.clear_pending();
+                        return event;
+                    }
+                    if let Some(event) = self.handle_fence_click(link_column, document_y as usize) {
+                        self.double_click.clear_pending();
+                        return event;
+                    }
+                }
+
                 if self.handle_click_collapse(relative_x, relative_y, width) {
                     self.double_click.clear_pending();
                     let clicked_line = self.scroll.scroll_offset + relative_y + 1;
@@ -178,6 +197,8 @@ impl<'a> MarkdownWidget<'a> {
                         self.rendered_lines.clone(),
                         width,
                     );
+                    self.selection.frozen_raw_blocks = self.rendered_raw_blocks();
+                    self.selection.frozen_decorations = self.rendered_decorations();
                     self.selection_active = true;
                     self.selection.anchor = Some(SelectionPos::new(document_x, document_y));
                     self.mode = MarkdownWidgetMode::Drag;
@@ -195,6 +216,8 @@ impl<'a> MarkdownWidget<'a> {
                     let copied_text = if self.selection.has_selection() {
                         self.selection.frozen_lines = Some(self.rendered_lines.clone());
                         self.selection.frozen_width = width;
+                        self.selection.frozen_raw_blocks = self.rendered_raw_blocks();
+                        self.selection.frozen_decorations = self.rendered_decorations();
                         self.selection.get_selected_text()
                     } else {
                         None
@@ -240,8 +263,8 @@ impl<'a> MarkdownWidget<'a> {
     fn check_pending_click_internal(&mut self, area: Rect) -> MarkdownEvent {
         if let Some((x, y, click_scroll_offset)) = self.double_click.check_pending_timeout() {
             let relative_y = y.saturating_sub(area.y) as usize;
-            let relative_x = x.saturating_sub(area.x) as usize;
-            let width = area.width as usize;
+            let (width, content_offset) = self.content_layout(area.width);
+            let relative_x = (x.saturating_sub(area.x) as usize).saturating_sub(content_offset);
 
             let clicked_line = click_scroll_offset + relative_y + 1;
             if clicked_line <= self.scroll.total_lines {
@@ -309,4 +332,16 @@ impl<'a> MarkdownWidget<'a> {
 
         false
     }
+
+    /// Width content is wrapped to in an area `area_width` columns wide, and
+    /// its offset from the left edge, past any document line numbers.
+    fn content_layout(&self, area_width: u16) -> (usize, usize) {
+        let line_num_width = if self.display.show_document_line_numbers {
+            6
+        } else {
+            0
+        };
+        self.display
+            .content_layout((area_width as usize).saturating_sub(line_num_width))
+    }
 }
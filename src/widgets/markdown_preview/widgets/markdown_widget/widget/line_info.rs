@@ -45,6 +45,9 @@ impl<'a> MarkdownWidget<'a> {
                 | TextSegment::Html(s) => s,
                 TextSegment::Link { text, .. } => text,
                 TextSegment::Checkbox(_) => "",
+                TextSegment::Decorated { text, original, .. } => {
+                    original.as_deref().unwrap_or(text)
+                }
             }
         }
 
@@ -76,13 +79,28 @@ impl<'a> MarkdownWidget<'a> {
         let elements = self.parse_elements();
         let mut visual_line_idx = 0;
         let mut logical_line_num = 0;
+        let mut skip_until = 0;
 
         for (idx, element) in elements.iter().enumerate() {
-            if !should_render_line(element, idx, &self.collapse) {
+            if idx < skip_until || !should_render_line(element, idx, &self.collapse) {
                 continue;
             }
 
             logical_line_num += 1;
+
+            if let Some(fence) = self.custom_fence_at(&elements, idx) {
+                skip_until = fence.end;
+                let line_count = self.render_custom_fence(&fence).len();
+                if document_y >= visual_line_idx && document_y < visual_line_idx + line_count {
+                    return Some((
+                        logical_line_num,
+                        "CodeBlockContent".to_string(),
+                        fence.content,
+                    ));
+                }
+                visual_line_idx += line_count;
+                continue;
+            }
             let rendered = render(element, width);
             let line_count = rendered.len();
 
@@ -7,22 +7,26 @@ mod line_info;
 mod render;
 mod state_sync;
 
+pub use crate::widgets::markdown_preview::widgets::markdown_widget::widget::features::fence::FenceRenderFn;
 pub use crate::widgets::markdown_preview::widgets::markdown_widget::widget::features::filter::element_to_plain_text_for_filter;
 pub use crate::widgets::markdown_preview::widgets::markdown_widget::widget::features::selection::apply_selection_highlighting;
 pub use crate::widgets::markdown_preview::widgets::markdown_widget::widget::state_sync::WidgetStateSync;
 
 use crate::primitives::pane::Pane;
+use crate::services::clipboard::{self, ClipboardSource};
 use crate::widgets::markdown_preview::services::theme::AppTheme;
+use crate::widgets::markdown_preview::widgets::markdown_widget::extensions::decorations::TextDecorator;
 use crate::widgets::markdown_preview::widgets::markdown_widget::extensions::scrollbar::ScrollbarConfig;
 use crate::widgets::markdown_preview::widgets::markdown_widget::extensions::toc::TocConfig;
 use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::events::MarkdownEvent;
 use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::types::GitStats;
 use crate::widgets::markdown_preview::widgets::markdown_widget::state::{
     CacheState, CollapseState, DisplaySettings, DoubleClickState, ExpandableState, GitStatsState,
-    ScrollState, SelectionState, SourceState, TocState, VimState,
+    ScrollState, SearchState, SelectionState, SourceState, TocState, VimState,
 };
 use crossterm::event::{KeyEvent, MouseEvent};
 use ratatui::layout::Rect;
+use std::collections::{HashMap, HashSet};
 
 pub(crate) const FRONTMATTER_SECTION_ID: usize = 0;
 pub(crate) const CURRENT_LINE_BG: ratatui::style::Color = ratatui::style::Color::Rgb(38, 52, 63);
@@ -41,10 +45,12 @@ pub struct MarkdownWidget<'a> {
     pub(crate) vim: VimState,
     pub(crate) selection: SelectionState,
     pub(crate) double_click: DoubleClickState,
+    pub(crate) search: SearchState,
     pub(crate) toc_state: Option<TocState>,
     pub(crate) is_resizing: bool,
     pub(crate) mode: MarkdownWidgetMode,
     pub(crate) show_statusline: bool,
+    pub(crate) show_document_stats: bool,
     pub(crate) show_scrollbar: bool,
     pub(crate) scrollbar_config: ScrollbarConfig,
     pub(crate) selection_active: bool,
@@ -54,6 +60,12 @@ pub struct MarkdownWidget<'a> {
     pub(crate) toc_hovered: bool,
     pub(crate) toc_hovered_entry: Option<usize>,
     pub(crate) toc_scroll_offset: usize,
+    pub(crate) toc_focused: bool,
+    pub(crate) toc_selected_entry: usize,
+    pub(crate) toc_collapsed: HashSet<String>,
+    pub(crate) toc_width: Option<u16>,
+    pub(crate) toc_resizing: bool,
+    pub(crate) toc_divider_clicks: DoubleClickState,
     pub(crate) rendered_lines: Vec<ratatui::text::Line<'static>>,
     pub(crate) app_theme: Option<AppTheme>,
     pub(crate) last_double_click: Option<(usize, String, String)>,
@@ -64,15 +76,24 @@ pub struct MarkdownWidget<'a> {
     pub(crate) pane: Option<Pane<'a>>,
     pub(crate) pane_title: Option<String>,
     pub(crate) pane_color: Option<ratatui::style::Color>,
+    pub(crate) fence_renderers: HashMap<String, FenceRenderFn<'a>>,
+    pub(crate) decorators: Vec<Box<dyn TextDecorator + 'a>>,
     pub inner_area: Option<Rect>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum MarkdownWidgetMode {
+    /// Scrolling and navigating.
     #[default]
     Normal,
+    /// Selecting text with the mouse.
     Drag,
+    /// Typing a line filter.
     Filter,
+    /// Typing a search query.
+    Search,
+    /// Navigating the table of contents with the keyboard.
+    Toc,
 }
 
 impl MarkdownWidget<'_> {
@@ -84,13 +105,18 @@ impl MarkdownWidget<'_> {
         self.handle_mouse_internal(&event, area)
     }
 
+    /// The current input mode, e.g. to leave typed keys to the widget
+    /// while it takes a filter or search query.
+    pub fn get_mode(&self) -> MarkdownWidgetMode {
+        self.mode
+    }
+
     pub fn update_git_stats(&mut self) {
         self.git_stats_state.update(self.source.source_path());
     }
 }
 
 impl<'a> MarkdownWidget<'a> {
-
     pub(crate) fn parse_elements(&self) -> Vec<crate::widgets::markdown_preview::MarkdownElement> {
         crate::widgets::markdown_preview::widgets::markdown_widget::foundation::parser::render_markdown_to_elements(
             &self.content,
@@ -118,14 +144,10 @@ impl<'a> MarkdownWidget<'a> {
             return None;
         }
 
-        if let Ok(mut clipboard) = arboard::Clipboard::new() {
-            if clipboard.set_text(&text).is_ok() {
-                if remember_in_selection_state {
-                    self.selection.last_copied_text = Some(text.clone());
-                }
-                return Some(MarkdownEvent::Copied { text });
-            }
+        clipboard::copy(&text, ClipboardSource::Markdown).ok()?;
+        if remember_in_selection_state {
+            self.selection.last_copied_text = Some(text.clone());
         }
-        None
+        Some(MarkdownEvent::Copied { text })
     }
 }
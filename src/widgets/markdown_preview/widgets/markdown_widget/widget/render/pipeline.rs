@@ -1,15 +1,27 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
 use crate::primitives::pane::Pane;
+use crate::widgets::markdown_preview::widgets::markdown_widget::extensions::decorations::DecorationRange;
 use crate::widgets::markdown_preview::widgets::markdown_widget::extensions::scrollbar::CustomScrollbar;
 use crate::widgets::markdown_preview::widgets::markdown_widget::extensions::selection::should_render_line;
-use crate::widgets::markdown_preview::widgets::markdown_widget::extensions::toc::Toc;
+use crate::widgets::markdown_preview::widgets::markdown_widget::extensions::theme::SyntaxHighlighter;
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::blocks::parse_blocks;
 use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::elements::{
     render_with_options, RenderOptions,
 };
 use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::helpers::hash_content;
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::stats::DocumentStats;
+use crate::widgets::markdown_preview::widgets::markdown_widget::foundation::types::FenceBlock;
 use crate::widgets::markdown_preview::widgets::markdown_widget::state::{
-    ParsedCache, RenderCache, TocState,
+    ParsedCache, RenderCache, RenderedElement,
 };
 use crate::widgets::markdown_preview::widgets::markdown_widget::widget::features::filter::element_to_plain_text_for_filter;
+use crate::widgets::markdown_preview::widgets::markdown_widget::widget::features::fold::copy_label_columns;
+use crate::widgets::markdown_preview::widgets::markdown_widget::widget::features::links::{
+    element_links, write_hyperlinks,
+};
+use crate::widgets::markdown_preview::widgets::markdown_widget::widget::features::search::apply_search_highlighting;
 use crate::widgets::markdown_preview::widgets::markdown_widget::widget::features::selection::apply_selection_highlighting;
 use crate::widgets::markdown_preview::widgets::markdown_widget::widget::{
     MarkdownWidget, CURRENT_LINE_BG, CURRENT_LINE_DRAG_BG,
@@ -111,7 +123,9 @@ impl<'a> Widget for &mut MarkdownWidget<'a> {
             0
         };
 
-        let width = (content_area.width as usize).saturating_sub(line_num_width);
+        let (width, content_offset) = self
+            .display
+            .content_layout((content_area.width as usize).saturating_sub(line_num_width));
         let content_hash = hash_content(&self.content);
         let show_line_numbers = self.display.show_line_numbers;
         let theme = self.display.code_block_theme;
@@ -131,6 +145,8 @@ impl<'a> Widget for &mut MarkdownWidget<'a> {
                 hasher.finish()
             })
             .unwrap_or(0);
+        let fence_hash = self.fence_hash();
+        let decoration_hash = self.decoration_hash();
 
         let show_heading_collapse = self.display.show_heading_collapse;
         let render_cache_valid = !self.filter_mode
@@ -145,6 +161,8 @@ impl<'a> Widget for &mut MarkdownWidget<'a> {
                         && c.theme == theme
                         && c.app_theme_hash == app_theme_hash
                         && c.show_heading_collapse == show_heading_collapse
+                        && c.fence_hash == fence_hash
+                        && c.decoration_hash == decoration_hash
                 })
                 .unwrap_or(false);
 
@@ -160,21 +178,52 @@ impl<'a> Widget for &mut MarkdownWidget<'a> {
                     .map(|c| c.content_hash == content_hash)
                     .unwrap_or(false);
 
-                let elements = if parsed_cache_valid {
-                    self.cache
+                if !parsed_cache_valid {
+                    // Blocks that did not change since the last parse keep their elements
+                    let previous = self
+                        .cache
                         .parsed
-                        .as_ref()
-                        .expect("parsed cache present")
-                        .elements
-                        .clone()
-                } else {
-                    let parsed = self.parse_elements();
+                        .take()
+                        .map(|parsed| parsed.blocks)
+                        .unwrap_or_default();
+                    let (blocks, elements) =
+                        parse_blocks(&self.content, &previous, &SyntaxHighlighter::new());
+                    self.cache.stats = None;
                     self.cache.parsed = Some(ParsedCache {
                         content_hash,
-                        elements: parsed.clone(),
+                        elements,
+                        blocks,
                     });
-                    parsed
-                };
+                }
+                let parsed = self.cache.parsed.as_ref().expect("parsed cache present");
+                let elements = parsed.elements.clone();
+                let element_keys = parsed.element_keys();
+
+                // Lines of unchanged blocks can be reused if they were
+                // rendered with the same settings
+                let mut previous_rendered = self
+                    .cache
+                    .render
+                    .take()
+                    .filter(|c| {
+                        c.width == width
+                            && c.show_line_numbers == show_line_numbers
+                            && c.theme == theme
+                            && c.app_theme_hash == app_theme_hash
+                            && c.show_heading_collapse == show_heading_collapse
+                            && c.fence_hash == fence_hash
+                            && c.decoration_hash == decoration_hash
+                    })
+                    .map(|c| c.rendered_elements)
+                    .unwrap_or_default();
+                let mut rendered_elements = HashMap::new();
+
+                if self.cache.stats.is_none() {
+                    self.cache.stats = Some(DocumentStats::from_elements(
+                        &elements,
+                        self.display.reading_time_includes_code,
+                    ));
+                }
 
                 let render_options = RenderOptions {
                     show_line_numbers,
@@ -189,9 +238,72 @@ impl<'a> Widget for &mut MarkdownWidget<'a> {
 
                 let mut lines: Vec<Line<'static>> = Vec::new();
                 let mut boundaries: Vec<(usize, usize)> = Vec::new();
+                let mut source_lines: Vec<usize> = Vec::new();
+                let mut raw_blocks: Vec<(usize, usize, String)> = Vec::new();
+                let mut fences: Vec<FenceBlock> = Vec::new();
+                let mut decorations = Vec::new();
+                let mut links = Vec::new();
+                let mut skip_until = 0;
 
                 for (idx, element) in elements.iter().enumerate() {
-                    if !should_render_line(element, idx, &self.collapse) {
+                    if idx < skip_until || !should_render_line(element, idx, &self.collapse) {
+                        continue;
+                    }
+
+                    // Fences with a registered renderer are rendered as one block
+                    if let Some(fence) = self.custom_fence_at(&elements, idx) {
+                        skip_until = fence.end;
+                        if let Some(ref filter) = filter_lower {
+                            let text = format!("{}\n{}", fence.language, fence.content);
+                            if !text.to_lowercase().contains(filter) {
+                                continue;
+                            }
+                        }
+
+                        let start_idx = lines.len();
+                        let rendered = self.render_custom_fence(&fence);
+                        let line_count = rendered.len();
+                        lines.extend(rendered);
+                        boundaries.push((start_idx, line_count));
+                        source_lines.push(element.source_line);
+                        if line_count > 0 {
+                            raw_blocks.push((start_idx, line_count, fence.content));
+                        }
+                        continue;
+                    }
+
+                    // Fences that cannot be highlighted are rendered as a collapsible block
+                    if let Some(fence) = self.fallback_fence_at(&elements, idx) {
+                        skip_until = fence.end;
+                        if let Some(ref filter) = filter_lower {
+                            let text = format!("{}\n{}", fence.language, fence.content);
+                            if !text.to_lowercase().contains(filter) {
+                                continue;
+                            }
+                        }
+
+                        let row = lines.len();
+                        let collapsed = self.is_fence_collapsed(&fence);
+                        for (source_line, rendered) in self.render_fallback_fence(
+                            &elements[idx..fence.end],
+                            &fence,
+                            collapsed,
+                            width,
+                            render_options,
+                        ) {
+                            boundaries.push((lines.len(), rendered.len()));
+                            source_lines.push(source_line);
+                            lines.extend(rendered);
+                        }
+                        fences.push(FenceBlock {
+                            id: fence.source_line,
+                            row,
+                            line_count: lines.len() - row,
+                            copy_columns: copy_label_columns(&lines[row]),
+                            language: fence.language,
+                            content: fence.content,
+                            collapsed,
+                        });
                         continue;
                     }
 
@@ -203,10 +315,54 @@ impl<'a> Widget for &mut MarkdownWidget<'a> {
                     }
 
                     let start_idx = lines.len();
-                    let rendered = render_with_options(element, width, render_options);
+                    let cached = element_keys[idx].map(|(id, hash, local)| {
+                        let key = (id, local);
+                        let cached = previous_rendered
+                            .remove(&key)
+                            .filter(|cached| cached.block_hash == hash)
+                            .unwrap_or_else(|| {
+                                let (lines, decorations) = self
+                                    .render_decorated(element, width, render_options, 0)
+                                    .unwrap_or_else(|| {
+                                        (
+                                            render_with_options(element, width, render_options),
+                                            Vec::new(),
+                                        )
+                                    });
+                                Arc::new(RenderedElement {
+                                    block_hash: hash,
+                                    lines,
+                                    decorations,
+                                })
+                            });
+                        rendered_elements.insert(key, Arc::clone(&cached));
+                        cached
+                    });
+                    let rendered = match cached {
+                        Some(cached) => {
+                            decorations.extend(cached.decorations.iter().map(|range| {
+                                DecorationRange {
+                                    row: range.row + start_idx,
+                                    ..range.clone()
+                                }
+                            }));
+                            cached.lines.clone()
+                        }
+                        None => {
+                            match self.render_decorated(element, width, render_options, start_idx) {
+                                Some((rendered, ranges)) => {
+                                    decorations.extend(ranges);
+                                    rendered
+                                }
+                                None => render_with_options(element, width, render_options),
+                            }
+                        }
+                    };
+                    links.extend(element_links(element, &rendered, start_idx));
                     let line_count = rendered.len();
                     lines.extend(rendered);
                     boundaries.push((start_idx, line_count));
+                    source_lines.push(element.source_line);
                 }
 
                 self.cache.render = Some(RenderCache {
@@ -216,8 +372,16 @@ impl<'a> Widget for &mut MarkdownWidget<'a> {
                     theme,
                     app_theme_hash,
                     show_heading_collapse,
+                    fence_hash,
+                    decoration_hash,
                     lines: lines.clone(),
                     line_boundaries: boundaries.clone(),
+                    source_lines,
+                    raw_blocks,
+                    fences,
+                    decorations,
+                    links,
+                    rendered_elements,
                 });
 
                 (lines, boundaries)
@@ -225,6 +389,19 @@ impl<'a> Widget for &mut MarkdownWidget<'a> {
 
         self.scroll.update_total_lines(all_lines.len());
         self.rendered_lines = all_lines.clone();
+        if let Some((line, rows)) = self.scroll.take_top_anchor() {
+            if let Some(row) = self.row_for_source_line(line) {
+                self.scroll.scroll_offset = (row + rows).min(self.scroll.max_scroll_offset());
+            }
+        }
+        if let Some(line) = self.scroll.take_source_anchor() {
+            if let Some(row) = self.row_for_source_line(line) {
+                self.scroll.set_current_line(row + 1);
+            }
+        }
+        if !render_cache_valid && self.search.is_active() {
+            self.search.find_matches(&all_lines);
+        }
 
         let start = self.scroll.scroll_offset.min(all_lines.len());
         let end = (self.scroll.scroll_offset + content_area.height as usize).min(all_lines.len());
@@ -236,6 +413,19 @@ impl<'a> Widget for &mut MarkdownWidget<'a> {
             visible_lines
         };
 
+        let visible_lines: Vec<Line<'static>> = if content_offset > 0 {
+            let gutter = " ".repeat(content_offset);
+            visible_lines
+                .into_iter()
+                .map(|mut line| {
+                    line.spans.insert(0, Span::raw(gutter.clone()));
+                    line
+                })
+                .collect()
+        } else {
+            visible_lines
+        };
+
         let current_visual_line = self.scroll.current_line.saturating_sub(1);
 
         let final_lines: Vec<Line<'_>> = if self.display.show_document_line_numbers {
@@ -346,6 +536,13 @@ impl<'a> Widget for &mut MarkdownWidget<'a> {
                 .collect()
         };
 
+        let final_lines = apply_search_highlighting(
+            final_lines,
+            &self.search,
+            start,
+            line_num_width + content_offset,
+        );
+
         for (i, line) in final_lines.iter().enumerate() {
             if i < content_area.height as usize {
                 let y = content_area.y + i as u16;
@@ -376,18 +573,23 @@ impl<'a> Widget for &mut MarkdownWidget<'a> {
             }
         }
 
-        if let Some(ov_area) = overlay_area {
-            let mut auto_state = TocState::from_content(&self.content);
-            auto_state.hovered = self.toc_hovered;
-            auto_state.hovered_entry = self.toc_hovered_entry;
-            auto_state.scroll_offset = self.toc_scroll_offset;
+        if self.display.hyperlinks {
+            if let Some(cache) = self.cache.render.as_ref() {
+                write_hyperlinks(
+                    buf,
+                    content_area,
+                    &final_lines,
+                    &cache.links,
+                    start,
+                    line_num_width + content_offset,
+                );
+            }
+        }
 
+        if let Some(ov_area) = overlay_area {
+            let auto_state = self.auto_toc_state();
             let final_state = self.resolved_toc_state(&auto_state);
-            let toc = Toc::new(final_state)
-                .expanded(self.toc_hovered)
-                .config(self.toc_config.clone());
-
-            toc.render(ov_area, buf);
+            self.toc_widget(final_state).render(ov_area, buf);
         }
 
         if let Some(sl_area) = statusline_area {
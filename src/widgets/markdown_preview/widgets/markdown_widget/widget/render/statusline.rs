@@ -1,4 +1,4 @@
-use crate::primitives::statusline::{StatusLineStacked, SLANT_BL_TR, SLANT_TL_BR};
+use crate::primitives::statusline::StatusLineStacked;
 use crate::widgets::markdown_preview::widgets::markdown_widget::widget::{
     MarkdownWidget, MarkdownWidgetMode,
 };
@@ -35,7 +35,23 @@ impl<'a> MarkdownWidget<'a> {
                     .map(|t| t.success)
                     .unwrap_or(Color::Rgb(152, 195, 121));
                 let filter_text = self.filter.as_deref().unwrap_or("");
-                (format!(" /{} ", filter_text), color)
+                (format!(" FILTER {} ", filter_text), color)
+            }
+            MarkdownWidgetMode::Search => {
+                let color = self
+                    .app_theme
+                    .as_ref()
+                    .map(|t| t.accent)
+                    .unwrap_or(Color::Rgb(198, 120, 221));
+                (format!(" /{} ", self.search.query()), color)
+            }
+            MarkdownWidgetMode::Toc => {
+                let color = self
+                    .app_theme
+                    .as_ref()
+                    .map(|t| t.primary)
+                    .unwrap_or(Color::Rgb(138, 99, 210));
+                (" TOC ".to_string(), color)
             }
         };
 
@@ -92,28 +108,25 @@ impl<'a> MarkdownWidget<'a> {
             area.x + mode_len + file_len
         };
 
-        let mut statusline = StatusLineStacked::new().start(
-            Span::from(mode_text.clone()).style(
-                Style::new()
-                    .fg(mode_fg)
-                    .bg(mode_color)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::from(SLANT_TL_BR).style(Style::new().fg(mode_color).bg(file_bg)),
+        let mut statusline = StatusLineStacked::new().segment_start(
+            Span::from(mode_text.clone()).style(Style::new().add_modifier(Modifier::BOLD)),
+            mode_fg,
+            mode_color,
         );
 
         if let Some(name) = filename {
-            let file_segment = format!(" {} ", name);
-            statusline = statusline.start(
-                Span::from(file_segment).style(Style::new().fg(file_fg).bg(file_bg)),
-                Span::from(SLANT_TL_BR).style(Style::new().fg(file_bg)),
-            );
+            statusline = statusline.segment_start(format!(" {} ", name), file_fg, file_bg);
         }
 
-        statusline = statusline.end(
-            Span::from(position_text).style(Style::new().fg(position_fg).bg(position_bg)),
-            Span::from(SLANT_BL_TR).style(Style::new().fg(position_bg)),
-        );
+        statusline = statusline.segment_end(position_text, position_fg, position_bg);
+
+        if let Some(stats_text) = self.stats_text() {
+            statusline = statusline.segment_end(stats_text, file_fg, file_bg);
+        }
+
+        if let Some(search_text) = self.search_text() {
+            statusline = statusline.segment_end(search_text, mode_fg, mode_color);
+        }
 
         ratatui::widgets::Widget::render(statusline, area, buf);
 
@@ -175,4 +188,49 @@ impl<'a> MarkdownWidget<'a> {
             buf.set_string(x, area.y, &del_num, red);
         }
     }
+
+    /// Status line text for the selection's counts while text is selected,
+    /// otherwise for the document's word count and reading time.
+    fn stats_text(&self) -> Option<String> {
+        if !self.show_document_stats {
+            return None;
+        }
+        if let Some(selection) = self.selection_stats() {
+            return Some(format!(
+                " {} words · {} chars selected ",
+                group_thousands(selection.words),
+                group_thousands(selection.chars)
+            ));
+        }
+        let stats = self.stats()?;
+        Some(format!(
+            " {} words · {} min read ",
+            group_thousands(stats.words),
+            stats.reading_time_minutes
+        ))
+    }
+}
+
+impl MarkdownWidget<'_> {
+    /// Status line text for the search match counter, e.g. ` 3/12 `.
+    fn search_text(&self) -> Option<String> {
+        if !self.search.is_active() {
+            return None;
+        }
+        let (current, total) = self.search_position().unwrap_or((0, 0));
+        Some(format!(" {}/{} ", current, total))
+    }
+}
+
+/// Formats `n` with `,` between groups of three digits.
+fn group_thousands(n: usize) -> String {
+    let digits = n.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
 }
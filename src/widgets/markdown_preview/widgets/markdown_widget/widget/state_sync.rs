@@ -1,6 +1,9 @@
-use crate::widgets::markdown_preview::widgets::markdown_widget::state::MarkdownState;
+use crate::widgets::markdown_preview::widgets::markdown_widget::state::{
+    DoubleClickState, MarkdownState,
+};
 use crate::widgets::markdown_preview::widgets::markdown_widget::widget::MarkdownWidget;
 use ratatui::layout::Rect;
+use std::collections::HashSet;
 
 #[derive(Debug, Clone)]
 pub struct WidgetStateSync {
@@ -8,6 +11,18 @@ pub struct WidgetStateSync {
     pub toc_hovered: bool,
     pub toc_hovered_entry: Option<usize>,
     pub toc_scroll_offset: usize,
+    /// Whether keyboard focus is in the TOC.
+    pub toc_focused: bool,
+    /// Index of the TOC entry selected in focus mode.
+    pub toc_selected_entry: usize,
+    /// Keys of the collapsed TOC subtrees.
+    pub toc_collapsed: HashSet<String>,
+    /// Width the TOC divider was dragged to.
+    pub toc_width: Option<u16>,
+    /// Whether the TOC divider is being dragged.
+    pub toc_resizing: bool,
+    /// Double-click detection on the TOC divider.
+    pub toc_divider_clicks: DoubleClickState,
     pub selection_active: bool,
     pub last_double_click: Option<(usize, String, String)>,
     pub filter: Option<String>,
@@ -23,6 +38,12 @@ impl WidgetStateSync {
             toc_hovered: false,
             toc_hovered_entry: None,
             toc_scroll_offset: 0,
+            toc_focused: false,
+            toc_selected_entry: 0,
+            toc_collapsed: HashSet::new(),
+            toc_width: None,
+            toc_resizing: false,
+            toc_divider_clicks: DoubleClickState::default(),
             selection_active: false,
             last_double_click: None,
             filter: None,
@@ -37,11 +58,19 @@ impl WidgetStateSync {
         state.toc_hovered = self.toc_hovered;
         state.toc_hovered_entry = self.toc_hovered_entry;
         state.toc_scroll_offset = self.toc_scroll_offset;
+        state.toc_focused = self.toc_focused;
+        state.toc_selected_entry = self.toc_selected_entry;
+        state.toc_collapsed = self.toc_collapsed.clone();
+        state.toc_width = self.toc_width;
+        state.toc_resizing = self.toc_resizing;
+        state.toc_divider_clicks = self.toc_divider_clicks.clone();
         state.selection_active = self.selection_active;
         state.filter = self.filter.clone();
         state.filter_mode = self.filter_mode;
         state.scroll.scroll_offset = self.scroll_offset;
         state.scroll.current_line = self.current_line;
+        state.scroll.source_anchor = None;
+        state.scroll.top_anchor = None;
     }
 
     pub fn take_double_click(&mut self) -> Option<(usize, String, String)> {
@@ -56,6 +85,12 @@ impl<'a> MarkdownWidget<'a> {
             toc_hovered: self.toc_hovered,
             toc_hovered_entry: self.toc_hovered_entry,
             toc_scroll_offset: self.toc_scroll_offset,
+            toc_focused: self.toc_focused,
+            toc_selected_entry: self.toc_selected_entry,
+            toc_collapsed: self.toc_collapsed.clone(),
+            toc_width: self.toc_width,
+            toc_resizing: self.toc_resizing,
+            toc_divider_clicks: self.toc_divider_clicks.clone(),
             selection_active: self.selection.is_active(),
             last_double_click: self.last_double_click.take(),
             filter: self.filter.clone(),
@@ -70,8 +105,17 @@ impl<'a> MarkdownWidget<'a> {
         state.toc_hovered = self.toc_hovered;
         state.toc_hovered_entry = self.toc_hovered_entry;
         state.toc_scroll_offset = self.toc_scroll_offset;
+        state.toc_focused = self.toc_focused;
+        state.toc_selected_entry = self.toc_selected_entry;
+        state.toc_collapsed = self.toc_collapsed;
+        state.toc_width = self.toc_width;
+        state.toc_resizing = self.toc_resizing;
+        state.toc_divider_clicks = self.toc_divider_clicks;
         state.selection_active = self.selection.is_active();
         state.filter = self.filter;
         state.filter_mode = self.filter_mode;
+        state.search = self.search;
+        state.scroll.source_anchor = None;
+        state.scroll.top_anchor = None;
     }
 }
@@ -11,6 +11,9 @@ pub use crate::primitives::menu_bar::*;
 #[cfg(feature = "pane")]
 pub use crate::primitives::pane::*;
 
+#[cfg(feature = "progress")]
+pub use crate::primitives::progress::*;
+
 #[cfg(feature = "resizable-grid")]
 pub use crate::primitives::resizable_grid::*;
 
@@ -23,6 +26,9 @@ pub use crate::primitives::statusline::*;
 #[cfg(feature = "termtui")]
 pub use crate::primitives::termtui::*;
 
+#[cfg(feature = "text-input")]
+pub use crate::primitives::text_input::*;
+
 #[cfg(feature = "toast")]
 pub use crate::primitives::toast::*;
 
@@ -36,6 +42,15 @@ pub use crate::primitives::widget_event::*;
 #[cfg(feature = "ai-chat")]
 pub use crate::widgets::ai_chat::*;
 
+#[cfg(feature = "chrome-bar")]
+pub use crate::widgets::chrome_bar::*;
+
+#[cfg(feature = "clipboard-history")]
+pub use crate::widgets::clipboard_history::*;
+
+#[cfg(feature = "copy-menu")]
+pub use crate::widgets::copy_menu::*;
+
 #[cfg(feature = "code-diff")]
 pub use crate::widgets::code_diff::*;
 
@@ -55,6 +70,15 @@ pub use crate::widgets::theme_picker::*;
 #[cfg(feature = "ai-chat")]
 pub mod ai_chat;
 
+#[cfg(feature = "chrome-bar")]
+pub mod chrome_bar;
+
+#[cfg(feature = "clipboard-history")]
+pub mod clipboard_history;
+
+#[cfg(feature = "copy-menu")]
+pub mod copy_menu;
+
 #[cfg(feature = "code-diff")]
 pub mod code_diff;
 
@@ -8,18 +8,24 @@ use ratatui::{
     Frame,
 };
 
+use crate::primitives::text_input::is_dead_key;
 use crate::widgets::theme_picker::builtin_themes::BUILTIN_THEMES;
 use crate::widgets::theme_picker::state::ThemePickerState;
 use crate::widgets::theme_picker::theme_colors::ThemeColors;
 
 const MAX_VISIBLE_THEMES: usize = 20;
 const POPUP_WIDTH: u16 = 44;
+/// Name of the entry that follows the OS dark/light preference.
+const FOLLOW_SYSTEM: &str = "follow-system";
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ThemePickerEvent {
     Selected(String),
     Cancelled,
     PreviewChanged(String),
+    /// The "Follow System" entry was chosen; the app should switch the theme
+    /// variant to follow the OS preference.
+    FollowSystem,
 }
 
 fn format_display_name(name: &str) -> String {
@@ -35,10 +41,11 @@ fn format_display_name(name: &str) -> String {
         .join(" ")
 }
 
-fn filter_themes(filter: &str) -> Vec<(usize, &'static str)> {
-    BUILTIN_THEMES
-        .iter()
-        .enumerate()
+fn filter_themes(filter: &str, follow_system: bool) -> Vec<(usize, &'static str)> {
+    let follow_system = follow_system.then_some((usize::MAX, FOLLOW_SYSTEM));
+    follow_system
+        .into_iter()
+        .chain(BUILTIN_THEMES.iter().copied().enumerate())
         .filter(|(_, name)| {
             if filter.is_empty() {
                 true
@@ -50,7 +57,6 @@ fn filter_themes(filter: &str) -> Vec<(usize, &'static str)> {
                         .contains(&filter_lower)
             }
         })
-        .map(|(i, name)| (i, *name))
         .collect()
 }
 
@@ -149,6 +155,7 @@ pub struct ThemePicker {
     width: u16,
     title: String,
     show_footer: bool,
+    follow_system: bool,
 }
 
 impl Default for ThemePicker {
@@ -164,6 +171,7 @@ impl ThemePicker {
             width: POPUP_WIDTH,
             title: "Select Theme".to_string(),
             show_footer: true,
+            follow_system: false,
         }
     }
 
@@ -182,6 +190,13 @@ impl ThemePicker {
         self
     }
 
+    /// Adds a "Follow System" entry above the themes, reported as
+    /// [`ThemePickerEvent::FollowSystem`] when chosen.
+    pub fn follow_system(mut self, enabled: bool) -> Self {
+        self.follow_system = enabled;
+        self
+    }
+
     pub fn show(&mut self) {
         self.state.show();
     }
@@ -205,7 +220,7 @@ impl ThemePicker {
 
         use crossterm::event::KeyCode;
 
-        let filtered = filter_themes(&self.state.filter());
+        let filtered = filter_themes(self.state.filter(), self.follow_system);
 
         match key {
             KeyCode::Esc => {
@@ -218,7 +233,10 @@ impl ThemePicker {
                 if !filtered.is_empty() {
                     let new_index = (self.state.index() + 1) % filtered.len();
                     self.state.set_index(new_index);
-                    if let Some((_, theme_name)) = filtered.get(new_index) {
+                    if let Some((_, theme_name)) = filtered
+                        .get(new_index)
+                        .filter(|(_, name)| *name != FOLLOW_SYSTEM)
+                    {
                         let theme = load_theme_preview(theme_name);
                         self.state.set_current_preview(theme);
                         return Some(ThemePickerEvent::PreviewChanged(theme_name.to_string()));
@@ -234,7 +252,10 @@ impl ThemePicker {
                         self.state.index() - 1
                     };
                     self.state.set_index(new_index);
-                    if let Some((_, theme_name)) = filtered.get(new_index) {
+                    if let Some((_, theme_name)) = filtered
+                        .get(new_index)
+                        .filter(|(_, name)| *name != FOLLOW_SYSTEM)
+                    {
                         let theme = load_theme_preview(theme_name);
                         self.state.set_current_preview(theme);
                         return Some(ThemePickerEvent::PreviewChanged(theme_name.to_string()));
@@ -247,6 +268,9 @@ impl ThemePicker {
                     self.state.hide();
                     self.state.clear_filter();
                     self.state.set_index(0);
+                    if *theme_name == FOLLOW_SYSTEM {
+                        return Some(ThemePickerEvent::FollowSystem);
+                    }
                     return Some(ThemePickerEvent::Selected(theme_name.to_string()));
                 }
                 None
@@ -257,7 +281,7 @@ impl ThemePicker {
                 None
             }
             KeyCode::Char(c) => {
-                if c.is_alphanumeric() || *c == ' ' || *c == '-' {
+                if c.is_alphanumeric() || *c == ' ' || *c == '-' || is_dead_key(*c) {
                     self.state.push_filter(*c);
                 }
                 None
@@ -266,6 +290,16 @@ impl ThemePicker {
         }
     }
 
+    /// Append pasted text, e.g. from a crossterm `Event::Paste`, to the
+    /// filter.
+    pub fn handle_paste(&mut self, text: &str) {
+        if self.state.is_visible() {
+            self.state
+                .push_filter_str(text.trim_end_matches(['\r', '\n']));
+            self.state.set_index(0);
+        }
+    }
+
     pub fn handle_mouse(&mut self, _mouse: crossterm::event::MouseEvent) {
         // Theme picker doesn't currently support mouse interaction
     }
@@ -276,7 +310,7 @@ impl ThemePicker {
         }
 
         let current_theme = self.state.current_preview();
-        let filtered = filter_themes(&self.state.filter());
+        let filtered = filter_themes(self.state.filter(), self.follow_system);
         let visible_count = filtered.len().min(MAX_VISIBLE_THEMES);
         let popup_height = (visible_count + if self.show_footer { 7 } else { 5 }) as u16;
 
@@ -295,13 +329,10 @@ impl ThemePicker {
         let mut items: Vec<Line> = Vec::new();
 
         let search_style = Style::default().fg(current_theme.text);
-        let cursor = if self.state.filter().is_empty() {
-            "_"
-        } else {
-            ""
-        };
+        let filter_input = self.state.filter_input();
+        let cursor = if filter_input.is_empty() { "_" } else { "" };
         let mut filter_str = String::new();
-        let _ = write!(filter_str, "{}{}", self.state.filter(), cursor);
+        let _ = write!(filter_str, "{}{}", filter_input.display_value(), cursor);
         items.push(Line::from(vec![
             Span::styled(" / ", Style::default().fg(current_theme.text_muted)),
             Span::styled(filter_str, search_style.add_modifier(Modifier::BOLD)),
@@ -1,10 +1,11 @@
+use crate::primitives::text_input::TextInputState;
 use crate::widgets::theme_picker::theme_colors::ThemeColors;
 
 #[derive(Debug, Clone)]
 pub struct ThemePickerState {
     visible: bool,
     index: usize,
-    filter: String,
+    filter: TextInputState,
     current_preview: ThemeColors,
     saved_index: usize,
     original_index: Option<usize>,
@@ -15,7 +16,7 @@ impl ThemePickerState {
         Self {
             visible: false,
             index: 0,
-            filter: String::new(),
+            filter: TextInputState::new(),
             current_preview: ThemeColors::default(),
             saved_index: 0,
             original_index: None,
@@ -44,15 +45,26 @@ impl ThemePickerState {
     }
 
     pub fn filter(&self) -> &str {
+        self.filter.value()
+    }
+
+    /// The filter input, e.g. for a dead key waiting to compose.
+    pub fn filter_input(&self) -> &TextInputState {
         &self.filter
     }
 
     pub fn push_filter(&mut self, c: char) {
-        self.filter.push(c);
+        self.filter.insert_char(c);
+    }
+
+    /// Append pasted text to the filter.
+    pub fn push_filter_str(&mut self, text: &str) {
+        self.filter.insert_str(text);
     }
 
+    /// Remove the last grapheme of the filter, or a pending dead key.
     pub fn pop_filter(&mut self) {
-        self.filter.pop();
+        self.filter.backspace();
     }
 
     pub fn clear_filter(&mut self) {
@@ -95,7 +107,7 @@ impl From<&ThemePickerState> for ThemePickerStateSnapshot {
         Self {
             visible: state.visible,
             index: state.index,
-            filter: state.filter.clone(),
+            filter: state.filter().to_string(),
             saved_index: state.saved_index,
         }
     }
@@ -0,0 +1,22 @@
+use ratatui::{
+    layout::Rect,
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use ratkit::scaffold::{view_fn, AppBuilder, AppContext, HotkeyFooterElement};
+use ratkit::widgets::hotkey_footer::HotkeyItem;
+
+fn main() -> std::io::Result<()> {
+    AppBuilder::new("{{project-name}}")
+        .with_theme_persistence()
+        .slot_header(view_fn(|frame: &mut Frame, area: Rect, ctx: &AppContext| {
+            frame.render_widget(Paragraph::new(format!(" {}", ctx.name())), area);
+        }))
+        .content(view_fn(|frame: &mut Frame, area: Rect, _: &AppContext| {
+            let body = Paragraph::new("Hello from ratkit!")
+                .block(Block::default().borders(Borders::ALL));
+            frame.render_widget(body, area);
+        }))
+        .slot_footer(HotkeyFooterElement::new(vec![HotkeyItem::new("q", "quit")]))
+        .run()
+}